@@ -52,6 +52,7 @@ from typing import TYPE_CHECKING, Any, Literal, overload
 from daft.logical.schema import Schema
 
 if TYPE_CHECKING:
+    from daft.io import IOConfig
     from daft.utils import ColumnInputType
     from daft.convert import InputListType
 
@@ -209,6 +210,34 @@ class Catalog(ABC):
         except ImportError:
             raise ImportError("Iceberg support not installed: pip install -U 'daft[iceberg]'")
 
+    @staticmethod
+    def from_iceberg_rest(
+        uri: str,
+        name: str = "default",
+        warehouse: str | None = None,
+        token: str | None = None,
+        io_config: IOConfig | None = None,
+    ) -> Catalog:
+        """Create a Daft Catalog which talks to an Iceberg REST catalog natively, without PyIceberg.
+
+        Args:
+            uri (str): url of the REST catalog server
+            name (str): name of the catalog, defaults to 'default'
+            warehouse (str, optional): warehouse to request the catalog config for
+            token (str, optional): bearer token sent with every request
+            io_config (IOConfig, optional): IO config used to read table metadata and data files
+
+        Returns:
+            Catalog: a new Catalog instance backed by the REST catalog.
+
+        Examples:
+            >>> catalog = Catalog.from_iceberg_rest("http://localhost:8181")
+            >>> catalog.list_tables()
+        """
+        from daft.catalog.__internal import IcebergRestCatalog
+
+        return IcebergRestCatalog._new(name, uri, warehouse, token, io_config)
+
     @staticmethod
     def from_unity(catalog: object) -> Catalog:
         """Create a Daft Catalog from a Unity Catalog client.
@@ -1,6 +1,6 @@
 from __future__ import annotations
 
-from typing import Any
+from typing import TYPE_CHECKING, Any
 
 from daft.catalog import Catalog, Identifier, Properties, Table
 from daft.daft import PyCatalog as _PyCatalog
@@ -9,6 +9,9 @@ from daft.dataframe import DataFrame
 from daft.logical.builder import LogicalPlanBuilder
 from daft.schema import Schema
 
+if TYPE_CHECKING:
+    from daft.io import IOConfig
+
 
 class _RustCatalog(Catalog):
     """Shim to wrap PyCatalog and subclass Catalog.
@@ -91,3 +94,19 @@ class MemoryTable(_RustTable):
     @staticmethod
     def _new(name: str, schema: Schema) -> Table:
         return _PyTable.new_memory_table(name, schema._schema)
+
+
+class IcebergRestCatalog(_RustCatalog):
+    @staticmethod
+    def _new(
+        name: str,
+        uri: str,
+        warehouse: str | None = None,
+        token: str | None = None,
+        io_config: IOConfig | None = None,
+    ) -> Catalog:
+        return _PyCatalog.new_iceberg_rest_catalog(name, uri, warehouse, token, io_config)
+
+
+class IcebergRestTable(_RustTable):
+    pass
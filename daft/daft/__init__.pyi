@@ -121,6 +121,8 @@ class ImageFormat(Enum):
     TIFF = 3
     GIF = 4
     BMP = 5
+    WEBP = 6
+    AVIF = 7
 
     @staticmethod
     def from_format_string(mode: str) -> ImageFormat:
@@ -1051,6 +1053,8 @@ class PyDataType:
         dtype: PyDataType, shape: tuple[int, ...] | None = None, use_offset_indices: builtins.bool = False
     ) -> PyDataType: ...
     @staticmethod
+    def geometry() -> PyDataType: ...
+    @staticmethod
     def python() -> PyDataType: ...
     def to_arrow(self, cast_tensor_type_for_ray: builtins.bool | None = None) -> pa.DataType: ...
     def is_null(self) -> builtins.bool: ...
@@ -1086,6 +1090,7 @@ class PyDataType:
     def is_fixed_shape_tensor(self) -> builtins.bool: ...
     def is_sparse_tensor(self) -> builtins.bool: ...
     def is_fixed_shape_sparse_tensor(self) -> builtins.bool: ...
+    def is_geometry(self) -> builtins.bool: ...
     def is_python(self) -> builtins.bool: ...
     def is_numeric(self) -> builtins.bool: ...
     def is_integer(self) -> builtins.bool: ...
@@ -1150,6 +1155,8 @@ class PyExpr:
     def stddev(self) -> PyExpr: ...
     def min(self) -> PyExpr: ...
     def max(self) -> PyExpr: ...
+    def min_by(self, by: PyExpr) -> PyExpr: ...
+    def max_by(self, by: PyExpr) -> PyExpr: ...
     def bool_and(self) -> PyExpr: ...
     def bool_or(self) -> PyExpr: ...
     def any_value(self, ignore_nulls: bool) -> PyExpr: ...
@@ -1695,6 +1702,9 @@ class LogicalPlanBuilder:
     def distinct(self, on: list[PyExpr]) -> LogicalPlanBuilder: ...
     def sample(self, fraction: float, with_replacement: bool, seed: int | None) -> LogicalPlanBuilder: ...
     def aggregate(self, agg_exprs: list[PyExpr], groupby_exprs: list[PyExpr]) -> LogicalPlanBuilder: ...
+    def aggregate_grouping_sets(
+        self, agg_exprs: list[PyExpr], groupby_exprs: list[PyExpr], grouping_sets: list[list[int]]
+    ) -> LogicalPlanBuilder: ...
     def pivot(
         self,
         groupby_exprs: list[PyExpr],
@@ -1888,6 +1898,7 @@ class PyDaftExecutionConfig:
         native_parquet_writer: bool | None = None,
         use_experimental_distributed_engine: bool | None = None,
         min_cpu_per_task: float | None = None,
+        recursive_cte_max_iterations: int | None = None,
     ) -> PyDaftExecutionConfig: ...
     @property
     def scan_tasks_min_size_bytes(self) -> int: ...
@@ -1939,6 +1950,8 @@ class PyDaftExecutionConfig:
     def use_experimental_distributed_engine(self) -> bool: ...
     @property
     def min_cpu_per_task(self) -> float: ...
+    @property
+    def recursive_cte_max_iterations(self) -> int: ...
 
 class PyDaftPlanningConfig:
     @staticmethod
@@ -2007,6 +2020,14 @@ class PyCatalog:
     def list_tables(self, pattern: str | None = None) -> list[PyIdentifier]: ...
     @staticmethod
     def new_memory_catalog(name: str) -> Catalog: ...
+    @staticmethod
+    def new_iceberg_rest_catalog(
+        name: str,
+        uri: str,
+        warehouse: str | None = None,
+        token: str | None = None,
+        io_config: IOConfig | None = None,
+    ) -> Catalog: ...
 
 class PyTable:
     def name(self) -> str: ...
@@ -2100,4 +2121,15 @@ class PyScalarFunction:
     def __call__(self, *args: PyExpr, **kwargs: PyExpr) -> PyExpr: ...
 
 def get_function_from_registry(name: str) -> PyScalarFunction: ...
+def build_ivf_index(
+    embeddings: PySeries,
+    path: str,
+    ids: PySeries | None = None,
+    nlist: int | None = None,
+    metric: str | None = None,
+    max_iterations: int = 10,
+    seed: int = 0,
+    io_config: IOConfig | None = None,
+) -> None: ...
+def infer_json_dtype(input: PySeries, path: str = "$", sample_size: int = 1000) -> PyDataType: ...
 def to_from_proto(builder: LogicalPlanBuilder) -> LogicalPlanBuilder: ...
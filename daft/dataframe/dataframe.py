@@ -753,6 +753,7 @@ class DataFrame:
         write_mode: Literal["append", "overwrite", "overwrite-partitions"] = "append",
         partition_cols: Optional[list[ColumnInputType]] = None,
         io_config: Optional[IOConfig] = None,
+        compression: Optional[str] = None,
     ) -> "DataFrame":
         """Writes the DataFrame as CSV files, returning a new DataFrame with paths to the files that were written.
 
@@ -763,6 +764,7 @@ class DataFrame:
             write_mode (str, optional): Operation mode of the write. `append` will add new data, `overwrite` will replace the contents of the root directory with new data. `overwrite-partitions` will replace only the contents in the partitions that are being written to. Defaults to "append".
             partition_cols (Optional[List[ColumnInputType]], optional): How to subpartition each partition further. Defaults to None.
             io_config (Optional[IOConfig], optional): configurations to use when interacting with remote storage.
+            compression (Optional[str], optional): compression codec for the written files, one of "gzip", "zstd", "snappy", "lz4", "bzip2", "xz", "lzma", "deflate" or "zlib". The codec's extension is appended to the file names, e.g. `.csv.gz`, so that reads infer it. Defaults to None (uncompressed).
 
         Returns:
             DataFrame: The filenames that were written out as strings.
@@ -770,6 +772,8 @@ class DataFrame:
         Note:
             This call is **blocking** and will execute the DataFrame when called
 
+            Compressed CSV writes are currently only supported with the Native runner.
+
         """
         if write_mode not in ["append", "overwrite", "overwrite-partitions"]:
             raise ValueError(
@@ -790,6 +794,7 @@ class DataFrame:
             write_mode=WriteMode.from_str(write_mode),
             file_format=FileFormat.Csv,
             io_config=io_config,
+            compression=compression,
         )
 
         # Block and write, then retrieve data
@@ -822,6 +827,7 @@ class DataFrame:
         write_mode: Literal["append", "overwrite", "overwrite-partitions"] = "append",
         partition_cols: Optional[list[ColumnInputType]] = None,
         io_config: Optional[IOConfig] = None,
+        compression: Optional[str] = None,
     ) -> "DataFrame":
         """Writes the DataFrame as JSON files, returning a new DataFrame with paths to the files that were written.
 
@@ -832,6 +838,7 @@ class DataFrame:
             write_mode (str, optional): Operation mode of the write. `append` will add new data, `overwrite` will replace the contents of the root directory with new data. `overwrite-partitions` will replace only the contents in the partitions that are being written to. Defaults to "append".
             partition_cols (Optional[List[ColumnInputType]], optional): How to subpartition each partition further. Defaults to None.
             io_config (Optional[IOConfig], optional): configurations to use when interacting with remote storage.
+            compression (Optional[str], optional): compression codec for the written files, one of "gzip", "zstd", "snappy", "lz4", "bzip2", "xz", "lzma", "deflate" or "zlib". The codec's extension is appended to the file names, e.g. `.json.gz`, so that reads infer it. Defaults to None (uncompressed).
 
         Returns:
             DataFrame: The filenames that were written out as strings.
@@ -860,6 +867,7 @@ class DataFrame:
             write_mode=WriteMode.from_str(write_mode),
             file_format=FileFormat.Json,
             io_config=io_config,
+            compression=compression,
         )
         # Block and write, then retrieve data
         write_df = DataFrame(builder)
@@ -2612,8 +2620,11 @@ class DataFrame:
         self,
         to_agg: Iterable[Expression],
         group_by: Optional[ExpressionsProjection] = None,
+        grouping_sets: Optional[list[list[int]]] = None,
     ) -> "DataFrame":
-        builder = self._builder.agg(list(to_agg), list(group_by) if group_by is not None else None)
+        builder = self._builder.agg(
+            list(to_agg), list(group_by) if group_by is not None else None, grouping_sets=grouping_sets
+        )
         return DataFrame(builder)
 
     def _map_agg_string_to_expr(self, expr: Expression, op: str) -> Expression:
@@ -2645,6 +2656,7 @@ class DataFrame:
         fn: Callable[[Expression], Expression],
         cols: tuple[ManyColumnsInputType, ...],
         group_by: Optional[ExpressionsProjection] = None,
+        grouping_sets: Optional[list[list[int]]] = None,
     ) -> "DataFrame":
         if len(cols) == 0:
             warnings.warn("No columns specified; performing aggregation on all columns.")
@@ -2652,7 +2664,7 @@ class DataFrame:
             groupby_name_set = set() if group_by is None else group_by.to_name_set()
             cols = tuple(c for c in self.column_names if c not in groupby_name_set)
         exprs = self._wildcard_inputs_to_expressions(cols)
-        return self._agg([fn(c) for c in exprs], group_by)
+        return self._agg([fn(c) for c in exprs], group_by, grouping_sets)
 
     def _map_groups(self, udf: Expression, group_by: Optional[ExpressionsProjection] = None) -> "DataFrame":
         builder = self._builder.map_groups(udf, list(group_by) if group_by is not None else None)
@@ -2935,6 +2947,63 @@ class DataFrame:
         """
         return GroupedDataFrame(self, ExpressionsProjection(self._wildcard_inputs_to_expressions(group_by)))
 
+    @DataframePublicAPI
+    def rollup(self, *group_by: ManyColumnsInputType) -> "GroupedDataFrame":
+        """Performs a GroupBy over each prefix of the given columns for aggregation, like SQL's ``GROUP BY ROLLUP``.
+
+        ``df.rollup("a", "b")`` aggregates over the grouping sets ``(a, b)``, ``(a)`` and ``()`` in a single pass.
+        Columns that are not part of a row's grouping set are null, which can be told apart from null values with
+        :func:`daft.functions.grouping`.
+
+        Args:
+            *group_by (Union[str, Expression]): columns to roll up, from the most to the least significant
+
+        Returns:
+            GroupedDataFrame: DataFrame to Aggregate
+
+        Examples:
+            >>> import daft
+            >>> from daft import col
+            >>> df = daft.from_pydict({"pet": ["cat", "dog", "dog", "cat"], "age": [1, 2, 3, 4]})
+            >>> df.rollup("pet").agg(col("age").sum()).sort("pet").to_pydict()
+            {'pet': ['cat', 'dog', None], 'age': [5, 5, 10]}
+
+        """
+        group_by_exprs = ExpressionsProjection(self._wildcard_inputs_to_expressions(group_by))
+        grouping_sets = [list(range(i)) for i in range(len(group_by_exprs), -1, -1)]
+        return GroupedDataFrame(self, group_by_exprs, grouping_sets)
+
+    @DataframePublicAPI
+    def cube(self, *group_by: ManyColumnsInputType) -> "GroupedDataFrame":
+        """Performs a GroupBy over every subset of the given columns for aggregation, like SQL's ``GROUP BY CUBE``.
+
+        ``df.cube("a", "b")`` aggregates over the grouping sets ``(a, b)``, ``(a)``, ``(b)`` and ``()`` in a single
+        pass. Columns that are not part of a row's grouping set are null, which can be told apart from null values with
+        :func:`daft.functions.grouping`.
+
+        Args:
+            *group_by (Union[str, Expression]): columns to group by
+
+        Returns:
+            GroupedDataFrame: DataFrame to Aggregate
+
+        Examples:
+            >>> import daft
+            >>> from daft import col
+            >>> from daft.functions import grouping_id
+            >>> df = daft.from_pydict(
+            ...     {"pet": ["cat", "dog", "dog", "cat"], "sex": ["F", "M", "F", "F"], "age": [1, 2, 3, 4]}
+            ... )
+            >>> df = df.cube("pet", "sex").agg(col("age").sum(), grouping_id().alias("gid"))
+            >>> df.sort(["gid", "pet", "sex"]).to_pydict()
+            {'pet': ['cat', 'dog', 'dog', 'cat', 'dog', None, None, None], 'sex': ['F', 'F', 'M', None, None, 'F', 'M', None], 'age': [5, 3, 2, 5, 5, 8, 2, 10], 'gid': [0, 0, 0, 1, 1, 2, 2, 3]}
+
+        """
+        group_by_exprs = ExpressionsProjection(self._wildcard_inputs_to_expressions(group_by))
+        n = len(group_by_exprs)
+        grouping_sets = [[i for i in range(n) if mask & (1 << (n - 1 - i))] for mask in range((1 << n) - 1, -1, -1)]
+        return GroupedDataFrame(self, group_by_exprs, grouping_sets)
+
     @DataframePublicAPI
     def pivot(
         self,
@@ -3765,6 +3834,8 @@ class DataFrame:
 class GroupedDataFrame:
     df: DataFrame
     group_by: ExpressionsProjection
+    # Indices into `group_by` of each grouping set to aggregate over, or None for a regular groupby.
+    grouping_sets: Optional[list[list[int]]] = None
 
     def __post_init__(self) -> None:
         resolved_groupby_schema = self.group_by.resolve_schema(self.df._builder.schema())
@@ -3796,7 +3867,7 @@ class GroupedDataFrame:
         Returns:
             DataFrame: DataFrame with grouped sums.
         """
-        return self.df._apply_agg_fn(Expression.sum, cols, self.group_by)
+        return self.df._apply_agg_fn(Expression.sum, cols, self.group_by, self.grouping_sets)
 
     def mean(self, *cols: ColumnInputType) -> DataFrame:
         """Performs grouped mean on this GroupedDataFrame.
@@ -3807,7 +3878,7 @@ class GroupedDataFrame:
         Returns:
             DataFrame: DataFrame with grouped mean.
         """
-        return self.df._apply_agg_fn(Expression.mean, cols, self.group_by)
+        return self.df._apply_agg_fn(Expression.mean, cols, self.group_by, self.grouping_sets)
 
     def stddev(self, *cols: ColumnInputType) -> DataFrame:
         """Performs grouped standard deviation on this GroupedDataFrame.
@@ -3837,7 +3908,7 @@ class GroupedDataFrame:
             (Showing first 2 of 2 rows)
 
         """
-        return self.df._apply_agg_fn(Expression.stddev, cols, self.group_by)
+        return self.df._apply_agg_fn(Expression.stddev, cols, self.group_by, self.grouping_sets)
 
     def min(self, *cols: ColumnInputType) -> DataFrame:
         """Perform grouped min on this GroupedDataFrame.
@@ -3848,7 +3919,7 @@ class GroupedDataFrame:
         Returns:
             DataFrame: DataFrame with grouped min.
         """
-        return self.df._apply_agg_fn(Expression.min, cols, self.group_by)
+        return self.df._apply_agg_fn(Expression.min, cols, self.group_by, self.grouping_sets)
 
     def max(self, *cols: ColumnInputType) -> DataFrame:
         """Performs grouped max on this GroupedDataFrame.
@@ -3859,7 +3930,7 @@ class GroupedDataFrame:
         Returns:
             DataFrame: DataFrame with grouped max.
         """
-        return self.df._apply_agg_fn(Expression.max, cols, self.group_by)
+        return self.df._apply_agg_fn(Expression.max, cols, self.group_by, self.grouping_sets)
 
     def any_value(self, *cols: ColumnInputType) -> DataFrame:
         """Returns an arbitrary value on this GroupedDataFrame.
@@ -3872,7 +3943,7 @@ class GroupedDataFrame:
         Returns:
             DataFrame: DataFrame with any values.
         """
-        return self.df._apply_agg_fn(Expression.any_value, cols, self.group_by)
+        return self.df._apply_agg_fn(Expression.any_value, cols, self.group_by, self.grouping_sets)
 
     def count(self, *cols: ColumnInputType) -> DataFrame:
         """Performs grouped count on this GroupedDataFrame.
@@ -3880,7 +3951,7 @@ class GroupedDataFrame:
         Returns:
             DataFrame: DataFrame with grouped count per column.
         """
-        return self.df._apply_agg_fn(Expression.count, cols, self.group_by)
+        return self.df._apply_agg_fn(Expression.count, cols, self.group_by, self.grouping_sets)
 
     def skew(self, *cols: ColumnInputType) -> DataFrame:
         """Performs grouped skew on this GroupedDataFrame.
@@ -3888,7 +3959,7 @@ class GroupedDataFrame:
         Returns:
             DataFrame: DataFrame with the grouped skew per column.
         """
-        return self.df._apply_agg_fn(Expression.skew, cols, self.group_by)
+        return self.df._apply_agg_fn(Expression.skew, cols, self.group_by, self.grouping_sets)
 
     def agg_list(self, *cols: ColumnInputType) -> DataFrame:
         """Performs grouped list on this GroupedDataFrame.
@@ -3896,7 +3967,7 @@ class GroupedDataFrame:
         Returns:
             DataFrame: DataFrame with grouped list per column.
         """
-        return self.df._apply_agg_fn(Expression.agg_list, cols, self.group_by)
+        return self.df._apply_agg_fn(Expression.agg_list, cols, self.group_by, self.grouping_sets)
 
     def agg_set(self, *cols: ColumnInputType) -> DataFrame:
         """Performs grouped set on this GroupedDataFrame (ignoring nulls).
@@ -3907,7 +3978,7 @@ class GroupedDataFrame:
         Returns:
             DataFrame: DataFrame with grouped set per column.
         """
-        return self.df._apply_agg_fn(Expression.agg_set, cols, self.group_by)
+        return self.df._apply_agg_fn(Expression.agg_set, cols, self.group_by, self.grouping_sets)
 
     def agg_concat(self, *cols: ColumnInputType) -> DataFrame:
         """Performs grouped concat on this GroupedDataFrame.
@@ -3915,7 +3986,7 @@ class GroupedDataFrame:
         Returns:
             DataFrame: DataFrame with grouped concatenated list per column.
         """
-        return self.df._apply_agg_fn(Expression.agg_concat, cols, self.group_by)
+        return self.df._apply_agg_fn(Expression.agg_concat, cols, self.group_by, self.grouping_sets)
 
     def agg(self, *to_agg: Union[Expression, Iterable[Expression]]) -> DataFrame:
         """Perform aggregations on this GroupedDataFrame. Allows for mixed aggregations.
@@ -3967,7 +4038,7 @@ class GroupedDataFrame:
             if not isinstance(expr, Expression):
                 raise ValueError(f"GroupedDataFrame.agg() only accepts expression type, received: {type(expr)}")
 
-        return self.df._agg(to_agg_list, group_by=self.group_by)
+        return self.df._agg(to_agg_list, group_by=self.group_by, grouping_sets=self.grouping_sets)
 
     def map_groups(self, udf: Expression) -> DataFrame:
         """Apply a user-defined function to each group. The name of the resultant column will default to the name of the first input column.
@@ -4003,4 +4074,6 @@ class GroupedDataFrame:
             (Showing first 2 of 2 rows)
 
         """
+        if self.grouping_sets is not None:
+            raise ValueError("map_groups is not supported with grouping sets, rollup or cube")
         return self.df._map_groups(udf, group_by=self.group_by)
@@ -565,6 +565,11 @@ class DataType:
     def to_arrow_dtype(self) -> pa.DataType:
         return self._dtype.to_arrow()
 
+    @classmethod
+    def geometry(cls) -> DataType:
+        """Create a Geometry DataType: geometries (points, lines, polygons, ...) stored as Well-Known Binary (WKB)."""
+        return cls._from_pydatatype(PyDataType.geometry())
+
     @classmethod
     def python(cls) -> DataType:
         """Create a Python DataType: a type which refers to an arbitrary Python object."""
@@ -901,6 +906,16 @@ class DataType:
         """
         return self._dtype.is_fixed_shape_sparse_tensor()
 
+    def is_geometry(self) -> builtins.bool:
+        """Check if this is a geometry type.
+
+        Examples:
+            >>> import daft
+            >>> dtype = daft.DataType.geometry()
+            >>> assert dtype.is_geometry()
+        """
+        return self._dtype.is_geometry()
+
     def is_python(self) -> builtins.bool:
         """Check if this is a python object type.
 
@@ -48,6 +48,7 @@ if TYPE_CHECKING:
     from daft.window import Window
 
     EncodingCodec = Literal["deflate", "gzip", "gz", "utf-8", "utf8" "zlib"]
+    SerializationFormat = Literal["json", "msgpack", "cbor", "bson", "avro", "protobuf"]
 
 
 def lit(value: object) -> Expression:
@@ -364,6 +365,15 @@ class Expression:
         """
         return ExpressionBinaryNamespace.from_expression(self)
 
+    @property
+    def geo(self) -> ExpressionGeoNamespace:
+        """Access geometry operations for this expression.
+
+        Returns:
+            ExpressionGeoNamespace: A namespace containing geometry operations
+        """
+        return ExpressionGeoNamespace.from_expression(self)
+
     @staticmethod
     def _from_pyexpr(pyexpr: _PyExpr) -> Expression:
         expr = Expression.__new__(Expression)
@@ -1136,6 +1146,44 @@ class Expression:
         expr = self._expr.max()
         return Expression._from_pyexpr(expr)
 
+    def min_by(self, by: Expression) -> Expression:
+        """Returns the value of the expression at the minimum non-null value of ``by``.
+
+        Ties are broken by the first row, and nested ``by`` values are compared lexicographically.
+
+        Args:
+            by: the expression to minimize.
+
+        Examples:
+            >>> import daft
+            >>> df = daft.from_pydict({"name": ["a", "b", "c"], "score": [2, 1, 3]})
+            >>> df.agg(df["name"].min_by(df["score"])).show()
+            ╭──────╮
+            │ name │
+            │ ---  │
+            │ Utf8 │
+            ╞══════╡
+            │ b    │
+            ╰──────╯
+            <BLANKLINE>
+            (Showing first 1 of 1 rows)
+        """
+        by = Expression._to_expression(by)
+        expr = self._expr.min_by(by._expr)
+        return Expression._from_pyexpr(expr)
+
+    def max_by(self, by: Expression) -> Expression:
+        """Returns the value of the expression at the maximum non-null value of ``by``.
+
+        Ties are broken by the first row, and nested ``by`` values are compared lexicographically.
+
+        Args:
+            by: the expression to maximize.
+        """
+        by = Expression._to_expression(by)
+        expr = self._expr.max_by(by._expr)
+        return Expression._from_pyexpr(expr)
+
     def bool_and(self) -> Expression:
         """Calculates the boolean AND of all values in a list.
 
@@ -1645,29 +1693,73 @@ class Expression:
         """Decodes or returns null, see `Expression.decode`."""
         return self._eval_expressions("try_decode", codec=codec)
 
-    def deserialize(self, format: Literal["json"], dtype: DataTypeLike) -> Expression:
-        """Deserializes the expression (string) using the specified format and data type.
+    def deserialize(
+        self,
+        format: SerializationFormat,
+        dtype: DataTypeLike,
+        *,
+        schema: builtins.str | None = None,
+        descriptor_set: builtins.bytes | None = None,
+        message: builtins.str | None = None,
+    ) -> Expression:
+        """Deserializes the expression using the specified format and data type.
+
+        The json format reads strings, and the msgpack, cbor, bson, avro and protobuf formats read binary.
 
         Args:
-            format (Literal["json"]): The serialization format.
+            format (SerializationFormat): The serialization format.
             dtype: The target data type to deserialize into.
+            schema (str, optional): The avro writer schema as JSON, which defaults to a schema derived from `dtype`.
+                Avro values use the single-object encoding, so their schema fingerprint must match.
+            descriptor_set (bytes, optional): The serialized protobuf `FileDescriptorSet` which describes the message,
+                e.g. from `protoc --descriptor_set_out`. Required for protobuf.
+            message (str, optional): The fully qualified name of the protobuf message. Required for protobuf.
 
         Returns:
             Expression: A new expression with the deserialized value.
+
+        Examples:
+            >>> import daft
+            >>> from daft import col
+            >>> df = daft.from_pydict({"data": [b"\x82\xa1a\x01\xa1b\xa2hi"]})
+            >>> df = df.select(col("data").deserialize("msgpack", "STRUCT<a INT64, b STRING>"))
+            >>> df.to_pydict()
+            {'data': [{'a': 1, 'b': 'hi'}]}
+
         """
         if isinstance(dtype, str):
             dtype = DataType._from_pydatatype(sql_datatype(dtype))
         else:
             assert isinstance(dtype, (DataType, type))
             dtype = DataType._infer_type(dtype)
-        return self._eval_expressions("deserialize", format, dtype._dtype)
+        return self._eval_expressions(
+            "deserialize",
+            format,
+            dtype._dtype,
+            schema=schema,
+            descriptor_set=descriptor_set,
+            message=message,
+        )
+
+    def try_deserialize(
+        self,
+        format: SerializationFormat,
+        dtype: DataTypeLike,
+        *,
+        schema: builtins.str | None = None,
+        descriptor_set: builtins.bytes | None = None,
+        message: builtins.str | None = None,
+    ) -> Expression:
+        """Deserializes the expression using the specified format and data type, inserting nulls on failures.
 
-    def try_deserialize(self, format: Literal["json"], dtype: DataTypeLike) -> Expression:
-        """Deserializes the expression (string) using the specified format and data type, inserting nulls on failures.
+        See `Expression.deserialize` for the formats and their options.
 
         Args:
-            format (Literal["json"]): The serialization format.
+            format (SerializationFormat): The serialization format.
             dtype: The target data type to deserialize into.
+            schema (str, optional): The avro writer schema as JSON.
+            descriptor_set (bytes, optional): The serialized protobuf `FileDescriptorSet`.
+            message (str, optional): The fully qualified name of the protobuf message.
 
         Returns:
             Expression: A new expression with the deserialized value (or null).
@@ -1677,18 +1769,46 @@ class Expression:
         else:
             assert isinstance(dtype, (DataType, type))
             dtype = DataType._infer_type(dtype)
-        return self._eval_expressions("try_deserialize", format, dtype._dtype)
+        return self._eval_expressions(
+            "try_deserialize",
+            format,
+            dtype._dtype,
+            schema=schema,
+            descriptor_set=descriptor_set,
+            message=message,
+        )
+
+    def serialize(
+        self,
+        format: SerializationFormat,
+        *,
+        schema: builtins.str | None = None,
+        descriptor_set: builtins.bytes | None = None,
+        message: builtins.str | None = None,
+    ) -> Expression:
+        """Serializes the expression using the specified format.
 
-    def serialize(self, format: Literal["json"]) -> Expression:
-        """Serializes the expression as a string using the specified format.
+        The json format writes strings, and the msgpack, cbor, bson, avro and protobuf formats write binary.
+        Only structs and maps can be serialized as bson documents or protobuf messages.
 
         Args:
-            format (Literal["json"]): The serialization format.
+            format (SerializationFormat): The serialization format.
+            schema (str, optional): The avro schema as JSON, which defaults to a schema derived from the data type.
+                Avro values use the single-object encoding, which starts with the fingerprint of the schema.
+            descriptor_set (bytes, optional): The serialized protobuf `FileDescriptorSet` which describes the message,
+                e.g. from `protoc --descriptor_set_out`. Required for protobuf.
+            message (str, optional): The fully qualified name of the protobuf message. Required for protobuf.
 
         Returns:
-            Expression: A new expression with the serialized string.
+            Expression: A new expression with the serialized string or binary.
         """
-        return self._eval_expressions("serialize", format)
+        return self._eval_expressions(
+            "serialize",
+            format,
+            schema=schema,
+            descriptor_set=descriptor_set,
+            message=message,
+        )
 
     def jq(self, filter: builtins.str) -> Expression:
         """Applies a [jq](https://jqlang.github.io/jq/manual/) filter to the expression (string), returning the results as a string.
@@ -1725,6 +1845,40 @@ class Expression:
         """
         return self._eval_expressions("jq", filter)
 
+    def json_extract(self, path: builtins.str, dtype: DataTypeLike | None = None) -> Expression:
+        """Extracts the values selected by a [JSONPath](https://www.rfc-editor.org/rfc/rfc9535) from the expression (string).
+
+        Each document is parsed once and the selected values are read directly into the ``dtype``. Values that
+        don't match the ``dtype`` are null, so documents can have different shapes. Paths with only names and
+        indices select a single value, and paths with wildcards, slices, unions or descendants select a list.
+
+        Args:
+            path (str): The JSONPath, e.g. ``$.a.b[0]``, ``$.items[*].id`` or ``$..name``. Filter expressions aren't
+                supported.
+            dtype (optional): The data type to extract the values as, which can be inferred from a sample with
+                [`infer_json_dtype`][daft.functions.infer_json_dtype]. Defaults to the values as JSON strings.
+
+        Returns:
+            Expression: Expression with the extracted values.
+
+        Examples:
+            >>> import daft
+            >>> from daft import col
+            >>> df = daft.from_pydict({"json": ['{"a": {"b": 1, "c": [1, 2]}}', '{"a": {"b": 2}}', '{"a": 3}']})
+            >>> df = df.select(col("json").json_extract("$.a", "STRUCT<b INT64, c LIST<INT64>>"))
+            >>> df.to_pydict()
+            {'json': [{'b': 1, 'c': [1, 2]}, {'b': 2, 'c': None}, None]}
+
+        """
+        if dtype is None:
+            return self._eval_expressions("json_extract", path)
+        if isinstance(dtype, str):
+            dtype = DataType._from_pydatatype(sql_datatype(dtype))
+        else:
+            assert isinstance(dtype, (DataType, type))
+            dtype = DataType._infer_type(dtype)
+        return self._eval_expressions("json_extract", path, dtype=dtype._dtype)
+
     def name(self) -> builtins.str:
         return self._expr.name()
 
@@ -2064,6 +2218,11 @@ class ExpressionUrlNamespace(ExpressionNamespace):
         max_connections: int = 32,
         on_error: Literal["raise", "null"] = "raise",
         io_config: IOConfig | None = None,
+        *,
+        max_connections_per_host: int | None = None,
+        host_delay: float | None = None,
+        max_bytes: int | None = None,
+        return_metadata: bool = False,
     ) -> Expression:
         """Treats each string as a URL, and downloads the bytes contents as a bytes column.
 
@@ -2073,6 +2232,14 @@ class ExpressionUrlNamespace(ExpressionNamespace):
                 the error but fallback to a Null value. Defaults to "raise".
             io_config: IOConfig to use when accessing remote storage. Note that the S3Config's `max_connections` parameter will be overridden
                 with `max_connections` that is passed in as a kwarg.
+            max_connections_per_host: The maximum number of concurrent requests to each HTTP(S) host. Defaults to no limit.
+            host_delay: The minimum number of seconds between the start of consecutive requests to the same HTTP(S) host.
+                Defaults to no delay.
+            max_bytes: The maximum number of bytes to download from each HTTP(S) URL. Larger responses are treated as a
+                download error. Defaults to no limit.
+            return_metadata: Whether to return a struct of the ``content``, the HTTP ``status``, the response ``headers``
+                and the final ``url`` after redirects, rather than only the contents. Error statuses then give a null
+                ``content`` instead of an error. Defaults to False.
 
         Returns:
             Expression: a Binary expression which is the bytes contents of the URL, or None if an error occurred during download
@@ -2085,6 +2252,9 @@ class ExpressionUrlNamespace(ExpressionNamespace):
             Alternatively, if you are running on machines with lower number of cores but very high network bandwidth, you can increase
             ``max_connections`` to get higher throughput with additional parallelism
 
+            HTTP(S) downloads honor the ``Retry-After`` of 429 and 503 responses by holding off every request to that
+            host, and resume interrupted responses with range requests when the server supports them.
+
         """
         multi_thread = ExpressionUrlNamespace._should_use_multithreading_tokio_runtime()
         io_config = ExpressionUrlNamespace._override_io_config_max_connections(max_connections, io_config)
@@ -2116,6 +2286,16 @@ class ExpressionUrlNamespace(ExpressionNamespace):
                 on_error=on_error_expr,
                 max_connections=max_connections_expr,
                 io_config=io_config_expr,
+                **{
+                    name: Expression._to_expression(value)._expr
+                    for name, value in [
+                        ("max_connections_per_host", max_connections_per_host),
+                        ("host_delay", None if host_delay is None else float(host_delay)),
+                        ("max_bytes", max_bytes),
+                        ("return_metadata", return_metadata or None),
+                    ]
+                    if value is not None
+                },
             )
         )
 
@@ -4428,6 +4608,73 @@ class ExpressionStringNamespace(ExpressionNamespace):
             )
         )
 
+    def chunk_text(
+        self,
+        chunk_size: int,
+        *,
+        overlap: int = 0,
+        strategy: Literal["characters", "tokens", "sentences", "recursive"] = "characters",
+        tokens_path: str | None = None,
+        io_config: IOConfig | None = None,
+        pattern: str | None = None,
+    ) -> Expression:
+        """Splits each string into overlapping chunks, such as for embedding documents.
+
+        Strategies:
+            - "characters": Windows of `chunk_size` characters.
+            - "tokens": Windows of `chunk_size` tokens, using a tiktoken tokenizer.
+            - "sentences": Runs of whole sentences with at most `chunk_size` characters.
+            - "recursive": Splits on paragraphs, then lines, sentences and words until pieces fit in `chunk_size`
+              characters, then merges adjacent pieces while they fit.
+
+        Args:
+            chunk_size: The maximum size of each chunk, in tokens for the "tokens" strategy and characters otherwise.
+            overlap: How much consecutive chunks overlap, in the same unit as `chunk_size`. The "sentences" and
+                "recursive" strategies only overlap by whole pieces that fit. Defaults to 0.
+            strategy: How to split strings into chunks. Defaults to "characters".
+            tokens_path (optional): The name of a built-in tokenizer or the path to a tiktoken token file for the
+                "tokens" strategy. Defaults to `cl100k_base`.
+            io_config (optional): IOConfig to use when accessing remote storage.
+            pattern (optional): Regex pattern to use to split strings in tokenization step. Necessary if loading from a file.
+
+        Returns:
+            Expression: A List[Struct] expression of the chunks of each string, with the chunk `text` and its
+                `start` and `end` character offsets in the original string.
+
+        Note:
+            Use `explode` to get one row per chunk. With the "sentences" and "recursive" strategies, pieces that are
+            longer than `chunk_size` on their own become a single chunk, and chunks have surrounding whitespace stripped.
+
+        Examples:
+            >>> import daft
+            >>> df = daft.from_pydict({"x": ["abcdefghij"]})
+            >>> df = df.select(df["x"].str.chunk_text(4, overlap=1).alias("chunks")).explode("chunks")
+            >>> df.select(df["chunks"].struct.get("text"), df["chunks"].struct.get("start")).show()
+            ╭──────┬────────╮
+            │ text ┆ start  │
+            │ ---  ┆ ---    │
+            │ Utf8 ┆ UInt64 │
+            ╞══════╪════════╡
+            │ abcd ┆ 0      │
+            ├╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌┤
+            │ defg ┆ 3      │
+            ├╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌┤
+            │ ghij ┆ 6      │
+            ╰──────┴────────╯
+            <BLANKLINE>
+            (Showing first 3 of 3 rows)
+
+        """
+        return self._eval_expressions(
+            "chunk_text",
+            chunk_size=chunk_size,
+            overlap=overlap,
+            strategy=strategy,
+            tokens_path=tokens_path,
+            io_config=io_config,
+            pattern=pattern,
+        )
+
     def tokenize_encode(
         self,
         tokens_path: str,
@@ -4436,23 +4683,36 @@ class ExpressionStringNamespace(ExpressionNamespace):
         pattern: str | None = None,
         special_tokens: str | None = None,
         use_special_tokens: bool | None = None,
+        max_length: int | None = None,
+        padding: Literal["longest", "max_length"] | None = None,
+        pad_token_id: int | None = None,
+        return_attention_mask: bool = False,
     ) -> Expression:
         """Encodes each string as a list of integer tokens using a tokenizer.
 
         Uses https://github.com/openai/tiktoken for tokenization.
 
         Supported built-in tokenizers: `cl100k_base`, `o200k_base`, `p50k_base`, `p50k_edit`, `r50k_base`. Also supports
-        loading tokens from a file in tiktoken format.
+        loading tokens from a file in tiktoken format, or from a HuggingFace `tokenizer.json` file (any path ending in
+        `.json`). HuggingFace tokenizers apply the normalizer, pre-tokenizer, model and post-processor configured in the
+        file, including its truncation and padding settings.
 
         Args:
             tokens_path: The name of a built-in tokenizer, or the path to a token file (supports downloading).
             io_config (optional): IOConfig to use when accessing remote storage.
-            pattern (optional): Regex pattern to use to split strings in tokenization step. Necessary if loading from a file.
-            special_tokens (optional): Name of the set of special tokens to use. Currently only "llama3" supported. Necessary if loading from a file.
+            pattern (optional): Regex pattern to use to split strings in tokenization step. Necessary if loading from a tiktoken file.
+            special_tokens (optional): Name of the set of special tokens to use. Currently only "llama3" supported. Necessary if loading from a tiktoken file.
             use_special_tokens (optional): Whether or not to parse special tokens included in input. Disabled by default. Automatically enabled if `special_tokens` is provided.
+                For HuggingFace tokenizers, whether to add special tokens such as `[CLS]` and `[SEP]`, enabled by default.
+            max_length (optional): Truncates encodings to at most this many tokens, including special tokens.
+            padding (optional): Pads encodings to the longest encoding in each batch with "longest", or to `max_length` with "max_length".
+                Defaults to the padding configured in a HuggingFace tokenizer file.
+            pad_token_id (optional): The token to pad with. Required to pad tiktoken encodings, and defaults to the pad token configured in a HuggingFace tokenizer file.
+            return_attention_mask (optional): Whether to return a struct of `input_ids` and an `attention_mask` list with 1 for each token and 0 for each padding token.
 
         Returns:
-            Expression: An expression with the encodings of the strings as lists of unsigned 32-bit integers.
+            Expression: An expression with the encodings of the strings as lists of unsigned 32-bit integers,
+                or a struct of `input_ids` and `attention_mask` lists if `return_attention_mask` is set.
 
         Note:
             If using this expression with Llama 3 tokens, note that Llama 3 does some extra preprocessing on
@@ -4466,6 +4726,10 @@ class ExpressionStringNamespace(ExpressionNamespace):
             io_config=io_config,
             pattern=pattern,
             special_tokens=special_tokens,
+            max_length=max_length,
+            padding=padding,
+            pad_token_id=pad_token_id,
+            return_attention_mask=return_attention_mask,
         )
 
     def tokenize_decode(
@@ -4475,19 +4739,21 @@ class ExpressionStringNamespace(ExpressionNamespace):
         io_config: IOConfig | None = None,
         pattern: str | None = None,
         special_tokens: str | None = None,
+        skip_special_tokens: bool = False,
     ) -> Expression:
         """Decodes each list of integer tokens into a string using a tokenizer.
 
         Uses [https://github.com/openai/tiktoken](https://github.com/openai/tiktoken) for tokenization.
 
         Supported built-in tokenizers: `cl100k_base`, `o200k_base`, `p50k_base`, `p50k_edit`, `r50k_base`. Also supports
-        loading tokens from a file in tiktoken format.
+        loading tokens from a file in tiktoken format, or from a HuggingFace `tokenizer.json` file (any path ending in `.json`).
 
         Args:
             tokens_path: The name of a built-in tokenizer, or the path to a token file (supports downloading).
             io_config (optional): IOConfig to use when accessing remote storage.
-            pattern (optional): Regex pattern to use to split strings in tokenization step. Necessary if loading from a file.
-            special_tokens (optional): Name of the set of special tokens to use. Currently only "llama3" supported. Necessary if loading from a file.
+            pattern (optional): Regex pattern to use to split strings in tokenization step. Necessary if loading from a tiktoken file.
+            special_tokens (optional): Name of the set of special tokens to use. Currently only "llama3" supported. Necessary if loading from a tiktoken file.
+            skip_special_tokens (optional): Whether to leave special tokens out of the decoded strings. Disabled by default.
 
         Returns:
             Expression: An expression with decoded strings.
@@ -4498,6 +4764,7 @@ class ExpressionStringNamespace(ExpressionNamespace):
             io_config=io_config,
             pattern=pattern,
             special_tokens=special_tokens,
+            skip_special_tokens=skip_special_tokens,
         )
 
     def count_matches(
@@ -5076,12 +5343,14 @@ class ExpressionImageNamespace(ExpressionNamespace):
     ) -> Expression:
         """Decodes the binary data in this column into images.
 
-        This can only be applied to binary columns that contain encoded images (e.g. PNG, JPEG, etc.)
+        This can only be applied to binary columns that contain encoded images (e.g. PNG, JPEG, WebP, etc.).
+        The format of each image is detected from its magic bytes. AVIF images are detected but can't be decoded.
 
         Args:
             on_error: Whether to raise when encountering an error, or log a warning and return a null
             mode: What mode to convert the images into before storing it in the column. This may prevent
-                errors relating to unsupported types.
+                errors relating to unsupported types. Without a mode, 16-bit and HDR images are converted to
+                8-bit images with the same channels; pass a 16-bit mode such as ``"RGB16"`` to keep their precision.
 
         Returns:
             Expression: An Image expression represnting an image column.
@@ -5096,7 +5365,8 @@ class ExpressionImageNamespace(ExpressionNamespace):
         """Encode an image column as the provided image file format, returning a binary column of encoded bytes.
 
         Args:
-            image_format: The image file format into which the images will be encoded.
+            image_format: The image file format into which the images will be encoded. WebP images are
+                encoded losslessly and only support 8-bit modes.
 
         Returns:
             Expression: A Binary expression representing a binary column of encoded image bytes.
@@ -5154,6 +5424,172 @@ class ExpressionImageNamespace(ExpressionNamespace):
         f = native.get_function_from_registry("to_mode")
         return Expression._from_pyexpr(f(self._expr, mode=image_mode))
 
+    def rotate(self, degrees: int) -> Expression:
+        """Rotates images clockwise by a multiple of 90 degrees.
+
+        Args:
+            degrees: Clockwise rotation in degrees, which must be a multiple of 90. Negative values rotate counterclockwise.
+
+        Returns:
+            Expression: An Image expression representing the rotated images.
+        """
+        f = native.get_function_from_registry("image_rotate")
+        return Expression._from_pyexpr(f(self._expr, degrees=lit(degrees)._expr))
+
+    def flip_horizontal(self) -> Expression:
+        """Flips images horizontally, mirroring them from left to right.
+
+        Returns:
+            Expression: An Image expression representing the flipped images.
+        """
+        f = native.get_function_from_registry("image_flip_horizontal")
+        return Expression._from_pyexpr(f(self._expr))
+
+    def flip_vertical(self) -> Expression:
+        """Flips images vertically, mirroring them from top to bottom.
+
+        Returns:
+            Expression: An Image expression representing the flipped images.
+        """
+        f = native.get_function_from_registry("image_flip_vertical")
+        return Expression._from_pyexpr(f(self._expr))
+
+    def pad_to_aspect(self, ratio: float, fill: int = 0) -> Expression:
+        """Pads images evenly on both sides so that their width / height matches the provided aspect ratio.
+
+        Args:
+            ratio: Desired width / height aspect ratio.
+            fill: Value between 0 and 255 that every channel of the padding is set to. Defaults to 0.
+
+        Returns:
+            Expression: An Image expression representing the padded images.
+        """
+        f = native.get_function_from_registry("image_pad_to_aspect")
+        return Expression._from_pyexpr(f(self._expr, ratio=lit(float(ratio))._expr, fill=lit(fill)._expr))
+
+    def center_crop(self, w: int, h: int) -> Expression:
+        """Crops the center of images to the provided width and height.
+
+        Images that are smaller than the crop along a dimension are left uncropped along that dimension.
+
+        Args:
+            w: Desired width of the cropped image.
+            h: Desired height of the cropped image.
+
+        Returns:
+            Expression: An Image expression representing the cropped images.
+        """
+        f = native.get_function_from_registry("image_center_crop")
+        return Expression._from_pyexpr(f(self._expr, w=lit(w)._expr, h=lit(h)._expr))
+
+    def gaussian_blur(self, sigma: float) -> Expression:
+        """Blurs images with a Gaussian kernel.
+
+        Args:
+            sigma: Standard deviation of the Gaussian kernel, in pixels.
+
+        Returns:
+            Expression: An Image expression representing the blurred images.
+        """
+        f = native.get_function_from_registry("image_gaussian_blur")
+        return Expression._from_pyexpr(f(self._expr, sigma=lit(float(sigma))._expr))
+
+    def adjust_brightness(self, factor: float) -> Expression:
+        """Adjusts the brightness of images by multiplying every color channel by ``factor``.
+
+        Args:
+            factor: Non-negative brightness factor. 0 gives a black image and 1 gives the original image.
+
+        Returns:
+            Expression: An Image expression representing the adjusted images.
+        """
+        f = native.get_function_from_registry("image_adjust_brightness")
+        return Expression._from_pyexpr(f(self._expr, factor=lit(float(factor))._expr))
+
+    def adjust_contrast(self, factor: float) -> Expression:
+        """Adjusts the contrast of images by blending every color channel with the mean gray level of the image.
+
+        Args:
+            factor: Non-negative contrast factor. 0 gives a solid gray image and 1 gives the original image.
+
+        Returns:
+            Expression: An Image expression representing the adjusted images.
+        """
+        f = native.get_function_from_registry("image_adjust_contrast")
+        return Expression._from_pyexpr(f(self._expr, factor=lit(float(factor))._expr))
+
+    def thumbnail(self, w: int, h: int) -> Expression:
+        """Downscales images to fit within the provided width and height, preserving their aspect ratio.
+
+        Images that already fit are left unchanged.
+
+        Args:
+            w: Maximum width of the thumbnail.
+            h: Maximum height of the thumbnail.
+
+        Returns:
+            Expression: An Image expression representing the thumbnails.
+        """
+        f = native.get_function_from_registry("image_thumbnail")
+        return Expression._from_pyexpr(f(self._expr, w=lit(w)._expr, h=lit(h)._expr))
+
+    def ahash(self) -> Expression:
+        """Computes the 64 bit average hash (aHash) of images.
+
+        The image is converted to grayscale and downscaled to 8x8, and each bit records whether a pixel is
+        brighter than the mean. Near-duplicate images have hashes with a small Hamming distance, which can be
+        computed with [`daft.functions.hamming_distance`][daft.functions.hamming_distance].
+
+        Returns:
+            Expression: A UInt64 expression of the hashes.
+        """
+        f = native.get_function_from_registry("image_ahash")
+        return Expression._from_pyexpr(f(self._expr))
+
+    def dhash(self) -> Expression:
+        """Computes the 64 bit difference hash (dHash) of images.
+
+        The image is converted to grayscale and downscaled to 9x8, and each bit records whether a pixel is
+        darker than its right neighbor. Near-duplicate images have hashes with a small Hamming distance, which
+        can be computed with [`daft.functions.hamming_distance`][daft.functions.hamming_distance].
+
+        Returns:
+            Expression: A UInt64 expression of the hashes.
+        """
+        f = native.get_function_from_registry("image_dhash")
+        return Expression._from_pyexpr(f(self._expr))
+
+    def phash(self) -> Expression:
+        """Computes the 64 bit DCT-based perceptual hash (pHash) of images.
+
+        The image is converted to grayscale and downscaled to 32x32, and each bit records whether one of the
+        8x8 lowest frequencies of its discrete cosine transform is above their median. Near-duplicate images
+        have hashes with a small Hamming distance, which can be computed with
+        [`daft.functions.hamming_distance`][daft.functions.hamming_distance].
+
+        Returns:
+            Expression: A UInt64 expression of the hashes.
+        """
+        f = native.get_function_from_registry("image_phash")
+        return Expression._from_pyexpr(f(self._expr))
+
+    def normalize(self, mean: float | list[float], std: float | list[float]) -> Expression:
+        """Converts images into float32 tensors of shape (height, width, channels), normalized per channel.
+
+        Every channel is scaled to [0, 1] and then normalized as ``(value - mean) / std``.
+
+        Args:
+            mean: Mean of every channel, or a single mean applied to all channels.
+            std: Standard deviation of every channel, or a single standard deviation applied to all channels.
+
+        Returns:
+            Expression: A Tensor expression of the normalized images, or a fixed shape Tensor expression for fixed shape images.
+        """
+        mean_expr = lit([float(m) for m in mean] if isinstance(mean, (list, tuple)) else float(mean))
+        std_expr = lit([float(s) for s in std] if isinstance(std, (list, tuple)) else float(std))
+        f = native.get_function_from_registry("image_normalize")
+        return Expression._from_pyexpr(f(self._expr, mean=mean_expr._expr, std=std_expr._expr))
+
 
 class ExpressionPartitioningNamespace(ExpressionNamespace):
     """The following methods are available under the `expr.partition` attribute."""
@@ -5296,6 +5732,85 @@ class ExpressionEmbeddingNamespace(ExpressionNamespace):
         """
         return self._eval_expressions("cosine_distance", other)
 
+    def dot_product(self, other: Expression) -> Expression:
+        """Compute the dot product between two embeddings.
+
+        Args:
+            other (Expression): The other embedding to compute the dot product with.
+
+        Returns:
+            Expression: a Float64 Expression with the dot product of the two embeddings.
+
+        Examples:
+            >>> import daft
+            >>> df = daft.from_pydict({"e1": [[1, 2, 3], [1, 2, 3]], "e2": [[1, 2, 3], [-1, 0, 1]]})
+            >>> dtype = daft.DataType.fixed_size_list(daft.DataType.float32(), 3)
+            >>> df.select(df["e1"].cast(dtype).embedding.dot_product(df["e2"].cast(dtype))).to_pydict()
+            {'e1': [14.0, 2.0]}
+
+        """
+        return self._eval_expressions("dot_product", other)
+
+    def euclidean_distance(self, other: Expression) -> Expression:
+        """Compute the Euclidean (L2) distance between two embeddings.
+
+        Args:
+            other (Expression): The other embedding to compute the Euclidean distance against.
+
+        Returns:
+            Expression: a Float64 Expression with the Euclidean distance between the two embeddings.
+
+        Examples:
+            >>> import daft
+            >>> df = daft.from_pydict({"e1": [[0, 0], [1, 1]], "e2": [[3, 4], [1, 1]]})
+            >>> dtype = daft.DataType.fixed_size_list(daft.DataType.float32(), 2)
+            >>> df.select(df["e1"].cast(dtype).embedding.euclidean_distance(df["e2"].cast(dtype))).to_pydict()
+            {'e1': [5.0, 0.0]}
+
+        """
+        return self._eval_expressions("euclidean_distance", other)
+
+    def manhattan_distance(self, other: Expression) -> Expression:
+        """Compute the Manhattan (L1) distance between two embeddings.
+
+        Args:
+            other (Expression): The other embedding to compute the Manhattan distance against.
+
+        Returns:
+            Expression: a Float64 Expression with the Manhattan distance between the two embeddings.
+
+        Examples:
+            >>> import daft
+            >>> df = daft.from_pydict({"e1": [[0, 0], [1, 1]], "e2": [[3, -4], [1, 1]]})
+            >>> dtype = daft.DataType.fixed_size_list(daft.DataType.float32(), 2)
+            >>> df.select(df["e1"].cast(dtype).embedding.manhattan_distance(df["e2"].cast(dtype))).to_pydict()
+            {'e1': [7.0, 0.0]}
+
+        """
+        return self._eval_expressions("manhattan_distance", other)
+
+    def hamming_distance(self, other: Expression) -> Expression:
+        """Compute the Hamming distance between two embeddings.
+
+        For integer embeddings, such as bit-packed binary embeddings, this is the number of differing bits.
+        For float embeddings, this is the number of differing elements.
+
+        Args:
+            other (Expression): The other embedding to compute the Hamming distance against.
+
+        Returns:
+            Expression: a UInt32 Expression with the Hamming distance between the two embeddings.
+
+        Examples:
+            >>> import daft
+            >>> df = daft.from_pydict({"e1": [[0b1011, 255], [1, 2]], "e2": [[0b0001, 255], [1, 2]]})
+            >>> dtype = daft.DataType.fixed_size_list(daft.DataType.uint8(), 2)
+            >>> df.select(df["e1"].cast(dtype).embedding.hamming_distance(df["e2"].cast(dtype))).to_pydict()
+            {'e1': [2, 0]}
+
+        """
+        return self._eval_expressions("hamming_distance", other)
+
 
 class ExpressionBinaryNamespace(ExpressionNamespace):
     """The following methods are available under the `expr.binary` attribute."""
@@ -5394,3 +5909,88 @@ class ExpressionBinaryNamespace(ExpressionNamespace):
 
         """
         return self._eval_expressions("binary_slice", start, length)
+
+
+class ExpressionGeoNamespace(ExpressionNamespace):
+    """The following methods are available under the `expr.geo` attribute."""
+
+    def from_wkt(self) -> Expression:
+        """Parses a Well-Known Text (WKT) string column into geometries.
+
+        Returns:
+            Expression: a Geometry expression
+        """
+        return self._eval_expressions("st_geomfromtext")
+
+    def from_wkb(self) -> Expression:
+        """Parses a Well-Known Binary (WKB) column into geometries, validating every value.
+
+        Returns:
+            Expression: a Geometry expression
+        """
+        return self._eval_expressions("st_geomfromwkb")
+
+    def from_geojson(self) -> Expression:
+        """Parses a GeoJSON string column into geometries.
+
+        Returns:
+            Expression: a Geometry expression
+        """
+        return self._eval_expressions("st_geomfromgeojson")
+
+    def to_wkt(self) -> Expression:
+        """Serializes a geometry column as Well-Known Text (WKT).
+
+        Returns:
+            Expression: a String expression
+        """
+        return self._eval_expressions("st_astext")
+
+    def to_wkb(self) -> Expression:
+        """Serializes a geometry column as Well-Known Binary (WKB).
+
+        Returns:
+            Expression: a Binary expression
+        """
+        return self._eval_expressions("st_aswkb")
+
+    def to_geojson(self) -> Expression:
+        """Serializes a geometry column as GeoJSON geometry objects.
+
+        Returns:
+            Expression: a String expression
+        """
+        return self._eval_expressions("st_asgeojson")
+
+    def contains(self, other: Expression) -> Expression:
+        """Returns whether each geometry completely contains the corresponding geometry in `other`.
+
+        Args:
+            other: The geometries to test for containment
+
+        Returns:
+            Expression: a Boolean expression
+        """
+        return self._eval_expressions("st_contains", other)
+
+    def intersects(self, other: Expression) -> Expression:
+        """Returns whether each geometry shares any point with the corresponding geometry in `other`.
+
+        Args:
+            other: The geometries to test for intersection
+
+        Returns:
+            Expression: a Boolean expression
+        """
+        return self._eval_expressions("st_intersects", other)
+
+    def distance(self, other: Expression) -> Expression:
+        """Returns the planar (Euclidean) distance between each geometry and the corresponding geometry in `other`.
+
+        Args:
+            other: The geometries to measure the distance to
+
+        Returns:
+            Expression: a Float64 expression
+        """
+        return self._eval_expressions("st_distance", other)
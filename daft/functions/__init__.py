@@ -11,10 +11,19 @@ from .functions import (
     rank,
     dense_rank,
     format,
+    grouping,
+    grouping_id,
+    hamming_distance,
+    knn,
+    build_ivf_index,
+    ivf_search,
+    semantic_join,
+    infer_json_dtype,
 )
 from .llm_generate import llm_generate
 
 __all__ = [
+    "build_ivf_index",
     "columns_avg",
     "columns_max",
     "columns_mean",
@@ -22,8 +31,15 @@ __all__ = [
     "columns_sum",
     "dense_rank",
     "format",
+    "grouping",
+    "grouping_id",
+    "hamming_distance",
+    "infer_json_dtype",
+    "ivf_search",
+    "knn",
     "llm_generate",
     "monotonically_increasing_id",
     "rank",
     "row_number",
+    "semantic_join",
 ]
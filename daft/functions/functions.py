@@ -1,8 +1,17 @@
 from __future__ import annotations
 
+from typing import TYPE_CHECKING, Literal
+
 import daft.daft as native
+from daft.daft import series_lit
 from daft.expressions import Expression, col, list_, lit
 
+if TYPE_CHECKING:
+    from daft.daft import IOConfig
+    from daft.datatype import DataType
+    from daft.dataframe import DataFrame
+    from daft.series import Series
+
 
 def monotonically_increasing_id() -> Expression:
     """Generates a column of monotonically increasing unique ids.
@@ -42,6 +51,60 @@ def monotonically_increasing_id() -> Expression:
     return Expression._from_pyexpr(f())
 
 
+def grouping(expr: Expression | str) -> Expression:
+    """Returns whether a grouping column is aggregated over in a grouping set.
+
+    This is 1 for rows of a grouping set that does not contain the column, where it is null, and 0 otherwise. It can
+    only be used in the aggregations of :meth:`DataFrame.rollup`, :meth:`DataFrame.cube` or SQL grouping sets.
+
+    Args:
+        expr: The grouping column.
+
+    Returns:
+        Expression: An Int64 expression that is 1 when the column is aggregated over and 0 otherwise.
+
+    Examples:
+        >>> import daft
+        >>> from daft import col
+        >>> from daft.functions import grouping
+        >>> df = daft.from_pydict({"pet": ["cat", "dog", None], "age": [1, 2, 3]})
+        >>> df = df.rollup("pet").agg(col("age").sum(), grouping("pet").alias("is_total"))
+        >>> df.sort(["is_total", "pet"]).to_pydict()
+        {'pet': ['cat', 'dog', None, None], 'age': [1, 2, 3, 6], 'is_total': [0, 0, 0, 1]}
+
+    """
+    expr = col(expr) if isinstance(expr, str) else expr
+    f = native.get_function_from_registry("grouping")
+    return Expression._from_pyexpr(f(expr._expr))
+
+
+def grouping_id(*exprs: Expression | str) -> Expression:
+    """Returns the bit vector of :func:`grouping` over the given grouping columns.
+
+    The first column is the most significant bit, and without arguments all grouping columns are used. It can only be used in the aggregations of
+    :meth:`DataFrame.rollup`, :meth:`DataFrame.cube` or SQL grouping sets.
+
+    Args:
+        exprs: The grouping columns.
+
+    Returns:
+        Expression: An Int64 expression identifying the grouping set of each row.
+
+    Examples:
+        >>> import daft
+        >>> from daft import col
+        >>> from daft.functions import grouping_id
+        >>> df = daft.from_pydict({"pet": ["cat", "dog"], "sex": ["F", "M"], "age": [1, 2]})
+        >>> df = df.rollup("pet", "sex").agg(col("age").sum(), grouping_id().alias("gid"))
+        >>> df.sort(["gid", "pet"]).to_pydict()
+        {'pet': ['cat', 'dog', 'cat', 'dog', None], 'sex': ['F', 'M', None, None, None], 'age': [1, 2, 1, 2, 3], 'gid': [0, 0, 1, 1, 3]}
+
+    """
+    exprs_ = [col(e) if isinstance(e, str) else e for e in exprs]
+    f = native.get_function_from_registry("grouping_id")
+    return Expression._from_pyexpr(f(*[e._expr for e in exprs_]))
+
+
 def columns_sum(*exprs: Expression | str) -> Expression:
     """Sum values across columns.
 
@@ -329,6 +392,261 @@ def dense_rank() -> Expression:
     return Expression._from_pyexpr(native.dense_rank())
 
 
+def hamming_distance(left: Expression | str, right: Expression | str) -> Expression:
+    """Computes the number of differing bits between two integers or two binary values of the same length.
+
+    This is typically used to compare perceptual image hashes, such as the ones produced by
+    [`Expression.image.phash()`][daft.expressions.expressions.ExpressionImageNamespace.phash].
+
+    Args:
+        left: An integer or binary expression.
+        right: An integer or binary expression of the same kind as ``left``.
+
+    Returns:
+        Expression: A UInt32 expression with the number of differing bits.
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import hamming_distance
+        >>> df = daft.from_pydict({"a": [0b1011, 7], "b": [0b0001, 0]})
+        >>> df.select(hamming_distance("a", "b").alias("dist")).to_pydict()
+        {'dist': [2, 3]}
+    """
+    left = col(left) if isinstance(left, str) else left
+    right = col(right) if isinstance(right, str) else right
+    f = native.get_function_from_registry("hamming_distance")
+    return Expression._from_pyexpr(f(left._expr, right._expr))
+
+
+def knn(
+    query: Expression | str,
+    reference: Expression | Series,
+    k: int,
+    metric: Literal["cosine", "dot", "euclidean", "manhattan", "hamming"] = "cosine",
+) -> Expression:
+    """Finds the ``k`` nearest neighbours of each query embedding among the reference embeddings by brute force.
+
+    Every query is compared with every reference, so the reference should usually be a Series of embeddings
+    rather than a column, which would only contain the references in the same partition as the query.
+
+    Args:
+        query: A fixed size list or embedding expression with the query vectors.
+        reference: The reference vectors, with the same inner dtype and size as the queries.
+        k: The number of neighbours to return for each query.
+        metric: How to score the references. Neighbours are sorted by ascending distance, except for
+            ``"dot"`` which is a similarity and is sorted by descending score. Defaults to ``"cosine"``.
+
+    Returns:
+        Expression: A list of ``{"id": UInt64, "score": Float64}`` structs per query, closest first, where ``id``
+            is the row of the neighbour in the reference.
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import knn
+        >>> dtype = daft.DataType.fixed_size_list(daft.DataType.float32(), 2)
+        >>> reference = daft.Series.from_pylist([[0, 0], [1, 1], [5, 5]]).cast(dtype)
+        >>> df = daft.from_pydict({"q": [[0, 1], [4, 4]]})
+        >>> df.select(knn(df["q"].cast(dtype), reference, k=2, metric="manhattan")).to_pydict()
+        {'q': [[{'id': 0, 'score': 1.0}, {'id': 1, 'score': 1.0}], [{'id': 2, 'score': 2.0}, {'id': 1, 'score': 6.0}]]}
+    """
+    query = col(query) if isinstance(query, str) else query
+    if not isinstance(reference, Expression):
+        reference = Expression._from_pyexpr(series_lit(reference._series))
+    f = native.get_function_from_registry("knn")
+    return Expression._from_pyexpr(f(query._expr, reference._expr, lit(k)._expr, metric=lit(metric)._expr))
+
+
+def build_ivf_index(
+    embeddings: Series,
+    path: str,
+    *,
+    ids: Series | None = None,
+    nlist: int | None = None,
+    metric: Literal["cosine", "dot", "euclidean", "manhattan"] = "cosine",
+    max_iterations: int = 10,
+    seed: int = 0,
+    io_config: IOConfig | None = None,
+) -> None:
+    """Builds an IVF-flat approximate nearest neighbour index over embeddings and writes it to ``path``.
+
+    The embeddings are clustered with k-means into ``nlist`` lists, and [`ivf_search`][daft.functions.ivf_search]
+    only scans the lists closest to each query. Null embeddings aren't indexed.
+
+    Args:
+        embeddings: A fixed size list or embedding Series with the vectors to index.
+        path: A local or object store path to write the index to, typically next to the data.
+        ids: The id of each embedding returned by searches. Defaults to the row number of each embedding.
+        nlist: The number of lists. Defaults to the square root of the number of embeddings.
+        metric: How searches score the indexed vectors, see [`knn`][daft.functions.knn]. Defaults to ``"cosine"``.
+        max_iterations: The maximum number of k-means iterations. Defaults to 10.
+        seed: The seed for sampling the initial centroids. Defaults to 0.
+        io_config: IOConfig to use when writing the index.
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import build_ivf_index
+        >>> dtype = daft.DataType.embedding(daft.DataType.float32(), 2)
+        >>> embeddings = daft.Series.from_pylist([[0, 1], [1, 1], [5, 5]]).cast(dtype)
+        >>> build_ivf_index(embeddings, "/tmp/index.ivf", nlist=2)  # doctest: +SKIP
+    """
+    native.build_ivf_index(
+        embeddings._series,
+        path,
+        ids=ids._series if ids is not None else None,
+        nlist=nlist,
+        metric=metric,
+        max_iterations=max_iterations,
+        seed=seed,
+        io_config=io_config,
+    )
+
+
+def ivf_search(
+    query: Expression | str,
+    index_path: str,
+    k: int,
+    *,
+    nprobe: int = 8,
+    io_config: IOConfig | None = None,
+) -> Expression:
+    """Finds the approximate ``k`` nearest neighbours of each query embedding in an IVF index.
+
+    Only the ``nprobe`` lists whose centroids are closest to a query are scanned, so increasing ``nprobe`` improves
+    recall at the cost of latency. Probing every list gives the same results as [`knn`][daft.functions.knn].
+
+    Args:
+        query: A fixed size list or embedding expression with the query vectors.
+        index_path: The path of an index written by [`build_ivf_index`][daft.functions.build_ivf_index].
+        k: The number of neighbours to return for each query.
+        nprobe: The number of lists to scan for each query. Defaults to 8.
+        io_config: IOConfig to use when reading the index.
+
+    Returns:
+        Expression: A list of ``{"id": UInt64, "score": Float64}`` structs per query, closest first.
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import build_ivf_index, ivf_search
+        >>> dtype = daft.DataType.embedding(daft.DataType.float32(), 2)
+        >>> build_ivf_index(daft.Series.from_pylist([[0, 1], [5, 5]]).cast(dtype), "/tmp/index.ivf")  # doctest: +SKIP
+        >>> df = daft.from_pydict({"q": [[4, 4]]})
+        >>> df.select(ivf_search(df["q"].cast(dtype), "/tmp/index.ivf", k=1)).to_pydict()  # doctest: +SKIP
+        {'q': [[{'id': 1, 'score': 0.0}]]}
+    """
+    query = col(query) if isinstance(query, str) else query
+    f = native.get_function_from_registry("ivf_search")
+    return Expression._from_pyexpr(
+        f(
+            query._expr,
+            lit(index_path)._expr,
+            lit(k)._expr,
+            nprobe=lit(nprobe)._expr,
+            io_config=Expression._to_expression(io_config)._expr,
+        )
+    )
+
+
+def semantic_join(
+    left: DataFrame,
+    right: DataFrame,
+    left_on: str,
+    right_on: str,
+    index_path: str,
+    *,
+    k: int = 1,
+    nprobe: int = 8,
+    metric: Literal["cosine", "dot", "euclidean", "manhattan"] = "cosine",
+    nlist: int | None = None,
+    score_column: str = "score",
+    io_config: IOConfig | None = None,
+) -> DataFrame:
+    """Joins each row of ``left`` with its ``k`` approximate nearest rows of ``right`` by embedding similarity.
+
+    An IVF index over the ``right_on`` embeddings is built and written to ``index_path``, then probed with the
+    ``left_on`` embeddings using [`ivf_search`][daft.functions.ivf_search]. Rows of ``left`` without any neighbour,
+    such as rows with null embeddings, are dropped.
+
+    Args:
+        left: The DataFrame with the query embeddings.
+        right: The DataFrame with the embeddings to index. It is materialized to build the index.
+        left_on: The fixed size list or embedding column of ``left``.
+        right_on: The fixed size list or embedding column of ``right``, of the same dtype as ``left_on``.
+        index_path: A local or object store path to write the index to.
+        k: The number of neighbours to join with each row of ``left``. Defaults to 1.
+        nprobe: The number of lists to scan for each query, trading latency for recall. Defaults to 8.
+        metric: How to score the neighbours, see [`knn`][daft.functions.knn]. Defaults to ``"cosine"``.
+        nlist: The number of lists of the index. Defaults to the square root of the number of rows of ``right``.
+        score_column: The name of the column with the score of each pair. Defaults to ``"score"``.
+        io_config: IOConfig to use when writing and reading the index.
+
+    Returns:
+        DataFrame: The columns of both DataFrames, as for an inner join, and the score of each pair.
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import semantic_join
+        >>> dtype = daft.DataType.embedding(daft.DataType.float32(), 2)
+        >>> docs = daft.from_pydict({"doc": ["a", "b"], "e": [[0, 1], [5, 5]]})
+        >>> docs = docs.with_column("e", docs["e"].cast(dtype))
+        >>> queries = daft.from_pydict({"q": ["x"], "qe": [[4, 4]]})
+        >>> queries = queries.with_column("qe", queries["qe"].cast(dtype))
+        >>> semantic_join(queries, docs, "qe", "e", "/tmp/docs.ivf").select("q", "doc").to_pydict()  # doctest: +SKIP
+        {'q': ['x'], 'doc': ['b']}
+    """
+    from daft.series import Series
+
+    row_id = "__semantic_join_row_id"
+    neighbour = "__semantic_join_neighbour"
+
+    right = right.with_column(row_id, monotonically_increasing_id()).collect()
+    table = right.select(row_id, right_on).to_arrow()
+    build_ivf_index(
+        Series.from_arrow(table[right_on]).cast(right.schema()[right_on].dtype),
+        index_path,
+        ids=Series.from_arrow(table[row_id]),
+        nlist=nlist,
+        metric=metric,
+        io_config=io_config,
+    )
+
+    left = left.with_column(neighbour, ivf_search(left[left_on], index_path, k, nprobe=nprobe, io_config=io_config))
+    left = left.explode(neighbour).where(col(neighbour).not_null())
+    left = left.with_columns(
+        {
+            row_id: col(neighbour).struct.get("id"),
+            score_column: col(neighbour).struct.get("score"),
+        }
+    ).exclude(neighbour)
+    return left.join(right, on=row_id).exclude(row_id)
+
+
+def infer_json_dtype(values: Series, path: str = "$", sample_size: int = 1000) -> DataType:
+    """Infers the data type of the values selected by a JSONPath from a sample of JSON strings.
+
+    The fields of objects are merged across documents, integers and floats widen to floats, and any other
+    conflicting types widen to strings. Use the result as the ``dtype`` of
+    [`Expression.json_extract`][daft.expressions.Expression.json_extract].
+
+    Args:
+        values: A string Series of JSON documents.
+        path: The JSONPath of the values. Defaults to the whole document.
+        sample_size: The number of non-null documents to sample. Defaults to 1000.
+
+    Returns:
+        DataType: The inferred data type, which is a string if every sampled value is null.
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import infer_json_dtype
+        >>> values = daft.Series.from_pylist(['{"a": {"b": 1}}', '{"a": {"b": 2.5, "c": "x"}}'])
+        >>> infer_json_dtype(values, "$.a")
+        Struct[b: Float64, c: Utf8]
+    """
+    from daft.datatype import DataType
+
+    return DataType._from_pydatatype(native.infer_json_dtype(values._series, path, sample_size))
+
+
 def format(f_string: str, *args: Expression | str) -> Expression:
     """Format a string using the given arguments.
 
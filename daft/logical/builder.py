@@ -254,9 +254,15 @@ class LogicalPlanBuilder:
         self,
         to_agg: list[Expression],
         group_by: list[Expression] | None,
+        grouping_sets: list[list[int]] | None = None,
     ) -> LogicalPlanBuilder:
         group_by_pyexprs = [expr._expr for expr in group_by] if group_by is not None else []
-        builder = self._builder.aggregate([expr._expr for expr in to_agg], group_by_pyexprs)
+        if grouping_sets is not None:
+            builder = self._builder.aggregate_grouping_sets(
+                [expr._expr for expr in to_agg], group_by_pyexprs, grouping_sets
+            )
+        else:
+            builder = self._builder.aggregate([expr._expr for expr in to_agg], group_by_pyexprs)
         return LogicalPlanBuilder(builder)
 
     def map_groups(self, udf: Expression, group_by: list[Expression] | None) -> LogicalPlanBuilder:
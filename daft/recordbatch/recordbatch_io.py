@@ -310,7 +310,8 @@ def write_tabular(
     elif file_format == FileFormat.Csv:
         format = pads.CsvFileFormat()
         opts = None
-        assert compression is None
+        if compression is not None:
+            raise ValueError("Compressed CSV writes are currently only supported with the native runner")
         inflation_factor = execution_config.csv_inflation_factor
         target_file_size = execution_config.csv_target_filesize
     else:
@@ -1133,3 +1133,44 @@ class SeriesImageNamespace(SeriesNamespace):
         if not isinstance(mode, ImageMode):
             raise ValueError(f"mode must be a string or ImageMode variant, but got: {mode}")
         return self._eval_expressions("to_mode", mode=mode)
+
+    def rotate(self, degrees: int) -> Series:
+        return self._eval_expressions("image_rotate", degrees=degrees)
+
+    def flip_horizontal(self) -> Series:
+        return self._eval_expressions("image_flip_horizontal")
+
+    def flip_vertical(self) -> Series:
+        return self._eval_expressions("image_flip_vertical")
+
+    def pad_to_aspect(self, ratio: float, fill: int = 0) -> Series:
+        return self._eval_expressions("image_pad_to_aspect", ratio=float(ratio), fill=fill)
+
+    def center_crop(self, w: int, h: int) -> Series:
+        return self._eval_expressions("image_center_crop", w=w, h=h)
+
+    def gaussian_blur(self, sigma: float) -> Series:
+        return self._eval_expressions("image_gaussian_blur", sigma=float(sigma))
+
+    def adjust_brightness(self, factor: float) -> Series:
+        return self._eval_expressions("image_adjust_brightness", factor=float(factor))
+
+    def adjust_contrast(self, factor: float) -> Series:
+        return self._eval_expressions("image_adjust_contrast", factor=float(factor))
+
+    def thumbnail(self, w: int, h: int) -> Series:
+        return self._eval_expressions("image_thumbnail", w=w, h=h)
+
+    def ahash(self) -> Series:
+        return self._eval_expressions("image_ahash")
+
+    def dhash(self) -> Series:
+        return self._eval_expressions("image_dhash")
+
+    def phash(self) -> Series:
+        return self._eval_expressions("image_phash")
+
+    def normalize(self, mean: float | list[float], std: float | list[float]) -> Series:
+        mean = [float(m) for m in mean] if isinstance(mean, (list, tuple)) else float(mean)
+        std = [float(s) for s in std] if isinstance(std, (list, tuple)) else float(std)
+        return self._eval_expressions("image_normalize", mean=mean, std=std)
@@ -104,6 +104,7 @@ impl PyDaftExecutionConfig {
         native_parquet_writer=None,
         use_experimental_distributed_engine=None,
         min_cpu_per_task=None,
+        recursive_cte_max_iterations=None,
     ))]
     fn with_config_values(
         &self,
@@ -135,6 +136,7 @@ impl PyDaftExecutionConfig {
         native_parquet_writer: Option<bool>,
         use_experimental_distributed_engine: Option<bool>,
         min_cpu_per_task: Option<f64>,
+        recursive_cte_max_iterations: Option<usize>,
     ) -> PyResult<Self> {
         let mut config = self.config.as_ref().clone();
 
@@ -247,6 +249,10 @@ impl PyDaftExecutionConfig {
             config.min_cpu_per_task = min_cpu_per_task;
         }
 
+        if let Some(recursive_cte_max_iterations) = recursive_cte_max_iterations {
+            config.recursive_cte_max_iterations = recursive_cte_max_iterations;
+        }
+
         Ok(Self {
             config: Arc::new(config),
         })
@@ -372,6 +378,11 @@ impl PyDaftExecutionConfig {
     fn min_cpu_per_task(&self) -> PyResult<f64> {
         Ok(self.config.min_cpu_per_task)
     }
+
+    #[getter]
+    fn recursive_cte_max_iterations(&self) -> PyResult<usize> {
+        Ok(self.config.recursive_cte_max_iterations)
+    }
 }
 
 impl_bincode_py_state_serialization!(PyDaftExecutionConfig);
@@ -35,6 +35,9 @@ pub enum CatalogError {
     #[snafu(display("{message}"))]
     Unsupported { message: String },
 
+    #[snafu(display("Catalog request failed: {message}"))]
+    RequestFailed { message: String },
+
     #[snafu(display("{error}"))]
     DaftError { error: DaftError },
 
@@ -83,6 +86,13 @@ impl CatalogError {
         }
     }
 
+    #[inline]
+    pub fn request_failed<S: Into<String>>(message: S) -> CatalogError {
+        CatalogError::RequestFailed {
+            message: message.into(),
+        }
+    }
+
     #[inline]
     pub fn invalid_identifier<S: Into<String>>(input: S) -> CatalogError {
         CatalogError::InvalidIdentifier {
@@ -0,0 +1,1136 @@
+//! A catalog backed by an Iceberg REST catalog server, see
+//! <https://github.com/apache/iceberg/blob/main/open-api/rest-catalog-open-api.yaml>.
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+    time::Duration,
+};
+
+use bytes::Bytes;
+use common_error::DaftError;
+use common_io_config::IOConfig;
+use common_runtime::get_io_runtime;
+use common_scan_info::ScanOperatorRef;
+use daft_context::{get_context, DataFrame};
+use daft_core::prelude::SchemaRef;
+use daft_dsl::{resolved_col, LiteralValue};
+use daft_logical_plan::LogicalPlanBuilder;
+use daft_scan::{
+    iceberg::{
+        spec::Schema as IcebergSchema,
+        write::{produce_snapshot, NewDataFile, WriteMode},
+        IcebergScanOperator, SnapshotSelection, TableMetadata,
+    },
+    storage_config::StorageConfig,
+};
+use daft_writers::make_iceberg_writer_factory;
+use indexmap::IndexMap;
+use reqwest::{Method, StatusCode};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    error::{CatalogError, CatalogResult},
+    Catalog, Identifier, Table, TableRef,
+};
+
+/// A catalog which talks to an Iceberg REST catalog server.
+///
+/// Namespaces may be nested, and tables must always be qualified by a namespace.
+#[derive(Clone, Debug)]
+pub struct IcebergRestCatalog {
+    name: String,
+    client: Arc<RestClient>,
+    /// IO config used to read table metadata and data files.
+    io_config: IOConfig,
+}
+
+#[derive(Debug)]
+struct RestClient {
+    client: reqwest::Client,
+    /// Url that all catalog routes are relative to, including the version and the optional prefix.
+    base_url: String,
+    token: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct CatalogConfig {
+    #[serde(default)]
+    defaults: HashMap<String, String>,
+    #[serde(default)]
+    overrides: HashMap<String, String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ErrorResponse {
+    error: ErrorModel,
+}
+
+#[derive(Debug, Deserialize)]
+struct ErrorModel {
+    message: String,
+}
+
+#[derive(Debug, Serialize)]
+struct CreateNamespaceRequest {
+    namespace: Vec<String>,
+    properties: HashMap<String, String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct ListNamespacesResponse {
+    namespaces: Vec<Vec<String>>,
+    next_page_token: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TableIdentifier {
+    namespace: Vec<String>,
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct ListTablesResponse {
+    identifiers: Vec<TableIdentifier>,
+    next_page_token: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct CreateTableRequest<'a> {
+    name: &'a str,
+    schema: IcebergSchema,
+}
+
+#[derive(Debug, Deserialize)]
+struct LoadTableResult {
+    metadata: TableMetadata,
+}
+
+impl RestClient {
+    /// Sends a request to the route at `path`, returning the response status and body.
+    fn send(
+        &self,
+        method: Method,
+        path: &str,
+        query: &[(&str, &str)],
+        body: Option<serde_json::Value>,
+    ) -> CatalogResult<(StatusCode, Bytes)> {
+        let mut request = self
+            .client
+            .request(method, format!("{}/{}", self.base_url, path))
+            .query(query);
+        if let Some(token) = &self.token {
+            request = request.bearer_auth(token);
+        }
+        if let Some(body) = body {
+            request = request.json(&body);
+        }
+        get_io_runtime(true)
+            .block_within_async_context(async move {
+                let response = request.send().await?;
+                let status = response.status();
+                Ok((status, response.bytes().await?))
+            })?
+            .map_err(|e: reqwest::Error| CatalogError::request_failed(e.to_string()))
+    }
+
+    /// Sends a request and parses its json response, erroring on any unsuccessful status.
+    fn send_json<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        path: &str,
+        query: &[(&str, &str)],
+        body: Option<serde_json::Value>,
+    ) -> CatalogResult<T> {
+        let (status, bytes) = self.send(method, path, query, body)?;
+        if !status.is_success() {
+            return Err(error_from_response(status, &bytes));
+        }
+        parse_json(&bytes)
+    }
+
+    /// Fetches the current metadata of a table.
+    fn load_table(&self, ident: &Identifier) -> CatalogResult<TableMetadata> {
+        let (status, bytes) = self.send(Method::GET, &table_path(ident)?, &[], None)?;
+        match status {
+            StatusCode::NOT_FOUND => Err(CatalogError::obj_not_found("table", ident)),
+            status if !status.is_success() => Err(error_from_response(status, &bytes)),
+            _ => Ok(parse_json::<LoadTableResult>(&bytes)?.metadata),
+        }
+    }
+}
+
+fn parse_json<T: DeserializeOwned>(bytes: &[u8]) -> CatalogResult<T> {
+    serde_json::from_slice(bytes).map_err(|e| {
+        CatalogError::request_failed(format!("invalid response from Iceberg REST catalog: {e}"))
+    })
+}
+
+fn error_from_response(status: StatusCode, bytes: &[u8]) -> CatalogError {
+    let message = serde_json::from_slice::<ErrorResponse>(bytes)
+        .map(|response| response.error.message)
+        .unwrap_or_else(|_| String::from_utf8_lossy(bytes).into_owned());
+    CatalogError::request_failed(format!("{status}: {message}"))
+}
+
+/// Encodes a namespace as a path segment, joining multi-level namespaces with the unit separator.
+fn namespace_path(namespace: &[String]) -> String {
+    format!(
+        "namespaces/{}",
+        urlencoding::encode(&namespace.join("\u{1f}"))
+    )
+}
+
+/// Returns the route of a table, which must be qualified by its namespace.
+fn table_path(ident: &Identifier) -> CatalogResult<String> {
+    let Some(namespace) = ident.qualifier() else {
+        return Err(CatalogError::invalid_identifier(format!(
+            "{ident} (Iceberg tables must be qualified by a namespace)"
+        )));
+    };
+    Ok(format!(
+        "{}/tables/{}",
+        namespace_path(namespace),
+        urlencoding::encode(ident.name())
+    ))
+}
+
+impl IcebergRestCatalog {
+    /// Connects to the REST catalog at `uri`, fetching its config for the optional `warehouse`.
+    pub fn try_new(
+        name: String,
+        uri: &str,
+        warehouse: Option<&str>,
+        token: Option<String>,
+        io_config: IOConfig,
+    ) -> CatalogResult<Self> {
+        let mut client = RestClient {
+            client: reqwest::Client::new(),
+            base_url: format!("{}/v1", uri.trim_end_matches('/')),
+            token,
+        };
+        let query = warehouse
+            .map(|warehouse| vec![("warehouse", warehouse)])
+            .unwrap_or_default();
+        let config: CatalogConfig = client.send_json(Method::GET, "config", &query, None)?;
+        if let Some(prefix) = config
+            .overrides
+            .get("prefix")
+            .or_else(|| config.defaults.get("prefix"))
+        {
+            client.base_url = format!("{}/{}", client.base_url, prefix.trim_matches('/'));
+        }
+        Ok(Self {
+            name,
+            client: Arc::new(client),
+            io_config,
+        })
+    }
+
+    /// Returns whether the resource at `path` exists.
+    fn exists(&self, path: &str) -> CatalogResult<bool> {
+        match self.client.send(Method::HEAD, path, &[], None)? {
+            (status, _) if status.is_success() => Ok(true),
+            (StatusCode::NOT_FOUND, _) => Ok(false),
+            (status, bytes) => Err(error_from_response(status, &bytes)),
+        }
+    }
+
+    /// Deletes the resource at `path`, mapping a missing resource to a not found error.
+    fn delete(&self, path: &str, type_: &str, ident: &Identifier) -> CatalogResult<()> {
+        match self.client.send(Method::DELETE, path, &[], None)? {
+            (status, _) if status.is_success() => Ok(()),
+            (StatusCode::NOT_FOUND, _) => Err(CatalogError::obj_not_found(type_, ident)),
+            (status, bytes) => Err(error_from_response(status, &bytes)),
+        }
+    }
+
+    fn load_table(&self, ident: &Identifier) -> CatalogResult<IcebergRestTable> {
+        let metadata = self.client.load_table(ident)?;
+        Ok(IcebergRestTable::new(
+            ident.clone(),
+            metadata,
+            self.client.clone(),
+            self.io_config.clone(),
+        ))
+    }
+
+    /// Lists the namespaces nested directly under `parent`, or the top-level namespaces.
+    fn list_namespaces_under(&self, parent: Option<&[String]>) -> CatalogResult<Vec<Identifier>> {
+        let parent = parent.map(|parent| parent.join("\u{1f}"));
+        let mut namespaces = vec![];
+        let mut page_token: Option<String> = None;
+        loop {
+            let mut query = vec![];
+            if let Some(parent) = &parent {
+                query.push(("parent", parent.as_str()));
+            }
+            if let Some(page_token) = &page_token {
+                query.push(("pageToken", page_token.as_str()));
+            }
+            let response: ListNamespacesResponse =
+                self.client
+                    .send_json(Method::GET, "namespaces", &query, None)?;
+            namespaces.extend(
+                response
+                    .namespaces
+                    .into_iter()
+                    .map(Identifier::try_new)
+                    .collect::<CatalogResult<Vec<_>>>()?,
+            );
+            match response.next_page_token {
+                Some(token) if !token.is_empty() => page_token = Some(token),
+                _ => return Ok(namespaces),
+            }
+        }
+    }
+
+    fn list_tables_in(&self, namespace: &[String]) -> CatalogResult<Vec<Identifier>> {
+        let path = format!("{}/tables", namespace_path(namespace));
+        let mut tables = vec![];
+        let mut page_token = None;
+        loop {
+            let query = page_token
+                .as_deref()
+                .map(|token| vec![("pageToken", token)])
+                .unwrap_or_default();
+            let response: ListTablesResponse =
+                self.client.send_json(Method::GET, &path, &query, None)?;
+            tables.extend(
+                response
+                    .identifiers
+                    .into_iter()
+                    .map(|table| Identifier::qualified(table.namespace, table.name)),
+            );
+            match response.next_page_token {
+                Some(token) if !token.is_empty() => page_token = Some(token),
+                _ => return Ok(tables),
+            }
+        }
+    }
+}
+
+/// A table loaded from an Iceberg REST catalog.
+#[derive(Clone, Debug)]
+pub struct IcebergRestTable {
+    ident: Identifier,
+    /// The latest metadata seen for the table, which is refreshed by commits.
+    metadata: Arc<RwLock<Arc<TableMetadata>>>,
+    snapshot: SnapshotSelection,
+    client: Arc<RestClient>,
+    io_config: IOConfig,
+}
+
+impl IcebergRestTable {
+    fn new(
+        ident: Identifier,
+        metadata: TableMetadata,
+        client: Arc<RestClient>,
+        io_config: IOConfig,
+    ) -> Self {
+        Self {
+            ident,
+            metadata: Arc::new(RwLock::new(Arc::new(metadata))),
+            snapshot: SnapshotSelection::Current,
+            client,
+            io_config,
+        }
+    }
+
+    /// Returns the table metadata as of when the table was loaded or last written to.
+    pub fn metadata(&self) -> Arc<TableMetadata> {
+        self.metadata.read().unwrap().clone()
+    }
+
+    /// Returns a copy of this table which reads the selected snapshot instead of the current one.
+    pub fn with_snapshot(&self, snapshot: SnapshotSelection) -> CatalogResult<Self> {
+        // validate the selection eagerly so that a bad snapshot errors here rather than on read
+        self.metadata().select_snapshot(&snapshot)?;
+        Ok(Self {
+            snapshot,
+            ..self.clone()
+        })
+    }
+
+    /// Writes the result of `plan` as data files of the table, then commits them as a new
+    /// snapshot of the `main` branch.
+    fn write(&self, plan: LogicalPlanBuilder, mode: WriteMode) -> CatalogResult<()> {
+        let metadata = self.metadata();
+        let schema = metadata.current_schema()?.to_daft();
+        let plan = plan.select(
+            schema
+                .into_iter()
+                .map(|field| resolved_col(field.name.as_str()).cast(&field.dtype))
+                .collect(),
+        )?;
+
+        let (factory, data_files) = make_iceberg_writer_factory(
+            &metadata,
+            Some(self.io_config.clone()),
+            &get_context().execution_config(),
+        )?;
+        let mut writer = factory.create_writer(0, None)?;
+        let runtime = get_io_runtime(true);
+        for part in DataFrame::from(plan).iter_partitions()? {
+            let part = part?;
+            writer = runtime.block_within_async_context(async move {
+                writer.write(part).await?;
+                Ok::<_, DaftError>(writer)
+            })??;
+        }
+        runtime.block_within_async_context(async move { writer.close().await })??;
+
+        self.commit(&data_files.take(), mode)
+    }
+
+    /// Commits a snapshot with `files`, retrying with refreshed metadata when another writer
+    /// commits to the table first.
+    fn commit(&self, files: &[NewDataFile], mode: WriteMode) -> CatalogResult<()> {
+        let path = table_path(&self.ident)?;
+        let storage_config = StorageConfig::new_internal(true, Some(self.io_config.clone()));
+        let mut base = self.metadata();
+        let property = |name: &str, default: u64| {
+            base.properties
+                .get(name)
+                .and_then(|value| value.parse().ok())
+                .unwrap_or(default)
+        };
+        let retries = property("commit.retry.num-retries", 4);
+        let max_wait = Duration::from_millis(property("commit.retry.max-wait-ms", 60_000));
+        let mut wait = Duration::from_millis(property("commit.retry.min-wait-ms", 100));
+
+        for attempt in 0.. {
+            let parent = base.refs.get("main").map(|main| main.snapshot_id);
+            let snapshot = produce_snapshot(&base, files, mode, &storage_config)?;
+            let snapshot_id = snapshot.snapshot_id;
+            let request = json!({
+                "identifier": {
+                    "namespace": self.ident.qualifier().unwrap_or_default(),
+                    "name": self.ident.name(),
+                },
+                "requirements": [
+                    {"type": "assert-ref-snapshot-id", "ref": "main", "snapshot-id": parent},
+                ],
+                "updates": [
+                    {"action": "add-snapshot", "snapshot": snapshot},
+                    {
+                        "action": "set-snapshot-ref",
+                        "ref-name": "main",
+                        "type": "branch",
+                        "snapshot-id": snapshot_id,
+                    },
+                ],
+            });
+            match self.client.send(Method::POST, &path, &[], Some(request))? {
+                (status, bytes) if status.is_success() => {
+                    let result: LoadTableResult = parse_json(&bytes)?;
+                    *self.metadata.write().unwrap() = Arc::new(result.metadata);
+                    return Ok(());
+                }
+                // the table has moved on since `base`, so retry on top of its latest snapshot
+                (StatusCode::CONFLICT, _) if attempt < retries => {
+                    std::thread::sleep(wait);
+                    wait = (wait * 2).min(max_wait);
+                    base = Arc::new(self.client.load_table(&self.ident)?);
+                }
+                (status, bytes) => return Err(error_from_response(status, &bytes)),
+            }
+        }
+        unreachable!("the commit loop only exits by returning")
+    }
+}
+
+impl Catalog for IcebergRestCatalog {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn create_namespace(&self, ident: &Identifier) -> CatalogResult<()> {
+        let request = CreateNamespaceRequest {
+            namespace: ident.clone().path(),
+            properties: HashMap::new(),
+        };
+        let body = serde_json::to_value(request).expect("request should serialize to json");
+        match self
+            .client
+            .send(Method::POST, "namespaces", &[], Some(body))?
+        {
+            (status, _) if status.is_success() => Ok(()),
+            (StatusCode::CONFLICT, _) => Err(CatalogError::obj_already_exists("namespace", ident)),
+            (status, bytes) => Err(error_from_response(status, &bytes)),
+        }
+    }
+
+    fn create_table(&self, ident: &Identifier, schema: SchemaRef) -> CatalogResult<TableRef> {
+        let Some(namespace) = ident.qualifier() else {
+            return Err(CatalogError::invalid_identifier(format!(
+                "{ident} (Iceberg tables must be qualified by a namespace)"
+            )));
+        };
+        let request = CreateTableRequest {
+            name: ident.name(),
+            schema: IcebergSchema::from_daft(&schema)?,
+        };
+        let body = serde_json::to_value(request).expect("request should serialize to json");
+        let path = format!("{}/tables", namespace_path(namespace));
+        let (status, bytes) = self.client.send(Method::POST, &path, &[], Some(body))?;
+        match status {
+            StatusCode::CONFLICT => Err(CatalogError::obj_already_exists("table", ident)),
+            StatusCode::NOT_FOUND => Err(CatalogError::obj_not_found(
+                "namespace",
+                &Identifier::new(namespace),
+            )),
+            status if !status.is_success() => Err(error_from_response(status, &bytes)),
+            _ => {
+                let result: LoadTableResult = parse_json(&bytes)?;
+                Ok(Arc::new(IcebergRestTable::new(
+                    ident.clone(),
+                    result.metadata,
+                    self.client.clone(),
+                    self.io_config.clone(),
+                )))
+            }
+        }
+    }
+
+    fn drop_namespace(&self, ident: &Identifier) -> CatalogResult<()> {
+        self.delete(&namespace_path(&ident.clone().path()), "namespace", ident)
+    }
+
+    fn drop_table(&self, ident: &Identifier) -> CatalogResult<()> {
+        self.delete(&table_path(ident)?, "table", ident)
+    }
+
+    fn get_table(&self, ident: &Identifier) -> CatalogResult<TableRef> {
+        Ok(Arc::new(self.load_table(ident)?))
+    }
+
+    fn has_namespace(&self, ident: &Identifier) -> CatalogResult<bool> {
+        self.exists(&namespace_path(&ident.clone().path()))
+    }
+
+    fn has_table(&self, ident: &Identifier) -> CatalogResult<bool> {
+        if !ident.has_qualifier() {
+            return Ok(false);
+        }
+        self.exists(&table_path(ident)?)
+    }
+
+    fn list_namespaces(&self, pattern: Option<&str>) -> CatalogResult<Vec<Identifier>> {
+        // like the pyiceberg catalog, a pattern is the parent namespace to list under
+        let parent =
+            pattern.map(|pattern| Identifier::from_sql(pattern, false).map(Identifier::path));
+        self.list_namespaces_under(parent.transpose()?.as_deref())
+    }
+
+    fn list_tables(&self, pattern: Option<&str>) -> CatalogResult<Vec<Identifier>> {
+        if let Some(pattern) = pattern {
+            return self.list_tables_in(&Identifier::from_sql(pattern, false)?.path());
+        }
+        let mut tables = vec![];
+        for namespace in self.list_namespaces_under(None)? {
+            tables.extend(self.list_tables_in(&namespace.path())?);
+        }
+        Ok(tables)
+    }
+
+    #[cfg(feature = "python")]
+    fn to_py(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<pyo3::PyObject> {
+        use pyo3::{intern, types::PyAnyMethods};
+
+        use crate::python::PyCatalog;
+
+        let pycatalog = PyCatalog(Arc::new(self.clone()));
+
+        Ok(py
+            .import(intern!(py, "daft.catalog.__internal"))?
+            .getattr("IcebergRestCatalog")?
+            .call1((pycatalog,))?
+            .unbind())
+    }
+}
+
+impl Table for IcebergRestTable {
+    fn name(&self) -> String {
+        self.ident.name().to_string()
+    }
+
+    fn schema(&self) -> CatalogResult<SchemaRef> {
+        Ok(Arc::new(
+            self.metadata().schema_for(&self.snapshot)?.to_daft(),
+        ))
+    }
+
+    fn to_logical_plan(&self) -> CatalogResult<LogicalPlanBuilder> {
+        let storage_config = StorageConfig::new_internal(true, Some(self.io_config.clone()));
+        let operator = IcebergScanOperator::try_new(
+            self.ident.to_string(),
+            self.metadata(),
+            &self.snapshot,
+            Arc::new(storage_config),
+        )?;
+        Ok(LogicalPlanBuilder::table_scan(
+            ScanOperatorRef(Arc::new(operator)),
+            None,
+        )?)
+    }
+
+    fn append(
+        &self,
+        plan: LogicalPlanBuilder,
+        options: IndexMap<String, LiteralValue>,
+    ) -> CatalogResult<()> {
+        if let Some(option) = options.keys().next() {
+            return Err(CatalogError::unsupported(format!(
+                "IcebergRestTable does not support the append option '{option}'"
+            )));
+        }
+        self.write(plan, WriteMode::Append)
+    }
+
+    fn overwrite(
+        &self,
+        plan: LogicalPlanBuilder,
+        options: IndexMap<String, LiteralValue>,
+    ) -> CatalogResult<()> {
+        let mut mode = WriteMode::Overwrite;
+        for (option, value) in options {
+            match (option.as_str(), value) {
+                ("partition_overwrite", LiteralValue::Utf8(value)) if value == "static" => {}
+                ("partition_overwrite", LiteralValue::Utf8(value)) if value == "dynamic" => {
+                    mode = WriteMode::DynamicPartitionOverwrite;
+                }
+                ("partition_overwrite", value) => {
+                    return Err(CatalogError::unsupported(format!(
+                        "partition_overwrite must be 'static' or 'dynamic', found {value}"
+                    )));
+                }
+                (option, _) => {
+                    return Err(CatalogError::unsupported(format!(
+                        "IcebergRestTable does not support the overwrite option '{option}'"
+                    )));
+                }
+            }
+        }
+        self.write(plan, mode)
+    }
+
+    #[cfg(feature = "python")]
+    fn to_py(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<pyo3::PyObject> {
+        use pyo3::{intern, types::PyAnyMethods};
+
+        use crate::python::PyTable;
+
+        let pytable = PyTable(Arc::new(self.clone()));
+
+        Ok(py
+            .import(intern!(py, "daft.catalog.__internal"))?
+            .getattr("IcebergRestTable")?
+            .call1((pytable,))?
+            .unbind())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::BTreeMap,
+        io::{BufRead, BufReader, Read, Write},
+        net::{TcpListener, TcpStream},
+        sync::{Arc, Mutex},
+    };
+
+    use common_io_config::IOConfig;
+    use daft_context::DataFrame;
+    use daft_core::prelude::{DataType, Field, Int64Array, IntoSeries, Schema, Utf8Array};
+    use daft_dsl::LiteralValue;
+    use daft_logical_plan::LogicalPlanBuilder;
+    use daft_recordbatch::RecordBatch;
+    use daft_scan::iceberg::{SnapshotSelection, TableMetadata};
+    use indexmap::IndexMap;
+    use serde_json::{json, Value};
+
+    use super::{IcebergRestCatalog, IcebergRestTable};
+    use crate::{error::CatalogError, Catalog, Identifier, Table};
+
+    /// Namespaces and the metadata of the tables in them, keyed by namespace then table name.
+    type State = BTreeMap<Vec<String>, BTreeMap<String, Value>>;
+
+    /// A minimal stand-in for a REST catalog server, which lists one namespace per page.
+    struct RestCatalogStandIn {
+        state: Mutex<State>,
+    }
+
+    impl RestCatalogStandIn {
+        fn handle(&self, method: &str, path: &str, query: &str, body: &[u8]) -> (u16, Value) {
+            let query = query
+                .split('&')
+                .filter_map(|kv| kv.split_once('='))
+                .map(|(k, v)| (k, urlencoding::decode(v).unwrap().into_owned()))
+                .collect::<BTreeMap<_, _>>();
+            let segments = path
+                .trim_start_matches('/')
+                .split('/')
+                .map(|s| urlencoding::decode(s).unwrap().into_owned())
+                .collect::<Vec<_>>();
+            let segments = segments.iter().map(String::as_str).collect::<Vec<_>>();
+            let mut state = self.state.lock().unwrap();
+            let not_found = (404, json!({"error": {"message": "not found", "code": 404}}));
+            let conflict = (
+                409,
+                json!({"error": {"message": "already exists", "code": 409}}),
+            );
+            let split = |ns: &str| ns.split('\u{1f}').map(String::from).collect::<Vec<_>>();
+
+            match (method, segments.as_slice()) {
+                ("GET", ["v1", "config"]) => (200, json!({"overrides": {"prefix": "pfx"}})),
+                ("GET", ["v1", "pfx", "namespaces"]) => {
+                    let parent = query.get("parent").map(|p| split(p)).unwrap_or_default();
+                    let children = state
+                        .keys()
+                        .filter(|ns| ns.len() == parent.len() + 1 && ns.starts_with(&parent))
+                        .collect::<Vec<_>>();
+                    let page = query
+                        .get("pageToken")
+                        .map_or(0, |token| token.parse::<usize>().unwrap());
+                    let next = (page + 1 < children.len()).then(|| (page + 1).to_string());
+                    (
+                        200,
+                        json!({"namespaces": children.get(page).into_iter().collect::<Vec<_>>(), "next-page-token": next}),
+                    )
+                }
+                ("POST", ["v1", "pfx", "namespaces"]) => {
+                    let request: Value = serde_json::from_slice(body).unwrap();
+                    let ns = serde_json::from_value(request["namespace"].clone()).unwrap();
+                    if state.contains_key(&ns) {
+                        return conflict;
+                    }
+                    state.insert(ns, BTreeMap::new());
+                    (200, request)
+                }
+                (method, ["v1", "pfx", "namespaces", ns]) => {
+                    match (method, state.contains_key(&split(ns))) {
+                        (_, false) => not_found,
+                        ("HEAD", true) => (204, Value::Null),
+                        ("DELETE", true) => {
+                            state.remove(&split(ns));
+                            (204, Value::Null)
+                        }
+                        _ => (405, Value::Null),
+                    }
+                }
+                (method, ["v1", "pfx", "namespaces", ns, "tables"]) => {
+                    let Some(tables) = state.get_mut(&split(ns)) else {
+                        return not_found;
+                    };
+                    if method == "GET" {
+                        let identifiers = tables
+                            .keys()
+                            .map(|name| json!({"namespace": split(ns), "name": name}))
+                            .collect::<Vec<_>>();
+                        return (200, json!({"identifiers": identifiers}));
+                    }
+                    let request: Value = serde_json::from_slice(body).unwrap();
+                    let name = request["name"].as_str().unwrap().to_string();
+                    if tables.contains_key(&name) {
+                        return conflict;
+                    }
+                    let metadata = json!({
+                        "format-version": 2,
+                        "location": format!("memory://{name}"),
+                        "last-updated-ms": 0,
+                        "last-column-id": 0,
+                        "current-schema-id": 0,
+                        "schemas": [request["schema"].clone()],
+                        "default-spec-id": 0,
+                        "partition-specs": [{"spec-id": 0, "fields": []}],
+                    });
+                    tables.insert(name, metadata.clone());
+                    (200, json!({"metadata": metadata}))
+                }
+                (method, ["v1", "pfx", "namespaces", ns, "tables", name]) => {
+                    let Some(tables) = state.get_mut(&split(ns)) else {
+                        return not_found;
+                    };
+                    let Some(metadata) = tables.get(*name).cloned() else {
+                        return not_found;
+                    };
+                    match method {
+                        "GET" => (
+                            200,
+                            json!({"metadata-location": "memory://", "metadata": metadata}),
+                        ),
+                        "HEAD" => (204, Value::Null),
+                        "POST" => {
+                            let request: Value = serde_json::from_slice(body).unwrap();
+                            let mut metadata: TableMetadata =
+                                serde_json::from_value(metadata).unwrap();
+                            let main = metadata.refs.get("main").map(|main| main.snapshot_id);
+                            if request["requirements"][0]["snapshot-id"].as_i64() != main {
+                                return (
+                                    409,
+                                    json!({"error": {"message": "main has changed", "code": 409}}),
+                                );
+                            }
+                            let snapshot = request["updates"][0]["snapshot"].clone();
+                            metadata.add_snapshot(serde_json::from_value(snapshot).unwrap());
+                            let metadata = serde_json::to_value(metadata).unwrap();
+                            tables.insert(name.to_string(), metadata.clone());
+                            (
+                                200,
+                                json!({"metadata-location": "memory://", "metadata": metadata}),
+                            )
+                        }
+                        "DELETE" => {
+                            tables.remove(*name);
+                            (204, Value::Null)
+                        }
+                        _ => (405, Value::Null),
+                    }
+                }
+                _ => not_found,
+            }
+        }
+
+        fn serve(&self, stream: TcpStream) {
+            let mut reader = BufReader::new(stream);
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut parts = request_line.split_whitespace();
+            let method = parts.next().unwrap().to_string();
+            let target = parts.next().unwrap().to_string();
+
+            let mut content_length = 0;
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                if header.trim().is_empty() {
+                    break;
+                }
+                if let Some((key, value)) = header.split_once(':') {
+                    if key.eq_ignore_ascii_case("content-length") {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                }
+            }
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+
+            let (path, query) = target.split_once('?').unwrap_or((&target, ""));
+            let (status, response) = self.handle(&method, path, query, &body);
+            let response = if response.is_null() {
+                String::new()
+            } else {
+                response.to_string()
+            };
+            write!(
+                reader.get_mut(),
+                "HTTP/1.1 {status} STATUS\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                response.len(),
+                if method == "HEAD" { "" } else { &response },
+            )
+            .unwrap();
+        }
+    }
+
+    /// Starts a stand-in catalog server in the background, returning its url.
+    fn start_server(state: State) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = Arc::new(RestCatalogStandIn {
+            state: Mutex::new(state),
+        });
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                server.serve(stream.unwrap());
+            }
+        });
+        format!("http://{addr}")
+    }
+
+    fn connect(uri: &str) -> IcebergRestCatalog {
+        IcebergRestCatalog::try_new("test".to_string(), uri, None, None, IOConfig::default())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_namespaces() {
+        let catalog = connect(&start_server(State::new()));
+        assert_eq!(catalog.list_namespaces(None).unwrap(), vec![]);
+
+        catalog.create_namespace(&Identifier::simple("a")).unwrap();
+        catalog.create_namespace(&Identifier::simple("b")).unwrap();
+        catalog
+            .create_namespace(&Identifier::new(["a", "c"]))
+            .unwrap();
+        assert!(matches!(
+            catalog.create_namespace(&Identifier::simple("a")),
+            Err(CatalogError::ObjectAlreadyExists { .. })
+        ));
+
+        // listing follows page tokens, and a pattern lists the namespaces under it
+        assert_eq!(
+            catalog.list_namespaces(None).unwrap(),
+            vec![Identifier::simple("a"), Identifier::simple("b")]
+        );
+        assert_eq!(
+            catalog.list_namespaces(Some("a")).unwrap(),
+            vec![Identifier::new(["a", "c"])]
+        );
+        assert!(catalog.has_namespace(&Identifier::new(["a", "c"])).unwrap());
+        assert!(!catalog.has_namespace(&Identifier::simple("c")).unwrap());
+
+        catalog.drop_namespace(&Identifier::simple("b")).unwrap();
+        assert!(!catalog.has_namespace(&Identifier::simple("b")).unwrap());
+        assert!(matches!(
+            catalog.drop_namespace(&Identifier::simple("b")),
+            Err(CatalogError::ObjectNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_tables() {
+        let catalog = connect(&start_server(State::new()));
+        catalog.create_namespace(&Identifier::simple("ns")).unwrap();
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int64),
+            Field::new("name", DataType::Utf8),
+        ]));
+        let ident = Identifier::new(["ns", "t"]);
+        let table = catalog.create_table(&ident, schema.clone()).unwrap();
+        assert_eq!(table.name(), "t");
+        assert_eq!(table.schema().unwrap(), schema);
+        assert!(matches!(
+            catalog.create_table(&ident, schema.clone()),
+            Err(CatalogError::ObjectAlreadyExists { .. })
+        ));
+        assert!(matches!(
+            catalog.create_table(&Identifier::new(["missing", "t"]), schema.clone()),
+            Err(CatalogError::ObjectNotFound { .. })
+        ));
+        assert!(matches!(
+            catalog.create_table(&Identifier::simple("t"), schema.clone()),
+            Err(CatalogError::InvalidIdentifier { .. })
+        ));
+
+        assert!(catalog.has_table(&ident).unwrap());
+        assert!(!catalog.has_table(&Identifier::simple("t")).unwrap());
+        assert_eq!(catalog.list_tables(None).unwrap(), vec![ident.clone()]);
+        assert_eq!(
+            catalog.list_tables(Some("ns")).unwrap(),
+            vec![ident.clone()]
+        );
+
+        let table = catalog.get_table(&ident).unwrap();
+        assert_eq!(table.schema().unwrap(), schema);
+        assert_eq!(table.to_logical_plan().unwrap().schema(), schema);
+
+        catalog.drop_table(&ident).unwrap();
+        assert!(!catalog.has_table(&ident).unwrap());
+        assert!(matches!(
+            catalog.get_table(&ident),
+            Err(CatalogError::ObjectNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_snapshot_selection() {
+        let metadata = json!({
+            "format-version": 2,
+            "location": "memory://t",
+            "last-updated-ms": 2000,
+            "last-column-id": 2,
+            "current-schema-id": 1,
+            "schemas": [
+                {"type": "struct", "schema-id": 0, "fields": [
+                    {"id": 1, "name": "id", "required": true, "type": "long"}
+                ]},
+                {"type": "struct", "schema-id": 1, "fields": [
+                    {"id": 1, "name": "id", "required": true, "type": "long"},
+                    {"id": 2, "name": "name", "required": false, "type": "string"}
+                ]}
+            ],
+            "default-spec-id": 0,
+            "partition-specs": [{"spec-id": 0, "fields": []}],
+            "current-snapshot-id": 2,
+            "snapshots": [
+                {"snapshot-id": 1, "timestamp-ms": 1000, "manifest-list": "memory://1.avro", "schema-id": 0},
+                {"snapshot-id": 2, "timestamp-ms": 2000, "manifest-list": "memory://2.avro", "schema-id": 1}
+            ],
+            "refs": {"v1": {"snapshot-id": 1, "type": "tag"}}
+        });
+        let state = State::from([(
+            vec!["ns".to_string()],
+            BTreeMap::from([("t".to_string(), metadata)]),
+        )]);
+        let catalog = connect(&start_server(state));
+        let table = catalog.load_table(&Identifier::new(["ns", "t"])).unwrap();
+        let num_fields = |table: &IcebergRestTable| table.schema().unwrap().len();
+
+        assert_eq!(num_fields(&table), 2);
+        assert_eq!(
+            num_fields(&table.with_snapshot(SnapshotSelection::Id(1)).unwrap()),
+            1
+        );
+        assert_eq!(
+            num_fields(
+                &table
+                    .with_snapshot(SnapshotSelection::Ref("v1".to_string()))
+                    .unwrap()
+            ),
+            1
+        );
+        assert_eq!(
+            num_fields(&table.with_snapshot(SnapshotSelection::AsOf(2500)).unwrap()),
+            2
+        );
+        assert!(table.with_snapshot(SnapshotSelection::Id(3)).is_err());
+    }
+
+    /// Returns a catalog state with one table `ns.t` of ids and names stored under `dir`, which
+    /// may be partitioned by name.
+    fn writable_state(dir: &std::path::Path, partitioned: bool) -> State {
+        let fields = if partitioned {
+            json!([{"source-id": 2, "field-id": 1000, "name": "name", "transform": "identity"}])
+        } else {
+            json!([])
+        };
+        let metadata = json!({
+            "format-version": 2,
+            "location": dir.to_str().unwrap(),
+            "last-updated-ms": 0,
+            "last-column-id": 2,
+            "current-schema-id": 0,
+            "schemas": [{"type": "struct", "schema-id": 0, "fields": [
+                {"id": 1, "name": "id", "required": false, "type": "long"},
+                {"id": 2, "name": "name", "required": false, "type": "string"}
+            ]}],
+            "default-spec-id": 0,
+            "partition-specs": [{"spec-id": 0, "fields": fields}],
+            "properties": {"commit.retry.min-wait-ms": "1"},
+        });
+        State::from([(
+            vec!["ns".to_string()],
+            BTreeMap::from([("t".to_string(), metadata)]),
+        )])
+    }
+
+    fn rows(ids: &[i64], names: &[&str]) -> LogicalPlanBuilder {
+        let batch = RecordBatch::from_nonempty_columns(vec![
+            Int64Array::from(("id", ids.to_vec())).into_series(),
+            Utf8Array::from_values("name", names.iter()).into_series(),
+        ])
+        .unwrap();
+        DataFrame::from_record_batches(batch.schema.clone(), vec![batch])
+            .unwrap()
+            .builder()
+            .clone()
+    }
+
+    /// Reads the ids and names of a table, sorted by id.
+    fn read(table: &dyn Table) -> Vec<(i64, String)> {
+        let mut rows = vec![];
+        for batch in DataFrame::from(table.to_logical_plan().unwrap())
+            .collect()
+            .unwrap()
+        {
+            let ids = batch.get_column(0).i64().unwrap();
+            let names = batch.get_column(1).utf8().unwrap();
+            for i in 0..batch.len() {
+                rows.push((ids.get(i).unwrap(), names.get(i).unwrap().to_string()));
+            }
+        }
+        rows.sort();
+        rows
+    }
+
+    fn expected(rows: &[(i64, &str)]) -> Vec<(i64, String)> {
+        rows.iter()
+            .map(|(id, name)| (*id, (*name).to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_append_and_overwrite() {
+        let dir = tempfile::tempdir().unwrap();
+        let catalog = connect(&start_server(writable_state(dir.path(), false)));
+        let ident = Identifier::new(["ns", "t"]);
+        let table = catalog.get_table(&ident).unwrap();
+
+        table
+            .append(rows(&[1, 2], &["a", "b"]), IndexMap::new())
+            .unwrap();
+        table.append(rows(&[3], &["c"]), IndexMap::new()).unwrap();
+        assert_eq!(
+            read(table.as_ref()),
+            expected(&[(1, "a"), (2, "b"), (3, "c")])
+        );
+
+        table
+            .overwrite(rows(&[4], &["d"]), IndexMap::new())
+            .unwrap();
+        assert_eq!(read(table.as_ref()), expected(&[(4, "d")]));
+
+        // the commits are visible to other readers of the table, and older snapshots remain
+        let table = catalog.load_table(&ident).unwrap();
+        assert_eq!(read(&table), expected(&[(4, "d")]));
+        assert_eq!(table.metadata().snapshots.len(), 3);
+        let first = table.metadata().snapshots[0].snapshot_id;
+        let first = table.with_snapshot(SnapshotSelection::Id(first)).unwrap();
+        assert_eq!(read(&first), expected(&[(1, "a"), (2, "b")]));
+
+        assert!(matches!(
+            table.append(
+                rows(&[5], &["e"]),
+                IndexMap::from([("mode".to_string(), LiteralValue::Utf8("x".to_string()))])
+            ),
+            Err(CatalogError::Unsupported { .. })
+        ));
+    }
+
+    #[test]
+    fn test_dynamic_partition_overwrite() {
+        let dir = tempfile::tempdir().unwrap();
+        let catalog = connect(&start_server(writable_state(dir.path(), true)));
+        let table = catalog.get_table(&Identifier::new(["ns", "t"])).unwrap();
+
+        table
+            .append(rows(&[1, 2, 3], &["a", "b", "a"]), IndexMap::new())
+            .unwrap();
+        let dynamic = IndexMap::from([(
+            "partition_overwrite".to_string(),
+            LiteralValue::Utf8("dynamic".to_string()),
+        )]);
+        table.overwrite(rows(&[4], &["a"]), dynamic).unwrap();
+        assert_eq!(read(table.as_ref()), expected(&[(2, "b"), (4, "a")]));
+
+        table
+            .overwrite(rows(&[5], &["c"]), IndexMap::new())
+            .unwrap();
+        assert_eq!(read(table.as_ref()), expected(&[(5, "c")]));
+    }
+
+    #[test]
+    fn test_commit_retries() {
+        let dir = tempfile::tempdir().unwrap();
+        let catalog = connect(&start_server(writable_state(dir.path(), false)));
+        let ident = Identifier::new(["ns", "t"]);
+        let first = catalog.load_table(&ident).unwrap();
+        let second = catalog.load_table(&ident).unwrap();
+
+        // the second table's metadata is stale after the first commit, so its commit conflicts
+        // and is retried on top of the first
+        first.append(rows(&[1], &["a"]), IndexMap::new()).unwrap();
+        second.append(rows(&[2], &["b"]), IndexMap::new()).unwrap();
+        let table = catalog.load_table(&ident).unwrap();
+        assert_eq!(read(&table), expected(&[(1, "a"), (2, "b")]));
+        let snapshots = &table.metadata().snapshots;
+        assert_eq!(
+            snapshots[1].parent_snapshot_id,
+            Some(snapshots[0].snapshot_id)
+        );
+    }
+}
@@ -1 +1,2 @@
+pub mod iceberg_rest;
 pub mod memory;
@@ -1,7 +1,7 @@
 mod bindings;
 mod catalog;
 mod identifier;
-mod impls;
+pub mod impls;
 mod table;
 
 pub use bindings::*;
@@ -12,7 +12,10 @@ use pyo3::{exceptions::PyIndexError, intern, prelude::*, types::PyDict};
 pub use wrappers::{PyCatalogWrapper, PyTableWrapper};
 
 use crate::{
-    impls::memory::{MemoryCatalog, MemoryTable},
+    impls::{
+        iceberg_rest::IcebergRestCatalog,
+        memory::{MemoryCatalog, MemoryTable},
+    },
     Catalog, CatalogRef, Identifier, Table, TableRef, TableSource,
 };
 
@@ -82,6 +85,20 @@ impl PyCatalog {
     fn new_memory_catalog(name: String, py: Python) -> PyResult<PyObject> {
         MemoryCatalog::new(name).to_py(py)
     }
+
+    #[staticmethod]
+    #[pyo3(signature = (name, uri, warehouse=None, token=None, io_config=None))]
+    fn new_iceberg_rest_catalog(
+        name: String,
+        uri: &str,
+        warehouse: Option<&str>,
+        token: Option<String>,
+        io_config: Option<common_io_config::python::IOConfig>,
+        py: Python,
+    ) -> PyResult<PyObject> {
+        let io_config = io_config.map(|c| c.config).unwrap_or_default();
+        IcebergRestCatalog::try_new(name, uri, warehouse, token, io_config)?.to_py(py)
+    }
 }
 
 #[derive(Clone)]
@@ -7,13 +7,17 @@ use async_compression::tokio::bufread::{
 use tokio::io::{AsyncBufRead, AsyncRead};
 use url::Url;
 
-#[derive(Debug)]
+use crate::{decoder::UnitDecoderReader, lz4::Lz4FrameDecoder, snappy::SnappyFrameDecoder};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CompressionCodec {
     Brotli,
     Bz,
     Deflate,
     Gzip,
+    Lz4,
     Lzma,
+    Snappy,
     Xz,
     Zlib,
     Zstd,
@@ -40,26 +44,58 @@ impl CompressionCodec {
     }
     #[must_use]
     pub fn from_extension(extension: &str) -> Option<Self> {
-        use CompressionCodec::{Brotli, Bz, Deflate, Gzip, Lzma, Xz, Zlib, Zstd};
+        use CompressionCodec::{Brotli, Bz, Deflate, Gzip, Lz4, Lzma, Snappy, Xz, Zlib, Zstd};
         match extension {
             "br" => Some(Brotli),
             "bz2" => Some(Bz),
             "deflate" => Some(Deflate),
             "gz" => Some(Gzip),
+            "lz4" => Some(Lz4),
             "lzma" => Some(Lzma),
+            "snappy" | "sz" => Some(Snappy),
             "xz" => Some(Xz),
             "zl" => Some(Zlib),
             "zstd" | "zst" => Some(Zstd),
-            "snappy" => todo!("Snappy compression support not yet implemented"),
             _ => None,
         }
     }
 
+    /// Parses a user-facing codec name such as `gzip` or `snappy`, also accepting file extensions.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        use CompressionCodec::{Brotli, Bz, Gzip, Zlib};
+        match name.to_ascii_lowercase().as_str() {
+            "brotli" => Some(Brotli),
+            "bzip2" => Some(Bz),
+            "gzip" => Some(Gzip),
+            "zlib" => Some(Zlib),
+            extension => Self::from_extension(extension),
+        }
+    }
+
+    /// The file extension written for this codec, without the leading dot.
+    #[must_use]
+    pub fn extension(&self) -> &'static str {
+        use CompressionCodec::{Brotli, Bz, Deflate, Gzip, Lz4, Lzma, Snappy, Xz, Zlib, Zstd};
+        match self {
+            Brotli => "br",
+            Bz => "bz2",
+            Deflate => "deflate",
+            Gzip => "gz",
+            Lz4 => "lz4",
+            Lzma => "lzma",
+            Snappy => "snappy",
+            Xz => "xz",
+            Zlib => "zl",
+            Zstd => "zst",
+        }
+    }
+
     pub fn to_decoder<T: AsyncBufRead + Send + 'static>(
         &self,
         reader: T,
     ) -> Pin<Box<dyn AsyncRead + Send>> {
-        use CompressionCodec::{Brotli, Bz, Deflate, Gzip, Lzma, Xz, Zlib, Zstd};
+        use CompressionCodec::{Brotli, Bz, Deflate, Gzip, Lz4, Lzma, Snappy, Xz, Zlib, Zstd};
         match self {
             Brotli => Box::pin(BrotliDecoder::new(reader)),
             Bz => Box::pin(BzDecoder::new(reader)),
@@ -73,7 +109,9 @@ impl CompressionCodec {
                 decoder.multiple_members(true);
                 Box::pin(decoder)
             }
+            Lz4 => Box::pin(UnitDecoderReader::new(reader, Lz4FrameDecoder::new())),
             Lzma => Box::pin(LzmaDecoder::new(reader)),
+            Snappy => Box::pin(UnitDecoderReader::new(reader, SnappyFrameDecoder::new())),
             Xz => Box::pin(XzDecoder::new(reader)),
             Zlib => Box::pin(ZlibDecoder::new(reader)),
             Zstd => Box::pin(ZstdDecoder::new(reader)),
@@ -0,0 +1,92 @@
+use std::{
+    io,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+
+use tokio::io::{AsyncBufRead, AsyncRead, ReadBuf};
+
+/// A compressed format made up of self-delimiting units (frames, chunks or blocks) that can be
+/// decoded one at a time once all of their bytes are buffered.
+pub(crate) trait UnitDecoder {
+    /// Decodes the unit at the front of `input`, appending its decompressed bytes to `output`.
+    ///
+    /// Returns the number of input bytes consumed, or `None` if `input` does not hold a complete unit yet.
+    fn decode(&mut self, input: &[u8], output: &mut Vec<u8>) -> io::Result<Option<usize>>;
+
+    /// Checks that the stream is allowed to end here, i.e. that it was not truncated mid-frame.
+    fn finish(&self) -> io::Result<()>;
+}
+
+/// Adapts a [`UnitDecoder`] into a streaming [`AsyncRead`] over a compressed [`AsyncBufRead`].
+pub(crate) struct UnitDecoderReader<R, D> {
+    reader: Pin<Box<R>>,
+    decoder: D,
+    input: Vec<u8>,
+    output: Vec<u8>,
+    position: usize,
+    done: bool,
+}
+
+impl<R, D> UnitDecoderReader<R, D> {
+    pub(crate) fn new(reader: R, decoder: D) -> Self {
+        Self {
+            reader: Box::pin(reader),
+            decoder,
+            input: Vec::new(),
+            output: Vec::new(),
+            position: 0,
+            done: false,
+        }
+    }
+}
+
+impl<R: AsyncBufRead, D: UnitDecoder + Unpin> AsyncRead for UnitDecoderReader<R, D> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        loop {
+            // Serve any bytes left over from the last decoded unit first.
+            if this.position < this.output.len() {
+                let len = buf.remaining().min(this.output.len() - this.position);
+                buf.put_slice(&this.output[this.position..this.position + len]);
+                this.position += len;
+                return Poll::Ready(Ok(()));
+            }
+            if this.done {
+                return Poll::Ready(Ok(()));
+            }
+            this.output.clear();
+            this.position = 0;
+            if let Some(consumed) = this.decoder.decode(&this.input, &mut this.output)? {
+                this.input.drain(..consumed);
+                continue;
+            }
+            let available = ready!(this.reader.as_mut().poll_fill_buf(cx))?;
+            if available.is_empty() {
+                if !this.input.is_empty() {
+                    return Poll::Ready(Err(io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        "compressed stream ended in the middle of a block",
+                    )));
+                }
+                this.decoder.finish()?;
+                this.done = true;
+                continue;
+            }
+            let len = available.len();
+            this.input.extend_from_slice(available);
+            this.reader.as_mut().consume(len);
+        }
+    }
+}
+
+pub(crate) fn invalid_data<E>(error: E) -> io::Error
+where
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    io::Error::new(io::ErrorKind::InvalidData, error)
+}
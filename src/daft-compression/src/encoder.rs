@@ -0,0 +1,143 @@
+use std::io::{self, Write};
+
+use bzip2::write::BzEncoder;
+use flate2::write::{DeflateEncoder, GzEncoder, ZlibEncoder};
+use xz2::{
+    stream::{LzmaOptions, Stream},
+    write::XzEncoder,
+};
+
+use crate::CompressionCodec;
+
+/// Preset used for both the xz and lzma encoders, matching the `xz` command line default.
+const XZ_PRESET: u32 = 6;
+
+/// A synchronous writer which compresses everything written to it.
+///
+/// [`CompressedWriter::finish`] must be called once all data is written so that the codec can
+/// write its trailer, dropping the writer may leave a truncated stream behind.
+pub enum CompressedWriter<W: Write> {
+    Uncompressed(W),
+    Bz(BzEncoder<W>),
+    Deflate(DeflateEncoder<W>),
+    Gzip(GzEncoder<W>),
+    Lz4(lz4_flex::frame::FrameEncoder<W>),
+    Lzma(XzEncoder<W>),
+    Snappy(Box<snap::write::FrameEncoder<W>>),
+    Xz(XzEncoder<W>),
+    Zlib(ZlibEncoder<W>),
+    Zstd(zstd::Encoder<'static, W>),
+}
+
+impl<W: Write> CompressedWriter<W> {
+    /// Wraps `writer` so that writes are compressed with `codec`, or passed through if there is none.
+    pub fn try_new(writer: W, codec: Option<CompressionCodec>) -> io::Result<Self> {
+        use flate2::Compression;
+        let Some(codec) = codec else {
+            return Ok(Self::Uncompressed(writer));
+        };
+        Ok(match codec {
+            CompressionCodec::Brotli => {
+                return Err(io::Error::new(
+                    io::ErrorKind::Unsupported,
+                    "brotli compression is not supported for writes",
+                ));
+            }
+            CompressionCodec::Bz => Self::Bz(BzEncoder::new(writer, bzip2::Compression::default())),
+            CompressionCodec::Deflate => {
+                Self::Deflate(DeflateEncoder::new(writer, Compression::default()))
+            }
+            CompressionCodec::Gzip => Self::Gzip(GzEncoder::new(writer, Compression::default())),
+            CompressionCodec::Lz4 => Self::Lz4(lz4_flex::frame::FrameEncoder::new(writer)),
+            CompressionCodec::Lzma => {
+                let stream = Stream::new_lzma_encoder(&LzmaOptions::new_preset(XZ_PRESET)?)?;
+                Self::Lzma(XzEncoder::new_stream(writer, stream))
+            }
+            CompressionCodec::Snappy => {
+                Self::Snappy(Box::new(snap::write::FrameEncoder::new(writer)))
+            }
+            CompressionCodec::Xz => Self::Xz(XzEncoder::new(writer, XZ_PRESET)),
+            CompressionCodec::Zlib => Self::Zlib(ZlibEncoder::new(writer, Compression::default())),
+            CompressionCodec::Zstd => {
+                Self::Zstd(zstd::Encoder::new(writer, zstd::DEFAULT_COMPRESSION_LEVEL)?)
+            }
+        })
+    }
+
+    /// Writes the codec's trailer and returns the underlying writer.
+    pub fn finish(self) -> io::Result<W> {
+        match self {
+            Self::Uncompressed(writer) => Ok(writer),
+            Self::Bz(encoder) => encoder.finish(),
+            Self::Deflate(encoder) => encoder.finish(),
+            Self::Gzip(encoder) => encoder.finish(),
+            Self::Lz4(encoder) => Ok(encoder.finish()?),
+            Self::Lzma(encoder) | Self::Xz(encoder) => encoder.finish(),
+            Self::Snappy(encoder) => encoder.into_inner().map_err(|e| e.into_error()),
+            Self::Zlib(encoder) => encoder.finish(),
+            Self::Zstd(encoder) => encoder.finish(),
+        }
+    }
+
+    fn inner(&mut self) -> &mut dyn Write {
+        match self {
+            Self::Uncompressed(writer) => writer,
+            Self::Bz(encoder) => encoder,
+            Self::Deflate(encoder) => encoder,
+            Self::Gzip(encoder) => encoder,
+            Self::Lz4(encoder) => encoder,
+            Self::Lzma(encoder) | Self::Xz(encoder) => encoder,
+            Self::Snappy(encoder) => encoder.as_mut(),
+            Self::Zlib(encoder) => encoder,
+            Self::Zstd(encoder) => encoder,
+        }
+    }
+}
+
+impl<W: Write> Write for CompressedWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.inner().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner().flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use tokio::io::AsyncReadExt;
+
+    use super::CompressedWriter;
+    use crate::CompressionCodec;
+
+    #[tokio::test]
+    async fn test_round_trip_through_decoder() {
+        let data: Vec<u8> = (0..100_000u32)
+            .flat_map(|i| (i % 97).to_le_bytes())
+            .collect();
+        for codec in [
+            CompressionCodec::Bz,
+            CompressionCodec::Deflate,
+            CompressionCodec::Gzip,
+            CompressionCodec::Lz4,
+            CompressionCodec::Lzma,
+            CompressionCodec::Snappy,
+            CompressionCodec::Xz,
+            CompressionCodec::Zlib,
+            CompressionCodec::Zstd,
+        ] {
+            let mut writer = CompressedWriter::try_new(vec![], Some(codec)).unwrap();
+            writer.write_all(&data).unwrap();
+            let compressed = writer.finish().unwrap();
+            assert_ne!(compressed, data);
+
+            let mut decoder = codec.to_decoder(std::io::Cursor::new(compressed));
+            let mut output = vec![];
+            decoder.read_to_end(&mut output).await.unwrap();
+            assert_eq!(output, data, "{codec:?}");
+        }
+    }
+}
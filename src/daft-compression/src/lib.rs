@@ -1,4 +1,9 @@
-//! Utilities for async decompression of data.
+//! Utilities for async decompression of data, and compression of written data.
 pub mod compression;
+mod decoder;
+mod encoder;
+mod lz4;
+mod snappy;
 
 pub use compression::CompressionCodec;
+pub use encoder::CompressedWriter;
@@ -0,0 +1,258 @@
+//! Decoder for the LZ4 frame format, see <https://github.com/lz4/lz4/blob/dev/doc/lz4_Frame_format.md>.
+use std::{hash::Hasher, io, ops::RangeInclusive};
+
+use twox_hash::XxHash32;
+
+use crate::decoder::{invalid_data, UnitDecoder};
+
+const MAGIC: u32 = 0x184D_2204;
+const SKIPPABLE_MAGIC: RangeInclusive<u32> = 0x184D_2A50..=0x184D_2A5F;
+const LEGACY_MAGIC: u32 = 0x184C_2102;
+/// Linked blocks may reference up to 64KiB of previously decoded data.
+const WINDOW_SIZE: usize = 64 * 1024;
+
+/// The settings of the frame currently being decoded, taken from its descriptor.
+struct Frame {
+    independent_blocks: bool,
+    block_checksums: bool,
+    content_checksum: Option<XxHash32>,
+    content_size: Option<u64>,
+    max_block_size: usize,
+    decoded: u64,
+}
+
+/// Decodes a stream of LZ4 frames block by block.
+pub(crate) struct Lz4FrameDecoder {
+    frame: Option<Frame>,
+    window: Vec<u8>,
+}
+
+impl Lz4FrameDecoder {
+    pub(crate) fn new() -> Self {
+        Self {
+            frame: None,
+            window: Vec::new(),
+        }
+    }
+
+    fn decode_header(&mut self, input: &[u8]) -> io::Result<Option<usize>> {
+        let Some(magic) = read_u32(input, 0) else {
+            return Ok(None);
+        };
+        if SKIPPABLE_MAGIC.contains(&magic) {
+            let Some(len) = read_u32(input, 4) else {
+                return Ok(None);
+            };
+            let frame_len = 8 + len as usize;
+            return Ok((input.len() >= frame_len).then_some(frame_len));
+        }
+        if magic == LEGACY_MAGIC {
+            return Err(invalid_data("legacy LZ4 frames are not supported"));
+        }
+        if magic != MAGIC {
+            return Err(invalid_data("invalid LZ4 frame magic number"));
+        }
+        let (Some(&flags), Some(&block_descriptor)) = (input.get(4), input.get(5)) else {
+            return Ok(None);
+        };
+        if flags >> 6 != 1 {
+            return Err(invalid_data("unsupported LZ4 frame version"));
+        }
+        if flags & 0x02 != 0 || block_descriptor & 0x8f != 0 {
+            return Err(invalid_data("reserved LZ4 frame descriptor bits are set"));
+        }
+        if flags & 0x01 != 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "LZ4 frames with a dictionary ID are not supported",
+            ));
+        }
+        let has_content_size = flags & 0x08 != 0;
+        let header_len = 7 + if has_content_size { 8 } else { 0 };
+        if input.len() < header_len {
+            return Ok(None);
+        }
+        let header_checksum = (XxHash32::oneshot(0, &input[4..header_len - 1]) >> 8) as u8;
+        if header_checksum != input[header_len - 1] {
+            return Err(invalid_data("LZ4 frame header checksum mismatch"));
+        }
+        let max_block_size = match (block_descriptor >> 4) & 0x07 {
+            4 => 64 * 1024,
+            5 => 256 * 1024,
+            6 => 1024 * 1024,
+            7 => 4 * 1024 * 1024,
+            _ => return Err(invalid_data("invalid LZ4 block maximum size")),
+        };
+        self.frame = Some(Frame {
+            independent_blocks: flags & 0x20 != 0,
+            block_checksums: flags & 0x10 != 0,
+            content_checksum: (flags & 0x04 != 0).then(XxHash32::default),
+            content_size: has_content_size
+                .then(|| u64::from_le_bytes(input[6..14].try_into().unwrap())),
+            max_block_size,
+            decoded: 0,
+        });
+        self.window.clear();
+        Ok(Some(header_len))
+    }
+}
+
+impl UnitDecoder for Lz4FrameDecoder {
+    fn decode(&mut self, input: &[u8], output: &mut Vec<u8>) -> io::Result<Option<usize>> {
+        let Some(frame) = &mut self.frame else {
+            return self.decode_header(input);
+        };
+        let Some(block_header) = read_u32(input, 0) else {
+            return Ok(None);
+        };
+
+        // A zero block size marks the end of the frame.
+        if block_header == 0 {
+            let Some(expected) = frame.content_checksum.as_ref() else {
+                self.frame = None;
+                return Ok(Some(4));
+            };
+            let Some(checksum) = read_u32(input, 4) else {
+                return Ok(None);
+            };
+            if checksum != expected.finish_32() {
+                return Err(invalid_data("LZ4 frame content checksum mismatch"));
+            }
+            if frame
+                .content_size
+                .is_some_and(|content_size| content_size != frame.decoded)
+            {
+                return Err(invalid_data("LZ4 frame content size mismatch"));
+            }
+            self.frame = None;
+            return Ok(Some(8));
+        }
+
+        let is_uncompressed = block_header & 0x8000_0000 != 0;
+        let block_len = (block_header & 0x7fff_ffff) as usize;
+        if block_len > frame.max_block_size {
+            return Err(invalid_data(
+                "LZ4 block exceeds the frame's maximum block size",
+            ));
+        }
+        let checksum_len = if frame.block_checksums { 4 } else { 0 };
+        if input.len() < 4 + block_len + checksum_len {
+            return Ok(None);
+        }
+        let block = &input[4..4 + block_len];
+        if frame.block_checksums
+            && read_u32(input, 4 + block_len) != Some(XxHash32::oneshot(0, block))
+        {
+            return Err(invalid_data("LZ4 block checksum mismatch"));
+        }
+
+        let start = output.len();
+        if is_uncompressed {
+            output.extend_from_slice(block);
+        } else {
+            output.resize(start + frame.max_block_size, 0);
+            let len = if frame.independent_blocks {
+                lz4_flex::block::decompress_into(block, &mut output[start..])
+            } else {
+                lz4_flex::block::decompress_into_with_dict(
+                    block,
+                    &mut output[start..],
+                    &self.window,
+                )
+            }
+            .map_err(invalid_data)?;
+            output.truncate(start + len);
+        }
+        let decoded = &output[start..];
+        frame.decoded += decoded.len() as u64;
+        if let Some(hasher) = &mut frame.content_checksum {
+            hasher.write(decoded);
+        }
+        if !frame.independent_blocks {
+            self.window.extend_from_slice(decoded);
+            if self.window.len() > WINDOW_SIZE {
+                self.window.drain(..self.window.len() - WINDOW_SIZE);
+            }
+        }
+        Ok(Some(4 + block_len + checksum_len))
+    }
+
+    fn finish(&self) -> io::Result<()> {
+        if self.frame.is_some() {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "LZ4 stream ended before the end of its frame",
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn read_u32(input: &[u8], offset: usize) -> Option<u32> {
+    let bytes = input.get(offset..offset + 4)?;
+    Some(u32::from_le_bytes(bytes.try_into().unwrap()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use lz4_flex::frame::{BlockMode, BlockSize, FrameEncoder, FrameInfo};
+    use tokio::io::{AsyncReadExt, BufReader};
+
+    use crate::CompressionCodec;
+
+    fn compress(data: &[u8], frame_info: FrameInfo) -> Vec<u8> {
+        let mut encoder = FrameEncoder::with_frame_info(frame_info, vec![]);
+        encoder.write_all(data).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    async fn decompress(data: Vec<u8>) -> std::io::Result<Vec<u8>> {
+        let reader = BufReader::with_capacity(13, std::io::Cursor::new(data));
+        let mut decoder = CompressionCodec::Lz4.to_decoder(reader);
+        let mut output = vec![];
+        decoder.read_to_end(&mut output).await?;
+        Ok(output)
+    }
+
+    #[tokio::test]
+    async fn test_round_trip() {
+        let data: Vec<u8> = (0..200_000u32)
+            .flat_map(|i| (i % 251).to_le_bytes())
+            .collect();
+        for block_mode in [BlockMode::Independent, BlockMode::Linked] {
+            let frame_info = FrameInfo::new()
+                .block_size(BlockSize::Max64KB)
+                .block_mode(block_mode)
+                .block_checksums(true)
+                .content_checksum(true)
+                .content_size(Some(data.len() as u64));
+            assert_eq!(decompress(compress(&data, frame_info)).await.unwrap(), data);
+        }
+        assert!(decompress(vec![]).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_concatenated_and_skippable_frames() {
+        let mut data = compress(b"hello, ", FrameInfo::new());
+        data.extend(0x184D_2A50u32.to_le_bytes());
+        data.extend(3u32.to_le_bytes());
+        data.extend(b"abc");
+        data.extend(compress(b"world", FrameInfo::new()));
+        assert_eq!(decompress(data).await.unwrap(), b"hello, world");
+    }
+
+    #[tokio::test]
+    async fn test_corrupt_and_truncated_frames() {
+        let frame_info = FrameInfo::new().content_checksum(true);
+        let mut data = compress(b"some bytes to checksum", frame_info.clone());
+        let last = data.len() - 1;
+        data[last] ^= 0xff;
+        assert!(decompress(data).await.is_err());
+
+        let mut data = compress(b"some bytes to truncate", frame_info);
+        data.truncate(data.len() - 8);
+        assert!(decompress(data).await.is_err());
+    }
+}
@@ -0,0 +1,146 @@
+//! Decoder for the Snappy framing format, see <https://github.com/google/snappy/blob/main/framing_format.txt>.
+use std::io;
+
+use crc::{Crc, CRC_32_ISCSI};
+
+use crate::decoder::{invalid_data, UnitDecoder};
+
+const STREAM_IDENTIFIER: &[u8] = b"sNaPpY";
+const MAX_UNCOMPRESSED_CHUNK_LEN: usize = 65536;
+const CASTAGNOLI: Crc<u32> = Crc::<u32>::new(&CRC_32_ISCSI);
+
+/// Decodes a Snappy framed stream chunk by chunk.
+pub(crate) struct SnappyFrameDecoder {
+    decoder: snap::raw::Decoder,
+    started: bool,
+}
+
+impl SnappyFrameDecoder {
+    pub(crate) fn new() -> Self {
+        Self {
+            decoder: snap::raw::Decoder::new(),
+            started: false,
+        }
+    }
+}
+
+impl UnitDecoder for SnappyFrameDecoder {
+    fn decode(&mut self, input: &[u8], output: &mut Vec<u8>) -> io::Result<Option<usize>> {
+        let Some(header) = input.get(..4) else {
+            return Ok(None);
+        };
+        let chunk_type = header[0];
+        let len = u32::from_le_bytes([header[1], header[2], header[3], 0]) as usize;
+        let Some(body) = input.get(4..4 + len) else {
+            return Ok(None);
+        };
+        match chunk_type {
+            // Streams may be concatenated, so the identifier can appear again mid-stream.
+            0xff => {
+                if body != STREAM_IDENTIFIER {
+                    return Err(invalid_data("invalid snappy stream identifier"));
+                }
+                self.started = true;
+            }
+            _ if !self.started => {
+                return Err(invalid_data(
+                    "snappy stream does not start with a stream identifier",
+                ));
+            }
+            0x00 | 0x01 => {
+                if body.len() < 4 {
+                    return Err(invalid_data("snappy chunk is too short for its checksum"));
+                }
+                let (checksum, data) = body.split_at(4);
+                let start = output.len();
+                if chunk_type == 0x00 {
+                    let decompressed_len = snap::raw::decompress_len(data).map_err(invalid_data)?;
+                    if decompressed_len > MAX_UNCOMPRESSED_CHUNK_LEN {
+                        return Err(invalid_data("snappy chunk exceeds the maximum chunk size"));
+                    }
+                    output.resize(start + decompressed_len, 0);
+                    self.decoder
+                        .decompress(data, &mut output[start..])
+                        .map_err(invalid_data)?;
+                } else {
+                    if data.len() > MAX_UNCOMPRESSED_CHUNK_LEN {
+                        return Err(invalid_data("snappy chunk exceeds the maximum chunk size"));
+                    }
+                    output.extend_from_slice(data);
+                }
+                let expected = u32::from_le_bytes(checksum.try_into().unwrap());
+                if masked_crc32c(&output[start..]) != expected {
+                    return Err(invalid_data("snappy chunk checksum mismatch"));
+                }
+            }
+            0x02..=0x7f => {
+                return Err(invalid_data(format!(
+                    "unskippable reserved snappy chunk type {chunk_type:#04x}"
+                )));
+            }
+            // Skippable chunks and padding.
+            _ => {}
+        }
+        Ok(Some(4 + len))
+    }
+
+    fn finish(&self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+fn masked_crc32c(data: &[u8]) -> u32 {
+    let crc = CASTAGNOLI.checksum(data);
+    crc.rotate_right(15).wrapping_add(0xa282_ead8)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use tokio::io::{AsyncReadExt, BufReader};
+
+    use crate::CompressionCodec;
+
+    fn compress(data: &[u8]) -> Vec<u8> {
+        let mut encoder = snap::write::FrameEncoder::new(vec![]);
+        encoder.write_all(data).unwrap();
+        encoder.into_inner().unwrap()
+    }
+
+    async fn decompress(data: Vec<u8>) -> std::io::Result<Vec<u8>> {
+        let reader = BufReader::with_capacity(7, std::io::Cursor::new(data));
+        let mut decoder = CompressionCodec::Snappy.to_decoder(reader);
+        let mut output = vec![];
+        decoder.read_to_end(&mut output).await?;
+        Ok(output)
+    }
+
+    #[tokio::test]
+    async fn test_round_trip() {
+        let data: Vec<u8> = (0..200_000u32)
+            .flat_map(|i| (i % 251).to_le_bytes())
+            .collect();
+        assert_eq!(decompress(compress(&data)).await.unwrap(), data);
+        assert!(decompress(vec![]).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_concatenated_streams() {
+        let mut data = compress(b"hello, ");
+        data.extend(compress(b"world"));
+        assert_eq!(decompress(data).await.unwrap(), b"hello, world");
+    }
+
+    #[tokio::test]
+    async fn test_corrupt_and_truncated_streams() {
+        let mut data = compress(b"some bytes to checksum");
+        let last = data.len() - 1;
+        data[last] ^= 0xff;
+        assert!(decompress(data).await.is_err());
+
+        let mut data = compress(b"some bytes to truncate");
+        data.pop();
+        assert!(decompress(data).await.is_err());
+    }
+}
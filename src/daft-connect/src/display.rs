@@ -116,6 +116,7 @@ fn type_to_string(dtype: &DataType) -> String {
         DataType::FixedShapeTensor(_, _) => "daft.fixed_shape_tensor",
         DataType::SparseTensor(_, _) => "daft.sparse_tensor",
         DataType::FixedShapeSparseTensor(_, _, _) => "daft.fixed_shape_sparse_tensor",
+        DataType::Geometry => "daft.geometry",
         DataType::Python => "daft.python",
         DataType::Unknown => "unknown",
         DataType::UInt8 => "arrow.uint8",
@@ -1,5 +1,6 @@
 use daft_core::count_mode::CountMode;
 use daft_dsl::unresolved_col;
+use daft_functions::grouping::{Grouping, GroupingId};
 use daft_schema::dtype::DataType;
 use spark_connect::Expression;
 
@@ -18,6 +19,8 @@ impl FunctionModule for AggregateFunctions {
         parent.add_fn("min", UnaryFunction(|arg| arg.min()));
         parent.add_fn("max", UnaryFunction(|arg| arg.max()));
         parent.add_fn("sum", UnaryFunction(|arg| arg.sum()));
+        parent.add_fn("grouping", Grouping);
+        parent.add_fn("grouping_id", GroupingId);
     }
 }
 
@@ -9,7 +9,7 @@ use arrow2::io::ipc::read::{read_stream_metadata, StreamReader, StreamState};
 use daft_core::series::Series;
 use daft_dsl::unresolved_col;
 use daft_logical_plan::{
-    ops::{SetQuantifier, UnionStrategy},
+    ops::{cube_grouping_sets, rollup_grouping_sets, SetQuantifier, UnionStrategy},
     JoinType, LogicalPlanBuilder, PyLogicalPlanBuilder,
 };
 use daft_micropartition::{self, python::PyMicroPartition, MicroPartition};
@@ -445,28 +445,6 @@ impl SparkAnalyzer<'_> {
         &self,
         aggregate: spark_connect::Aggregate,
     ) -> ConnectResult<LogicalPlanBuilder> {
-        fn check_grouptype(group_type: GroupType) -> ConnectResult<()> {
-            match group_type {
-                GroupType::Groupby => {}
-                GroupType::Unspecified => {
-                    invalid_argument_err!("GroupType must be specified; got Unspecified");
-                }
-                GroupType::Rollup => {
-                    not_yet_implemented!("GroupType.Rollup not yet supported");
-                }
-                GroupType::Cube => {
-                    not_yet_implemented!("GroupType.Cube");
-                }
-                GroupType::Pivot => {
-                    not_yet_implemented!("GroupType.Pivot");
-                }
-                GroupType::GroupingSets => {
-                    not_yet_implemented!("GroupType.GroupingSets");
-                }
-            }
-            Ok(())
-        }
-
         let spark_connect::Aggregate {
             input,
             group_type,
@@ -482,17 +460,11 @@ impl SparkAnalyzer<'_> {
 
         let group_type = GroupType::try_from(group_type).wrap_err("Invalid group type")?;
 
-        check_grouptype(group_type)?;
-
         if let Some(pivot) = pivot {
             not_yet_implemented!("Pivot not yet supported; got {pivot:?}");
         }
 
-        if !grouping_sets.is_empty() {
-            not_yet_implemented!("Grouping sets not yet supported; got {grouping_sets:?}");
-        }
-
-        let grouping_expressions: Vec<_> = grouping_expressions
+        let mut grouping_expressions: Vec<_> = grouping_expressions
             .iter()
             .map(analyze_expr)
             .try_collect()?;
@@ -502,7 +474,46 @@ impl SparkAnalyzer<'_> {
             .map(analyze_expr)
             .try_collect()?;
 
-        plan = plan.aggregate(aggregate_expressions, grouping_expressions)?;
+        let grouping_sets = match group_type {
+            GroupType::Groupby => None,
+            GroupType::Unspecified => {
+                invalid_argument_err!("GroupType must be specified; got Unspecified");
+            }
+            GroupType::Pivot => {
+                not_yet_implemented!("GroupType.Pivot");
+            }
+            GroupType::Rollup => Some(rollup_grouping_sets(grouping_expressions.len())),
+            GroupType::Cube => Some(cube_grouping_sets(grouping_expressions.len())),
+            GroupType::GroupingSets => Some(
+                grouping_sets
+                    .iter()
+                    .map(|set| {
+                        set.grouping_set
+                            .iter()
+                            .map(|expr| {
+                                let expr = analyze_expr(expr)?;
+                                Ok(match grouping_expressions.iter().position(|e| *e == expr) {
+                                    Some(i) => i,
+                                    None => {
+                                        grouping_expressions.push(expr);
+                                        grouping_expressions.len() - 1
+                                    }
+                                })
+                            })
+                            .collect::<ConnectResult<Vec<_>>>()
+                    })
+                    .try_collect()?,
+            ),
+        };
+
+        plan = match grouping_sets {
+            Some(grouping_sets) => plan.aggregate_grouping_sets(
+                aggregate_expressions,
+                grouping_expressions,
+                grouping_sets,
+            )?,
+            None => plan.aggregate(aggregate_expressions, grouping_expressions)?,
+        };
 
         Ok(plan)
     }
@@ -0,0 +1,245 @@
+//! A small DataFrame API for using Daft as a Rust library, without python.
+//!
+//! ```ignore
+//! let df = DataFrame::from_record_batches(schema, batches)?
+//!     .filter(resolved_col("x").gt(lit(1)))?
+//!     .select(vec![resolved_col("x"), resolved_col("y")])?;
+//! let batches = df.collect()?;
+//! ```
+
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use daft_core::prelude::{JoinType, SchemaRef};
+use daft_dsl::ExprRef;
+use daft_logical_plan::{JoinOptions, LogicalPlanBuilder};
+use daft_micropartition::{
+    partitioning::{MicroPartitionSet, PartitionSet},
+    MicroPartition, MicroPartitionRef,
+};
+use daft_recordbatch::RecordBatch;
+use futures::{Stream, TryStreamExt};
+
+use crate::get_context;
+
+/// A lazily evaluated table, which is run on the [`LocalRunner`](crate::LocalRunner) of the
+/// global [`DaftContext`](crate::DaftContext) when its results are requested.
+#[derive(Debug, Clone)]
+pub struct DataFrame {
+    builder: LogicalPlanBuilder,
+}
+
+impl From<LogicalPlanBuilder> for DataFrame {
+    fn from(builder: LogicalPlanBuilder) -> Self {
+        Self { builder }
+    }
+}
+
+impl DataFrame {
+    /// Creates a DataFrame of a single partition with the record batches, which must all have the
+    /// given schema.
+    pub fn from_record_batches(schema: SchemaRef, batches: Vec<RecordBatch>) -> DaftResult<Self> {
+        let part = MicroPartition::new_loaded(schema.clone(), Arc::new(batches), None);
+        Self::from_micropartitions(schema, vec![part])
+    }
+
+    /// Creates a DataFrame with a partition for each of the micropartitions, which must all have
+    /// the given schema.
+    pub fn from_micropartitions(schema: SchemaRef, parts: Vec<MicroPartition>) -> DaftResult<Self> {
+        let pset = MicroPartitionSet::empty();
+        for (i, part) in parts.into_iter().enumerate() {
+            pset.set_partition(i, &Arc::new(part))?;
+        }
+        let pset = Arc::new(pset);
+        let runner = get_context().get_or_create_local_runner()?;
+        let cache_entry = runner.put_partition_set_into_cache(pset.clone());
+        let builder = LogicalPlanBuilder::in_memory_scan(
+            &cache_entry.key(),
+            cache_entry,
+            schema,
+            pset.num_partitions(),
+            pset.size_bytes()?,
+            pset.metadata().num_rows,
+        )?;
+        Ok(builder.into())
+    }
+
+    pub fn builder(&self) -> &LogicalPlanBuilder {
+        &self.builder
+    }
+
+    pub fn schema(&self) -> SchemaRef {
+        self.builder.schema()
+    }
+
+    /// Returns the unoptimized logical plan as text.
+    pub fn explain(&self) -> String {
+        self.builder.repr_ascii(false)
+    }
+
+    pub fn select(&self, columns: Vec<ExprRef>) -> DaftResult<Self> {
+        self.builder.select(columns).map(Into::into)
+    }
+
+    pub fn with_columns(&self, columns: Vec<ExprRef>) -> DaftResult<Self> {
+        self.builder.with_columns(columns).map(Into::into)
+    }
+
+    pub fn exclude(&self, names: Vec<String>) -> DaftResult<Self> {
+        self.builder.exclude(names).map(Into::into)
+    }
+
+    pub fn filter(&self, predicate: ExprRef) -> DaftResult<Self> {
+        self.builder.filter(predicate).map(Into::into)
+    }
+
+    pub fn limit(&self, limit: u64) -> DaftResult<Self> {
+        self.builder.limit(limit, false).map(Into::into)
+    }
+
+    /// Sorts by the columns, where nulls come first when descending like the python API.
+    pub fn sort(&self, by: Vec<ExprRef>, descending: Vec<bool>) -> DaftResult<Self> {
+        let nulls_first = descending.clone();
+        self.builder
+            .sort(by, descending, nulls_first)
+            .map(Into::into)
+    }
+
+    pub fn distinct(&self) -> DaftResult<Self> {
+        self.builder.distinct(None).map(Into::into)
+    }
+
+    /// Aggregates the whole DataFrame, or each group if there are `group_by` columns.
+    pub fn aggregate(&self, aggs: Vec<ExprRef>, group_by: Vec<ExprRef>) -> DaftResult<Self> {
+        self.builder.aggregate(aggs, group_by).map(Into::into)
+    }
+
+    /// Joins on pairs of left and right keys, where keys of the same column name on both sides are
+    /// merged into a single output column like the python API.
+    pub fn join(
+        &self,
+        right: &Self,
+        left_on: Vec<ExprRef>,
+        right_on: Vec<ExprRef>,
+        how: JoinType,
+    ) -> DaftResult<Self> {
+        self.builder
+            .join_on_keys(
+                &right.builder,
+                left_on,
+                right_on,
+                how,
+                None,
+                JoinOptions::default(),
+            )
+            .map(Into::into)
+    }
+
+    pub fn concat(&self, other: &Self) -> DaftResult<Self> {
+        self.builder.concat(&other.builder).map(Into::into)
+    }
+
+    /// Runs the DataFrame, returning an iterator of the resulting partitions.
+    ///
+    /// **Important**: This must not be called from within a tokio runtime, use `stream` instead.
+    pub fn iter_partitions(
+        &self,
+    ) -> DaftResult<impl Iterator<Item = DaftResult<MicroPartitionRef>>> {
+        let ctx = get_context();
+        let runner = ctx.get_or_create_local_runner()?;
+        runner.run_iter(&self.builder, ctx.execution_config(), None)
+    }
+
+    /// Runs the DataFrame, returning a stream of the resulting partitions.
+    pub async fn stream(&self) -> DaftResult<impl Stream<Item = DaftResult<MicroPartitionRef>>> {
+        let ctx = get_context();
+        let runner = ctx.get_or_create_local_runner()?;
+        runner
+            .run_stream(&self.builder, ctx.execution_config(), None)
+            .await
+    }
+
+    /// Runs the DataFrame, returning all of the resulting record batches.
+    ///
+    /// **Important**: This must not be called from within a tokio runtime, use `collect_async`
+    /// instead.
+    pub fn collect(&self) -> DaftResult<Vec<RecordBatch>> {
+        let mut batches = vec![];
+        for part in self.iter_partitions()? {
+            batches.extend(part?.get_tables()?.iter().cloned());
+        }
+        Ok(batches)
+    }
+
+    /// Async equivalent of `collect`.
+    pub async fn collect_async(&self) -> DaftResult<Vec<RecordBatch>> {
+        let parts = self.stream().await?.try_collect::<Vec<_>>().await?;
+        let mut batches = vec![];
+        for part in parts {
+            batches.extend(part.get_tables()?.iter().cloned());
+        }
+        Ok(batches)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use daft_core::prelude::*;
+    use daft_dsl::{lit, unresolved_col};
+
+    use super::*;
+
+    fn dataframe() -> DaftResult<DataFrame> {
+        let batch = RecordBatch::from_nonempty_columns(vec![
+            Int64Array::from(("x", vec![3, 1, 2, 4])).into_series(),
+            Utf8Array::from_values("y", ["a", "b", "a", "a"].iter()).into_series(),
+        ])?;
+        DataFrame::from_record_batches(batch.schema.clone(), vec![batch])
+    }
+
+    fn collect(df: &DataFrame) -> DaftResult<RecordBatch> {
+        let batches = df.collect()?;
+        RecordBatch::concat(&batches.iter().collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn test_dataframe() -> DaftResult<()> {
+        let df = dataframe()?
+            .filter(unresolved_col("x").gt(lit(1)))?
+            .with_columns(vec![unresolved_col("x").mul(lit(10)).alias("z")])?
+            .sort(vec![unresolved_col("x")], vec![true])?;
+        let result = collect(&df)?;
+        assert_eq!(result.schema.names(), ["x", "y", "z"]);
+        let z = result.get_column(2).i64()?.as_slice().to_vec();
+        assert_eq!(z, [40, 30, 20]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_dataframe_aggregate_and_join() -> DaftResult<()> {
+        let sums = dataframe()?.aggregate(
+            vec![unresolved_col("x").sum().alias("total")],
+            vec![unresolved_col("y")],
+        )?;
+        let df = dataframe()?
+            .join(
+                &sums,
+                vec![unresolved_col("y")],
+                vec![unresolved_col("y")],
+                JoinType::Inner,
+            )?
+            .sort(vec![unresolved_col("x")], vec![false])?;
+        let result = collect(&df)?;
+        let totals = result.get_column(2).i64()?.as_slice().to_vec();
+        assert_eq!(totals, [1, 9, 9, 9]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_dataframe_stream() -> DaftResult<()> {
+        let df = dataframe()?.limit(2)?;
+        let batches = df.collect_async().await?;
+        assert_eq!(batches.iter().map(RecordBatch::len).sum::<usize>(), 2);
+        Ok(())
+    }
+}
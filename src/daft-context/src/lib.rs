@@ -3,16 +3,21 @@ use std::sync::{Arc, OnceLock, RwLock};
 
 use common_daft_config::{DaftExecutionConfig, DaftPlanningConfig, IOConfig};
 use common_error::{DaftError, DaftResult};
+use daft_py_runners::Runner;
 #[cfg(feature = "python")]
-use daft_py_runners::{NativeRunner, RayRunner};
-use daft_py_runners::{Runner, RunnerConfig};
+use daft_py_runners::{NativeRunner, RayRunner, RunnerConfig};
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 
 #[cfg(feature = "python")]
 mod python;
 
+pub mod dataframe;
 pub mod partition_cache;
+pub mod runner;
+
+pub use dataframe::DataFrame;
+pub use runner::LocalRunner;
 
 #[derive(Debug)]
 struct ContextState {
@@ -30,6 +35,8 @@ struct ContextState {
     /// ray -> native
     /// ray -> py
     runner: Option<Arc<Runner>>,
+    /// The pure-Rust runner, which is created on first use.
+    local_runner: Option<Arc<LocalRunner>>,
 }
 
 #[derive(Debug, Default)]
@@ -67,10 +74,17 @@ impl ContextState {
     }
 }
 
-#[cfg(not(feature = "python"))]
 impl ContextState {
-    fn get_or_create_runner(&mut self) -> DaftResult<Arc<Runner>> {
-        unimplemented!()
+    /// Retrieves the pure-Rust runner, creating it if it has not yet been created.
+    fn get_or_create_local_runner(&mut self) -> DaftResult<Arc<LocalRunner>> {
+        if let Some(runner) = self.local_runner.as_ref() {
+            return Ok(runner.clone());
+        }
+
+        let runner = Arc::new(LocalRunner::try_new(None)?);
+        self.local_runner = Some(runner.clone());
+
+        Ok(runner)
     }
 }
 
@@ -91,7 +105,9 @@ impl DaftContext {
     pub fn get_or_create_runner(&self) -> DaftResult<Arc<Runner>> {
         self.with_state_mut(|state| state.get_or_create_runner())
     }
+}
 
+impl DaftContext {
     /// Get the current runner, if one has been set.
     pub fn runner(&self) -> Option<Arc<Runner>> {
         self.with_state(|state| state.runner.clone())
@@ -111,6 +127,27 @@ impl DaftContext {
         })
     }
 
+    /// Retrieves the pure-Rust runner, which runs plans without python.
+    ///
+    /// WARNING: This will create the runner if it has not yet been created.
+    pub fn get_or_create_local_runner(&self) -> DaftResult<Arc<LocalRunner>> {
+        self.with_state_mut(|state| state.get_or_create_local_runner())
+    }
+
+    /// Set the pure-Rust runner, e.g. to configure its number of threads.
+    /// IMPORTANT: This can only be set once. Setting it more than once will error.
+    pub fn set_local_runner(&self, runner: Arc<LocalRunner>) -> DaftResult<()> {
+        self.with_state_mut(|state| {
+            if state.local_runner.is_some() {
+                return Err(DaftError::InternalError(
+                    "Cannot set local runner more than once".to_string(),
+                ));
+            }
+            state.local_runner.replace(runner);
+            Ok(())
+        })
+    }
+
     fn with_state<F, R>(&self, f: F) -> R
     where
         F: FnOnce(&ContextState) -> R,
@@ -160,63 +197,31 @@ impl DaftContext {
 
 #[cfg(not(feature = "python"))]
 impl DaftContext {
+    /// The python runners are unavailable without the `python` feature, use
+    /// `get_or_create_local_runner` instead.
     pub fn get_or_create_runner(&self) -> DaftResult<Arc<Runner>> {
-        unimplemented!()
-    }
-
-    pub fn runner(&self) -> Option<Arc<Runner>> {
-        unimplemented!()
-    }
-
-    pub fn set_runner(&self, runner: Arc<Runner>) -> DaftResult<()> {
-        unimplemented!()
-    }
-
-    /// Execute a callback with read access to the state.
-    /// The guard is automatically released when the callback returns.
-    pub fn with_state<F, R>(&self, f: F) -> R
-    where
-        F: FnOnce(&ContextState) -> R,
-    {
-        unimplemented!()
-    }
-
-    /// Execute a callback with mutable access to the state.
-    /// The guard is automatically released when the callback returns.
-    pub fn with_state_mut<F, R>(&self, f: F) -> R
-    where
-        F: FnOnce(&mut ContextState) -> R,
-    {
-        unimplemented!()
+        Err(DaftError::InternalError(
+            "The python runners require the 'python' feature, use the local runner instead"
+                .to_string(),
+        ))
     }
 }
 
 static DAFT_CONTEXT: OnceLock<DaftContext> = OnceLock::new();
 
-#[cfg(feature = "python")]
 pub fn get_context() -> DaftContext {
-    match DAFT_CONTEXT.get() {
-        Some(ctx) => ctx.clone(),
-        None => {
+    DAFT_CONTEXT
+        .get_or_init(|| {
             let state = ContextState {
                 config: Config::from_env(),
                 runner: None,
+                local_runner: None,
             };
             let state = RwLock::new(state);
             let state = Arc::new(state);
-            let ctx = DaftContext { state };
-
-            DAFT_CONTEXT
-                .set(ctx.clone())
-                .expect("Failed to set DaftContext");
-            ctx
-        }
-    }
-}
-
-#[cfg(not(feature = "python"))]
-pub fn get_context() -> DaftContext {
-    unimplemented!()
+            DaftContext { state }
+        })
+        .clone()
 }
 
 #[cfg(feature = "python")]
@@ -244,7 +249,9 @@ pub fn set_runner_ray(
     _max_task_backlog: Option<usize>,
     _force_client_mode: Option<bool>,
 ) -> DaftResult<DaftContext> {
-    unimplemented!()
+    Err(DaftError::InternalError(
+        "The ray runner requires the 'python' feature".to_string(),
+    ))
 }
 
 #[cfg(feature = "python")]
@@ -259,12 +266,19 @@ pub fn set_runner_native(num_threads: Option<usize>) -> DaftResult<DaftContext>
     Ok(ctx)
 }
 
+/// Without python, the native runner is the pure-Rust [`LocalRunner`].
 #[cfg(not(feature = "python"))]
 pub fn set_runner_native(num_threads: Option<usize>) -> DaftResult<DaftContext> {
-    unimplemented!()
+    let ctx = get_context();
+
+    let runner = Arc::new(LocalRunner::try_new(num_threads)?);
+    ctx.set_local_runner(runner)?;
+
+    Ok(ctx)
 }
 
 /// Helper function to parse a boolean environment variable.
+#[cfg(feature = "python")]
 fn parse_bool_env_var(var_name: &str) -> Option<bool> {
     std::env::var(var_name)
         .ok()
@@ -272,6 +286,7 @@ fn parse_bool_env_var(var_name: &str) -> Option<bool> {
 }
 
 /// Helper function to parse a numeric environment variable.
+#[cfg(feature = "python")]
 fn parse_usize_env_var(var_name: &str) -> Option<usize> {
     std::env::var(var_name).ok().and_then(|s| s.parse().ok())
 }
@@ -332,11 +347,6 @@ fn get_runner_config_from_env() -> DaftResult<RunnerConfig> {
     }
 }
 
-#[cfg(not(feature = "python"))]
-fn get_runner_config_from_env() -> DaftResult<RunnerConfig> {
-    unimplemented!()
-}
-
 #[cfg(feature = "python")]
 pub fn register_modules(parent: &Bound<PyModule>) -> pyo3::PyResult<()> {
     parent.add_function(wrap_pyfunction!(
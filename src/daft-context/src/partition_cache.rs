@@ -47,7 +47,7 @@ pub fn logical_plan_from_micropartitions(
     let cache_entry = put_partition_set_into_cache(pset_ref.clone())?;
     let partition_key = &cache_entry.key();
     let size_bytes = pset_ref.size_bytes()?;
-    let num_rows = pset_ref.len();
+    let num_rows = pset_ref.metadata().num_rows;
     let num_partitions = pset_ref.num_partitions();
     // create a scan from the entry
     let builder = LogicalPlanBuilder::in_memory_scan(
@@ -97,9 +97,8 @@ pub fn put_partition_set_into_cache(
 pub fn put_partition_set_into_cache(
     pset: Arc<MicroPartitionSet>,
 ) -> DaftResult<PartitionCacheEntry> {
-    Err(DaftError::InternalError(
-        "put_partition_set_into_cache requires 'python' feature".to_string(),
-    ))
+    let runner = get_context().get_or_create_local_runner()?;
+    Ok(runner.put_partition_set_into_cache(pset))
 }
 
 #[cfg(test)]
@@ -112,7 +111,6 @@ mod test {
     use super::*;
 
     #[test]
-    #[cfg(feature = "python")]
     fn test_from_arrow_sanity() {
         let schema = Schema::new(vec![Field::new("col1", DataType::Int64)]);
         let schema = Arc::new(schema);
@@ -0,0 +1,89 @@
+//! A pure-Rust runner, which is the Rust equivalent of the python `NativeRunner`.
+//!
+//! It runs logical plans on the local execution engine without going through python, so that Daft
+//! can be embedded as a library in a Rust program.
+
+use std::sync::Arc;
+
+use common_daft_config::DaftExecutionConfig;
+use common_error::DaftResult;
+use daft_local_execution::NativeExecutor;
+use daft_local_plan::translate;
+use daft_logical_plan::LogicalPlanBuilder;
+use daft_micropartition::{
+    partitioning::{
+        InMemoryPartitionSetCache, MicroPartitionSet, PartitionCacheEntry, PartitionSetCache,
+    },
+    MicroPartitionRef,
+};
+use futures::{Stream, StreamExt};
+
+/// Runs logical plans on the local execution engine.
+#[derive(Debug, Default)]
+pub struct LocalRunner {
+    /// The partition sets of in-memory scans, which are only kept alive by their cache entries.
+    partition_cache: InMemoryPartitionSetCache,
+    /// Dropping the executor cancels its runs, so it lives as long as the runner.
+    executor: NativeExecutor,
+}
+
+impl LocalRunner {
+    /// Creates a runner, where `num_threads` sets the number of threads of the compute runtime.
+    ///
+    /// The number of threads can only be set once per process, so this errors if it was already set.
+    pub fn try_new(num_threads: Option<usize>) -> DaftResult<Self> {
+        if let Some(num_threads) = num_threads {
+            common_runtime::set_compute_runtime_num_worker_threads(num_threads)?;
+        }
+        Ok(Self::default())
+    }
+
+    /// Puts a partition set into the cache so that in-memory scans can refer to it.
+    ///
+    /// The partition set stays in the cache for as long as the returned entry is alive.
+    pub fn put_partition_set_into_cache(
+        &self,
+        pset: Arc<MicroPartitionSet>,
+    ) -> PartitionCacheEntry {
+        let key = uuid::Uuid::new_v4().to_string();
+        self.partition_cache.put_partition_set(&key, &pset);
+        PartitionCacheEntry::new_rust(key, pset)
+    }
+
+    /// Optimizes and runs the plan, returning an iterator of the resulting partitions.
+    ///
+    /// **Important**: This must not be called from within a tokio runtime, use `run_stream` instead.
+    pub fn run_iter(
+        &self,
+        builder: &LogicalPlanBuilder,
+        cfg: Arc<DaftExecutionConfig>,
+        results_buffer_size: Option<usize>,
+    ) -> DaftResult<impl Iterator<Item = DaftResult<MicroPartitionRef>>> {
+        let builder = builder.optimize()?;
+        self.execute(&builder, cfg, results_buffer_size)
+            .map(IntoIterator::into_iter)
+    }
+
+    /// Optimizes and runs the plan, returning a stream of the resulting partitions.
+    pub async fn run_stream(
+        &self,
+        builder: &LogicalPlanBuilder,
+        cfg: Arc<DaftExecutionConfig>,
+        results_buffer_size: Option<usize>,
+    ) -> DaftResult<impl Stream<Item = DaftResult<MicroPartitionRef>>> {
+        let builder = builder.optimize_async().await?;
+        let result = self.execute(&builder, cfg, results_buffer_size)?;
+        Ok(result.into_stream().boxed())
+    }
+
+    fn execute(
+        &self,
+        builder: &LogicalPlanBuilder,
+        cfg: Arc<DaftExecutionConfig>,
+        results_buffer_size: Option<usize>,
+    ) -> DaftResult<daft_local_execution::ExecutionEngineResult> {
+        let plan = translate(&builder.build())?;
+        self.executor
+            .run(&plan, &self.partition_cache, cfg, results_buffer_size, None)
+    }
+}
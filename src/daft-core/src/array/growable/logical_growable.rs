@@ -85,3 +85,4 @@ impl_logical_growable!(
 impl_logical_growable!(LogicalImageGrowable, ImageType);
 impl_logical_growable!(LogicalTensorGrowable, TensorType);
 impl_logical_growable!(LogicalMapGrowable, MapType);
+impl_logical_growable!(LogicalGeometryGrowable, GeometryType);
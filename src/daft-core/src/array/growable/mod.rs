@@ -225,3 +225,4 @@ impl_growable_array!(
 impl_growable_array!(ImageArray, logical_growable::LogicalImageGrowable<'a>);
 impl_growable_array!(TensorArray, logical_growable::LogicalTensorGrowable<'a>);
 impl_growable_array!(MapArray, map_growable::MapGrowable<'a>);
+impl_growable_array!(GeometryArray, logical_growable::LogicalGeometryGrowable<'a>);
@@ -32,20 +32,20 @@ use crate::{
     array::{
         growable::make_growable,
         image_array::ImageArraySidecarData,
-        ops::{from_arrow::FromArrow, full::FullNull, DaftCompare},
+        ops::{decimal, from_arrow::FromArrow, full::FullNull, DaftCompare},
         DataArray, FixedSizeListArray, ListArray, StructArray,
     },
     datatypes::{
         logical::{
             DateArray, DurationArray, EmbeddingArray, FixedShapeImageArray,
-            FixedShapeSparseTensorArray, FixedShapeTensorArray, ImageArray, LogicalArray, MapArray,
-            SparseTensorArray, TensorArray, TimeArray, TimestampArray,
+            FixedShapeSparseTensorArray, FixedShapeTensorArray, GeometryArray, ImageArray,
+            LogicalArray, MapArray, SparseTensorArray, TensorArray, TimeArray, TimestampArray,
         },
-        DaftArrayType, DaftArrowBackedType, DaftLogicalType, DataType, Field, ImageMode,
-        Int64Array, NullArray, TimeUnit, UInt64Array, Utf8Array,
+        DaftArrayType, DaftArrowBackedType, DaftLogicalType, DataType, Decimal128Array, Field,
+        ImageMode, Int64Array, NullArray, TimeUnit, UInt64Array, Utf8Array,
     },
     series::{IntoSeries, Series},
-    utils::display::display_time64,
+    utils::{display::display_time64, wkb},
     with_match_numeric_daft_types,
 };
 
@@ -78,6 +78,49 @@ where
                 })?;
                 Ok(new_pyseries.into())
             }
+            // Geometries are stored as WKB, so only binary data (validated below) can be cast.
+            DataType::Geometry => match self.data_type() {
+                DataType::Null => {
+                    Ok(GeometryArray::full_null(self.name(), dtype, self.len()).into_series())
+                }
+                DataType::Binary => {
+                    let binary = self
+                        .data
+                        .as_any()
+                        .downcast_ref::<arrow2::array::BinaryArray<i64>>()
+                        .unwrap();
+                    for value in binary.iter().flatten() {
+                        wkb::read_header(value)?;
+                    }
+                    let new_field = Arc::new(Field::new(self.name(), dtype.clone()));
+                    Series::from_arrow(new_field, self.data.clone())
+                }
+                other => Err(DaftError::TypeError(format!(
+                    "Cannot cast {other} to Geometry: only Binary (WKB) values can be cast, use a geometry parsing function instead"
+                ))),
+            },
+            // Decimals are cast exactly instead of through Arrow, which silently nulls out values
+            // that overflow and does not support strings.
+            DataType::Decimal128(..)
+                if self.data_type().is_integer() || self.data_type() == &DataType::Utf8 =>
+            {
+                Ok(
+                    decimal::cast_to_decimal128(self.name(), self.data_type(), self.data(), dtype)?
+                        .into_series(),
+                )
+            }
+            DataType::Decimal128(..) if self.data_type().is_decimal128() => {
+                let decimals = Decimal128Array::new(self.field.clone(), self.data.clone())?;
+                Ok(decimals.cast_to_decimal(dtype)?.into_series())
+            }
+            DataType::Utf8 if self.data_type().is_decimal128() => {
+                let decimals = Decimal128Array::new(self.field.clone(), self.data.clone())?;
+                Ok(decimals.cast_to_utf8().into_series())
+            }
+            dtype if dtype.is_integer() && self.data_type().is_decimal128() => {
+                let decimals = Decimal128Array::new(self.field.clone(), self.data.clone())?;
+                decimals.cast_to_integer(dtype)
+            }
             _ => {
                 // Cast from DataArray to the target DataType
                 // by using Arrow's casting mechanisms.
@@ -1246,6 +1289,24 @@ impl PythonArray {
     }
 }
 
+impl GeometryArray {
+    pub fn cast(&self, dtype: &DataType) -> DaftResult<Series> {
+        match dtype {
+            DataType::Null => {
+                Ok(NullArray::full_null(self.name(), dtype, self.len()).into_series())
+            }
+            dtype if dtype == self.data_type() => Ok(self.clone().into_series()),
+            DataType::Binary => Ok(self.physical.clone().into_series()),
+            #[cfg(feature = "python")]
+            DataType::Python => self.physical.cast(dtype),
+            _ => Err(DaftError::TypeError(format!(
+                "Cannot cast Geometry to {}",
+                dtype
+            ))),
+        }
+    }
+}
+
 impl EmbeddingArray {
     pub fn cast(&self, dtype: &DataType) -> DaftResult<Series> {
         match (dtype, self.data_type()) {
@@ -2650,4 +2711,48 @@ mod tests {
             scale,
         );
     }
+
+    #[test]
+    fn test_binary_geometry_round_trip() {
+        let wkb = [wkb::point_to_wkb(1.0, 2.0), wkb::point_to_wkb(3.0, 4.0)];
+        let binary = crate::datatypes::BinaryArray::from_iter(
+            "geom",
+            vec![Some(wkb[0].as_slice()), None, Some(wkb[1].as_slice())].into_iter(),
+        )
+        .into_series();
+
+        let geometry = binary.cast(&DataType::Geometry).unwrap();
+        assert_eq!(geometry.data_type(), &DataType::Geometry);
+        assert_eq!(
+            geometry.geometry().unwrap().str_value(0).unwrap(),
+            "<Geometry Point, 21 bytes>"
+        );
+        assert_eq!(geometry.geometry().unwrap().str_value(1).unwrap(), "None");
+
+        let round_tripped = geometry.cast(&DataType::Binary).unwrap();
+        assert_eq!(binary.to_arrow(), round_tripped.to_arrow());
+    }
+
+    #[test]
+    fn test_invalid_casts_to_geometry() {
+        let invalid_wkb = crate::datatypes::BinaryArray::from_iter(
+            "geom",
+            vec![Some(b"POINT (1 2)")].into_iter(),
+        )
+        .into_series();
+        assert!(invalid_wkb.cast(&DataType::Geometry).is_err());
+
+        let utf8 =
+            Utf8Array::from_iter("geom", vec![Some("POINT (1 2)")].into_iter()).into_series();
+        assert!(matches!(
+            utf8.cast(&DataType::Geometry),
+            Err(DaftError::TypeError(_))
+        ));
+
+        let ints = create_test_i64_array(vec![1, 2]).into_series();
+        assert!(matches!(
+            ints.cast(&DataType::Geometry),
+            Err(DaftError::TypeError(_))
+        ));
+    }
 }
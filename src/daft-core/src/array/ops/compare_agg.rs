@@ -3,8 +3,9 @@ use common_error::DaftResult;
 
 use super::{full::FullNull, DaftCompareAggable, GroupIndices};
 use crate::{
-    array::{ListArray, StructArray},
+    array::{FixedSizeListArray, ListArray, StructArray},
     datatypes::*,
+    series::IntoSeries,
 };
 
 fn grouped_cmp_native<T, F>(
@@ -466,6 +467,7 @@ impl DaftCompareAggable for DataArray<NullType> {
     }
 }
 
+#[cfg(feature = "python")]
 macro_rules! impl_todo_daft_comparable {
     ($da:ident) => {
         impl DaftCompareAggable for $da {
@@ -501,11 +503,36 @@ macro_rules! impl_todo_daft_comparable {
     };
 }
 
-impl_todo_daft_comparable!(StructArray);
-impl_todo_daft_comparable!(FixedSizeListArray);
-impl_todo_daft_comparable!(ListArray);
-impl_todo_daft_comparable!(ExtensionArray);
-impl_todo_daft_comparable!(IntervalArray);
+/// Implements min and max by taking the values at `arg_min` and `arg_max`, which compare nested
+/// values lexicographically like sorting.
+macro_rules! impl_daft_comparable_by_arg {
+    ($da:ident) => {
+        impl DaftCompareAggable for $da {
+            type Output = DaftResult<$da>;
+            fn min(&self) -> Self::Output {
+                self.take(&self.clone().into_series().arg_min(None)?)
+            }
+
+            fn max(&self) -> Self::Output {
+                self.take(&self.clone().into_series().arg_max(None)?)
+            }
+
+            fn grouped_min(&self, groups: &super::GroupIndices) -> Self::Output {
+                self.take(&self.clone().into_series().arg_min(Some(groups))?)
+            }
+
+            fn grouped_max(&self, groups: &super::GroupIndices) -> Self::Output {
+                self.take(&self.clone().into_series().arg_max(Some(groups))?)
+            }
+        }
+    };
+}
+
+impl_daft_comparable_by_arg!(StructArray);
+impl_daft_comparable_by_arg!(FixedSizeListArray);
+impl_daft_comparable_by_arg!(ListArray);
+impl_daft_comparable_by_arg!(ExtensionArray);
+impl_daft_comparable_by_arg!(IntervalArray);
 
 #[cfg(feature = "python")]
 impl_todo_daft_comparable!(PythonArray);
@@ -0,0 +1,476 @@
+//! Exact, overflow-checked kernels for `Decimal128` values.
+//!
+//! Decimals are stored as `i128` values scaled by `10^scale`. None of the kernels here go through
+//! floating point: results are either exact, rounded half away from zero when the scale shrinks, or
+//! an error when they do not fit in the output precision.
+use std::sync::Arc;
+
+use arrow2::array::{PrimitiveArray, Utf8Array as ArrowUtf8Array};
+use common_error::{DaftError, DaftResult};
+
+use crate::{
+    array::ops::as_arrow::AsArrow,
+    datatypes::{DataType, Decimal128Array, Field, Utf8Array},
+    series::{IntoSeries, Series},
+    utils::display::display_decimal128,
+    with_match_integer_daft_types,
+};
+
+/// The largest precision a `Decimal128` can have.
+pub const MAX_DECIMAL128_PRECISION: usize = 38;
+
+/// The largest unscaled value that fits in the given precision.
+pub fn max_for_precision(precision: usize) -> i128 {
+    10i128.pow(precision as u32) - 1
+}
+
+fn overflow_error(value: impl std::fmt::Display, dtype: &DataType) -> DaftError {
+    DaftError::ComputeError(format!("Decimal overflow: {value} does not fit in {dtype}"))
+}
+
+fn precision_and_scale(dtype: &DataType) -> (usize, usize) {
+    let DataType::Decimal128(precision, scale) = dtype else {
+        unreachable!("Expected a Decimal128 type, got {dtype}")
+    };
+    (*precision, *scale)
+}
+
+/// The type of a `Decimal128` rounded to `decimals` fractional digits.
+///
+/// The result keeps an extra integral digit for values that round up, e.g. `9.99` to `10.0`.
+pub fn rounded_type(dtype: &DataType, decimals: i32) -> DataType {
+    let (precision, scale) = precision_and_scale(dtype);
+    let decimals = decimals.max(0) as usize;
+    if decimals >= scale {
+        return dtype.clone();
+    }
+    DataType::Decimal128(
+        (precision - scale + decimals + 1).min(MAX_DECIMAL128_PRECISION),
+        decimals,
+    )
+}
+
+/// Returns `value` if it fits in the precision of `dtype`, otherwise an overflow error.
+fn check_precision(value: i128, dtype: &DataType) -> DaftResult<i128> {
+    let (precision, scale) = precision_and_scale(dtype);
+    if value.unsigned_abs() > max_for_precision(precision).unsigned_abs() {
+        return Err(overflow_error(
+            display_decimal128(value, precision as u8, scale as i8),
+            dtype,
+        ));
+    }
+    Ok(value)
+}
+
+/// Divides `numerator` by `denominator`, rounding half away from zero.
+///
+/// Returns `None` if `denominator` is zero or the result overflows.
+pub fn div_round_half_up(numerator: i128, denominator: i128) -> Option<i128> {
+    let quotient = numerator.checked_div(denominator)?;
+    let remainder = numerator % denominator;
+    if remainder.unsigned_abs() * 2 >= denominator.unsigned_abs() {
+        let sign = numerator.signum() * denominator.signum();
+        quotient.checked_add(sign)
+    } else {
+        Some(quotient)
+    }
+}
+
+/// Converts an unscaled value from `from_scale` to `to_scale`, rounding half away from zero.
+///
+/// Returns `None` on overflow.
+pub fn rescale(value: i128, from_scale: usize, to_scale: usize) -> Option<i128> {
+    if to_scale >= from_scale {
+        10i128
+            .checked_pow((to_scale - from_scale) as u32)
+            .and_then(|factor| value.checked_mul(factor))
+    } else {
+        match 10i128.checked_pow((from_scale - to_scale) as u32) {
+            Some(factor) => div_round_half_up(value, factor),
+            // Dropping 39 or more digits always rounds to zero.
+            None => Some(0),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum ParseDecimalError {
+    /// The string is not a number.
+    Invalid,
+    /// The number does not fit in an `i128` at the requested scale.
+    Overflow,
+}
+
+/// Parses a decimal string such as `-12.345` or `1.2e3` into an unscaled value at `scale`.
+///
+/// Extra fractional digits are rounded half away from zero.
+fn parse_decimal(s: &str, scale: usize) -> Result<i128, ParseDecimalError> {
+    use ParseDecimalError::{Invalid, Overflow};
+
+    let s = s.trim();
+    let (negative, s) = match s.as_bytes().first().ok_or(Invalid)? {
+        b'-' => (true, &s[1..]),
+        b'+' => (false, &s[1..]),
+        _ => (false, s),
+    };
+    let (mantissa, exponent) = match s.find(['e', 'E']) {
+        Some(i) => (&s[..i], s[i + 1..].parse::<i64>().map_err(|_| Invalid)?),
+        None => (s, 0),
+    };
+    let (integral, fractional) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    if integral.is_empty() && fractional.is_empty() {
+        return Err(Invalid);
+    }
+    let digits = integral
+        .bytes()
+        .chain(fractional.bytes())
+        .map(|b| b.is_ascii_digit().then(|| b - b'0'))
+        .collect::<Option<Vec<_>>>()
+        .ok_or(Invalid)?;
+
+    // The digits represent `digits * 10^-(fractional.len() - exponent)`.
+    let shift = scale as i64 + exponent - fractional.len() as i64;
+    let (kept, round_digit) = if shift >= 0 {
+        (digits.len(), None)
+    } else {
+        let kept = digits.len() as i64 + shift;
+        if kept < 0 {
+            return Ok(0);
+        }
+        (kept as usize, digits.get(kept as usize).copied())
+    };
+
+    let value = (|| {
+        let mut value = digits[..kept].iter().try_fold(0i128, |acc, d| {
+            acc.checked_mul(10)?.checked_add(i128::from(*d))
+        })?;
+        if shift > 0 {
+            value = value.checked_mul(10i128.checked_pow(u32::try_from(shift).ok()?)?)?;
+        }
+        if round_digit.is_some_and(|d| d >= 5) {
+            value = value.checked_add(1)?;
+        }
+        Some(if negative { -value } else { value })
+    })();
+    value.ok_or(Overflow)
+}
+
+impl Decimal128Array {
+    /// Applies `op` to every pair of non-null values, broadcasting unit-length arrays.
+    fn decimal_binary_op(
+        &self,
+        rhs: &Self,
+        dtype: &DataType,
+        op: impl Fn(i128, i128) -> DaftResult<i128>,
+    ) -> DaftResult<Self> {
+        let len = match (self.len(), rhs.len()) {
+            (a, b) if a == b => a,
+            (a, 1) => a,
+            (1, b) => b,
+            (a, b) => {
+                return Err(DaftError::ValueError(format!(
+                    "Cannot apply operation on arrays of different lengths: {a} vs {b}"
+                )))
+            }
+        };
+        let get = |arr: &Self, idx: usize| arr.get(if arr.len() == 1 { 0 } else { idx });
+        let values = (0..len)
+            .map(|idx| match (get(self, idx), get(rhs, idx)) {
+                (Some(l), Some(r)) => op(l, r).and_then(|v| check_precision(v, dtype)).map(Some),
+                _ => Ok(None),
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        Ok(Self::from_iter(
+            Field::new(self.name(), dtype.clone()),
+            values.into_iter(),
+        ))
+    }
+
+    /// Adds two decimals of the same type, erroring on overflow.
+    pub fn checked_add(&self, rhs: &Self) -> DaftResult<Self> {
+        assert_eq!(self.data_type(), rhs.data_type());
+        let dtype = self.data_type();
+        self.decimal_binary_op(rhs, dtype, |l, r| {
+            l.checked_add(r).ok_or_else(|| overflow_error("sum", dtype))
+        })
+    }
+
+    /// Subtracts two decimals of the same type, erroring on overflow.
+    pub fn checked_sub(&self, rhs: &Self) -> DaftResult<Self> {
+        assert_eq!(self.data_type(), rhs.data_type());
+        let dtype = self.data_type();
+        self.decimal_binary_op(rhs, dtype, |l, r| {
+            l.checked_sub(r)
+                .ok_or_else(|| overflow_error("difference", dtype))
+        })
+    }
+
+    /// Multiplies two decimals of any precision and scale into `dtype`.
+    ///
+    /// The exact product has scale `s1 + s2`, which is rounded to the scale of `dtype`.
+    pub fn checked_mul(&self, rhs: &Self, dtype: &DataType) -> DaftResult<Self> {
+        let (_, s1) = precision_and_scale(self.data_type());
+        let (_, s2) = precision_and_scale(rhs.data_type());
+        let (_, scale) = precision_and_scale(dtype);
+        self.decimal_binary_op(rhs, dtype, |l, r| {
+            l.checked_mul(r)
+                .and_then(|v| rescale(v, s1 + s2, scale))
+                .ok_or_else(|| overflow_error("product", dtype))
+        })
+    }
+
+    /// Divides two decimals of any precision and scale into `dtype`, rounding half away from zero.
+    pub fn checked_div(&self, rhs: &Self, dtype: &DataType) -> DaftResult<Self> {
+        let (_, s1) = precision_and_scale(self.data_type());
+        let (_, s2) = precision_and_scale(rhs.data_type());
+        let (_, scale) = precision_and_scale(dtype);
+        self.decimal_binary_op(rhs, dtype, |l, r| {
+            if r == 0 {
+                return Err(DaftError::ComputeError(
+                    "Decimal division by zero".to_string(),
+                ));
+            }
+            // l / r has scale s1 - s2, so scale the numerator up to reach the output scale.
+            let numerator = rescale(l, s1, scale + s2);
+            numerator
+                .and_then(|n| div_round_half_up(n, r))
+                .ok_or_else(|| overflow_error("quotient", dtype))
+        })
+    }
+
+    /// Rounds to `decimals` fractional digits, rounding half away from zero.
+    pub fn round(&self, decimals: i32) -> DaftResult<Self> {
+        self.cast_to_decimal(&rounded_type(self.data_type(), decimals))
+    }
+
+    /// Converts to another decimal type exactly, rounding half away from zero if the scale shrinks.
+    pub(crate) fn cast_to_decimal(&self, dtype: &DataType) -> DaftResult<Self> {
+        let (_, from_scale) = precision_and_scale(self.data_type());
+        let (_, to_scale) = precision_and_scale(dtype);
+        let values = self
+            .as_arrow()
+            .iter()
+            .map(|v| {
+                v.map(|v| {
+                    rescale(*v, from_scale, to_scale)
+                        .ok_or_else(|| overflow_error(v, dtype))
+                        .and_then(|v| check_precision(v, dtype))
+                })
+                .transpose()
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        Ok(Self::from_iter(
+            Field::new(self.name(), dtype.clone()),
+            values.into_iter(),
+        ))
+    }
+
+    /// Formats every value exactly, e.g. `Decimal128(5, 2)` value `-105` as `-1.05`.
+    pub(crate) fn cast_to_utf8(&self) -> Utf8Array {
+        let (precision, scale) = precision_and_scale(self.data_type());
+        Utf8Array::from_iter(
+            self.name(),
+            self.as_arrow()
+                .iter()
+                .map(|v| v.map(|v| display_decimal128(*v, precision as u8, scale as i8))),
+        )
+    }
+
+    /// Converts to an integer type, truncating the fractional part and erroring if out of range.
+    pub(crate) fn cast_to_integer(&self, dtype: &DataType) -> DaftResult<Series> {
+        let (precision, scale) = precision_and_scale(self.data_type());
+        let factor = 10i128.pow(scale as u32);
+        with_match_integer_daft_types!(dtype, |$T| {
+            type Native = <$T as DaftNumericType>::Native;
+            let values = self
+                .as_arrow()
+                .iter()
+                .map(|v| {
+                    v.map(|v| {
+                        Native::try_from(*v / factor).map_err(|_| {
+                            DaftError::ComputeError(format!(
+                                "Cannot cast {} to {dtype}: value out of range",
+                                display_decimal128(*v, precision as u8, scale as i8)
+                            ))
+                        })
+                    })
+                    .transpose()
+                })
+                .collect::<DaftResult<Vec<_>>>()?;
+            Ok(DataArray::<$T>::from_iter(
+                Field::new(self.name(), dtype.clone()),
+                values.into_iter(),
+            )
+            .into_series())
+        })
+    }
+}
+
+/// Casts integers or strings to a decimal type exactly.
+///
+/// Integers that do not fit are an error. Strings that are not numbers become null, following the
+/// behavior of string to number casts, while numbers that do not fit are an error.
+pub(crate) fn cast_to_decimal128(
+    name: &str,
+    from: &DataType,
+    data: &dyn arrow2::array::Array,
+    dtype: &DataType,
+) -> DaftResult<Decimal128Array> {
+    let (_, scale) = precision_and_scale(dtype);
+    let field = Arc::new(Field::new(name, dtype.clone()));
+    let convert = |v: Option<i128>, display: &dyn std::fmt::Display| {
+        v.ok_or_else(|| overflow_error(display, dtype))
+            .and_then(|v| check_precision(v, dtype))
+    };
+    let values = match from {
+        DataType::Utf8 => {
+            let data = data.as_any().downcast_ref::<ArrowUtf8Array<i64>>().unwrap();
+            data.iter()
+                .map(|s| match s.map(|s| (s, parse_decimal(s, scale))) {
+                    Some((s, Ok(v))) => convert(Some(v), &s).map(Some),
+                    Some((s, Err(ParseDecimalError::Overflow))) => convert(None, &s).map(Some),
+                    Some((_, Err(ParseDecimalError::Invalid))) | None => Ok(None),
+                })
+                .collect::<DaftResult<Vec<_>>>()?
+        }
+        from if from.is_integer() => with_match_integer_daft_types!(from, |$T| {
+            let data = data
+                .as_any()
+                .downcast_ref::<PrimitiveArray<<$T as DaftNumericType>::Native>>()
+                .unwrap();
+            data.iter()
+                .map(|v| {
+                    v.map(|v| convert(rescale(i128::from(*v), 0, scale), v))
+                        .transpose()
+                })
+                .collect::<DaftResult<Vec<_>>>()?
+        }),
+        other => unreachable!("Cannot exactly cast {other} to a decimal"),
+    };
+    Ok(Decimal128Array::from_iter(field, values.into_iter()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::datatypes::Int64Array;
+
+    fn decimals(values: &[Option<i128>], precision: usize, scale: usize) -> Decimal128Array {
+        Decimal128Array::from_iter(
+            Field::new("a", DataType::Decimal128(precision, scale)),
+            values.iter().copied(),
+        )
+    }
+
+    fn values(arr: &Decimal128Array) -> Vec<Option<i128>> {
+        arr.as_arrow().iter().map(|v| v.copied()).collect()
+    }
+
+    #[test]
+    fn test_parse_decimal() {
+        assert_eq!(parse_decimal("12.345", 2), Ok(1235));
+        assert_eq!(parse_decimal("-12.345", 2), Ok(-1235));
+        assert_eq!(parse_decimal(" +0.1 ", 3), Ok(100));
+        assert_eq!(parse_decimal("1.5e2", 0), Ok(150));
+        assert_eq!(parse_decimal("5E-1", 0), Ok(1));
+        assert_eq!(parse_decimal(".004", 2), Ok(0));
+        assert_eq!(parse_decimal("1e40", 0), Err(ParseDecimalError::Overflow));
+        assert_eq!(parse_decimal("abc", 2), Err(ParseDecimalError::Invalid));
+        assert_eq!(parse_decimal("1.2.3", 2), Err(ParseDecimalError::Invalid));
+        assert_eq!(parse_decimal("", 2), Err(ParseDecimalError::Invalid));
+        assert_eq!(parse_decimal("-.", 2), Err(ParseDecimalError::Invalid));
+    }
+
+    #[test]
+    fn test_rescale_rounds_half_away_from_zero() {
+        assert_eq!(rescale(125, 2, 1), Some(13));
+        assert_eq!(rescale(-125, 2, 1), Some(-13));
+        assert_eq!(rescale(124, 2, 1), Some(12));
+        assert_eq!(rescale(12, 1, 3), Some(1200));
+        assert_eq!(rescale(i128::MAX, 0, 1), None);
+    }
+
+    #[test]
+    fn test_arithmetic_keeps_cents() -> DaftResult<()> {
+        // 0.10 + 0.20 is exactly 0.30, unlike with floats.
+        let a = decimals(&[Some(10), None, Some(-5)], 5, 2);
+        let b = decimals(&[Some(20), Some(1), Some(5)], 5, 2);
+        assert_eq!(values(&a.checked_add(&b)?), vec![Some(30), None, Some(0)]);
+        assert_eq!(
+            values(&a.checked_sub(&b)?),
+            vec![Some(-10), None, Some(-10)]
+        );
+
+        // 1.05 * 0.5 = 0.525 at scale 3.
+        let price = decimals(&[Some(105)], 5, 2);
+        let factor = decimals(&[Some(5)], 3, 1);
+        let product = price.checked_mul(&factor, &DataType::Decimal128(8, 3))?;
+        assert_eq!(values(&product), vec![Some(525)]);
+
+        // 10.00 / 3 = 3.333333 at scale 6, 2.00 / 3 rounds up to 0.666667.
+        let numerators = decimals(&[Some(1000), Some(200)], 5, 2);
+        let three = decimals(&[Some(3)], 1, 0);
+        let quotient = numerators.checked_div(&three, &DataType::Decimal128(11, 6))?;
+        assert_eq!(values(&quotient), vec![Some(3_333_333), Some(666_667)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_arithmetic_errors() {
+        let a = decimals(&[Some(999)], 3, 0);
+        assert!(a.checked_add(&a).is_err());
+        let zero = decimals(&[Some(0)], 1, 0);
+        assert!(a.checked_div(&zero, &DataType::Decimal128(10, 6)).is_err());
+    }
+
+    #[test]
+    fn test_round() -> DaftResult<()> {
+        let a = decimals(&[Some(999), Some(-125), Some(124), None], 3, 2);
+        let rounded = a.round(1)?;
+        assert_eq!(rounded.data_type(), &DataType::Decimal128(3, 1));
+        assert_eq!(values(&rounded), vec![Some(100), Some(-13), Some(12), None]);
+        assert_eq!(values(&a.round(5)?), values(&a));
+        Ok(())
+    }
+
+    #[test]
+    fn test_exact_casts() -> DaftResult<()> {
+        let a = decimals(&[Some(-105), Some(199), None], 5, 2);
+        let strings = a.cast_to_utf8();
+        assert_eq!(
+            strings.as_arrow().iter().collect::<Vec<_>>(),
+            vec![Some("-1.05"), Some("1.99"), None]
+        );
+        let back = cast_to_decimal128("a", &DataType::Utf8, strings.data(), a.data_type())?;
+        assert_eq!(values(&back), values(&a));
+
+        let ints = a.cast_to_integer(&DataType::Int64)?;
+        assert_eq!(
+            ints.i64()?.as_arrow().iter().collect::<Vec<_>>(),
+            vec![Some(&-1), Some(&1), None]
+        );
+        let too_small = decimals(&[Some(30000)], 5, 0);
+        assert!(too_small.cast_to_integer(&DataType::Int8).is_err());
+
+        let ints = Int64Array::from(("a", vec![12, -3]));
+        let cast = cast_to_decimal128(
+            "a",
+            &DataType::Int64,
+            ints.data(),
+            &DataType::Decimal128(5, 2),
+        )?;
+        assert_eq!(values(&cast), vec![Some(1200), Some(-300)]);
+        let cast = cast_to_decimal128(
+            "a",
+            &DataType::Int64,
+            ints.data(),
+            &DataType::Decimal128(2, 1),
+        );
+        assert!(cast.is_err());
+
+        let narrower = a.cast_to_decimal(&DataType::Decimal128(4, 1))?;
+        assert_eq!(values(&narrower), vec![Some(-11), Some(20), None]);
+        assert!(a.cast_to_decimal(&DataType::Decimal128(1, 1)).is_err());
+        Ok(())
+    }
+}
@@ -3,7 +3,7 @@ use std::sync::Arc;
 use arrow2::array::PrimitiveArray;
 use common_error::DaftResult;
 
-use super::{DaftCountAggable, DaftSumAggable};
+use super::{decimal::div_round_half_up, DaftCountAggable, DaftSumAggable};
 use crate::{
     array::ops::{DaftMeanAggable, GroupIndices},
     datatypes::*,
@@ -31,10 +31,10 @@ impl DaftMeanAggable for DataArray<Float64Type> {
 impl DataArray<Decimal128Type> {
     pub fn merge_mean(&self, counts: &DataArray<UInt64Type>) -> DaftResult<Self> {
         assert_eq!(self.len(), counts.len());
-        let means = self
-            .into_iter()
-            .zip(counts)
-            .map(|(sum, count)| sum.zip(count).map(|(s, c)| s / (*c as i128)));
+        let means = self.into_iter().zip(counts).map(|(sum, count)| {
+            sum.zip(count)
+                .and_then(|(s, c)| div_round_half_up(*s, *c as i128))
+        });
         Ok(Self::from_iter(self.field.clone(), means))
     }
 }
@@ -46,7 +46,9 @@ impl DaftMeanAggable for DataArray<Decimal128Type> {
         let count = self.count(CountMode::Valid)?.get(0);
         let sum = self.sum()?.get(0);
 
-        let val = sum.zip(count).map(|(s, c)| s / (c as i128));
+        let val = sum
+            .zip(count)
+            .and_then(|(s, c)| div_round_half_up(s, c as i128));
 
         Ok(Self::from_iter(self.field.clone(), std::iter::once(val)))
     }
@@ -19,6 +19,7 @@ mod comparison;
 mod concat;
 mod concat_agg;
 mod count;
+pub mod decimal;
 mod exp;
 mod filter;
 mod float;
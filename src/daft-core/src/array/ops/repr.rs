@@ -6,8 +6,8 @@ use crate::{
     datatypes::{
         logical::{
             DateArray, DurationArray, EmbeddingArray, FixedShapeImageArray,
-            FixedShapeSparseTensorArray, FixedShapeTensorArray, ImageArray, MapArray,
-            SparseTensorArray, TensorArray, TimeArray, TimestampArray,
+            FixedShapeSparseTensorArray, FixedShapeTensorArray, GeometryArray, ImageArray,
+            MapArray, SparseTensorArray, TensorArray, TimeArray, TimestampArray,
         },
         BinaryArray, BooleanArray, DaftNumericType, DataType, Decimal128Array, ExtensionArray,
         FixedSizeBinaryArray, IntervalArray, IntervalValue, NullArray, UInt64Array, Utf8Array,
@@ -292,6 +292,22 @@ impl EmbeddingArray {
     }
 }
 
+impl GeometryArray {
+    pub fn str_value(&self, idx: usize) -> DaftResult<String> {
+        match self.physical.get(idx) {
+            None => Ok("None".to_string()),
+            Some(wkb) => match crate::utils::wkb::read_header(wkb) {
+                Ok(header) => Ok(format!(
+                    "<Geometry {}, {} bytes>",
+                    header.geometry_type,
+                    wkb.len()
+                )),
+                Err(_) => Ok(format!("<Geometry invalid, {} bytes>", wkb.len())),
+            },
+        }
+    }
+}
+
 impl ImageArray {
     pub fn str_value(&self, idx: usize) -> DaftResult<String> {
         if self.physical.is_valid(idx) {
@@ -445,6 +461,7 @@ impl_array_html_value!(DurationArray);
 impl_array_html_value!(IntervalArray);
 impl_array_html_value!(TimestampArray);
 impl_array_html_value!(EmbeddingArray);
+impl_array_html_value!(GeometryArray);
 
 #[cfg(feature = "python")]
 impl crate::datatypes::PythonArray {
@@ -12,15 +12,18 @@ use crate::{
     datatypes::{
         logical::{
             DateArray, DurationArray, EmbeddingArray, FixedShapeImageArray,
-            FixedShapeSparseTensorArray, FixedShapeTensorArray, ImageArray, MapArray,
-            SparseTensorArray, TensorArray, TimeArray, TimestampArray,
+            FixedShapeSparseTensorArray, FixedShapeTensorArray, GeometryArray, ImageArray,
+            MapArray, SparseTensorArray, TensorArray, TimeArray, TimestampArray,
         },
         BinaryArray, BooleanArray, DaftIntegerType, DaftNumericType, Decimal128Array,
         ExtensionArray, FixedSizeBinaryArray, Float32Array, Float64Array, IntervalArray, NullArray,
         Utf8Array,
     },
-    kernels::search_sorted::{build_nulls_first_compare_with_nulls, cmp_float},
-    series::Series,
+    kernels::{
+        row_encoding::{build_row_compare, encode_rows, requires_row_encoding},
+        search_sorted::{build_nulls_first_compare_with_nulls, cmp_float},
+    },
+    series::{IntoSeries, Series},
 };
 
 pub fn build_multi_array_compare(
@@ -45,12 +48,16 @@ pub fn build_multi_array_bicompare(
         .zip(descending.iter())
         .zip(nulls_first.iter())
     {
-        cmp_list.push(build_nulls_first_compare_with_nulls(
-            l.to_arrow().as_ref(),
-            r.to_arrow().as_ref(),
-            *desc,
-            *nf,
-        )?);
+        if requires_row_encoding(l.data_type()) {
+            cmp_list.push(build_row_compare(l, r, *desc, *nf)?);
+        } else {
+            cmp_list.push(build_nulls_first_compare_with_nulls(
+                l.to_arrow().as_ref(),
+                r.to_arrow().as_ref(),
+                *desc,
+                *nf,
+            )?);
+        }
     }
 
     let combined_comparator = Box::new(move |a_idx: usize, b_idx: usize| -> std::cmp::Ordering {
@@ -678,64 +685,80 @@ macro_rules! impl_binary_like_sort {
 impl_binary_like_sort!(BinaryArray);
 impl_binary_like_sort!(Utf8Array);
 
+/// Sorts an array whose type requires the row encoding, by taking the indices of its argsort.
+macro_rules! sort_by_row_encoding {
+    ($self:expr, $descending:expr, $nulls_first:expr) => {{
+        let indices = $self
+            .clone()
+            .into_series()
+            .argsort($descending, $nulls_first)?;
+        $self.take(indices.u64()?)
+    }};
+}
+
 impl FixedSizeBinaryArray {
-    pub fn argsort<I>(&self, _descending: bool, _nulls_first: bool) -> DaftResult<DataArray<I>>
+    pub fn argsort<I>(&self, descending: bool, nulls_first: bool) -> DaftResult<DataArray<I>>
     where
         I: DaftIntegerType,
         <I as DaftNumericType>::Native: arrow2::types::Index,
     {
-        todo!("impl argsort for FixedSizeBinaryArray")
+        self.argsort_multikey(&[], &[descending], &[nulls_first])
     }
+
     pub fn argsort_multikey<I>(
         &self,
-        _others: &[Series],
-        _descending: &[bool],
-        _nulls_first: &[bool],
+        others: &[Series],
+        descending: &[bool],
+        nulls_first: &[bool],
     ) -> DaftResult<DataArray<I>>
     where
         I: DaftIntegerType,
         <I as DaftNumericType>::Native: arrow2::types::Index,
     {
-        todo!("impl argsort_multikey for FixedSizeBinaryArray")
+        let mut sort_keys = vec![self.clone().into_series()];
+        sort_keys.extend_from_slice(others);
+        let rows = encode_rows(&sort_keys, descending, nulls_first)?;
+        Ok(rows.argsort::<I>(false, false)?.rename(self.name()))
     }
-    pub fn sort(&self, _descending: bool, _nulls_first: bool) -> DaftResult<Self> {
-        todo!("impl sort for FixedSizeBinaryArray")
+
+    pub fn sort(&self, descending: bool, nulls_first: bool) -> DaftResult<Self> {
+        sort_by_row_encoding!(self, descending, nulls_first)
     }
 }
 
 impl FixedSizeListArray {
-    pub fn sort(&self, _descending: bool, _nulls_first: bool) -> DaftResult<Self> {
-        todo!("impl sort for FixedSizeListArray")
+    pub fn sort(&self, descending: bool, nulls_first: bool) -> DaftResult<Self> {
+        sort_by_row_encoding!(self, descending, nulls_first)
     }
 }
 
 impl ListArray {
-    pub fn sort(&self, _descending: bool, _nulls_first: bool) -> DaftResult<Self> {
-        todo!("impl sort for ListArray")
+    pub fn sort(&self, descending: bool, nulls_first: bool) -> DaftResult<Self> {
+        sort_by_row_encoding!(self, descending, nulls_first)
     }
 }
 
 impl MapArray {
-    pub fn sort(&self, _descending: bool, _nulls_first: bool) -> DaftResult<Self> {
-        todo!("impl sort for MapArray")
+    pub fn sort(&self, descending: bool, nulls_first: bool) -> DaftResult<Self> {
+        sort_by_row_encoding!(self, descending, nulls_first)
     }
 }
 
 impl StructArray {
-    pub fn sort(&self, _descending: bool, _nulls_first: bool) -> DaftResult<Self> {
-        todo!("impl sort for StructArray")
+    pub fn sort(&self, descending: bool, nulls_first: bool) -> DaftResult<Self> {
+        sort_by_row_encoding!(self, descending, nulls_first)
     }
 }
 
 impl ExtensionArray {
-    pub fn sort(&self, _descending: bool, _nulls_first: bool) -> DaftResult<Self> {
-        todo!("impl sort for ExtensionArray")
+    pub fn sort(&self, descending: bool, nulls_first: bool) -> DaftResult<Self> {
+        sort_by_row_encoding!(self, descending, nulls_first)
     }
 }
 
 impl IntervalArray {
-    pub fn sort(&self, _descending: bool, _nulls_first: bool) -> DaftResult<Self> {
-        todo!("impl sort for IntervalArray")
+    pub fn sort(&self, descending: bool, nulls_first: bool) -> DaftResult<Self> {
+        sort_by_row_encoding!(self, descending, nulls_first)
     }
 }
 
@@ -774,44 +797,51 @@ impl TimestampArray {
     }
 }
 
+impl GeometryArray {
+    pub fn sort(&self, descending: bool, nulls_first: bool) -> DaftResult<Self> {
+        let new_array = self.physical.sort(descending, nulls_first)?;
+        Ok(Self::new(self.field.clone(), new_array))
+    }
+}
+
 impl EmbeddingArray {
-    pub fn sort(&self, _descending: bool, _nulls_first: bool) -> DaftResult<Self> {
-        todo!("impl sort for EmbeddingArray")
+    pub fn sort(&self, descending: bool, nulls_first: bool) -> DaftResult<Self> {
+        sort_by_row_encoding!(self, descending, nulls_first)
     }
 }
 
 impl ImageArray {
-    pub fn sort(&self, _descending: bool, _nulls_first: bool) -> DaftResult<Self> {
-        todo!("impl sort for ImageArray")
+    pub fn sort(&self, descending: bool, nulls_first: bool) -> DaftResult<Self> {
+        sort_by_row_encoding!(self, descending, nulls_first)
     }
 }
 
 impl FixedShapeImageArray {
-    pub fn sort(&self, _descending: bool, _nulls_first: bool) -> DaftResult<Self> {
-        todo!("impl sort for FixedShapeImageArray")
+    pub fn sort(&self, descending: bool, nulls_first: bool) -> DaftResult<Self> {
+        sort_by_row_encoding!(self, descending, nulls_first)
     }
 }
 
 impl TensorArray {
-    pub fn sort(&self, _descending: bool, _nulls_first: bool) -> DaftResult<Self> {
-        todo!("impl sort for TensorArray")
+    pub fn sort(&self, descending: bool, nulls_first: bool) -> DaftResult<Self> {
+        sort_by_row_encoding!(self, descending, nulls_first)
     }
 }
 
 impl SparseTensorArray {
-    pub fn sort(&self, _descending: bool, _nulls_first: bool) -> DaftResult<Self> {
-        todo!("impl sort for SparseTensorArray")
+    pub fn sort(&self, descending: bool, nulls_first: bool) -> DaftResult<Self> {
+        sort_by_row_encoding!(self, descending, nulls_first)
     }
 }
 
 impl FixedShapeSparseTensorArray {
-    pub fn sort(&self, _descending: bool, _nulls_first: bool) -> DaftResult<Self> {
-        todo!("impl sort for FixedShapeSparseTensorArray")
+    pub fn sort(&self, descending: bool, nulls_first: bool) -> DaftResult<Self> {
+        sort_by_row_encoding!(self, descending, nulls_first)
     }
 }
 
 impl FixedShapeTensorArray {
-    pub fn sort(&self, _descending: bool, _nulls_first: bool) -> DaftResult<Self> {
-        todo!("impl sort for FixedShapeTensorArray")
+    pub fn sort(&self, descending: bool, nulls_first: bool) -> DaftResult<Self> {
+        sort_by_row_encoding!(self, descending, nulls_first)
     }
 }
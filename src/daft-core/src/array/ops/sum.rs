@@ -1,7 +1,7 @@
 use arrow2::array::Array;
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
 
-use super::{as_arrow::AsArrow, DaftSumAggable};
+use super::{as_arrow::AsArrow, decimal::max_for_precision, DaftSumAggable};
 use crate::{array::ops::GroupIndices, datatypes::*};
 macro_rules! impl_daft_numeric_agg {
     ($T:ident, $AggType: ident) => {
@@ -55,4 +55,56 @@ impl_daft_numeric_agg!(Int64Type, i64);
 impl_daft_numeric_agg!(UInt64Type, u64);
 impl_daft_numeric_agg!(Float32Type, f32);
 impl_daft_numeric_agg!(Float64Type, f64);
-impl_daft_numeric_agg!(Decimal128Type, i128);
+
+/// Decimal sums are checked against the precision of the array, which callers widen to the sum
+/// supertype beforehand, so that an overflow is an error instead of a wrong total.
+impl DaftSumAggable for &Decimal128Array {
+    type Output = DaftResult<Decimal128Array>;
+
+    fn sum(&self) -> Self::Output {
+        let sum_value = checked_decimal_sum(self, self.as_arrow().iter().flatten().copied())?;
+        Ok(Decimal128Array::from_iter(
+            self.field.clone(),
+            std::iter::once(sum_value),
+        ))
+    }
+
+    fn grouped_sum(&self, groups: &GroupIndices) -> Self::Output {
+        let arrow_array = self.as_arrow();
+        let sum_per_group = groups
+            .iter()
+            .map(|g| {
+                let values = g.iter().filter_map(|idx| arrow_array.get(*idx as usize));
+                checked_decimal_sum(self, values)
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        Ok(Decimal128Array::from_iter(
+            self.field.clone(),
+            sum_per_group.into_iter(),
+        ))
+    }
+}
+
+fn checked_decimal_sum(
+    arr: &Decimal128Array,
+    mut values: impl Iterator<Item = i128>,
+) -> DaftResult<Option<i128>> {
+    let DataType::Decimal128(precision, _) = arr.data_type() else {
+        unreachable!("Expected a Decimal128 array, got {}", arr.data_type())
+    };
+    let max = max_for_precision(*precision);
+    values.try_fold(None, |acc: Option<i128>, v| {
+        let sum = acc
+            .unwrap_or(0)
+            .checked_add(v)
+            .filter(|sum| sum.unsigned_abs() <= max.unsigned_abs());
+        match sum {
+            Some(sum) => Ok(Some(sum)),
+            None => Err(DaftError::ComputeError(format!(
+                "Decimal overflow: sum of {} does not fit in {}",
+                arr.name(),
+                arr.data_type()
+            ))),
+        }
+    })
+}
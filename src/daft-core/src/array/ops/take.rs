@@ -75,6 +75,7 @@ impl_logicalarray_take!(FixedShapeImageArray);
 impl_logicalarray_take!(TensorArray);
 impl_logicalarray_take!(SparseTensorArray);
 impl_logicalarray_take!(FixedShapeSparseTensorArray);
+impl_logicalarray_take!(GeometryArray);
 impl_logicalarray_take!(FixedShapeTensorArray);
 impl_logicalarray_take!(MapArray);
 
@@ -5,7 +5,7 @@ use common_error::DaftResult;
 use super::{
     DaftArrayType, DaftDataType, DataArray, DataType, DurationType, EmbeddingType,
     FixedShapeImageType, FixedShapeSparseTensorType, FixedShapeTensorType, FixedSizeListArray,
-    ImageType, MapType, SparseTensorType, TensorType, TimeType, TimestampType,
+    GeometryType, ImageType, MapType, SparseTensorType, TensorType, TimeType, TimestampType,
 };
 use crate::{
     array::{ListArray, StructArray},
@@ -195,6 +195,7 @@ pub type SparseTensorArray = LogicalArray<SparseTensorType>;
 pub type FixedShapeSparseTensorArray = LogicalArray<FixedShapeSparseTensorType>;
 pub type FixedShapeImageArray = LogicalArray<FixedShapeImageType>;
 pub type MapArray = LogicalArray<MapType>;
+pub type GeometryArray = LogicalArray<GeometryType>;
 
 pub trait DaftImageryType: DaftLogicalType {}
 
@@ -25,6 +25,7 @@ macro_rules! with_match_daft_types {
             DataType::FixedSizeList(_, _) => __with_ty__! { FixedSizeListType },
             DataType::Float32 => __with_ty__! { Float32Type },
             DataType::Float64 => __with_ty__! { Float64Type },
+            DataType::Geometry => __with_ty__! { GeometryType },
             DataType::Image(..) => __with_ty__! { ImageType },
             DataType::Int16 => __with_ty__! { Int16Type },
             DataType::Int32 => __with_ty__! { Int32Type },
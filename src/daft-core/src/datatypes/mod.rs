@@ -22,6 +22,7 @@ pub use daft_schema::{
     image_mode::ImageMode,
     time_unit::{format_string_has_offset, infer_timeunit_from_format_string, TimeUnit},
 };
+pub(crate) use infer_datatype::integer_to_decimal128;
 pub use infer_datatype::try_physical_supertype;
 use num_traits::{Bounded, Float, FromPrimitive, Num, NumCast, ToPrimitive, Zero};
 use serde::Serialize;
@@ -246,6 +247,7 @@ impl_daft_logical_data_array_datatype!(ImageType, Unknown, StructType);
 impl_daft_logical_data_array_datatype!(TensorType, Unknown, StructType);
 impl_daft_logical_data_array_datatype!(SparseTensorType, Unknown, StructType);
 impl_daft_logical_data_array_datatype!(FixedShapeSparseTensorType, Unknown, StructType);
+impl_daft_logical_data_array_datatype!(GeometryType, Geometry, BinaryType);
 impl_daft_logical_fixed_size_list_datatype!(EmbeddingType, Unknown);
 impl_daft_logical_fixed_size_list_datatype!(FixedShapeImageType, Unknown);
 impl_daft_logical_fixed_size_list_datatype!(FixedShapeTensorType, Unknown);
@@ -1,3 +1,4 @@
 pub mod hashing;
+pub mod row_encoding;
 pub mod search_sorted;
 pub mod utf8;
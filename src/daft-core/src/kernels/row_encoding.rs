@@ -0,0 +1,362 @@
+//! An order-preserving encoding of rows into bytes, similar to arrow's row format.
+//!
+//! Comparing two encoded rows as byte strings compares the original rows lexicographically, so the
+//! encoding lets us sort by and compare nested types (lists, structs, maps, tensors...) which the
+//! arrow comparators don't support. Nested values are ordered element by element, where a shorter
+//! list that is a prefix of a longer one sorts first, and floats are ordered like `cmp_float` with
+//! NaN greater than all other values.
+
+use arrow2::array::ord::DynComparator;
+use common_error::{DaftError, DaftResult};
+
+use crate::{
+    array::ops::as_arrow::AsArrow,
+    datatypes::{BinaryArray, DataType, ExtensionArray},
+    kernels::search_sorted::DynPartialComparator,
+    series::Series,
+};
+
+const NULL_FIRST: u8 = 0x00;
+const VALID: u8 = 0x01;
+const NULL_LAST: u8 = 0x02;
+
+const LIST_END: u8 = 0x00;
+const LIST_ELEMENT: u8 = 0x01;
+
+/// Returns whether sorting by the type requires the row encoding, i.e. whether the arrow sort
+/// kernels and comparators don't support it.
+pub fn requires_row_encoding(dtype: &DataType) -> bool {
+    matches!(
+        dtype.to_physical(),
+        DataType::FixedSizeBinary(_)
+            | DataType::List(_)
+            | DataType::FixedSizeList(..)
+            | DataType::Struct(_)
+            | DataType::Interval
+            | DataType::Extension(..)
+    )
+}
+
+/// Encodes the rows of the columns, so that comparing the encoded rows orders them by the columns
+/// with the given sort directions and null placements.
+///
+/// `nulls_first` places the top-level nulls of a column regardless of its direction, while nulls
+/// nested in a value are ordered as if they were the smallest values when `nulls_first` is true,
+/// and the largest otherwise, before the direction is applied.
+pub fn encode_rows(
+    columns: &[Series],
+    descending: &[bool],
+    nulls_first: &[bool],
+) -> DaftResult<BinaryArray> {
+    if columns.len() != descending.len() || columns.len() != nulls_first.len() {
+        return Err(DaftError::ValueError(format!(
+            "columns, descending and nulls_first length must match, got {} vs {} vs {}",
+            columns.len(),
+            descending.len(),
+            nulls_first.len()
+        )));
+    }
+    let num_rows = columns.first().map_or(0, Series::len);
+    let mut rows = vec![Vec::new(); num_rows];
+    for ((column, desc), nf) in columns.iter().zip(descending).zip(nulls_first) {
+        if column.len() != num_rows {
+            return Err(DaftError::ValueError(format!(
+                "All columns must have the same length to encode rows, got {} vs {}",
+                column.len(),
+                num_rows
+            )));
+        }
+        for (row, value) in rows.iter_mut().zip(encode_values(column, *nf)?) {
+            let (marker, value) = value.split_first().unwrap();
+            row.push(*marker);
+            if *desc {
+                row.extend(value.iter().map(|b| !b));
+            } else {
+                row.extend_from_slice(value);
+            }
+        }
+    }
+    let arrow_arr = arrow2::array::BinaryArray::<i64>::from_iter_values(rows.iter());
+    Ok(BinaryArray::from(("rows", Box::new(arrow_arr))))
+}
+
+/// Builds a comparator between the rows of `left` and `right`, which orders them like sorting by
+/// the column with the given direction and null placement.
+pub fn build_row_compare(
+    left: &Series,
+    right: &Series,
+    descending: bool,
+    nulls_first: bool,
+) -> DaftResult<DynComparator> {
+    let left = encode_rows(&[left.clone()], &[descending], &[nulls_first])?;
+    let right = encode_rows(&[right.clone()], &[descending], &[nulls_first])?;
+    Ok(Box::new(move |i, j| {
+        let l = left.as_arrow().value(i);
+        let r = right.as_arrow().value(j);
+        l.cmp(r)
+    }))
+}
+
+/// Like `build_row_compare` in ascending order, but returns `None` if either value is null.
+pub fn build_partial_row_compare(
+    left: &Series,
+    right: &Series,
+) -> DaftResult<DynPartialComparator> {
+    let left_validity = left.validity().cloned();
+    let right_validity = right.validity().cloned();
+    let cmp = build_row_compare(left, right, false, false)?;
+    Ok(Box::new(move |i, j| {
+        let is_valid = left_validity.as_ref().is_none_or(|v| v.get_bit(i))
+            && right_validity.as_ref().is_none_or(|v| v.get_bit(j));
+        is_valid.then(|| cmp(i, j))
+    }))
+}
+
+/// Encodes each value of the series as a null marker followed by its ascending encoding, which
+/// doesn't need a length since no encoded value is a prefix of another of the same type.
+fn encode_values(series: &Series, nulls_first: bool) -> DaftResult<Vec<Vec<u8>>> {
+    let series = series.as_physical()?;
+    let null = if nulls_first { NULL_FIRST } else { NULL_LAST };
+    let mut rows = (0..series.len())
+        .map(|i| vec![if series.is_valid(i) { VALID } else { null }])
+        .collect::<Vec<_>>();
+
+    fn extend<T>(
+        rows: &mut [Vec<u8>],
+        values: impl Iterator<Item = Option<T>>,
+        mut encode: impl FnMut(&mut Vec<u8>, T),
+    ) {
+        for (row, value) in rows.iter_mut().zip(values) {
+            if let Some(value) = value {
+                encode(row, value);
+            }
+        }
+    }
+    let signed = |row: &mut Vec<u8>, v: i64| row.extend(((v as u64) ^ (1 << 63)).to_be_bytes());
+    let unsigned = |row: &mut Vec<u8>, v: u64| row.extend(v.to_be_bytes());
+    let float = |row: &mut Vec<u8>, v: f64| row.extend(encode_f64(v));
+
+    match series.data_type() {
+        DataType::Null => {}
+        DataType::Boolean => extend(&mut rows, series.bool()?.as_arrow().iter(), |row, v| {
+            row.push(u8::from(v));
+        }),
+        DataType::Int8 => extend(&mut rows, series.i8()?.as_arrow().iter(), |row, v| {
+            signed(row, i64::from(*v));
+        }),
+        DataType::Int16 => extend(&mut rows, series.i16()?.as_arrow().iter(), |row, v| {
+            signed(row, i64::from(*v));
+        }),
+        DataType::Int32 => extend(&mut rows, series.i32()?.as_arrow().iter(), |row, v| {
+            signed(row, i64::from(*v));
+        }),
+        DataType::Int64 => extend(&mut rows, series.i64()?.as_arrow().iter(), |row, v| {
+            signed(row, *v);
+        }),
+        DataType::UInt8 => extend(&mut rows, series.u8()?.as_arrow().iter(), |row, v| {
+            unsigned(row, u64::from(*v));
+        }),
+        DataType::UInt16 => extend(&mut rows, series.u16()?.as_arrow().iter(), |row, v| {
+            unsigned(row, u64::from(*v));
+        }),
+        DataType::UInt32 => extend(&mut rows, series.u32()?.as_arrow().iter(), |row, v| {
+            unsigned(row, u64::from(*v));
+        }),
+        DataType::UInt64 => extend(&mut rows, series.u64()?.as_arrow().iter(), |row, v| {
+            unsigned(row, *v);
+        }),
+        DataType::Float32 => extend(&mut rows, series.f32()?.as_arrow().iter(), |row, v| {
+            float(row, f64::from(*v));
+        }),
+        DataType::Float64 => extend(&mut rows, series.f64()?.as_arrow().iter(), |row, v| {
+            float(row, *v);
+        }),
+        DataType::Decimal128(..) => {
+            extend(
+                &mut rows,
+                series.decimal128()?.as_arrow().iter(),
+                |row, v| {
+                    row.extend(((*v as u128) ^ (1 << 127)).to_be_bytes());
+                },
+            );
+        }
+        DataType::Interval => extend(&mut rows, series.interval()?.as_arrow().iter(), |row, v| {
+            signed(row, i64::from(v.months()));
+            signed(row, i64::from(v.days()));
+            signed(row, v.ns());
+        }),
+        DataType::Utf8 => extend(&mut rows, series.utf8()?.as_arrow().iter(), |row, v| {
+            encode_bytes(row, v.as_bytes());
+        }),
+        DataType::Binary => extend(&mut rows, series.binary()?.as_arrow().iter(), encode_bytes),
+        DataType::FixedSizeBinary(_) => extend(
+            &mut rows,
+            series.fixed_size_binary()?.as_arrow().iter(),
+            |row, v| row.extend_from_slice(v),
+        ),
+        DataType::List(_) => {
+            let array = series.list()?;
+            let elements = encode_values(&array.flat_child, nulls_first)?;
+            for (i, window) in array.offsets().buffer().windows(2).enumerate() {
+                if !series.is_valid(i) {
+                    continue;
+                }
+                for element in &elements[window[0] as usize..window[1] as usize] {
+                    rows[i].push(LIST_ELEMENT);
+                    rows[i].extend_from_slice(element);
+                }
+                rows[i].push(LIST_END);
+            }
+        }
+        DataType::FixedSizeList(..) => {
+            let array = series.fixed_size_list()?;
+            let size = array.fixed_element_len();
+            let elements = encode_values(&array.flat_child, nulls_first)?;
+            for (i, row) in rows.iter_mut().enumerate() {
+                if series.is_valid(i) {
+                    row.extend(elements[i * size..(i + 1) * size].iter().flatten());
+                }
+            }
+        }
+        DataType::Struct(_) => {
+            let array = series.struct_()?;
+            let children = array
+                .children
+                .iter()
+                .map(|child| encode_values(child, nulls_first))
+                .collect::<DaftResult<Vec<_>>>()?;
+            for (i, row) in rows.iter_mut().enumerate() {
+                if series.is_valid(i) {
+                    for child in &children {
+                        row.extend_from_slice(&child[i]);
+                    }
+                }
+            }
+        }
+        DataType::Extension(_, inner, _) => {
+            let storage = series
+                .downcast::<ExtensionArray>()?
+                .data()
+                .convert_logical_type(inner.to_arrow()?);
+            let storage = Series::try_from((series.name(), storage))?;
+            return encode_values(&storage, nulls_first);
+        }
+        other => {
+            return Err(DaftError::TypeError(format!(
+                "Cannot sort or compare values of type {other}"
+            )))
+        }
+    }
+    Ok(rows)
+}
+
+/// Encodes the bytes with each 0x00 escaped as [0x00, 0xFF] and terminated by [0x00, 0x00], so
+/// that no encoding is a prefix of another and a prefix of a value sorts before the value.
+fn encode_bytes(row: &mut Vec<u8>, bytes: &[u8]) {
+    for byte in bytes {
+        row.push(*byte);
+        if *byte == 0 {
+            row.push(0xFF);
+        }
+    }
+    row.extend([0x00, 0x00]);
+}
+
+/// Encodes the float so that its bytes are ordered like `cmp_float`, where all NaNs are equal and
+/// greater than all other values, and -0.0 equals 0.0.
+fn encode_f64(v: f64) -> [u8; 8] {
+    let v = if v.is_nan() {
+        f64::NAN
+    } else if v == 0.0 {
+        0.0
+    } else {
+        v
+    };
+    // Flips the other bits of negative values, so that the bits are ordered as signed integers.
+    let bits = v.to_bits() as i64;
+    let bits = bits ^ (((bits >> 63) as u64) >> 1) as i64;
+    ((bits as u64) ^ (1 << 63)).to_be_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use super::*;
+    use crate::{
+        array::{ListArray, StructArray},
+        datatypes::{Field, Float64Array, Int64Array, Utf8Array},
+        series::IntoSeries,
+    };
+
+    fn sorted_rows(columns: &[Series], descending: &[bool], nulls_first: &[bool]) -> Vec<usize> {
+        let rows = encode_rows(columns, descending, nulls_first).unwrap();
+        let mut indices = (0..rows.len()).collect::<Vec<_>>();
+        indices.sort_by_key(|i| rows.as_arrow().value(*i).to_vec());
+        indices
+    }
+
+    #[test]
+    fn test_encode_primitives() -> DaftResult<()> {
+        let ints = Int64Array::from_iter(
+            Field::new("a", DataType::Int64),
+            vec![Some(3), None, Some(-5), Some(0), Some(i64::MIN)].into_iter(),
+        )
+        .into_series();
+        assert_eq!(
+            sorted_rows(&[ints.clone()], &[false], &[false]),
+            [4, 2, 3, 0, 1]
+        );
+        assert_eq!(sorted_rows(&[ints], &[true], &[true]), [1, 0, 3, 2, 4]);
+
+        let floats =
+            Float64Array::from(("b", vec![1.5, f64::NAN, -0.0, f64::NEG_INFINITY, 0.0, -2.0]))
+                .into_series();
+        assert_eq!(
+            sorted_rows(&[floats], &[false], &[false]),
+            [3, 5, 2, 4, 0, 1]
+        );
+
+        let strs = Utf8Array::from_values("c", ["b", "a\0", "", "a", "ab"].iter()).into_series();
+        assert_eq!(sorted_rows(&[strs], &[false], &[false]), [2, 3, 1, 4, 0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode_nested() -> DaftResult<()> {
+        let child = Int64Array::from_iter(
+            Field::new("item", DataType::Int64),
+            vec![Some(1), Some(2), Some(1), Some(1), None, Some(0)].into_iter(),
+        )
+        .into_series();
+        // [[1, 2], [1], [], [1, null], null, [0]]
+        let list = ListArray::new(
+            Field::new("l", DataType::List(Box::new(DataType::Int64))),
+            child,
+            arrow2::offset::OffsetsBuffer::try_from(vec![0, 2, 3, 3, 5, 5, 6])?,
+            Some(arrow2::bitmap::Bitmap::from([
+                true, true, true, true, false, true,
+            ])),
+        )
+        .into_series();
+        assert_eq!(
+            sorted_rows(&[list.clone()], &[false], &[false]),
+            [2, 5, 1, 0, 3, 4]
+        );
+        assert_eq!(sorted_rows(&[list], &[false], &[true]), [4, 2, 5, 1, 3, 0]);
+
+        let a = Int64Array::from(("a", vec![2, 1, 2, 1])).into_series();
+        let b = Utf8Array::from_values("b", ["x", "y", "w", "y"].iter()).into_series();
+        let ids = Int64Array::from(("id", vec![0, 1, 2, 3])).into_series();
+        let fields = vec![a.field().clone(), b.field().clone()];
+        let st = StructArray::new(Field::new("s", DataType::Struct(fields)), vec![a, b], None)
+            .into_series();
+        assert_eq!(
+            sorted_rows(&[st.clone(), ids.clone()], &[true, true], &[false, false]),
+            [0, 2, 3, 1]
+        );
+        let indices = Series::argsort_multikey(&[st, ids], &[true, true], &[false, false])?;
+        assert_eq!(indices.u64()?.as_slice(), [0, 2, 3, 1]);
+        Ok(())
+    }
+}
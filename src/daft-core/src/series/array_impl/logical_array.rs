@@ -197,4 +197,5 @@ impl_series_like_for_logical_array!(EmbeddingArray);
 impl_series_like_for_logical_array!(FixedShapeTensorArray);
 impl_series_like_for_logical_array!(SparseTensorArray);
 impl_series_like_for_logical_array!(FixedShapeSparseTensorArray);
+impl_series_like_for_logical_array!(GeometryArray);
 impl_series_like_for_logical_array!(MapArray);
@@ -47,18 +47,24 @@ macro_rules! impl_series_like_for_nested_arrays {
                 self.0.validity()
             }
 
-            fn min(&self, _groups: Option<&GroupIndices>) -> DaftResult<Series> {
-                Err(DaftError::ValueError(format!(
-                    "{} does not support min",
-                    stringify!($da)
-                )))
+            fn min(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+                use crate::array::ops::DaftCompareAggable;
+                match groups {
+                    Some(groups) => {
+                        Ok(DaftCompareAggable::grouped_min(&self.0, groups)?.into_series())
+                    }
+                    None => Ok(DaftCompareAggable::min(&self.0)?.into_series()),
+                }
             }
 
-            fn max(&self, _groups: Option<&GroupIndices>) -> DaftResult<Series> {
-                Err(DaftError::ValueError(format!(
-                    "{} does not support max",
-                    stringify!($da)
-                )))
+            fn max(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+                use crate::array::ops::DaftCompareAggable;
+                match groups {
+                    Some(groups) => {
+                        Ok(DaftCompareAggable::grouped_max(&self.0, groups)?.into_series())
+                    }
+                    None => Ok(DaftCompareAggable::max(&self.0)?.into_series()),
+                }
             }
 
             fn agg_list(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
@@ -1,3 +1,5 @@
+use std::cmp::Ordering;
+
 use arrow2::{array::PrimitiveArray, offset::OffsetsBuffer};
 use common_error::{DaftError, DaftResult};
 
@@ -5,8 +7,9 @@ use crate::{
     array::{
         growable::make_growable,
         ops::{
-            DaftApproxSketchAggable, DaftCountAggable, DaftHllMergeAggable, DaftMeanAggable,
-            DaftSetAggable, DaftSkewAggable as _, DaftStddevAggable, DaftSumAggable, GroupIndices,
+            build_multi_array_compare, full::FullNull, DaftApproxSketchAggable, DaftCountAggable,
+            DaftHllMergeAggable, DaftMeanAggable, DaftSetAggable, DaftSkewAggable as _,
+            DaftStddevAggable, DaftSumAggable, GroupIndices,
         },
         ListArray,
     },
@@ -199,6 +202,68 @@ impl Series {
         self.inner.max(groups)
     }
 
+    /// Returns the index of the smallest non-null value of each group, or of the whole series if
+    /// there are no groups, which is null if all of the values are null.
+    ///
+    /// Ties are broken by the first index, and values are ordered like sorting, so nested types are
+    /// compared lexicographically.
+    pub fn arg_min(&self, groups: Option<&GroupIndices>) -> DaftResult<UInt64Array> {
+        self.arg_cmp(groups, Ordering::Less)
+    }
+
+    /// Returns the index of the largest non-null value of each group, like `arg_min`.
+    pub fn arg_max(&self, groups: Option<&GroupIndices>) -> DaftResult<UInt64Array> {
+        self.arg_cmp(groups, Ordering::Greater)
+    }
+
+    fn arg_cmp(
+        &self,
+        groups: Option<&GroupIndices>,
+        ordering: Ordering,
+    ) -> DaftResult<UInt64Array> {
+        let field = Field::new(self.name(), DataType::UInt64);
+        if self.data_type().is_null() {
+            let num_groups = groups.map_or(1, |groups| groups.len());
+            return Ok(UInt64Array::full_null(
+                self.name(),
+                &DataType::UInt64,
+                num_groups,
+            ));
+        }
+        let cmp = build_multi_array_compare(&[self.as_physical()?], &[false], &[false])?;
+        let validity = self.validity();
+        let find = |indices: &mut dyn Iterator<Item = u64>| {
+            indices
+                .filter(|i| validity.is_none_or(|v| v.get_bit(*i as usize)))
+                .reduce(|best, i| {
+                    if cmp(i as usize, best as usize) == ordering {
+                        i
+                    } else {
+                        best
+                    }
+                })
+        };
+        let indices = match groups {
+            Some(groups) => groups
+                .iter()
+                .map(|g| find(&mut g.iter().copied()))
+                .collect::<Vec<_>>(),
+            None => vec![find(&mut (0..self.len() as u64))],
+        };
+        Ok(UInt64Array::from_iter(field, indices.into_iter()))
+    }
+
+    /// Returns the value of each group at the smallest non-null value of `by`, or of the whole
+    /// series if there are no groups.
+    pub fn min_by(&self, by: &Self, groups: Option<&GroupIndices>) -> DaftResult<Self> {
+        self.take(&by.arg_min(groups)?.into_series())
+    }
+
+    /// Returns the value of each group at the largest non-null value of `by`, like `min_by`.
+    pub fn max_by(&self, by: &Self, groups: Option<&GroupIndices>) -> DaftResult<Self> {
+        self.take(&by.arg_max(groups)?.into_series())
+    }
+
     pub fn any_value(&self, groups: Option<&GroupIndices>, ignore_nulls: bool) -> DaftResult<Self> {
         let indices = match groups {
             Some(groups) => {
@@ -409,3 +474,55 @@ impl DaftSetAggable for Series {
         Ok(result)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{
+        array::{ops::DaftCompareAggable, StructArray},
+        datatypes::{DataType, Field, Float64Array, Int64Array, Utf8Array},
+        series::{IntoSeries, Series},
+    };
+
+    #[test]
+    fn test_min_by_max_by() -> DaftResult<()> {
+        let names = Utf8Array::from_values("name", ["a", "b", "c", "d", "e"].iter()).into_series();
+        let scores = Float64Array::from_iter(
+            Field::new("score", DataType::Float64),
+            vec![Some(2.0), None, Some(f64::NAN), Some(1.0), Some(1.0)].into_iter(),
+        )
+        .into_series();
+        let groups = vec![vec![0, 1, 2], vec![3, 4], vec![1]];
+
+        let min_by = names.min_by(&scores, Some(&groups))?;
+        assert_eq!(
+            min_by.utf8()?.into_iter().collect::<Vec<_>>(),
+            [Some("a"), Some("d"), None]
+        );
+        let max_by = names.max_by(&scores, Some(&groups))?;
+        assert_eq!(
+            max_by.utf8()?.into_iter().collect::<Vec<_>>(),
+            [Some("c"), Some("d"), None]
+        );
+        assert_eq!(names.max_by(&scores, None)?.utf8()?.get(0), Some("c"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_struct_min_max() -> DaftResult<()> {
+        let a = Int64Array::from(("a", vec![2, 1, 2])).into_series();
+        let b = Utf8Array::from_values("b", ["x", "y", "w"].iter()).into_series();
+        let fields = vec![a.field().clone(), b.field().clone()];
+        let st = StructArray::new(Field::new("s", DataType::Struct(fields)), vec![a, b], None);
+
+        let min = DaftCompareAggable::min(&st)?.into_series();
+        assert_eq!(min.struct_()?.children[1].utf8()?.get(0), Some("y"));
+        let max = DaftCompareAggable::grouped_max(&st, &vec![vec![0, 2], vec![1]])?;
+        assert_eq!(
+            max.children[1].utf8()?.into_iter().collect::<Vec<_>>(),
+            [Some("x"), Some("y")]
+        );
+        Ok(())
+    }
+}
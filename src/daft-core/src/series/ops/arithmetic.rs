@@ -7,7 +7,7 @@ use daft_schema::prelude::*;
 use crate::series::utils::python_fn::run_python_binary_operator_fn;
 use crate::{
     array::prelude::*,
-    datatypes::{InferDataType, Int32Type, Utf8Array},
+    datatypes::{integer_to_decimal128, InferDataType, Int32Type, Utf8Array},
     series::{utils::cast::cast_downcast_op, IntoSeries, Series},
     with_match_integer_daft_types, with_match_numeric_daft_types,
 };
@@ -35,6 +35,16 @@ macro_rules! arithmetic_op_not_implemented {
     };
 }
 
+/// Casts an operand of a decimal multiplication or division to a decimal, keeping its own scale
+/// so that the kernel can compute the exact result.
+fn cast_decimal_operand(series: &Series, output_type: &DataType) -> DaftResult<Series> {
+    match series.data_type() {
+        DataType::Decimal128(..) => Ok(series.clone()),
+        dtype if dtype.is_integer() => series.cast(&integer_to_decimal128(dtype)?),
+        _ => series.cast(output_type),
+    }
+}
+
 impl Add for &Series {
     type Output = DaftResult<Series>;
     fn add(self, rhs: Self) -> Self::Output {
@@ -263,7 +273,12 @@ impl Mul for &Series {
             // Decimal Types
             // ----------------
             DataType::Decimal128(..) => {
-                Ok(cast_downcast_op!(lhs, rhs, &output_type, Decimal128Array, mul)?.into_series())
+                let lhs = cast_decimal_operand(lhs, &output_type)?;
+                let rhs = cast_decimal_operand(rhs, &output_type)?;
+                Ok(lhs
+                    .decimal128()?
+                    .checked_mul(rhs.decimal128()?, &output_type)?
+                    .into_series())
             }
             // ----------------
             // FixedSizeLists of numeric types (fsl, embedding, tensor, etc.)
@@ -326,7 +341,12 @@ impl Div for &Series {
             // Decimal Types
             // ----------------
             DataType::Decimal128(..) => {
-                Ok(cast_downcast_op!(lhs, rhs, &output_type, Decimal128Array, div)?.into_series())
+                let lhs = cast_decimal_operand(lhs, &output_type)?;
+                let rhs = cast_decimal_operand(rhs, &output_type)?;
+                Ok(lhs
+                    .decimal128()?
+                    .checked_div(rhs.decimal128()?, &output_type)?
+                    .into_series())
             }
             // ----------------
             // FixedSizeLists of numeric types (fsl, embedding, tensor, etc.)
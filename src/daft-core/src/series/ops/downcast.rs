@@ -4,7 +4,7 @@ use logical::{
     TensorArray,
 };
 
-use self::logical::{DurationArray, ImageArray, MapArray};
+use self::logical::{DurationArray, GeometryArray, ImageArray, MapArray};
 use crate::{
     array::{ListArray, StructArray},
     datatypes::{
@@ -168,4 +168,8 @@ impl Series {
     pub fn fixed_shape_sparse_tensor(&self) -> DaftResult<&FixedShapeSparseTensorArray> {
         self.downcast()
     }
+
+    pub fn geometry(&self) -> DaftResult<&GeometryArray> {
+        self.downcast()
+    }
 }
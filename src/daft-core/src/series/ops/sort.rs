@@ -1,12 +1,17 @@
 use common_error::{DaftError, DaftResult};
 
 use crate::{
+    datatypes::UInt64Type,
+    kernels::row_encoding::{encode_rows, requires_row_encoding},
     series::{array_impl::IntoSeries, Series},
     with_match_comparable_daft_types,
 };
 
 impl Series {
     pub fn argsort(&self, descending: bool, nulls_first: bool) -> DaftResult<Self> {
+        if requires_row_encoding(self.data_type()) {
+            return Self::argsort_rows(&[self.clone()], &[descending], &[nulls_first]);
+        }
         let series = self.as_physical()?;
         with_match_comparable_daft_types!(series.data_type(), |$T| {
             let downcasted = series.downcast::<<$T as DaftDataType>::ArrayType>()?;
@@ -34,6 +39,13 @@ impl Series {
                 .argsort(*descending.first().unwrap(), *nulls_first.first().unwrap());
         }
 
+        if sort_keys
+            .iter()
+            .any(|key| requires_row_encoding(key.data_type()))
+        {
+            return Self::argsort_rows(sort_keys, descending, nulls_first);
+        }
+
         let first = sort_keys.first().unwrap().as_physical()?;
         with_match_comparable_daft_types!(first.data_type(), |$T| {
             let downcasted = first.downcast::<<$T as DaftDataType>::ArrayType>()?;
@@ -42,6 +54,18 @@ impl Series {
         })
     }
 
+    /// Argsorts by the row encoding of the keys, which supports nested and other types that the
+    /// arrow sort kernels don't.
+    fn argsort_rows(
+        sort_keys: &[Self],
+        descending: &[bool],
+        nulls_first: &[bool],
+    ) -> DaftResult<Self> {
+        let rows = encode_rows(sort_keys, descending, nulls_first)?;
+        let indices = rows.argsort::<UInt64Type>(false, false)?;
+        Ok(indices.rename(sort_keys[0].name()).into_series())
+    }
+
     pub fn sort(&self, descending: bool, nulls_first: bool) -> DaftResult<Self> {
         self.inner.sort(descending, nulls_first)
     }
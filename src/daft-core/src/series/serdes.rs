@@ -11,8 +11,8 @@ use crate::{
     datatypes::{
         logical::{
             DateArray, DurationArray, EmbeddingArray, FixedShapeImageArray,
-            FixedShapeSparseTensorArray, FixedShapeTensorArray, ImageArray, MapArray,
-            SparseTensorArray, TensorArray, TimeArray, TimestampArray,
+            FixedShapeSparseTensorArray, FixedShapeTensorArray, GeometryArray, ImageArray,
+            MapArray, SparseTensorArray, TensorArray, TimeArray, TimestampArray,
         },
         *,
     },
@@ -315,6 +315,17 @@ impl<'d> serde::Deserialize<'d> for Series {
                         )
                         .into_series())
                     }
+                    DataType::Geometry => {
+                        type PType = <<GeometryType as DaftLogicalType>::PhysicalType as DaftDataType>::ArrayType;
+                        let physical = map.next_value::<Series>()?;
+                        Ok(
+                            GeometryArray::new(
+                                field,
+                                physical.downcast::<PType>().unwrap().clone(),
+                            )
+                            .into_series(),
+                        )
+                    }
                     DataType::Tensor(..) => {
                         type PType = <<TensorType as DaftLogicalType>::PhysicalType as DaftDataType>::ArrayType;
                         let physical = map.next_value::<Series>()?;
@@ -336,3 +347,54 @@ impl<'d> serde::Deserialize<'d> for Series {
         deserializer.deserialize_map(SeriesVisitor)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        datatypes::{BinaryArray, DataType, UInt64Array},
+        series::{IntoSeries, Series},
+        utils::wkb::point_to_wkb,
+    };
+
+    fn geometry_series(name: &str, points: &[Option<(f64, f64)>]) -> Series {
+        let wkb = points
+            .iter()
+            .map(|p| p.map(|(x, y)| point_to_wkb(x, y)))
+            .collect::<Vec<_>>();
+        BinaryArray::from_iter(name, wkb.into_iter())
+            .into_series()
+            .cast(&DataType::Geometry)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_geometry_serde_round_trip() {
+        let series = geometry_series("geom", &[Some((1.0, 2.0)), None, Some((-1.0, 0.5))]);
+        let bytes = bincode::serialize(&series).unwrap();
+        let deserialized: Series = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(deserialized.data_type(), &DataType::Geometry);
+        assert_eq!(series.to_arrow(), deserialized.to_arrow());
+    }
+
+    #[test]
+    fn test_geometry_take_concat_sort() {
+        let a = geometry_series("geom", &[Some((1.0, 2.0)), None]);
+        let b = geometry_series("geom", &[Some((0.0, 0.0))]);
+
+        let concatenated = Series::concat(&[&a, &b]).unwrap();
+        assert_eq!(concatenated.data_type(), &DataType::Geometry);
+        assert_eq!(concatenated.len(), 3);
+
+        let idx = UInt64Array::from(("idx", vec![2u64, 0])).into_series();
+        let taken = concatenated.take(&idx).unwrap();
+        assert_eq!(
+            taken.to_arrow(),
+            geometry_series("geom", &[Some((0.0, 0.0)), Some((1.0, 2.0))]).to_arrow()
+        );
+
+        let sorted = concatenated.sort(false, false).unwrap();
+        assert_eq!(sorted.data_type(), &DataType::Geometry);
+        assert_eq!(sorted.len(), 3);
+        assert!(sorted.slice(2, 3).unwrap().to_arrow().is_null(0));
+    }
+}
@@ -4,3 +4,4 @@ pub mod dyn_compare;
 pub mod identity_hash_set;
 pub mod stats;
 pub mod supertype;
+pub mod wkb;
@@ -0,0 +1,287 @@
+//! Minimal, dependency-free reader for Well-Known Binary (WKB) geometries.
+//!
+//! This only understands enough of the format to validate values, describe them, and compute their
+//! bounding boxes. Full geometry parsing lives in `daft-functions-geo`.
+use common_error::{DaftError, DaftResult};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WkbGeometryType {
+    Point,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection,
+}
+
+impl WkbGeometryType {
+    fn from_code(code: u32) -> Option<Self> {
+        match code {
+            1 => Some(Self::Point),
+            2 => Some(Self::LineString),
+            3 => Some(Self::Polygon),
+            4 => Some(Self::MultiPoint),
+            5 => Some(Self::MultiLineString),
+            6 => Some(Self::MultiPolygon),
+            7 => Some(Self::GeometryCollection),
+            _ => None,
+        }
+    }
+
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::Point => 1,
+            Self::LineString => 2,
+            Self::Polygon => 3,
+            Self::MultiPoint => 4,
+            Self::MultiLineString => 5,
+            Self::MultiPolygon => 6,
+            Self::GeometryCollection => 7,
+        }
+    }
+}
+
+impl std::fmt::Display for WkbGeometryType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Point => "Point",
+            Self::LineString => "LineString",
+            Self::Polygon => "Polygon",
+            Self::MultiPoint => "MultiPoint",
+            Self::MultiLineString => "MultiLineString",
+            Self::MultiPolygon => "MultiPolygon",
+            Self::GeometryCollection => "GeometryCollection",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// The fixed-size prefix of a WKB value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WkbHeader {
+    pub little_endian: bool,
+    pub geometry_type: WkbGeometryType,
+    pub has_z: bool,
+    pub has_m: bool,
+    /// Whether the value is EWKB with an embedded SRID.
+    pub has_srid: bool,
+}
+
+impl WkbHeader {
+    fn dims(&self) -> usize {
+        2 + usize::from(self.has_z) + usize::from(self.has_m)
+    }
+}
+
+const EWKB_Z_FLAG: u32 = 0x8000_0000;
+const EWKB_M_FLAG: u32 = 0x4000_0000;
+const EWKB_SRID_FLAG: u32 = 0x2000_0000;
+
+/// Axis-aligned bounding box as `[xmin, ymin, xmax, ymax]`.
+pub type BoundingBox = [f64; 4];
+
+/// Merges two bounding boxes into the smallest box containing both.
+pub fn merge_bounding_boxes(a: BoundingBox, b: BoundingBox) -> BoundingBox {
+    [
+        a[0].min(b[0]),
+        a[1].min(b[1]),
+        a[2].max(b[2]),
+        a[3].max(b[3]),
+    ]
+}
+
+/// Returns whether two bounding boxes overlap, including touching edges.
+pub fn bounding_boxes_intersect(a: BoundingBox, b: BoundingBox) -> bool {
+    a[0] <= b[2] && b[0] <= a[2] && a[1] <= b[3] && b[1] <= a[3]
+}
+
+/// Encodes a little-endian 2D WKB point.
+pub fn point_to_wkb(x: f64, y: f64) -> Vec<u8> {
+    let mut out = Vec::with_capacity(21);
+    out.push(1);
+    out.extend_from_slice(&WkbGeometryType::Point.code().to_le_bytes());
+    out.extend_from_slice(&x.to_le_bytes());
+    out.extend_from_slice(&y.to_le_bytes());
+    out
+}
+
+fn invalid(msg: impl std::fmt::Display) -> DaftError {
+    DaftError::ValueError(format!("Invalid WKB geometry: {msg}"))
+}
+
+struct Reader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> DaftResult<&'a [u8]> {
+        let end = self
+            .pos
+            .checked_add(n)
+            .filter(|end| *end <= self.buf.len())
+            .ok_or_else(|| invalid(format!("unexpected end of input at byte {}", self.pos)))?;
+        let out = &self.buf[self.pos..end];
+        self.pos = end;
+        Ok(out)
+    }
+
+    fn u32(&mut self, little_endian: bool) -> DaftResult<u32> {
+        let bytes: [u8; 4] = self.take(4)?.try_into().unwrap();
+        Ok(if little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    fn f64(&mut self, little_endian: bool) -> DaftResult<f64> {
+        let bytes: [u8; 8] = self.take(8)?.try_into().unwrap();
+        Ok(if little_endian {
+            f64::from_le_bytes(bytes)
+        } else {
+            f64::from_be_bytes(bytes)
+        })
+    }
+
+    fn header(&mut self) -> DaftResult<WkbHeader> {
+        let little_endian = match self.take(1)?[0] {
+            0 => false,
+            1 => true,
+            b => return Err(invalid(format!("unknown byte order {b}"))),
+        };
+        let raw = self.u32(little_endian)?;
+        let has_srid = raw & EWKB_SRID_FLAG != 0;
+        let mut has_z = raw & EWKB_Z_FLAG != 0;
+        let mut has_m = raw & EWKB_M_FLAG != 0;
+        let iso = raw & !(EWKB_Z_FLAG | EWKB_M_FLAG | EWKB_SRID_FLAG);
+        match iso / 1000 {
+            0 => {}
+            1 => has_z = true,
+            2 => has_m = true,
+            3 => (has_z, has_m) = (true, true),
+            _ => return Err(invalid(format!("unknown geometry type {raw}"))),
+        }
+        let geometry_type = WkbGeometryType::from_code(iso % 1000)
+            .ok_or_else(|| invalid(format!("unknown geometry type {raw}")))?;
+        if has_srid {
+            self.u32(little_endian)?;
+        }
+        Ok(WkbHeader {
+            little_endian,
+            geometry_type,
+            has_z,
+            has_m,
+            has_srid,
+        })
+    }
+
+    fn coords(
+        &mut self,
+        header: &WkbHeader,
+        count: usize,
+        bbox: &mut Option<BoundingBox>,
+    ) -> DaftResult<()> {
+        for _ in 0..count {
+            let x = self.f64(header.little_endian)?;
+            let y = self.f64(header.little_endian)?;
+            for _ in 2..header.dims() {
+                self.f64(header.little_endian)?;
+            }
+            // Empty points are encoded with NaN coordinates.
+            if x.is_nan() || y.is_nan() {
+                continue;
+            }
+            let point = [x, y, x, y];
+            *bbox = Some(bbox.map_or(point, |b| merge_bounding_boxes(b, point)));
+        }
+        Ok(())
+    }
+
+    fn geometry(&mut self, bbox: &mut Option<BoundingBox>) -> DaftResult<WkbHeader> {
+        let header = self.header()?;
+        let le = header.little_endian;
+        match header.geometry_type {
+            WkbGeometryType::Point => self.coords(&header, 1, bbox)?,
+            WkbGeometryType::LineString => {
+                let n = self.u32(le)? as usize;
+                self.coords(&header, n, bbox)?;
+            }
+            WkbGeometryType::Polygon => {
+                let rings = self.u32(le)?;
+                for _ in 0..rings {
+                    let n = self.u32(le)? as usize;
+                    self.coords(&header, n, bbox)?;
+                }
+            }
+            WkbGeometryType::MultiPoint
+            | WkbGeometryType::MultiLineString
+            | WkbGeometryType::MultiPolygon
+            | WkbGeometryType::GeometryCollection => {
+                let parts = self.u32(le)?;
+                for _ in 0..parts {
+                    self.geometry(bbox)?;
+                }
+            }
+        }
+        Ok(header)
+    }
+}
+
+/// Parses and validates only the header of a WKB value.
+pub fn read_header(wkb: &[u8]) -> DaftResult<WkbHeader> {
+    Reader { buf: wkb, pos: 0 }.header()
+}
+
+/// Walks the whole WKB value, returning its header and bounding box.
+///
+/// The bounding box is `None` for empty geometries.
+pub fn read_bounding_box(wkb: &[u8]) -> DaftResult<(WkbHeader, Option<BoundingBox>)> {
+    let mut reader = Reader { buf: wkb, pos: 0 };
+    let mut bbox = None;
+    let header = reader.geometry(&mut bbox)?;
+    if reader.pos != wkb.len() {
+        return Err(invalid(format!(
+            "{} trailing bytes",
+            wkb.len() - reader.pos
+        )));
+    }
+    Ok((header, bbox))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point_round_trip() {
+        let wkb = point_to_wkb(1.0, -2.0);
+        assert_eq!(wkb.len(), 21);
+        let (header, bbox) = read_bounding_box(&wkb).unwrap();
+        assert_eq!(header.geometry_type, WkbGeometryType::Point);
+        assert_eq!(bbox, Some([1.0, -2.0, 1.0, -2.0]));
+    }
+
+    #[test]
+    fn test_big_endian_linestring_z() {
+        let mut wkb = vec![0];
+        wkb.extend_from_slice(&1002u32.to_be_bytes());
+        wkb.extend_from_slice(&2u32.to_be_bytes());
+        for v in [0.0f64, 5.0, 9.0, 3.0, -1.0, 9.0] {
+            wkb.extend_from_slice(&v.to_be_bytes());
+        }
+        let (header, bbox) = read_bounding_box(&wkb).unwrap();
+        assert!(header.has_z && !header.has_m);
+        assert_eq!(header.geometry_type, WkbGeometryType::LineString);
+        assert_eq!(bbox, Some([0.0, -1.0, 3.0, 5.0]));
+    }
+
+    #[test]
+    fn test_invalid_headers() {
+        assert!(read_header(&[]).is_err());
+        assert!(read_header(&[2, 1, 0, 0, 0]).is_err());
+        assert!(read_header(&[1, 42, 0, 0, 0]).is_err());
+        assert!(read_bounding_box(&[1, 1, 0, 0, 0]).is_err());
+    }
+}
@@ -82,7 +82,7 @@ impl StagePlanBuilder {
                     Ok(TreeNodeRecursion::Stop)
                 }
             }
-            LogicalPlan::Pivot(_) => {
+            LogicalPlan::Pivot(_) | LogicalPlan::RecursiveCte(_) => {
                 can_translate = false;
                 Ok(TreeNodeRecursion::Stop)
             }
@@ -338,6 +338,14 @@ pub enum AggExpr {
     #[display("max({_0})")]
     Max(ExprRef),
 
+    /// The value of the first expression at the minimum of the second.
+    #[display("min_by({_0}, {_1})")]
+    MinBy(ExprRef, ExprRef),
+
+    /// The value of the first expression at the maximum of the second.
+    #[display("max_by({_0}, {_1})")]
+    MaxBy(ExprRef, ExprRef),
+
     #[display("bool_and({_0})")]
     BoolAnd(ExprRef),
 
@@ -451,7 +459,9 @@ impl AggExpr {
             | Self::List(expr)
             | Self::Set(expr)
             | Self::Concat(expr)
-            | Self::Skew(expr) => expr.name(),
+            | Self::Skew(expr)
+            | Self::MinBy(expr, _)
+            | Self::MaxBy(expr, _) => expr.name(),
             Self::MapGroups { func: _, inputs } => inputs.first().unwrap().name(),
         }
     }
@@ -513,6 +523,16 @@ impl AggExpr {
                 let child_id = expr.semantic_id(schema);
                 FieldID::new(format!("{child_id}.local_max()"))
             }
+            Self::MinBy(expr, by) => {
+                let child_id = expr.semantic_id(schema);
+                let by_id = by.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_min_by({by_id})"))
+            }
+            Self::MaxBy(expr, by) => {
+                let child_id = expr.semantic_id(schema);
+                let by_id = by.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_max_by({by_id})"))
+            }
             Self::BoolAnd(expr) => {
                 let child_id = expr.semantic_id(schema);
                 FieldID::new(format!("{child_id}.local_bool_and()"))
@@ -567,15 +587,16 @@ impl AggExpr {
             | Self::Set(expr)
             | Self::Concat(expr)
             | Self::Skew(expr) => vec![expr.clone()],
+            Self::MinBy(expr, by) | Self::MaxBy(expr, by) => vec![expr.clone(), by.clone()],
             Self::MapGroups { func: _, inputs } => inputs.clone(),
         }
     }
 
     pub fn with_new_children(&self, mut children: Vec<ExprRef>) -> Self {
-        if let Self::MapGroups { func: _, inputs } = &self {
-            assert_eq!(children.len(), inputs.len());
-        } else {
-            assert_eq!(children.len(), 1);
+        match self {
+            Self::MapGroups { func: _, inputs } => assert_eq!(children.len(), inputs.len()),
+            Self::MinBy(..) | Self::MaxBy(..) => assert_eq!(children.len(), 2),
+            _ => assert_eq!(children.len(), 1),
         }
         let mut first_child = || children.pop().unwrap();
         match self {
@@ -593,6 +614,14 @@ impl AggExpr {
             Self::Set(_expr) => Self::Set(first_child()),
             Self::Concat(_) => Self::Concat(first_child()),
             Self::Skew(_) => Self::Skew(first_child()),
+            Self::MinBy(..) => {
+                let by = first_child();
+                Self::MinBy(first_child(), by)
+            }
+            Self::MaxBy(..) => {
+                let by = first_child();
+                Self::MaxBy(first_child(), by)
+            }
             Self::MapGroups { func, inputs: _ } => Self::MapGroups {
                 func: func.clone(),
                 inputs: children,
@@ -706,6 +735,18 @@ impl AggExpr {
                 Ok(Field::new(field.name.as_str(), field.dtype))
             }
 
+            Self::MinBy(expr, by) | Self::MaxBy(expr, by) => {
+                let field = expr.to_field(schema)?;
+                let by_field = by.to_field(schema)?;
+                if by_field.dtype.is_null() {
+                    return Err(DaftError::TypeError(format!(
+                        "Cannot order by null column \"{}\" in min_by() or max_by()",
+                        by_field.name
+                    )));
+                }
+                Ok(Field::new(field.name.as_str(), field.dtype))
+            }
+
             Self::List(expr) | Self::Set(expr) => expr.to_field(schema)?.to_list_field(),
 
             Self::BoolAnd(expr) | Self::BoolOr(expr) => {
@@ -997,6 +1038,16 @@ impl Expr {
         Self::Agg(AggExpr::BoolOr(self)).into()
     }
 
+    /// The value of this expression at the minimum of `by`.
+    pub fn min_by(self: ExprRef, by: ExprRef) -> ExprRef {
+        Self::Agg(AggExpr::MinBy(self, by)).into()
+    }
+
+    /// The value of this expression at the maximum of `by`.
+    pub fn max_by(self: ExprRef, by: ExprRef) -> ExprRef {
+        Self::Agg(AggExpr::MaxBy(self, by)).into()
+    }
+
     pub fn any_value(self: ExprRef, ignore_nulls: bool) -> ExprRef {
         Self::Agg(AggExpr::AnyValue(self, ignore_nulls)).into()
     }
@@ -1654,6 +1705,8 @@ impl Expr {
             Self::ScalarFunction(func) => match func.name() {
                 "struct" => "struct", // FIXME: make struct its own expr variant
                 "monotonically_increasing_id" => "monotonically_increasing_id", // Special case for functions with no inputs
+                "grouping" => "grouping",
+                "grouping_id" => "grouping_id",
                 _ => func.inputs.first().unwrap().name(),
             },
             Self::BinaryOp {
@@ -198,6 +198,7 @@ where
 
 impl_strict_fromliteral!(String, Utf8);
 impl_strict_fromliteral!(bool, Boolean);
+impl_strict_fromliteral!(Vec<u8>, Binary);
 impl_int_fromliteral!(i8);
 impl_int_fromliteral!(u8);
 impl_int_fromliteral!(i16);
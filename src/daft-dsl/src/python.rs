@@ -395,6 +395,14 @@ impl PyExpr {
         Ok(self.expr.clone().bool_or().into())
     }
 
+    pub fn min_by(&self, by: &Self) -> PyResult<Self> {
+        Ok(self.expr.clone().min_by(by.expr.clone()).into())
+    }
+
+    pub fn max_by(&self, by: &Self) -> PyResult<Self> {
+        Ok(self.expr.clone().max_by(by.expr.clone()).into())
+    }
+
     pub fn any_value(&self, ignore_nulls: bool) -> PyResult<Self> {
         Ok(self.expr.clone().any_value(ignore_nulls).into())
     }
@@ -0,0 +1,110 @@
+mod parse;
+mod predicates;
+mod serialize;
+mod utils;
+pub mod wkb;
+
+use daft_dsl::functions::{FunctionModule, FunctionRegistry};
+pub use parse::{
+    st_geomfromgeojson, st_geomfromtext, st_geomfromwkb, StGeomFromGeoJson, StGeomFromText,
+    StGeomFromWkb,
+};
+pub use predicates::{
+    st_contains, st_distance, st_intersects, StContains, StDistance, StIntersects,
+};
+pub use serialize::{st_asgeojson, st_astext, st_aswkb, StAsGeoJson, StAsText, StAsWkb};
+
+pub struct GeoFunctions;
+
+impl FunctionModule for GeoFunctions {
+    fn register(parent: &mut FunctionRegistry) {
+        parent.add_fn(StGeomFromText);
+        parent.add_fn(StGeomFromGeoJson);
+        parent.add_fn(StGeomFromWkb);
+        parent.add_fn(StAsText);
+        parent.add_fn(StAsGeoJson);
+        parent.add_fn(StAsWkb);
+        parent.add_fn(StContains);
+        parent.add_fn(StIntersects);
+        parent.add_fn(StDistance);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use daft_core::{
+        datatypes::{DataType, Utf8Array},
+        series::IntoSeries,
+    };
+    use daft_dsl::functions::{FunctionArgs, ScalarUDF};
+
+    use super::*;
+
+    fn call(
+        udf: &dyn ScalarUDF,
+        args: Vec<daft_core::series::Series>,
+    ) -> daft_core::series::Series {
+        udf.call(FunctionArgs::new_unnamed(args)).unwrap()
+    }
+
+    #[test]
+    fn test_wkt_round_trip_and_predicates() {
+        let polygons = Utf8Array::from_iter(
+            "a",
+            vec![
+                Some("POLYGON((0 0,4 0,4 4,0 4,0 0))"),
+                Some("POLYGON((10 10,11 10,11 11,10 11,10 10))"),
+                None,
+            ]
+            .into_iter(),
+        )
+        .into_series();
+        let point = Utf8Array::from_iter("b", vec![Some("POINT(1 1)")].into_iter()).into_series();
+
+        let polygons = call(&StGeomFromText, vec![polygons]);
+        let point = call(&StGeomFromText, vec![point]);
+        assert_eq!(polygons.data_type(), &DataType::Geometry);
+
+        let text = call(&StAsText, vec![point.clone()]);
+        assert_eq!(text.utf8().unwrap().get(0), Some("POINT(1 1)"));
+
+        let contains = call(&StContains, vec![polygons.clone(), point.clone()]);
+        let contains = contains.bool().unwrap();
+        assert_eq!(contains.get(0), Some(true));
+        assert_eq!(contains.get(1), Some(false));
+        assert_eq!(contains.get(2), None);
+
+        let intersects = call(&StIntersects, vec![point.clone(), polygons.clone()]);
+        assert_eq!(intersects.bool().unwrap().get(1), Some(false));
+
+        let distance = call(&StDistance, vec![polygons, point]);
+        let distance = distance.f64().unwrap();
+        assert_eq!(distance.get(0), Some(0.0));
+        assert!((distance.get(1).unwrap() - 162f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_geojson_round_trip() {
+        let input = Utf8Array::from_iter(
+            "g",
+            vec![Some(r#"{"type":"Point","coordinates":[1.0,2.0]}"#)].into_iter(),
+        )
+        .into_series();
+        let geometry = call(&StGeomFromGeoJson, vec![input]);
+        let wkt = call(&StAsText, vec![geometry.clone()]);
+        assert_eq!(wkt.utf8().unwrap().get(0), Some("POINT(1 2)"));
+        let geojson = call(&StAsGeoJson, vec![geometry]);
+        assert_eq!(
+            geojson.utf8().unwrap().get(0),
+            Some(r#"{"type":"Point","coordinates":[1.0,2.0]}"#)
+        );
+    }
+
+    #[test]
+    fn test_invalid_wkt() {
+        let input = Utf8Array::from_iter("g", vec![Some("POINT(1)")].into_iter()).into_series();
+        assert!(StGeomFromText
+            .call(FunctionArgs::new_unnamed(vec![input]))
+            .is_err());
+    }
+}
@@ -0,0 +1,175 @@
+use std::str::FromStr;
+
+use common_error::{ensure, DaftError, DaftResult};
+use daft_core::{
+    array::ops::as_arrow::AsArrow,
+    datatypes::{DataType, Field},
+    prelude::Schema,
+    series::Series,
+};
+use daft_dsl::{
+    functions::{FunctionArgs, ScalarFunction, ScalarUDF, UnaryArg},
+    ExprRef,
+};
+use geo::Geometry;
+use geojson::GeoJson;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    utils::{geometries_to_series, wkb_to_series},
+    wkb::from_wkb,
+};
+
+fn parse_wkt(text: &str) -> DaftResult<Geometry> {
+    let wkt = wkt::Wkt::<f64>::from_str(text)
+        .map_err(|e| DaftError::ValueError(format!("Invalid WKT geometry {text:?}: {e}")))?;
+    Geometry::try_from(wkt)
+        .map_err(|e| DaftError::ValueError(format!("Invalid WKT geometry {text:?}: {e}")))
+}
+
+fn parse_geojson(text: &str) -> DaftResult<Geometry> {
+    let geojson = GeoJson::from_str(text)
+        .map_err(|e| DaftError::ValueError(format!("Invalid GeoJSON geometry: {e}")))?;
+    Geometry::try_from(geojson)
+        .map_err(|e| DaftError::ValueError(format!("Invalid GeoJSON geometry: {e}")))
+}
+
+fn parse_utf8(input: &Series, parse: fn(&str) -> DaftResult<Geometry>) -> DaftResult<Series> {
+    let values = input
+        .utf8()?
+        .as_arrow()
+        .iter()
+        .map(|text| text.map(parse).transpose())
+        .collect::<DaftResult<Vec<_>>>()?;
+    Ok(geometries_to_series(input.name(), values))
+}
+
+fn utf8_return_field(
+    function: &str,
+    inputs: FunctionArgs<ExprRef>,
+    schema: &Schema,
+) -> DaftResult<Field> {
+    let UnaryArg { input } = inputs.try_into()?;
+    let input = input.to_field(schema)?;
+    ensure!(
+        input.dtype.is_string(),
+        TypeError: "Expected input to {function} to be a string, but received {input}"
+    );
+    Ok(Field::new(input.name, DataType::Geometry))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct StGeomFromText;
+
+#[typetag::serde]
+impl ScalarUDF for StGeomFromText {
+    fn name(&self) -> &'static str {
+        "st_geomfromtext"
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["st_geomfromwkt"]
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        utf8_return_field(self.name(), inputs, schema)
+    }
+
+    fn call(&self, inputs: FunctionArgs<Series>) -> DaftResult<Series> {
+        let UnaryArg { input } = inputs.try_into()?;
+        parse_utf8(&input, parse_wkt)
+    }
+
+    fn docstring(&self) -> &'static str {
+        "Parses a Well-Known Text (WKT) string into a geometry."
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct StGeomFromGeoJson;
+
+#[typetag::serde]
+impl ScalarUDF for StGeomFromGeoJson {
+    fn name(&self) -> &'static str {
+        "st_geomfromgeojson"
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        utf8_return_field(self.name(), inputs, schema)
+    }
+
+    fn call(&self, inputs: FunctionArgs<Series>) -> DaftResult<Series> {
+        let UnaryArg { input } = inputs.try_into()?;
+        parse_utf8(&input, parse_geojson)
+    }
+
+    fn docstring(&self) -> &'static str {
+        "Parses a GeoJSON geometry, feature, or feature collection into a geometry."
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct StGeomFromWkb;
+
+#[typetag::serde]
+impl ScalarUDF for StGeomFromWkb {
+    fn name(&self) -> &'static str {
+        "st_geomfromwkb"
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        let UnaryArg { input } = inputs.try_into()?;
+        let input = input.to_field(schema)?;
+        ensure!(
+            input.dtype.is_binary(),
+            TypeError: "Expected input to st_geomfromwkb to be binary, but received {input}"
+        );
+        Ok(Field::new(input.name, DataType::Geometry))
+    }
+
+    fn call(&self, inputs: FunctionArgs<Series>) -> DaftResult<Series> {
+        let UnaryArg { input } = inputs.try_into()?;
+        // Fully decode each value so that malformed bodies are rejected, not just bad headers.
+        let values = input
+            .binary()?
+            .as_arrow()
+            .iter()
+            .map(|wkb| {
+                wkb.map(|wkb| from_wkb(wkb).map(|_| wkb.to_vec()))
+                    .transpose()
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        Ok(wkb_to_series(input.name(), values))
+    }
+
+    fn docstring(&self) -> &'static str {
+        "Parses Well-Known Binary (WKB) into a geometry."
+    }
+}
+
+#[must_use]
+pub fn st_geomfromtext(input: ExprRef) -> ExprRef {
+    ScalarFunction::new(StGeomFromText, vec![input]).into()
+}
+
+#[must_use]
+pub fn st_geomfromgeojson(input: ExprRef) -> ExprRef {
+    ScalarFunction::new(StGeomFromGeoJson, vec![input]).into()
+}
+
+#[must_use]
+pub fn st_geomfromwkb(input: ExprRef) -> ExprRef {
+    ScalarFunction::new(StGeomFromWkb, vec![input]).into()
+}
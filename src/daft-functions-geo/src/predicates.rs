@@ -0,0 +1,142 @@
+use common_error::DaftResult;
+use daft_core::{
+    datatypes::{BooleanArray, DataType, Field, Float64Array},
+    prelude::Schema,
+    series::{IntoSeries, Series},
+};
+use daft_dsl::{
+    functions::{FunctionArgs, ScalarFunction, ScalarUDF},
+    ExprRef,
+};
+use geo::{Contains, Distance, Euclidean, Geometry, Intersects};
+use serde::{Deserialize, Serialize};
+
+use crate::utils::{binary_geometry_op, ensure_geometry};
+
+#[derive(common_macros::FunctionArgs)]
+struct GeometryPairArgs<T> {
+    input: T,
+    other: T,
+}
+
+fn pair_return_field(
+    function: &str,
+    dtype: DataType,
+    inputs: FunctionArgs<ExprRef>,
+    schema: &Schema,
+) -> DaftResult<Field> {
+    let GeometryPairArgs { input, other } = inputs.try_into()?;
+    let input = input.to_field(schema)?;
+    let other = other.to_field(schema)?;
+    ensure_geometry(function, &input)?;
+    ensure_geometry(function, &other)?;
+    Ok(Field::new(input.name, dtype))
+}
+
+fn boolean_predicate(
+    inputs: FunctionArgs<Series>,
+    predicate: fn(&Geometry, &Geometry) -> bool,
+) -> DaftResult<Series> {
+    let GeometryPairArgs { input, other } = inputs.try_into()?;
+    let values = binary_geometry_op(&input, &other, predicate)?;
+    Ok(BooleanArray::from_iter(input.name(), values.into_iter()).into_series())
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct StContains;
+
+#[typetag::serde]
+impl ScalarUDF for StContains {
+    fn name(&self) -> &'static str {
+        "st_contains"
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        pair_return_field(self.name(), DataType::Boolean, inputs, schema)
+    }
+
+    fn call(&self, inputs: FunctionArgs<Series>) -> DaftResult<Series> {
+        boolean_predicate(inputs, |a, b| a.contains(b))
+    }
+
+    fn docstring(&self) -> &'static str {
+        "Returns whether the first geometry completely contains the second."
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct StIntersects;
+
+#[typetag::serde]
+impl ScalarUDF for StIntersects {
+    fn name(&self) -> &'static str {
+        "st_intersects"
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        pair_return_field(self.name(), DataType::Boolean, inputs, schema)
+    }
+
+    fn call(&self, inputs: FunctionArgs<Series>) -> DaftResult<Series> {
+        boolean_predicate(inputs, |a, b| a.intersects(b))
+    }
+
+    fn docstring(&self) -> &'static str {
+        "Returns whether the two geometries share any point."
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct StDistance;
+
+#[typetag::serde]
+impl ScalarUDF for StDistance {
+    fn name(&self) -> &'static str {
+        "st_distance"
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        pair_return_field(self.name(), DataType::Float64, inputs, schema)
+    }
+
+    fn call(&self, inputs: FunctionArgs<Series>) -> DaftResult<Series> {
+        let GeometryPairArgs { input, other } = inputs.try_into()?;
+        let values = binary_geometry_op(&input, &other, |a, b| Euclidean::distance(a, b))?;
+        Ok(Float64Array::from_iter(
+            Field::new(input.name(), DataType::Float64),
+            values.into_iter(),
+        )
+        .into_series())
+    }
+
+    fn docstring(&self) -> &'static str {
+        "Returns the minimum planar (Euclidean) distance between the two geometries."
+    }
+}
+
+#[must_use]
+pub fn st_contains(input: ExprRef, other: ExprRef) -> ExprRef {
+    ScalarFunction::new(StContains, vec![input, other]).into()
+}
+
+#[must_use]
+pub fn st_intersects(input: ExprRef, other: ExprRef) -> ExprRef {
+    ScalarFunction::new(StIntersects, vec![input, other]).into()
+}
+
+#[must_use]
+pub fn st_distance(input: ExprRef, other: ExprRef) -> ExprRef {
+    ScalarFunction::new(StDistance, vec![input, other]).into()
+}
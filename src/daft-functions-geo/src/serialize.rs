@@ -0,0 +1,144 @@
+use common_error::DaftResult;
+use daft_core::{
+    datatypes::{DataType, Field, Utf8Array},
+    prelude::Schema,
+    series::{IntoSeries, Series},
+};
+use daft_dsl::{
+    functions::{FunctionArgs, ScalarFunction, ScalarUDF, UnaryArg},
+    ExprRef,
+};
+use geo::Geometry;
+use serde::{Deserialize, Serialize};
+use wkt::ToWkt;
+
+use crate::utils::{decode_geometries, ensure_geometry};
+
+fn to_geojson(geometry: &Geometry) -> String {
+    geojson::Geometry::new(geojson::Value::from(geometry)).to_string()
+}
+
+fn format_geometries(input: &Series, format: fn(&Geometry) -> String) -> DaftResult<Series> {
+    let values = decode_geometries(input)?;
+    Ok(
+        Utf8Array::from_iter(input.name(), values.iter().map(|g| g.as_ref().map(format)))
+            .into_series(),
+    )
+}
+
+fn geometry_return_field(
+    function: &str,
+    dtype: DataType,
+    inputs: FunctionArgs<ExprRef>,
+    schema: &Schema,
+) -> DaftResult<Field> {
+    let UnaryArg { input } = inputs.try_into()?;
+    let input = input.to_field(schema)?;
+    ensure_geometry(function, &input)?;
+    Ok(Field::new(input.name, dtype))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct StAsText;
+
+#[typetag::serde]
+impl ScalarUDF for StAsText {
+    fn name(&self) -> &'static str {
+        "st_astext"
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["st_aswkt"]
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        geometry_return_field(self.name(), DataType::Utf8, inputs, schema)
+    }
+
+    fn call(&self, inputs: FunctionArgs<Series>) -> DaftResult<Series> {
+        let UnaryArg { input } = inputs.try_into()?;
+        format_geometries(&input, |g| g.wkt_string())
+    }
+
+    fn docstring(&self) -> &'static str {
+        "Serializes a geometry as Well-Known Text (WKT)."
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct StAsGeoJson;
+
+#[typetag::serde]
+impl ScalarUDF for StAsGeoJson {
+    fn name(&self) -> &'static str {
+        "st_asgeojson"
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        geometry_return_field(self.name(), DataType::Utf8, inputs, schema)
+    }
+
+    fn call(&self, inputs: FunctionArgs<Series>) -> DaftResult<Series> {
+        let UnaryArg { input } = inputs.try_into()?;
+        format_geometries(&input, to_geojson)
+    }
+
+    fn docstring(&self) -> &'static str {
+        "Serializes a geometry as a GeoJSON geometry object."
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct StAsWkb;
+
+#[typetag::serde]
+impl ScalarUDF for StAsWkb {
+    fn name(&self) -> &'static str {
+        "st_aswkb"
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["st_asbinary"]
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        geometry_return_field(self.name(), DataType::Binary, inputs, schema)
+    }
+
+    fn call(&self, inputs: FunctionArgs<Series>) -> DaftResult<Series> {
+        let UnaryArg { input } = inputs.try_into()?;
+        // Geometries are already stored as WKB.
+        input.cast(&DataType::Binary)
+    }
+
+    fn docstring(&self) -> &'static str {
+        "Serializes a geometry as Well-Known Binary (WKB)."
+    }
+}
+
+#[must_use]
+pub fn st_astext(input: ExprRef) -> ExprRef {
+    ScalarFunction::new(StAsText, vec![input]).into()
+}
+
+#[must_use]
+pub fn st_asgeojson(input: ExprRef) -> ExprRef {
+    ScalarFunction::new(StAsGeoJson, vec![input]).into()
+}
+
+#[must_use]
+pub fn st_aswkb(input: ExprRef) -> ExprRef {
+    ScalarFunction::new(StAsWkb, vec![input]).into()
+}
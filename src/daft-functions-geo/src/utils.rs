@@ -0,0 +1,85 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    array::ops::as_arrow::AsArrow,
+    datatypes::{logical::GeometryArray, BinaryArray, DataType, Field},
+    series::{IntoSeries, Series},
+};
+use geo::Geometry;
+
+use crate::wkb::{from_wkb, to_wkb};
+
+pub(crate) fn ensure_geometry(function: &str, field: &Field) -> DaftResult<()> {
+    match field.dtype {
+        DataType::Geometry | DataType::Null => Ok(()),
+        _ => Err(DaftError::TypeError(format!(
+            "Expected input to {function} to be a Geometry, but received {field}"
+        ))),
+    }
+}
+
+/// Decodes every value of a Geometry (or Null) series.
+pub(crate) fn decode_geometries(series: &Series) -> DaftResult<Vec<Option<Geometry>>> {
+    match series.data_type() {
+        DataType::Null => Ok(vec![None; series.len()]),
+        DataType::Geometry => series
+            .geometry()?
+            .physical
+            .as_arrow()
+            .iter()
+            .map(|wkb| wkb.map(from_wkb).transpose())
+            .collect(),
+        other => Err(DaftError::TypeError(format!(
+            "Expected a Geometry series, but received {other}"
+        ))),
+    }
+}
+
+/// Builds a Geometry series from already encoded WKB values.
+pub(crate) fn wkb_to_series(name: &str, values: Vec<Option<Vec<u8>>>) -> Series {
+    let physical = BinaryArray::from_iter(name, values.into_iter());
+    GeometryArray::new(Field::new(name, DataType::Geometry), physical).into_series()
+}
+
+/// Builds a Geometry series by encoding each geometry as WKB.
+pub(crate) fn geometries_to_series(name: &str, values: Vec<Option<Geometry>>) -> Series {
+    wkb_to_series(
+        name,
+        values.into_iter().map(|g| g.as_ref().map(to_wkb)).collect(),
+    )
+}
+
+/// Applies `f` pairwise over two geometry series, broadcasting unit-length inputs.
+pub(crate) fn binary_geometry_op<T>(
+    left: &Series,
+    right: &Series,
+    f: impl Fn(&Geometry, &Geometry) -> T,
+) -> DaftResult<Vec<Option<T>>> {
+    let len = match (left.len(), right.len()) {
+        (l, r) if l == r => l,
+        (1, r) => r,
+        (l, 1) => l,
+        (l, r) => {
+            return Err(DaftError::ValueError(format!(
+                "Expected inputs to have the same length or a length of 1, but received {l} and {r}"
+            )))
+        }
+    };
+    let left = decode_geometries(left)?;
+    let right = decode_geometries(right)?;
+    Ok((0..len)
+        .map(
+            |i| match (broadcast_get(&left, i), broadcast_get(&right, i)) {
+                (Some(l), Some(r)) => Some(f(l, r)),
+                _ => None,
+            },
+        )
+        .collect())
+}
+
+fn broadcast_get(values: &[Option<Geometry>], idx: usize) -> Option<&Geometry> {
+    if values.len() == 1 {
+        values[0].as_ref()
+    } else {
+        values[idx].as_ref()
+    }
+}
@@ -0,0 +1,258 @@
+//! Conversion between Well-Known Binary (WKB) and [`geo::Geometry`].
+//!
+//! Geometries are always written as little-endian 2D ISO WKB. When reading, Z and M ordinates are
+//! accepted but dropped, since `geo` only models planar geometries.
+use common_error::{DaftError, DaftResult};
+use daft_core::utils::wkb::{read_header, WkbGeometryType, WkbHeader};
+use geo::{
+    Coord, Geometry, GeometryCollection, LineString, MultiLineString, MultiPoint, MultiPolygon,
+    Point, Polygon,
+};
+
+fn invalid(msg: impl std::fmt::Display) -> DaftError {
+    DaftError::ValueError(format!("Invalid WKB geometry: {msg}"))
+}
+
+struct Reader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> DaftResult<&'a [u8]> {
+        let end = self
+            .pos
+            .checked_add(n)
+            .filter(|end| *end <= self.buf.len())
+            .ok_or_else(|| invalid(format!("unexpected end of input at byte {}", self.pos)))?;
+        let out = &self.buf[self.pos..end];
+        self.pos = end;
+        Ok(out)
+    }
+
+    fn u32(&mut self, header: &WkbHeader) -> DaftResult<u32> {
+        let bytes: [u8; 4] = self.take(4)?.try_into().unwrap();
+        Ok(if header.little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    fn f64(&mut self, header: &WkbHeader) -> DaftResult<f64> {
+        let bytes: [u8; 8] = self.take(8)?.try_into().unwrap();
+        Ok(if header.little_endian {
+            f64::from_le_bytes(bytes)
+        } else {
+            f64::from_be_bytes(bytes)
+        })
+    }
+
+    fn header(&mut self) -> DaftResult<WkbHeader> {
+        let header = read_header(&self.buf[self.pos..])?;
+        // byte order + type code, plus the SRID if present.
+        self.take(if header.has_srid { 9 } else { 5 })?;
+        Ok(header)
+    }
+
+    fn coord(&mut self, header: &WkbHeader) -> DaftResult<Coord> {
+        let x = self.f64(header)?;
+        let y = self.f64(header)?;
+        for _ in 0..(usize::from(header.has_z) + usize::from(header.has_m)) {
+            self.f64(header)?;
+        }
+        Ok(Coord { x, y })
+    }
+
+    fn line_string(&mut self, header: &WkbHeader) -> DaftResult<LineString> {
+        let n = self.u32(header)?;
+        (0..n)
+            .map(|_| self.coord(header))
+            .collect::<DaftResult<Vec<_>>>()
+            .map(LineString::new)
+    }
+
+    fn polygon(&mut self, header: &WkbHeader) -> DaftResult<Polygon> {
+        let rings = self.u32(header)?;
+        let mut rings = (0..rings)
+            .map(|_| self.line_string(header))
+            .collect::<DaftResult<Vec<_>>>()?;
+        if rings.is_empty() {
+            return Ok(Polygon::new(LineString::new(vec![]), vec![]));
+        }
+        let exterior = rings.remove(0);
+        Ok(Polygon::new(exterior, rings))
+    }
+
+    fn parts(&mut self, header: &WkbHeader) -> DaftResult<Vec<Geometry>> {
+        let n = self.u32(header)?;
+        (0..n).map(|_| self.geometry()).collect()
+    }
+
+    fn geometry(&mut self) -> DaftResult<Geometry> {
+        let header = self.header()?;
+        let geometry = match header.geometry_type {
+            WkbGeometryType::Point => {
+                let coord = self.coord(&header)?;
+                if coord.x.is_nan() && coord.y.is_nan() {
+                    return Err(invalid("empty points are not supported"));
+                }
+                Geometry::Point(Point(coord))
+            }
+            WkbGeometryType::LineString => Geometry::LineString(self.line_string(&header)?),
+            WkbGeometryType::Polygon => Geometry::Polygon(self.polygon(&header)?),
+            WkbGeometryType::MultiPoint => Geometry::MultiPoint(MultiPoint::new(
+                self.parts(&header)?
+                    .into_iter()
+                    .map(|g| Point::try_from(g).map_err(|_| invalid("expected point")))
+                    .collect::<DaftResult<_>>()?,
+            )),
+            WkbGeometryType::MultiLineString => Geometry::MultiLineString(MultiLineString::new(
+                self.parts(&header)?
+                    .into_iter()
+                    .map(|g| LineString::try_from(g).map_err(|_| invalid("expected linestring")))
+                    .collect::<DaftResult<_>>()?,
+            )),
+            WkbGeometryType::MultiPolygon => Geometry::MultiPolygon(MultiPolygon::new(
+                self.parts(&header)?
+                    .into_iter()
+                    .map(|g| Polygon::try_from(g).map_err(|_| invalid("expected polygon")))
+                    .collect::<DaftResult<_>>()?,
+            )),
+            WkbGeometryType::GeometryCollection => {
+                Geometry::GeometryCollection(GeometryCollection::new_from(self.parts(&header)?))
+            }
+        };
+        Ok(geometry)
+    }
+}
+
+/// Parses a WKB value into a [`Geometry`].
+pub fn from_wkb(wkb: &[u8]) -> DaftResult<Geometry> {
+    let mut reader = Reader { buf: wkb, pos: 0 };
+    let geometry = reader.geometry()?;
+    if reader.pos != wkb.len() {
+        return Err(invalid(format!(
+            "{} trailing bytes",
+            wkb.len() - reader.pos
+        )));
+    }
+    Ok(geometry)
+}
+
+fn write_header(out: &mut Vec<u8>, geometry_type: WkbGeometryType) {
+    out.push(1);
+    out.extend_from_slice(&geometry_type.code().to_le_bytes());
+}
+
+fn write_u32(out: &mut Vec<u8>, n: usize) {
+    out.extend_from_slice(&(n as u32).to_le_bytes());
+}
+
+fn write_coords<'a>(out: &mut Vec<u8>, coords: impl ExactSizeIterator<Item = &'a Coord>) {
+    write_u32(out, coords.len());
+    for coord in coords {
+        out.extend_from_slice(&coord.x.to_le_bytes());
+        out.extend_from_slice(&coord.y.to_le_bytes());
+    }
+}
+
+fn write_polygon(out: &mut Vec<u8>, polygon: &Polygon) {
+    write_header(out, WkbGeometryType::Polygon);
+    if polygon.exterior().0.is_empty() && polygon.interiors().is_empty() {
+        write_u32(out, 0);
+        return;
+    }
+    write_u32(out, 1 + polygon.interiors().len());
+    write_coords(out, polygon.exterior().0.iter());
+    for interior in polygon.interiors() {
+        write_coords(out, interior.0.iter());
+    }
+}
+
+fn write_geometry(out: &mut Vec<u8>, geometry: &Geometry) {
+    match geometry {
+        Geometry::Point(p) => {
+            write_header(out, WkbGeometryType::Point);
+            out.extend_from_slice(&p.x().to_le_bytes());
+            out.extend_from_slice(&p.y().to_le_bytes());
+        }
+        Geometry::Line(l) => {
+            write_header(out, WkbGeometryType::LineString);
+            write_coords(out, [l.start, l.end].iter());
+        }
+        Geometry::LineString(ls) => {
+            write_header(out, WkbGeometryType::LineString);
+            write_coords(out, ls.0.iter());
+        }
+        Geometry::Polygon(p) => write_polygon(out, p),
+        Geometry::Rect(r) => write_polygon(out, &r.to_polygon()),
+        Geometry::Triangle(t) => write_polygon(out, &t.to_polygon()),
+        Geometry::MultiPoint(mp) => {
+            write_header(out, WkbGeometryType::MultiPoint);
+            write_u32(out, mp.0.len());
+            for p in &mp.0 {
+                write_geometry(out, &Geometry::Point(*p));
+            }
+        }
+        Geometry::MultiLineString(mls) => {
+            write_header(out, WkbGeometryType::MultiLineString);
+            write_u32(out, mls.0.len());
+            for ls in &mls.0 {
+                write_header(out, WkbGeometryType::LineString);
+                write_coords(out, ls.0.iter());
+            }
+        }
+        Geometry::MultiPolygon(mp) => {
+            write_header(out, WkbGeometryType::MultiPolygon);
+            write_u32(out, mp.0.len());
+            for p in &mp.0 {
+                write_polygon(out, p);
+            }
+        }
+        Geometry::GeometryCollection(gc) => {
+            write_header(out, WkbGeometryType::GeometryCollection);
+            write_u32(out, gc.0.len());
+            for g in &gc.0 {
+                write_geometry(out, g);
+            }
+        }
+    }
+}
+
+/// Serializes a [`Geometry`] as little-endian 2D WKB.
+pub fn to_wkb(geometry: &Geometry) -> Vec<u8> {
+    let mut out = Vec::new();
+    write_geometry(&mut out, geometry);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use geo::{line_string, point, polygon};
+
+    use super::*;
+
+    #[test]
+    fn test_wkb_round_trip() {
+        let geometries: Vec<Geometry> = vec![
+            point!(x: 1.0, y: 2.0).into(),
+            line_string![(x: 0.0, y: 0.0), (x: 1.0, y: 1.0)].into(),
+            polygon![(x: 0.0, y: 0.0), (x: 1.0, y: 0.0), (x: 1.0, y: 1.0), (x: 0.0, y: 0.0)].into(),
+            Geometry::MultiPoint(vec![point!(x: 1.0, y: 2.0), point!(x: 3.0, y: 4.0)].into()),
+            Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+                point!(x: 1.0, y: 2.0).into(),
+            ])),
+        ];
+        for geometry in geometries {
+            let wkb = to_wkb(&geometry);
+            assert_eq!(from_wkb(&wkb).unwrap(), geometry);
+        }
+    }
+
+    #[test]
+    fn test_wkb_truncated() {
+        let wkb = to_wkb(&point!(x: 1.0, y: 2.0).into());
+        assert!(from_wkb(&wkb[..wkb.len() - 1]).is_err());
+    }
+}
@@ -0,0 +1,317 @@
+use std::{borrow::Cow, fmt::Write, ops::RangeInclusive, sync::Arc};
+
+use arrow2::{
+    datatypes::DataType as ArrowDataType,
+    io::json::read::{
+        self,
+        json_deserializer::{Number, Value},
+    },
+};
+use common_error::DaftError;
+use daft_core::{prelude::Utf8Array, series::IntoSeries};
+use daft_dsl::functions::prelude::*;
+
+use crate::path::JsonPath;
+
+/// Extracts the values selected by a JSONPath from a UTF-8 array of JSON documents.
+///
+/// Each document is parsed once and the selected values are read straight into an array of the
+/// given `dtype`, or into their JSON text if there is no `dtype`. Values which don't match the
+/// `dtype` are null, so documents can have different shapes.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct JsonExtract;
+
+#[derive(FunctionArgs)]
+struct JsonExtractArgs<T> {
+    input: T,
+    path: String,
+    #[arg(optional)]
+    dtype: Option<DataType>,
+}
+
+#[typetag::serde]
+impl ScalarUDF for JsonExtract {
+    fn name(&self) -> &'static str {
+        "json_extract"
+    }
+
+    fn docstring(&self) -> &'static str {
+        "Extracts the values selected by a JSONPath from a JSON string expression, as the given data type or as JSON strings."
+    }
+
+    fn get_return_field(&self, args: FunctionArgs<ExprRef>, schema: &Schema) -> DaftResult<Field> {
+        let JsonExtractArgs { input, path, dtype } = args.try_into()?;
+        let input = input.to_field(schema)?;
+        ensure!(input.dtype == DataType::Utf8, TypeError: "Input must be a string type");
+        path.parse::<JsonPath>()?;
+        Ok(Field::new(input.name, dtype.unwrap_or(DataType::Utf8)))
+    }
+
+    fn call(&self, args: FunctionArgs<Series>) -> DaftResult<Series> {
+        let JsonExtractArgs { input, path, dtype } = args.try_into()?;
+        extract(input.utf8()?, &path.parse()?, dtype.as_ref())
+    }
+}
+
+pub(crate) fn extract(
+    input: &Utf8Array,
+    path: &JsonPath,
+    dtype: Option<&DataType>,
+) -> DaftResult<Series> {
+    let values = input
+        .into_iter()
+        .map(|item| item.map_or(Ok(Value::Null), |text| Ok(path.extract(&parse(text)?))))
+        .collect::<DaftResult<Vec<_>>>()?;
+    let Some(dtype) = dtype else {
+        let texts = values.iter().map(|value| match value {
+            Value::Null => None,
+            _ => Some(to_json(value)),
+        });
+        return Ok(Utf8Array::from_iter(input.name(), texts).into_series());
+    };
+    let field = Field::new(input.name(), dtype.clone());
+    let values = values
+        .into_iter()
+        .map(|value| conform(value, dtype))
+        .collect();
+    let arrow2_dtype = ArrowDataType::LargeList(Box::new(field.to_arrow()?));
+    let arrow2_array = read::deserialize(&Value::Array(values), arrow2_dtype)?;
+    Series::from_arrow(Arc::new(field), arrow2_array)
+}
+
+/// Infers the data type of the values selected by a JSONPath from the first `sample_size`
+/// documents, merging the fields of objects and widening any conflicting types to strings.
+pub fn infer_dtype(input: &Utf8Array, path: &JsonPath, sample_size: usize) -> DaftResult<DataType> {
+    let values = input
+        .into_iter()
+        .flatten()
+        .take(sample_size)
+        .map(|text| Ok(path.extract(&parse(text)?)))
+        .collect::<DaftResult<Vec<_>>>()?;
+    match read::infer(&Value::Array(values))? {
+        ArrowDataType::List(field) => Ok(field.data_type().into()),
+        // every sampled value was null, so fall back to the JSON text.
+        _ => Ok(DataType::Utf8),
+    }
+}
+
+fn parse(text: &str) -> DaftResult<Value<'_>> {
+    read::json_deserializer::parse(text.as_bytes())
+        .map_err(|e| DaftError::ValueError(format!("Failed to parse JSON: {e}")))
+}
+
+/// Reshapes a value towards the `dtype` where the arrow deserializer would otherwise give a null:
+/// objects and arrays read as strings are kept as JSON text, and scalars read as lists become a
+/// single item list. Numbers that don't fit an integer `dtype` become null.
+fn conform<'a>(value: Value<'a>, dtype: &DataType) -> Value<'a> {
+    match (dtype, value) {
+        (DataType::Utf8, value @ (Value::Object(_) | Value::Array(_))) => {
+            Value::String(Cow::Owned(to_json(&value)))
+        }
+        (DataType::List(child), Value::Array(items)) => {
+            Value::Array(items.into_iter().map(|item| conform(item, child)).collect())
+        }
+        (DataType::List(_), Value::Null) => Value::Null,
+        (DataType::List(child), value) => Value::Array(vec![conform(value, child)]),
+        (DataType::Struct(fields), Value::Object(map)) => Value::Object(
+            map.into_iter()
+                .filter_map(|(name, value)| {
+                    let field = fields.iter().find(|field| field.name == name)?;
+                    let value = conform(value, &field.dtype);
+                    Some((name, value))
+                })
+                .collect(),
+        ),
+        (dtype, Value::Number(number)) => match integer_range(dtype) {
+            Some(range) if !integer_part(&number).is_some_and(|i| range.contains(&i)) => {
+                Value::Null
+            }
+            _ => Value::Number(number),
+        },
+        (_, value) => value,
+    }
+}
+
+/// The values of an integer `dtype`, or `None` for any other type.
+fn integer_range(dtype: &DataType) -> Option<RangeInclusive<i128>> {
+    let range = match dtype {
+        DataType::Int8 => i8::MIN.into()..=i8::MAX.into(),
+        DataType::Int16 => i16::MIN.into()..=i16::MAX.into(),
+        DataType::Int32 => i32::MIN.into()..=i32::MAX.into(),
+        DataType::Int64 => i64::MIN.into()..=i64::MAX.into(),
+        DataType::UInt8 => 0..=u8::MAX.into(),
+        DataType::UInt16 => 0..=u16::MAX.into(),
+        DataType::UInt32 => 0..=u32::MAX.into(),
+        DataType::UInt64 => 0..=u64::MAX.into(),
+        _ => return None,
+    };
+    Some(range)
+}
+
+/// The truncated integer part of a number without an exponent, which is what the arrow
+/// deserializer reads into integers (it panics on anything that doesn't fit).
+fn integer_part(number: &Number) -> Option<i128> {
+    let (Number::Integer(digits, exponent) | Number::Float(digits, exponent)) = number;
+    if !exponent.is_empty() {
+        return None;
+    }
+    let integer = digits.split(|c| *c == b'.').next()?;
+    std::str::from_utf8(integer).ok()?.parse().ok()
+}
+
+fn to_json(value: &Value) -> String {
+    let mut out = String::new();
+    write_json(value, &mut out);
+    out
+}
+
+fn write_json(value: &Value, out: &mut String) {
+    match value {
+        Value::Null => out.push_str("null"),
+        Value::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+        Value::Number(Number::Integer(number, exponent) | Number::Float(number, exponent)) => {
+            out.push_str(&String::from_utf8_lossy(number));
+            if !exponent.is_empty() {
+                out.push('e');
+                out.push_str(&String::from_utf8_lossy(exponent));
+            }
+        }
+        Value::String(s) => write_string(s, out),
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_json(item, out);
+            }
+            out.push(']');
+        }
+        Value::Object(map) => {
+            out.push('{');
+            for (i, (name, item)) in map.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_string(name, out);
+                out.push(':');
+                write_json(item, out);
+            }
+            out.push('}');
+        }
+    }
+}
+
+fn write_string(s: &str, out: &mut String) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use daft_core::prelude::AsArrow;
+
+    use super::*;
+
+    fn documents() -> Utf8Array {
+        Utf8Array::from_iter(
+            "data",
+            [
+                Some(r#"{"a": {"b": 1, "c": "x"}, "tags": ["p", "q"]}"#),
+                Some(r#"{"a": {"b": 2.5, "d": [1, {"e": "\"y\""}]}, "tags": "r"}"#),
+                None,
+                Some(r#"{"a": 3}"#),
+            ]
+            .iter()
+            .copied(),
+        )
+    }
+
+    #[test]
+    fn test_extract_json_text() -> DaftResult<()> {
+        let result = extract(&documents(), &"$.a.d".parse()?, None)?;
+        let result = result.utf8()?.as_arrow().iter().collect::<Vec<_>>();
+        assert_eq!(result, [None, Some(r#"[1,{"e":"\"y\""}]"#), None, None]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_extract_inferred_dtype() -> DaftResult<()> {
+        let path = "$.a".parse()?;
+        // the fields of each object are merged, and conflicting types widen to strings.
+        let dtype = infer_dtype(&documents(), &path, 2)?;
+        assert_eq!(
+            dtype,
+            DataType::Struct(vec![
+                Field::new("b", DataType::Float64),
+                Field::new("c", DataType::Utf8),
+                Field::new("d", DataType::List(Box::new(DataType::Utf8))),
+            ])
+        );
+        // the integer in the last row conflicts with the objects.
+        assert_eq!(infer_dtype(&documents(), &path, 100)?, DataType::Utf8);
+
+        let path = "$.tags".parse()?;
+        let dtype = infer_dtype(&documents(), &path, 100)?;
+        assert_eq!(dtype, DataType::List(Box::new(DataType::Utf8)));
+        let result = extract(&documents(), &path, Some(&dtype))?;
+        assert_eq!(result.list()?.offsets().as_slice(), &[0, 2, 3, 3, 3]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_extract_struct_with_different_shapes() -> DaftResult<()> {
+        let dtype = DataType::Struct(vec![
+            Field::new("b", DataType::Float64),
+            Field::new("d", DataType::Utf8),
+        ]);
+        let result = extract(&documents(), &"$.a".parse()?, Some(&dtype))?;
+        let result = result.struct_()?;
+        let b = result.children[0]
+            .f64()?
+            .as_arrow()
+            .iter()
+            .map(|v| v.copied())
+            .collect::<Vec<_>>();
+        assert_eq!(b, [Some(1.0), Some(2.5), None, None]);
+        assert_eq!(result.len(), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn test_extract_integers_out_of_range() -> DaftResult<()> {
+        let input = Utf8Array::from_values(
+            "data",
+            vec!["300", "-1", "2.5", "1e3", "255", "\"1\""].into_iter(),
+        );
+        let result = extract(&input, &"$".parse()?, Some(&DataType::UInt8))?;
+        let result = result
+            .u8()?
+            .as_arrow()
+            .iter()
+            .map(|v| v.copied())
+            .collect::<Vec<_>>();
+        assert_eq!(result, [None, None, Some(2), None, Some(255), None]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_extract_invalid_json() {
+        let input = Utf8Array::from_values("data", vec!["{"].into_iter());
+        let path = "$.a".parse().unwrap();
+        assert!(extract(&input, &path, None).is_err());
+    }
+}
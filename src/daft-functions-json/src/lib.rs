@@ -1,6 +1,13 @@
 use daft_dsl::functions::{FunctionModule, FunctionRegistry};
 
+mod extract;
 mod jq;
+mod path;
+#[cfg(feature = "python")]
+mod python;
+
+#[cfg(feature = "python")]
+pub use python::register_modules;
 
 /// JsonFunctions module.
 pub struct JsonFunctions;
@@ -8,6 +15,7 @@ pub struct JsonFunctions;
 /// JsonFunctions module registration.
 impl FunctionModule for JsonFunctions {
     fn register(parent: &mut FunctionRegistry) {
+        parent.add_fn(crate::extract::JsonExtract);
         parent.add_fn(crate::jq::Jq);
     }
 }
@@ -0,0 +1,383 @@
+//! A JSONPath implementation over parsed JSON values.
+//!
+//! The supported syntax is the subset of [RFC 9535](https://www.rfc-editor.org/rfc/rfc9535) without
+//! filter expressions: `$`, `.name`, `['name']`, `[0]`, `[-1]`, `[start:end:step]`, `[*]`, `.*`,
+//! unions like `[0, 'name']` and descendants like `..name`. A leading `$` is optional, so `a.b` is
+//! the same as `$.a.b`.
+
+use std::str::FromStr;
+
+use arrow2::io::json::read::json_deserializer::Value;
+use common_error::{DaftError, DaftResult};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Selector {
+    Name(String),
+    Index(i64),
+    Slice {
+        start: Option<i64>,
+        end: Option<i64>,
+        step: i64,
+    },
+    Wildcard,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Segment {
+    /// Whether the selectors apply to every descendant (`..`) rather than only the children.
+    descendant: bool,
+    selectors: Vec<Selector>,
+}
+
+/// A parsed JSONPath expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonPath {
+    segments: Vec<Segment>,
+}
+
+impl JsonPath {
+    /// Whether the path selects at most one value, i.e. it only has single names and indices.
+    pub fn is_definite(&self) -> bool {
+        self.segments.iter().all(|segment| {
+            !segment.descendant
+                && matches!(
+                    segment.selectors.as_slice(),
+                    [Selector::Name(_) | Selector::Index(_)]
+                )
+        })
+    }
+
+    /// Returns every value selected by the path, in document order.
+    pub fn select<'v, 'a>(&self, root: &'v Value<'a>) -> Vec<&'v Value<'a>> {
+        let mut nodes = vec![root];
+        for segment in &self.segments {
+            let mut selected = vec![];
+            for node in nodes {
+                if segment.descendant {
+                    let mut descendants = vec![];
+                    collect_descendants(node, &mut descendants);
+                    for descendant in descendants {
+                        apply_selectors(&segment.selectors, descendant, &mut selected);
+                    }
+                } else {
+                    apply_selectors(&segment.selectors, node, &mut selected);
+                }
+            }
+            nodes = selected;
+        }
+        nodes
+    }
+
+    /// Extracts the selected value for a definite path (or null if there is none), otherwise an
+    /// array of all the selected values.
+    pub fn extract<'a>(&self, root: &Value<'a>) -> Value<'a> {
+        let selected = self.select(root);
+        if self.is_definite() {
+            selected
+                .first()
+                .map_or(Value::Null, |value| (*value).clone())
+        } else {
+            Value::Array(selected.into_iter().cloned().collect())
+        }
+    }
+}
+
+impl FromStr for JsonPath {
+    type Err = DaftError;
+
+    fn from_str(path: &str) -> DaftResult<Self> {
+        Parser { path, pos: 0 }.parse()
+    }
+}
+
+fn collect_descendants<'v, 'a>(node: &'v Value<'a>, out: &mut Vec<&'v Value<'a>>) {
+    out.push(node);
+    match node {
+        Value::Array(items) => items.iter().for_each(|item| collect_descendants(item, out)),
+        Value::Object(map) => map.values().for_each(|item| collect_descendants(item, out)),
+        _ => {}
+    }
+}
+
+fn apply_selectors<'v, 'a>(
+    selectors: &[Selector],
+    node: &'v Value<'a>,
+    out: &mut Vec<&'v Value<'a>>,
+) {
+    for selector in selectors {
+        match (selector, node) {
+            (Selector::Name(name), Value::Object(map)) => out.extend(map.get(name)),
+            (Selector::Wildcard, Value::Object(map)) => out.extend(map.values()),
+            (Selector::Wildcard, Value::Array(items)) => out.extend(items),
+            (Selector::Index(index), Value::Array(items)) => {
+                let len = items.len() as i64;
+                let index = if *index < 0 { index + len } else { *index };
+                if (0..len).contains(&index) {
+                    out.push(&items[index as usize]);
+                }
+            }
+            (Selector::Slice { start, end, step }, Value::Array(items)) => {
+                out.extend(
+                    slice_indices(items.len() as i64, *start, *end, *step).map(|i| &items[i]),
+                );
+            }
+            _ => {}
+        }
+    }
+}
+
+/// The indices selected by a slice, following the semantics of RFC 9535.
+fn slice_indices(
+    len: i64,
+    start: Option<i64>,
+    end: Option<i64>,
+    step: i64,
+) -> impl Iterator<Item = usize> {
+    let normalize = |i: i64| if i < 0 { i + len } else { i };
+    let (mut i, bound) = match step {
+        0 => (0, 0),
+        1.. => (
+            normalize(start.unwrap_or(0)).clamp(0, len),
+            normalize(end.unwrap_or(len)).clamp(0, len),
+        ),
+        _ => (
+            start.map_or(len - 1, normalize).clamp(-1, len - 1),
+            end.map_or(-1, normalize).clamp(-1, len - 1),
+        ),
+    };
+    std::iter::from_fn(move || {
+        let in_bounds = if step > 0 { i < bound } else { i > bound };
+        if step == 0 || !in_bounds {
+            return None;
+        }
+        let index = i as usize;
+        i += step;
+        Some(index)
+    })
+}
+
+struct Parser<'p> {
+    path: &'p str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn parse(mut self) -> DaftResult<JsonPath> {
+        let mut segments = vec![];
+        if !self.eat('$') && self.peek().is_some_and(is_name_char) {
+            // a bare leading name is relative to the root.
+            segments.push(Segment {
+                descendant: false,
+                selectors: vec![Selector::Name(self.name()?)],
+            });
+        }
+        while let Some(c) = self.peek() {
+            let segment = match c {
+                '.' => {
+                    self.pos += 1;
+                    let descendant = self.eat('.');
+                    let selectors = if self.eat('*') {
+                        vec![Selector::Wildcard]
+                    } else if descendant && self.peek() == Some('[') {
+                        self.bracket()?
+                    } else {
+                        vec![Selector::Name(self.name()?)]
+                    };
+                    Segment {
+                        descendant,
+                        selectors,
+                    }
+                }
+                '[' => Segment {
+                    descendant: false,
+                    selectors: self.bracket()?,
+                },
+                _ => return Err(self.error("expected '.' or '['")),
+            };
+            segments.push(segment);
+        }
+        Ok(JsonPath { segments })
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.path[self.pos..].chars().next()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> DaftResult<()> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected '{c}'")))
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek().filter(|c| c.is_whitespace()) {
+            self.pos += c.len_utf8();
+        }
+    }
+
+    fn error(&self, reason: &str) -> DaftError {
+        DaftError::ValueError(format!(
+            "Invalid JSONPath '{}' at position {}: {reason}",
+            self.path, self.pos
+        ))
+    }
+
+    fn name(&mut self) -> DaftResult<String> {
+        let start = self.pos;
+        while let Some(c) = self.peek().filter(|c| is_name_char(*c)) {
+            self.pos += c.len_utf8();
+        }
+        if self.pos == start {
+            return Err(self.error("expected a member name"));
+        }
+        Ok(self.path[start..self.pos].to_string())
+    }
+
+    /// Parses a comma separated list of selectors within square brackets.
+    fn bracket(&mut self) -> DaftResult<Vec<Selector>> {
+        self.expect('[')?;
+        let mut selectors = vec![];
+        loop {
+            self.skip_whitespace();
+            selectors.push(self.selector()?);
+            self.skip_whitespace();
+            if !self.eat(',') {
+                break;
+            }
+        }
+        self.expect(']')?;
+        Ok(selectors)
+    }
+
+    fn selector(&mut self) -> DaftResult<Selector> {
+        match self.peek() {
+            Some('*') => {
+                self.pos += 1;
+                Ok(Selector::Wildcard)
+            }
+            Some(quote @ ('\'' | '"')) => {
+                self.pos += 1;
+                self.quoted(quote).map(Selector::Name)
+            }
+            _ => {
+                let start = self.integer()?;
+                if !self.eat(':') {
+                    return start
+                        .map(Selector::Index)
+                        .ok_or_else(|| self.error("expected a selector"));
+                }
+                let end = self.integer()?;
+                let step = if self.eat(':') { self.integer()? } else { None };
+                Ok(Selector::Slice {
+                    start,
+                    end,
+                    step: step.unwrap_or(1),
+                })
+            }
+        }
+    }
+
+    fn integer(&mut self) -> DaftResult<Option<i64>> {
+        self.skip_whitespace();
+        let start = self.pos;
+        self.eat('-');
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        let digits = &self.path[start..self.pos];
+        self.skip_whitespace();
+        if digits.is_empty() {
+            return Ok(None);
+        }
+        digits
+            .parse()
+            .map(Some)
+            .map_err(|_| self.error(&format!("invalid integer '{digits}'")))
+    }
+
+    /// Parses the rest of a quoted member name, unescaping backslashes.
+    fn quoted(&mut self, quote: char) -> DaftResult<String> {
+        let mut name = String::new();
+        loop {
+            let Some(c) = self.peek() else {
+                return Err(self.error("unterminated string"));
+            };
+            self.pos += c.len_utf8();
+            match c {
+                '\\' => {
+                    let Some(escaped) = self.peek() else {
+                        return Err(self.error("unterminated string"));
+                    };
+                    self.pos += escaped.len_utf8();
+                    name.push(match escaped {
+                        'n' => '\n',
+                        't' => '\t',
+                        'r' => '\r',
+                        c => c,
+                    });
+                }
+                c if c == quote => return Ok(name),
+                c => name.push(c),
+            }
+        }
+    }
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '-' || !c.is_ascii()
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow2::io::json::read::json_deserializer::parse;
+
+    use super::*;
+
+    fn select(path: &str, json: &str) -> Vec<String> {
+        let value = parse(json.as_bytes()).unwrap();
+        let path: JsonPath = path.parse().unwrap();
+        path.select(&value)
+            .into_iter()
+            .map(|value| format!("{value:?}"))
+            .collect()
+    }
+
+    #[test]
+    fn test_json_path() {
+        let json = r#"{"a": {"b": [1, 2, 3, 4]}, "c": [{"b": 5}, {"d": 6}]}"#;
+        assert_eq!(select("$.a.b[0]", json), select("$['a'][\"b\"][-4]", json));
+        assert_eq!(select("a.b[1:3]", json), select("$.a.b[1,2]", json));
+        assert_eq!(select("$.a.b[::-2]", json), select("$.a.b[3, 1]", json));
+        assert_eq!(select("$..b", json).len(), 2);
+        assert_eq!(select("$.c[*].b", json).len(), 1);
+        assert_eq!(select("$.c.*", json).len(), 2);
+        assert!(select("$.a.b[4]", json).is_empty());
+        assert!(select("$.missing.b", json).is_empty());
+    }
+
+    #[test]
+    fn test_json_path_definite() {
+        assert!("$.a[0]['b']".parse::<JsonPath>().unwrap().is_definite());
+        assert!(!"$.a[*]".parse::<JsonPath>().unwrap().is_definite());
+        assert!(!"$..a".parse::<JsonPath>().unwrap().is_definite());
+        assert!(!"$[0,1]".parse::<JsonPath>().unwrap().is_definite());
+    }
+
+    #[test]
+    fn test_json_path_invalid() {
+        for path in ["$.", "$[", "$['a'", "$[a]", "$ a", "$.a[1"] {
+            assert!(path.parse::<JsonPath>().is_err(), "{path}");
+        }
+    }
+}
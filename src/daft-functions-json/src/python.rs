@@ -0,0 +1,23 @@
+use daft_core::python::{PyDataType, PySeries};
+use pyo3::{prelude::*, wrap_pyfunction};
+
+use crate::{extract::infer_dtype, path::JsonPath};
+
+/// Infers the data type of the values selected by a JSONPath from a sample of JSON strings.
+#[pyfunction(signature = (input, path="$", sample_size=1000))]
+pub fn infer_json_dtype(
+    py: Python,
+    input: PySeries,
+    path: &str,
+    sample_size: usize,
+) -> PyResult<PyDataType> {
+    py.allow_threads(|| {
+        let path = path.parse::<JsonPath>()?;
+        Ok(infer_dtype(input.series.utf8()?, &path, sample_size)?.into())
+    })
+}
+
+pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
+    parent.add_function(wrap_pyfunction!(infer_json_dtype, parent)?)?;
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use daft_dsl::functions::prelude::*;
 
-use crate::format::Format;
+use crate::format::{Format, FormatOptions};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct Deserialize;
@@ -10,6 +10,24 @@ pub struct DeserializeArgs<T> {
     input: T,
     format: Format,
     dtype: DataType,
+    #[arg(optional)]
+    schema: Option<String>,
+    #[arg(optional)]
+    descriptor_set: Option<Vec<u8>>,
+    #[arg(optional)]
+    message: Option<String>,
+}
+
+impl<T> DeserializeArgs<T> {
+    fn options(&self) -> DaftResult<FormatOptions> {
+        let options = FormatOptions {
+            schema: self.schema.clone(),
+            descriptor_set: self.descriptor_set.clone(),
+            message: self.message.clone(),
+        };
+        self.format.validate_options(&options)?;
+        Ok(options)
+    }
 }
 
 #[typetag::serde]
@@ -19,7 +37,7 @@ impl ScalarUDF for Deserialize {
     }
 
     fn docstring(&self) -> &'static str {
-        "Deserializes the expression (string) using the specified format and data type."
+        "Deserializes the expression (string for json, otherwise binary) using the specified format and data type."
     }
 
     fn get_return_field(
@@ -31,12 +49,9 @@ impl ScalarUDF for Deserialize {
     }
 
     fn call(&self, inputs: FunctionArgs<Series>) -> DaftResult<Series> {
-        let DeserializeArgs {
-            input,
-            format,
-            dtype,
-        } = inputs.try_into()?;
-        format.deserializer()(input.utf8()?, &dtype)
+        let args: DeserializeArgs<Series> = inputs.try_into()?;
+        let options = args.options()?;
+        args.format.deserializer()(&args.input, &args.dtype, &options)
     }
 }
 
@@ -50,7 +65,7 @@ impl ScalarUDF for TryDeserialize {
     }
 
     fn docstring(&self) -> &'static str {
-        "Deserializes the expression (string) using the specified format and data type, insert null on parsing failures."
+        "Deserializes the expression (string for json, otherwise binary) using the specified format and data type, insert null on parsing failures."
     }
 
     fn get_return_field(
@@ -62,28 +77,25 @@ impl ScalarUDF for TryDeserialize {
     }
 
     fn call(&self, inputs: FunctionArgs<Series>) -> DaftResult<Series> {
-        let DeserializeArgs {
-            input,
-            format,
-            dtype,
-        } = inputs.try_into()?;
-        format.try_deserializer()(input.utf8()?, &dtype)
+        let args: DeserializeArgs<Series> = inputs.try_into()?;
+        let options = args.options()?;
+        args.format.try_deserializer()(&args.input, &args.dtype, &options)
     }
 }
 
 fn get_field(inputs: FunctionArgs<ExprRef>, schema: &Schema) -> DaftResult<Field> {
     // validate argument arity
-    let DeserializeArgs {
-        input,
-        format: _,
-        dtype,
-    } = inputs.try_into()?;
-    // validate input argument type, [try_]deserialize currently only supports string inputs.
-    let input = input.to_field(schema)?;
+    let args: DeserializeArgs<ExprRef> = inputs.try_into()?;
+    args.options()?;
+    // validate input argument type, json takes string inputs and the other formats take binary.
+    let input = args.input.to_field(schema)?;
     ensure!(
-        input.dtype.is_string(),
-        "text argument must be of string type."
+        input.dtype == args.format.serialized_dtype(),
+        TypeError: "{} input argument must be of {} type, got {}.",
+        args.format,
+        args.format.serialized_dtype(),
+        input.dtype
     );
     // use name of the single argument as the output field name
-    Ok(Field::new(input.name, dtype))
+    Ok(Field::new(input.name, args.dtype))
 }
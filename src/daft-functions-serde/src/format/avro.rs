@@ -0,0 +1,596 @@
+use std::collections::{HashMap, HashSet};
+
+use common_error::{DaftError, DaftResult};
+use daft_core::{prelude::DataType, series::Series};
+use serde_json::Value as JsonValue;
+
+use super::{
+    codec::{self, write_varint, zigzag_decode, zigzag_encode, Codec, Reader},
+    value::Value,
+    FormatOptions,
+};
+
+/// Deserializes each Avro single-object input to the `dtype`.
+pub(crate) fn deserialize(
+    input: &Series,
+    dtype: &DataType,
+    options: &FormatOptions,
+) -> DaftResult<Series> {
+    codec::deserialize(input, dtype, &Avro::new(dtype, options)?, true)
+}
+
+/// Deserializes each Avro single-object input to the `dtype`, inserting null on any parsing failure.
+pub(crate) fn try_deserialize(
+    input: &Series,
+    dtype: &DataType,
+    options: &FormatOptions,
+) -> DaftResult<Series> {
+    codec::deserialize(input, dtype, &Avro::new(dtype, options)?, false)
+}
+
+/// Serializes each input value with the Avro single-object encoding.
+pub(crate) fn serialize(input: Series, options: &FormatOptions) -> DaftResult<Series> {
+    let codec = Avro::new(input.data_type(), options)?;
+    codec::serialize(&input, &codec, true)
+}
+
+/// Serializes each input value with the Avro single-object encoding, inserting null on any failures.
+pub(crate) fn try_serialize(input: Series, options: &FormatOptions) -> DaftResult<Series> {
+    let codec = Avro::new(input.data_type(), options)?;
+    codec::serialize(&input, &codec, false)
+}
+
+/// The two byte marker which starts each single-object encoded item.
+const MAGIC: [u8; 2] = [0xc3, 0x01];
+
+/// The Avro single-object encoding, see
+/// <https://avro.apache.org/docs/1.11.1/specification/#single-object-encoding>.
+///
+/// Each item is the marker, the fingerprint of the writer schema and the binary encoding of the
+/// value. The schema is given as JSON, else it is derived from the daft type where every field and
+/// list item is nullable.
+struct Avro {
+    schema: Schema,
+    fingerprint: u64,
+}
+
+impl Avro {
+    fn new(dtype: &DataType, options: &FormatOptions) -> DaftResult<Self> {
+        let schema = match &options.schema {
+            Some(schema) => {
+                let json = serde_json::from_str(schema).map_err(|e| {
+                    DaftError::ValueError(format!("Failed to parse Avro schema: {e}"))
+                })?;
+                Schema::parse(&json, None, &mut HashMap::new())?
+            }
+            None => Schema::from_dtype(dtype, "record")?,
+        };
+        let fingerprint = fingerprint(schema.canonical_form(&mut HashSet::new()).as_bytes());
+        Ok(Self {
+            schema,
+            fingerprint,
+        })
+    }
+}
+
+impl Codec for Avro {
+    fn encode(&self, value: &Value) -> DaftResult<Vec<u8>> {
+        let mut out = Vec::from(MAGIC);
+        out.extend(self.fingerprint.to_le_bytes());
+        write_value(&mut out, &self.schema, value)?;
+        Ok(out)
+    }
+
+    fn decode(&self, bytes: &[u8]) -> DaftResult<Value> {
+        let mut reader = Reader::new(bytes, "Avro");
+        if reader.array()? != MAGIC {
+            return Err(reader.error("missing single-object marker"));
+        }
+        let fingerprint = u64::from_le_bytes(reader.array()?);
+        if fingerprint != self.fingerprint {
+            return Err(reader.error(format!(
+                "schema fingerprint {fingerprint:#018x} does not match {:#018x}",
+                self.fingerprint
+            )));
+        }
+        let value = read_value(&mut reader, &self.schema)?;
+        if !reader.is_empty() {
+            return Err(reader.error("trailing bytes"));
+        }
+        Ok(value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Schema {
+    Null,
+    Boolean,
+    Int,
+    Long,
+    Float,
+    Double,
+    Bytes,
+    String,
+    Record {
+        name: String,
+        fields: Vec<(String, Schema)>,
+    },
+    Enum {
+        name: String,
+        symbols: Vec<String>,
+    },
+    Array(Box<Schema>),
+    Map(Box<Schema>),
+    Union(Vec<Schema>),
+    Fixed {
+        name: String,
+        size: usize,
+    },
+}
+
+impl Schema {
+    /// Derives a schema for the `dtype`, naming records and fixed types after their path.
+    fn from_dtype(dtype: &DataType, name: &str) -> DaftResult<Self> {
+        let nullable = |schema: Self| match schema {
+            Self::Null => Self::Null,
+            schema => Self::Union(vec![Self::Null, schema]),
+        };
+        Ok(match dtype {
+            DataType::Null => Self::Null,
+            DataType::Boolean => Self::Boolean,
+            DataType::Int8
+            | DataType::Int16
+            | DataType::Int32
+            | DataType::UInt8
+            | DataType::UInt16
+            | DataType::Date => Self::Int,
+            DataType::Int64
+            | DataType::UInt32
+            | DataType::UInt64
+            | DataType::Timestamp(..)
+            | DataType::Time(_)
+            | DataType::Duration(_) => Self::Long,
+            DataType::Float32 => Self::Float,
+            DataType::Float64 => Self::Double,
+            DataType::Utf8 => Self::String,
+            DataType::Binary => Self::Bytes,
+            DataType::FixedSizeBinary(size) => Self::Fixed {
+                name: name.to_string(),
+                size: *size,
+            },
+            DataType::List(child) | DataType::FixedSizeList(child, _) => {
+                Self::Array(Box::new(nullable(Self::from_dtype(child, name)?)))
+            }
+            DataType::Struct(fields) => Self::Record {
+                name: name.to_string(),
+                fields: fields
+                    .iter()
+                    .map(|field| {
+                        let schema =
+                            Self::from_dtype(&field.dtype, &format!("{name}_{}", field.name))?;
+                        Ok((field.name.clone(), nullable(schema)))
+                    })
+                    .collect::<DaftResult<_>>()?,
+            },
+            DataType::Map { key, value } if key.is_string() => {
+                Self::Map(Box::new(nullable(Self::from_dtype(value, name)?)))
+            }
+            dtype if dtype.is_logical() && !dtype.is_map() => {
+                Self::from_dtype(&dtype.to_physical(), name)?
+            }
+            dtype => {
+                return Err(DaftError::TypeError(format!(
+                    "Cannot derive an Avro schema for type {dtype}, please provide a schema"
+                )))
+            }
+        })
+    }
+
+    /// Parses a JSON schema, where `names` holds the named types defined so far.
+    fn parse(
+        json: &JsonValue,
+        namespace: Option<&str>,
+        names: &mut HashMap<String, Option<Self>>,
+    ) -> DaftResult<Self> {
+        let invalid = || DaftError::ValueError(format!("Invalid Avro schema: {json}"));
+        match json {
+            JsonValue::String(name) => match name.as_str() {
+                "null" => Ok(Self::Null),
+                "boolean" => Ok(Self::Boolean),
+                "int" => Ok(Self::Int),
+                "long" => Ok(Self::Long),
+                "float" => Ok(Self::Float),
+                "double" => Ok(Self::Double),
+                "bytes" => Ok(Self::Bytes),
+                "string" => Ok(Self::String),
+                name => {
+                    let fullname = match namespace {
+                        Some(namespace) if !name.contains('.') => format!("{namespace}.{name}"),
+                        _ => name.to_string(),
+                    };
+                    match names.get(&fullname).or_else(|| names.get(name)) {
+                        Some(Some(schema)) => Ok(schema.clone()),
+                        Some(None) => Err(DaftError::ValueError(format!(
+                            "Recursive Avro schemas are not supported, {fullname} refers to itself"
+                        ))),
+                        None => Err(DaftError::ValueError(format!(
+                            "Unknown Avro type {fullname}"
+                        ))),
+                    }
+                }
+            },
+            JsonValue::Array(branches) => branches
+                .iter()
+                .map(|branch| Self::parse(branch, namespace, names))
+                .collect::<DaftResult<_>>()
+                .map(Self::Union),
+            JsonValue::Object(object) => {
+                let ty = object.get("type").ok_or_else(invalid)?;
+                let Some(ty) = ty.as_str() else {
+                    return Self::parse(ty, namespace, names);
+                };
+                let (fullname, namespace) = match ty {
+                    "record" | "error" | "enum" | "fixed" => {
+                        let name = object
+                            .get("name")
+                            .and_then(JsonValue::as_str)
+                            .ok_or_else(invalid)?;
+                        let namespace = object
+                            .get("namespace")
+                            .and_then(JsonValue::as_str)
+                            .or(namespace);
+                        let fullname = match (name.rsplit_once('.'), namespace) {
+                            (Some(_), _) | (None, None | Some("")) => name.to_string(),
+                            (None, Some(namespace)) => format!("{namespace}.{name}"),
+                        };
+                        let namespace = fullname.rsplit_once('.').map(|(ns, _)| ns.to_string());
+                        (fullname, namespace)
+                    }
+                    _ => (String::new(), namespace.map(str::to_string)),
+                };
+                let namespace = namespace.as_deref();
+                let schema = match ty {
+                    "record" | "error" => {
+                        names.insert(fullname.clone(), None);
+                        let fields = object
+                            .get("fields")
+                            .and_then(JsonValue::as_array)
+                            .ok_or_else(invalid)?
+                            .iter()
+                            .map(|field| {
+                                let name = field
+                                    .get("name")
+                                    .and_then(JsonValue::as_str)
+                                    .ok_or_else(invalid)?;
+                                let ty = field.get("type").ok_or_else(invalid)?;
+                                Ok((name.to_string(), Self::parse(ty, namespace, names)?))
+                            })
+                            .collect::<DaftResult<_>>()?;
+                        Self::Record {
+                            name: fullname.clone(),
+                            fields,
+                        }
+                    }
+                    "enum" => Self::Enum {
+                        name: fullname.clone(),
+                        symbols: object
+                            .get("symbols")
+                            .and_then(JsonValue::as_array)
+                            .ok_or_else(invalid)?
+                            .iter()
+                            .map(|symbol| symbol.as_str().map(str::to_string).ok_or_else(invalid))
+                            .collect::<DaftResult<_>>()?,
+                    },
+                    "fixed" => Self::Fixed {
+                        name: fullname.clone(),
+                        size: object
+                            .get("size")
+                            .and_then(JsonValue::as_u64)
+                            .ok_or_else(invalid)? as usize,
+                    },
+                    "array" => Self::Array(Box::new(Self::parse(
+                        object.get("items").ok_or_else(invalid)?,
+                        namespace,
+                        names,
+                    )?)),
+                    "map" => Self::Map(Box::new(Self::parse(
+                        object.get("values").ok_or_else(invalid)?,
+                        namespace,
+                        names,
+                    )?)),
+                    // primitive types with attributes e.g. logical types.
+                    _ => Self::parse(&JsonValue::String(ty.to_string()), namespace, names)?,
+                };
+                if !fullname.is_empty() {
+                    names.insert(fullname, Some(schema.clone()));
+                }
+                Ok(schema)
+            }
+            _ => Err(invalid()),
+        }
+    }
+
+    /// Returns the Parsing Canonical Form of the schema, where `seen` holds the names of the types
+    /// written so far which are then written by name only, see
+    /// <https://avro.apache.org/docs/1.11.1/specification/#parsing-canonical-form-for-schemas>.
+    fn canonical_form(&self, seen: &mut HashSet<String>) -> String {
+        let quote = |s: &str| JsonValue::String(s.to_string()).to_string();
+        match self {
+            Self::Null => quote("null"),
+            Self::Boolean => quote("boolean"),
+            Self::Int => quote("int"),
+            Self::Long => quote("long"),
+            Self::Float => quote("float"),
+            Self::Double => quote("double"),
+            Self::Bytes => quote("bytes"),
+            Self::String => quote("string"),
+            Self::Record { name, .. } | Self::Enum { name, .. } | Self::Fixed { name, .. }
+                if !seen.insert(name.clone()) =>
+            {
+                quote(name)
+            }
+            Self::Record { name, fields } => {
+                let fields = fields
+                    .iter()
+                    .map(|(name, schema)| {
+                        format!(
+                            "{{\"name\":{},\"type\":{}}}",
+                            quote(name),
+                            schema.canonical_form(seen)
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                format!(
+                    "{{\"name\":{},\"type\":\"record\",\"fields\":[{}]}}",
+                    quote(name),
+                    fields.join(",")
+                )
+            }
+            Self::Enum { name, symbols } => format!(
+                "{{\"name\":{},\"type\":\"enum\",\"symbols\":[{}]}}",
+                quote(name),
+                symbols
+                    .iter()
+                    .map(|s| quote(s))
+                    .collect::<Vec<_>>()
+                    .join(",")
+            ),
+            Self::Array(items) => format!(
+                "{{\"type\":\"array\",\"items\":{}}}",
+                items.canonical_form(seen)
+            ),
+            Self::Map(values) => format!(
+                "{{\"type\":\"map\",\"values\":{}}}",
+                values.canonical_form(seen)
+            ),
+            Self::Union(branches) => format!(
+                "[{}]",
+                branches
+                    .iter()
+                    .map(|branch| branch.canonical_form(seen))
+                    .collect::<Vec<_>>()
+                    .join(",")
+            ),
+            Self::Fixed { name, size } => format!(
+                "{{\"name\":{},\"type\":\"fixed\",\"size\":{size}}}",
+                quote(name)
+            ),
+        }
+    }
+
+    /// Whether a value can be written with this schema, used to pick the branch of a union.
+    fn accepts(&self, value: &Value) -> bool {
+        matches!(
+            (self, value),
+            (Self::Null, Value::Null)
+                | (Self::Boolean, Value::Bool(_))
+                | (
+                    Self::Int | Self::Long | Self::Float | Self::Double,
+                    Value::Int(_) | Value::UInt(_)
+                )
+                | (Self::Float | Self::Double, Value::Float(_))
+                | (Self::String | Self::Enum { .. }, Value::String(_))
+                | (Self::Bytes, Value::Bytes(_) | Value::String(_))
+                | (Self::Array(_), Value::Array(_))
+                | (Self::Record { .. } | Self::Map(_), Value::Map(_))
+        ) || matches!((self, value), (Self::Fixed { size, .. }, Value::Bytes(b)) if b.len() == *size)
+    }
+}
+
+/// The CRC-64-AVRO fingerprint of the bytes.
+fn fingerprint(bytes: &[u8]) -> u64 {
+    const EMPTY: u64 = 0xc15d_213a_a4d7_a795;
+    let mut table = [0u64; 256];
+    for (i, entry) in table.iter_mut().enumerate() {
+        let mut fp = i as u64;
+        for _ in 0..8 {
+            fp = (fp >> 1) ^ (EMPTY & (fp & 1).wrapping_neg());
+        }
+        *entry = fp;
+    }
+    bytes.iter().fold(EMPTY, |fp, b| {
+        (fp >> 8) ^ table[((fp ^ u64::from(*b)) & 0xff) as usize]
+    })
+}
+
+fn mismatch(schema: &Schema, value: &Value) -> DaftError {
+    DaftError::ValueError(format!(
+        "Failed to write Avro: cannot write {value:?} as {}",
+        schema.canonical_form(&mut HashSet::new())
+    ))
+}
+
+fn write_long(out: &mut Vec<u8>, value: i64) {
+    write_varint(out, zigzag_encode(value));
+}
+
+fn write_bytes(out: &mut Vec<u8>, bytes: &[u8]) {
+    write_long(out, bytes.len() as i64);
+    out.extend(bytes);
+}
+
+fn write_value(out: &mut Vec<u8>, schema: &Schema, value: &Value) -> DaftResult<()> {
+    match (schema, value) {
+        (Schema::Null, Value::Null) => {}
+        (Schema::Boolean, Value::Bool(b)) => out.push(u8::from(*b)),
+        (Schema::Int, value) => {
+            let i = value
+                .as_i64()
+                .and_then(|i| i32::try_from(i).ok())
+                .ok_or_else(|| mismatch(schema, value))?;
+            write_long(out, i64::from(i));
+        }
+        (Schema::Long, value) => {
+            let i = value.as_i64().ok_or_else(|| mismatch(schema, value))?;
+            write_long(out, i);
+        }
+        (Schema::Float, value) => {
+            let f = value.as_f64().ok_or_else(|| mismatch(schema, value))?;
+            out.extend((f as f32).to_le_bytes());
+        }
+        (Schema::Double, value) => {
+            let f = value.as_f64().ok_or_else(|| mismatch(schema, value))?;
+            out.extend(f.to_le_bytes());
+        }
+        (Schema::Bytes, Value::Bytes(b)) => write_bytes(out, b),
+        (Schema::Bytes | Schema::String, Value::String(s)) => write_bytes(out, s.as_bytes()),
+        (Schema::Record { fields, .. }, Value::Map(_)) => {
+            for (name, field_schema) in fields {
+                write_value(out, field_schema, value.get(name).unwrap_or(&Value::Null))?;
+            }
+        }
+        (Schema::Enum { symbols, .. }, Value::String(s)) => {
+            let index = symbols
+                .iter()
+                .position(|symbol| symbol == s)
+                .ok_or_else(|| mismatch(schema, value))?;
+            write_long(out, index as i64);
+        }
+        (Schema::Array(items), Value::Array(values)) => {
+            if !values.is_empty() {
+                write_long(out, values.len() as i64);
+                for value in values {
+                    write_value(out, items, value)?;
+                }
+            }
+            write_long(out, 0);
+        }
+        (Schema::Map(values), Value::Map(entries)) => {
+            if !entries.is_empty() {
+                write_long(out, entries.len() as i64);
+                for (key, value) in entries {
+                    let Value::String(key) = key else {
+                        return Err(mismatch(&Schema::String, key));
+                    };
+                    write_bytes(out, key.as_bytes());
+                    write_value(out, values, value)?;
+                }
+            }
+            write_long(out, 0);
+        }
+        (Schema::Union(branches), value) => {
+            let index = branches
+                .iter()
+                .position(|branch| branch.accepts(value))
+                .ok_or_else(|| mismatch(schema, value))?;
+            write_long(out, index as i64);
+            write_value(out, &branches[index], value)?;
+        }
+        (Schema::Fixed { size, .. }, Value::Bytes(b)) if b.len() == *size => out.extend(b),
+        (schema, value) => return Err(mismatch(schema, value)),
+    }
+    Ok(())
+}
+
+fn read_long(reader: &mut Reader) -> DaftResult<i64> {
+    Ok(zigzag_decode(reader.varint()?))
+}
+
+fn read_len(reader: &mut Reader) -> DaftResult<usize> {
+    let len = read_long(reader)?;
+    usize::try_from(len).map_err(|_| reader.error(format!("invalid length {len}")))
+}
+
+/// Reads the blocks of an array or map, calling `read_item` for each item.
+fn read_blocks(
+    reader: &mut Reader,
+    mut read_item: impl FnMut(&mut Reader) -> DaftResult<()>,
+) -> DaftResult<()> {
+    loop {
+        let count = read_long(reader)?;
+        if count == 0 {
+            return Ok(());
+        }
+        if count < 0 {
+            // negative counts are followed by the size of the block in bytes.
+            read_long(reader)?;
+        }
+        for _ in 0..count.unsigned_abs() {
+            read_item(reader)?;
+        }
+    }
+}
+
+fn read_value(reader: &mut Reader, schema: &Schema) -> DaftResult<Value> {
+    let value = match schema {
+        Schema::Null => Value::Null,
+        Schema::Boolean => match reader.u8()? {
+            0 => Value::Bool(false),
+            1 => Value::Bool(true),
+            b => return Err(reader.error(format!("invalid boolean {b}"))),
+        },
+        Schema::Int | Schema::Long => Value::Int(read_long(reader)?),
+        Schema::Float => Value::Float(f64::from(f32::from_le_bytes(reader.array()?))),
+        Schema::Double => Value::Float(f64::from_le_bytes(reader.array()?)),
+        Schema::Bytes => {
+            let len = read_len(reader)?;
+            Value::Bytes(reader.take(len)?.to_vec())
+        }
+        Schema::String => {
+            let len = read_len(reader)?;
+            Value::String(reader.string(len)?)
+        }
+        Schema::Record { fields, .. } => Value::Map(
+            fields
+                .iter()
+                .map(|(name, schema)| {
+                    Ok((Value::String(name.clone()), read_value(reader, schema)?))
+                })
+                .collect::<DaftResult<_>>()?,
+        ),
+        Schema::Enum { symbols, .. } => {
+            let index = read_len(reader)?;
+            let symbol = symbols
+                .get(index)
+                .ok_or_else(|| reader.error(format!("invalid enum index {index}")))?;
+            Value::String(symbol.clone())
+        }
+        Schema::Array(items) => {
+            let mut values = Vec::new();
+            read_blocks(reader, |reader| {
+                values.push(read_value(reader, items)?);
+                Ok(())
+            })?;
+            Value::Array(values)
+        }
+        Schema::Map(values) => {
+            let mut entries = Vec::new();
+            read_blocks(reader, |reader| {
+                let len = read_len(reader)?;
+                let key = reader.string(len)?;
+                entries.push((Value::String(key), read_value(reader, values)?));
+                Ok(())
+            })?;
+            Value::Map(entries)
+        }
+        Schema::Union(branches) => {
+            let index = read_len(reader)?;
+            let branch = branches
+                .get(index)
+                .ok_or_else(|| reader.error(format!("invalid union index {index}")))?;
+            read_value(reader, branch)?
+        }
+        Schema::Fixed { size, .. } => Value::Bytes(reader.take(*size)?.to_vec()),
+    };
+    Ok(value)
+}
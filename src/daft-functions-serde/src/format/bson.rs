@@ -0,0 +1,247 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::{prelude::DataType, series::Series};
+
+use super::{
+    codec::{self, Codec, Reader},
+    value::Value,
+    FormatOptions,
+};
+
+/// Deserializes each BSON document input to the `dtype`.
+pub(crate) fn deserialize(
+    input: &Series,
+    dtype: &DataType,
+    _: &FormatOptions,
+) -> DaftResult<Series> {
+    codec::deserialize(input, dtype, &Bson, true)
+}
+
+/// Deserializes each BSON document input to the `dtype`, inserting null on any parsing failure.
+pub(crate) fn try_deserialize(
+    input: &Series,
+    dtype: &DataType,
+    _: &FormatOptions,
+) -> DaftResult<Series> {
+    codec::deserialize(input, dtype, &Bson, false)
+}
+
+/// Serializes each input struct or map as a BSON document.
+pub(crate) fn serialize(input: Series, _: &FormatOptions) -> DaftResult<Series> {
+    codec::serialize(&input, &Bson, true)
+}
+
+/// Serializes each input struct or map as a BSON document, inserting null on any failures.
+pub(crate) fn try_serialize(input: Series, _: &FormatOptions) -> DaftResult<Series> {
+    codec::serialize(&input, &Bson, false)
+}
+
+/// The BSON format, see <https://bsonspec.org/spec.html>.
+///
+/// Each item is a document, so only structs and maps with string keys can be serialized. Object
+/// ids are read as binary, and datetimes and timestamps as integers.
+struct Bson;
+
+impl Codec for Bson {
+    fn encode(&self, value: &Value) -> DaftResult<Vec<u8>> {
+        let Value::Map(entries) = value else {
+            return Err(DaftError::ValueError(
+                "Failed to write BSON: only structs and maps can be written as documents"
+                    .to_string(),
+            ));
+        };
+        let mut out = Vec::new();
+        write_document(&mut out, entries.iter().map(|(k, v)| (k, v)))?;
+        Ok(out)
+    }
+
+    fn decode(&self, bytes: &[u8]) -> DaftResult<Value> {
+        let mut reader = Reader::new(bytes, "BSON");
+        let entries = read_document(&mut reader)?;
+        if !reader.is_empty() {
+            return Err(reader.error("trailing bytes"));
+        }
+        Ok(Value::Map(
+            entries
+                .into_iter()
+                .map(|(k, v)| (Value::String(k), v))
+                .collect(),
+        ))
+    }
+}
+
+fn write_document<'a>(
+    out: &mut Vec<u8>,
+    entries: impl Iterator<Item = (&'a Value, &'a Value)>,
+) -> DaftResult<()> {
+    // the length is patched in once the document is written.
+    let start = out.len();
+    out.extend([0; 4]);
+    for (key, value) in entries {
+        let Value::String(key) = key else {
+            return Err(DaftError::ValueError(format!(
+                "Failed to write BSON: document keys must be strings, got {key:?}"
+            )));
+        };
+        if key.contains('\0') {
+            return Err(DaftError::ValueError(format!(
+                "Failed to write BSON: document key {key:?} contains a null byte"
+            )));
+        }
+        write_element(out, key, value)?;
+    }
+    out.push(0);
+    let len = i32::try_from(out.len() - start).map_err(|_| {
+        DaftError::ValueError("Failed to write BSON: document is too large".to_string())
+    })?;
+    out[start..start + 4].copy_from_slice(&len.to_le_bytes());
+    Ok(())
+}
+
+fn write_element(out: &mut Vec<u8>, key: &str, value: &Value) -> DaftResult<()> {
+    let type_index = out.len();
+    out.push(0);
+    out.extend(key.as_bytes());
+    out.push(0);
+    let element_type = match value {
+        Value::Null => 0x0a,
+        Value::Bool(b) => {
+            out.push(u8::from(*b));
+            0x08
+        }
+        Value::Int(i) => {
+            if let Ok(i) = i32::try_from(*i) {
+                out.extend(i.to_le_bytes());
+                0x10
+            } else {
+                out.extend(i.to_le_bytes());
+                0x12
+            }
+        }
+        Value::UInt(u) => {
+            if let Ok(i) = i32::try_from(*u) {
+                out.extend(i.to_le_bytes());
+                0x10
+            } else if let Ok(i) = i64::try_from(*u) {
+                out.extend(i.to_le_bytes());
+                0x12
+            } else {
+                return Err(DaftError::ValueError(format!(
+                    "Failed to write BSON: integer {u} is out of range"
+                )));
+            }
+        }
+        Value::Float(f) => {
+            out.extend(f.to_le_bytes());
+            0x01
+        }
+        Value::String(s) => {
+            out.extend((s.len() as i32 + 1).to_le_bytes());
+            out.extend(s.as_bytes());
+            out.push(0);
+            0x02
+        }
+        Value::Bytes(b) => {
+            out.extend((b.len() as i32).to_le_bytes());
+            // generic binary subtype.
+            out.push(0x00);
+            out.extend(b);
+            0x05
+        }
+        Value::Array(items) => {
+            // arrays are documents keyed by the index of each item.
+            let keys = (0..items.len())
+                .map(|i| Value::String(i.to_string()))
+                .collect::<Vec<_>>();
+            write_document(out, keys.iter().zip(items))?;
+            0x04
+        }
+        Value::Map(entries) => {
+            write_document(out, entries.iter().map(|(k, v)| (k, v)))?;
+            0x03
+        }
+    };
+    out[type_index] = element_type;
+    Ok(())
+}
+
+fn read_document(reader: &mut Reader) -> DaftResult<Vec<(String, Value)>> {
+    let start = reader.position();
+    let len = i32::from_le_bytes(reader.array()?);
+    let end = usize::try_from(len)
+        .ok()
+        .and_then(|len| start.checked_add(len))
+        .ok_or_else(|| reader.error(format!("invalid document length {len}")))?;
+    let mut entries = Vec::new();
+    loop {
+        let element_type = reader.u8()?;
+        if element_type == 0 {
+            break;
+        }
+        let key = read_cstring(reader)?;
+        let value = read_element(reader, element_type)?;
+        entries.push((key, value));
+    }
+    if reader.position() != end {
+        return Err(reader.error("document length does not match its contents"));
+    }
+    Ok(entries)
+}
+
+fn read_element(reader: &mut Reader, element_type: u8) -> DaftResult<Value> {
+    let value = match element_type {
+        0x01 => Value::Float(f64::from_le_bytes(reader.array()?)),
+        0x02 => {
+            let len = i32::from_le_bytes(reader.array()?);
+            let len = usize::try_from(len)
+                .ok()
+                .filter(|len| *len > 0)
+                .ok_or_else(|| reader.error(format!("invalid string length {len}")))?;
+            let s = reader.string(len - 1)?;
+            if reader.u8()? != 0 {
+                return Err(reader.error("string is not null-terminated"));
+            }
+            Value::String(s)
+        }
+        0x03 => Value::Map(
+            read_document(reader)?
+                .into_iter()
+                .map(|(k, v)| (Value::String(k), v))
+                .collect(),
+        ),
+        0x04 => Value::Array(read_document(reader)?.into_iter().map(|(_, v)| v).collect()),
+        0x05 => {
+            let len = i32::from_le_bytes(reader.array()?);
+            let len = usize::try_from(len)
+                .map_err(|_| reader.error(format!("invalid binary length {len}")))?;
+            let _subtype = reader.u8()?;
+            Value::Bytes(reader.take(len)?.to_vec())
+        }
+        // undefined (deprecated)
+        0x06 => Value::Null,
+        0x07 => Value::Bytes(reader.take(12)?.to_vec()),
+        0x08 => match reader.u8()? {
+            0 => Value::Bool(false),
+            1 => Value::Bool(true),
+            b => return Err(reader.error(format!("invalid boolean {b}"))),
+        },
+        // UTC datetime in milliseconds since the epoch.
+        0x09 => Value::Int(i64::from_le_bytes(reader.array()?)),
+        0x0a => Value::Null,
+        0x10 => Value::Int(i64::from(i32::from_le_bytes(reader.array()?))),
+        0x11 => Value::UInt(u64::from_le_bytes(reader.array()?)),
+        0x12 => Value::Int(i64::from_le_bytes(reader.array()?)),
+        _ => return Err(reader.error(format!("unsupported element type 0x{element_type:02x}"))),
+    };
+    Ok(value)
+}
+
+fn read_cstring(reader: &mut Reader) -> DaftResult<String> {
+    let mut bytes = Vec::new();
+    loop {
+        match reader.u8()? {
+            0 => break,
+            b => bytes.push(b),
+        }
+    }
+    String::from_utf8(bytes).map_err(|e| reader.error(e))
+}
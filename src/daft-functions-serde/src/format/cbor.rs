@@ -0,0 +1,122 @@
+use ciborium::value::{Integer, Value as CborValue};
+use common_error::{DaftError, DaftResult};
+use daft_core::{prelude::DataType, series::Series};
+
+use super::{
+    codec::{self, Codec},
+    value::Value,
+    FormatOptions,
+};
+
+/// Deserializes each CBOR input to the `dtype`.
+pub(crate) fn deserialize(
+    input: &Series,
+    dtype: &DataType,
+    _: &FormatOptions,
+) -> DaftResult<Series> {
+    codec::deserialize(input, dtype, &Cbor, true)
+}
+
+/// Deserializes each CBOR input to the `dtype`, inserting null on any parsing failure.
+pub(crate) fn try_deserialize(
+    input: &Series,
+    dtype: &DataType,
+    _: &FormatOptions,
+) -> DaftResult<Series> {
+    codec::deserialize(input, dtype, &Cbor, false)
+}
+
+/// Serializes each input value as CBOR.
+pub(crate) fn serialize(input: Series, _: &FormatOptions) -> DaftResult<Series> {
+    codec::serialize(&input, &Cbor, true)
+}
+
+/// Serializes each input value as CBOR, inserting null on any failures.
+pub(crate) fn try_serialize(input: Series, _: &FormatOptions) -> DaftResult<Series> {
+    codec::serialize(&input, &Cbor, false)
+}
+
+/// The CBOR format, see <https://www.rfc-editor.org/rfc/rfc8949.html>.
+struct Cbor;
+
+impl Codec for Cbor {
+    fn encode(&self, value: &Value) -> DaftResult<Vec<u8>> {
+        let mut out = Vec::new();
+        ciborium::into_writer(&to_cbor(value), &mut out)
+            .map_err(|e| DaftError::ValueError(format!("Failed to write CBOR: {e}")))?;
+        Ok(out)
+    }
+
+    fn decode(&self, mut bytes: &[u8]) -> DaftResult<Value> {
+        let value: CborValue = ciborium::from_reader(&mut bytes)
+            .map_err(|e| DaftError::ValueError(format!("Failed to parse CBOR: {e}")))?;
+        if !bytes.is_empty() {
+            return Err(DaftError::ValueError(
+                "Failed to parse CBOR: trailing bytes".to_string(),
+            ));
+        }
+        from_cbor(value)
+    }
+}
+
+fn to_cbor(value: &Value) -> CborValue {
+    match value {
+        Value::Null => CborValue::Null,
+        Value::Bool(b) => CborValue::Bool(*b),
+        Value::Int(i) => CborValue::Integer((*i).into()),
+        Value::UInt(u) => CborValue::Integer((*u).into()),
+        Value::Float(f) => CborValue::Float(*f),
+        Value::String(s) => CborValue::Text(s.clone()),
+        Value::Bytes(b) => CborValue::Bytes(b.clone()),
+        Value::Array(items) => CborValue::Array(items.iter().map(to_cbor).collect()),
+        Value::Map(entries) => CborValue::Map(
+            entries
+                .iter()
+                .map(|(k, v)| (to_cbor(k), to_cbor(v)))
+                .collect(),
+        ),
+    }
+}
+
+fn from_cbor(value: CborValue) -> DaftResult<Value> {
+    Ok(match value {
+        CborValue::Null => Value::Null,
+        CborValue::Bool(b) => Value::Bool(b),
+        CborValue::Integer(i) => from_integer(i)?,
+        CborValue::Float(f) => Value::Float(f),
+        CborValue::Text(s) => Value::String(s),
+        CborValue::Bytes(b) => Value::Bytes(b),
+        CborValue::Array(items) => Value::Array(
+            items
+                .into_iter()
+                .map(from_cbor)
+                .collect::<DaftResult<_>>()?,
+        ),
+        CborValue::Map(entries) => Value::Map(
+            entries
+                .into_iter()
+                .map(|(k, v)| Ok((from_cbor(k)?, from_cbor(v)?)))
+                .collect::<DaftResult<_>>()?,
+        ),
+        // tags only annotate the meaning of their value, e.g. an epoch timestamp.
+        CborValue::Tag(_, value) => from_cbor(*value)?,
+        value => {
+            return Err(DaftError::ValueError(format!(
+                "Failed to parse CBOR: unsupported value {value:?}"
+            )))
+        }
+    })
+}
+
+fn from_integer(i: Integer) -> DaftResult<Value> {
+    let i = i128::from(i);
+    if let Ok(i) = i64::try_from(i) {
+        Ok(Value::Int(i))
+    } else if let Ok(u) = u64::try_from(i) {
+        Ok(Value::UInt(u))
+    } else {
+        Err(DaftError::ValueError(format!(
+            "Failed to parse CBOR: integer {i} is out of range"
+        )))
+    }
+}
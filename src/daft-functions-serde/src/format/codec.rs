@@ -0,0 +1,140 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    prelude::{AsArrow, BinaryArray, DataType},
+    series::{IntoSeries, Series},
+};
+
+use super::value::{self, Value};
+
+/// A binary format which encodes and decodes one [`Value`] per item.
+pub(crate) trait Codec {
+    fn encode(&self, value: &Value) -> DaftResult<Vec<u8>>;
+
+    fn decode(&self, bytes: &[u8]) -> DaftResult<Value>;
+}
+
+/// Encodes each input value with the codec, inserting null on failures unless `strict`.
+pub(crate) fn serialize(input: &Series, codec: &impl Codec, strict: bool) -> DaftResult<Series> {
+    let encoded = value::from_series(input)?
+        .iter()
+        .map(|value| match value {
+            Value::Null => Ok(None),
+            value => match codec.encode(value) {
+                Ok(bytes) => Ok(Some(bytes)),
+                Err(err) if strict => Err(err),
+                Err(_) => Ok(None),
+            },
+        })
+        .collect::<DaftResult<Vec<_>>>()?;
+    Ok(BinaryArray::from_iter(input.name(), encoded.iter().map(Option::as_deref)).into_series())
+}
+
+/// Decodes each input item with the codec to the `dtype`, inserting null on failures unless `strict`.
+pub(crate) fn deserialize(
+    input: &Series,
+    dtype: &DataType,
+    codec: &impl Codec,
+    strict: bool,
+) -> DaftResult<Series> {
+    let values = input
+        .binary()?
+        .as_arrow()
+        .iter()
+        .map(|bytes| match bytes {
+            None => Ok(Value::Null),
+            Some(bytes) => match codec.decode(bytes) {
+                Ok(value) => Ok(value),
+                Err(err) if strict => Err(err),
+                Err(_) => Ok(Value::Null),
+            },
+        })
+        .collect::<DaftResult<Vec<_>>>()?;
+    value::into_series(input.name(), &values, dtype, strict)
+}
+
+/// A cursor over the bytes of a single encoded item.
+pub(crate) struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    format: &'static str,
+}
+
+impl<'a> Reader<'a> {
+    pub(crate) fn new(bytes: &'a [u8], format: &'static str) -> Self {
+        Self {
+            bytes,
+            pos: 0,
+            format,
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.pos >= self.bytes.len()
+    }
+
+    pub(crate) fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Returns an error describing malformed input.
+    pub(crate) fn error(&self, message: impl std::fmt::Display) -> DaftError {
+        DaftError::ValueError(format!(
+            "Failed to parse {}: {} at byte {}",
+            self.format, message, self.pos
+        ))
+    }
+
+    pub(crate) fn take(&mut self, n: usize) -> DaftResult<&'a [u8]> {
+        let end = self
+            .pos
+            .checked_add(n)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or_else(|| self.error("unexpected end of input"))?;
+        let bytes = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    pub(crate) fn array<const N: usize>(&mut self) -> DaftResult<[u8; N]> {
+        Ok(self.take(N)?.try_into().unwrap())
+    }
+
+    pub(crate) fn u8(&mut self) -> DaftResult<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    /// Reads an unsigned LEB128 varint.
+    pub(crate) fn varint(&mut self) -> DaftResult<u64> {
+        let mut result = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.u8()?;
+            result |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(result);
+            }
+        }
+        Err(self.error("varint is too long"))
+    }
+
+    pub(crate) fn string(&mut self, len: usize) -> DaftResult<String> {
+        let bytes = self.take(len)?;
+        String::from_utf8(bytes.to_vec()).map_err(|e| self.error(e))
+    }
+}
+
+/// Writes an unsigned LEB128 varint.
+pub(crate) fn write_varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+pub(crate) fn zigzag_encode(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+pub(crate) fn zigzag_decode(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
@@ -12,11 +12,18 @@ use arrow2::{
 use common_error::{DaftError, DaftResult};
 use daft_core::{
     prelude::{DataType, Field, Utf8Array},
-    series::Series,
+    series::{IntoSeries, Series},
 };
 
+use super::FormatOptions;
+
 /// Deserializes each `text` input to the `dtype`.
-pub(crate) fn deserialize(input: &Utf8Array, dtype: &DataType) -> DaftResult<Series> {
+pub(crate) fn deserialize(
+    input: &Series,
+    dtype: &DataType,
+    _: &FormatOptions,
+) -> DaftResult<Series> {
+    let input = input.utf8()?;
     let field = Field::new(input.name(), dtype.clone());
     // parse each item in the array to a JSON Value, then make a JSON Array.
     let json_items: Vec<Value> = input
@@ -41,7 +48,8 @@ pub fn parse_item(item: Option<&str>) -> DaftResult<Value> {
 }
 
 /// Deserializes each `text` input to the `dtype`, inserting null on any parsing failure.
-pub fn try_deserialize(input: &Utf8Array, dtype: &DataType) -> DaftResult<Series> {
+pub fn try_deserialize(input: &Series, dtype: &DataType, _: &FormatOptions) -> DaftResult<Series> {
+    let input = input.utf8()?;
     let field = Field::new(input.name(), dtype.clone());
     // parse each item in the array to a JSON Value, then make a JSON Array.
     let json_items: Vec<Value> = input.into_iter().map(try_parse_item).collect();
@@ -61,7 +69,7 @@ pub fn try_parse_item(item: Option<&str>) -> Value {
 }
 
 /// Serializes each input value as a JSON string.
-pub fn serialize(input: Series) -> DaftResult<Utf8Array> {
+pub fn serialize(input: Series, _: &FormatOptions) -> DaftResult<Series> {
     // setup inputs
     let name = input.name();
     let input = input.to_arrow();
@@ -83,11 +91,11 @@ pub fn serialize(input: Series) -> DaftResult<Utf8Array> {
         validity,
     );
     let array = Box::new(array);
-    Ok(Utf8Array::from((name, array)))
+    Ok(Utf8Array::from((name, array)).into_series())
 }
 
 /// Serializes each input value as a JSON string, inserting null on any failures.
-pub fn try_serialize(_: Series) -> DaftResult<Utf8Array> {
+pub fn try_serialize(_: Series, _: &FormatOptions) -> DaftResult<Series> {
     // try_serialize will require deeper arrow2 json work, and it is not immediately obvious if it's even useful, so punting here.
     Err(DaftError::ComputeError(
         "try_serialize with json is not currently supported.".to_string(),
@@ -1,31 +1,57 @@
 use std::{fmt::Display, str::FromStr};
 
 use common_error::{value_err, DaftError, DaftResult};
-use daft_core::{
-    prelude::{DataType, Utf8Array},
-    series::Series,
-};
+use daft_core::{prelude::DataType, series::Series};
 use daft_dsl::{FromLiteral, Literal, LiteralValue};
 use serde::{Deserialize, Serialize};
 
+mod avro;
+mod bson;
+mod cbor;
+mod codec;
 mod json;
+mod msgpack;
+mod protobuf;
+mod value;
 
-/// Signature of a deserialization implementation e.g. strings to arbitrary series.
-pub type Deserializer = fn(input: &Utf8Array, dtype: &DataType) -> DaftResult<Series>;
+/// Signature of a deserialization implementation e.g. strings or binary to arbitrary series.
+pub type Deserializer =
+    fn(input: &Series, dtype: &DataType, options: &FormatOptions) -> DaftResult<Series>;
 
-/// Signature of a serialization implementation e.g. arbitrary series to string.
-pub type Serializer = fn(input: Series) -> DaftResult<Utf8Array>;
+/// Signature of a serialization implementation e.g. arbitrary series to strings or binary.
+pub type Serializer = fn(input: Series, options: &FormatOptions) -> DaftResult<Series>;
 
 /// Supported formsts for the serialize and deserialize functions.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum Format {
     Json,
+    MessagePack,
+    Cbor,
+    Bson,
+    Avro,
+    Protobuf,
+}
+
+/// Format specific options for the serialize and deserialize functions.
+#[derive(Debug, Default)]
+pub struct FormatOptions {
+    /// The Avro schema as JSON, which is otherwise derived from the data type.
+    pub schema: Option<String>,
+    /// The serialized protobuf `FileDescriptorSet` which describes the message.
+    pub descriptor_set: Option<Vec<u8>>,
+    /// The fully qualified name of the protobuf message.
+    pub message: Option<String>,
 }
 
 impl Display for Format {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_str(match self {
             Self::Json => "json",
+            Self::MessagePack => "msgpack",
+            Self::Cbor => "cbor",
+            Self::Bson => "bson",
+            Self::Avro => "avro",
+            Self::Protobuf => "protobuf",
         })
     }
 }
@@ -52,6 +78,11 @@ impl FromStr for Format {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
             "json" => Ok(Self::Json),
+            "msgpack" | "messagepack" => Ok(Self::MessagePack),
+            "cbor" => Ok(Self::Cbor),
+            "bson" => Ok(Self::Bson),
+            "avro" => Ok(Self::Avro),
+            "protobuf" => Ok(Self::Protobuf),
             _ => Err(DaftError::not_implemented(format!(
                 "unsupported format: {}",
                 s
@@ -61,27 +92,71 @@ impl FromStr for Format {
 }
 
 impl Format {
+    /// The type of serialized values, text for json and binary otherwise.
+    pub(crate) fn serialized_dtype(&self) -> DataType {
+        match self {
+            Self::Json => DataType::Utf8,
+            _ => DataType::Binary,
+        }
+    }
+
+    /// Validates that the options apply to this format.
+    pub(crate) fn validate_options(&self, options: &FormatOptions) -> DaftResult<()> {
+        if options.schema.is_some() && *self != Self::Avro {
+            value_err!("schema is only supported by the avro format, not {}", self);
+        }
+        if (options.descriptor_set.is_some() || options.message.is_some())
+            && *self != Self::Protobuf
+        {
+            value_err!(
+                "descriptor_set and message are only supported by the protobuf format, not {}",
+                self
+            );
+        }
+        Ok(())
+    }
+
     pub(crate) fn deserializer(&self) -> Deserializer {
         match self {
             Self::Json => json::deserialize,
+            Self::MessagePack => msgpack::deserialize,
+            Self::Cbor => cbor::deserialize,
+            Self::Bson => bson::deserialize,
+            Self::Avro => avro::deserialize,
+            Self::Protobuf => protobuf::deserialize,
         }
     }
 
     pub(crate) fn try_deserializer(&self) -> Deserializer {
         match self {
             Self::Json => json::try_deserialize,
+            Self::MessagePack => msgpack::try_deserialize,
+            Self::Cbor => cbor::try_deserialize,
+            Self::Bson => bson::try_deserialize,
+            Self::Avro => avro::try_deserialize,
+            Self::Protobuf => protobuf::try_deserialize,
         }
     }
 
     pub(crate) fn serializer(&self) -> Serializer {
         match self {
             Self::Json => json::serialize,
+            Self::MessagePack => msgpack::serialize,
+            Self::Cbor => cbor::serialize,
+            Self::Bson => bson::serialize,
+            Self::Avro => avro::serialize,
+            Self::Protobuf => protobuf::serialize,
         }
     }
 
     pub(crate) fn try_serializer(&self) -> Serializer {
         match self {
             Self::Json => json::try_serialize,
+            Self::MessagePack => msgpack::try_serialize,
+            Self::Cbor => cbor::try_serialize,
+            Self::Bson => bson::try_serialize,
+            Self::Avro => avro::try_serialize,
+            Self::Protobuf => protobuf::try_serialize,
         }
     }
 }
@@ -0,0 +1,231 @@
+use common_error::DaftResult;
+use daft_core::{prelude::DataType, series::Series};
+
+use super::{
+    codec::{self, Codec, Reader},
+    value::Value,
+    FormatOptions,
+};
+
+/// Deserializes each MessagePack input to the `dtype`.
+pub(crate) fn deserialize(
+    input: &Series,
+    dtype: &DataType,
+    _: &FormatOptions,
+) -> DaftResult<Series> {
+    codec::deserialize(input, dtype, &MessagePack, true)
+}
+
+/// Deserializes each MessagePack input to the `dtype`, inserting null on any parsing failure.
+pub(crate) fn try_deserialize(
+    input: &Series,
+    dtype: &DataType,
+    _: &FormatOptions,
+) -> DaftResult<Series> {
+    codec::deserialize(input, dtype, &MessagePack, false)
+}
+
+/// Serializes each input value as MessagePack.
+pub(crate) fn serialize(input: Series, _: &FormatOptions) -> DaftResult<Series> {
+    codec::serialize(&input, &MessagePack, true)
+}
+
+/// Serializes each input value as MessagePack, inserting null on any failures.
+pub(crate) fn try_serialize(input: Series, _: &FormatOptions) -> DaftResult<Series> {
+    codec::serialize(&input, &MessagePack, false)
+}
+
+/// The MessagePack format, see <https://github.com/msgpack/msgpack/blob/master/spec.md>.
+struct MessagePack;
+
+impl Codec for MessagePack {
+    fn encode(&self, value: &Value) -> DaftResult<Vec<u8>> {
+        let mut out = Vec::new();
+        write_value(&mut out, value);
+        Ok(out)
+    }
+
+    fn decode(&self, bytes: &[u8]) -> DaftResult<Value> {
+        let mut reader = Reader::new(bytes, "MessagePack");
+        let value = read_value(&mut reader)?;
+        if !reader.is_empty() {
+            return Err(reader.error("trailing bytes"));
+        }
+        Ok(value)
+    }
+}
+
+fn write_value(out: &mut Vec<u8>, value: &Value) {
+    match value {
+        Value::Null => out.push(0xc0),
+        Value::Bool(false) => out.push(0xc2),
+        Value::Bool(true) => out.push(0xc3),
+        Value::Int(i) if *i >= 0 => write_uint(out, *i as u64),
+        Value::Int(i) => write_int(out, *i),
+        Value::UInt(u) => write_uint(out, *u),
+        Value::Float(f) => {
+            out.push(0xcb);
+            out.extend(f.to_be_bytes());
+        }
+        Value::String(s) => {
+            write_len(out, s.len(), Some((0xa0, 32)), Some(0xd9), 0xda, 0xdb);
+            out.extend(s.as_bytes());
+        }
+        Value::Bytes(b) => {
+            write_len(out, b.len(), None, Some(0xc4), 0xc5, 0xc6);
+            out.extend(b);
+        }
+        Value::Array(items) => {
+            write_len(out, items.len(), Some((0x90, 16)), None, 0xdc, 0xdd);
+            for item in items {
+                write_value(out, item);
+            }
+        }
+        Value::Map(entries) => {
+            write_len(out, entries.len(), Some((0x80, 16)), None, 0xde, 0xdf);
+            for (k, v) in entries {
+                write_value(out, k);
+                write_value(out, v);
+            }
+        }
+    }
+}
+
+fn write_uint(out: &mut Vec<u8>, u: u64) {
+    if u < 0x80 {
+        out.push(u as u8);
+    } else if let Ok(u) = u8::try_from(u) {
+        out.extend([0xcc, u]);
+    } else if let Ok(u) = u16::try_from(u) {
+        out.push(0xcd);
+        out.extend(u.to_be_bytes());
+    } else if let Ok(u) = u32::try_from(u) {
+        out.push(0xce);
+        out.extend(u.to_be_bytes());
+    } else {
+        out.push(0xcf);
+        out.extend(u.to_be_bytes());
+    }
+}
+
+fn write_int(out: &mut Vec<u8>, i: i64) {
+    if i >= -32 {
+        out.push(i as u8);
+    } else if let Ok(i) = i8::try_from(i) {
+        out.extend([0xd0, i as u8]);
+    } else if let Ok(i) = i16::try_from(i) {
+        out.push(0xd1);
+        out.extend(i.to_be_bytes());
+    } else if let Ok(i) = i32::try_from(i) {
+        out.push(0xd2);
+        out.extend(i.to_be_bytes());
+    } else {
+        out.push(0xd3);
+        out.extend(i.to_be_bytes());
+    }
+}
+
+/// Writes the header of a string, binary, array or map, using the smallest of its fixed size,
+/// 8, 16 or 32-bit length markers.
+fn write_len(
+    out: &mut Vec<u8>,
+    len: usize,
+    fixed: Option<(u8, usize)>,
+    len8: Option<u8>,
+    len16: u8,
+    len32: u8,
+) {
+    match (fixed, len8) {
+        (Some((marker, max)), _) if len < max => out.push(marker | len as u8),
+        (_, Some(marker)) if u8::try_from(len).is_ok() => out.extend([marker, len as u8]),
+        _ if u16::try_from(len).is_ok() => {
+            out.push(len16);
+            out.extend((len as u16).to_be_bytes());
+        }
+        _ => {
+            out.push(len32);
+            out.extend((len as u32).to_be_bytes());
+        }
+    }
+}
+
+fn read_value(reader: &mut Reader) -> DaftResult<Value> {
+    let marker = reader.u8()?;
+    let value = match marker {
+        0x00..=0x7f => Value::UInt(u64::from(marker)),
+        0x80..=0x8f => read_map(reader, usize::from(marker & 0x0f))?,
+        0x90..=0x9f => read_array(reader, usize::from(marker & 0x0f))?,
+        0xa0..=0xbf => Value::String(reader.string(usize::from(marker & 0x1f))?),
+        0xc0 => Value::Null,
+        0xc2 => Value::Bool(false),
+        0xc3 => Value::Bool(true),
+        0xc4 => {
+            let len = reader.u8()?;
+            Value::Bytes(reader.take(usize::from(len))?.to_vec())
+        }
+        0xc5 => {
+            let len = u16::from_be_bytes(reader.array()?);
+            Value::Bytes(reader.take(usize::from(len))?.to_vec())
+        }
+        0xc6 => {
+            let len = u32::from_be_bytes(reader.array()?);
+            Value::Bytes(reader.take(len as usize)?.to_vec())
+        }
+        0xca => Value::Float(f64::from(f32::from_be_bytes(reader.array()?))),
+        0xcb => Value::Float(f64::from_be_bytes(reader.array()?)),
+        0xcc => Value::UInt(u64::from(reader.u8()?)),
+        0xcd => Value::UInt(u64::from(u16::from_be_bytes(reader.array()?))),
+        0xce => Value::UInt(u64::from(u32::from_be_bytes(reader.array()?))),
+        0xcf => Value::UInt(u64::from_be_bytes(reader.array()?)),
+        0xd0 => Value::Int(i64::from(reader.u8()? as i8)),
+        0xd1 => Value::Int(i64::from(i16::from_be_bytes(reader.array()?))),
+        0xd2 => Value::Int(i64::from(i32::from_be_bytes(reader.array()?))),
+        0xd3 => Value::Int(i64::from_be_bytes(reader.array()?)),
+        0xd9 => {
+            let len = reader.u8()?;
+            Value::String(reader.string(usize::from(len))?)
+        }
+        0xda => {
+            let len = u16::from_be_bytes(reader.array()?);
+            Value::String(reader.string(usize::from(len))?)
+        }
+        0xdb => {
+            let len = u32::from_be_bytes(reader.array()?);
+            Value::String(reader.string(len as usize)?)
+        }
+        0xdc => {
+            let len = u16::from_be_bytes(reader.array()?);
+            read_array(reader, usize::from(len))?
+        }
+        0xdd => {
+            let len = u32::from_be_bytes(reader.array()?);
+            read_array(reader, len as usize)?
+        }
+        0xde => {
+            let len = u16::from_be_bytes(reader.array()?);
+            read_map(reader, usize::from(len))?
+        }
+        0xdf => {
+            let len = u32::from_be_bytes(reader.array()?);
+            read_map(reader, len as usize)?
+        }
+        0xe0..=0xff => Value::Int(i64::from(marker as i8)),
+        // 0xc1 is never used, the rest are extension types.
+        _ => return Err(reader.error(format!("unsupported marker 0x{marker:02x}"))),
+    };
+    Ok(value)
+}
+
+fn read_array(reader: &mut Reader, len: usize) -> DaftResult<Value> {
+    (0..len)
+        .map(|_| read_value(reader))
+        .collect::<DaftResult<_>>()
+        .map(Value::Array)
+}
+
+fn read_map(reader: &mut Reader, len: usize) -> DaftResult<Value> {
+    (0..len)
+        .map(|_| Ok((read_value(reader)?, read_value(reader)?)))
+        .collect::<DaftResult<_>>()
+        .map(Value::Map)
+}
@@ -0,0 +1,484 @@
+use std::collections::HashMap;
+
+use common_error::{DaftError, DaftResult};
+use daft_core::{prelude::DataType, series::Series};
+use prost::Message as _;
+use prost_types::{
+    field_descriptor_proto::{Label, Type},
+    DescriptorProto, EnumDescriptorProto, FileDescriptorSet,
+};
+
+use super::{
+    codec::{self, write_varint, zigzag_decode, zigzag_encode, Codec, Reader},
+    value::Value,
+    FormatOptions,
+};
+
+/// Deserializes each Protobuf message input to the `dtype`.
+pub(crate) fn deserialize(
+    input: &Series,
+    dtype: &DataType,
+    options: &FormatOptions,
+) -> DaftResult<Series> {
+    codec::deserialize(input, dtype, &Protobuf::new(options)?, true)
+}
+
+/// Deserializes each Protobuf message input to the `dtype`, inserting null on any parsing failure.
+pub(crate) fn try_deserialize(
+    input: &Series,
+    dtype: &DataType,
+    options: &FormatOptions,
+) -> DaftResult<Series> {
+    codec::deserialize(input, dtype, &Protobuf::new(options)?, false)
+}
+
+/// Serializes each input struct as a Protobuf message.
+pub(crate) fn serialize(input: Series, options: &FormatOptions) -> DaftResult<Series> {
+    codec::serialize(&input, &Protobuf::new(options)?, true)
+}
+
+/// Serializes each input struct as a Protobuf message, inserting null on any failures.
+pub(crate) fn try_serialize(input: Series, options: &FormatOptions) -> DaftResult<Series> {
+    codec::serialize(&input, &Protobuf::new(options)?, false)
+}
+
+/// The Protobuf binary wire format, see <https://protobuf.dev/programming-guides/encoding/>.
+///
+/// Messages are described by a serialized `FileDescriptorSet`, as written by
+/// `protoc --descriptor_set_out`. Enums are read as their numbers and can be written from their
+/// numbers or names, map fields are read as maps, and unset fields without presence are read as
+/// their default values.
+struct Protobuf {
+    messages: HashMap<String, MessageDescriptor>,
+    enums: HashMap<String, HashMap<String, i32>>,
+    message: String,
+}
+
+struct MessageDescriptor {
+    fields: Vec<FieldDescriptor>,
+    map_entry: bool,
+}
+
+struct FieldDescriptor {
+    name: String,
+    number: u32,
+    ty: Type,
+    type_name: String,
+    repeated: bool,
+    packed: bool,
+    /// Whether an unset field is null rather than its default value.
+    presence: bool,
+}
+
+impl Protobuf {
+    fn new(options: &FormatOptions) -> DaftResult<Self> {
+        let (Some(descriptor_set), Some(message)) = (&options.descriptor_set, &options.message)
+        else {
+            return Err(DaftError::ValueError(
+                "The protobuf format requires a descriptor_set and a message name".to_string(),
+            ));
+        };
+        let descriptor_set = FileDescriptorSet::decode(descriptor_set.as_slice()).map_err(|e| {
+            DaftError::ValueError(format!("Failed to parse protobuf descriptor set: {e}"))
+        })?;
+        let mut protobuf = Self {
+            messages: HashMap::new(),
+            enums: HashMap::new(),
+            message: format!(".{}", message.trim_start_matches('.')),
+        };
+        for file in &descriptor_set.file {
+            let prefix = match file.package() {
+                "" => String::new(),
+                package => format!(".{package}"),
+            };
+            let proto3 = file.syntax() == "proto3";
+            protobuf.add_enums(&prefix, &file.enum_type);
+            protobuf.add_messages(&prefix, &file.message_type, proto3);
+        }
+        if !protobuf.messages.contains_key(&protobuf.message) {
+            return Err(DaftError::ValueError(format!(
+                "Message {message} is not in the protobuf descriptor set"
+            )));
+        }
+        Ok(protobuf)
+    }
+
+    fn add_enums(&mut self, prefix: &str, enums: &[EnumDescriptorProto]) {
+        for descriptor in enums {
+            let values = descriptor
+                .value
+                .iter()
+                .map(|value| (value.name().to_string(), value.number()))
+                .collect();
+            self.enums
+                .insert(format!("{prefix}.{}", descriptor.name()), values);
+        }
+    }
+
+    fn add_messages(&mut self, prefix: &str, messages: &[DescriptorProto], proto3: bool) {
+        for descriptor in messages {
+            let name = format!("{prefix}.{}", descriptor.name());
+            let fields = descriptor
+                .field
+                .iter()
+                .map(|field| {
+                    let repeated = field.label() == Label::Repeated;
+                    let scalar = !matches!(
+                        field.r#type(),
+                        Type::String | Type::Bytes | Type::Message | Type::Group
+                    );
+                    FieldDescriptor {
+                        name: field.name().to_string(),
+                        number: field.number() as u32,
+                        ty: field.r#type(),
+                        type_name: field.type_name().to_string(),
+                        repeated,
+                        packed: repeated
+                            && scalar
+                            && field
+                                .options
+                                .as_ref()
+                                .and_then(|options| options.packed)
+                                .unwrap_or(proto3),
+                        presence: !repeated
+                            && (!proto3
+                                || field.r#type() == Type::Message
+                                || field.oneof_index.is_some()),
+                    }
+                })
+                .collect();
+            let map_entry = descriptor
+                .options
+                .as_ref()
+                .and_then(|options| options.map_entry)
+                .unwrap_or(false);
+            self.add_enums(&name, &descriptor.enum_type);
+            self.add_messages(&name, &descriptor.nested_type, proto3);
+            self.messages
+                .insert(name, MessageDescriptor { fields, map_entry });
+        }
+    }
+
+    fn message(&self, name: &str) -> DaftResult<&MessageDescriptor> {
+        self.messages.get(name).ok_or_else(|| {
+            DaftError::ValueError(format!(
+                "Message {name} is not in the protobuf descriptor set"
+            ))
+        })
+    }
+
+    fn is_map(&self, field: &FieldDescriptor) -> bool {
+        field.ty == Type::Message
+            && self
+                .messages
+                .get(&field.type_name)
+                .is_some_and(|message| message.map_entry)
+    }
+}
+
+impl Codec for Protobuf {
+    fn encode(&self, value: &Value) -> DaftResult<Vec<u8>> {
+        let mut out = Vec::new();
+        self.write_message(&mut out, &self.message, value)?;
+        Ok(out)
+    }
+
+    fn decode(&self, bytes: &[u8]) -> DaftResult<Value> {
+        self.read_message(&mut Reader::new(bytes, "protobuf"), &self.message)
+    }
+}
+
+const VARINT: u8 = 0;
+const FIXED64: u8 = 1;
+const LEN: u8 = 2;
+const FIXED32: u8 = 5;
+
+fn wire_type(ty: Type) -> u8 {
+    match ty {
+        Type::Double | Type::Fixed64 | Type::Sfixed64 => FIXED64,
+        Type::Float | Type::Fixed32 | Type::Sfixed32 => FIXED32,
+        Type::String | Type::Bytes | Type::Message => LEN,
+        // groups are rejected when they are read or written.
+        _ => VARINT,
+    }
+}
+
+fn default_value(ty: Type) -> Value {
+    match ty {
+        Type::Double | Type::Float => Value::Float(0.0),
+        Type::Uint32 | Type::Uint64 | Type::Fixed32 | Type::Fixed64 => Value::UInt(0),
+        Type::Bool => Value::Bool(false),
+        Type::String => Value::String(String::new()),
+        Type::Bytes => Value::Bytes(Vec::new()),
+        Type::Message | Type::Group => Value::Null,
+        _ => Value::Int(0),
+    }
+}
+
+fn mismatch(field: &FieldDescriptor, value: &Value) -> DaftError {
+    DaftError::ValueError(format!(
+        "Failed to write protobuf: cannot write {value:?} to field {} of type {:?}",
+        field.name, field.ty
+    ))
+}
+
+impl Protobuf {
+    fn write_message(&self, out: &mut Vec<u8>, name: &str, value: &Value) -> DaftResult<()> {
+        let Value::Map(_) = value else {
+            return Err(DaftError::ValueError(format!(
+                "Failed to write protobuf: message {name} must be a struct, got {value:?}"
+            )));
+        };
+        for field in &self.message(name)?.fields {
+            let value = match value.get(&field.name) {
+                None | Some(Value::Null) => continue,
+                Some(value) => value,
+            };
+            if self.is_map(field) {
+                let Value::Map(entries) = value else {
+                    return Err(mismatch(field, value));
+                };
+                for (k, v) in entries {
+                    let entry = Value::Map(vec![
+                        (Value::String("key".to_string()), k.clone()),
+                        (Value::String("value".to_string()), v.clone()),
+                    ]);
+                    self.write_field(out, field, &entry)?;
+                }
+            } else if field.repeated {
+                let Value::Array(items) = value else {
+                    return Err(mismatch(field, value));
+                };
+                let items = items.iter().filter(|item| **item != Value::Null);
+                if field.packed {
+                    let mut packed = Vec::new();
+                    for item in items {
+                        self.write_value(&mut packed, field, item)?;
+                    }
+                    write_varint(out, u64::from((field.number << 3) | u32::from(LEN)));
+                    write_varint(out, packed.len() as u64);
+                    out.extend(packed);
+                } else {
+                    for item in items {
+                        self.write_field(out, field, item)?;
+                    }
+                }
+            } else {
+                self.write_field(out, field, value)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_field(
+        &self,
+        out: &mut Vec<u8>,
+        field: &FieldDescriptor,
+        value: &Value,
+    ) -> DaftResult<()> {
+        write_varint(
+            out,
+            u64::from((field.number << 3) | u32::from(wire_type(field.ty))),
+        );
+        self.write_value(out, field, value)
+    }
+
+    fn write_value(
+        &self,
+        out: &mut Vec<u8>,
+        field: &FieldDescriptor,
+        value: &Value,
+    ) -> DaftResult<()> {
+        let err = || mismatch(field, value);
+        match field.ty {
+            Type::Double => out.extend(value.as_f64().ok_or_else(err)?.to_le_bytes()),
+            Type::Float => out.extend((value.as_f64().ok_or_else(err)? as f32).to_le_bytes()),
+            Type::Int64 => write_varint(out, value.as_i64().ok_or_else(err)? as u64),
+            Type::Int32 => {
+                let i = value.as_i64().filter(|i| i32::try_from(*i).is_ok());
+                write_varint(out, i.ok_or_else(err)? as u64);
+            }
+            Type::Uint64 => write_varint(out, value.as_u64().ok_or_else(err)?),
+            Type::Uint32 => {
+                let u = value.as_u64().filter(|u| u32::try_from(*u).is_ok());
+                write_varint(out, u.ok_or_else(err)?);
+            }
+            Type::Sint64 => write_varint(out, zigzag_encode(value.as_i64().ok_or_else(err)?)),
+            Type::Sint32 => {
+                let i = value.as_i64().filter(|i| i32::try_from(*i).is_ok());
+                write_varint(out, zigzag_encode(i.ok_or_else(err)?));
+            }
+            Type::Fixed64 => out.extend(value.as_u64().ok_or_else(err)?.to_le_bytes()),
+            Type::Fixed32 => {
+                let u = value.as_u64().and_then(|u| u32::try_from(u).ok());
+                out.extend(u.ok_or_else(err)?.to_le_bytes());
+            }
+            Type::Sfixed64 => out.extend(value.as_i64().ok_or_else(err)?.to_le_bytes()),
+            Type::Sfixed32 => {
+                let i = value.as_i64().and_then(|i| i32::try_from(i).ok());
+                out.extend(i.ok_or_else(err)?.to_le_bytes());
+            }
+            Type::Bool => match value {
+                Value::Bool(b) => out.push(u8::from(*b)),
+                _ => return Err(err()),
+            },
+            Type::Enum => {
+                let number = match value {
+                    Value::String(name) => self
+                        .enums
+                        .get(&field.type_name)
+                        .and_then(|values| values.get(name))
+                        .copied(),
+                    value => value.as_i64().and_then(|i| i32::try_from(i).ok()),
+                };
+                write_varint(out, i64::from(number.ok_or_else(err)?) as u64);
+            }
+            Type::String | Type::Bytes => {
+                let bytes = match value {
+                    Value::String(s) => s.as_bytes(),
+                    Value::Bytes(b) if field.ty == Type::Bytes => b,
+                    _ => return Err(err()),
+                };
+                write_varint(out, bytes.len() as u64);
+                out.extend(bytes);
+            }
+            Type::Message => {
+                let mut message = Vec::new();
+                self.write_message(&mut message, &field.type_name, value)?;
+                write_varint(out, message.len() as u64);
+                out.extend(message);
+            }
+            Type::Group => {
+                return Err(DaftError::ValueError(format!(
+                    "Failed to write protobuf: group field {} is not supported",
+                    field.name
+                )))
+            }
+        }
+        Ok(())
+    }
+
+    fn read_message(&self, reader: &mut Reader, name: &str) -> DaftResult<Value> {
+        let message = self.message(name)?;
+        let mut values = HashMap::<u32, Value>::new();
+        let mut repeated = HashMap::<u32, Vec<Value>>::new();
+        while !reader.is_empty() {
+            let key = reader.varint()?;
+            let (number, wire) = ((key >> 3) as u32, (key & 0x7) as u8);
+            let Some(field) = message.fields.iter().find(|field| field.number == number) else {
+                skip(reader, wire)?;
+                continue;
+            };
+            if field.repeated && wire == LEN && wire_type(field.ty) != LEN {
+                // packed repeated scalars.
+                let len = reader.varint()? as usize;
+                let mut packed = Reader::new(reader.take(len)?, "protobuf");
+                while !packed.is_empty() {
+                    let value = self.read_value(&mut packed, field, wire_type(field.ty))?;
+                    repeated.entry(number).or_default().push(value);
+                }
+            } else if field.repeated {
+                let value = self.read_value(reader, field, wire)?;
+                repeated.entry(number).or_default().push(value);
+            } else {
+                let value = self.read_value(reader, field, wire)?;
+                values.insert(number, value);
+            }
+        }
+        let entries = message
+            .fields
+            .iter()
+            .map(|field| {
+                let value = if self.is_map(field) {
+                    let entries = repeated.remove(&field.number).unwrap_or_default();
+                    Value::Map(
+                        entries
+                            .into_iter()
+                            .map(|entry| {
+                                let key = entry.get("key").cloned().unwrap_or(Value::Null);
+                                let value = entry.get("value").cloned().unwrap_or(Value::Null);
+                                (key, value)
+                            })
+                            .collect(),
+                    )
+                } else if field.repeated {
+                    Value::Array(repeated.remove(&field.number).unwrap_or_default())
+                } else if let Some(value) = values.remove(&field.number) {
+                    value
+                } else if field.presence {
+                    Value::Null
+                } else {
+                    default_value(field.ty)
+                };
+                (Value::String(field.name.clone()), value)
+            })
+            .collect();
+        Ok(Value::Map(entries))
+    }
+
+    fn read_value(
+        &self,
+        reader: &mut Reader,
+        field: &FieldDescriptor,
+        wire: u8,
+    ) -> DaftResult<Value> {
+        if field.ty == Type::Group || wire != wire_type(field.ty) {
+            return Err(reader.error(format!(
+                "unexpected wire type {wire} for field {} of type {:?}",
+                field.name, field.ty
+            )));
+        }
+        let value = match field.ty {
+            Type::Double => Value::Float(f64::from_le_bytes(reader.array()?)),
+            Type::Float => Value::Float(f64::from(f32::from_le_bytes(reader.array()?))),
+            Type::Int64 | Type::Enum => Value::Int(reader.varint()? as i64),
+            // negative int32 values are sign extended to 64 bits.
+            Type::Int32 => Value::Int(i64::from(reader.varint()? as i64 as i32)),
+            Type::Uint64 => Value::UInt(reader.varint()?),
+            Type::Uint32 => Value::UInt(u64::from(reader.varint()? as u32)),
+            Type::Sint64 => Value::Int(zigzag_decode(reader.varint()?)),
+            Type::Sint32 => Value::Int(i64::from(zigzag_decode(reader.varint()?) as i32)),
+            Type::Fixed64 => Value::UInt(u64::from_le_bytes(reader.array()?)),
+            Type::Fixed32 => Value::UInt(u64::from(u32::from_le_bytes(reader.array()?))),
+            Type::Sfixed64 => Value::Int(i64::from_le_bytes(reader.array()?)),
+            Type::Sfixed32 => Value::Int(i64::from(i32::from_le_bytes(reader.array()?))),
+            Type::Bool => Value::Bool(reader.varint()? != 0),
+            Type::String => {
+                let len = reader.varint()? as usize;
+                Value::String(reader.string(len)?)
+            }
+            Type::Bytes => {
+                let len = reader.varint()? as usize;
+                Value::Bytes(reader.take(len)?.to_vec())
+            }
+            Type::Message => {
+                let len = reader.varint()? as usize;
+                let mut message = Reader::new(reader.take(len)?, "protobuf");
+                self.read_message(&mut message, &field.type_name)?
+            }
+            Type::Group => unreachable!("groups are rejected above"),
+        };
+        Ok(value)
+    }
+}
+
+/// Skips the value of an unknown field.
+fn skip(reader: &mut Reader, wire: u8) -> DaftResult<()> {
+    match wire {
+        VARINT => {
+            reader.varint()?;
+        }
+        FIXED64 => {
+            reader.take(8)?;
+        }
+        LEN => {
+            let len = reader.varint()? as usize;
+            reader.take(len)?;
+        }
+        FIXED32 => {
+            reader.take(4)?;
+        }
+        _ => return Err(reader.error(format!("unsupported wire type {wire}"))),
+    }
+    Ok(())
+}
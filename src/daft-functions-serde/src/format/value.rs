@@ -0,0 +1,465 @@
+use std::sync::Arc;
+
+use arrow2::{
+    array::{
+        Array, BinaryArray, BooleanArray, FixedSizeBinaryArray, FixedSizeListArray, ListArray,
+        NullArray, PrimitiveArray, StructArray, Utf8Array,
+    },
+    bitmap::Bitmap,
+    datatypes::DataType as ArrowDataType,
+    offset::OffsetsBuffer,
+    types::NativeType,
+};
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    prelude::{DataType, Field},
+    series::Series,
+};
+
+/// A self-describing value which the binary formats encode and decode, sitting between daft
+/// arrays and the bytes of each format.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Value {
+    Null,
+    Bool(bool),
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+    String(String),
+    Bytes(Vec<u8>),
+    Array(Vec<Value>),
+    /// Structs and maps, in order of their entries.
+    Map(Vec<(Value, Value)>),
+}
+
+impl Value {
+    /// Returns the value of the entry with the given string key.
+    pub(crate) fn get(&self, key: &str) -> Option<&Self> {
+        match self {
+            Self::Map(entries) => entries
+                .iter()
+                .find(|(k, _)| matches!(k, Self::String(s) if s == key))
+                .map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_i64(&self) -> Option<i64> {
+        match self {
+            Self::Int(i) => Some(*i),
+            Self::UInt(u) => i64::try_from(*u).ok(),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_u64(&self) -> Option<u64> {
+        match self {
+            Self::Int(i) => u64::try_from(*i).ok(),
+            Self::UInt(u) => Some(*u),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Int(i) => Some(*i as f64),
+            Self::UInt(u) => Some(*u as f64),
+            Self::Float(f) => Some(*f),
+            _ => None,
+        }
+    }
+}
+
+/// Converts each item of the series to a [`Value`], with nulls as [`Value::Null`].
+pub(crate) fn from_series(input: &Series) -> DaftResult<Vec<Value>> {
+    let physical = input.as_physical()?;
+    from_arrow(input.data_type(), physical.to_arrow().as_ref())
+}
+
+/// Converts a physical arrow array to values, where `dtype` is the (possibly logical) daft type
+/// of the array so that maps can be told apart from lists of structs.
+fn from_arrow(dtype: &DataType, array: &dyn Array) -> DaftResult<Vec<Value>> {
+    macro_rules! primitives {
+        ($T:ty, $variant:ident) => {
+            downcast::<PrimitiveArray<$T>>(array)?
+                .iter()
+                .map(|v| v.map_or(Value::Null, |v| Value::$variant((*v).into())))
+                .collect()
+        };
+    }
+
+    let values = match dtype {
+        DataType::Null => vec![Value::Null; array.len()],
+        DataType::Boolean => downcast::<BooleanArray>(array)?
+            .iter()
+            .map(|v| v.map_or(Value::Null, Value::Bool))
+            .collect(),
+        DataType::Int8 => primitives!(i8, Int),
+        DataType::Int16 => primitives!(i16, Int),
+        DataType::Int32 => primitives!(i32, Int),
+        DataType::Int64 => primitives!(i64, Int),
+        DataType::UInt8 => primitives!(u8, UInt),
+        DataType::UInt16 => primitives!(u16, UInt),
+        DataType::UInt32 => primitives!(u32, UInt),
+        DataType::UInt64 => primitives!(u64, UInt),
+        DataType::Float32 => primitives!(f32, Float),
+        DataType::Float64 => primitives!(f64, Float),
+        DataType::Decimal128(_, scale) => {
+            let divisor = 10f64.powi(*scale as i32);
+            downcast::<PrimitiveArray<i128>>(array)?
+                .iter()
+                .map(|v| v.map_or(Value::Null, |v| Value::Float(*v as f64 / divisor)))
+                .collect()
+        }
+        DataType::Utf8 => downcast::<Utf8Array<i64>>(array)?
+            .iter()
+            .map(|v| v.map_or(Value::Null, |v| Value::String(v.to_string())))
+            .collect(),
+        DataType::Binary => downcast::<BinaryArray<i64>>(array)?
+            .iter()
+            .map(|v| v.map_or(Value::Null, |v| Value::Bytes(v.to_vec())))
+            .collect(),
+        DataType::FixedSizeBinary(_) => downcast::<FixedSizeBinaryArray>(array)?
+            .iter()
+            .map(|v| v.map_or(Value::Null, |v| Value::Bytes(v.to_vec())))
+            .collect(),
+        DataType::List(child) => {
+            let list = downcast::<ListArray<i64>>(array)?;
+            let items = from_arrow(child, list.values().as_ref())?;
+            list.offsets()
+                .windows(2)
+                .enumerate()
+                .map(|(i, w)| {
+                    if list.is_null(i) {
+                        Value::Null
+                    } else {
+                        Value::Array(items[w[0] as usize..w[1] as usize].to_vec())
+                    }
+                })
+                .collect()
+        }
+        DataType::FixedSizeList(child, size) => {
+            let list = downcast::<FixedSizeListArray>(array)?;
+            let items = from_arrow(child, list.values().as_ref())?;
+            (0..list.len())
+                .map(|i| {
+                    if list.is_null(i) {
+                        Value::Null
+                    } else {
+                        Value::Array(items[i * size..(i + 1) * size].to_vec())
+                    }
+                })
+                .collect()
+        }
+        DataType::Struct(fields) => {
+            let array = downcast::<StructArray>(array)?;
+            let children = fields
+                .iter()
+                .zip(array.values())
+                .map(|(field, child)| from_arrow(&field.dtype, child.as_ref()))
+                .collect::<DaftResult<Vec<_>>>()?;
+            (0..array.len())
+                .map(|i| {
+                    if array.is_null(i) {
+                        Value::Null
+                    } else {
+                        Value::Map(
+                            fields
+                                .iter()
+                                .zip(&children)
+                                .map(|(field, child)| {
+                                    (Value::String(field.name.clone()), child[i].clone())
+                                })
+                                .collect(),
+                        )
+                    }
+                })
+                .collect()
+        }
+        DataType::Map { key, value } => {
+            // maps are physically lists of key-value structs.
+            let entries_dtype = DataType::List(Box::new(DataType::Struct(vec![
+                Field::new("key", *key.clone()),
+                Field::new("value", *value.clone()),
+            ])));
+            from_arrow(&entries_dtype, array)?
+                .into_iter()
+                .map(|entries| match entries {
+                    Value::Array(entries) => Value::Map(
+                        entries
+                            .into_iter()
+                            .map(|entry| match entry {
+                                Value::Map(mut kv) if kv.len() == 2 => {
+                                    let (_, v) = kv.pop().unwrap();
+                                    let (_, k) = kv.pop().unwrap();
+                                    (k, v)
+                                }
+                                _ => (Value::Null, Value::Null),
+                            })
+                            .collect(),
+                    ),
+                    other => other,
+                })
+                .collect()
+        }
+        dtype if dtype.is_logical() => from_arrow(&dtype.to_physical(), array)?,
+        dtype => {
+            return Err(DaftError::TypeError(format!(
+                "Serializing values of type {dtype} is not supported"
+            )))
+        }
+    };
+    Ok(values)
+}
+
+fn downcast<T: Array>(array: &dyn Array) -> DaftResult<&T> {
+    array.as_any().downcast_ref::<T>().ok_or_else(|| {
+        DaftError::ComputeError(format!(
+            "Unexpected arrow array of type {:?}",
+            array.data_type()
+        ))
+    })
+}
+
+/// Converts values to a series of the `dtype`.
+///
+/// Values which do not match the `dtype` are an error when `strict`, otherwise they become null.
+pub(crate) fn into_series(
+    name: &str,
+    values: &[Value],
+    dtype: &DataType,
+    strict: bool,
+) -> DaftResult<Series> {
+    let physical = dtype.to_physical();
+    let values = values.iter().collect::<Vec<_>>();
+    let array = Builder { strict }.build(dtype, &values)?;
+    let series = Series::from_arrow(Arc::new(Field::new(name, physical.clone())), array)?;
+    if physical == *dtype {
+        Ok(series)
+    } else {
+        series.cast(dtype)
+    }
+}
+
+struct Builder {
+    strict: bool,
+}
+
+impl Builder {
+    /// Handles a value which does not match the `dtype`.
+    fn mismatch<T>(&self, value: &Value, dtype: &DataType) -> DaftResult<Option<T>> {
+        if self.strict {
+            Err(DaftError::ValueError(format!(
+                "Cannot deserialize {value:?} as {dtype}"
+            )))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Converts each non-null value with `f`, where `None` means the value does not match the `dtype`.
+    fn convert<'a, T>(
+        &self,
+        dtype: &DataType,
+        values: &[&'a Value],
+        f: impl Fn(&'a Value) -> Option<T>,
+    ) -> DaftResult<Vec<Option<T>>> {
+        values
+            .iter()
+            .map(|value| match *value {
+                Value::Null => Ok(None),
+                value => f(value).map_or_else(|| self.mismatch(value, dtype), |v| Ok(Some(v))),
+            })
+            .collect()
+    }
+
+    fn primitives<T: NativeType>(
+        &self,
+        dtype: &DataType,
+        values: &[&Value],
+        f: impl Fn(&Value) -> Option<T>,
+    ) -> DaftResult<Box<dyn Array>> {
+        let array = PrimitiveArray::<T>::from(self.convert(dtype, values, f)?);
+        Ok(Box::new(array.to(dtype.to_arrow()?)))
+    }
+
+    /// Builds the physical arrow array of the `dtype`.
+    fn build(&self, dtype: &DataType, values: &[&Value]) -> DaftResult<Box<dyn Array>> {
+        macro_rules! integers {
+            ($T:ty) => {
+                self.primitives::<$T>(dtype, values, |v| {
+                    v.as_i64().and_then(|i| <$T>::try_from(i).ok())
+                })
+            };
+            ($T:ty, unsigned) => {
+                self.primitives::<$T>(dtype, values, |v| {
+                    v.as_u64().and_then(|u| <$T>::try_from(u).ok())
+                })
+            };
+        }
+
+        match dtype {
+            DataType::Null => Ok(Box::new(NullArray::new(ArrowDataType::Null, values.len()))),
+            DataType::Boolean => {
+                let array = BooleanArray::from(self.convert(dtype, values, |v| match v {
+                    Value::Bool(b) => Some(*b),
+                    _ => None,
+                })?);
+                Ok(Box::new(array))
+            }
+            DataType::Int8 => integers!(i8),
+            DataType::Int16 => integers!(i16),
+            DataType::Int32 => integers!(i32),
+            DataType::Int64 => integers!(i64),
+            DataType::UInt8 => integers!(u8, unsigned),
+            DataType::UInt16 => integers!(u16, unsigned),
+            DataType::UInt32 => integers!(u32, unsigned),
+            DataType::UInt64 => integers!(u64, unsigned),
+            DataType::Float32 => {
+                self.primitives::<f32>(dtype, values, |v| v.as_f64().map(|f| f as f32))
+            }
+            DataType::Float64 => self.primitives::<f64>(dtype, values, Value::as_f64),
+            DataType::Decimal128(_, scale) => {
+                let factor = 10i128.pow(*scale as u32);
+                self.primitives::<i128>(dtype, values, |v| match v {
+                    Value::Float(f) => Some((f * factor as f64).round() as i128),
+                    v => v
+                        .as_i64()
+                        .map(i128::from)
+                        .or_else(|| v.as_u64().map(i128::from))
+                        .and_then(|i| i.checked_mul(factor)),
+                })
+            }
+            DataType::Utf8 => {
+                let strings = self.convert(dtype, values, |v| match v {
+                    Value::String(s) => Some(s.clone()),
+                    Value::Bytes(b) => String::from_utf8(b.clone()).ok(),
+                    _ => None,
+                })?;
+                Ok(Box::new(Utf8Array::<i64>::from(strings)))
+            }
+            DataType::Binary => {
+                let bytes = self.convert(dtype, values, |v| match v {
+                    Value::Bytes(b) => Some(b.clone()),
+                    Value::String(s) => Some(s.clone().into_bytes()),
+                    _ => None,
+                })?;
+                Ok(Box::new(BinaryArray::<i64>::from(bytes)))
+            }
+            DataType::FixedSizeBinary(size) => {
+                let bytes = self.convert(dtype, values, |v| match v {
+                    Value::Bytes(b) if b.len() == *size => Some(b.clone()),
+                    _ => None,
+                })?;
+                let validity = bytes.iter().map(Option::is_some).collect::<Vec<_>>();
+                let buffer = bytes
+                    .into_iter()
+                    .flat_map(|b| b.unwrap_or_else(|| vec![0; *size]))
+                    .collect::<Vec<_>>();
+                Ok(Box::new(FixedSizeBinaryArray::new(
+                    dtype.to_arrow()?,
+                    buffer.into(),
+                    to_validity(validity),
+                )))
+            }
+            DataType::List(child) => {
+                let items = self.convert(dtype, values, |v| match v {
+                    Value::Array(items) => Some(items.iter().collect::<Vec<_>>()),
+                    _ => None,
+                })?;
+                let mut offsets = Vec::with_capacity(items.len() + 1);
+                offsets.push(0i64);
+                let mut validity = Vec::with_capacity(items.len());
+                let mut flat = Vec::new();
+                for item in items {
+                    validity.push(item.is_some());
+                    flat.extend(item.unwrap_or_default());
+                    offsets.push(flat.len() as i64);
+                }
+                Ok(Box::new(ListArray::<i64>::new(
+                    dtype.to_physical().to_arrow()?,
+                    OffsetsBuffer::try_from(offsets)?,
+                    self.build(child, &flat)?,
+                    to_validity(validity),
+                )))
+            }
+            DataType::FixedSizeList(child, size) => {
+                let items = self.convert(dtype, values, |v| match v {
+                    Value::Array(items) if items.len() == *size => {
+                        Some(items.iter().collect::<Vec<_>>())
+                    }
+                    _ => None,
+                })?;
+                let validity = items.iter().map(Option::is_some).collect::<Vec<_>>();
+                let flat = items
+                    .into_iter()
+                    .flat_map(|item| item.unwrap_or_else(|| vec![&Value::Null; *size]))
+                    .collect::<Vec<_>>();
+                Ok(Box::new(FixedSizeListArray::new(
+                    dtype.to_physical().to_arrow()?,
+                    self.build(child, &flat)?,
+                    to_validity(validity),
+                )))
+            }
+            DataType::Struct(fields) => {
+                let structs = self.convert(dtype, values, |v| match v {
+                    Value::Map(_) => Some(v),
+                    _ => None,
+                })?;
+                let children = fields
+                    .iter()
+                    .map(|field| {
+                        let values = structs
+                            .iter()
+                            .map(|v| v.and_then(|v| v.get(&field.name)).unwrap_or(&Value::Null))
+                            .collect::<Vec<_>>();
+                        self.build(&field.dtype, &values)
+                    })
+                    .collect::<DaftResult<Vec<_>>>()?;
+                let validity = structs.iter().map(Option::is_some).collect();
+                Ok(Box::new(StructArray::new(
+                    dtype.to_physical().to_arrow()?,
+                    children,
+                    to_validity(validity),
+                )))
+            }
+            DataType::Map { key, value } => {
+                // build the physical list of key-value structs.
+                let entries = values
+                    .iter()
+                    .map(|v| match v {
+                        Value::Map(entries) => Value::Array(
+                            entries
+                                .iter()
+                                .map(|(k, v)| {
+                                    Value::Map(vec![
+                                        (Value::String("key".to_string()), k.clone()),
+                                        (Value::String("value".to_string()), v.clone()),
+                                    ])
+                                })
+                                .collect(),
+                        ),
+                        v => (*v).clone(),
+                    })
+                    .collect::<Vec<_>>();
+                let entries_dtype = DataType::List(Box::new(DataType::Struct(vec![
+                    Field::new("key", *key.clone()),
+                    Field::new("value", *value.clone()),
+                ])));
+                self.build(&entries_dtype, &entries.iter().collect::<Vec<_>>())
+            }
+            dtype if dtype.is_logical() => self.build(&dtype.to_physical(), values),
+            dtype => Err(DaftError::TypeError(format!(
+                "Deserializing values of type {dtype} is not supported"
+            ))),
+        }
+    }
+}
+
+fn to_validity(validity: Vec<bool>) -> Option<Bitmap> {
+    if validity.iter().all(|v| *v) {
+        None
+    } else {
+        Some(Bitmap::from(validity))
+    }
+}
@@ -1,7 +1,6 @@
-use daft_core::series::IntoSeries;
 use daft_dsl::functions::prelude::*;
 
-use crate::format::Format;
+use crate::format::{Format, FormatOptions};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct Serialize;
@@ -10,6 +9,24 @@ pub struct Serialize;
 pub struct SerializeArgs<T> {
     input: T,
     format: Format,
+    #[arg(optional)]
+    schema: Option<String>,
+    #[arg(optional)]
+    descriptor_set: Option<Vec<u8>>,
+    #[arg(optional)]
+    message: Option<String>,
+}
+
+impl<T> SerializeArgs<T> {
+    fn options(&self) -> DaftResult<FormatOptions> {
+        let options = FormatOptions {
+            schema: self.schema.clone(),
+            descriptor_set: self.descriptor_set.clone(),
+            message: self.message.clone(),
+        };
+        self.format.validate_options(&options)?;
+        Ok(options)
+    }
 }
 
 #[typetag::serde]
@@ -19,7 +36,7 @@ impl ScalarUDF for Serialize {
     }
 
     fn docstring(&self) -> &'static str {
-        "Serializes the expression as a string using the specified format."
+        "Serializes the expression as a string (json) or binary using the specified format."
     }
 
     fn get_return_field(
@@ -31,8 +48,9 @@ impl ScalarUDF for Serialize {
     }
 
     fn call(&self, inputs: FunctionArgs<Series>) -> DaftResult<Series> {
-        let SerializeArgs { input, format } = inputs.try_into()?;
-        format.serializer()(input).map(|array| array.into_series())
+        let args: SerializeArgs<Series> = inputs.try_into()?;
+        let options = args.options()?;
+        args.format.serializer()(args.input, &options)
     }
 }
 
@@ -46,7 +64,7 @@ impl ScalarUDF for TrySerialize {
     }
 
     fn docstring(&self) -> &'static str {
-        "Serializes the expression as a string using the specified format, insert null on failures."
+        "Serializes the expression as a string (json) or binary using the specified format, insert null on failures."
     }
 
     fn get_return_field(
@@ -58,16 +76,18 @@ impl ScalarUDF for TrySerialize {
     }
 
     fn call(&self, inputs: FunctionArgs<Series>) -> DaftResult<Series> {
-        let SerializeArgs { input, format } = inputs.try_into()?;
-        format.try_serializer()(input).map(|array| array.into_series())
+        let args: SerializeArgs<Series> = inputs.try_into()?;
+        let options = args.options()?;
+        args.format.try_serializer()(args.input, &options)
     }
 }
 
 fn get_field(inputs: FunctionArgs<ExprRef>, schema: &Schema) -> DaftResult<Field> {
     // validate argument arity
-    let SerializeArgs { input, format: _ } = inputs.try_into()?;
+    let args: SerializeArgs<ExprRef> = inputs.try_into()?;
+    args.options()?;
     // [try_]serialize supports any arbitrary value
-    let input = input.to_field(schema)?;
+    let input = args.input.to_field(schema)?;
     // use name of the single argument as the output field name
-    Ok(Field::new(input.name, DataType::Utf8))
+    Ok(Field::new(input.name, args.format.serialized_dtype()))
 }
@@ -8,12 +8,11 @@ use std::{
 
 use base64::{engine::general_purpose, DecodeError, Engine};
 use common_error::{DaftError, DaftResult};
-use common_runtime::get_io_runtime;
-use daft_io::{get_io_client, IOConfig};
+use daft_io::IOConfig;
 use snafu::{prelude::*, Snafu};
 use tiktoken_rs::CoreBPE;
 
-use crate::special_tokens::get_special_tokens;
+use crate::{special_tokens::get_special_tokens, tokenizer::read_file};
 
 type DynError = Box<dyn std::error::Error + Send + Sync + 'static>;
 
@@ -158,13 +157,7 @@ fn get_file_bpe(
     special_tokens: Vec<String>,
 ) -> DaftResult<DaftBPE> {
     // Fetch the token file as a string
-    let client = get_io_client(false, io_config)?;
-    let runtime = get_io_runtime(false);
-
-    let path = path.to_string();
-    let file_bytes = runtime.block_within_async_context(async move {
-        client.single_url_get(path, None, None).await?.bytes().await
-    })??;
+    let file_bytes = read_file(path, io_config)?;
     let file_str = std::str::from_utf8(&file_bytes).with_context(|_| InvalidUtf8SequenceSnafu)?;
 
     let tokens_res = parse_tokens(file_str)?;
@@ -231,6 +224,20 @@ impl DaftBPE {
         }
     }
 
+    /// Byte lengths of the tokens of `s`, without special tokens.
+    ///
+    /// The tokens partition the bytes of `s`, so their lengths give the offset of each token.
+    pub fn token_lengths(&self, s: &str) -> Vec<usize> {
+        self.bpe
+            ._decode_native_and_split(self.bpe.encode_ordinary(s))
+            .map(|token| token.len())
+            .collect()
+    }
+
+    pub fn is_special(&self, token: u32) -> bool {
+        self.specials.contains(&token)
+    }
+
     pub fn decode(&self, tokens: &[u32]) -> DaftResult<String> {
         // invalid token check
         if let Some(&bad_token) = tokens
@@ -6,7 +6,7 @@ use daft_dsl::functions::prelude::*;
 use daft_io::IOConfig;
 use serde::{Deserialize, Serialize};
 
-use crate::bpe::DaftBPE;
+use crate::tokenizer::DaftTokenizer;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct TokenizeDecodeFunction;
@@ -26,6 +26,8 @@ struct DecodeArgs<T> {
     pub pattern: Option<String>,
     #[arg(optional)]
     pub special_tokens: Option<String>,
+    #[arg(optional)]
+    pub skip_special_tokens: Option<bool>,
 }
 
 #[typetag::serde]
@@ -38,6 +40,7 @@ impl ScalarUDF for TokenizeDecodeFunction {
             io_config,
             pattern,
             special_tokens,
+            skip_special_tokens,
         } = args.try_into()?;
 
         tokenize_decode_series(
@@ -46,6 +49,7 @@ impl ScalarUDF for TokenizeDecodeFunction {
             io_config.map(Arc::new),
             pattern.as_deref(),
             special_tokens.as_deref(),
+            skip_special_tokens.unwrap_or(false),
         )
     }
 
@@ -65,7 +69,11 @@ impl ScalarUDF for TokenizeDecodeFunction {
 }
 
 // Helper function that operates on a single Utf8 series
-fn decode_list(series: &Series, bpe: &DaftBPE) -> DaftResult<String> {
+fn decode_list(
+    series: &Series,
+    tokenizer: &DaftTokenizer,
+    skip_special_tokens: bool,
+) -> DaftResult<String> {
     if !series.data_type().is_integer() {
         return Err(DaftError::TypeError(format!(
             "expected integer list inner type, got {}",
@@ -75,7 +83,7 @@ fn decode_list(series: &Series, bpe: &DaftBPE) -> DaftResult<String> {
     let series = series.cast(&DataType::UInt32)?;
     let data = series.u32()?.as_arrow();
     let tokens: &[u32] = data.values().as_slice();
-    bpe.decode(tokens)
+    tokenizer.decode(tokens, skip_special_tokens)
 }
 
 fn tokenize_decode_array(
@@ -84,15 +92,16 @@ fn tokenize_decode_array(
     io_config: Option<Arc<IOConfig>>,
     pattern: Option<&str>,
     special_tokens: Option<&str>,
+    skip_special_tokens: bool,
 ) -> DaftResult<Utf8Array> {
-    let bpe = DaftBPE::new(tokens_path, io_config, pattern, special_tokens)?;
+    let tokenizer = DaftTokenizer::new(tokens_path, io_config, pattern, special_tokens)?;
     let offsets = arr.offsets();
     let strs = (0..offsets.len() - 1)
         .map(|i| {
             let start = offsets[i] as usize;
             let end = offsets[i + 1] as usize;
             let sub_series = arr.flat_child.slice(start, end)?;
-            decode_list(&sub_series, &bpe)
+            decode_list(&sub_series, &tokenizer, skip_special_tokens)
         })
         .collect::<DaftResult<Vec<String>>>()?;
     Utf8Array::from_iter(arr.name(), strs.iter().map(Some)).with_validity(arr.validity().cloned())
@@ -104,6 +113,7 @@ fn tokenize_decode_series(
     io_config: Option<Arc<IOConfig>>,
     pattern: Option<&str>,
     special_tokens: Option<&str>,
+    skip_special_tokens: bool,
 ) -> DaftResult<Series> {
     match series.data_type() {
         DataType::List(_) => Ok(tokenize_decode_array(
@@ -112,6 +122,7 @@ fn tokenize_decode_series(
             io_config,
             pattern,
             special_tokens,
+            skip_special_tokens,
         )?
         .into_series()),
         dt => Err(DaftError::TypeError(format!(
@@ -4,13 +4,16 @@ use arrow2::{
     array::{MutableArray, MutablePrimitiveArray, PrimitiveArray},
     offset::OffsetsBuffer,
 };
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
 use daft_core::prelude::*;
 use daft_dsl::functions::prelude::*;
 use daft_io::IOConfig;
 use serde::{Deserialize, Serialize};
 
-use crate::bpe::DaftBPE;
+use crate::{
+    hf::{Direction, PaddingStrategy},
+    tokenizer::DaftTokenizer,
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct TokenizeEncodeFunction;
@@ -33,6 +36,80 @@ struct EncodeArgs<T> {
     pub special_tokens: Option<String>,
     #[arg(optional)]
     pub use_special_tokens: Option<bool>,
+    #[arg(optional)]
+    pub max_length: Option<usize>,
+    #[arg(optional)]
+    pub padding: Option<String>,
+    #[arg(optional)]
+    pub pad_token_id: Option<u32>,
+    #[arg(optional)]
+    pub return_attention_mask: Option<bool>,
+}
+
+/// How encodings are padded, resolved from the function arguments and the tokenizer file.
+struct Padding {
+    // `None` pads to the longest encoding in the batch.
+    length: Option<usize>,
+    pad_to_multiple_of: Option<usize>,
+    direction: Direction,
+    pad_id: u32,
+}
+
+fn get_padding(
+    tokenizer: &DaftTokenizer,
+    padding: Option<&str>,
+    max_length: Option<usize>,
+    pad_token_id: Option<u32>,
+) -> DaftResult<Option<Padding>> {
+    let config = tokenizer.padding();
+    let length = match (padding, config) {
+        (Some("longest"), _) => None,
+        (Some("max_length"), _) => Some(max_length.ok_or_else(|| {
+            DaftError::ValueError("max_length must be provided to pad to max_length".to_string())
+        })?),
+        (Some(other), _) => {
+            return Err(DaftError::ValueError(format!(
+                "Unsupported padding strategy {other}, expected \"longest\" or \"max_length\""
+            )))
+        }
+        (None, Some(config)) => match config.strategy {
+            PaddingStrategy::BatchLongest => None,
+            PaddingStrategy::Fixed(length) => Some(length),
+        },
+        (None, None) => return Ok(None),
+    };
+
+    let pad_id = match (pad_token_id, config, tokenizer) {
+        (Some(pad_id), ..) => pad_id,
+        (None, Some(config), _) => config.pad_id,
+        (None, None, DaftTokenizer::HuggingFace(_)) => 0,
+        (None, None, DaftTokenizer::Tiktoken(_)) => {
+            return Err(DaftError::ValueError(
+                "pad_token_id must be provided to pad tiktoken encodings".to_string(),
+            ))
+        }
+    };
+    Ok(Some(Padding {
+        length,
+        pad_to_multiple_of: config.and_then(|c| c.pad_to_multiple_of),
+        direction: config.map(|c| c.direction).unwrap_or_default(),
+        pad_id,
+    }))
+}
+
+fn list_type(inner: DataType) -> DataType {
+    DataType::List(Box::new(inner))
+}
+
+fn encode_return_type(return_attention_mask: bool) -> DataType {
+    if return_attention_mask {
+        DataType::Struct(vec![
+            Field::new("input_ids", list_type(DataType::UInt32)),
+            Field::new("attention_mask", list_type(DataType::UInt8)),
+        ])
+    } else {
+        list_type(DataType::UInt32)
+    }
 }
 
 #[typetag::serde]
@@ -49,85 +126,135 @@ impl ScalarUDF for TokenizeEncodeFunction {
             pattern,
             special_tokens,
             use_special_tokens,
+            max_length,
+            padding,
+            pad_token_id,
+            return_attention_mask,
         } = args.try_into()?;
 
-        // if special tokens are passed in, enable using special tokens
-        let use_special_tokens = use_special_tokens.unwrap_or_else(|| special_tokens.is_some());
-        tokenize_encode_series(
-            &input,
+        let tokenizer = DaftTokenizer::new(
             &tokens_path,
             io_config.map(Arc::new),
             pattern.as_deref(),
             special_tokens.as_deref(),
-            use_special_tokens,
-        )
+        )?;
+        // if special tokens are passed in, enable using special tokens
+        // HuggingFace tokenizers add their special tokens unless told otherwise
+        let use_special_tokens = use_special_tokens.unwrap_or_else(|| match tokenizer {
+            DaftTokenizer::Tiktoken(_) => special_tokens.is_some(),
+            DaftTokenizer::HuggingFace(_) => true,
+        });
+        let padding = get_padding(&tokenizer, padding.as_deref(), max_length, pad_token_id)?;
+        input.with_utf8_array(|arr| {
+            tokenize_encode_array(
+                arr,
+                &tokenizer,
+                use_special_tokens,
+                max_length,
+                padding.as_ref(),
+                return_attention_mask.unwrap_or(false),
+            )
+        })
     }
     fn get_return_field(&self, args: FunctionArgs<ExprRef>, schema: &Schema) -> DaftResult<Field> {
-        let input = args.required((0, "input"))?.to_field(schema)?;
+        let EncodeArgs {
+            input,
+            return_attention_mask,
+            ..
+        } = args.try_into()?;
+        let input = input.to_field(schema)?;
         ensure!(
             input.dtype.is_string(),
             TypeError: "Expects input to tokenize_encode to be utf8, but received {input}",
         );
         Ok(Field::new(
             input.name,
-            DataType::List(Box::new(DataType::UInt32)),
+            encode_return_type(return_attention_mask.unwrap_or(false)),
         ))
     }
 }
 
-fn tokenize_encode_array(
-    arr: &Utf8Array,
-    tokens_path: &str,
-    io_config: Option<Arc<IOConfig>>,
-    pattern: Option<&str>,
-    special_tokens: Option<&str>,
-    use_special_tokens: bool,
-) -> DaftResult<ListArray> {
-    let bpe = DaftBPE::new(tokens_path, io_config, pattern, special_tokens)?;
-
-    let mut flat_child = MutablePrimitiveArray::<u32>::new();
-    let mut offsets: Vec<i64> = Vec::with_capacity(arr.len() + 1);
+fn list_series<T: arrow2::types::NativeType>(
+    name: &str,
+    dtype: DataType,
+    lists: &[Option<Vec<T>>],
+    validity: Option<&arrow2::bitmap::Bitmap>,
+) -> DaftResult<Series> {
+    let mut flat_child = MutablePrimitiveArray::<T>::new();
+    let mut offsets: Vec<i64> = Vec::with_capacity(lists.len() + 1);
     offsets.push(0);
-    let self_arrow = arr.as_arrow();
-    for s_opt in self_arrow {
-        if let Some(s) = s_opt {
-            let tokens = bpe.encode(s, use_special_tokens);
-            let tokens_iter = tokens.iter().map(|t| Some(*t));
-            flat_child.extend(tokens_iter);
+    for list in lists {
+        if let Some(list) = list {
+            flat_child.extend_from_slice(list);
         }
         offsets.push(flat_child.len() as i64);
     }
-    let flat_child: PrimitiveArray<u32> = flat_child.into();
+    let flat_child: PrimitiveArray<T> = flat_child.into();
     let child_series = Series::from_arrow(
-        Field::new("flat_child", DataType::UInt32).into(),
+        Field::new("flat_child", dtype.clone()).into(),
         Box::new(flat_child),
     )?;
     let offsets = OffsetsBuffer::try_from(offsets)?;
     Ok(ListArray::new(
-        Field::new(arr.name(), DataType::List(Box::new(DataType::UInt32))),
+        Field::new(name, list_type(dtype)),
         child_series,
         offsets,
-        arr.validity().cloned(),
-    ))
+        validity.cloned(),
+    )
+    .into_series())
 }
 
-fn tokenize_encode_series(
-    series: &Series,
-    tokens_path: &str,
-    io_config: Option<Arc<IOConfig>>,
-    pattern: Option<&str>,
-    special_tokens: Option<&str>,
+fn tokenize_encode_array(
+    arr: &Utf8Array,
+    tokenizer: &DaftTokenizer,
     use_special_tokens: bool,
+    max_length: Option<usize>,
+    padding: Option<&Padding>,
+    return_attention_mask: bool,
 ) -> DaftResult<Series> {
-    series.with_utf8_array(|arr| {
-        Ok(tokenize_encode_array(
-            arr,
-            tokens_path,
-            io_config.clone(),
-            pattern,
-            special_tokens,
-            use_special_tokens,
-        )?
-        .into_series())
-    })
+    let mut input_ids = arr
+        .as_arrow()
+        .iter()
+        .map(|s| s.map(|s| tokenizer.encode(s, use_special_tokens, max_length)))
+        .collect::<Vec<_>>();
+    let mut attention_mask = input_ids
+        .iter()
+        .map(|ids| ids.as_ref().map(|ids| vec![1u8; ids.len()]))
+        .collect::<Vec<_>>();
+
+    if let Some(padding) = padding {
+        let longest = input_ids.iter().flatten().map(Vec::len).max().unwrap_or(0);
+        let mut length = padding.length.unwrap_or(longest);
+        if let Some(multiple) = padding.pad_to_multiple_of.filter(|m| *m > 0) {
+            length = length.div_ceil(multiple) * multiple;
+        }
+        for (ids, mask) in input_ids.iter_mut().zip(attention_mask.iter_mut()) {
+            if let (Some(ids), Some(mask)) = (ids, mask) {
+                let num_pad = length.saturating_sub(ids.len());
+                match padding.direction {
+                    Direction::Right => {
+                        ids.resize(ids.len() + num_pad, padding.pad_id);
+                        mask.resize(mask.len() + num_pad, 0);
+                    }
+                    Direction::Left => {
+                        ids.splice(0..0, std::iter::repeat_n(padding.pad_id, num_pad));
+                        mask.splice(0..0, std::iter::repeat_n(0, num_pad));
+                    }
+                }
+            }
+        }
+    }
+
+    let validity = arr.validity();
+    let input_ids = list_series(arr.name(), DataType::UInt32, &input_ids, validity)?;
+    if !return_attention_mask {
+        return Ok(input_ids);
+    }
+    let attention_mask = list_series("attention_mask", DataType::UInt8, &attention_mask, None)?;
+    Ok(StructArray::new(
+        Field::new(arr.name(), encode_return_type(true)),
+        vec![input_ids.rename("input_ids"), attention_mask],
+        validity.cloned(),
+    )
+    .into_series())
 }
@@ -0,0 +1,192 @@
+use serde::Deserialize;
+
+use super::{
+    pre_tokenizers::{Metaspace, PrependScheme},
+    Pattern, CHAR_BYTES,
+};
+
+// Variants are named after the types in tokenizer.json.
+#[allow(clippy::upper_case_acronyms, clippy::enum_variant_names)]
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type")]
+pub(crate) enum Decoder {
+    ByteLevel,
+    WordPiece {
+        #[serde(default = "default_prefix")]
+        prefix: String,
+        #[serde(default = "super::default_true")]
+        cleanup: bool,
+    },
+    Metaspace(Metaspace),
+    BPEDecoder {
+        #[serde(default = "default_suffix")]
+        suffix: String,
+    },
+    ByteFallback,
+    Fuse,
+    Strip {
+        content: char,
+        start: usize,
+        stop: usize,
+    },
+    Replace {
+        pattern: Pattern,
+        content: String,
+    },
+    Sequence {
+        decoders: Vec<Decoder>,
+    },
+}
+
+fn default_prefix() -> String {
+    "##".to_string()
+}
+
+fn default_suffix() -> String {
+    "</w>".to_string()
+}
+
+/// Removes the spaces that BERT's pre-tokenizer puts around punctuation and contractions.
+fn cleanup(s: &str) -> String {
+    s.replace(" .", ".")
+        .replace(" ?", "?")
+        .replace(" !", "!")
+        .replace(" ,", ",")
+        .replace(" ' ", "'")
+        .replace(" n't", "n't")
+        .replace(" 'm", "'m")
+        .replace(" do not", " don't")
+        .replace(" 's", "'s")
+        .replace(" 've", "'ve")
+        .replace(" 're", "'re")
+}
+
+/// Parses a byte fallback token such as `<0x0A>`.
+fn parse_byte_token(token: &str) -> Option<u8> {
+    let hex = token.strip_prefix("<0x")?.strip_suffix('>')?;
+    if hex.len() != 2 {
+        return None;
+    }
+    u8::from_str_radix(hex, 16).ok()
+}
+
+impl Decoder {
+    /// Decodes tokens into strings, which are concatenated to form the decoded text.
+    pub(crate) fn decode_chain(&self, tokens: Vec<String>) -> Vec<String> {
+        match self {
+            Self::ByteLevel => {
+                let mut bytes = Vec::new();
+                for c in tokens.iter().flat_map(|token| token.chars()) {
+                    match CHAR_BYTES.get(&c) {
+                        Some(b) => bytes.push(*b),
+                        None => bytes.extend(c.encode_utf8(&mut [0; 4]).as_bytes()),
+                    }
+                }
+                vec![String::from_utf8_lossy(&bytes).into_owned()]
+            }
+            Self::WordPiece {
+                prefix,
+                cleanup: should_cleanup,
+            } => tokens
+                .into_iter()
+                .enumerate()
+                .map(|(i, token)| {
+                    let token = match token.strip_prefix(prefix.as_str()) {
+                        _ if i == 0 => token,
+                        Some(rest) => rest.to_string(),
+                        None => format!(" {token}"),
+                    };
+                    if *should_cleanup {
+                        cleanup(&token)
+                    } else {
+                        token
+                    }
+                })
+                .collect(),
+            Self::Metaspace(metaspace) => tokens
+                .into_iter()
+                .enumerate()
+                .map(|(i, token)| {
+                    // The space prepended when encoding is dropped from the first token.
+                    let strip = i == 0 && metaspace.prepend_scheme() != PrependScheme::Never;
+                    token
+                        .chars()
+                        .filter_map(|c| match c {
+                            c if c != metaspace.replacement => Some(c),
+                            _ if strip => None,
+                            _ => Some(' '),
+                        })
+                        .collect()
+                })
+                .collect(),
+            Self::BPEDecoder { suffix } => {
+                let num_tokens = tokens.len();
+                tokens
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, token)| {
+                        let replacement = if i == num_tokens - 1 { "" } else { " " };
+                        token.replace(suffix.as_str(), replacement)
+                    })
+                    .collect()
+            }
+            Self::ByteFallback => {
+                let mut result = Vec::with_capacity(tokens.len());
+                let mut bytes = Vec::new();
+                let flush = |bytes: &mut Vec<u8>, result: &mut Vec<String>| {
+                    if bytes.is_empty() {
+                        return;
+                    }
+                    match String::from_utf8(std::mem::take(bytes)) {
+                        Ok(s) => result.push(s),
+                        Err(e) => result.extend(std::iter::repeat_n(
+                            "\u{FFFD}".to_string(),
+                            e.as_bytes().len(),
+                        )),
+                    }
+                };
+                for token in tokens {
+                    match parse_byte_token(&token) {
+                        Some(b) => bytes.push(b),
+                        None => {
+                            flush(&mut bytes, &mut result);
+                            result.push(token);
+                        }
+                    }
+                }
+                flush(&mut bytes, &mut result);
+                result
+            }
+            Self::Fuse => vec![tokens.concat()],
+            Self::Strip {
+                content,
+                start,
+                stop,
+            } => tokens
+                .into_iter()
+                .map(|token| {
+                    let chars = token.chars().collect::<Vec<_>>();
+                    let leading = chars
+                        .iter()
+                        .take(*start)
+                        .take_while(|c| *c == content)
+                        .count();
+                    let trailing = chars[leading..]
+                        .iter()
+                        .rev()
+                        .take(*stop)
+                        .take_while(|c| *c == content)
+                        .count();
+                    chars[leading..chars.len() - trailing].iter().collect()
+                })
+                .collect(),
+            Self::Replace { pattern, content } => tokens
+                .iter()
+                .map(|token| pattern.replace(token, content))
+                .collect(),
+            Self::Sequence { decoders } => decoders
+                .iter()
+                .fold(tokens, |tokens, decoder| decoder.decode_chain(tokens)),
+        }
+    }
+}
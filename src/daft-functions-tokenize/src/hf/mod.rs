@@ -0,0 +1,488 @@
+//! A tokenizer for the `tokenizer.json` files produced by HuggingFace's `tokenizers` library.
+//!
+//! Encoding runs the same pipeline as `tokenizers`: added tokens are split out of the input first,
+//! then the remaining text is normalized, pre-tokenized into words and tokenized by the model, and
+//! finally the post-processor adds special tokens such as `[CLS]` and `[SEP]`. Offsets and type ids
+//! aren't tracked since only the token ids are returned.
+
+mod decoders;
+mod models;
+mod normalizers;
+mod pre_tokenizers;
+mod processors;
+
+use std::{
+    collections::{HashMap, HashSet},
+    sync::LazyLock,
+};
+
+use common_error::{DaftError, DaftResult};
+use serde::Deserialize;
+use snafu::{ResultExt, Snafu};
+
+use self::{
+    decoders::Decoder, models::Model, normalizers::Normalizer, pre_tokenizers::PreTokenizer,
+    processors::PostProcessor,
+};
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("Error parsing tokenizer.json: {}", source))]
+    InvalidTokenizerJson { source: serde_json::Error },
+
+    #[snafu(display("Error compiling pattern {} in tokenizer.json: {}", pattern, source))]
+    InvalidPattern {
+        pattern: String,
+        source: Box<fancy_regex::Error>,
+    },
+
+    #[snafu(display("Token {} is missing from the vocabulary", token))]
+    MissingToken { token: String },
+}
+
+impl From<Error> for DaftError {
+    fn from(err: Error) -> Self {
+        Self::ValueError(err.to_string())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct AddedToken {
+    id: u32,
+    content: String,
+    #[serde(default)]
+    single_word: bool,
+    #[serde(default)]
+    lstrip: bool,
+    #[serde(default)]
+    rstrip: bool,
+    #[serde(default)]
+    special: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+pub enum Direction {
+    Left,
+    #[default]
+    Right,
+}
+
+#[derive(Debug, Deserialize)]
+struct TruncationParams {
+    max_length: usize,
+    #[serde(default)]
+    direction: Direction,
+}
+
+#[derive(Debug, Deserialize)]
+pub enum PaddingStrategy {
+    BatchLongest,
+    Fixed(usize),
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PaddingParams {
+    pub strategy: PaddingStrategy,
+    #[serde(default)]
+    pub direction: Direction,
+    #[serde(default)]
+    pub pad_to_multiple_of: Option<usize>,
+    #[serde(default)]
+    pub pad_id: u32,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenizerJson {
+    #[serde(default)]
+    added_tokens: Vec<AddedToken>,
+    normalizer: Option<Normalizer>,
+    pre_tokenizer: Option<PreTokenizer>,
+    model: Model,
+    post_processor: Option<PostProcessor>,
+    decoder: Option<Decoder>,
+    truncation: Option<TruncationParams>,
+    padding: Option<PaddingParams>,
+}
+
+/// A piece of the input after splitting out the added tokens.
+enum Segment<'a> {
+    Added(u32),
+    /// Text that still needs to be tokenized, and whether it starts at the beginning of the input.
+    Text(&'a str, bool),
+}
+
+pub struct HfTokenizer {
+    added_tokens: Vec<AddedToken>,
+    // Matches any added token, preferring the longest one at each position.
+    added_tokens_pattern: Option<regex::Regex>,
+    added_token_index: HashMap<String, usize>,
+    special_ids: HashSet<u32>,
+    normalizer: Option<Normalizer>,
+    pre_tokenizer: Option<PreTokenizer>,
+    model: Model,
+    post_processor: Option<PostProcessor>,
+    decoder: Option<Decoder>,
+    truncation: Option<TruncationParams>,
+    padding: Option<PaddingParams>,
+}
+
+impl HfTokenizer {
+    pub fn from_json(bytes: &[u8]) -> DaftResult<Self> {
+        let TokenizerJson {
+            added_tokens,
+            normalizer,
+            pre_tokenizer,
+            model,
+            post_processor,
+            decoder,
+            truncation,
+            padding,
+        } = serde_json::from_slice(bytes).context(InvalidTokenizerJsonSnafu)?;
+
+        let added_tokens_pattern =
+            if added_tokens.is_empty() {
+                None
+            } else {
+                let mut contents = added_tokens
+                    .iter()
+                    .map(|t| t.content.as_str())
+                    .collect::<Vec<_>>();
+                contents.sort_by_key(|c| std::cmp::Reverse(c.len()));
+                let pattern = contents
+                    .into_iter()
+                    .map(regex::escape)
+                    .collect::<Vec<_>>()
+                    .join("|");
+                Some(regex::Regex::new(&pattern).map_err(|e| {
+                    DaftError::ValueError(format!("Error matching added tokens: {e}"))
+                })?)
+            };
+        let added_token_index = added_tokens
+            .iter()
+            .enumerate()
+            .map(|(i, t)| (t.content.clone(), i))
+            .collect();
+        let special_ids = added_tokens
+            .iter()
+            .filter(|t| t.special)
+            .map(|t| t.id)
+            .collect();
+
+        Ok(Self {
+            added_tokens,
+            added_tokens_pattern,
+            added_token_index,
+            special_ids,
+            normalizer,
+            pre_tokenizer,
+            model,
+            post_processor,
+            decoder,
+            truncation,
+            padding,
+        })
+    }
+
+    /// Padding configured in the tokenizer.json file, if any.
+    pub fn padding(&self) -> Option<&PaddingParams> {
+        self.padding.as_ref()
+    }
+
+    pub fn is_special(&self, id: u32) -> bool {
+        self.special_ids.contains(&id)
+    }
+
+    /// Encodes `s` into token ids.
+    ///
+    /// Encodings longer than `max_length`, or the maximum length configured in the tokenizer.json
+    /// file, are truncated, keeping room for the special tokens added by the post-processor.
+    pub fn encode(&self, s: &str, add_special_tokens: bool, max_length: Option<usize>) -> Vec<u32> {
+        let mut ids = Vec::new();
+        for segment in self.split_on_added_tokens(s) {
+            match segment {
+                Segment::Added(id) => ids.push(id),
+                Segment::Text(text, at_start) => {
+                    let normalized = match &self.normalizer {
+                        Some(normalizer) => normalizer.normalize(text),
+                        None => text.to_string(),
+                    };
+                    let words = match &self.pre_tokenizer {
+                        Some(pre_tokenizer) => pre_tokenizer.pre_tokenize(&normalized, at_start),
+                        None => vec![normalized],
+                    };
+                    for word in words {
+                        self.model.tokenize(&word, &mut ids);
+                    }
+                }
+            }
+        }
+
+        let post_processor = self.post_processor.as_ref().filter(|_| add_special_tokens);
+        let direction = self
+            .truncation
+            .as_ref()
+            .map(|t| t.direction)
+            .unwrap_or_default();
+        if let Some(max_length) =
+            max_length.or_else(|| self.truncation.as_ref().map(|t| t.max_length))
+        {
+            let max_length = max_length
+                .saturating_sub(post_processor.map_or(0, PostProcessor::num_added_tokens));
+            if ids.len() > max_length {
+                match direction {
+                    Direction::Right => ids.truncate(max_length),
+                    Direction::Left => {
+                        ids.drain(..ids.len() - max_length);
+                    }
+                }
+            }
+        }
+
+        match post_processor {
+            Some(post_processor) => post_processor.process(ids),
+            None => ids,
+        }
+    }
+
+    /// Decodes token ids into a string, failing with the first id that isn't in the vocabulary.
+    pub fn decode(&self, ids: &[u32], skip_special_tokens: bool) -> Result<String, u32> {
+        let tokens = ids
+            .iter()
+            .filter(|id| !(skip_special_tokens && self.is_special(**id)))
+            .map(|id| self.id_to_token(*id).map(str::to_string).ok_or(*id))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(match &self.decoder {
+            Some(decoder) => decoder.decode_chain(tokens).concat(),
+            None => tokens.join(" "),
+        })
+    }
+
+    fn id_to_token(&self, id: u32) -> Option<&str> {
+        self.added_tokens
+            .iter()
+            .find(|t| t.id == id)
+            .map(|t| t.content.as_str())
+            .or_else(|| self.model.id_to_token(id))
+    }
+
+    fn split_on_added_tokens<'a>(&self, s: &'a str) -> Vec<Segment<'a>> {
+        let Some(pattern) = &self.added_tokens_pattern else {
+            return vec![Segment::Text(s, true)];
+        };
+
+        let mut segments = Vec::new();
+        let mut text_start = 0;
+        let mut pos = 0;
+        while let Some(m) = pattern.find_at(s, pos) {
+            let token = &self.added_tokens[self.added_token_index[m.as_str()]];
+            if token.single_word && !is_word_boundary(s, m.start(), m.end()) {
+                pos = m.start() + s[m.start()..].chars().next().map_or(1, char::len_utf8);
+                continue;
+            }
+
+            let text_end = if token.lstrip {
+                text_start + s[text_start..m.start()].trim_end().len()
+            } else {
+                m.start()
+            };
+            if text_end > text_start {
+                segments.push(Segment::Text(&s[text_start..text_end], text_start == 0));
+            }
+            segments.push(Segment::Added(token.id));
+
+            text_start = m.end();
+            if token.rstrip {
+                text_start = s.len() - s[text_start..].trim_start().len();
+            }
+            pos = text_start;
+        }
+        if text_start < s.len() {
+            segments.push(Segment::Text(&s[text_start..], text_start == 0));
+        }
+        segments
+    }
+}
+
+fn is_word_boundary(s: &str, start: usize, end: usize) -> bool {
+    let is_word_char = |c: char| c.is_alphanumeric() || c == '_';
+    !s[..start].chars().next_back().is_some_and(is_word_char)
+        && !s[end..].chars().next().is_some_and(is_word_char)
+}
+
+/// A string or regex pattern, used to split or replace parts of a string.
+#[derive(Debug, Deserialize)]
+#[serde(try_from = "PatternJson")]
+pub(crate) enum Pattern {
+    String(String),
+    Regex(fancy_regex::Regex),
+}
+
+#[derive(Deserialize)]
+enum PatternJson {
+    String(String),
+    Regex(String),
+}
+
+impl TryFrom<PatternJson> for Pattern {
+    type Error = Error;
+
+    fn try_from(pattern: PatternJson) -> Result<Self, Self::Error> {
+        match pattern {
+            PatternJson::String(s) => Ok(Self::String(s)),
+            PatternJson::Regex(pattern) => fancy_regex::Regex::new(&pattern)
+                .map(Self::Regex)
+                .map_err(|e| Error::InvalidPattern {
+                    pattern,
+                    source: Box::new(e),
+                }),
+        }
+    }
+}
+
+impl Pattern {
+    /// Byte ranges of every non-overlapping match of the pattern in `s`.
+    pub(crate) fn find_matches(&self, s: &str) -> Vec<(usize, usize)> {
+        match self {
+            Self::String(pattern) if pattern.is_empty() => Vec::new(),
+            Self::String(pattern) => s
+                .match_indices(pattern.as_str())
+                .map(|(start, m)| (start, start + m.len()))
+                .collect(),
+            Self::Regex(regex) => find_regex_matches(regex, s),
+        }
+    }
+
+    pub(crate) fn replace(&self, s: &str, content: &str) -> String {
+        let mut result = String::with_capacity(s.len());
+        let mut last = 0;
+        for (start, end) in self.find_matches(s) {
+            result.push_str(&s[last..start]);
+            result.push_str(content);
+            last = end;
+        }
+        result.push_str(&s[last..]);
+        result
+    }
+}
+
+fn find_regex_matches(regex: &fancy_regex::Regex, s: &str) -> Vec<(usize, usize)> {
+    regex
+        .find_iter(s)
+        .map_while(Result::ok)
+        .filter(|m| !m.as_str().is_empty())
+        .map(|m| (m.start(), m.end()))
+        .collect()
+}
+
+/// How the matches of a pattern are kept when splitting a string on them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub(crate) enum SplitBehavior {
+    Removed,
+    Isolated,
+    MergedWithPrevious,
+    MergedWithNext,
+    Contiguous,
+}
+
+/// Splits `s` on the given non-overlapping, sorted `matches`, dropping empty pieces.
+pub(crate) fn split(
+    s: &str,
+    matches: &[(usize, usize)],
+    behavior: SplitBehavior,
+    invert: bool,
+) -> Vec<String> {
+    // Cover the whole string with alternating runs of matches and non-matches.
+    let mut runs = Vec::with_capacity(matches.len() * 2 + 1);
+    let mut last = 0;
+    for &(start, end) in matches {
+        if start > last {
+            runs.push((last, start, invert));
+        }
+        runs.push((start, end, !invert));
+        last = end;
+    }
+    if last < s.len() {
+        runs.push((last, s.len(), invert));
+    }
+
+    let mut pieces: Vec<(usize, usize)> = Vec::with_capacity(runs.len());
+    match behavior {
+        SplitBehavior::Removed => {
+            pieces.extend(runs.iter().filter(|r| !r.2).map(|r| (r.0, r.1)));
+        }
+        SplitBehavior::Isolated => pieces.extend(runs.iter().map(|r| (r.0, r.1))),
+        SplitBehavior::MergedWithPrevious => {
+            let mut previous_match = false;
+            for &(start, end, is_match) in &runs {
+                match pieces.last_mut() {
+                    Some(last) if is_match && !previous_match => last.1 = end,
+                    _ => pieces.push((start, end)),
+                }
+                previous_match = is_match;
+            }
+        }
+        SplitBehavior::MergedWithNext => {
+            let mut previous_match = false;
+            for &(start, end, is_match) in runs.iter().rev() {
+                match pieces.last_mut() {
+                    Some(last) if is_match && !previous_match => last.0 = start,
+                    _ => pieces.push((start, end)),
+                }
+                previous_match = is_match;
+            }
+            pieces.reverse();
+        }
+        SplitBehavior::Contiguous => {
+            let mut previous_match = false;
+            for &(start, end, is_match) in &runs {
+                match pieces.last_mut() {
+                    Some(last) if is_match == previous_match => last.1 = end,
+                    _ => pieces.push((start, end)),
+                }
+                previous_match = is_match;
+            }
+        }
+    }
+    pieces
+        .into_iter()
+        .filter(|(start, end)| end > start)
+        .map(|(start, end)| s[start..end].to_string())
+        .collect()
+}
+
+/// The printable characters that byte-level tokenizers use to represent each byte, as in GPT-2.
+static BYTES_CHAR: LazyLock<[char; 256]> = LazyLock::new(|| {
+    let mut chars = ['\0'; 256];
+    let mut n = 0;
+    for b in 0..=255u8 {
+        let printable = matches!(b, b'!'..=b'~' | 0xA1..=0xAC | 0xAE..=0xFF);
+        chars[b as usize] = if printable {
+            char::from(b)
+        } else {
+            n += 1;
+            char::from_u32(255 + n).unwrap()
+        };
+    }
+    chars
+});
+
+static CHAR_BYTES: LazyLock<HashMap<char, u8>> = LazyLock::new(|| {
+    BYTES_CHAR
+        .iter()
+        .enumerate()
+        .map(|(b, c)| (*c, b as u8))
+        .collect()
+});
+
+pub(crate) fn to_byte_level(s: &str) -> String {
+    s.bytes().map(|b| BYTES_CHAR[b as usize]).collect()
+}
+
+/// The byte fallback token of a byte, e.g. `<0x0A>` for a newline.
+pub(crate) fn byte_fallback_token(b: u8) -> String {
+    format!("<0x{b:02X}>")
+}
+
+pub(crate) const fn default_true() -> bool {
+    true
+}
@@ -0,0 +1,473 @@
+use std::collections::HashMap;
+
+use serde::{de::Error as _, Deserialize, Deserializer};
+
+use super::{byte_fallback_token, Error};
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug)]
+pub(crate) enum Model {
+    BPE(Bpe),
+    WordPiece(WordPiece),
+    WordLevel(WordLevel),
+    Unigram(Unigram),
+}
+
+impl<'de> Deserialize<'de> for Model {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        // Older tokenizer.json files don't record the type of their model.
+        let model_type = match value.get("type").and_then(serde_json::Value::as_str) {
+            Some(model_type) => model_type,
+            None if value.get("merges").is_some() => "BPE",
+            None if value.get("unk_id").is_some() => "Unigram",
+            None if value.get("continuing_subword_prefix").is_some() => "WordPiece",
+            None => "WordLevel",
+        };
+        match model_type {
+            "BPE" => serde_json::from_value(value).map(Self::BPE),
+            "WordPiece" => serde_json::from_value(value).map(Self::WordPiece),
+            "WordLevel" => serde_json::from_value(value).map(Self::WordLevel),
+            "Unigram" => serde_json::from_value(value).map(Self::Unigram),
+            other => return Err(D::Error::custom(format!("unsupported model type {other}"))),
+        }
+        .map_err(D::Error::custom)
+    }
+}
+
+impl Model {
+    /// Tokenizes a single word, appending its token ids to `ids`.
+    pub(crate) fn tokenize(&self, word: &str, ids: &mut Vec<u32>) {
+        if word.is_empty() {
+            return;
+        }
+        match self {
+            Self::BPE(model) => model.tokenize(word, ids),
+            Self::WordPiece(model) => model.tokenize(word, ids),
+            Self::WordLevel(model) => ids.extend(model.vocab.get(word).or(model.unk_id.as_ref())),
+            Self::Unigram(model) => model.tokenize(word, ids),
+        }
+    }
+
+    pub(crate) fn id_to_token(&self, id: u32) -> Option<&str> {
+        let vocab_r = match self {
+            Self::BPE(model) => &model.vocab_r,
+            Self::WordPiece(model) => &model.vocab_r,
+            Self::WordLevel(model) => &model.vocab_r,
+            Self::Unigram(model) => return model.pieces.get(id as usize).map(|p| p.0.as_str()),
+        };
+        vocab_r.get(&id).map(String::as_str)
+    }
+}
+
+fn reverse_vocab(vocab: &HashMap<String, u32>) -> HashMap<u32, String> {
+    vocab
+        .iter()
+        .map(|(token, id)| (*id, token.clone()))
+        .collect()
+}
+
+fn token_id(vocab: &HashMap<String, u32>, token: &str) -> Result<u32, Error> {
+    vocab
+        .get(token)
+        .copied()
+        .ok_or_else(|| Error::MissingToken {
+            token: token.to_string(),
+        })
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Merge {
+    // Older tokenizer.json files join the pair with a space.
+    Joined(String),
+    Pair(String, String),
+}
+
+#[derive(Deserialize)]
+struct BpeJson {
+    vocab: HashMap<String, u32>,
+    merges: Vec<Merge>,
+    #[serde(default)]
+    unk_token: Option<String>,
+    #[serde(default)]
+    continuing_subword_prefix: Option<String>,
+    #[serde(default)]
+    end_of_word_suffix: Option<String>,
+    #[serde(default)]
+    fuse_unk: bool,
+    #[serde(default)]
+    byte_fallback: bool,
+    #[serde(default)]
+    ignore_merges: bool,
+}
+
+/// Byte-pair encoding, which repeatedly merges the pair of adjacent tokens with the lowest rank.
+#[derive(Debug, Deserialize)]
+#[serde(try_from = "BpeJson")]
+pub(crate) struct Bpe {
+    vocab: HashMap<String, u32>,
+    vocab_r: HashMap<u32, String>,
+    // Maps pairs of token ids to the rank of their merge and the id of the merged token.
+    merges: HashMap<(u32, u32), (usize, u32)>,
+    unk_id: Option<u32>,
+    continuing_subword_prefix: Option<String>,
+    end_of_word_suffix: Option<String>,
+    fuse_unk: bool,
+    byte_fallback: bool,
+    ignore_merges: bool,
+}
+
+impl TryFrom<BpeJson> for Bpe {
+    type Error = Error;
+
+    fn try_from(json: BpeJson) -> Result<Self, Self::Error> {
+        let prefix_len = json
+            .continuing_subword_prefix
+            .as_ref()
+            .map_or(0, String::len);
+        let merges = json
+            .merges
+            .into_iter()
+            .enumerate()
+            .map(|(rank, merge)| {
+                let (a, b) = match merge {
+                    Merge::Joined(joined) => match joined.split_once(' ') {
+                        Some((a, b)) => (a.to_string(), b.to_string()),
+                        None => return Err(Error::MissingToken { token: joined }),
+                    },
+                    Merge::Pair(a, b) => (a, b),
+                };
+                // The prefix of the second token is dropped when merging.
+                let merged = format!("{a}{}", b.get(prefix_len..).unwrap_or(&b));
+                Ok((
+                    (token_id(&json.vocab, &a)?, token_id(&json.vocab, &b)?),
+                    (rank, token_id(&json.vocab, &merged)?),
+                ))
+            })
+            .collect::<Result<_, _>>()?;
+        let unk_id = json
+            .unk_token
+            .map(|unk| token_id(&json.vocab, &unk))
+            .transpose()?;
+        Ok(Self {
+            vocab_r: reverse_vocab(&json.vocab),
+            vocab: json.vocab,
+            merges,
+            unk_id,
+            continuing_subword_prefix: json.continuing_subword_prefix,
+            end_of_word_suffix: json.end_of_word_suffix,
+            fuse_unk: json.fuse_unk,
+            byte_fallback: json.byte_fallback,
+            ignore_merges: json.ignore_merges,
+        })
+    }
+}
+
+impl Bpe {
+    fn tokenize(&self, word: &str, ids: &mut Vec<u32>) {
+        if self.ignore_merges {
+            if let Some(id) = self.vocab.get(word) {
+                ids.push(*id);
+                return;
+            }
+        }
+
+        let mut symbols = Vec::with_capacity(word.len());
+        let mut previous_unk = false;
+        let num_chars = word.chars().count();
+        for (i, (start, c)) in word.char_indices().enumerate() {
+            let part = &word[start..start + c.len_utf8()];
+            let mut symbol = String::new();
+            if i > 0 {
+                symbol.extend(self.continuing_subword_prefix.as_deref());
+            }
+            symbol.push_str(part);
+            if i == num_chars - 1 {
+                symbol.extend(self.end_of_word_suffix.as_deref());
+            }
+
+            if let Some(id) = self.vocab.get(&symbol) {
+                symbols.push(*id);
+                previous_unk = false;
+                continue;
+            }
+            if self.byte_fallback {
+                let byte_ids = part
+                    .bytes()
+                    .map(|b| self.vocab.get(&byte_fallback_token(b)).copied())
+                    .collect::<Option<Vec<_>>>();
+                if let Some(byte_ids) = byte_ids {
+                    symbols.extend(byte_ids);
+                    previous_unk = false;
+                    continue;
+                }
+            }
+            if let Some(unk_id) = self.unk_id {
+                if !(self.fuse_unk && previous_unk) {
+                    symbols.push(unk_id);
+                }
+                previous_unk = true;
+            }
+        }
+
+        // Merge the leftmost pair with the lowest rank until no pair can be merged.
+        while let Some((_, i, merged)) = symbols
+            .windows(2)
+            .enumerate()
+            .filter_map(|(i, pair)| {
+                let (rank, merged) = self.merges.get(&(pair[0], pair[1]))?;
+                Some((*rank, i, *merged))
+            })
+            .min()
+        {
+            symbols[i] = merged;
+            symbols.remove(i + 1);
+        }
+        ids.extend(symbols);
+    }
+}
+
+#[derive(Deserialize)]
+struct WordPieceJson {
+    vocab: HashMap<String, u32>,
+    unk_token: String,
+    #[serde(default = "default_continuing_subword_prefix")]
+    continuing_subword_prefix: String,
+    #[serde(default = "default_max_input_chars_per_word")]
+    max_input_chars_per_word: usize,
+}
+
+fn default_continuing_subword_prefix() -> String {
+    "##".to_string()
+}
+
+const fn default_max_input_chars_per_word() -> usize {
+    100
+}
+
+/// Greedily splits words into the longest tokens in the vocabulary, as done by BERT.
+#[derive(Debug, Deserialize)]
+#[serde(try_from = "WordPieceJson")]
+pub(crate) struct WordPiece {
+    vocab: HashMap<String, u32>,
+    vocab_r: HashMap<u32, String>,
+    unk_id: u32,
+    continuing_subword_prefix: String,
+    max_input_chars_per_word: usize,
+}
+
+impl TryFrom<WordPieceJson> for WordPiece {
+    type Error = Error;
+
+    fn try_from(json: WordPieceJson) -> Result<Self, Self::Error> {
+        Ok(Self {
+            unk_id: token_id(&json.vocab, &json.unk_token)?,
+            vocab_r: reverse_vocab(&json.vocab),
+            vocab: json.vocab,
+            continuing_subword_prefix: json.continuing_subword_prefix,
+            max_input_chars_per_word: json.max_input_chars_per_word,
+        })
+    }
+}
+
+impl WordPiece {
+    fn tokenize(&self, word: &str, ids: &mut Vec<u32>) {
+        if word.chars().count() > self.max_input_chars_per_word {
+            ids.push(self.unk_id);
+            return;
+        }
+
+        let num_ids = ids.len();
+        let mut start = 0;
+        while start < word.len() {
+            let mut end = word.len();
+            let mut token_id = None;
+            while start < end {
+                let id = if start > 0 {
+                    self.vocab.get(&format!(
+                        "{}{}",
+                        self.continuing_subword_prefix,
+                        &word[start..end]
+                    ))
+                } else {
+                    self.vocab.get(&word[start..end])
+                };
+                if id.is_some() {
+                    token_id = id;
+                    break;
+                }
+                end -= word[start..end].chars().next_back().unwrap().len_utf8();
+            }
+            match token_id {
+                Some(id) => ids.push(*id),
+                None => {
+                    // The whole word is unknown if any part of it is.
+                    ids.truncate(num_ids);
+                    ids.push(self.unk_id);
+                    return;
+                }
+            }
+            start = end;
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct WordLevelJson {
+    vocab: HashMap<String, u32>,
+    #[serde(default)]
+    unk_token: Option<String>,
+}
+
+/// Maps each word to a single token.
+#[derive(Debug, Deserialize)]
+#[serde(try_from = "WordLevelJson")]
+pub(crate) struct WordLevel {
+    vocab: HashMap<String, u32>,
+    vocab_r: HashMap<u32, String>,
+    unk_id: Option<u32>,
+}
+
+impl TryFrom<WordLevelJson> for WordLevel {
+    type Error = Error;
+
+    fn try_from(json: WordLevelJson) -> Result<Self, Self::Error> {
+        Ok(Self {
+            unk_id: json.unk_token.and_then(|unk| json.vocab.get(&unk).copied()),
+            vocab_r: reverse_vocab(&json.vocab),
+            vocab: json.vocab,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+struct UnigramJson {
+    vocab: Vec<(String, f64)>,
+    #[serde(default)]
+    unk_id: Option<usize>,
+    #[serde(default)]
+    byte_fallback: bool,
+}
+
+/// The Unigram language model used by SentencePiece, which picks the segmentation of each word
+/// with the highest total score.
+#[derive(Debug, Deserialize)]
+#[serde(try_from = "UnigramJson")]
+pub(crate) struct Unigram {
+    pieces: Vec<(String, f64)>,
+    piece_ids: HashMap<String, u32>,
+    unk_id: Option<u32>,
+    unk_score: f64,
+    max_piece_len: usize,
+    byte_fallback: bool,
+}
+
+impl TryFrom<UnigramJson> for Unigram {
+    type Error = Error;
+
+    fn try_from(json: UnigramJson) -> Result<Self, Self::Error> {
+        if let Some(unk_id) = json.unk_id {
+            if unk_id >= json.vocab.len() {
+                return Err(Error::MissingToken {
+                    token: format!("with id {unk_id}"),
+                });
+            }
+        }
+        // Unknown characters score lower than any piece, as in SentencePiece.
+        let min_score = json
+            .vocab
+            .iter()
+            .map(|(_, score)| *score)
+            .fold(f64::INFINITY, f64::min);
+        Ok(Self {
+            piece_ids: json
+                .vocab
+                .iter()
+                .enumerate()
+                .map(|(id, (piece, _))| (piece.clone(), id as u32))
+                .collect(),
+            unk_id: json.unk_id.map(|id| id as u32),
+            unk_score: min_score - 10.0,
+            max_piece_len: json
+                .vocab
+                .iter()
+                .map(|(piece, _)| piece.len())
+                .max()
+                .unwrap_or(0),
+            pieces: json.vocab,
+            byte_fallback: json.byte_fallback,
+        })
+    }
+}
+
+impl Unigram {
+    fn tokenize(&self, word: &str, ids: &mut Vec<u32>) {
+        // best[end] is the score of the best segmentation of word[..end], the start of its last
+        // piece and the id of that piece, or `None` if it's unknown.
+        let mut best: Vec<Option<(f64, usize, Option<u32>)>> = vec![None; word.len() + 1];
+        best[0] = Some((0.0, 0, None));
+        for (start, c) in word.char_indices() {
+            let Some((base_score, ..)) = best[start] else {
+                continue;
+            };
+            let mut update = |end: usize, score: f64, id: Option<u32>| {
+                if best[end].is_none_or(|(best_score, ..)| score > best_score) {
+                    best[end] = Some((score, start, id));
+                }
+            };
+
+            let char_end = start + c.len_utf8();
+            let mut has_single_char_piece = false;
+            let ends = word[start..]
+                .char_indices()
+                .skip(1)
+                .map(|(i, _)| start + i)
+                .chain(std::iter::once(word.len()));
+            for end in ends.take_while(|end| end - start <= self.max_piece_len) {
+                if let Some(id) = self.piece_ids.get(&word[start..end]) {
+                    update(end, base_score + self.pieces[*id as usize].1, Some(*id));
+                    has_single_char_piece |= end == char_end;
+                }
+            }
+            if !has_single_char_piece {
+                update(char_end, base_score + self.unk_score, None);
+            }
+        }
+
+        let mut segments = Vec::new();
+        let mut end = word.len();
+        while end > 0 {
+            let (_, start, id) = best[end].expect("every character boundary is reachable");
+            segments.push((start, end, id));
+            end = start;
+        }
+
+        let mut previous_unk = false;
+        for (start, end, id) in segments.into_iter().rev() {
+            if let Some(id) = id {
+                ids.push(id);
+                previous_unk = false;
+                continue;
+            }
+            if self.byte_fallback {
+                let byte_ids = word[start..end]
+                    .bytes()
+                    .map(|b| self.piece_ids.get(&byte_fallback_token(b)).copied())
+                    .collect::<Option<Vec<_>>>();
+                if let Some(byte_ids) = byte_ids {
+                    ids.extend(byte_ids);
+                    previous_unk = false;
+                    continue;
+                }
+            }
+            // Consecutive unknown characters are fused into a single unknown token.
+            if let Some(unk_id) = self.unk_id {
+                if !previous_unk {
+                    ids.push(unk_id);
+                }
+                previous_unk = true;
+            }
+        }
+    }
+}
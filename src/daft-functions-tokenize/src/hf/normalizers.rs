@@ -0,0 +1,260 @@
+use std::sync::LazyLock;
+
+use base64::{engine::general_purpose, Engine};
+use serde::Deserialize;
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+use unicode_segmentation::UnicodeSegmentation;
+
+use super::{default_true, to_byte_level, Pattern};
+
+// Variants are named after the types in tokenizer.json.
+#[allow(clippy::upper_case_acronyms, clippy::enum_variant_names)]
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type")]
+pub(crate) enum Normalizer {
+    BertNormalizer {
+        #[serde(default = "default_true")]
+        clean_text: bool,
+        #[serde(default = "default_true")]
+        handle_chinese_chars: bool,
+        // Follows `lowercase` when unset.
+        #[serde(default)]
+        strip_accents: Option<bool>,
+        #[serde(default = "default_true")]
+        lowercase: bool,
+    },
+    Lowercase,
+    StripAccents,
+    NFC,
+    NFD,
+    NFKC,
+    NFKD,
+    Nmt,
+    Strip {
+        #[serde(default)]
+        strip_left: bool,
+        #[serde(default)]
+        strip_right: bool,
+    },
+    Replace {
+        pattern: Pattern,
+        content: String,
+    },
+    Prepend {
+        prepend: String,
+    },
+    Precompiled {
+        precompiled_charsmap: Option<Precompiled>,
+    },
+    ByteLevel,
+    Sequence {
+        normalizers: Vec<Normalizer>,
+    },
+}
+
+/// Control characters that BERT removes from its input, other than whitespace.
+static BERT_REMOVED_CHARS: LazyLock<regex::Regex> = LazyLock::new(|| {
+    regex::Regex::new(r"[[\x00\x{FFFD}\p{Cc}\p{Cf}\p{Cn}\p{Co}]--[\t\n\r]]").unwrap()
+});
+
+fn is_chinese_char(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x4E00..=0x9FFF
+            | 0x3400..=0x4DBF
+            | 0x20000..=0x2A6DF
+            | 0x2A700..=0x2B73F
+            | 0x2B740..=0x2B81F
+            | 0x2B920..=0x2CEAF
+            | 0xF900..=0xFAFF
+            | 0x2F800..=0x2FA1F
+    )
+}
+
+fn strip_accents(s: &str) -> String {
+    s.chars().filter(|c| !is_combining_mark(*c)).collect()
+}
+
+impl Normalizer {
+    pub(crate) fn normalize(&self, s: &str) -> String {
+        match self {
+            Self::BertNormalizer {
+                clean_text,
+                handle_chinese_chars,
+                strip_accents: should_strip_accents,
+                lowercase,
+            } => {
+                let mut s = s.to_string();
+                if *clean_text {
+                    s = BERT_REMOVED_CHARS
+                        .replace_all(&s, "")
+                        .chars()
+                        .map(|c| if c.is_whitespace() { ' ' } else { c })
+                        .collect();
+                }
+                if *handle_chinese_chars {
+                    s = s
+                        .chars()
+                        .flat_map(|c| {
+                            if is_chinese_char(c) {
+                                vec![' ', c, ' ']
+                            } else {
+                                vec![c]
+                            }
+                        })
+                        .collect();
+                }
+                if should_strip_accents.unwrap_or(*lowercase) {
+                    s = strip_accents(&s.nfd().collect::<String>());
+                }
+                if *lowercase {
+                    s = s.to_lowercase();
+                }
+                s
+            }
+            Self::Lowercase => s.to_lowercase(),
+            Self::StripAccents => strip_accents(s),
+            Self::NFC => s.nfc().collect(),
+            Self::NFD => s.nfd().collect(),
+            Self::NFKC => s.nfkc().collect(),
+            Self::NFKD => s.nfkd().collect(),
+            Self::Nmt => s
+                .chars()
+                .filter(|c| {
+                    !matches!(
+                        *c as u32,
+                        0x0001..=0x0008 | 0x000B | 0x000E..=0x001F | 0x007F | 0x008F | 0x009F
+                    )
+                })
+                .map(|c| match c as u32 {
+                    0x0009
+                    | 0x000A
+                    | 0x000C
+                    | 0x000D
+                    | 0x1680
+                    | 0x200B..=0x200F
+                    | 0x2028
+                    | 0x2029
+                    | 0x2581
+                    | 0xFEFF
+                    | 0xFFFD => ' ',
+                    _ => c,
+                })
+                .collect(),
+            Self::Strip {
+                strip_left,
+                strip_right,
+            } => {
+                let s = if *strip_left { s.trim_start() } else { s };
+                let s = if *strip_right { s.trim_end() } else { s };
+                s.to_string()
+            }
+            Self::Replace { pattern, content } => pattern.replace(s, content),
+            Self::Prepend { prepend } if !s.is_empty() => format!("{prepend}{s}"),
+            Self::Prepend { .. } => String::new(),
+            Self::Precompiled {
+                precompiled_charsmap: Some(precompiled),
+            } => precompiled.normalize(s),
+            Self::Precompiled {
+                precompiled_charsmap: None,
+            } => s.to_string(),
+            Self::ByteLevel => to_byte_level(s),
+            Self::Sequence { normalizers } => normalizers
+                .iter()
+                .fold(s.to_string(), |s, normalizer| normalizer.normalize(&s)),
+        }
+    }
+}
+
+/// The normalization rules of a SentencePiece model, stored as a double-array trie from
+/// UTF-8 sequences to offsets of their null-terminated replacements.
+#[derive(Debug, Deserialize)]
+#[serde(try_from = "String")]
+pub(crate) struct Precompiled {
+    trie: Vec<u32>,
+    normalized: Vec<u8>,
+}
+
+impl TryFrom<String> for Precompiled {
+    type Error = String;
+
+    fn try_from(charsmap: String) -> Result<Self, Self::Error> {
+        let bytes = general_purpose::STANDARD
+            .decode(charsmap)
+            .map_err(|e| format!("Invalid precompiled charsmap: {e}"))?;
+        let invalid = || "Invalid precompiled charsmap: truncated data".to_string();
+        let trie_size = u32::from_le_bytes(bytes.get(..4).ok_or_else(invalid)?.try_into().unwrap());
+        let trie_end = 4 + trie_size as usize;
+        let trie = bytes
+            .get(4..trie_end)
+            .ok_or_else(invalid)?
+            .chunks_exact(4)
+            .map(|unit| u32::from_le_bytes(unit.try_into().unwrap()))
+            .collect();
+        Ok(Self {
+            trie,
+            normalized: bytes[trie_end..].to_vec(),
+        })
+    }
+}
+
+impl Precompiled {
+    fn normalize(&self, s: &str) -> String {
+        let mut result = String::with_capacity(s.len());
+        for grapheme in s.graphemes(true) {
+            // Like SentencePiece, whole graphemes are replaced if there's a rule for them, and
+            // otherwise each of their characters is replaced on its own.
+            if grapheme.len() < 6 {
+                if let Some(normalized) = self.transform(grapheme) {
+                    result.push_str(normalized);
+                    continue;
+                }
+            }
+            for (i, c) in grapheme.char_indices() {
+                let part = &grapheme[i..i + c.len_utf8()];
+                result.push_str(self.transform(part).unwrap_or(part));
+            }
+        }
+        result
+    }
+
+    fn transform(&self, chunk: &str) -> Option<&str> {
+        let start = *self.common_prefix_search(chunk.as_bytes()).first()? as usize;
+        let len = self.normalized.get(start..)?.iter().position(|b| *b == 0)?;
+        std::str::from_utf8(&self.normalized[start..start + len]).ok()
+    }
+
+    /// Values of every key in the trie that's a prefix of `key`, from shortest to longest.
+    fn common_prefix_search(&self, key: &[u8]) -> Vec<u32> {
+        let has_leaf = |unit: u32| (unit >> 8) & 1 == 1;
+        let value = |unit: u32| unit & ((1 << 31) - 1);
+        let label = |unit: u32| unit & ((1 << 31) | 0xFF);
+        let offset = |unit: u32| ((unit >> 10) << ((unit & (1 << 9)) >> 6)) as usize;
+
+        let mut results = Vec::new();
+        let Some(&unit) = self.trie.first() else {
+            return results;
+        };
+        let mut node_pos = offset(unit);
+        for &c in key {
+            if c == 0 {
+                break;
+            }
+            node_pos ^= c as usize;
+            let Some(&unit) = self.trie.get(node_pos) else {
+                break;
+            };
+            if label(unit) != u32::from(c) {
+                break;
+            }
+            node_pos ^= offset(unit);
+            if has_leaf(unit) {
+                match self.trie.get(node_pos) {
+                    Some(&leaf) => results.push(value(leaf)),
+                    None => break,
+                }
+            }
+        }
+        results
+    }
+}
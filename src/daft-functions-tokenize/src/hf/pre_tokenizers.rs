@@ -0,0 +1,201 @@
+use std::sync::LazyLock;
+
+use serde::Deserialize;
+
+use super::{default_true, find_regex_matches, split, to_byte_level, Pattern, SplitBehavior};
+
+// Variants are named after the types in tokenizer.json.
+#[allow(clippy::enum_variant_names)]
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type")]
+pub(crate) enum PreTokenizer {
+    BertPreTokenizer,
+    Whitespace,
+    WhitespaceSplit,
+    ByteLevel {
+        #[serde(default = "default_true")]
+        add_prefix_space: bool,
+        #[serde(default = "default_true")]
+        use_regex: bool,
+    },
+    Metaspace(Metaspace),
+    Split {
+        pattern: Pattern,
+        behavior: SplitBehavior,
+        #[serde(default)]
+        invert: bool,
+    },
+    Punctuation {
+        #[serde(default = "isolated")]
+        behavior: SplitBehavior,
+    },
+    Digits {
+        #[serde(default)]
+        individual_digits: bool,
+    },
+    CharDelimiterSplit {
+        delimiter: char,
+    },
+    Sequence {
+        pretokenizers: Vec<PreTokenizer>,
+    },
+}
+
+const fn isolated() -> SplitBehavior {
+    SplitBehavior::Isolated
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum PrependScheme {
+    First,
+    Never,
+    Always,
+}
+
+/// Replaces spaces with a visible character such as `▁`, as done by SentencePiece.
+#[derive(Debug, Deserialize)]
+pub(crate) struct Metaspace {
+    pub replacement: char,
+    #[serde(default)]
+    prepend_scheme: Option<PrependScheme>,
+    // Older tokenizer.json files use this instead of `prepend_scheme`.
+    #[serde(default)]
+    add_prefix_space: Option<bool>,
+    #[serde(default = "default_true")]
+    split: bool,
+}
+
+impl Metaspace {
+    pub(crate) fn prepend_scheme(&self) -> PrependScheme {
+        self.prepend_scheme.unwrap_or(match self.add_prefix_space {
+            Some(false) => PrependScheme::Never,
+            _ => PrependScheme::Always,
+        })
+    }
+}
+
+/// The pattern GPT-2 uses to split text into words before byte-level BPE.
+static GPT2_PATTERN: LazyLock<fancy_regex::Regex> = LazyLock::new(|| {
+    fancy_regex::Regex::new(
+        r"'s|'t|'re|'ve|'m|'ll|'d| ?\p{L}+| ?\p{N}+| ?[^\s\p{L}\p{N}]+|\s+(?!\S)|\s+",
+    )
+    .unwrap()
+});
+
+static WHITESPACE_PATTERN: LazyLock<regex::Regex> =
+    LazyLock::new(|| regex::Regex::new(r"\w+|[^\w\s]+").unwrap());
+
+static PUNCTUATION_PATTERN: LazyLock<regex::Regex> =
+    LazyLock::new(|| regex::Regex::new(r"^\p{P}$").unwrap());
+
+fn is_punctuation(c: char) -> bool {
+    c.is_ascii_punctuation() || PUNCTUATION_PATTERN.is_match(c.encode_utf8(&mut [0; 4]))
+}
+
+/// Byte ranges of every character of `s` matching `predicate`.
+fn char_matches(s: &str, predicate: impl Fn(char) -> bool) -> Vec<(usize, usize)> {
+    s.char_indices()
+        .filter(|(_, c)| predicate(*c))
+        .map(|(i, c)| (i, i + c.len_utf8()))
+        .collect()
+}
+
+impl PreTokenizer {
+    /// Splits `s` into words, where `at_start` is whether `s` starts at the beginning of the input.
+    pub(crate) fn pre_tokenize(&self, s: &str, at_start: bool) -> Vec<String> {
+        match self {
+            Self::BertPreTokenizer => s
+                .split_whitespace()
+                .flat_map(|word| {
+                    split(
+                        word,
+                        &char_matches(word, is_punctuation),
+                        SplitBehavior::Isolated,
+                        false,
+                    )
+                })
+                .collect(),
+            Self::Whitespace => WHITESPACE_PATTERN
+                .find_iter(s)
+                .map(|m| m.as_str().to_string())
+                .collect(),
+            Self::WhitespaceSplit => s.split_whitespace().map(str::to_string).collect(),
+            Self::ByteLevel {
+                add_prefix_space,
+                use_regex,
+            } => {
+                let s = if *add_prefix_space && !s.starts_with(' ') {
+                    format!(" {s}")
+                } else {
+                    s.to_string()
+                };
+                let words = if *use_regex {
+                    split(
+                        &s,
+                        &find_regex_matches(&GPT2_PATTERN, &s),
+                        SplitBehavior::Isolated,
+                        false,
+                    )
+                } else {
+                    vec![s]
+                };
+                words.iter().map(|word| to_byte_level(word)).collect()
+            }
+            Self::Metaspace(metaspace) => {
+                let replacement = metaspace.replacement;
+                let mut s = s.replace(' ', replacement.encode_utf8(&mut [0; 4]));
+                let prepend = match metaspace.prepend_scheme() {
+                    PrependScheme::Always => true,
+                    PrependScheme::First => at_start,
+                    PrependScheme::Never => false,
+                };
+                if prepend && !s.starts_with(replacement) {
+                    s.insert(0, replacement);
+                }
+                if metaspace.split {
+                    let matches = char_matches(&s, |c| c == replacement);
+                    split(&s, &matches, SplitBehavior::MergedWithNext, false)
+                } else {
+                    vec![s]
+                }
+            }
+            Self::Split {
+                pattern,
+                behavior,
+                invert,
+            } => split(s, &pattern.find_matches(s), *behavior, *invert),
+            Self::Punctuation { behavior } => {
+                split(s, &char_matches(s, is_punctuation), *behavior, false)
+            }
+            Self::Digits { individual_digits } => {
+                let matches = char_matches(s, |c| c.is_numeric());
+                let behavior = if *individual_digits {
+                    SplitBehavior::Isolated
+                } else {
+                    SplitBehavior::Contiguous
+                };
+                split(s, &matches, behavior, false)
+            }
+            Self::CharDelimiterSplit { delimiter } => split(
+                s,
+                &char_matches(s, |c| c == *delimiter),
+                SplitBehavior::Removed,
+                false,
+            ),
+            Self::Sequence { pretokenizers } => {
+                pretokenizers
+                    .iter()
+                    .fold(vec![s.to_string()], |words, pre_tokenizer| {
+                        words
+                            .iter()
+                            .enumerate()
+                            .flat_map(|(i, word)| {
+                                pre_tokenizer.pre_tokenize(word, at_start && i == 0)
+                            })
+                            .collect()
+                    })
+            }
+        }
+    }
+}
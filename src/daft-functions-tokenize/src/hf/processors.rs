@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type")]
+pub(crate) enum PostProcessor {
+    TemplateProcessing {
+        single: Vec<TemplatePiece>,
+        #[serde(default)]
+        special_tokens: HashMap<String, SpecialToken>,
+    },
+    BertProcessing {
+        sep: (String, u32),
+        cls: (String, u32),
+    },
+    RobertaProcessing {
+        sep: (String, u32),
+        cls: (String, u32),
+    },
+    // Only adjusts offsets, which aren't tracked.
+    ByteLevel,
+    Sequence {
+        processors: Vec<PostProcessor>,
+    },
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) enum TemplatePiece {
+    SpecialToken { id: String },
+    Sequence {},
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct SpecialToken {
+    ids: Vec<u32>,
+}
+
+impl PostProcessor {
+    /// Adds special tokens to the ids of a single sequence.
+    pub(crate) fn process(&self, ids: Vec<u32>) -> Vec<u32> {
+        match self {
+            Self::TemplateProcessing {
+                single,
+                special_tokens,
+            } => {
+                let mut result = Vec::with_capacity(ids.len() + single.len());
+                for piece in single {
+                    match piece {
+                        TemplatePiece::SpecialToken { id } => {
+                            result.extend(special_tokens.get(id).into_iter().flat_map(|t| &t.ids));
+                        }
+                        TemplatePiece::Sequence {} => result.extend(&ids),
+                    }
+                }
+                result
+            }
+            Self::BertProcessing { sep, cls } | Self::RobertaProcessing { sep, cls } => {
+                let mut result = Vec::with_capacity(ids.len() + 2);
+                result.push(cls.1);
+                result.extend(ids);
+                result.push(sep.1);
+                result
+            }
+            Self::ByteLevel => ids,
+            Self::Sequence { processors } => processors
+                .iter()
+                .fold(ids, |ids, processor| processor.process(ids)),
+        }
+    }
+
+    /// The number of special tokens added to a single sequence.
+    pub(crate) fn num_added_tokens(&self) -> usize {
+        self.process(Vec::new()).len()
+    }
+}
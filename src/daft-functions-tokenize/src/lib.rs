@@ -3,7 +3,11 @@ use daft_dsl::functions::FunctionModule;
 mod bpe;
 mod decode;
 mod encode;
+mod hf;
 mod special_tokens;
+mod tokenizer;
+
+pub use bpe::DaftBPE;
 
 pub struct TokenizeFunctions;
 
@@ -0,0 +1,98 @@
+use std::{path::Path, sync::Arc};
+
+use common_error::{DaftError, DaftResult};
+use common_runtime::get_io_runtime;
+use daft_io::{get_io_client, IOConfig};
+
+use crate::{
+    bpe::{DaftBPE, Error},
+    hf::{HfTokenizer, PaddingParams},
+};
+
+/// Fetches a file from a local or object-store path.
+pub fn read_file(path: &str, io_config: Arc<IOConfig>) -> DaftResult<bytes::Bytes> {
+    let client = get_io_client(false, io_config)?;
+    let runtime = get_io_runtime(false);
+
+    let path = path.to_string();
+    Ok(runtime.block_within_async_context(async move {
+        client.single_url_get(path, None, None).await?.bytes().await
+    })??)
+}
+
+/// A tokenizer loaded from either a tiktoken token file or a HuggingFace `tokenizer.json` file.
+// Only one tokenizer is created per call, so the size difference doesn't matter.
+#[allow(clippy::large_enum_variant)]
+pub enum DaftTokenizer {
+    Tiktoken(DaftBPE),
+    HuggingFace(HfTokenizer),
+}
+
+impl DaftTokenizer {
+    pub fn new(
+        tokens_path: &str,
+        io_config: Option<Arc<IOConfig>>,
+        pattern: Option<&str>,
+        special_tokens: Option<&str>,
+    ) -> DaftResult<Self> {
+        let is_json = Path::new(tokens_path)
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("json"));
+        if !is_json {
+            return DaftBPE::new(tokens_path, io_config, pattern, special_tokens)
+                .map(Self::Tiktoken);
+        }
+
+        // The pattern and special tokens are read from the tokenizer.json file instead.
+        if pattern.is_some() || special_tokens.is_some() {
+            return Err(DaftError::ValueError(
+                "pattern and special_tokens are not supported for HuggingFace tokenizer.json files"
+                    .to_string(),
+            ));
+        }
+        let file_bytes = read_file(tokens_path, io_config.unwrap_or_default())?;
+        HfTokenizer::from_json(&file_bytes).map(Self::HuggingFace)
+    }
+
+    /// Encodes `s` into at most `max_length` token ids.
+    ///
+    /// `use_special_tokens` controls whether special tokens in the text are encoded for tiktoken,
+    /// and whether the post-processor adds special tokens for HuggingFace tokenizers.
+    pub fn encode(&self, s: &str, use_special_tokens: bool, max_length: Option<usize>) -> Vec<u32> {
+        match self {
+            Self::Tiktoken(bpe) => {
+                let mut tokens = bpe.encode(s, use_special_tokens);
+                if let Some(max_length) = max_length {
+                    tokens.truncate(max_length);
+                }
+                tokens
+            }
+            Self::HuggingFace(tokenizer) => tokenizer.encode(s, use_special_tokens, max_length),
+        }
+    }
+
+    pub fn decode(&self, tokens: &[u32], skip_special_tokens: bool) -> DaftResult<String> {
+        match self {
+            Self::Tiktoken(bpe) if skip_special_tokens => {
+                let tokens = tokens
+                    .iter()
+                    .copied()
+                    .filter(|t| !bpe.is_special(*t))
+                    .collect::<Vec<_>>();
+                bpe.decode(&tokens)
+            }
+            Self::Tiktoken(bpe) => bpe.decode(tokens),
+            Self::HuggingFace(tokenizer) => tokenizer
+                .decode(tokens, skip_special_tokens)
+                .map_err(|token| Error::BadToken { token }.into()),
+        }
+    }
+
+    /// Padding configured in the tokenizer file, if any.
+    pub fn padding(&self) -> Option<&PaddingParams> {
+        match self {
+            Self::Tiktoken(_) => None,
+            Self::HuggingFace(tokenizer) => tokenizer.padding(),
+        }
+    }
+}
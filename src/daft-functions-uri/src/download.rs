@@ -1,5 +1,10 @@
-use std::sync::Arc;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 
+use arrow2::offset::OffsetsBuffer;
 use common_error::{ensure, DaftError, DaftResult};
 use common_runtime::get_io_runtime;
 use daft_core::prelude::*;
@@ -7,9 +12,16 @@ use daft_dsl::{
     functions::{FunctionArgs, ScalarUDF},
     ExprRef,
 };
-use daft_io::{get_io_client, Error, IOConfig, IOStatsContext, IOStatsRef};
+use daft_io::{
+    get_io_client, Error, HttpResponse, HttpSource, IOClient, IOConfig, IOStatsContext, IOStatsRef,
+    MAX_RETRY_AFTER,
+};
 use futures::{StreamExt, TryStreamExt};
 use serde::Serialize;
+use tokio::{
+    sync::{Semaphore, SemaphorePermit},
+    time::Instant,
+};
 
 /// Container for the keyword arguments of `url_download`
 /// ex:
@@ -19,6 +31,7 @@ use serde::Serialize;
 /// url_download(input, on_error='raise')
 /// url_download(input, on_error='null')
 /// url_download(input, max_connections=32, on_error='raise')
+/// url_download(input, max_connections_per_host=2, host_delay=0.5, max_bytes=1048576, return_metadata=True)
 /// ```
 #[derive(Debug, Clone, Serialize, serde::Deserialize, PartialEq, Eq, Hash)]
 pub struct UrlDownload;
@@ -34,6 +47,34 @@ pub struct UrlDownloadArgs<T> {
     pub max_connections: Option<usize>,
     #[arg(optional)]
     pub on_error: Option<String>,
+    #[arg(optional)]
+    pub max_connections_per_host: Option<usize>,
+    /// Seconds between the start of consecutive requests to the same host.
+    #[arg(optional)]
+    pub host_delay: Option<f64>,
+    #[arg(optional)]
+    pub max_bytes: Option<usize>,
+    #[arg(optional)]
+    pub return_metadata: Option<bool>,
+}
+
+/// Options for downloading http and https URLs politely, where the URLs of any other scheme are
+/// downloaded as usual.
+#[derive(Debug, Clone, Default)]
+struct HttpOptions {
+    max_connections_per_host: Option<usize>,
+    host_delay: Duration,
+    max_bytes: Option<usize>,
+    return_metadata: bool,
+}
+
+impl HttpOptions {
+    fn is_default(&self) -> bool {
+        self.max_connections_per_host.is_none()
+            && self.host_delay.is_zero()
+            && self.max_bytes.is_none()
+            && !self.return_metadata
+    }
 }
 
 #[typetag::serde]
@@ -48,6 +89,10 @@ impl ScalarUDF for UrlDownload {
             io_config,
             max_connections,
             on_error,
+            max_connections_per_host,
+            host_delay,
+            max_bytes,
+            return_metadata,
         } = inputs.try_into()?;
 
         let max_connections = max_connections.unwrap_or(32);
@@ -65,18 +110,36 @@ impl ScalarUDF for UrlDownload {
                 )))
             }
         };
+        let http_options = HttpOptions {
+            max_connections_per_host,
+            host_delay: parse_host_delay(host_delay)?,
+            max_bytes,
+            return_metadata: return_metadata.unwrap_or(false),
+        };
 
         let array = input.utf8()?;
         let io_stats = IOStatsContext::new("download");
-        let result = url_download(
-            array,
-            max_connections,
-            raise_error_on_failure,
-            multi_thread,
-            Arc::new(io_config),
-            Some(io_stats),
-        )?;
-        Ok(result.into_series())
+        if http_options.is_default() {
+            let result = url_download(
+                array,
+                max_connections,
+                raise_error_on_failure,
+                multi_thread,
+                Arc::new(io_config),
+                Some(io_stats),
+            )?;
+            Ok(result.into_series())
+        } else {
+            url_download_http(
+                array,
+                max_connections,
+                raise_error_on_failure,
+                multi_thread,
+                Arc::new(io_config),
+                Some(io_stats),
+                http_options,
+            )
+        }
     }
 
     fn get_return_field(
@@ -84,13 +147,55 @@ impl ScalarUDF for UrlDownload {
         inputs: FunctionArgs<ExprRef>,
         schema: &Schema,
     ) -> DaftResult<Field> {
-        let UrlDownloadArgs { input, .. } = inputs.try_into()?;
+        let UrlDownloadArgs {
+            input,
+            max_connections_per_host,
+            host_delay,
+            return_metadata,
+            ..
+        } = inputs.try_into()?;
         let field = input.to_field(schema)?;
         ensure!(field.dtype.is_string(), TypeError: "Input must be a string");
-        Ok(Field::new(field.name, DataType::Binary))
+        ensure!(
+            max_connections_per_host != Some(0),
+            ValueError: "max_connections_per_host for url_download must be non-zero"
+        );
+        parse_host_delay(host_delay)?;
+        if return_metadata.unwrap_or(false) {
+            Ok(Field::new(field.name, metadata_dtype()))
+        } else {
+            Ok(Field::new(field.name, DataType::Binary))
+        }
     }
 }
 
+fn parse_host_delay(host_delay: Option<f64>) -> DaftResult<Duration> {
+    host_delay.map_or(Ok(Duration::ZERO), |seconds| {
+        Duration::try_from_secs_f64(seconds).map_err(|_| {
+            DaftError::ValueError(format!(
+                "host_delay for url_download must be a non-negative number of seconds, got {seconds}"
+            ))
+        })
+    })
+}
+
+/// The struct of each download with `return_metadata`, where the status and headers are null for
+/// URLs that aren't http or https.
+fn metadata_dtype() -> DataType {
+    DataType::Struct(vec![
+        Field::new("content", DataType::Binary),
+        Field::new("status", DataType::UInt16),
+        Field::new(
+            "headers",
+            DataType::Map {
+                key: Box::new(DataType::Utf8),
+                value: Box::new(DataType::Utf8),
+            },
+        ),
+        Field::new("url", DataType::Utf8),
+    ])
+}
+
 fn url_download(
     array: &Utf8Array,
     max_connections: usize,
@@ -173,3 +278,274 @@ fn url_download(
         .with_validity_slice(valid.as_slice())
         .unwrap())
 }
+
+/// Limits the concurrency of, and spaces out, the requests to each host.
+struct HostThrottle {
+    max_connections: usize,
+    delay: Duration,
+    hosts: Mutex<HashMap<String, Arc<Host>>>,
+}
+
+struct Host {
+    connections: Semaphore,
+    next_request: tokio::sync::Mutex<Instant>,
+}
+
+impl HostThrottle {
+    fn host(&self, url: &str) -> Arc<Host> {
+        let name = url::Url::parse(url)
+            .ok()
+            .and_then(|url| {
+                let host = url.host_str()?;
+                Some(match url.port() {
+                    Some(port) => format!("{host}:{port}"),
+                    None => host.to_string(),
+                })
+            })
+            .unwrap_or_default();
+        self.hosts
+            .lock()
+            .unwrap()
+            .entry(name)
+            .or_insert_with(|| {
+                Arc::new(Host {
+                    connections: Semaphore::new(self.max_connections),
+                    next_request: tokio::sync::Mutex::new(Instant::now()),
+                })
+            })
+            .clone()
+    }
+}
+
+impl Host {
+    /// Waits for a free connection, and until `delay` after the previous request started.
+    async fn acquire(&self, delay: Duration) -> SemaphorePermit<'_> {
+        let permit = self
+            .connections
+            .acquire()
+            .await
+            .expect("host semaphore is never closed");
+        let mut next_request = self.next_request.lock().await;
+        tokio::time::sleep_until(*next_request).await;
+        *next_request = Instant::now() + delay;
+        permit
+    }
+
+    /// Holds off every request to the host for `delay`.
+    async fn back_off(&self, delay: Duration) {
+        let mut next_request = self.next_request.lock().await;
+        *next_request = (*next_request).max(Instant::now() + delay);
+    }
+}
+
+/// A downloaded URL, with the metadata of the final response for http and https URLs.
+type Download = (Option<bytes::Bytes>, Option<HttpResponse>, String);
+
+/// Downloads an http or https URL, holding off the whole host when it responds with a
+/// `Retry-After`.
+async fn http_download(
+    source: &HttpSource,
+    throttle: &HostThrottle,
+    url: &str,
+    options: &HttpOptions,
+    num_tries: u32,
+    io_stats: Option<IOStatsRef>,
+) -> daft_io::Result<HttpResponse> {
+    let host = throttle.host(url);
+    let mut tries = 1;
+    loop {
+        let response = {
+            let _permit = host.acquire(throttle.delay).await;
+            source
+                .download(url, options.max_bytes, io_stats.clone())
+                .await?
+        };
+        match response.retry_after {
+            Some(delay) if tries < num_tries && delay <= MAX_RETRY_AFTER => {
+                host.back_off(delay).await;
+                tries += 1;
+            }
+            _ => return Ok(response),
+        }
+    }
+}
+
+async fn download(
+    io_client: &IOClient,
+    throttle: &HostThrottle,
+    url: String,
+    options: &HttpOptions,
+    num_tries: u32,
+    io_stats: Option<IOStatsRef>,
+) -> daft_io::Result<Download> {
+    let Some(source) = io_client.get_http_source(&url).await? else {
+        let content = io_client
+            .single_url_get(url.clone(), None, io_stats)
+            .await?;
+        return Ok((Some(content.bytes().await?), None, url));
+    };
+    let mut response = http_download(&source, throttle, &url, options, num_tries, io_stats).await?;
+    if options.return_metadata {
+        Ok((response.body.take(), Some(response), url))
+    } else {
+        Ok((Some(response.into_body()?), None, url))
+    }
+}
+
+fn url_download_http(
+    array: &Utf8Array,
+    max_connections: usize,
+    raise_error_on_failure: bool,
+    multi_thread: bool,
+    config: Arc<IOConfig>,
+    io_stats: Option<IOStatsRef>,
+    options: HttpOptions,
+) -> DaftResult<Series> {
+    ensure!(
+        max_connections > 0,
+        ValueError: "max_connections for url_download must be non-zero"
+    );
+    let runtime_handle = get_io_runtime(true);
+    let max_connections = match multi_thread {
+        false => max_connections,
+        true => max_connections * usize::from(std::thread::available_parallelism()?),
+    };
+    let throttle = Arc::new(HostThrottle {
+        max_connections: options.max_connections_per_host.unwrap_or(max_connections),
+        delay: options.host_delay,
+        hosts: Mutex::default(),
+    });
+    let num_tries = config.http.num_tries;
+    let io_client = get_io_client(multi_thread, config)?;
+    let options = Arc::new(options);
+
+    let urls = array
+        .as_arrow()
+        .iter()
+        .map(|s| s.map(std::string::ToString::to_string))
+        .collect::<Vec<_>>();
+    let fetches = {
+        let options = options.clone();
+        async move {
+            let stream = futures::stream::iter(urls.into_iter().enumerate().map(move |(i, url)| {
+                let io_client = io_client.clone();
+                let throttle = throttle.clone();
+                let options = options.clone();
+                let io_stats = io_stats.clone();
+                tokio::spawn(async move {
+                    let Some(url) = url else {
+                        return Ok(None);
+                    };
+                    let result =
+                        download(&io_client, &throttle, url, &options, num_tries, io_stats).await;
+                    match result {
+                        Ok(download) => Ok(Some(download)),
+                        Err(err) if raise_error_on_failure => Err(err),
+                        Err(err) => {
+                            log::warn!(
+                                "Error occurred during url_download at index: {i} {err} (falling back to Null)"
+                            );
+                            Ok(None)
+                        }
+                    }
+                })
+            }))
+            .buffered(max_connections)
+            .map(|r| r.map_err(|error| Error::JoinError { source: error })?);
+            stream.try_collect::<Vec<_>>().await
+        }
+    };
+    let results = runtime_handle.block_within_async_context(fetches)??;
+
+    let name = array.name();
+    let content = BinaryArray::from_iter(
+        if options.return_metadata {
+            "content"
+        } else {
+            name
+        },
+        results
+            .iter()
+            .map(|download| download.as_ref().and_then(|(content, ..)| content.as_ref())),
+    );
+    if !options.return_metadata {
+        return Ok(content.into_series());
+    }
+
+    let responses = results
+        .iter()
+        .map(|download| {
+            download
+                .as_ref()
+                .and_then(|(_, response, _)| response.as_ref())
+        })
+        .collect::<Vec<_>>();
+    let status = UInt16Array::from_iter(
+        Field::new("status", DataType::UInt16),
+        responses.iter().map(|response| response.map(|r| r.status)),
+    );
+    let url = Utf8Array::from_iter(
+        "url",
+        results.iter().map(|download| {
+            download.as_ref().map(|(_, response, url)| {
+                response
+                    .as_ref()
+                    .map_or(url.as_str(), |response| response.url.as_str())
+            })
+        }),
+    );
+    let validity = bitmap::Bitmap::from_iter(results.iter().map(Option::is_some));
+    Ok(StructArray::new(
+        Field::new(name, metadata_dtype()),
+        vec![
+            content.into_series(),
+            status.into_series(),
+            headers_array(&responses)?.into_series(),
+            url.into_series(),
+        ],
+        Some(validity),
+    )
+    .into_series())
+}
+
+/// The headers of each response as a map, which is null for URLs that aren't http or https.
+fn headers_array(responses: &[Option<&HttpResponse>]) -> DaftResult<MapArray> {
+    let headers = responses
+        .iter()
+        .flatten()
+        .flat_map(|r| r.headers.iter())
+        .collect::<Vec<_>>();
+    let keys = Utf8Array::from_values("key", headers.iter().map(|(key, _)| key));
+    let values = Utf8Array::from_values("value", headers.iter().map(|(_, value)| value));
+    let entry_dtype = DataType::Struct(vec![
+        Field::new("key", DataType::Utf8),
+        Field::new("value", DataType::Utf8),
+    ]);
+    let entries = StructArray::new(
+        Field::new("entries", entry_dtype.clone()),
+        vec![keys.into_series(), values.into_series()],
+        None,
+    );
+
+    let lengths = responses
+        .iter()
+        .map(|response| response.map_or(0, |r| r.headers.len()));
+    let offsets = OffsetsBuffer::from(arrow2::offset::Offsets::try_from_lengths(lengths)?);
+    let validity = bitmap::Bitmap::from_iter(responses.iter().map(Option::is_some));
+    let list = ListArray::new(
+        Field::new("headers", DataType::List(Box::new(entry_dtype))),
+        entries.into_series(),
+        offsets,
+        Some(validity),
+    );
+    Ok(MapArray::new(
+        Field::new(
+            "headers",
+            DataType::Map {
+                key: Box::new(DataType::Utf8),
+                value: Box::new(DataType::Utf8),
+            },
+        ),
+        list,
+    ))
+}
@@ -0,0 +1,364 @@
+use std::{collections::VecDeque, str::FromStr, sync::Arc};
+
+use arrow2::offset::OffsetsBuffer;
+use common_error::{ensure, DaftError, DaftResult};
+use common_io_config::IOConfig;
+use daft_core::{
+    array::{ListArray, StructArray},
+    prelude::{AsArrow, DataType, Field, Schema, UInt64Array, Utf8Array},
+    series::{IntoSeries, Series},
+};
+use daft_dsl::{
+    functions::{FunctionArgs, ScalarUDF},
+    ExprRef,
+};
+use daft_functions_tokenize::DaftBPE;
+use serde::{Deserialize, Serialize};
+use unicode_segmentation::UnicodeSegmentation;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct ChunkText;
+
+#[derive(FunctionArgs)]
+struct ChunkTextArgs<T> {
+    input: T,
+    chunk_size: usize,
+    #[arg(optional)]
+    overlap: Option<usize>,
+    #[arg(optional)]
+    strategy: Option<String>,
+    #[arg(optional)]
+    tokens_path: Option<String>,
+    #[arg(optional)]
+    io_config: Option<IOConfig>,
+    #[arg(optional)]
+    pattern: Option<String>,
+}
+
+/// How text is split into chunks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ChunkStrategy {
+    /// Windows of `chunk_size` characters.
+    Characters,
+    /// Windows of `chunk_size` tokens.
+    Tokens,
+    /// Runs of whole sentences with at most `chunk_size` characters.
+    Sentences,
+    /// Pieces split on paragraphs, then lines, sentences and words until they fit in
+    /// `chunk_size` characters, merged back together while they fit.
+    Recursive,
+}
+
+impl FromStr for ChunkStrategy {
+    type Err = DaftError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "characters" => Ok(Self::Characters),
+            "tokens" => Ok(Self::Tokens),
+            "sentences" => Ok(Self::Sentences),
+            "recursive" => Ok(Self::Recursive),
+            _ => Err(DaftError::ValueError(format!(
+                "Unsupported chunking strategy {s}, expected one of \"characters\", \"tokens\", \"sentences\" or \"recursive\""
+            ))),
+        }
+    }
+}
+
+const DEFAULT_TOKENIZER: &str = "cl100k_base";
+const RECURSIVE_SEPARATORS: &[&str] = &["\n\n", "\n", ". ", " ", ""];
+
+fn chunk_fields() -> Vec<Field> {
+    vec![
+        Field::new("text", DataType::Utf8),
+        Field::new("start", DataType::UInt64),
+        Field::new("end", DataType::UInt64),
+    ]
+}
+
+fn chunk_text_return_type() -> DataType {
+    DataType::List(Box::new(DataType::Struct(chunk_fields())))
+}
+
+#[typetag::serde]
+impl ScalarUDF for ChunkText {
+    fn name(&self) -> &'static str {
+        "chunk_text"
+    }
+
+    fn call(&self, inputs: daft_dsl::functions::FunctionArgs<Series>) -> DaftResult<Series> {
+        let ChunkTextArgs {
+            input,
+            chunk_size,
+            overlap,
+            strategy,
+            tokens_path,
+            io_config,
+            pattern,
+        } = inputs.try_into()?;
+        let overlap = overlap.unwrap_or(0);
+        ensure!(chunk_size > 0, ValueError: "chunk_size must be positive");
+        ensure!(
+            overlap < chunk_size,
+            ValueError: "overlap must be smaller than chunk_size, got overlap {overlap} and chunk_size {chunk_size}"
+        );
+        let strategy = strategy
+            .as_deref()
+            .map_or(Ok(ChunkStrategy::Characters), str::parse)?;
+
+        let chunker = Chunker {
+            chunk_size,
+            overlap,
+            strategy,
+            bpe: match strategy {
+                ChunkStrategy::Tokens => Some(DaftBPE::new(
+                    tokens_path.as_deref().unwrap_or(DEFAULT_TOKENIZER),
+                    io_config.map(Arc::new),
+                    pattern.as_deref(),
+                    None,
+                )?),
+                _ => None,
+            },
+        };
+        input.with_utf8_array(|arr| Ok(chunk_text_impl(arr, &chunker)?.into_series()))
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        let ChunkTextArgs { input, .. } = inputs.try_into()?;
+        let input = input.to_field(schema)?;
+        ensure!(input.dtype.is_string(), TypeError: "Expected string type for input argument");
+        Ok(Field::new(input.name, chunk_text_return_type()))
+    }
+
+    fn docstring(&self) -> &'static str {
+        "Splits a string into overlapping chunks, returning the text and character offsets of each chunk."
+    }
+}
+
+struct Chunker {
+    chunk_size: usize,
+    overlap: usize,
+    strategy: ChunkStrategy,
+    bpe: Option<DaftBPE>,
+}
+
+/// Maps byte offsets of a string to character offsets.
+struct CharOffsets {
+    char_starts: Vec<usize>,
+}
+
+impl CharOffsets {
+    fn new(s: &str) -> Self {
+        Self {
+            char_starts: s
+                .char_indices()
+                .map(|(i, _)| i)
+                .chain(std::iter::once(s.len()))
+                .collect(),
+        }
+    }
+
+    /// The character offset of a byte offset on a character boundary.
+    fn char_offset(&self, byte_offset: usize) -> usize {
+        self.char_starts
+            .partition_point(|start| *start < byte_offset)
+    }
+
+    fn char_len(&self, (start, end): (usize, usize)) -> usize {
+        self.char_offset(end) - self.char_offset(start)
+    }
+}
+
+impl Chunker {
+    /// Splits `s` into byte ranges of chunks.
+    fn chunk(&self, s: &str, offsets: &CharOffsets) -> Vec<(usize, usize)> {
+        match self.strategy {
+            ChunkStrategy::Characters => self.windows(&offsets.char_starts),
+            ChunkStrategy::Tokens => {
+                let bpe = self.bpe.as_ref().expect("tokens strategy has a tokenizer");
+                let mut token_starts = vec![0];
+                for len in bpe.token_lengths(s) {
+                    token_starts.push(token_starts.last().unwrap() + len);
+                }
+                // Tokens can split multi-byte characters, so widen chunks to whole characters.
+                self.windows(&token_starts)
+                    .into_iter()
+                    .map(|(start, end)| {
+                        let start = (0..=start).rev().find(|i| s.is_char_boundary(*i)).unwrap();
+                        let end = (end..=s.len()).find(|i| s.is_char_boundary(*i)).unwrap();
+                        (start, end)
+                    })
+                    .collect()
+            }
+            ChunkStrategy::Sentences => {
+                let sentences = s
+                    .split_sentence_bound_indices()
+                    .map(|(start, sentence)| trim_span(s, (start, start + sentence.len())))
+                    .filter(|(start, end)| end > start)
+                    .collect::<Vec<_>>();
+                let mut chunks = Vec::new();
+                self.merge(&sentences, offsets, &mut chunks);
+                chunks
+            }
+            ChunkStrategy::Recursive => {
+                let mut chunks = Vec::new();
+                self.split_recursive(s, (0, s.len()), RECURSIVE_SEPARATORS, offsets, &mut chunks);
+                chunks
+                    .into_iter()
+                    .map(|chunk| trim_span(s, chunk))
+                    .filter(|(start, end)| end > start)
+                    .collect()
+            }
+        }
+    }
+
+    /// Windows of `chunk_size` units that overlap by `overlap` units, given the byte offset of the
+    /// start of each unit followed by the length of the string.
+    fn windows(&self, unit_starts: &[usize]) -> Vec<(usize, usize)> {
+        let num_units = unit_starts.len() - 1;
+        let step = self.chunk_size - self.overlap;
+        let mut chunks = Vec::new();
+        let mut start = 0;
+        while start < num_units {
+            let end = (start + self.chunk_size).min(num_units);
+            chunks.push((unit_starts[start], unit_starts[end]));
+            if end == num_units {
+                break;
+            }
+            start += step;
+        }
+        chunks
+    }
+
+    /// Merges consecutive pieces into chunks of at most `chunk_size` characters, starting each
+    /// chunk with the trailing pieces of the previous one that fit in `overlap` characters.
+    ///
+    /// Pieces longer than `chunk_size` become chunks of their own.
+    fn merge(
+        &self,
+        pieces: &[(usize, usize)],
+        offsets: &CharOffsets,
+        chunks: &mut Vec<(usize, usize)>,
+    ) {
+        let mut current = VecDeque::<(usize, usize)>::new();
+        let current_len = |current: &VecDeque<(usize, usize)>, end: usize| {
+            current
+                .front()
+                .map_or(0, |(start, _)| offsets.char_len((*start, end)))
+        };
+        for &(start, end) in pieces {
+            if !current.is_empty() && current_len(&current, end) > self.chunk_size {
+                chunks.push((current.front().unwrap().0, current.back().unwrap().1));
+                while let Some((_, last_end)) = current.back().copied() {
+                    if current_len(&current, last_end) <= self.overlap
+                        && current_len(&current, end) <= self.chunk_size
+                    {
+                        break;
+                    }
+                    current.pop_front();
+                }
+            }
+            current.push_back((start, end));
+        }
+        if let (Some(first), Some(last)) = (current.front(), current.back()) {
+            chunks.push((first.0, last.1));
+        }
+    }
+
+    fn split_recursive(
+        &self,
+        s: &str,
+        (start, end): (usize, usize),
+        separators: &[&str],
+        offsets: &CharOffsets,
+        chunks: &mut Vec<(usize, usize)>,
+    ) {
+        let text = &s[start..end];
+        let (i, separator) = separators
+            .iter()
+            .enumerate()
+            .find(|(_, separator)| separator.is_empty() || text.contains(**separator))
+            .map_or((separators.len(), ""), |(i, separator)| (i, *separator));
+        let remaining_separators = separators.get(i + 1..).unwrap_or_default();
+
+        // Keep separators at the end of each piece so the pieces cover the whole text.
+        let mut pieces = Vec::new();
+        if separator.is_empty() {
+            pieces.extend(
+                text.char_indices()
+                    .map(|(j, c)| (start + j, start + j + c.len_utf8())),
+            );
+        } else {
+            let mut piece_start = start;
+            for (j, _) in text.match_indices(separator) {
+                pieces.push((piece_start, start + j + separator.len()));
+                piece_start = start + j + separator.len();
+            }
+            if piece_start < end {
+                pieces.push((piece_start, end));
+            }
+        }
+
+        let mut fitting = Vec::new();
+        for piece in pieces {
+            if offsets.char_len(piece) <= self.chunk_size {
+                fitting.push(piece);
+                continue;
+            }
+            self.merge(&fitting, offsets, chunks);
+            fitting.clear();
+            if remaining_separators.is_empty() {
+                chunks.push(piece);
+            } else {
+                self.split_recursive(s, piece, remaining_separators, offsets, chunks);
+            }
+        }
+        self.merge(&fitting, offsets, chunks);
+    }
+}
+
+/// Shrinks a byte range of `s` to exclude leading and trailing whitespace.
+fn trim_span(s: &str, (start, end): (usize, usize)) -> (usize, usize) {
+    let text = &s[start..end];
+    let start = start + (text.len() - text.trim_start().len());
+    (start, start + text.trim().len())
+}
+
+fn chunk_text_impl(arr: &Utf8Array, chunker: &Chunker) -> DaftResult<ListArray> {
+    let mut texts = Vec::new();
+    let mut starts = Vec::new();
+    let mut ends = Vec::new();
+    let mut list_offsets = Vec::with_capacity(arr.len() + 1);
+    list_offsets.push(0i64);
+    for s in arr.as_arrow() {
+        if let Some(s) = s {
+            let offsets = CharOffsets::new(s);
+            for (start, end) in chunker.chunk(s, &offsets) {
+                texts.push(&s[start..end]);
+                starts.push(offsets.char_offset(start) as u64);
+                ends.push(offsets.char_offset(end) as u64);
+            }
+        }
+        list_offsets.push(texts.len() as i64);
+    }
+
+    let chunks = StructArray::new(
+        Field::new("chunks", DataType::Struct(chunk_fields())),
+        vec![
+            Utf8Array::from_values("text", texts.into_iter()).into_series(),
+            UInt64Array::from_values("start", starts.into_iter()).into_series(),
+            UInt64Array::from_values("end", ends.into_iter()).into_series(),
+        ],
+        None,
+    );
+    Ok(ListArray::new(
+        Field::new(arr.name(), chunk_text_return_type()),
+        chunks.into_series(),
+        OffsetsBuffer::try_from(list_offsets)?,
+        arr.validity().cloned(),
+    ))
+}
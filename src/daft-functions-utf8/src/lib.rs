@@ -1,4 +1,5 @@
 mod capitalize;
+mod chunk_text;
 mod contains;
 mod count_matches;
 mod endswith;
@@ -31,6 +32,7 @@ mod upper;
 pub(crate) mod utils;
 
 pub use capitalize::*;
+pub use chunk_text::*;
 pub use contains::*;
 pub use count_matches::*;
 pub use endswith::*;
@@ -65,6 +67,7 @@ pub struct Utf8Functions;
 impl daft_dsl::functions::FunctionModule for Utf8Functions {
     fn register(parent: &mut daft_dsl::functions::FunctionRegistry) {
         parent.add_fn(Capitalize);
+        parent.add_fn(ChunkText);
         parent.add_fn(Contains);
         parent.add_fn(CountMatches);
         parent.add_fn(EndsWith);
@@ -0,0 +1,36 @@
+use common_error::DaftResult;
+use daft_dsl::functions::{prelude::*, ScalarFunction};
+use serde::{Deserialize, Serialize};
+
+use super::vector::{call_pairwise, pairwise_return_field, Metric};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct DotProductFunction;
+
+#[typetag::serde]
+impl ScalarUDF for DotProductFunction {
+    fn name(&self) -> &'static str {
+        "dot_product"
+    }
+
+    fn call(&self, inputs: daft_dsl::functions::FunctionArgs<Series>) -> DaftResult<Series> {
+        call_pairwise(self.name(), Metric::Dot, inputs)
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        pairwise_return_field(self.name(), inputs, schema)
+    }
+
+    fn docstring(&self) -> &'static str {
+        "Computes the dot product of two embeddings."
+    }
+}
+
+#[must_use]
+pub fn dot_product(a: ExprRef, b: ExprRef) -> ExprRef {
+    ScalarFunction::new(DotProductFunction {}, vec![a, b]).into()
+}
@@ -0,0 +1,36 @@
+use common_error::DaftResult;
+use daft_dsl::functions::{prelude::*, ScalarFunction};
+use serde::{Deserialize, Serialize};
+
+use super::vector::{call_pairwise, pairwise_return_field, Metric};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct EuclideanDistanceFunction;
+
+#[typetag::serde]
+impl ScalarUDF for EuclideanDistanceFunction {
+    fn name(&self) -> &'static str {
+        "euclidean_distance"
+    }
+
+    fn call(&self, inputs: daft_dsl::functions::FunctionArgs<Series>) -> DaftResult<Series> {
+        call_pairwise(self.name(), Metric::Euclidean, inputs)
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        pairwise_return_field(self.name(), inputs, schema)
+    }
+
+    fn docstring(&self) -> &'static str {
+        "Computes the Euclidean (L2) distance between two embeddings."
+    }
+}
+
+#[must_use]
+pub fn euclidean_distance(a: ExprRef, b: ExprRef) -> ExprRef {
+    ScalarFunction::new(EuclideanDistanceFunction {}, vec![a, b]).into()
+}
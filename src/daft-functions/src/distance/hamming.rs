@@ -0,0 +1,141 @@
+use common_error::{value_err, DaftError, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::functions::{prelude::*, ScalarFunction};
+use serde::{Deserialize, Serialize};
+
+use super::vector::{check_vector_fields, pairwise, Metric};
+
+#[derive(FunctionArgs)]
+struct Args<T> {
+    input: T,
+    query: T,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct HammingDistanceFunction;
+
+#[typetag::serde]
+impl ScalarUDF for HammingDistanceFunction {
+    fn name(&self) -> &'static str {
+        "hamming_distance"
+    }
+
+    fn call(&self, inputs: daft_dsl::functions::FunctionArgs<Series>) -> DaftResult<Series> {
+        let Args { input, query } = inputs.try_into()?;
+        if is_vector(input.data_type()) {
+            let res = pairwise(self.name(), Metric::Hamming, &input, &query)?;
+            return Ok(UInt32Array::from_iter(
+                Field::new(input.name(), DataType::UInt32),
+                res.into_iter().map(|d| d.map(|d| d as u32)),
+            )
+            .into_series());
+        }
+        let len = match (input.len(), query.len()) {
+            (a, b) if a == b => a,
+            (1, b) => b,
+            (a, 1) => a,
+            (a, b) => {
+                value_err!("Expected inputs to 'hamming_distance' to have the same length or one of them to have length 1, instead got {a} and {b}")
+            }
+        };
+
+        let res = if input.data_type().is_integer() {
+            let input = integer_bits(&input)?;
+            let query = integer_bits(&query)?;
+            (0..len)
+                .map(|i| match (input[i % input.len()], query[i % query.len()]) {
+                    (Some(a), Some(b)) => Some((a ^ b).count_ones()),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        } else {
+            let input = binary_values(&input)?;
+            let query = binary_values(&query)?;
+            (0..len)
+                .map(|i| match (input[i % input.len()], query[i % query.len()]) {
+                    (Some(a), Some(b)) => {
+                        if a.len() != b.len() {
+                            value_err!("Expected inputs to 'hamming_distance' to have the same number of bytes, instead got {} and {}", a.len(), b.len())
+                        }
+                        Ok(Some(
+                            a.iter().zip(b).map(|(a, b)| (a ^ b).count_ones()).sum(),
+                        ))
+                    }
+                    _ => Ok(None),
+                })
+                .collect::<DaftResult<Vec<_>>>()?
+        };
+
+        let name = if input.len() == len {
+            input.name()
+        } else {
+            query.name()
+        };
+        Ok(
+            UInt32Array::from_iter(Field::new(name, DataType::UInt32), res.into_iter())
+                .into_series(),
+        )
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        let Args { input, query } = inputs.try_into()?;
+        let input = input.to_field(schema)?;
+        let query = query.to_field(schema)?;
+
+        if is_vector(&input.dtype) || is_vector(&query.dtype) {
+            check_vector_fields(self.name(), &input, &query)?;
+            return Ok(Field::new(input.name, DataType::UInt32));
+        }
+        let is_bitwise_comparable = |dtype: &DataType| {
+            dtype.is_integer() || dtype.is_binary() || dtype.is_fixed_size_binary()
+        };
+        if !is_bitwise_comparable(&input.dtype) || !is_bitwise_comparable(&query.dtype) {
+            value_err!("Expected inputs to 'hamming_distance' to be integers or binary, instead got {} and {}", input.dtype, query.dtype)
+        }
+        if input.dtype.is_integer() != query.dtype.is_integer() {
+            value_err!("Expected inputs to 'hamming_distance' to both be integers or both be binary, instead got {} and {}", input.dtype, query.dtype)
+        }
+        Ok(Field::new(input.name, DataType::UInt32))
+    }
+
+    fn docstring(&self) -> &'static str {
+        "Computes the number of differing bits between two integers or two binary values of the same length, or between two integer embeddings. For float embeddings, counts the number of differing elements."
+    }
+}
+
+#[must_use]
+pub fn hamming_distance(a: ExprRef, b: ExprRef) -> ExprRef {
+    ScalarFunction::new(HammingDistanceFunction {}, vec![a, b]).into()
+}
+
+fn is_vector(dtype: &DataType) -> bool {
+    matches!(dtype, DataType::FixedSizeList(..) | DataType::Embedding(..))
+}
+
+/// Returns the bits of every value of an integer series, sign-extended to 64 bits.
+fn integer_bits(s: &Series) -> DaftResult<Vec<Option<u64>>> {
+    if matches!(
+        s.data_type(),
+        DataType::UInt8 | DataType::UInt16 | DataType::UInt32 | DataType::UInt64
+    ) {
+        let s = s.cast(&DataType::UInt64)?;
+        Ok(s.u64()?.into_iter().map(|v| v.copied()).collect())
+    } else {
+        let s = s.cast(&DataType::Int64)?;
+        Ok(s.i64()?.into_iter().map(|v| v.map(|v| *v as u64)).collect())
+    }
+}
+
+fn binary_values(s: &Series) -> DaftResult<Vec<Option<&[u8]>>> {
+    match s.data_type() {
+        DataType::Binary => Ok(s.binary()?.as_arrow().iter().collect()),
+        DataType::FixedSizeBinary(_) => Ok(s.fixed_size_binary()?.as_arrow().iter().collect()),
+        dtype => Err(DaftError::TypeError(format!(
+            "Expected inputs to 'hamming_distance' to be binary, instead got {dtype}"
+        ))),
+    }
+}
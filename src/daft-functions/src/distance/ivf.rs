@@ -0,0 +1,497 @@
+//! An inverted file (IVF-flat) index for approximate nearest neighbour search.
+//!
+//! The indexed vectors are clustered with k-means into `nlist` lists. A search only scans the
+//! lists of the `nprobe` centroids closest to the query, so a larger `nprobe` trades latency for
+//! recall. Vectors are stored uncompressed as `f32`.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, LazyLock, Mutex},
+};
+
+use common_error::{ensure, value_err, DaftError, DaftResult};
+use common_runtime::get_io_runtime;
+use daft_core::prelude::*;
+use daft_io::{get_io_client, IOConfig};
+
+use super::{
+    kernels,
+    knn::{keep_closest, Neighbour},
+    vector::{check_vector_fields, Metric, Vectors},
+};
+
+/// An index read from a path, with the size of the file it was read from.
+type CachedIndex = (usize, Arc<IvfIndex>);
+
+const MAGIC: &[u8; 8] = b"DAFTIVF1";
+
+/// Number of training vectors sampled per list, as k-means converges long before using them all.
+const TRAINING_SAMPLES_PER_LIST: usize = 256;
+
+#[derive(Debug, PartialEq)]
+pub struct IvfIndex {
+    metric: Metric,
+    dim: usize,
+    /// `nlist` centroids of `dim` elements each.
+    centroids: Vec<f32>,
+    lists: Vec<IvfList>,
+}
+
+#[derive(Debug, PartialEq)]
+struct IvfList {
+    ids: Vec<u64>,
+    /// `ids.len()` vectors of `dim` elements each.
+    vectors: Vec<f32>,
+}
+
+impl IvfIndex {
+    /// Builds an index over the non-null vectors of a fixed size list or embedding series.
+    ///
+    /// Each vector is identified by the id in the same row of `ids`, or by its row number if there
+    /// are no ids. `nlist` defaults to the square root of the number of vectors.
+    pub fn build(
+        embeddings: &Series,
+        ids: Option<&Series>,
+        nlist: Option<usize>,
+        metric: Metric,
+        max_iterations: usize,
+        seed: u64,
+    ) -> DaftResult<Self> {
+        check_vector_fields("build_ivf_index", embeddings.field(), embeddings.field())?;
+        if metric == Metric::Hamming {
+            value_err!("The hamming metric is not supported by IVF indexes");
+        }
+        let ids = match ids {
+            Some(ids) => {
+                ensure!(ids.len() == embeddings.len(), ValueError: "Expected {} ids for the embeddings, instead got {}", embeddings.len(), ids.len());
+                let ids = ids.cast(&DataType::UInt64)?;
+                ids.u64()?
+                    .into_iter()
+                    .map(|id| {
+                        id.copied().ok_or_else(|| {
+                            DaftError::ValueError("IVF index ids must not be null".to_string())
+                        })
+                    })
+                    .collect::<DaftResult<Vec<_>>>()?
+            }
+            None => (0..embeddings.len() as u64).collect(),
+        };
+
+        let dim = embeddings_dim(embeddings);
+        let embeddings = as_f32_vectors(embeddings, dim)?;
+        let embeddings = embeddings.fixed_size_list()?;
+        let embeddings = Vectors::<f32>::try_new(embeddings)?;
+
+        let mut row_ids = Vec::new();
+        let mut data = Vec::new();
+        for (i, id) in ids.into_iter().enumerate() {
+            let Some(vector) = embeddings.get(i) else {
+                continue;
+            };
+            if metric == Metric::Cosine {
+                // the cosine distance to a zero vector is undefined, so they can't be neighbours.
+                let Some(normalized) = normalize(vector) else {
+                    continue;
+                };
+                data.extend(normalized);
+            } else {
+                data.extend_from_slice(vector);
+            }
+            row_ids.push(id);
+        }
+
+        let n = row_ids.len();
+        let nlist = nlist
+            .unwrap_or_else(|| (n as f64).sqrt().round() as usize)
+            .clamp(1, n.max(1));
+        let centroids = if n == 0 {
+            vec![0.0; dim]
+        } else {
+            train_centroids(&data, dim, nlist, max_iterations, seed)
+        };
+
+        let mut lists = (0..nlist)
+            .map(|_| IvfList {
+                ids: Vec::new(),
+                vectors: Vec::new(),
+            })
+            .collect::<Vec<_>>();
+        for (id, vector) in row_ids.into_iter().zip(data.chunks_exact(dim.max(1))) {
+            let list = &mut lists[nearest_centroid(&centroids, dim, vector)];
+            list.ids.push(id);
+            list.vectors.extend_from_slice(vector);
+        }
+
+        Ok(Self {
+            metric,
+            dim,
+            centroids,
+            lists,
+        })
+    }
+
+    pub fn metric(&self) -> Metric {
+        self.metric
+    }
+
+    pub fn dim(&self) -> usize {
+        self.dim
+    }
+
+    pub fn nlist(&self) -> usize {
+        self.lists.len()
+    }
+
+    pub fn len(&self) -> usize {
+        self.lists.iter().map(|list| list.ids.len()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the `k` closest indexed vectors to `query` among the lists of the `nprobe` closest
+    /// centroids, closest first.
+    pub fn search(&self, query: &[f32], k: usize, nprobe: usize) -> Vec<Neighbour> {
+        let normalized;
+        let probe = if self.metric == Metric::Cosine {
+            let Some(query) = normalize(query) else {
+                return Vec::new();
+            };
+            normalized = query;
+            &normalized
+        } else {
+            query
+        };
+
+        let mut centroids = self
+            .centroids
+            .chunks_exact(self.dim.max(1))
+            .enumerate()
+            .map(|(i, centroid)| (i as u64, kernels::squared_euclidean(probe, centroid)))
+            .collect::<Vec<_>>();
+        keep_closest(&mut centroids, nprobe, Metric::Euclidean);
+
+        let mut neighbours = Vec::new();
+        for (list, _) in centroids {
+            let list = &self.lists[list as usize];
+            for (id, vector) in list
+                .ids
+                .iter()
+                .zip(list.vectors.chunks_exact(self.dim.max(1)))
+            {
+                let score = self.metric.compute(query, vector);
+                if !score.is_nan() {
+                    neighbours.push((*id, score));
+                }
+            }
+        }
+        keep_closest(&mut neighbours, k, self.metric);
+        neighbours
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(
+            MAGIC.len() + 9 + 4 * self.centroids.len() + self.len() * (8 + 4 * self.dim),
+        );
+        out.extend(MAGIC);
+        out.push(match self.metric {
+            Metric::Cosine => 0,
+            Metric::Dot => 1,
+            Metric::Euclidean => 2,
+            Metric::Manhattan => 3,
+            Metric::Hamming => unreachable!("IVF indexes don't support the hamming metric"),
+        });
+        out.extend((self.dim as u32).to_le_bytes());
+        out.extend((self.nlist() as u32).to_le_bytes());
+        out.extend(self.centroids.iter().flat_map(|v| v.to_le_bytes()));
+        for list in &self.lists {
+            out.extend((list.ids.len() as u64).to_le_bytes());
+            out.extend(list.ids.iter().flat_map(|id| id.to_le_bytes()));
+            out.extend(list.vectors.iter().flat_map(|v| v.to_le_bytes()));
+        }
+        out
+    }
+
+    pub fn from_bytes(mut bytes: &[u8]) -> DaftResult<Self> {
+        if take(&mut bytes, MAGIC.len())? != MAGIC {
+            value_err!("Failed to read IVF index: not an IVF index file");
+        }
+        let metric = match take(&mut bytes, 1)?[0] {
+            0 => Metric::Cosine,
+            1 => Metric::Dot,
+            2 => Metric::Euclidean,
+            3 => Metric::Manhattan,
+            metric => value_err!("Failed to read IVF index: unknown metric {metric}"),
+        };
+        let dim = u32::from_le_bytes(take_array(&mut bytes)?) as usize;
+        let nlist = u32::from_le_bytes(take_array(&mut bytes)?) as usize;
+        let centroids = take_f32s(&mut bytes, nlist * dim)?;
+        let mut lists = Vec::with_capacity(nlist);
+        for _ in 0..nlist {
+            let len = u64::from_le_bytes(take_array(&mut bytes)?) as usize;
+            let ids = (0..len)
+                .map(|_| Ok(u64::from_le_bytes(take_array(&mut bytes)?)))
+                .collect::<DaftResult<_>>()?;
+            let vectors = take_f32s(&mut bytes, len * dim)?;
+            lists.push(IvfList { ids, vectors });
+        }
+        if !bytes.is_empty() {
+            value_err!("Failed to read IVF index: trailing bytes");
+        }
+        Ok(Self {
+            metric,
+            dim,
+            centroids,
+            lists,
+        })
+    }
+
+    /// Writes the index to a local or object-store path.
+    pub fn write(&self, path: &str, io_config: Arc<IOConfig>) -> DaftResult<()> {
+        let client = get_io_client(false, io_config)?;
+        let runtime = get_io_runtime(false);
+        let path = path.to_string();
+        let data = bytes::Bytes::from(self.to_bytes());
+        Ok(runtime.block_within_async_context(async move {
+            client.single_url_put(&path, data, None).await
+        })??)
+    }
+
+    /// Reads an index from a local or object-store path.
+    ///
+    /// Indexes are cached by path and size, so an index is only read once per process unless it
+    /// is rewritten.
+    pub fn read(path: &str, io_config: Arc<IOConfig>) -> DaftResult<Arc<Self>> {
+        static CACHE: LazyLock<Mutex<HashMap<String, CachedIndex>>> =
+            LazyLock::new(Default::default);
+
+        let client = get_io_client(false, io_config)?;
+        let runtime = get_io_runtime(false);
+        let owned_path = path.to_string();
+        let owned_client = client.clone();
+        let size = runtime.block_within_async_context(async move {
+            owned_client.single_url_get_size(owned_path, None).await
+        })??;
+        if let Some((_, index)) = CACHE
+            .lock()
+            .unwrap()
+            .get(path)
+            .filter(|(cached_size, _)| *cached_size == size)
+        {
+            return Ok(index.clone());
+        }
+
+        let owned_path = path.to_string();
+        let bytes = runtime.block_within_async_context(async move {
+            client
+                .single_url_get(owned_path, None, None)
+                .await?
+                .bytes()
+                .await
+        })??;
+        let index = Arc::new(Self::from_bytes(&bytes)?);
+        CACHE
+            .lock()
+            .unwrap()
+            .insert(path.to_string(), (bytes.len(), index.clone()));
+        Ok(index)
+    }
+}
+
+fn take<'a>(bytes: &mut &'a [u8], len: usize) -> DaftResult<&'a [u8]> {
+    if bytes.len() < len {
+        value_err!("Failed to read IVF index: unexpected end of file");
+    }
+    let (head, tail) = bytes.split_at(len);
+    *bytes = tail;
+    Ok(head)
+}
+
+fn take_array<const N: usize>(bytes: &mut &[u8]) -> DaftResult<[u8; N]> {
+    Ok(take(bytes, N)?.try_into().expect("took exactly N bytes"))
+}
+
+fn take_f32s(bytes: &mut &[u8], len: usize) -> DaftResult<Vec<f32>> {
+    let bytes = take(bytes, len.checked_mul(4).unwrap_or(usize::MAX))?;
+    Ok(bytes
+        .chunks_exact(4)
+        .map(|b| f32::from_le_bytes(b.try_into().expect("chunks of 4 bytes")))
+        .collect())
+}
+
+/// Returns the size of the vectors of a fixed size list or embedding series.
+pub(crate) fn embeddings_dim(embeddings: &Series) -> usize {
+    match embeddings.data_type() {
+        DataType::FixedSizeList(_, size) | DataType::Embedding(_, size) => *size,
+        dtype => unreachable!("Expected a fixed size list or embedding, instead got {dtype}"),
+    }
+}
+
+/// Casts a fixed size list or embedding series to a fixed size list of `f32`.
+pub(crate) fn as_f32_vectors(embeddings: &Series, dim: usize) -> DaftResult<Series> {
+    embeddings
+        .as_physical()?
+        .cast(&DataType::FixedSizeList(Box::new(DataType::Float32), dim))
+}
+
+fn normalize(vector: &[f32]) -> Option<Vec<f32>> {
+    let norm = kernels::dot(vector, vector).sqrt();
+    (norm > 0.0).then(|| {
+        vector
+            .iter()
+            .map(|v| (f64::from(*v) / norm) as f32)
+            .collect()
+    })
+}
+
+fn nearest_centroid(centroids: &[f32], dim: usize, vector: &[f32]) -> usize {
+    centroids
+        .chunks_exact(dim.max(1))
+        .map(|centroid| kernels::squared_euclidean(vector, centroid))
+        .enumerate()
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map_or(0, |(i, _)| i)
+}
+
+/// Clusters the `dim`-sized vectors in `data` into `nlist` centroids with Lloyd's algorithm.
+fn train_centroids(
+    data: &[f32],
+    dim: usize,
+    nlist: usize,
+    max_iterations: usize,
+    seed: u64,
+) -> Vec<f32> {
+    let n = data.len() / dim.max(1);
+    let mut rng = SplitMix64(seed);
+
+    // a random sample of the vectors, the first `nlist` of which are the initial centroids.
+    let mut sample = (0..n).collect::<Vec<_>>();
+    let sample_len = n.min(nlist * TRAINING_SAMPLES_PER_LIST);
+    for i in 0..sample_len {
+        let j = i + (rng.next() % (n - i) as u64) as usize;
+        sample.swap(i, j);
+    }
+    sample.truncate(sample_len);
+    let vector = |i: usize| &data[i * dim..(i + 1) * dim];
+
+    let mut centroids = sample[..nlist]
+        .iter()
+        .flat_map(|i| vector(*i))
+        .copied()
+        .collect::<Vec<_>>();
+    let mut assignments = vec![usize::MAX; sample_len];
+    for _ in 0..max_iterations {
+        let mut changed = false;
+        for (assignment, i) in assignments.iter_mut().zip(&sample) {
+            let nearest = nearest_centroid(&centroids, dim, vector(*i));
+            changed |= *assignment != nearest;
+            *assignment = nearest;
+        }
+        if !changed {
+            break;
+        }
+
+        let mut sums = vec![0.0f64; nlist * dim];
+        let mut counts = vec![0usize; nlist];
+        for (assignment, i) in assignments.iter().zip(&sample) {
+            counts[*assignment] += 1;
+            let sum = &mut sums[assignment * dim..(assignment + 1) * dim];
+            for (s, v) in sum.iter_mut().zip(vector(*i)) {
+                *s += f64::from(*v);
+            }
+        }
+        for (c, count) in counts.iter().enumerate() {
+            let centroid = &mut centroids[c * dim..(c + 1) * dim];
+            if *count == 0 {
+                // reseed empty lists with a random vector rather than leaving them unused.
+                centroid.copy_from_slice(vector(sample[(rng.next() % sample_len as u64) as usize]));
+            } else {
+                for (v, s) in centroid.iter_mut().zip(&sums[c * dim..(c + 1) * dim]) {
+                    *v = (s / *count as f64) as f32;
+                }
+            }
+        }
+    }
+    centroids
+}
+
+/// A small, seedable pseudo-random generator, see <https://prng.di.unimi.it/splitmix64.c>.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn embeddings(vectors: &[[f32; 2]]) -> Series {
+        let flat = Float32Array::from((
+            "item",
+            vectors.iter().flatten().copied().collect::<Vec<_>>(),
+        ));
+        FixedSizeListArray::new(
+            Field::new(
+                "embeddings",
+                DataType::FixedSizeList(Box::new(DataType::Float32), 2),
+            ),
+            flat.into_series(),
+            None,
+        )
+        .into_series()
+    }
+
+    #[test]
+    fn test_build_and_search() -> DaftResult<()> {
+        // two well separated clusters.
+        let vectors = [
+            [0.0, 0.0],
+            [0.1, 0.0],
+            [0.0, 0.1],
+            [10.0, 10.0],
+            [10.1, 10.0],
+            [10.0, 10.1],
+        ];
+        let index = IvfIndex::build(
+            &embeddings(&vectors),
+            None,
+            Some(2),
+            Metric::Euclidean,
+            10,
+            0,
+        )?;
+        assert_eq!(index.nlist(), 2);
+        assert_eq!(index.len(), 6);
+
+        let neighbours = index.search(&[10.0, 10.0], 2, 1);
+        assert_eq!(
+            neighbours.iter().map(|(id, _)| *id).collect::<Vec<_>>(),
+            vec![3, 4]
+        );
+        assert!((neighbours[1].1 - 0.1).abs() < 1e-6);
+
+        // probing a single list only finds the vectors in the closest cluster.
+        assert_eq!(index.search(&[0.0, 0.0], 10, 1).len(), 3);
+        assert_eq!(index.search(&[0.0, 0.0], 10, 2).len(), 6);
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_roundtrip() -> DaftResult<()> {
+        let vectors = [[1.0, 0.0], [0.0, 1.0], [0.0, 0.0], [1.0, 1.0]];
+        let index = IvfIndex::build(&embeddings(&vectors), None, None, Metric::Cosine, 10, 42)?;
+        // the zero vector has no cosine distance to anything, so it isn't indexed.
+        assert_eq!(index.len(), 3);
+        assert_eq!(IvfIndex::from_bytes(&index.to_bytes())?, index);
+        assert!(IvfIndex::from_bytes(&index.to_bytes()[..20]).is_err());
+        Ok(())
+    }
+}
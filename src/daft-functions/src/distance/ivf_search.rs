@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use common_error::{ensure, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::functions::{prelude::*, ScalarFunction};
+use daft_io::IOConfig;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    ivf::{as_f32_vectors, embeddings_dim, IvfIndex},
+    knn::{neighbours_dtype, neighbours_into_series},
+    vector::{check_vector_fields, Vectors},
+};
+
+#[derive(FunctionArgs)]
+struct Args<T> {
+    input: T,
+    index: String,
+    k: usize,
+    #[arg(optional)]
+    nprobe: Option<usize>,
+    #[arg(optional)]
+    io_config: Option<IOConfig>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct IvfSearchFunction;
+
+#[typetag::serde]
+impl ScalarUDF for IvfSearchFunction {
+    fn name(&self) -> &'static str {
+        "ivf_search"
+    }
+
+    fn call(&self, inputs: daft_dsl::functions::FunctionArgs<Series>) -> DaftResult<Series> {
+        let Args {
+            input,
+            index,
+            k,
+            nprobe,
+            io_config,
+        } = inputs.try_into()?;
+        check_vector_fields(self.name(), input.field(), input.field())?;
+        let index = IvfIndex::read(&index, Arc::new(io_config.unwrap_or_default()))?;
+        let nprobe = nprobe.unwrap_or(8);
+        ensure!(nprobe > 0, ValueError: "nprobe for ivf_search must be non-zero");
+
+        let dim = embeddings_dim(&input);
+        ensure!(dim == index.dim(), ValueError: "Expected inputs to 'ivf_search' to have the same size as the index, instead got {dim} and {}", index.dim());
+        let queries = as_f32_vectors(&input, dim)?;
+        let queries = Vectors::<f32>::try_new(queries.fixed_size_list()?)?;
+        let neighbours = (0..queries.len())
+            .map(|i| Some(index.search(queries.get(i)?, k, nprobe)))
+            .collect();
+        neighbours_into_series(input.name(), neighbours, input.validity().is_some())
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        let Args { input, .. } = inputs.try_into()?;
+        let input = input.to_field(schema)?;
+        check_vector_fields(self.name(), &input, &input)?;
+        Ok(Field::new(input.name, neighbours_dtype()))
+    }
+
+    fn docstring(&self) -> &'static str {
+        "Finds the approximate `k` nearest neighbours of each input vector in an IVF index, probing the `nprobe` closest lists."
+    }
+}
+
+#[must_use]
+pub fn ivf_search(input: ExprRef, index: ExprRef, k: ExprRef) -> ExprRef {
+    ScalarFunction::new(IvfSearchFunction {}, vec![input, index, k]).into()
+}
@@ -0,0 +1,175 @@
+//! SIMD kernels for comparing two vectors of the same length.
+//!
+//! Every kernel widens its inputs to `f64` lanes before accumulating, so `i8` and `f32` vectors
+//! are compared without overflow and with the same precision as `f64` vectors.
+
+use std::simd::{
+    num::{SimdFloat, SimdInt, SimdUint},
+    Simd,
+};
+
+use daft_core::datatypes::{DaftNumericType, NumericNative};
+
+const LANES: usize = 8;
+
+type F64s = Simd<f64, LANES>;
+
+pub(crate) trait VectorElement:
+    NumericNative<DAFTTYPE: DaftNumericType<Native = Self>>
+{
+    /// Loads exactly `LANES` elements into `f64` lanes.
+    fn load(chunk: &[Self]) -> F64s;
+
+    fn widen(self) -> f64;
+
+    /// Number of differing bits for integers, or of differing elements for floats.
+    fn hamming(a: &[Self], b: &[Self]) -> u32;
+}
+
+impl VectorElement for i8 {
+    fn load(chunk: &[Self]) -> F64s {
+        Simd::<Self, LANES>::from_slice(chunk).cast()
+    }
+
+    fn widen(self) -> f64 {
+        f64::from(self)
+    }
+
+    fn hamming(a: &[Self], b: &[Self]) -> u32 {
+        a.iter().zip(b).map(|(a, b)| (a ^ b).count_ones()).sum()
+    }
+}
+
+impl VectorElement for u8 {
+    fn load(chunk: &[Self]) -> F64s {
+        Simd::<Self, LANES>::from_slice(chunk).cast()
+    }
+
+    fn widen(self) -> f64 {
+        f64::from(self)
+    }
+
+    fn hamming(a: &[Self], b: &[Self]) -> u32 {
+        a.iter().zip(b).map(|(a, b)| (a ^ b).count_ones()).sum()
+    }
+}
+
+impl VectorElement for f32 {
+    fn load(chunk: &[Self]) -> F64s {
+        Simd::<Self, LANES>::from_slice(chunk).cast()
+    }
+
+    fn widen(self) -> f64 {
+        f64::from(self)
+    }
+
+    fn hamming(a: &[Self], b: &[Self]) -> u32 {
+        a.iter().zip(b).filter(|(a, b)| a != b).count() as u32
+    }
+}
+
+impl VectorElement for f64 {
+    fn load(chunk: &[Self]) -> F64s {
+        F64s::from_slice(chunk)
+    }
+
+    fn widen(self) -> f64 {
+        self
+    }
+
+    fn hamming(a: &[Self], b: &[Self]) -> u32 {
+        a.iter().zip(b).filter(|(a, b)| a != b).count() as u32
+    }
+}
+
+/// Sums `lane(a, b)` over all lanes, handling the tail that doesn't fill a full lane with `scalar`.
+#[inline(always)]
+fn reduce<T: VectorElement>(
+    a: &[T],
+    b: &[T],
+    lane: impl Fn(F64s, F64s) -> F64s,
+    scalar: impl Fn(f64, f64) -> f64,
+) -> f64 {
+    let a_chunks = a.chunks_exact(LANES);
+    let b_chunks = b.chunks_exact(LANES);
+    let tail = a_chunks
+        .remainder()
+        .iter()
+        .zip(b_chunks.remainder())
+        .map(|(a, b)| scalar(a.widen(), b.widen()))
+        .sum::<f64>();
+    let acc = a_chunks
+        .zip(b_chunks)
+        .fold(F64s::splat(0.0), |acc, (a, b)| {
+            acc + lane(T::load(a), T::load(b))
+        });
+    acc.reduce_sum() + tail
+}
+
+pub(crate) fn dot<T: VectorElement>(a: &[T], b: &[T]) -> f64 {
+    reduce(a, b, |a, b| a * b, |a, b| a * b)
+}
+
+pub(crate) fn squared_euclidean<T: VectorElement>(a: &[T], b: &[T]) -> f64 {
+    reduce(
+        a,
+        b,
+        |a, b| {
+            let d = a - b;
+            d * d
+        },
+        |a, b| (a - b) * (a - b),
+    )
+}
+
+pub(crate) fn euclidean<T: VectorElement>(a: &[T], b: &[T]) -> f64 {
+    squared_euclidean(a, b).sqrt()
+}
+
+pub(crate) fn manhattan<T: VectorElement>(a: &[T], b: &[T]) -> f64 {
+    reduce(a, b, |a, b| (a - b).abs(), |a, b| (a - b).abs())
+}
+
+pub(crate) fn cosine<T: VectorElement>(a: &[T], b: &[T]) -> f64 {
+    let xy = dot(a, b);
+    let x_sq = dot(a, a).sqrt();
+    let y_sq = dot(b, b).sqrt();
+    1.0 - xy / (x_sq * y_sq)
+}
+
+pub(crate) fn hamming<T: VectorElement>(a: &[T], b: &[T]) -> u32 {
+    T::hamming(a, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kernels_match_scalar() {
+        // 19 elements so that both the lanes and the tail are exercised.
+        let a = (0..19).map(|i| (i as f32 - 6.0) / 2.0).collect::<Vec<_>>();
+        let b = (0..19).map(|i| (i % 5) as f32 - 1.0).collect::<Vec<_>>();
+        let pairs = || {
+            a.iter()
+                .zip(&b)
+                .map(|(a, b)| (f64::from(*a), f64::from(*b)))
+        };
+
+        let expected_dot = pairs().map(|(a, b)| a * b).sum::<f64>();
+        let expected_l2 = pairs().map(|(a, b)| (a - b).powi(2)).sum::<f64>().sqrt();
+        let expected_l1 = pairs().map(|(a, b)| (a - b).abs()).sum::<f64>();
+
+        assert!((dot(&a, &b) - expected_dot).abs() < 1e-9);
+        assert!((euclidean(&a, &b) - expected_l2).abs() < 1e-9);
+        assert!((manhattan(&a, &b) - expected_l1).abs() < 1e-9);
+        assert!(cosine(&a, &a).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_hamming() {
+        assert_eq!(hamming::<u8>(&[0b1011, 0xff], &[0b0001, 0xff]), 2);
+        assert_eq!(hamming::<i8>(&[-1], &[0]), 8);
+        assert_eq!(hamming::<f32>(&[1.0, 2.0, 3.0], &[1.0, 2.5, 3.5]), 2);
+    }
+}
@@ -0,0 +1,177 @@
+use std::cmp::Ordering;
+
+use arrow2::offset::OffsetsBuffer;
+use common_error::DaftResult;
+use daft_core::prelude::*;
+use daft_dsl::functions::{prelude::*, ScalarFunction};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    kernels::VectorElement,
+    vector::{check_vector_fields, with_vector_type, Metric, Vectors},
+};
+
+#[derive(FunctionArgs)]
+struct Args<T> {
+    input: T,
+    reference: T,
+    k: usize,
+    #[arg(optional)]
+    metric: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct KnnFunction;
+
+#[typetag::serde]
+impl ScalarUDF for KnnFunction {
+    fn name(&self) -> &'static str {
+        "knn"
+    }
+
+    fn call(&self, inputs: daft_dsl::functions::FunctionArgs<Series>) -> DaftResult<Series> {
+        let Args {
+            input,
+            reference,
+            k,
+            metric,
+        } = inputs.try_into()?;
+        let metric = parse_metric(metric)?;
+        check_vector_fields(self.name(), input.field(), reference.field())?;
+
+        let queries = input.as_physical()?;
+        let references = reference.as_physical()?;
+        let queries = queries.fixed_size_list()?;
+        let references = references.fixed_size_list()?;
+        let neighbours = with_vector_type!(queries.child_data_type(), T, {
+            nearest::<T>(metric, k, queries, references)
+        })?;
+
+        neighbours_into_series(input.name(), neighbours, input.validity().is_some())
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        let Args {
+            input,
+            reference,
+            metric,
+            ..
+        } = inputs.try_into()?;
+        let input = input.to_field(schema)?;
+        let reference = reference.to_field(schema)?;
+        check_vector_fields(self.name(), &input, &reference)?;
+        parse_metric(metric)?;
+        Ok(Field::new(input.name, neighbours_dtype()))
+    }
+
+    fn docstring(&self) -> &'static str {
+        "Finds the `k` nearest reference vectors of each input vector by brute force, returning their row ids in the reference and their scores."
+    }
+}
+
+#[must_use]
+pub fn knn(input: ExprRef, reference: ExprRef, k: ExprRef) -> ExprRef {
+    ScalarFunction::new(KnnFunction {}, vec![input, reference, k]).into()
+}
+
+pub(crate) fn parse_metric(metric: Option<String>) -> DaftResult<Metric> {
+    metric.map_or(Ok(Metric::Cosine), |m| m.parse())
+}
+
+/// The row id of a reference vector and its score.
+pub(crate) type Neighbour = (u64, f64);
+
+/// The dtype of the neighbours of each query, a list of `{id, score}` structs.
+pub(crate) fn neighbours_dtype() -> DataType {
+    DataType::List(Box::new(DataType::Struct(vec![
+        Field::new("id", DataType::UInt64),
+        Field::new("score", DataType::Float64),
+    ])))
+}
+
+pub(crate) fn neighbours_into_series(
+    name: &str,
+    neighbours: Vec<Option<Vec<Neighbour>>>,
+    nullable: bool,
+) -> DaftResult<Series> {
+    let mut ids = Vec::new();
+    let mut scores = Vec::new();
+    let mut offsets = Vec::with_capacity(neighbours.len() + 1);
+    offsets.push(0i64);
+    for row in &neighbours {
+        for (id, score) in row.iter().flatten() {
+            ids.push(*id);
+            scores.push(*score);
+        }
+        offsets.push(ids.len() as i64);
+    }
+    let validity =
+        nullable.then(|| bitmap::Bitmap::from_iter(neighbours.iter().map(Option::is_some)));
+
+    let DataType::List(neighbour_dtype) = neighbours_dtype() else {
+        unreachable!("neighbours are a list")
+    };
+    let neighbour = StructArray::new(
+        Field::new(name, *neighbour_dtype),
+        vec![
+            UInt64Array::from(("id", ids)).into_series(),
+            Float64Array::from(("score", scores)).into_series(),
+        ],
+        None,
+    );
+    Ok(ListArray::new(
+        Field::new(name, neighbours_dtype()),
+        neighbour.into_series(),
+        OffsetsBuffer::try_from(offsets)?,
+        validity,
+    )
+    .into_series())
+}
+
+/// Keeps the `k` closest neighbours by `metric`, closest first.
+pub(crate) fn keep_closest(neighbours: &mut Vec<Neighbour>, k: usize, metric: Metric) {
+    let closest_first = |a: &Neighbour, b: &Neighbour| -> Ordering {
+        let ordering = if metric.is_similarity() {
+            b.1.total_cmp(&a.1)
+        } else {
+            a.1.total_cmp(&b.1)
+        };
+        ordering.then(a.0.cmp(&b.0))
+    };
+    if neighbours.len() > k {
+        neighbours.select_nth_unstable_by(k, closest_first);
+        neighbours.truncate(k);
+    }
+    neighbours.sort_unstable_by(closest_first);
+}
+
+/// Returns the ids and scores of the `k` closest references of each query, closest first.
+///
+/// Null references and NaN scores, e.g. the cosine distance to a zero vector, are skipped.
+fn nearest<T: VectorElement>(
+    metric: Metric,
+    k: usize,
+    queries: &FixedSizeListArray,
+    references: &FixedSizeListArray,
+) -> DaftResult<Vec<Option<Vec<Neighbour>>>> {
+    let queries = Vectors::<T>::try_new(queries)?;
+    let references = Vectors::<T>::try_new(references)?;
+
+    Ok((0..queries.len())
+        .map(|i| {
+            let query = queries.get(i)?;
+            let mut scores = (0..references.len())
+                .filter_map(|j| {
+                    let score = metric.compute(query, references.get(j)?);
+                    (!score.is_nan()).then_some((j as u64, score))
+                })
+                .collect::<Vec<_>>();
+            keep_closest(&mut scores, k, metric);
+            Some(scores)
+        })
+        .collect())
+}
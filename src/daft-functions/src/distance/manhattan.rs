@@ -0,0 +1,36 @@
+use common_error::DaftResult;
+use daft_dsl::functions::{prelude::*, ScalarFunction};
+use serde::{Deserialize, Serialize};
+
+use super::vector::{call_pairwise, pairwise_return_field, Metric};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct ManhattanDistanceFunction;
+
+#[typetag::serde]
+impl ScalarUDF for ManhattanDistanceFunction {
+    fn name(&self) -> &'static str {
+        "manhattan_distance"
+    }
+
+    fn call(&self, inputs: daft_dsl::functions::FunctionArgs<Series>) -> DaftResult<Series> {
+        call_pairwise(self.name(), Metric::Manhattan, inputs)
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        pairwise_return_field(self.name(), inputs, schema)
+    }
+
+    fn docstring(&self) -> &'static str {
+        "Computes the Manhattan (L1) distance between two embeddings."
+    }
+}
+
+#[must_use]
+pub fn manhattan_distance(a: ExprRef, b: ExprRef) -> ExprRef {
+    ScalarFunction::new(ManhattanDistanceFunction {}, vec![a, b]).into()
+}
@@ -1,12 +1,33 @@
 use cosine::CosineDistanceFunction;
 use daft_dsl::functions::FunctionModule;
+use dot::DotProductFunction;
+use euclidean::EuclideanDistanceFunction;
+use hamming::HammingDistanceFunction;
+use ivf_search::IvfSearchFunction;
+use knn::KnnFunction;
+use manhattan::ManhattanDistanceFunction;
 
 pub mod cosine;
+pub mod dot;
+pub mod euclidean;
+pub mod hamming;
+pub mod ivf;
+pub mod ivf_search;
+mod kernels;
+pub mod knn;
+pub mod manhattan;
+pub mod vector;
 
 pub struct DistanceFunctions;
 
 impl FunctionModule for DistanceFunctions {
     fn register(parent: &mut daft_dsl::functions::FunctionRegistry) {
         parent.add_fn(CosineDistanceFunction);
+        parent.add_fn(DotProductFunction);
+        parent.add_fn(EuclideanDistanceFunction);
+        parent.add_fn(HammingDistanceFunction);
+        parent.add_fn(IvfSearchFunction);
+        parent.add_fn(KnnFunction);
+        parent.add_fn(ManhattanDistanceFunction);
     }
 }
@@ -0,0 +1,215 @@
+use std::str::FromStr;
+
+use common_error::{value_err, DaftError, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::functions::prelude::*;
+
+use super::kernels::{self, VectorElement};
+
+/// Dispatches on the inner dtype of a vector, which must already be checked with [`check_vector_fields`].
+macro_rules! with_vector_type {
+    ($dtype:expr, $t:ident, $body:block) => {
+        match $dtype {
+            DataType::Int8 => {
+                type $t = i8;
+                $body
+            }
+            DataType::UInt8 => {
+                type $t = u8;
+                $body
+            }
+            DataType::Float32 => {
+                type $t = f32;
+                $body
+            }
+            DataType::Float64 => {
+                type $t = f64;
+                $body
+            }
+            dtype => unreachable!("Unsupported vector inner dtype: {dtype}"),
+        }
+    };
+}
+pub(crate) use with_vector_type;
+
+/// A way of comparing two vectors of the same length.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metric {
+    Cosine,
+    Dot,
+    Euclidean,
+    Manhattan,
+    Hamming,
+}
+
+impl Metric {
+    /// Whether a larger score means the vectors are closer, which is only the case for similarities.
+    pub fn is_similarity(self) -> bool {
+        matches!(self, Self::Dot)
+    }
+
+    pub(crate) fn compute<T: VectorElement>(self, a: &[T], b: &[T]) -> f64 {
+        match self {
+            Self::Cosine => kernels::cosine(a, b),
+            Self::Dot => kernels::dot(a, b),
+            Self::Euclidean => kernels::euclidean(a, b),
+            Self::Manhattan => kernels::manhattan(a, b),
+            Self::Hamming => f64::from(kernels::hamming(a, b)),
+        }
+    }
+}
+
+impl FromStr for Metric {
+    type Err = DaftError;
+
+    fn from_str(s: &str) -> DaftResult<Self> {
+        match s.to_lowercase().as_str() {
+            "cosine" => Ok(Self::Cosine),
+            "dot" | "dot_product" => Ok(Self::Dot),
+            "euclidean" | "l2" => Ok(Self::Euclidean),
+            "manhattan" | "l1" => Ok(Self::Manhattan),
+            "hamming" => Ok(Self::Hamming),
+            _ => value_err!(
+                "Invalid metric: {s}, expected one of cosine, dot, euclidean, manhattan or hamming"
+            ),
+        }
+    }
+}
+
+/// Checks that two fields are fixed size lists or embeddings of the same supported inner dtype and size.
+pub(crate) fn check_vector_fields(name: &str, source: &Field, query: &Field) -> DaftResult<()> {
+    match (&source.dtype, &query.dtype) {
+        (
+            DataType::FixedSizeList(source_inner_dtype, source_size)
+            | DataType::Embedding(source_inner_dtype, source_size),
+            DataType::FixedSizeList(query_inner_dtype, query_size)
+            | DataType::Embedding(query_inner_dtype, query_size),
+        ) => {
+            if source_inner_dtype != query_inner_dtype {
+                value_err!("Expected inputs to '{name}' to have the same inner dtype, instead got {source_inner_dtype} and {query_inner_dtype}")
+            }
+            if !matches!(
+                source_inner_dtype.as_ref(),
+                DataType::Int8 | DataType::UInt8 | DataType::Float32 | DataType::Float64
+            ) {
+                value_err!("Expected inputs to '{name}' to have Int8|UInt8|Float32|Float64 inner dtype, instead got {source_inner_dtype}")
+            }
+            if source_size != query_size {
+                value_err!("Expected inputs to '{name}' to have the same size, instead got {source_size} and {query_size}")
+            }
+            Ok(())
+        }
+        _ => {
+            value_err!(
+                "Expected inputs to '{name}' to be fixed size list or embedding, instead got {} and {}",
+                source.dtype,
+                query.dtype
+            )
+        }
+    }
+}
+
+/// Compares each source vector with the query vector of the same row, or with the only query
+/// vector if `query` has length 1.
+pub(crate) fn pairwise(
+    name: &str,
+    metric: Metric,
+    source: &Series,
+    query: &Series,
+) -> DaftResult<Vec<Option<f64>>> {
+    check_vector_fields(name, source.field(), query.field())?;
+    if query.len() != 1 && query.len() != source.len() {
+        return Err(DaftError::ValueError(format!(
+            "Query length ({}) must match source length ({})",
+            query.len(),
+            source.len()
+        )));
+    }
+    let source = source.as_physical()?;
+    let query = query.as_physical()?;
+    let source = source.fixed_size_list()?;
+    let query = query.fixed_size_list()?;
+
+    fn compute<T: VectorElement>(
+        metric: Metric,
+        source: &FixedSizeListArray,
+        query: &FixedSizeListArray,
+    ) -> DaftResult<Vec<Option<f64>>> {
+        let source = Vectors::<T>::try_new(source)?;
+        let query = Vectors::<T>::try_new(query)?;
+        Ok((0..source.len())
+            .map(|i| {
+                let a = source.get(i)?;
+                let b = query.get(if query.len() == 1 { 0 } else { i })?;
+                Some(metric.compute(a, b))
+            })
+            .collect())
+    }
+
+    with_vector_type!(source.child_data_type(), T, {
+        compute::<T>(metric, source, query)
+    })
+}
+
+/// A zero-copy view over the rows of a [`FixedSizeListArray`] of numbers.
+pub(crate) struct Vectors<'a, T> {
+    values: &'a [T],
+    size: usize,
+    len: usize,
+    validity: Option<&'a bitmap::Bitmap>,
+}
+
+impl<'a, T: VectorElement> Vectors<'a, T> {
+    pub fn try_new(array: &'a FixedSizeListArray) -> DaftResult<Self> {
+        Ok(Self {
+            values: array.flat_child.try_as_slice::<T>()?,
+            size: array.fixed_element_len(),
+            len: array.len(),
+            validity: array.validity(),
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn get(&self, i: usize) -> Option<&'a [T]> {
+        if self.validity.is_some_and(|v| !v.get_bit(i)) {
+            return None;
+        }
+        Some(&self.values[i * self.size..(i + 1) * self.size])
+    }
+}
+
+#[derive(FunctionArgs)]
+pub(crate) struct PairwiseArgs<T> {
+    pub input: T,
+    pub query: T,
+}
+
+/// Evaluates a function comparing the `input` and `query` vectors with `metric`.
+pub(crate) fn call_pairwise(
+    name: &str,
+    metric: Metric,
+    inputs: FunctionArgs<Series>,
+) -> DaftResult<Series> {
+    let PairwiseArgs { input, query } = inputs.try_into()?;
+    let res = pairwise(name, metric, &input, &query)?;
+    Ok(
+        Float64Array::from_iter(Field::new(input.name(), DataType::Float64), res.into_iter())
+            .into_series(),
+    )
+}
+
+/// Returns the field of a function comparing the `input` and `query` vectors.
+pub(crate) fn pairwise_return_field(
+    name: &str,
+    inputs: FunctionArgs<ExprRef>,
+    schema: &Schema,
+) -> DaftResult<Field> {
+    let PairwiseArgs { input, query } = inputs.try_into()?;
+    let input = input.to_field(schema)?;
+    let query = query.to_field(schema)?;
+    check_vector_fields(name, &input, &query)?;
+    Ok(Field::new(input.name, DataType::Float64))
+}
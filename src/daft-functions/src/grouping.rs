@@ -0,0 +1,85 @@
+use common_error::DaftError;
+use daft_dsl::{
+    functions::{prelude::*, ScalarFunction},
+    Expr,
+};
+
+/// `GROUPING(expr)` is 1 when `expr` is aggregated over (not part of the grouping set of the row)
+/// and 0 otherwise.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct Grouping;
+
+/// `GROUPING_ID(expr, ...)` is the bit vector of `GROUPING(expr)` over its arguments, with the
+/// first argument as the most significant bit. Without arguments, all grouping expressions are used.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct GroupingId;
+
+fn grouping_sets_only_err(name: &str) -> DaftError {
+    DaftError::ValueError(format!(
+        "{name}() can only be used in an aggregation with grouping sets, ROLLUP or CUBE"
+    ))
+}
+
+#[typetag::serde]
+impl ScalarUDF for Grouping {
+    fn name(&self) -> &'static str {
+        "grouping"
+    }
+
+    fn call(&self, _: FunctionArgs<Series>) -> DaftResult<Series> {
+        Err(grouping_sets_only_err(self.name()))
+    }
+
+    fn get_return_field(&self, inputs: FunctionArgs<ExprRef>, _: &Schema) -> DaftResult<Field> {
+        if inputs.len() != 1 {
+            return Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            )));
+        }
+        Ok(Field::new(self.name(), DataType::Int64))
+    }
+}
+
+#[typetag::serde]
+impl ScalarUDF for GroupingId {
+    fn name(&self) -> &'static str {
+        "grouping_id"
+    }
+
+    fn call(&self, _: FunctionArgs<Series>) -> DaftResult<Series> {
+        Err(grouping_sets_only_err(self.name()))
+    }
+
+    fn get_return_field(&self, inputs: FunctionArgs<ExprRef>, _: &Schema) -> DaftResult<Field> {
+        if inputs.len() > 63 {
+            return Err(DaftError::ValueError(format!(
+                "Expected at most 63 input args, got {}",
+                inputs.len()
+            )));
+        }
+        Ok(Field::new(self.name(), DataType::Int64))
+    }
+}
+
+#[must_use]
+pub fn grouping(input: ExprRef) -> ExprRef {
+    ScalarFunction::new(Grouping, vec![input]).into()
+}
+
+#[must_use]
+pub fn grouping_id(inputs: Vec<ExprRef>) -> ExprRef {
+    ScalarFunction::new(GroupingId, inputs).into()
+}
+
+/// Returns true if the expression contains a `grouping()` or `grouping_id()` call.
+pub fn contains_grouping(expr: &ExprRef) -> bool {
+    match expr.as_ref() {
+        Expr::ScalarFunction(sf)
+            if sf.is_function_type::<Grouping>() || sf.is_function_type::<GroupingId>() =>
+        {
+            true
+        }
+        _ => expr.children().iter().any(contains_grouping),
+    }
+}
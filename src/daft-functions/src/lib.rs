@@ -1,3 +1,4 @@
+#![feature(portable_simd)]
 #![allow(
     deprecated,
     reason = "moving over all scalarUDFs to new pattern. Remove once completed!"
@@ -5,6 +6,7 @@
 pub mod coalesce;
 pub mod distance;
 pub mod float;
+pub mod grouping;
 pub mod hash;
 pub mod minhash;
 pub mod monotonically_increasing_id;
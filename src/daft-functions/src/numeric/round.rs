@@ -1,5 +1,6 @@
 use common_error::{DaftError, DaftResult};
 use daft_core::{
+    array::ops::decimal,
     prelude::{DataType, Field, Float32Array, Float64Array, Schema},
     series::{IntoSeries, Series},
 };
@@ -39,11 +40,17 @@ impl ScalarUDF for Round {
         inputs: FunctionArgs<ExprRef>,
         schema: &Schema,
     ) -> DaftResult<Field> {
-        let RoundArgs { input, .. } = inputs.try_into()?;
+        let RoundArgs { input, decimals } = inputs.try_into()?;
 
         let field = input.to_field(schema)?;
 
-        let dtype = field.dtype.to_floating_representation()?;
+        let dtype = match &field.dtype {
+            // Decimals are rounded exactly instead of going through floating point.
+            dtype @ DataType::Decimal128(..) => {
+                decimal::rounded_type(dtype, decimals.unwrap_or(0) as i32)
+            }
+            dtype => dtype.to_floating_representation()?,
+        };
         Ok(Field::new(field.name, dtype))
     }
 
@@ -73,6 +80,7 @@ pub fn series_round(s: &Series, decimal: i32) -> DaftResult<Series> {
         | DataType::UInt64 => s.clone().cast(&s.to_floating_data_type()?),
         DataType::Float32 => Ok(f32_round(s.f32().unwrap(), decimal)?.into_series()),
         DataType::Float64 => Ok(f64_round(s.f64().unwrap(), decimal)?.into_series()),
+        DataType::Decimal128(..) => Ok(s.decimal128()?.round(decimal)?.into_series()),
         dt => Err(DaftError::TypeError(format!(
             "round not implemented for {}",
             dt
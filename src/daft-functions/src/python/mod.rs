@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use daft_core::prelude::Schema;
+use daft_core::{prelude::Schema, python::PySeries};
 use daft_dsl::{
     functions::{
         FunctionArg, FunctionArgs, ScalarFunction, ScalarFunctionFactory, FUNCTION_REGISTRY,
@@ -13,6 +13,8 @@ use pyo3::{
     wrap_pyfunction, Bound, PyResult,
 };
 
+use crate::distance::{ivf::IvfIndex, knn::parse_metric};
+
 #[pyo3::pyclass]
 pub struct PyScalarFunction {
     pub inner: Arc<dyn ScalarFunctionFactory>,
@@ -70,8 +72,46 @@ pub fn get_function_from_registry(name: &str) -> PyResult<PyScalarFunction> {
         .expect("Function was missing an implementation"))
 }
 
+/// Builds an IVF index over an embeddings series and writes it to `path`.
+#[pyo3::pyfunction(signature = (
+    embeddings,
+    path,
+    ids=None,
+    nlist=None,
+    metric=None,
+    max_iterations=10,
+    seed=0,
+    io_config=None
+))]
+#[allow(clippy::too_many_arguments)]
+pub fn build_ivf_index(
+    py: Python,
+    embeddings: PySeries,
+    path: &str,
+    ids: Option<PySeries>,
+    nlist: Option<usize>,
+    metric: Option<String>,
+    max_iterations: usize,
+    seed: u64,
+    io_config: Option<daft_io::python::IOConfig>,
+) -> PyResult<()> {
+    py.allow_threads(|| {
+        let index = IvfIndex::build(
+            &embeddings.series,
+            ids.as_ref().map(|ids| &ids.series),
+            nlist,
+            parse_metric(metric)?,
+            max_iterations,
+            seed,
+        )?;
+        index.write(path, Arc::new(io_config.unwrap_or_default().config))
+    })?;
+    Ok(())
+}
+
 pub fn register(parent: &Bound<PyModule>) -> PyResult<()> {
     parent.add_function(wrap_pyfunction!(get_function_from_registry, parent)?)?;
+    parent.add_function(wrap_pyfunction!(build_ivf_index, parent)?)?;
 
     Ok(())
 }
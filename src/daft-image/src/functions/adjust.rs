@@ -0,0 +1,105 @@
+use common_error::{ensure, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::{
+    functions::{FunctionArgs, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+use super::transform_return_field;
+use crate::ops::ImageTransform;
+
+/// ```text
+/// image_adjust_brightness(input, factor)
+/// image_adjust_contrast(input, factor)
+/// ```
+#[derive(FunctionArgs)]
+struct ImageAdjustArgs<T> {
+    input: T,
+    factor: f32,
+}
+
+impl<T> ImageAdjustArgs<T> {
+    fn factor(&self) -> DaftResult<f32> {
+        ensure!(
+            self.factor.is_finite() && self.factor >= 0.0,
+            ValueError: "Adjustment factor must be a non-negative number, got {}",
+            self.factor
+        );
+        Ok(self.factor)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct ImageAdjustBrightness;
+
+#[typetag::serde]
+impl ScalarUDF for ImageAdjustBrightness {
+    fn call(&self, inputs: daft_dsl::functions::FunctionArgs<Series>) -> DaftResult<Series> {
+        let args: ImageAdjustArgs<Series> = inputs.try_into()?;
+        let transform = ImageTransform::AdjustBrightness {
+            factor: args.factor()?,
+        };
+
+        crate::series::transform(&args.input, &transform)
+    }
+
+    fn name(&self) -> &'static str {
+        "image_adjust_brightness"
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        let args: ImageAdjustArgs<ExprRef> = inputs.try_into()?;
+        let transform = ImageTransform::AdjustBrightness {
+            factor: args.factor()?,
+        };
+
+        let field = args.input.to_field(schema)?;
+        transform_return_field("ImageAdjustBrightness", field, &transform)
+    }
+
+    fn docstring(&self) -> &'static str {
+        "Adjusts the brightness of an image by multiplying every color channel by `factor`. A factor of 0 gives a black image and 1 gives the original image."
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct ImageAdjustContrast;
+
+#[typetag::serde]
+impl ScalarUDF for ImageAdjustContrast {
+    fn call(&self, inputs: daft_dsl::functions::FunctionArgs<Series>) -> DaftResult<Series> {
+        let args: ImageAdjustArgs<Series> = inputs.try_into()?;
+        let transform = ImageTransform::AdjustContrast {
+            factor: args.factor()?,
+        };
+
+        crate::series::transform(&args.input, &transform)
+    }
+
+    fn name(&self) -> &'static str {
+        "image_adjust_contrast"
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        let args: ImageAdjustArgs<ExprRef> = inputs.try_into()?;
+        let transform = ImageTransform::AdjustContrast {
+            factor: args.factor()?,
+        };
+
+        let field = args.input.to_field(schema)?;
+        transform_return_field("ImageAdjustContrast", field, &transform)
+    }
+
+    fn docstring(&self) -> &'static str {
+        "Adjusts the contrast of an image by blending it with its mean gray level. A factor of 0 gives a solid gray image and 1 gives the original image."
+    }
+}
@@ -0,0 +1,63 @@
+use common_error::{ensure, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::{
+    functions::{FunctionArgs, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+use super::transform_return_field;
+use crate::ops::ImageTransform;
+
+/// ```text
+/// image_gaussian_blur(input, sigma)
+/// ```
+#[derive(FunctionArgs)]
+struct ImageGaussianBlurArgs<T> {
+    input: T,
+    sigma: f32,
+}
+
+impl<T> ImageGaussianBlurArgs<T> {
+    fn transform(&self) -> DaftResult<ImageTransform> {
+        ensure!(
+            self.sigma.is_finite() && self.sigma > 0.0,
+            ValueError: "Gaussian blur sigma must be a positive number, got {}",
+            self.sigma
+        );
+        Ok(ImageTransform::GaussianBlur { sigma: self.sigma })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct ImageGaussianBlur;
+
+#[typetag::serde]
+impl ScalarUDF for ImageGaussianBlur {
+    fn call(&self, inputs: daft_dsl::functions::FunctionArgs<Series>) -> DaftResult<Series> {
+        let args: ImageGaussianBlurArgs<Series> = inputs.try_into()?;
+        let transform = args.transform()?;
+
+        crate::series::transform(&args.input, &transform)
+    }
+
+    fn name(&self) -> &'static str {
+        "image_gaussian_blur"
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        let args: ImageGaussianBlurArgs<ExprRef> = inputs.try_into()?;
+        let transform = args.transform()?;
+
+        let field = args.input.to_field(schema)?;
+        transform_return_field("ImageGaussianBlur", field, &transform)
+    }
+
+    fn docstring(&self) -> &'static str {
+        "Blurs an image with a Gaussian kernel of the given standard deviation."
+    }
+}
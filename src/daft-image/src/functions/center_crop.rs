@@ -0,0 +1,63 @@
+use common_error::{ensure, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::{
+    functions::{FunctionArgs, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+use super::transform_return_field;
+use crate::ops::ImageTransform;
+
+/// ```text
+/// image_center_crop(input, w, h)
+/// ```
+#[derive(FunctionArgs)]
+struct ImageCenterCropArgs<T> {
+    input: T,
+    w: u32,
+    h: u32,
+}
+
+impl<T> ImageCenterCropArgs<T> {
+    fn transform(&self) -> DaftResult<ImageTransform> {
+        ensure!(self.w > 0 && self.h > 0, ValueError: "Center crop width and height must be positive");
+        Ok(ImageTransform::CenterCrop {
+            width: self.w,
+            height: self.h,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct ImageCenterCrop;
+
+#[typetag::serde]
+impl ScalarUDF for ImageCenterCrop {
+    fn call(&self, inputs: daft_dsl::functions::FunctionArgs<Series>) -> DaftResult<Series> {
+        let args: ImageCenterCropArgs<Series> = inputs.try_into()?;
+        let transform = args.transform()?;
+
+        crate::series::transform(&args.input, &transform)
+    }
+
+    fn name(&self) -> &'static str {
+        "image_center_crop"
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        let args: ImageCenterCropArgs<ExprRef> = inputs.try_into()?;
+        let transform = args.transform()?;
+
+        let field = args.input.to_field(schema)?;
+        transform_return_field("ImageCenterCrop", field, &transform)
+    }
+
+    fn docstring(&self) -> &'static str {
+        "Crops the center of an image to the specified width and height. Images smaller than the crop are left uncropped along that dimension."
+    }
+}
@@ -0,0 +1,85 @@
+use common_error::DaftResult;
+use daft_core::prelude::*;
+use daft_dsl::{
+    functions::{FunctionArgs, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+use super::transform_return_field;
+use crate::ops::ImageTransform;
+
+/// ```text
+/// image_flip_horizontal(input)
+/// image_flip_vertical(input)
+/// ```
+#[derive(FunctionArgs)]
+struct ImageFlipArgs<T> {
+    input: T,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct ImageFlipHorizontal;
+
+#[typetag::serde]
+impl ScalarUDF for ImageFlipHorizontal {
+    fn call(&self, inputs: daft_dsl::functions::FunctionArgs<Series>) -> DaftResult<Series> {
+        let args: ImageFlipArgs<Series> = inputs.try_into()?;
+        let transform = ImageTransform::FlipHorizontal;
+
+        crate::series::transform(&args.input, &transform)
+    }
+
+    fn name(&self) -> &'static str {
+        "image_flip_horizontal"
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        let args: ImageFlipArgs<ExprRef> = inputs.try_into()?;
+        let transform = ImageTransform::FlipHorizontal;
+
+        let field = args.input.to_field(schema)?;
+        transform_return_field("ImageFlipHorizontal", field, &transform)
+    }
+
+    fn docstring(&self) -> &'static str {
+        "Flips an image horizontally, mirroring it from left to right."
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct ImageFlipVertical;
+
+#[typetag::serde]
+impl ScalarUDF for ImageFlipVertical {
+    fn call(&self, inputs: daft_dsl::functions::FunctionArgs<Series>) -> DaftResult<Series> {
+        let args: ImageFlipArgs<Series> = inputs.try_into()?;
+        let transform = ImageTransform::FlipVertical;
+
+        crate::series::transform(&args.input, &transform)
+    }
+
+    fn name(&self) -> &'static str {
+        "image_flip_vertical"
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        let args: ImageFlipArgs<ExprRef> = inputs.try_into()?;
+        let transform = ImageTransform::FlipVertical;
+
+        let field = args.input.to_field(schema)?;
+        transform_return_field("ImageFlipVertical", field, &transform)
+    }
+
+    fn docstring(&self) -> &'static str {
+        "Flips an image vertically, mirroring it from top to bottom."
+    }
+}
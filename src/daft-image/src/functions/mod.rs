@@ -1,19 +1,68 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
 use daft_dsl::functions::FunctionModule;
 
+use crate::ops::ImageTransform;
+
+pub mod adjust;
+pub mod blur;
+pub mod center_crop;
 pub mod crop;
 pub mod decode;
 pub mod encode;
+pub mod flip;
+pub mod normalize;
+pub mod pad;
+pub mod perceptual_hash;
 pub mod resize;
+pub mod rotate;
+pub mod thumbnail;
 pub mod to_mode;
 
 pub struct ImageFunctions;
 
 impl FunctionModule for ImageFunctions {
     fn register(parent: &mut daft_dsl::functions::FunctionRegistry) {
+        parent.add_fn(adjust::ImageAdjustBrightness);
+        parent.add_fn(adjust::ImageAdjustContrast);
+        parent.add_fn(blur::ImageGaussianBlur);
+        parent.add_fn(center_crop::ImageCenterCrop);
         parent.add_fn(crop::ImageCrop);
         parent.add_fn(decode::ImageDecode);
         parent.add_fn(encode::ImageEncode);
+        parent.add_fn(flip::ImageFlipHorizontal);
+        parent.add_fn(flip::ImageFlipVertical);
+        parent.add_fn(normalize::ImageNormalize);
+        parent.add_fn(pad::ImagePadToAspect);
+        parent.add_fn(perceptual_hash::ImageAverageHash);
+        parent.add_fn(perceptual_hash::ImageDifferenceHash);
+        parent.add_fn(perceptual_hash::ImagePerceptualHash);
         parent.add_fn(resize::ImageResize);
+        parent.add_fn(rotate::ImageRotate);
+        parent.add_fn(thumbnail::ImageThumbnail);
         parent.add_fn(to_mode::ImageToMode);
     }
 }
+
+/// Returns the field produced by applying `transform` to every image of `field`.
+///
+/// Fixed shape images stay fixed shape, since every image is transformed into the same shape.
+fn transform_return_field(
+    function: &str,
+    field: Field,
+    transform: &ImageTransform,
+) -> DaftResult<Field> {
+    match field.dtype {
+        DataType::Image(_) => Ok(field),
+        DataType::FixedShapeImage(mode, height, width) => {
+            let (width, height) = transform.output_shape(width, height);
+            Ok(Field::new(
+                field.name,
+                DataType::FixedShapeImage(mode, height, width),
+            ))
+        }
+        _ => Err(DaftError::TypeError(format!(
+            "{function} can only operate on ImageArrays and FixedShapeImageArrays, got {field}"
+        ))),
+    }
+}
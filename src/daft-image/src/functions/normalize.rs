@@ -0,0 +1,104 @@
+use common_error::{ensure, DaftError, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::{
+    functions::{FunctionArgs, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+/// ```text
+/// image_normalize(input, mean, std)
+/// image_normalize(input, [0.485, 0.456, 0.406], [0.229, 0.224, 0.225])
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct ImageNormalize;
+
+#[derive(FunctionArgs)]
+struct ImageNormalizeArgs<T> {
+    input: T,
+    mean: T,
+    std: T,
+}
+
+/// Extracts per-channel parameters from either a scalar or a single list value.
+fn channel_params(s: &Series, name: &str) -> DaftResult<Vec<f32>> {
+    ensure!(s.len() == 1, ValueError: "{name} must be a scalar or a list literal");
+    let values = match s.data_type() {
+        DataType::List(_) => s.list()?.get(0),
+        DataType::FixedSizeList(..) => s.fixed_size_list()?.get(0),
+        _ => Some(s.clone()),
+    }
+    .ok_or_else(|| DaftError::ValueError(format!("{name} must not be null")))?;
+    let values = values.cast(&DataType::Float32)?;
+    let values = values
+        .f32()?
+        .into_iter()
+        .map(|v| v.copied())
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(|| DaftError::ValueError(format!("{name} must not contain nulls")))?;
+    ensure!(!values.is_empty(), ValueError: "{name} must not be empty");
+    Ok(values)
+}
+
+fn check_param_dtype(field: &Field) -> DaftResult<()> {
+    let is_numeric = match &field.dtype {
+        DataType::List(child) | DataType::FixedSizeList(child, _) => child.is_numeric(),
+        dtype => dtype.is_numeric(),
+    };
+    ensure!(
+        is_numeric,
+        TypeError: "{} must be a number or a list of numbers, got {}",
+        field.name,
+        field.dtype
+    );
+    Ok(())
+}
+
+#[typetag::serde]
+impl ScalarUDF for ImageNormalize {
+    fn call(&self, inputs: daft_dsl::functions::FunctionArgs<Series>) -> DaftResult<Series> {
+        let ImageNormalizeArgs { input, mean, std } = inputs.try_into()?;
+        let mean = channel_params(&mean, "mean")?;
+        let std = channel_params(&std, "std")?;
+        ensure!(
+            std.iter().all(|v| *v != 0.0),
+            ValueError: "std must not contain zeros"
+        );
+
+        crate::series::normalize(&input, &mean, &std)
+    }
+
+    fn name(&self) -> &'static str {
+        "image_normalize"
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        let ImageNormalizeArgs { input, mean, std } = inputs.try_into()?;
+        check_param_dtype(&mean.to_field(schema)?)?;
+        check_param_dtype(&std.to_field(schema)?)?;
+
+        let field = input.to_field(schema)?;
+        let output_dtype = match field.dtype {
+            DataType::Image(_) => DataType::Tensor(Box::new(DataType::Float32)),
+            DataType::FixedShapeImage(mode, h, w) => DataType::FixedShapeTensor(
+                Box::new(DataType::Float32),
+                vec![u64::from(h), u64::from(w), u64::from(mode.num_channels())],
+            ),
+            _ => {
+                return Err(DaftError::TypeError(format!(
+                    "ImageNormalize can only operate on ImageArrays and FixedShapeImageArrays, got {field}"
+                )))
+            }
+        };
+
+        Ok(Field::new(field.name, output_dtype))
+    }
+
+    fn docstring(&self) -> &'static str {
+        "Converts an image into a float32 tensor of shape [height, width, channels], scaling every channel to [0, 1] and normalizing it with the given per-channel mean and standard deviation."
+    }
+}
@@ -0,0 +1,69 @@
+use common_error::{ensure, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::{
+    functions::{FunctionArgs, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+use super::transform_return_field;
+use crate::ops::ImageTransform;
+
+/// ```text
+/// image_pad_to_aspect(input, ratio)
+/// image_pad_to_aspect(input, ratio, fill=255)
+/// ```
+#[derive(FunctionArgs)]
+struct ImagePadToAspectArgs<T> {
+    input: T,
+    ratio: f64,
+    #[arg(optional)]
+    fill: Option<u8>,
+}
+
+impl<T> ImagePadToAspectArgs<T> {
+    fn transform(&self) -> DaftResult<ImageTransform> {
+        ensure!(
+            self.ratio.is_finite() && self.ratio > 0.0,
+            ValueError: "Aspect ratio must be a positive number, got {}",
+            self.ratio
+        );
+        Ok(ImageTransform::PadToAspect {
+            ratio: self.ratio,
+            fill: self.fill.unwrap_or(0),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct ImagePadToAspect;
+
+#[typetag::serde]
+impl ScalarUDF for ImagePadToAspect {
+    fn call(&self, inputs: daft_dsl::functions::FunctionArgs<Series>) -> DaftResult<Series> {
+        let args: ImagePadToAspectArgs<Series> = inputs.try_into()?;
+        let transform = args.transform()?;
+
+        crate::series::transform(&args.input, &transform)
+    }
+
+    fn name(&self) -> &'static str {
+        "image_pad_to_aspect"
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        let args: ImagePadToAspectArgs<ExprRef> = inputs.try_into()?;
+        let transform = args.transform()?;
+
+        let field = args.input.to_field(schema)?;
+        transform_return_field("ImagePadToAspect", field, &transform)
+    }
+
+    fn docstring(&self) -> &'static str {
+        "Pads an image evenly on both sides to the given width / height aspect ratio, filling every channel of the padding with `fill` (defaults to 0)."
+    }
+}
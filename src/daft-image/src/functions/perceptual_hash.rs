@@ -0,0 +1,89 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::{
+    functions::{FunctionArgs, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::perceptual_hash::PerceptualHash;
+
+/// ```text
+/// image_ahash(input)
+/// image_dhash(input)
+/// image_phash(input)
+/// ```
+#[derive(FunctionArgs)]
+struct ImageHashArgs<T> {
+    input: T,
+}
+
+fn hash_return_field(
+    function: &str,
+    inputs: FunctionArgs<ExprRef>,
+    schema: &Schema,
+) -> DaftResult<Field> {
+    let ImageHashArgs { input } = inputs.try_into()?;
+    let field = input.to_field(schema)?;
+    match field.dtype {
+        DataType::Image(_) | DataType::FixedShapeImage(..) => {
+            Ok(Field::new(field.name, DataType::UInt64))
+        }
+        _ => Err(DaftError::TypeError(format!(
+            "{function} can only hash ImageArrays and FixedShapeImageArrays, got {field}"
+        ))),
+    }
+}
+
+macro_rules! impl_perceptual_hash_udf {
+    ($struct:ident, $name:literal, $kind:expr, $doc:literal) => {
+        #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+        pub struct $struct;
+
+        #[typetag::serde]
+        impl ScalarUDF for $struct {
+            fn call(
+                &self,
+                inputs: daft_dsl::functions::FunctionArgs<Series>,
+            ) -> DaftResult<Series> {
+                let ImageHashArgs { input } = inputs.try_into()?;
+                crate::series::perceptual_hash(&input, $kind)
+            }
+
+            fn name(&self) -> &'static str {
+                $name
+            }
+
+            fn get_return_field(
+                &self,
+                inputs: FunctionArgs<ExprRef>,
+                schema: &Schema,
+            ) -> DaftResult<Field> {
+                hash_return_field(stringify!($struct), inputs, schema)
+            }
+
+            fn docstring(&self) -> &'static str {
+                $doc
+            }
+        }
+    };
+}
+
+impl_perceptual_hash_udf!(
+    ImageAverageHash,
+    "image_ahash",
+    PerceptualHash::Average,
+    "Computes the 64 bit average hash (aHash) of an image. Near-duplicate images have hashes with a small Hamming distance."
+);
+impl_perceptual_hash_udf!(
+    ImageDifferenceHash,
+    "image_dhash",
+    PerceptualHash::Difference,
+    "Computes the 64 bit difference hash (dHash) of an image. Near-duplicate images have hashes with a small Hamming distance."
+);
+impl_perceptual_hash_udf!(
+    ImagePerceptualHash,
+    "image_phash",
+    PerceptualHash::Perceptual,
+    "Computes the 64 bit DCT-based perceptual hash (pHash) of an image. Near-duplicate images have hashes with a small Hamming distance."
+);
@@ -0,0 +1,65 @@
+use common_error::{ensure, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::{
+    functions::{FunctionArgs, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+use super::transform_return_field;
+use crate::ops::ImageTransform;
+
+/// ```text
+/// image_rotate(input, degrees)
+/// ```
+#[derive(FunctionArgs)]
+struct ImageRotateArgs<T> {
+    input: T,
+    degrees: i64,
+}
+
+impl<T> ImageRotateArgs<T> {
+    fn transform(&self) -> DaftResult<ImageTransform> {
+        ensure!(
+            self.degrees % 90 == 0,
+            ValueError: "Images can only be rotated by a multiple of 90 degrees, got {}",
+            self.degrees
+        );
+        Ok(ImageTransform::Rotate {
+            quarter_turns: (self.degrees.rem_euclid(360) / 90) as u32,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct ImageRotate;
+
+#[typetag::serde]
+impl ScalarUDF for ImageRotate {
+    fn call(&self, inputs: daft_dsl::functions::FunctionArgs<Series>) -> DaftResult<Series> {
+        let args: ImageRotateArgs<Series> = inputs.try_into()?;
+        let transform = args.transform()?;
+
+        crate::series::transform(&args.input, &transform)
+    }
+
+    fn name(&self) -> &'static str {
+        "image_rotate"
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        let args: ImageRotateArgs<ExprRef> = inputs.try_into()?;
+        let transform = args.transform()?;
+
+        let field = args.input.to_field(schema)?;
+        transform_return_field("ImageRotate", field, &transform)
+    }
+
+    fn docstring(&self) -> &'static str {
+        "Rotates an image clockwise by a multiple of 90 degrees."
+    }
+}
@@ -0,0 +1,63 @@
+use common_error::{ensure, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::{
+    functions::{FunctionArgs, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+use super::transform_return_field;
+use crate::ops::ImageTransform;
+
+/// ```text
+/// image_thumbnail(input, w, h)
+/// ```
+#[derive(FunctionArgs)]
+struct ImageThumbnailArgs<T> {
+    input: T,
+    w: u32,
+    h: u32,
+}
+
+impl<T> ImageThumbnailArgs<T> {
+    fn transform(&self) -> DaftResult<ImageTransform> {
+        ensure!(self.w > 0 && self.h > 0, ValueError: "Thumbnail width and height must be positive");
+        Ok(ImageTransform::Thumbnail {
+            width: self.w,
+            height: self.h,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct ImageThumbnail;
+
+#[typetag::serde]
+impl ScalarUDF for ImageThumbnail {
+    fn call(&self, inputs: daft_dsl::functions::FunctionArgs<Series>) -> DaftResult<Series> {
+        let args: ImageThumbnailArgs<Series> = inputs.try_into()?;
+        let transform = args.transform()?;
+
+        crate::series::transform(&args.input, &transform)
+    }
+
+    fn name(&self) -> &'static str {
+        "image_thumbnail"
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        let args: ImageThumbnailArgs<ExprRef> = inputs.try_into()?;
+        let transform = args.transform()?;
+
+        let field = args.input.to_field(schema)?;
+        transform_return_field("ImageThumbnail", field, &transform)
+    }
+
+    fn docstring(&self) -> &'static str {
+        "Downscales an image to fit within the specified width and height while preserving its aspect ratio. Images that already fit are left unchanged."
+    }
+}
@@ -8,6 +8,8 @@ use common_error::{DaftError, DaftResult};
 use daft_core::{array::image_array::BBox, datatypes::prelude::*};
 use image::{ColorType, DynamicImage, ImageBuffer, Luma, LumaA, Rgb, Rgba};
 
+use crate::ops::ImageTransform;
+
 #[allow(clippy::upper_case_acronyms, dead_code)]
 #[derive(Debug)]
 pub enum DaftImageBuffer<'a> {
@@ -44,6 +46,22 @@ macro_rules! with_method_on_image_buffer {
     }};
 }
 
+macro_rules! map_u8_image_buffer {
+    (
+    $buffer:expr, |$img:ident| $body:expr
+) => {{
+        use DaftImageBuffer::{L, LA, RGB, RGBA};
+
+        match $buffer {
+            L($img) => L(image_buffer_vec_to_cow($body)),
+            LA($img) => LA(image_buffer_vec_to_cow($body)),
+            RGB($img) => RGB(image_buffer_vec_to_cow($body)),
+            RGBA($img) => RGBA(image_buffer_vec_to_cow($body)),
+            other => unimplemented!("Mode {other:?} not implemented"),
+        }
+    }};
+}
+
 impl<'a> DaftImageBuffer<'a> {
     pub fn from_raw(mode: &ImageMode, width: u32, height: u32, data: Cow<'a, [u8]>) -> Self {
         use DaftImageBuffer::{L, LA, RGB, RGBA};
@@ -55,6 +73,27 @@ impl<'a> DaftImageBuffer<'a> {
             _ => unimplemented!("{mode} is currently not implemented!"),
         }
     }
+
+    pub fn from_raw_u16(mode: &ImageMode, width: u32, height: u32, data: Cow<'a, [u16]>) -> Self {
+        use DaftImageBuffer::{L16, LA16, RGB16, RGBA16};
+        match mode {
+            ImageMode::L16 => L16(ImageBuffer::from_raw(width, height, data).unwrap()),
+            ImageMode::LA16 => LA16(ImageBuffer::from_raw(width, height, data).unwrap()),
+            ImageMode::RGB16 => RGB16(ImageBuffer::from_raw(width, height, data).unwrap()),
+            ImageMode::RGBA16 => RGBA16(ImageBuffer::from_raw(width, height, data).unwrap()),
+            _ => unimplemented!("{mode} is not a 16-bit image mode!"),
+        }
+    }
+
+    pub fn from_raw_f32(mode: &ImageMode, width: u32, height: u32, data: Cow<'a, [f32]>) -> Self {
+        use DaftImageBuffer::{RGB32F, RGBA32F};
+        match mode {
+            ImageMode::RGB32F => RGB32F(ImageBuffer::from_raw(width, height, data).unwrap()),
+            ImageMode::RGBA32F => RGBA32F(ImageBuffer::from_raw(width, height, data).unwrap()),
+            _ => unimplemented!("{mode} is not a 32-bit float image mode!"),
+        }
+    }
+
     pub fn height(&self) -> u32 {
         with_method_on_image_buffer!(self, height)
     }
@@ -73,6 +112,27 @@ impl<'a> DaftImageBuffer<'a> {
             _ => unimplemented!("unimplemented {self:?}"),
         }
     }
+
+    pub fn as_u16_slice(&self) -> &[u16] {
+        use DaftImageBuffer::{L16, LA16, RGB16, RGBA16};
+        match self {
+            L16(img) => img.as_raw(),
+            LA16(img) => img.as_raw(),
+            RGB16(img) => img.as_raw(),
+            RGBA16(img) => img.as_raw(),
+            _ => unimplemented!("unimplemented {self:?}"),
+        }
+    }
+
+    pub fn as_f32_slice(&self) -> &[f32] {
+        use DaftImageBuffer::{RGB32F, RGBA32F};
+        match self {
+            RGB32F(img) => img.as_raw(),
+            RGBA32F(img) => img.as_raw(),
+            _ => unimplemented!("unimplemented {self:?}"),
+        }
+    }
+
     pub fn mode(&self) -> ImageMode {
         use DaftImageBuffer::{L, L16, LA, LA16, RGB, RGB16, RGB32F, RGBA, RGBA16, RGBA32F};
 
@@ -106,8 +166,24 @@ impl<'a> DaftImageBuffer<'a> {
         }
     }
 
+    /// Decodes an encoded image, detecting its format from the magic bytes at the start of the data.
+    ///
+    /// 16-bit images decode into the 16-bit image modes and HDR images into the 32-bit float modes.
     pub fn decode(bytes: &[u8]) -> DaftResult<Self> {
-        image::load_from_memory(bytes)
+        let decoded = match ImageFormat::from_magic_bytes(bytes) {
+            Some(ImageFormat::AVIF) => {
+                return Err(DaftError::ValueError(
+                    "Decoding image from bytes failed: decoding AVIF images is not supported"
+                        .to_string(),
+                ))
+            }
+            Some(image_format) => {
+                image::load_from_memory_with_format(bytes, convert_img_fmt(image_format))
+            }
+            // Fall back to the image crate's own detection for formats that Daft can't encode, e.g. ICO or HDR.
+            None => image::load_from_memory(bytes),
+        };
+        decoded
             .map(std::convert::Into::into)
             .map_err(|e| DaftError::ValueError(format!("Decoding image from bytes failed: {e}")))
     }
@@ -116,14 +192,20 @@ impl<'a> DaftImageBuffer<'a> {
     where
         W: Write + Seek,
     {
-        image::write_buffer_with_format(
-            writer,
-            self.as_u8_slice(),
-            self.width(),
-            self.height(),
-            self.color(),
-            convert_img_fmt(image_format),
-        )
+        use DaftImageBuffer::{L, LA, RGB, RGBA};
+        match self {
+            L(..) | LA(..) | RGB(..) | RGBA(..) => image::write_buffer_with_format(
+                writer,
+                self.as_u8_slice(),
+                self.width(),
+                self.height(),
+                self.color(),
+                convert_img_fmt(image_format),
+            ),
+            _ => self
+                .to_dynamic_image()
+                .write_to(writer, convert_img_fmt(image_format)),
+        }
         .map_err(|e| {
             DaftError::ValueError(format!(
                 "Encoding image into file format {image_format} failed: {e}"
@@ -201,6 +283,163 @@ impl<'a> DaftImageBuffer<'a> {
         }
     }
 
+    pub fn transform(&self, transform: &ImageTransform) -> Self {
+        match transform {
+            ImageTransform::Rotate { quarter_turns } => self.rotate(*quarter_turns),
+            ImageTransform::FlipHorizontal => {
+                map_u8_image_buffer!(self, |img| image::imageops::flip_horizontal(img))
+            }
+            ImageTransform::FlipVertical => {
+                map_u8_image_buffer!(self, |img| image::imageops::flip_vertical(img))
+            }
+            ImageTransform::PadToAspect { fill, .. } => {
+                let (w, h) = transform.output_shape(self.width(), self.height());
+                self.pad(w, h, *fill)
+            }
+            ImageTransform::CenterCrop { .. } => {
+                let (w, h) = transform.output_shape(self.width(), self.height());
+                let x = (self.width() - w) / 2;
+                let y = (self.height() - h) / 2;
+                self.crop(&BBox(x, y, w, h))
+            }
+            ImageTransform::GaussianBlur { sigma } => {
+                map_u8_image_buffer!(self, |img| image::imageops::blur(img, *sigma))
+            }
+            ImageTransform::AdjustBrightness { factor } => self.map_color_channels(|v| v * factor),
+            ImageTransform::AdjustContrast { factor } => {
+                let mean = self.mean_luminance();
+                self.map_color_channels(|v| factor.mul_add(v - mean, mean))
+            }
+            ImageTransform::Thumbnail { .. } => {
+                let (w, h) = transform.output_shape(self.width(), self.height());
+                if (w, h) == (self.width(), self.height()) {
+                    self.copy()
+                } else {
+                    self.resize(w, h)
+                }
+            }
+        }
+    }
+
+    /// Rotates the image clockwise by the given number of quarter turns.
+    pub fn rotate(&self, quarter_turns: u32) -> Self {
+        match quarter_turns % 4 {
+            0 => self.copy(),
+            1 => map_u8_image_buffer!(self, |img| image::imageops::rotate90(img)),
+            2 => map_u8_image_buffer!(self, |img| image::imageops::rotate180(img)),
+            _ => map_u8_image_buffer!(self, |img| image::imageops::rotate270(img)),
+        }
+    }
+
+    fn copy(&self) -> Self {
+        Self::from_raw(
+            &self.mode(),
+            self.width(),
+            self.height(),
+            Cow::Owned(self.as_u8_slice().to_vec()),
+        )
+    }
+
+    /// Centers the image on a `w` x `h` canvas whose channels are all set to `fill`.
+    fn pad(&self, w: u32, h: u32, fill: u8) -> Self {
+        let num_channels = self.mode().num_channels() as usize;
+        let x = i64::from((w - self.width()) / 2);
+        let y = i64::from((h - self.height()) / 2);
+        map_u8_image_buffer!(self, |img| {
+            let mut canvas =
+                ImageBuffer::from_raw(w, h, vec![fill; w as usize * h as usize * num_channels])
+                    .unwrap();
+            image::imageops::replace(&mut canvas, img, x, y);
+            canvas
+        })
+    }
+
+    /// Applies `f` to every color channel, leaving the alpha channel untouched.
+    ///
+    /// Values are passed to `f` as floats in `[0, 255]` and the result is rounded and clamped.
+    fn map_color_channels(&self, f: impl Fn(f32) -> f32) -> Self {
+        let mode = self.mode();
+        let num_channels = mode.num_channels() as usize;
+        let num_color_channels = match mode {
+            ImageMode::LA | ImageMode::RGBA => num_channels - 1,
+            _ => num_channels,
+        };
+        let mut data = self.as_u8_slice().to_vec();
+        for pixel in data.chunks_exact_mut(num_channels) {
+            for v in &mut pixel[..num_color_channels] {
+                *v = f(f32::from(*v)).round().clamp(0.0, 255.0) as u8;
+            }
+        }
+        Self::from_raw(&mode, self.width(), self.height(), Cow::Owned(data))
+    }
+
+    /// Mean grayscale intensity of the image, using the ITU-R 601-2 luma transform for color images.
+    fn mean_luminance(&self) -> f32 {
+        let num_channels = self.mode().num_channels() as usize;
+        let data = self.as_u8_slice();
+        let num_pixels = data.len() / num_channels;
+        if num_pixels == 0 {
+            return 0.0;
+        }
+        let total: f64 = data
+            .chunks_exact(num_channels)
+            .map(|pixel| match self.mode() {
+                ImageMode::RGB | ImageMode::RGBA => [0.299, 0.587, 0.114]
+                    .iter()
+                    .zip(pixel)
+                    .map(|(weight, v)| weight * f64::from(*v))
+                    .sum(),
+                _ => f64::from(pixel[0]),
+            })
+            .sum();
+        (total / num_pixels as f64) as f32
+    }
+
+    /// Scales every channel to `[0, 1]` and normalizes it with the per-channel `mean` and `std`,
+    /// returning the values in height, width, channel order.
+    ///
+    /// `mean` and `std` must either have a single value, which is applied to every channel, or one
+    /// value per channel.
+    pub fn normalize(&self, mean: &[f32], std: &[f32]) -> DaftResult<Vec<f32>> {
+        let num_channels = self.mode().num_channels() as usize;
+        for (name, values) in [("mean", mean), ("std", std)] {
+            if values.len() != 1 && values.len() != num_channels {
+                return Err(DaftError::ValueError(format!(
+                    "Expected {name} to have 1 or {num_channels} values to normalize an image with mode {}, but got {}",
+                    self.mode(),
+                    values.len()
+                )));
+            }
+        }
+        Ok(self
+            .as_u8_slice()
+            .iter()
+            .enumerate()
+            .map(|(i, v)| {
+                let channel = i % num_channels;
+                let mean = mean[channel % mean.len()];
+                let std = std[channel % std.len()];
+                (f32::from(*v) / 255.0 - mean) / std
+            })
+            .collect())
+    }
+
+    fn to_dynamic_image(&self) -> DynamicImage {
+        use DaftImageBuffer::{L, L16, LA, LA16, RGB, RGB16, RGB32F, RGBA, RGBA16, RGBA32F};
+        match self {
+            L(buf) => image_buffer_cow_to_vec(buf.clone()).into(),
+            LA(buf) => image_buffer_cow_to_vec(buf.clone()).into(),
+            RGB(buf) => image_buffer_cow_to_vec(buf.clone()).into(),
+            RGBA(buf) => image_buffer_cow_to_vec(buf.clone()).into(),
+            L16(buf) => image_buffer_cow_to_vec(buf.clone()).into(),
+            LA16(buf) => image_buffer_cow_to_vec(buf.clone()).into(),
+            RGB16(buf) => image_buffer_cow_to_vec(buf.clone()).into(),
+            RGBA16(buf) => image_buffer_cow_to_vec(buf.clone()).into(),
+            RGB32F(buf) => image_buffer_cow_to_vec(buf.clone()).into(),
+            RGBA32F(buf) => image_buffer_cow_to_vec(buf.clone()).into(),
+        }
+    }
+
     pub fn into_mode(self, mode: ImageMode) -> Self {
         let img: DynamicImage = self.into();
         // I couldn't find a method from the image crate to do this
@@ -308,5 +547,7 @@ fn convert_img_fmt(fmt: ImageFormat) -> image::ImageFormat {
         ImageFormat::TIFF => image::ImageFormat::Tiff,
         ImageFormat::GIF => image::ImageFormat::Gif,
         ImageFormat::BMP => image::ImageFormat::Bmp,
+        ImageFormat::WEBP => image::ImageFormat::WebP,
+        ImageFormat::AVIF => image::ImageFormat::Avif,
     }
 }
@@ -2,6 +2,7 @@ mod counting_writer;
 mod image_buffer;
 mod iters;
 pub mod ops;
+pub mod perceptual_hash;
 use counting_writer::CountingWriter;
 use image_buffer::DaftImageBuffer;
 pub mod functions;
@@ -9,10 +9,13 @@ use daft_core::{
     },
     datatypes::prelude::*,
     prelude::ImageArray,
+    series::IntoSeries,
 };
 use num_traits::FromPrimitive;
 
-use crate::{iters::ImageBufferIter, CountingWriter, DaftImageBuffer};
+use crate::{
+    iters::ImageBufferIter, perceptual_hash::PerceptualHash, CountingWriter, DaftImageBuffer,
+};
 
 #[allow(clippy::len_without_is_empty)]
 pub trait AsImageObj {
@@ -21,6 +24,77 @@ pub trait AsImageObj {
     fn as_image_obj(&self, idx: usize) -> Option<DaftImageBuffer<'_>>;
 }
 
+/// A per-image transformation that produces another image of the same mode.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ImageTransform {
+    /// Rotates the image clockwise by the given number of quarter turns.
+    Rotate {
+        quarter_turns: u32,
+    },
+    FlipHorizontal,
+    FlipVertical,
+    /// Pads the image evenly on both sides so that `width / height == ratio`.
+    PadToAspect {
+        ratio: f64,
+        fill: u8,
+    },
+    /// Crops a centered `width` x `height` region, clamped to the size of the image.
+    CenterCrop {
+        width: u32,
+        height: u32,
+    },
+    GaussianBlur {
+        sigma: f32,
+    },
+    /// Multiplies every color channel by `factor`.
+    AdjustBrightness {
+        factor: f32,
+    },
+    /// Blends every color channel with the mean luminance of the image, scaled by `factor`.
+    AdjustContrast {
+        factor: f32,
+    },
+    /// Downscales the image to fit within `width` x `height`, preserving its aspect ratio.
+    Thumbnail {
+        width: u32,
+        height: u32,
+    },
+}
+
+impl ImageTransform {
+    /// Returns the `(width, height)` of an image of size `width` x `height` after this transform.
+    #[must_use]
+    pub fn output_shape(&self, width: u32, height: u32) -> (u32, u32) {
+        match *self {
+            Self::Rotate { quarter_turns } if quarter_turns % 2 == 1 => (height, width),
+            Self::PadToAspect { ratio, .. } if width > 0 && height > 0 => {
+                if f64::from(width) / f64::from(height) < ratio {
+                    let padded = (f64::from(height) * ratio).round() as u32;
+                    (padded.max(width), height)
+                } else {
+                    let padded = (f64::from(width) / ratio).round() as u32;
+                    (width, padded.max(height))
+                }
+            }
+            Self::CenterCrop {
+                width: crop_width,
+                height: crop_height,
+            } => (width.min(crop_width), height.min(crop_height)),
+            Self::Thumbnail {
+                width: max_width,
+                height: max_height,
+            } if width > max_width || height > max_height => {
+                let scale = (f64::from(max_width) / f64::from(width))
+                    .min(f64::from(max_height) / f64::from(height));
+                let w = (f64::from(width) * scale).floor() as u32;
+                let h = (f64::from(height) * scale).floor() as u32;
+                (w.max(1), h.max(1))
+            }
+            _ => (width, height),
+        }
+    }
+}
+
 pub trait ImageOps {
     fn encode(&self, image_format: ImageFormat) -> DaftResult<BinaryArray>;
     fn resize(&self, w: u32, h: u32) -> DaftResult<Self>
@@ -38,6 +112,9 @@ pub trait ImageOps {
     fn to_mode(&self, mode: ImageMode) -> DaftResult<Self>
     where
         Self: Sized;
+    fn transform(&self, transform: &ImageTransform) -> DaftResult<Self>
+    where
+        Self: Sized;
 }
 
 pub(crate) fn image_array_from_img_buffers(
@@ -45,13 +122,40 @@ pub(crate) fn image_array_from_img_buffers(
     inputs: &[Option<DaftImageBuffer<'_>>],
     image_mode: Option<ImageMode>,
 ) -> DaftResult<ImageArray> {
-    use DaftImageBuffer::{L, LA, RGB, RGBA};
-    let is_all_u8 = inputs
+    let dtype = inputs
         .iter()
-        .filter_map(|b| b.as_ref())
-        .all(|b| matches!(b, L(..) | LA(..) | RGB(..) | RGBA(..)));
-    assert!(is_all_u8);
+        .flatten()
+        .next()
+        .map_or(DataType::UInt8, |b| b.mode().get_dtype());
+    match dtype {
+        DataType::UInt8 => typed_image_array_from_img_buffers(
+            name,
+            inputs,
+            image_mode,
+            DaftImageBuffer::as_u8_slice,
+        ),
+        DataType::UInt16 => typed_image_array_from_img_buffers(
+            name,
+            inputs,
+            image_mode,
+            DaftImageBuffer::as_u16_slice,
+        ),
+        DataType::Float32 => typed_image_array_from_img_buffers(
+            name,
+            inputs,
+            image_mode,
+            DaftImageBuffer::as_f32_slice,
+        ),
+        _ => unreachable!("Image modes only have uint8, uint16 or float32 values"),
+    }
+}
 
+fn typed_image_array_from_img_buffers<'a, T: arrow2::types::NativeType>(
+    name: &str,
+    inputs: &[Option<DaftImageBuffer<'a>>],
+    image_mode: Option<ImageMode>,
+    as_slice: impl for<'b> Fn(&'b DaftImageBuffer<'a>) -> &'b [T],
+) -> DaftResult<ImageArray> {
     let mut data_ref = Vec::with_capacity(inputs.len());
     let mut heights = Vec::with_capacity(inputs.len());
     let mut channels = Vec::with_capacity(inputs.len());
@@ -64,8 +168,8 @@ pub(crate) fn image_array_from_img_buffers(
     for ib in inputs {
         validity.push(ib.is_some());
         let (height, width, mode, buffer) = match ib {
-            Some(ib) => (ib.height(), ib.width(), ib.mode(), ib.as_u8_slice()),
-            None => (0u32, 0u32, ImageMode::L, &[] as &[u8]),
+            Some(ib) => (ib.height(), ib.width(), ib.mode(), as_slice(ib)),
+            None => (0u32, 0u32, ImageMode::L, &[] as &[T]),
         };
         heights.push(height);
         widths.push(width);
@@ -194,6 +298,11 @@ impl ImageOps for ImageArray {
             .collect();
         image_array_from_img_buffers(self.name(), &buffers, Some(mode))
     }
+
+    fn transform(&self, transform: &ImageTransform) -> DaftResult<Self> {
+        let result = transform_images(self, transform);
+        image_array_from_img_buffers(self.name(), result.as_slice(), self.image_mode())
+    }
 }
 
 impl ImageOps for FixedShapeImageArray {
@@ -254,6 +363,26 @@ impl ImageOps for FixedShapeImageArray {
         };
         fixed_image_array_from_img_buffers(self.name(), &buffers, &mode, *height, *width)
     }
+
+    fn transform(&self, transform: &ImageTransform) -> DaftResult<Self>
+    where
+        Self: Sized,
+    {
+        let result = transform_images(self, transform);
+
+        let (height, width) = match self.data_type() {
+            DataType::FixedShapeImage(_, h, w) => (h, w),
+            _ => unreachable!("self should always be a FixedShapeImage"),
+        };
+        let (width, height) = transform.output_shape(*width, *height);
+        fixed_image_array_from_img_buffers(
+            self.name(),
+            result.as_slice(),
+            self.image_mode(),
+            height,
+            width,
+        )
+    }
 }
 
 impl AsImageObj for ImageArray {
@@ -282,22 +411,30 @@ impl AsImageObj for ImageArray {
         let start = *offsets.get(idx).unwrap() as usize;
         let end = *offsets.get(idx + 1).unwrap() as usize;
 
-        let values = da
-            .flat_child
-            .u8()
-            .unwrap()
-            .data()
-            .as_any()
-            .downcast_ref::<arrow2::array::UInt8Array>()
-            .unwrap();
-        let slice_data = Cow::Borrowed(&values.values().as_slice()[start..end] as &'a [u8]);
-
         let c = ca.value(idx);
         let h = ha.value(idx);
         let w = wa.value(idx);
         let m: ImageMode = ImageMode::from_u8(ma.value(idx)).unwrap();
         assert_eq!(m.num_channels(), c);
-        let result = DaftImageBuffer::from_raw(&m, w, h, slice_data);
+        let result = match m.get_dtype() {
+            DataType::UInt16 => {
+                let values = da.flat_child.u16().unwrap().as_arrow();
+                let slice_data =
+                    Cow::Borrowed(&values.values().as_slice()[start..end] as &'a [u16]);
+                DaftImageBuffer::from_raw_u16(&m, w, h, slice_data)
+            }
+            DataType::Float32 => {
+                let values = da.flat_child.f32().unwrap().as_arrow();
+                let slice_data =
+                    Cow::Borrowed(&values.values().as_slice()[start..end] as &'a [f32]);
+                DaftImageBuffer::from_raw_f32(&m, w, h, slice_data)
+            }
+            _ => {
+                let values = da.flat_child.u8().unwrap().as_arrow();
+                let slice_data = Cow::Borrowed(&values.values().as_slice()[start..end] as &'a [u8]);
+                DaftImageBuffer::from_raw(&m, w, h, slice_data)
+            }
+        };
 
         assert_eq!(result.height(), h);
         assert_eq!(result.width(), w);
@@ -322,13 +459,27 @@ impl AsImageObj for FixedShapeImageArray {
 
         match self.data_type() {
             DataType::FixedShapeImage(mode, height, width) => {
-                let arrow_array = self.physical.flat_child.downcast::<UInt8Array>().unwrap().as_arrow();
                 let num_channels = mode.num_channels();
                 let size = height * width * u32::from(num_channels);
                 let start = idx * size as usize;
                 let end = (idx + 1) * size as usize;
-                let slice_data = Cow::Borrowed(&arrow_array.values().as_slice()[start..end] as &'a [u8]);
-                let result = DaftImageBuffer::from_raw(mode, *width, *height, slice_data);
+                let result = match mode.get_dtype() {
+                    DataType::UInt16 => {
+                        let arrow_array = self.physical.flat_child.downcast::<UInt16Array>().unwrap().as_arrow();
+                        let slice_data = Cow::Borrowed(&arrow_array.values().as_slice()[start..end] as &'a [u16]);
+                        DaftImageBuffer::from_raw_u16(mode, *width, *height, slice_data)
+                    }
+                    DataType::Float32 => {
+                        let arrow_array = self.physical.flat_child.downcast::<Float32Array>().unwrap().as_arrow();
+                        let slice_data = Cow::Borrowed(&arrow_array.values().as_slice()[start..end] as &'a [f32]);
+                        DaftImageBuffer::from_raw_f32(mode, *width, *height, slice_data)
+                    }
+                    _ => {
+                        let arrow_array = self.physical.flat_child.downcast::<UInt8Array>().unwrap().as_arrow();
+                        let slice_data = Cow::Borrowed(&arrow_array.values().as_slice()[start..end] as &'a [u8]);
+                        DaftImageBuffer::from_raw(mode, *width, *height, slice_data)
+                    }
+                };
 
                 assert_eq!(result.height(), *height);
                 assert_eq!(result.width(), *width);
@@ -441,6 +592,123 @@ where
         .collect::<Vec<_>>()
 }
 
+fn transform_images<'a, Arr: AsImageObj>(
+    images: &'a Arr,
+    transform: &ImageTransform,
+) -> Vec<Option<DaftImageBuffer<'a>>> {
+    ImageBufferIter::new(images)
+        .map(|img| img.map(|img| img.transform(transform)))
+        .collect::<Vec<_>>()
+}
+
+/// Computes a perceptual hash of every image.
+pub fn perceptual_hash<Arr: AsImageObj>(images: &Arr, kind: PerceptualHash) -> UInt64Array {
+    let hashes = ImageBufferIter::new(images)
+        .map(|img| img.map(|img| kind.hash(img)))
+        .collect::<Vec<_>>();
+    UInt64Array::from((
+        images.name(),
+        Box::new(arrow2::array::UInt64Array::from(hashes)),
+    ))
+}
+
+/// Normalizes every image into a `Float32` tensor of shape `[height, width, channels]`.
+pub fn normalize_to_tensor<Arr: AsImageObj>(
+    images: &Arr,
+    mean: &[f32],
+    std: &[f32],
+) -> DaftResult<TensorArray> {
+    let mut values = Vec::new();
+    let mut data_offsets = Vec::with_capacity(images.len() + 1);
+    data_offsets.push(0i64);
+    let mut shapes = Vec::with_capacity(images.len() * 3);
+    let mut shape_offsets = Vec::with_capacity(images.len() + 1);
+    shape_offsets.push(0i64);
+    let mut validity = arrow2::bitmap::MutableBitmap::with_capacity(images.len());
+
+    for img in ImageBufferIter::new(images) {
+        validity.push(img.is_some());
+        if let Some(img) = img {
+            values.extend(img.normalize(mean, std)?);
+            shapes.extend([
+                u64::from(img.height()),
+                u64::from(img.width()),
+                u64::from(img.mode().num_channels()),
+            ]);
+        }
+        data_offsets.push(values.len() as i64);
+        shape_offsets.push(shapes.len() as i64);
+    }
+    let validity: Option<arrow2::bitmap::Bitmap> = match validity.unset_bits() {
+        0 => None,
+        _ => Some(validity.into()),
+    };
+
+    let data_array = ListArray::new(
+        Field::new("data", DataType::List(Box::new(DataType::Float32))),
+        Float32Array::from(("item", values)).into_series(),
+        arrow2::offset::OffsetsBuffer::try_from(data_offsets)?,
+        validity.clone(),
+    );
+    let shape_array = ListArray::new(
+        Field::new("shape", DataType::List(Box::new(DataType::UInt64))),
+        UInt64Array::from(("item", shapes)).into_series(),
+        arrow2::offset::OffsetsBuffer::try_from(shape_offsets)?,
+        validity.clone(),
+    );
+    let dtype = DataType::Tensor(Box::new(DataType::Float32));
+    let physical = StructArray::new(
+        Field::new(images.name(), dtype.to_physical()),
+        vec![data_array.into_series(), shape_array.into_series()],
+        validity,
+    );
+    Ok(TensorArray::new(Field::new(images.name(), dtype), physical))
+}
+
+/// Normalizes every image of a fixed shape image array into a `Float32` tensor of shape
+/// `[height, width, channels]`.
+pub fn normalize_to_fixed_shape_tensor(
+    images: &FixedShapeImageArray,
+    mean: &[f32],
+    std: &[f32],
+) -> DaftResult<FixedShapeTensorArray> {
+    let (mode, height, width) = match images.data_type() {
+        DataType::FixedShapeImage(mode, h, w) => (mode, *h, *w),
+        _ => unreachable!("images should always be a FixedShapeImage"),
+    };
+    let shape = vec![
+        u64::from(height),
+        u64::from(width),
+        u64::from(mode.num_channels()),
+    ];
+    let list_size = shape.iter().product::<u64>() as usize;
+
+    let mut values = Vec::with_capacity(images.len() * list_size);
+    let mut validity = arrow2::bitmap::MutableBitmap::with_capacity(images.len());
+    for img in ImageBufferIter::new(images) {
+        validity.push(img.is_some());
+        match img {
+            Some(img) => values.extend(img.normalize(mean, std)?),
+            None => values.extend(std::iter::repeat_n(0f32, list_size)),
+        }
+    }
+    let validity: Option<arrow2::bitmap::Bitmap> = match validity.unset_bits() {
+        0 => None,
+        _ => Some(validity.into()),
+    };
+
+    let dtype = DataType::FixedShapeTensor(Box::new(DataType::Float32), shape);
+    let physical = FixedSizeListArray::new(
+        Field::new(images.name(), dtype.to_physical()),
+        Float32Array::from(("item", values)).into_series(),
+        validity,
+    );
+    Ok(FixedShapeTensorArray::new(
+        Field::new(images.name(), dtype),
+        physical,
+    ))
+}
+
 #[must_use]
 pub fn image_html_value(arr: &ImageArray, idx: usize) -> String {
     let maybe_image = arr.as_image_obj(idx);
@@ -0,0 +1,114 @@
+use std::f64::consts::PI;
+
+use daft_core::datatypes::prelude::*;
+use image::{imageops::FilterType, ImageBuffer, Luma};
+
+use crate::DaftImageBuffer;
+
+/// Number of bits along each side of the hash grid, giving 64 bit hashes.
+const HASH_SIZE: u32 = 8;
+/// Side length of the image that the DCT of a perceptual hash is computed over.
+const PHASH_IMAGE_SIZE: u32 = HASH_SIZE * 4;
+
+/// A 64 bit perceptual hash of an image.
+///
+/// Similar images produce hashes with a small Hamming distance. Bits are packed in row-major order
+/// of the hash grid, starting at the most significant bit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PerceptualHash {
+    /// Whether each pixel of the 8x8 grayscale thumbnail is brighter than the mean.
+    Average,
+    /// Whether each pixel of the 9x8 grayscale thumbnail is darker than its right neighbor.
+    Difference,
+    /// Whether each of the 8x8 lowest frequencies of the DCT of the 32x32 grayscale thumbnail is
+    /// above their median.
+    Perceptual,
+}
+
+impl PerceptualHash {
+    pub(crate) fn hash(&self, img: DaftImageBuffer<'_>) -> u64 {
+        match self {
+            Self::Average => {
+                let pixels = grayscale_thumbnail(img, HASH_SIZE, HASH_SIZE);
+                let mean = pixels.iter().map(|v| f64::from(*v)).sum::<f64>() / pixels.len() as f64;
+                pack_bits(pixels.iter().map(|v| f64::from(*v) > mean))
+            }
+            Self::Difference => {
+                let pixels = grayscale_thumbnail(img, HASH_SIZE + 1, HASH_SIZE);
+                pack_bits(
+                    pixels
+                        .chunks_exact(HASH_SIZE as usize + 1)
+                        .flat_map(|row| row.windows(2).map(|pair| pair[1] > pair[0])),
+                )
+            }
+            Self::Perceptual => {
+                let pixels = grayscale_thumbnail(img, PHASH_IMAGE_SIZE, PHASH_IMAGE_SIZE);
+                let pixels = pixels.iter().map(|v| f64::from(*v)).collect::<Vec<_>>();
+                let low_frequencies = dct_2d_low_frequencies(&pixels, PHASH_IMAGE_SIZE as usize);
+                let median = median(&low_frequencies);
+                pack_bits(low_frequencies.iter().map(|v| *v > median))
+            }
+        }
+    }
+}
+
+/// Converts the image to grayscale and resizes it to `width` x `height`, returning its pixels in
+/// row-major order.
+fn grayscale_thumbnail(img: DaftImageBuffer<'_>, width: u32, height: u32) -> Vec<u8> {
+    let DaftImageBuffer::L(gray) = img.into_mode(ImageMode::L) else {
+        unreachable!("Converting an image to mode L should produce a grayscale image")
+    };
+    let gray: ImageBuffer<Luma<u8>, Vec<u8>> =
+        ImageBuffer::from_raw(gray.width(), gray.height(), gray.into_raw().into_owned()).unwrap();
+    image::imageops::resize(&gray, width, height, FilterType::Lanczos3).into_raw()
+}
+
+/// Computes the unnormalized 2D DCT-II of a `size` x `size` grid and returns the top-left
+/// `HASH_SIZE` x `HASH_SIZE` coefficients in row-major order.
+fn dct_2d_low_frequencies(pixels: &[f64], size: usize) -> Vec<f64> {
+    let num_frequencies = HASH_SIZE as usize;
+    // cosines[k][n] = cos(pi * k * (2n + 1) / 2N)
+    let cosines = (0..num_frequencies)
+        .map(|k| {
+            (0..size)
+                .map(|n| (PI * k as f64 * (2 * n + 1) as f64 / (2 * size) as f64).cos())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    // Transform each row, keeping only the low frequencies.
+    let rows = pixels
+        .chunks_exact(size)
+        .map(|row| {
+            cosines
+                .iter()
+                .map(|cos| 2.0 * row.iter().zip(cos).map(|(x, c)| x * c).sum::<f64>())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    // Then transform each of the remaining columns.
+    let mut result = vec![0.0; num_frequencies * num_frequencies];
+    for (k, cos) in cosines.iter().enumerate() {
+        for l in 0..num_frequencies {
+            result[k * num_frequencies + l] =
+                2.0 * rows.iter().zip(cos).map(|(row, c)| row[l] * c).sum::<f64>();
+        }
+    }
+    result
+}
+
+fn median(values: &[f64]) -> f64 {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    let mid = sorted.len() / 2;
+    if sorted.len() % 2 == 0 {
+        (sorted[mid - 1] + sorted[mid]) / 2.0
+    } else {
+        sorted[mid]
+    }
+}
+
+fn pack_bits(bits: impl Iterator<Item = bool>) -> u64 {
+    bits.fold(0u64, |hash, bit| (hash << 1) | u64::from(bit))
+}
@@ -2,7 +2,11 @@ use common_error::{DaftError, DaftResult};
 use daft_core::prelude::*;
 
 use crate::{
-    ops::{image_array_from_img_buffers, ImageOps},
+    ops::{
+        image_array_from_img_buffers, normalize_to_fixed_shape_tensor, normalize_to_tensor,
+        perceptual_hash as perceptual_hash_images, ImageOps, ImageTransform,
+    },
+    perceptual_hash::PerceptualHash,
     DaftImageBuffer,
 };
 fn image_decode_impl(
@@ -33,9 +37,26 @@ fn image_decode_impl(
                 None
             }
         };
-        if let Some(mode) = mode {
-            img_buf = img_buf.map(|buf| buf.into_mode(mode));
-        }
+        img_buf = img_buf.map(|buf| match mode {
+            Some(mode) => buf.into_mode(mode),
+            // Without an explicit mode the column only stores 8-bit images, so 16-bit and HDR images are
+            // converted to the 8-bit mode with the same channels.
+            None => {
+                let mode = buf.mode();
+                let mode_8bit = match mode {
+                    ImageMode::L16 => ImageMode::L,
+                    ImageMode::LA16 => ImageMode::LA,
+                    ImageMode::RGB16 | ImageMode::RGB32F => ImageMode::RGB,
+                    ImageMode::RGBA16 | ImageMode::RGBA32F => ImageMode::RGBA,
+                    _ => mode,
+                };
+                if mode_8bit == mode {
+                    buf
+                } else {
+                    buf.into_mode(mode_8bit)
+                }
+            }
+        });
         let dtype = img_buf.as_ref().map(|im| im.mode().get_dtype());
         match (dtype.as_ref(), cached_dtype.as_ref()) {
             (Some(t1), Some(t2)) => {
@@ -52,12 +73,7 @@ fn image_decode_impl(
         }
         img_bufs.push(img_buf);
     }
-    // Fall back to UInt8 dtype if series is all nulls.
-    let cached_dtype = cached_dtype.unwrap_or(DataType::UInt8);
-    match cached_dtype {
-        DataType::UInt8 => Ok(image_array_from_img_buffers(ba.name(), img_bufs.as_slice(), mode)?),
-        _ => unimplemented!("Decoding images of dtype {cached_dtype:?} is not supported, only uint8 images are supported."),
-    }
+    image_array_from_img_buffers(ba.name(), img_bufs.as_slice(), mode)
 }
 
 /// Decodes a series of binary data into image arrays.
@@ -197,3 +213,73 @@ pub fn to_mode(s: &Series, mode: ImageMode) -> DaftResult<Series> {
         ))),
     }
 }
+
+/// Applies a per-image transformation to images in a Series.
+///
+/// # Arguments
+/// * `s` - Input Series containing image data
+/// * `transform` - The transformation to apply to every image
+///
+/// # Returns
+/// A DaftResult containing a new Series with transformed images
+pub fn transform(s: &Series, transform: &ImageTransform) -> DaftResult<Series> {
+    match &s.data_type() {
+        DataType::Image(_) => s
+            .downcast::<ImageArray>()?
+            .transform(transform)
+            .map(|arr| arr.into_series()),
+        DataType::FixedShapeImage(..) => s
+            .fixed_size_image()?
+            .transform(transform)
+            .map(|arr| arr.into_series()),
+        dt => Err(DaftError::ValueError(format!(
+            "Expected input to image transform to be an Image type, but received: {dt}"
+        ))),
+    }
+}
+
+/// Converts images in a Series into normalized `Float32` tensors of shape `[height, width, channels]`.
+///
+/// # Arguments
+/// * `s` - Input Series containing image data
+/// * `mean` - Per-channel mean, or a single mean for all channels
+/// * `std` - Per-channel standard deviation, or a single standard deviation for all channels
+///
+/// # Returns
+/// A DaftResult containing a new Series of tensors
+pub fn normalize(s: &Series, mean: &[f32], std: &[f32]) -> DaftResult<Series> {
+    match &s.data_type() {
+        DataType::Image(_) => {
+            normalize_to_tensor(s.downcast::<ImageArray>()?, mean, std).map(|arr| arr.into_series())
+        }
+        DataType::FixedShapeImage(..) => {
+            normalize_to_fixed_shape_tensor(s.fixed_size_image()?, mean, std)
+                .map(|arr| arr.into_series())
+        }
+        dt => Err(DaftError::ValueError(format!(
+            "Expected input to normalize to be an Image type, but received: {dt}"
+        ))),
+    }
+}
+
+/// Computes a 64 bit perceptual hash of every image in a Series.
+///
+/// # Arguments
+/// * `s` - Input Series containing image data
+/// * `kind` - The perceptual hash algorithm to use
+///
+/// # Returns
+/// A DaftResult containing a new UInt64 Series of hashes
+pub fn perceptual_hash(s: &Series, kind: PerceptualHash) -> DaftResult<Series> {
+    match &s.data_type() {
+        DataType::Image(_) => {
+            Ok(perceptual_hash_images(s.downcast::<ImageArray>()?, kind).into_series())
+        }
+        DataType::FixedShapeImage(..) => {
+            Ok(perceptual_hash_images(s.fixed_size_image()?, kind).into_series())
+        }
+        dt => Err(DaftError::ValueError(format!(
+            "Expected input to perceptual hash to be an Image type, but received: {dt}"
+        ))),
+    }
+}
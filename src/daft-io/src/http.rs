@@ -3,18 +3,26 @@ use std::{
     num::ParseIntError,
     string::FromUtf8Error,
     sync::{Arc, LazyLock},
-    time::Duration,
+    time::{Duration, SystemTime},
 };
 
 use async_trait::async_trait;
+use bytes::Bytes;
 use common_io_config::HTTPConfig;
-use futures::{stream::BoxStream, TryStreamExt};
+use futures::{stream::BoxStream, StreamExt, TryStreamExt};
+use http::Extensions;
 use regex::Regex;
 use reqwest_middleware::{
-    reqwest::header::{self, CONTENT_LENGTH, RANGE},
-    ClientBuilder, ClientWithMiddleware,
+    reqwest::{
+        header::{self, HeaderMap, ACCEPT_RANGES, CONTENT_LENGTH, RANGE, RETRY_AFTER},
+        Request, Response, StatusCode,
+    },
+    ClientBuilder, ClientWithMiddleware, Middleware, Next,
+};
+use reqwest_retry::{
+    default_on_request_failure, default_on_request_success, policies::ExponentialBackoff, Jitter,
+    RetryTransientMiddleware, Retryable, RetryableStrategy,
 };
-use reqwest_retry::{policies::ExponentialBackoff, Jitter, RetryTransientMiddleware};
 use snafu::{IntoError, ResultExt, Snafu};
 use url::Position;
 
@@ -29,6 +37,9 @@ use crate::{
 
 const HTTP_DELIMITER: &str = "/";
 
+/// The longest `Retry-After` that is waited for, longer ones are treated as a failed request.
+pub const MAX_RETRY_AFTER: Duration = Duration::from_secs(300);
+
 static HTML_A_TAG_HREF_RE: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(r#"<(a|A)\s+(?:[^>]*?\s+)?(href|HREF)=["'](?P<url>[^"']+)"#).unwrap()
 });
@@ -87,6 +98,12 @@ enum Error {
 
     #[snafu(display("Unable to create HTTP header: {source}"))]
     UnableToCreateHeader { source: header::InvalidHeaderValue },
+
+    #[snafu(display("Response from {path} is larger than the limit of {max_bytes} bytes"))]
+    ContentTooLarge { path: String, max_bytes: usize },
+
+    #[snafu(display("Unable to resume reading {path}, server responded with {status}"))]
+    UnableToResume { path: String, status: StatusCode },
 }
 
 /// Finds and retrieves FileMetadata from HTML text
@@ -147,9 +164,111 @@ fn get_file_metadata_from_html(path: &str, text: &str) -> super::Result<Vec<File
     Ok(metas.into_iter().flatten().collect())
 }
 
+/// Parses the `Retry-After` header of a 429 or 503 response, which is either a number of seconds
+/// or an HTTP date.
+fn parse_retry_after(status: StatusCode, headers: &HeaderMap) -> Option<Duration> {
+    if !matches!(
+        status,
+        StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE
+    ) {
+        return None;
+    }
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = httpdate::parse_http_date(value).ok()?;
+    Some(date.duration_since(SystemTime::now()).unwrap_or_default())
+}
+
+fn retry_after(response: &Response) -> Option<Duration> {
+    parse_retry_after(response.status(), response.headers())
+}
+
+/// The default retry strategy, except that responses with a `Retry-After` are left to
+/// [`RetryAfterMiddleware`] since the exponential backoff doesn't honor it.
+struct RetryAfterStrategy;
+
+impl RetryableStrategy for RetryAfterStrategy {
+    fn handle(&self, res: &Result<Response, reqwest_middleware::Error>) -> Option<Retryable> {
+        match res {
+            Ok(response) if retry_after(response).is_some() => None,
+            Ok(response) => default_on_request_success(response),
+            Err(error) => default_on_request_failure(error),
+        }
+    }
+}
+
+/// Marks a request whose `Retry-After` responses are returned rather than waited for, so that the
+/// caller can hold off other requests to the same server.
+#[derive(Debug, Clone, Copy)]
+struct DeferRetryAfter;
+
+/// Waits for and retries responses with a `Retry-After`, up to `num_tries` times.
+struct RetryAfterMiddleware {
+    num_tries: u32,
+}
+
+#[async_trait]
+impl Middleware for RetryAfterMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let defer = extensions.get::<DeferRetryAfter>().is_some();
+        let mut tries = 1;
+        loop {
+            // requests with streaming bodies can't be sent twice.
+            let Some(duplicate) = req.try_clone() else {
+                return next.run(req, extensions).await;
+            };
+            let response = next.clone().run(duplicate, extensions).await?;
+            match retry_after(&response) {
+                Some(delay) if !defer && tries < self.num_tries && delay <= MAX_RETRY_AFTER => {
+                    tokio::time::sleep(delay).await;
+                    tries += 1;
+                }
+                _ => return Ok(response),
+            }
+        }
+    }
+}
+
+/// The final response of [`HttpSource::download`], after any redirects.
+#[derive(Debug)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub url: String,
+    /// The body, which is only read for successful responses.
+    pub body: Option<Bytes>,
+    /// How long the server asked to wait before retrying a 429 or 503 response.
+    pub retry_after: Option<Duration>,
+}
+
+impl HttpResponse {
+    /// Returns the body, or an error if the response wasn't successful.
+    pub fn into_body(self) -> super::Result<Bytes> {
+        match (self.body, self.status) {
+            (Some(body), _) => Ok(body),
+            (None, 404 | 410) => Err(super::Error::NotFound {
+                path: self.url,
+                source: format!("HTTP status {}", self.status).into(),
+            }),
+            (None, status) => Err(super::Error::UnableToOpenFile {
+                path: self.url,
+                source: format!("HTTP status {status}").into(),
+            }),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct HttpSource {
     pub(crate) client: ClientWithMiddleware,
+    pub(crate) num_tries: u32,
 }
 
 impl From<Error> for super::Error {
@@ -210,16 +329,135 @@ impl HttpSource {
             .build()
             .context(UnableToCreateClientSnafu)?;
 
-        // reqwest-retry already comes with a default retry strategy that matches http standards
-        // override it only if you need a custom one due to non standard behavior
-        let retry_middleware = RetryTransientMiddleware::new_with_policy(retry_policy)
-            .with_retry_log_level(tracing::Level::DEBUG);
+        // reqwest-retry's default retry strategy matches http standards, except that its backoff
+        // doesn't honor `Retry-After`, so those responses are retried by the inner middleware.
+        // NOTE: reqwest-retry only allows lowering the retry log level with its default strategy.
+        let retry_middleware = RetryTransientMiddleware::new_with_policy_and_strategy(
+            retry_policy,
+            RetryAfterStrategy,
+        );
 
         let client = ClientBuilder::new(base_client)
             .with(retry_middleware)
+            .with(RetryAfterMiddleware {
+                num_tries: config.num_tries,
+            })
             .build();
 
-        Ok(Self { client }.into())
+        Ok(Self {
+            client,
+            num_tries: config.num_tries,
+        }
+        .into())
+    }
+
+    /// Downloads a URL with the metadata of its final response, without waiting for a
+    /// `Retry-After` so that callers can hold off other requests to the same host.
+    ///
+    /// The body of a successful response is limited to `max_bytes`, and reading it resumes with a
+    /// range request if the connection drops and the server accepts ranges.
+    pub async fn download(
+        &self,
+        uri: &str,
+        max_bytes: Option<usize>,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<HttpResponse> {
+        let response = self
+            .client
+            .get(uri)
+            .with_extension(DeferRetryAfter)
+            .send()
+            .await
+            .context(UnableToConnectSnafu::<String> { path: uri.into() })?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_get_requests(1);
+        }
+        let status = response.status();
+        let url = response.url().to_string();
+        let headers = response
+            .headers()
+            .iter()
+            .map(|(name, value)| {
+                let value = String::from_utf8_lossy(value.as_bytes()).into_owned();
+                (name.to_string(), value)
+            })
+            .collect();
+        let retry_after = retry_after(&response);
+        if !status.is_success() {
+            return Ok(HttpResponse {
+                status: status.as_u16(),
+                headers,
+                url,
+                body: None,
+                retry_after,
+            });
+        }
+
+        let too_large = || {
+            Error::ContentTooLarge {
+                path: uri.to_string(),
+                max_bytes: max_bytes.unwrap_or_default(),
+            }
+            .into()
+        };
+        if let (Some(max_bytes), Some(size)) = (max_bytes, response.content_length()) {
+            if size as usize > max_bytes {
+                return Err(too_large());
+            }
+        }
+        let resumable = response
+            .headers()
+            .get(ACCEPT_RANGES)
+            .is_some_and(|v| v.as_bytes() == b"bytes");
+        let mut body = Vec::with_capacity(response.content_length().unwrap_or(0) as usize);
+        let mut stream = response.bytes_stream();
+        let mut tries = 1;
+        while let Some(chunk) = stream.next().await {
+            match chunk {
+                Ok(chunk) => {
+                    body.extend_from_slice(&chunk);
+                    if let Some(is) = io_stats.as_ref() {
+                        is.mark_bytes_read(chunk.len());
+                    }
+                    if max_bytes.is_some_and(|max_bytes| body.len() > max_bytes) {
+                        return Err(too_large());
+                    }
+                }
+                Err(error) if !resumable || tries >= self.num_tries => {
+                    return Err(UnableToReadBytesSnafu::<String> { path: uri.into() }
+                        .into_error(error)
+                        .into());
+                }
+                Err(_) => {
+                    tries += 1;
+                    let response = self
+                        .client
+                        .get(&url)
+                        .header(RANGE, format!("bytes={}-", body.len()))
+                        .send()
+                        .await
+                        .context(UnableToConnectSnafu::<String> { path: uri.into() })?;
+                    if response.status() != StatusCode::PARTIAL_CONTENT {
+                        return Err(Error::UnableToResume {
+                            path: uri.to_string(),
+                            status: response.status(),
+                        }
+                        .into());
+                    }
+                    if let Some(is) = io_stats.as_ref() {
+                        is.mark_get_requests(1);
+                    }
+                    stream = response.bytes_stream();
+                }
+            }
+        }
+        Ok(HttpResponse {
+            status: status.as_u16(),
+            headers,
+            url,
+            body: Some(body.into()),
+            retry_after,
+        })
     }
 }
 
@@ -390,10 +628,44 @@ impl ObjectSource for HttpSource {
 
 #[cfg(test)]
 mod tests {
-    use std::default;
+    use std::{
+        default,
+        time::{Duration, SystemTime},
+    };
 
+    use reqwest_middleware::reqwest::{
+        header::{HeaderMap, HeaderValue, RETRY_AFTER},
+        StatusCode,
+    };
+
+    use super::parse_retry_after;
     use crate::{integrations::test_full_get, object_io::ObjectSource, HttpSource, Result};
 
+    #[test]
+    fn test_parse_retry_after() {
+        let headers = |value: &str| {
+            let mut headers = HeaderMap::new();
+            headers.insert(RETRY_AFTER, HeaderValue::from_str(value).unwrap());
+            headers
+        };
+        let too_many = StatusCode::TOO_MANY_REQUESTS;
+        assert_eq!(
+            parse_retry_after(too_many, &headers("3")),
+            Some(Duration::from_secs(3))
+        );
+        let date = httpdate::fmt_http_date(SystemTime::now() + Duration::from_secs(60));
+        let delay = parse_retry_after(too_many, &headers(&date)).unwrap();
+        assert!(delay > Duration::from_secs(50) && delay <= Duration::from_secs(60));
+        let past = httpdate::fmt_http_date(SystemTime::UNIX_EPOCH);
+        assert_eq!(
+            parse_retry_after(too_many, &headers(&past)),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(parse_retry_after(too_many, &headers("soon")), None);
+        assert_eq!(parse_retry_after(too_many, &HeaderMap::new()), None);
+        assert_eq!(parse_retry_after(StatusCode::OK, &headers("3")), None);
+    }
+
     #[tokio::test]
     async fn test_full_get_from_http() -> Result<()> {
         let parquet_file_path = "https://daft-public-data.s3.us-west-2.amazonaws.com/test_fixtures/parquet_small/0dad4c3f-da0d-49db-90d8-98684571391b-0.parquet";
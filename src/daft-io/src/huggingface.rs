@@ -238,11 +238,7 @@ impl HFSource {
     pub async fn get_client(config: &HTTPConfig) -> super::Result<Arc<Self>> {
         let http_source = HttpSource::get_client(config).await?;
         let http_source = Arc::try_unwrap(http_source).expect("Could not unwrap Arc<HttpSource>");
-        let client = http_source.client;
-        Ok(Self {
-            http_source: HttpSource { client },
-        }
-        .into())
+        Ok(Self { http_source }.into())
     }
 }
 
@@ -36,6 +36,7 @@ use std::{borrow::Cow, collections::HashMap, hash::Hash, ops::Range, sync::Arc};
 use common_error::{DaftError, DaftResult};
 pub use common_io_config::{AzureConfig, GCSConfig, HTTPConfig, IOConfig, S3Config};
 use futures::stream::BoxStream;
+pub use http::{HttpResponse, HttpSource, MAX_RETRY_AFTER};
 use object_io::StreamingRetryParams;
 pub use object_io::{FileMetadata, GetResult};
 #[cfg(feature = "python")]
@@ -45,7 +46,7 @@ use snafu::{prelude::*, Snafu};
 pub use stats::{IOStatsContext, IOStatsRef};
 use url::ParseError;
 
-use self::{http::HttpSource, local::LocalSource, object_io::ObjectSource};
+use self::{local::LocalSource, object_io::ObjectSource};
 use crate::range::GetRange;
 
 #[derive(Debug, Snafu)]
@@ -281,6 +282,16 @@ impl IOClient {
         Ok(files)
     }
 
+    /// Returns the HTTP source for an http or https URL, or `None` for any other URL.
+    pub async fn get_http_source(&self, input: &str) -> Result<Option<Arc<HttpSource>>> {
+        let (source_type, _) = parse_url(input)?;
+        if source_type != SourceType::Http {
+            return Ok(None);
+        }
+        let source = self.get_source(input).await?;
+        Ok(source.as_any_arc().downcast().ok())
+    }
+
     pub async fn single_url_get(
         &self,
         input: String,
@@ -23,6 +23,7 @@ pub mod rex {
 
     use daft_dsl::functions::{python::PythonUDF, FunctionArgs, FunctionExpr, ScalarFunction, ScalarUDF};
     pub use daft_dsl::*;
+    pub use daft_core::join::JoinSide;
 
     /// Creates an expression from a python-scalar function
     pub fn from_py_func<A, E>(func: PythonUDF, args: A) -> Expr
@@ -68,7 +69,9 @@ pub mod rel {
     use common_error::DaftResult;
     pub use daft_logical_plan::*;
     pub use daft_logical_plan::ops::*;
-    use crate::schema::Schema;
+    pub use daft_logical_plan::partitioning::{HashRepartitionConfig, IntoPartitionsConfig, RandomShuffleConfig, RepartitionSpec};
+    use crate::rex::{WindowExpr, WindowSpec};
+    use crate::schema::{Field, Schema};
 
     /// Consider updating other set operators to use the setq as it's better practice.
     pub use daft_logical_plan::ops::SetQuantifier;
@@ -77,6 +80,9 @@ pub mod rel {
     /// Keep scan info together..
     pub use daft_logical_plan::source_info::*;
     pub use common_scan_info::PhysicalScanInfo;
+    pub use common_scan_info::{Sharder, ShardingStrategy};
+    /// SubqueryAlias is defined alongside the LogicalPlan rather than with the ops.
+    pub use daft_logical_plan::logical_plan::SubqueryAlias;
 
     /// Creates a new source relational operator.
     pub fn new_source<S, I>(schema: S, info: I) -> DaftResult<Source>
@@ -211,6 +217,192 @@ pub mod rel {
             Err(DaftError::InternalError("Expected LogicalPlan::Aggregate!".to_string()))
         }
     }
+
+    /// We just created this plan via the builder, so we immediately take back ownership of it.
+    fn into_plan(builder: LogicalPlanBuilder) -> LogicalPlan {
+        Arc::unwrap_or_clone(builder.plan)
+    }
+
+    /// Creates a new explode relational operator.
+    pub fn new_explode<I, P, E>(input: I, to_explode: P) -> DaftResult<Explode>
+    where
+        I: Into<Arc<LogicalPlan>>,
+        P: IntoIterator<Item = E>,
+        E: Into<Arc<Expr>>,
+    {
+        let input = input.into();
+        let to_explode = to_explode.into_iter().map(|e| e.into()).collect();
+        let builder = LogicalPlanBuilder::new(input, None).explode(to_explode)?;
+        match into_plan(builder) {
+            LogicalPlan::Explode(explode) => Ok(explode),
+            _ => Err(DaftError::InternalError("Expected LogicalPlan::Explode!".to_string())),
+        }
+    }
+
+    /// Creates a new unpivot relational operator.
+    pub fn new_unpivot<I, P, E>(input: I, ids: P, values: P, variable_name: String, value_name: String) -> DaftResult<Unpivot>
+    where
+        I: Into<Arc<LogicalPlan>>,
+        P: IntoIterator<Item = E>,
+        E: Into<Arc<Expr>>,
+    {
+        let input = input.into();
+        let ids = ids.into_iter().map(|e| e.into()).collect();
+        let values = values.into_iter().map(|e| e.into()).collect();
+        let builder = LogicalPlanBuilder::new(input, None).unpivot(ids, values, variable_name, value_name)?;
+        match into_plan(builder) {
+            LogicalPlan::Unpivot(unpivot) => Ok(unpivot),
+            _ => Err(DaftError::InternalError("Expected LogicalPlan::Unpivot!".to_string())),
+        }
+    }
+
+    /// Creates a new sort relational operator.
+    pub fn new_sort<I, P, E>(input: I, sort_by: P, descending: Vec<bool>, nulls_first: Vec<bool>) -> DaftResult<Sort>
+    where
+        I: Into<Arc<LogicalPlan>>,
+        P: IntoIterator<Item = E>,
+        E: Into<Arc<Expr>>,
+    {
+        let input: Arc<LogicalPlan> = input.into();
+        let sort_by: Vec<Arc<Expr>> = sort_by.into_iter().map(|e| e.into()).collect();
+        Ok(Sort { plan_id: None,
+            node_id: None, input, sort_by, descending, nulls_first, stats_state: stats::StatsState::NotMaterialized })
+    }
+
+    /// Creates a new repartition relational operator.
+    pub fn new_repartition<I>(input: I, repartition_spec: RepartitionSpec) -> DaftResult<Repartition>
+    where
+        I: Into<Arc<LogicalPlan>>,
+    {
+        let input: Arc<LogicalPlan> = input.into();
+        Ok(Repartition { plan_id: None,
+            node_id: None, input, repartition_spec, stats_state: stats::StatsState::NotMaterialized })
+    }
+
+    /// Creates a new pivot relational operator.
+    pub fn new_pivot<I, G, E, A>(input: I, groups: G, pivot_column: E, value_column: E, agg: A, names: Vec<String>) -> DaftResult<Pivot>
+    where
+        I: Into<Arc<LogicalPlan>>,
+        G: IntoIterator<Item = E>,
+        E: Into<Arc<Expr>>,
+        A: Into<Arc<Expr>>,
+    {
+        let input = input.into();
+        let group_by = groups.into_iter().map(|e| e.into()).collect();
+        let builder = LogicalPlanBuilder::new(input, None).pivot(group_by, pivot_column.into(), value_column.into(), agg.into(), names)?;
+        match into_plan(builder) {
+            LogicalPlan::Pivot(pivot) => Ok(pivot),
+            _ => Err(DaftError::InternalError("Expected LogicalPlan::Pivot!".to_string())),
+        }
+    }
+
+    /// Creates a new join relational operator, the predicate must only contain join-side columns.
+    pub fn new_join<R, P>(lhs: R, rhs: R, on: Option<P>, join_type: JoinType, join_strategy: Option<JoinStrategy>) -> DaftResult<Join>
+    where
+        R: Into<Arc<LogicalPlan>>,
+        P: Into<Arc<Expr>>,
+    {
+        let left: Arc<LogicalPlan> = lhs.into();
+        let right: Arc<LogicalPlan> = rhs.into();
+        let on = join::JoinPredicate::try_new(on.map(|p| p.into()))?;
+        let output_schema = daft_dsl::join::infer_join_schema(&left.schema(), &right.schema(), join_type)?;
+        Ok(Join { plan_id: None,
+            node_id: None, left, right, on, join_type, join_strategy, output_schema, stats_state: stats::StatsState::NotMaterialized })
+    }
+
+    /// Creates a new sink relational operator, the schema is given because it depends on the sink.
+    pub fn new_sink<I, S>(input: I, schema: S, sink_info: Arc<SinkInfo>) -> DaftResult<Sink>
+    where
+        I: Into<Arc<LogicalPlan>>,
+        S: Into<Arc<Schema>>,
+    {
+        let input: Arc<LogicalPlan> = input.into();
+        Ok(Sink { plan_id: None,
+            node_id: None, input, schema: schema.into(), sink_info, stats_state: stats::StatsState::NotMaterialized })
+    }
+
+    /// Creates a new sample relational operator.
+    pub fn new_sample<I>(input: I, fraction: f64, with_replacement: bool, seed: Option<u64>) -> DaftResult<Sample>
+    where
+        I: Into<Arc<LogicalPlan>>,
+    {
+        let input: Arc<LogicalPlan> = input.into();
+        Ok(Sample { plan_id: None,
+            node_id: None, input, fraction, with_replacement, seed, stats_state: stats::StatsState::NotMaterialized })
+    }
+
+    /// Creates a new monotonically increasing id relational operator.
+    pub fn new_monotonically_increasing_id<I>(input: I, column_name: &str, starting_offset: Option<u64>) -> DaftResult<MonotonicallyIncreasingId>
+    where
+        I: Into<Arc<LogicalPlan>>,
+    {
+        let input = input.into();
+        let builder = LogicalPlanBuilder::new(input, None).add_monotonically_increasing_id(Some(column_name), starting_offset)?;
+        match into_plan(builder) {
+            LogicalPlan::MonotonicallyIncreasingId(monotonically_increasing_id) => Ok(monotonically_increasing_id),
+            _ => Err(DaftError::InternalError("Expected LogicalPlan::MonotonicallyIncreasingId!".to_string())),
+        }
+    }
+
+    /// Creates a new subquery alias relational operator.
+    pub fn new_subquery_alias<I>(input: I, name: &str) -> DaftResult<SubqueryAlias>
+    where
+        I: Into<Arc<LogicalPlan>>,
+    {
+        Ok(SubqueryAlias::new(input.into(), name))
+    }
+
+    /// Creates a new window relational operator.
+    pub fn new_window<I>(input: I, window_functions: Vec<WindowExpr>, aliases: Vec<String>, window_spec: WindowSpec) -> DaftResult<Window>
+    where
+        I: Into<Arc<LogicalPlan>>,
+    {
+        // Same as the package private constructor, the window columns are appended to the input.
+        let input: Arc<LogicalPlan> = input.into();
+        let input_schema = input.schema();
+        let mut fields: Vec<Field> = input_schema.into_iter().cloned().collect();
+        for (alias, window_function) in aliases.iter().zip(window_functions.iter()) {
+            let dtype = window_function.to_field(&input_schema)?.dtype;
+            fields.push(Field::new(alias, dtype));
+        }
+        let schema = Arc::new(Schema::new(fields));
+        Ok(Window { plan_id: None,
+            node_id: None, input, window_functions, aliases, window_spec, schema, stats_state: stats::StatsState::NotMaterialized })
+    }
+
+    /// Creates a new top-n relational operator.
+    pub fn new_top_n<I, P, E>(input: I, sort_by: P, descending: Vec<bool>, nulls_first: Vec<bool>, limit: u64) -> DaftResult<TopN>
+    where
+        I: Into<Arc<LogicalPlan>>,
+        P: IntoIterator<Item = E>,
+        E: Into<Arc<Expr>>,
+    {
+        let input: Arc<LogicalPlan> = input.into();
+        let sort_by: Vec<Arc<Expr>> = sort_by.into_iter().map(|e| e.into()).collect();
+        Ok(TopN { plan_id: None,
+            node_id: None, input, sort_by, descending, nulls_first, limit, stats_state: stats::StatsState::NotMaterialized })
+    }
+
+    /// Creates a new shard relational operator.
+    pub fn new_shard<I>(input: I, sharder: Sharder) -> DaftResult<Shard>
+    where
+        I: Into<Arc<LogicalPlan>>,
+    {
+        let input: Arc<LogicalPlan> = input.into();
+        Ok(Shard { plan_id: None,
+            node_id: None, input, sharder, stats_state: stats::StatsState::NotMaterialized })
+    }
+
+    /// Creates a new recursive cte relational operator whose recursive term reads the working table by its cache key.
+    pub fn new_recursive_cte<R>(name: &str, working_table_key: String, anchor: R, recursive: R, is_all: bool) -> DaftResult<RecursiveCte>
+    where
+        R: Into<Arc<LogicalPlan>>,
+    {
+        let anchor = anchor.into();
+        let recursive = recursive.into();
+        Ok(RecursiveCte { plan_id: None,
+            node_id: None, name: name.into(), working_table_key, anchor, recursive, is_all, stats_state: stats::StatsState::NotMaterialized })
+    }
 }
 
 /// Flatten the daft_schema package, consider the prelude.
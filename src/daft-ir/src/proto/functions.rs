@@ -1,8 +1,8 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use super::ProtoResult;
 use crate::{
-    from_proto_err, non_null, not_implemented_err,
+    from_proto_err, non_null,
     proto::{from_proto, from_proto_vec, to_proto_vec, ToFromProto},
 };
 
@@ -37,14 +37,9 @@ pub fn from_proto_function(message: proto::Function) -> ProtoResult<ir::Expr> {
         }
         proto::FunctionDescriptor::Rs(rs) => {
             // handle special form, otherwise it's a ScalarFunction
-            // match from_special_form(&rs)? {
-            //     Some(_) => {
-            //         //
-            //         not_implemented_err!("special forms for scalar functions")
-            //     },
-            //     None => {
-            //     }
-            // }
+            if let Some(expr) = from_special_form(&rs.name, args.clone())? {
+                return Ok(expr);
+            }
             // Daft currently does not have static function resolution, once implemented, then
             // we will be resolving to *concrete implementations* of functions based upon type
             // signatures via string mangling or other techniques. For now, it suffices to lookup
@@ -69,37 +64,26 @@ pub fn function_expr_to_proto(
     func: &ir::functions::FunctionExpr,
     args: &[ir::ExprRef],
 ) -> ProtoResult<proto::Function> {
-    // build the args
+    // Convert all arguments to unbound arguments (aka no param name) then reuse existing conversion logic.
+    let mut function_args: Vec<_> = args
+        .iter()
+        .map(|arg| ir::functions::FunctionArg::Unnamed(arg.clone()))
+        .collect();
 
-    // switch
+    // switch, special forms append their parameters as named arguments.
     let descriptor = match func {
-        ir::functions::FunctionExpr::Map(map_expr) => {
-            let rs = map_expr.to_proto()?;
-            proto::FunctionDescriptor::Rs(rs)
-        }
-        ir::functions::FunctionExpr::Sketch(sketch_expr) => {
-            let rs = sketch_expr.to_proto()?;
-            proto::FunctionDescriptor::Rs(rs)
-        }
-        ir::functions::FunctionExpr::Struct(struct_expr) => {
-            let rs = struct_expr.to_proto()?;
-            proto::FunctionDescriptor::Rs(rs)
-        }
-        ir::functions::FunctionExpr::Partitioning(partitioning_expr) => {
-            let rs = partitioning_expr.to_proto()?;
-            proto::FunctionDescriptor::Rs(rs)
-        }
         ir::functions::FunctionExpr::Python(python_udf) => {
             let py = python_udf.to_proto()?;
             proto::FunctionDescriptor::Py(py)
         }
+        _ => {
+            let (name, params) = to_special_form(func)?;
+            function_args.extend(params);
+            proto::FunctionDescriptor::Rs(proto::function::RsFunction {
+                name: name.to_string(),
+            })
+        }
     };
-
-    // Convert all arguments to unbound arguments (aka no param name) then reuse existing conversion logic.
-    let function_args = args
-        .iter()
-        .map(|arg| ir::functions::FunctionArg::Unnamed(arg.clone()))
-        .collect();
     let function_args = ir::functions::FunctionArgs::new_unchecked(function_args);
     let args = function_args.to_proto()?;
 
@@ -200,7 +184,7 @@ impl ToFromProto for ir::functions::FunctionArg<ir::ExprRef> {
     }
 }
 
-/// Returns some ToFromProto type for the special form magic strings.
+/// Returns the special form magic string and its parameters as named arguments.
 ///
 /// Note:
 /// This lets us consolidate the modeling of scalar functions in the protos while
@@ -212,116 +196,166 @@ impl ToFromProto for ir::functions::FunctionArg<ir::ExprRef> {
 /// transform" special form since its pattern matched elsewhere, then making the
 /// python UDF its own thing. I've chose to model all as builtins because it's
 /// quite simple to go in/out at the expense of some hackery.
-#[allow(unused)]
-fn from_special_form(
-    message: proto::function::RsFunction,
-) -> ProtoResult<Option<ir::functions::FunctionExpr>> {
-    let sf = match message.name.as_str() {
-        "_map_get" => {
-            let map_expr = ir::functions::map::MapExpr::from_proto(message)?;
-            ir::functions::FunctionExpr::Map(map_expr)
-        }
-        "_sketch_percentile" => {
-            let sketch_expr = ir::functions::sketch::SketchExpr::from_proto(message)?;
-            ir::functions::FunctionExpr::Sketch(sketch_expr)
-        }
-        "_struct_get" => {
-            let struct_expr = ir::functions::struct_::StructExpr::from_proto(message)?;
-            ir::functions::FunctionExpr::Struct(struct_expr)
-        }
-        // Interestingly, we have common_scan_info::partitioning and functions::partitioning
-        "_partitioning_years" => {
-            let partitioning_expr =
-                ir::functions::partitioning::PartitioningExpr::from_proto(message)?;
-            ir::functions::FunctionExpr::Partitioning(partitioning_expr)
-        }
-        "_partitioning_months" => {
-            let partitioning_expr =
-                ir::functions::partitioning::PartitioningExpr::from_proto(message)?;
-            ir::functions::FunctionExpr::Partitioning(partitioning_expr)
+fn to_special_form(
+    func: &ir::functions::FunctionExpr,
+) -> ProtoResult<(&'static str, Vec<ir::functions::FunctionArg<ir::ExprRef>>)> {
+    use ir::functions::{
+        map::MapExpr, partitioning::PartitioningExpr, sketch::SketchExpr, struct_::StructExpr,
+        FunctionArg, FunctionExpr,
+    };
+    let lit = |value: ir::rex::LiteralValue| Arc::new(ir::Expr::Literal(value));
+    let special_form = match func {
+        FunctionExpr::Map(MapExpr::Get) => ("_map_get", vec![]),
+        FunctionExpr::Sketch(SketchExpr::Percentile {
+            percentiles,
+            force_list_output,
+        }) => {
+            let percentiles = percentiles
+                .0
+                .iter()
+                .map(|p| lit(ir::rex::LiteralValue::Float64(*p)))
+                .collect();
+            let params = vec![
+                FunctionArg::named("percentiles", ir::Expr::List(percentiles).into()),
+                FunctionArg::named(
+                    "force_list_output",
+                    lit(ir::rex::LiteralValue::Boolean(*force_list_output)),
+                ),
+            ];
+            ("_sketch_percentile", params)
         }
-        "_partitioning_days" => {
-            let partitioning_expr =
-                ir::functions::partitioning::PartitioningExpr::from_proto(message)?;
-            ir::functions::FunctionExpr::Partitioning(partitioning_expr)
+        FunctionExpr::Struct(StructExpr::Get(name)) => {
+            let params = vec![FunctionArg::named(
+                "name",
+                lit(ir::rex::LiteralValue::Utf8(name.clone())),
+            )];
+            ("_struct_get", params)
         }
-        "_partitioning_hours" => {
-            let partitioning_expr =
-                ir::functions::partitioning::PartitioningExpr::from_proto(message)?;
-            ir::functions::FunctionExpr::Partitioning(partitioning_expr)
+        FunctionExpr::Partitioning(PartitioningExpr::Years) => ("_partitioning_years", vec![]),
+        FunctionExpr::Partitioning(PartitioningExpr::Months) => ("_partitioning_months", vec![]),
+        FunctionExpr::Partitioning(PartitioningExpr::Days) => ("_partitioning_days", vec![]),
+        FunctionExpr::Partitioning(PartitioningExpr::Hours) => ("_partitioning_hours", vec![]),
+        FunctionExpr::Partitioning(PartitioningExpr::IcebergBucket(n)) => {
+            let params = vec![FunctionArg::named(
+                "n",
+                lit(ir::rex::LiteralValue::Int32(*n)),
+            )];
+            ("_partitioning_iceberg_bucket", params)
         }
-        "_partitioning_iceberg_bucket" => {
-            let partitioning_expr =
-                ir::functions::partitioning::PartitioningExpr::from_proto(message)?;
-            ir::functions::FunctionExpr::Partitioning(partitioning_expr)
+        FunctionExpr::Partitioning(PartitioningExpr::IcebergTruncate(w)) => {
+            let params = vec![FunctionArg::named(
+                "w",
+                lit(ir::rex::LiteralValue::Int64(*w)),
+            )];
+            ("_partitioning_iceberg_truncate", params)
         }
-        "_partitioning_iceberg_truncate" => {
-            let partitioning_expr =
-                ir::functions::partitioning::PartitioningExpr::from_proto(message)?;
-            ir::functions::FunctionExpr::Partitioning(partitioning_expr)
+        FunctionExpr::Python(_) => {
+            crate::to_proto_err!("python functions are not special forms")
         }
-        _ => return Ok(None),
     };
-    Ok(Some(sf))
-}
-
-impl ToFromProto for ir::functions::map::MapExpr {
-    type Message = proto::function::RsFunction;
-
-    fn from_proto(_: Self::Message) -> ProtoResult<Self>
-    where
-        Self: Sized,
-    {
-        not_implemented_err!("map_expr")
-    }
-
-    fn to_proto(&self) -> ProtoResult<Self::Message> {
-        not_implemented_err!("map_expr")
-    }
+    Ok(special_form)
 }
 
-impl ToFromProto for ir::functions::sketch::SketchExpr {
-    type Message = proto::function::RsFunction;
-
-    fn from_proto(_: Self::Message) -> ProtoResult<Self>
-    where
-        Self: Sized,
-    {
-        not_implemented_err!("sketch_expr")
-    }
-
-    fn to_proto(&self) -> ProtoResult<Self::Message> {
-        not_implemented_err!("sketch_expr")
+/// Returns the special form expression for the magic strings, or None for other functions.
+fn from_special_form(
+    name: &str,
+    args: ir::functions::FunctionArgs<ir::ExprRef>,
+) -> ProtoResult<Option<ir::Expr>> {
+    use ir::functions::{
+        map::MapExpr, partitioning::PartitioningExpr, sketch::SketchExpr, struct_::StructExpr,
+        FunctionExpr,
+    };
+    let is_special_form = matches!(
+        name,
+        "_map_get"
+            | "_sketch_percentile"
+            | "_struct_get"
+            | "_partitioning_years"
+            | "_partitioning_months"
+            | "_partitioning_days"
+            | "_partitioning_hours"
+            | "_partitioning_iceberg_bucket"
+            | "_partitioning_iceberg_truncate"
+    );
+    if !is_special_form {
+        return Ok(None);
     }
+    let (inputs, params) = args.into_unnamed_and_named()?;
+    let func = match name {
+        "_map_get" => FunctionExpr::Map(MapExpr::Get),
+        "_sketch_percentile" => {
+            let percentiles = match special_form_param(name, &params, "percentiles")?.as_ref() {
+                ir::Expr::List(items) => items
+                    .iter()
+                    .map(|item| match item.as_ref() {
+                        ir::Expr::Literal(ir::rex::LiteralValue::Float64(p)) => Ok(*p),
+                        _ => from_proto_err!("expected float percentiles, found: {}", item),
+                    })
+                    .collect::<ProtoResult<Vec<_>>>()?,
+                param => from_proto_err!("expected a list of percentiles, found: {}", param),
+            };
+            let force_list_output = match special_form_literal(name, &params, "force_list_output")?
+            {
+                ir::rex::LiteralValue::Boolean(b) => b,
+                param => from_proto_err!("expected a boolean force_list_output, found: {}", param),
+            };
+            FunctionExpr::Sketch(SketchExpr::Percentile {
+                percentiles: ir::functions::sketch::HashableVecPercentiles(percentiles),
+                force_list_output,
+            })
+        }
+        "_struct_get" => match special_form_literal(name, &params, "name")? {
+            ir::rex::LiteralValue::Utf8(field) => FunctionExpr::Struct(StructExpr::Get(field)),
+            param => from_proto_err!("expected a string field name, found: {}", param),
+        },
+        // Interestingly, we have common_scan_info::partitioning and functions::partitioning
+        "_partitioning_years" => FunctionExpr::Partitioning(PartitioningExpr::Years),
+        "_partitioning_months" => FunctionExpr::Partitioning(PartitioningExpr::Months),
+        "_partitioning_days" => FunctionExpr::Partitioning(PartitioningExpr::Days),
+        "_partitioning_hours" => FunctionExpr::Partitioning(PartitioningExpr::Hours),
+        "_partitioning_iceberg_bucket" => match special_form_literal(name, &params, "n")? {
+            ir::rex::LiteralValue::Int32(n) => {
+                FunctionExpr::Partitioning(PartitioningExpr::IcebergBucket(n))
+            }
+            param => from_proto_err!("expected an int32 number of buckets, found: {}", param),
+        },
+        "_partitioning_iceberg_truncate" => match special_form_literal(name, &params, "w")? {
+            ir::rex::LiteralValue::Int64(w) => {
+                FunctionExpr::Partitioning(PartitioningExpr::IcebergTruncate(w))
+            }
+            param => from_proto_err!("expected an int64 truncate width, found: {}", param),
+        },
+        _ => unreachable!("{} is not a special form", name),
+    };
+    Ok(Some(ir::Expr::Function { func, inputs }))
 }
 
-impl ToFromProto for ir::functions::struct_::StructExpr {
-    type Message = proto::function::RsFunction;
-
-    fn from_proto(_: Self::Message) -> ProtoResult<Self>
-    where
-        Self: Sized,
-    {
-        not_implemented_err!("struct_expr")
-    }
-
-    fn to_proto(&self) -> ProtoResult<Self::Message> {
-        not_implemented_err!("struct_expr")
+/// Returns a special form's parameter which was passed as a named argument.
+fn special_form_param(
+    name: &str,
+    params: &HashMap<Arc<str>, ir::ExprRef>,
+    param: &str,
+) -> ProtoResult<ir::ExprRef> {
+    match params.get(param) {
+        Some(expr) => Ok(expr.clone()),
+        None => from_proto_err!("{} is missing the `{}` parameter", name, param),
     }
 }
 
-impl ToFromProto for ir::functions::partitioning::PartitioningExpr {
-    type Message = proto::function::RsFunction;
-
-    fn from_proto(_: Self::Message) -> ProtoResult<Self>
-    where
-        Self: Sized,
-    {
-        not_implemented_err!("partitioning_expr")
-    }
-
-    fn to_proto(&self) -> ProtoResult<Self::Message> {
-        not_implemented_err!("partitioning_expr")
+/// Returns a special form's parameter which must be a literal.
+fn special_form_literal(
+    name: &str,
+    params: &HashMap<Arc<str>, ir::ExprRef>,
+    param: &str,
+) -> ProtoResult<ir::rex::LiteralValue> {
+    match special_form_param(name, params, param)?.as_ref() {
+        ir::Expr::Literal(value) => Ok(value.clone()),
+        expr => from_proto_err!(
+            "expected a literal `{}` for {}, found: {}",
+            param,
+            name,
+            expr
+        ),
     }
 }
 
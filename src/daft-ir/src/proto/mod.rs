@@ -3,6 +3,8 @@ pub mod rex;
 pub mod functions;
 pub mod rel;
 pub mod schema;
+#[cfg(test)]
+mod tests;
 
 use std::sync::Arc;
 
@@ -77,6 +79,46 @@ pub(crate) fn to_protos(
     exprs.iter().map(|e| e.to_proto()).collect()
 }
 
+/// Daft holds sort keys and their options in parallel vectors, so zip them into sort orders.
+pub(crate) fn to_sort_orders(
+    sort_by: &[crate::ExprRef],
+    descending: &[bool],
+    nulls_first: &[bool],
+) -> ProtoResult<Vec<daft_proto::protos::daft::v1::SortOrder>> {
+    if sort_by.len() != descending.len() || sort_by.len() != nulls_first.len() {
+        crate::to_proto_err!(
+            "expected as many sort options as sort keys, found {} keys, {} descending and {} nulls_first",
+            sort_by.len(),
+            descending.len(),
+            nulls_first.len()
+        )
+    }
+    let mut sort_orders = vec![];
+    for ((expr, descending), nulls_first) in sort_by.iter().zip(descending).zip(nulls_first) {
+        sort_orders.push(daft_proto::protos::daft::v1::SortOrder {
+            expr: Some(expr.to_proto()?),
+            ascending: !descending,
+            nulls_first: *nulls_first,
+        });
+    }
+    Ok(sort_orders)
+}
+
+/// Unzips sort orders into the sort keys, descending and nulls_first vectors.
+pub(crate) fn from_sort_orders(
+    sort_orders: Vec<daft_proto::protos::daft::v1::SortOrder>,
+) -> ProtoResult<(Vec<crate::ExprRef>, Vec<bool>, Vec<bool>)> {
+    let mut sort_by = vec![];
+    let mut descending = vec![];
+    let mut nulls_first = vec![];
+    for sort_order in sort_orders {
+        sort_by.push(from_proto(sort_order.expr)?);
+        descending.push(!sort_order.ascending);
+        nulls_first.push(sort_order.nulls_first);
+    }
+    Ok((sort_by, descending, nulls_first))
+}
+
 /// Maps some daft-ir Vec<T> into a Vec<M> without taking ownership.
 pub(crate) fn to_proto_vec<'a, I, T, M>(iter: I) -> ProtoResult<Vec<M>>
 where
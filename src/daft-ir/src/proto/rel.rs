@@ -4,8 +4,11 @@ use daft_logical_plan::ops::ActorPoolProject;
 
 use super::{ProtoResult, ToFromProto};
 use crate::{
-    non_null, not_implemented_err, not_optimized_err,
-    proto::{from_proto, from_proto_arc, from_protos, to_proto_vec, to_protos, UNIT},
+    from_proto_err, non_null, not_optimized_err,
+    proto::{
+        from_proto, from_proto_arc, from_proto_vec, from_protos, from_sort_orders, to_proto_vec,
+        to_protos, to_sort_orders, UNIT,
+    },
 };
 
 /// Export daft_ir types under an `ir` namespace to concisely disambiguate domains.
@@ -21,9 +24,9 @@ mod proto {
     pub use daft_proto::protos::daft::v1::rel::Variant as RelVariant;
     pub use daft_proto::protos::daft::v1::source_info::Variant as SourceInfoVariant;
     pub use daft_proto::protos::daft::v1::partition_transform::Variant as PartitionTransformVariant;
+    pub use daft_proto::protos::daft::v1::rel_repartition::Spec as RepartitionSpec;
 }
 
-#[allow(unused)]
 impl ToFromProto for ir::rel::LogicalPlan {
     type Message = proto::Rel;
 
@@ -54,24 +57,20 @@ impl ToFromProto for ir::rel::LogicalPlan {
                 Self::Limit(limit)
             }
             proto::RelVariant::Explode(explode) => {
-                not_implemented_err!("explode");
-                // let explode = ir::rel::Explode::from_proto(*explode)?;
-                // Self::Explode(explode)
+                let explode = ir::rel::Explode::from_proto(*explode)?;
+                Self::Explode(explode)
             }
             proto::RelVariant::Unpivot(unpivot) => {
-                not_implemented_err!("unpivot");
-                // let unpivot = ir::rel::Unpivot::from_proto(*unpivot)?;
-                // Self::Unpivot(unpivot)
+                let unpivot = ir::rel::Unpivot::from_proto(*unpivot)?;
+                Self::Unpivot(unpivot)
             }
             proto::RelVariant::Sort(sort) => {
-                not_implemented_err!("sort");
-                // let sort = ir::rel::Sort::from_proto(*sort)?;
-                // Self::Sort(sort)
+                let sort = ir::rel::Sort::from_proto(*sort)?;
+                Self::Sort(sort)
             }
             proto::RelVariant::Repartition(repartition) => {
-                not_implemented_err!("repartition");
-                // let repartition = ir::rel::Repartition::from_proto(*repartition)?;
-                // Self::Repartition(repartition)
+                let repartition = ir::rel::Repartition::from_proto(*repartition)?;
+                Self::Repartition(repartition)
             }
             proto::RelVariant::Distinct(distinct) => {
                 let distinct = ir::rel::Distinct::from_proto(*distinct)?;
@@ -82,9 +81,8 @@ impl ToFromProto for ir::rel::LogicalPlan {
                 Self::Aggregate(aggregate)
             }
             proto::RelVariant::Pivot(pivot) => {
-                not_implemented_err!("pivot");
-                // let pivot = ir::rel::Pivot::from_proto(*pivot)?;
-                // Self::Pivot(pivot)
+                let pivot = ir::rel::Pivot::from_proto(*pivot)?;
+                Self::Pivot(pivot)
             }
             proto::RelVariant::Concat(concat) => {
                 let concat = ir::rel::Concat::from_proto(*concat)?;
@@ -106,39 +104,41 @@ impl ToFromProto for ir::rel::LogicalPlan {
                 Self::Intersect(intersect)
             }
             proto::RelVariant::Join(join) => {
-                not_implemented_err!("join");
-                // let join = ir::rel::Join::from_proto(*join)?;
-                // Self::Join(join)
+                let join = ir::rel::Join::from_proto(*join)?;
+                Self::Join(join)
             }
             proto::RelVariant::Sink(sink) => {
-                not_implemented_err!("sink");
-                // let sink = ir::rel::Sink::from_proto(*sink)?;
-                // Self::Sink(sink)
+                let sink = ir::rel::Sink::from_proto(*sink)?;
+                Self::Sink(sink)
             }
             proto::RelVariant::Sample(sample) => {
-                not_implemented_err!("sample");
-                // let sample = ir::rel::Sample::from_proto(*sample)?;
-                // Self::Sample(sample)
+                let sample = ir::rel::Sample::from_proto(*sample)?;
+                Self::Sample(sample)
             }
             proto::RelVariant::MonotonicallyIncreasingId(monotonically_increasing_id) => {
-                not_implemented_err!("monotonically_increasing_id");
-                // let monotonically_increasing_id = ir::rel::MonotonicallyIncreasingId::from_proto(*monotonically_increasing_id)?;
-                // Self::MonotonicallyIncreasingId(monotonically_increasing_id)
+                let monotonically_increasing_id =
+                    ir::rel::MonotonicallyIncreasingId::from_proto(*monotonically_increasing_id)?;
+                Self::MonotonicallyIncreasingId(monotonically_increasing_id)
             }
             proto::RelVariant::SubqueryAlias(subquery_alias) => {
-                not_implemented_err!("subquery_alias");
-                // let subquery_alias = ir::rel::SubqueryAlias::from_proto(*subquery_alias)?;
-                // Self::SubqueryAlias(subquery_alias)
+                let subquery_alias = ir::rel::SubqueryAlias::from_proto(*subquery_alias)?;
+                Self::SubqueryAlias(subquery_alias)
             }
             proto::RelVariant::Window(window) => {
-                not_implemented_err!("window");
-                // let window = ir::rel::Window::from_proto(*window)?;
-                // Self::Window(window)
+                let window = ir::rel::Window::from_proto(*window)?;
+                Self::Window(window)
             }
             proto::RelVariant::TopN(top_n) => {
-                not_implemented_err!("top_n");
-                // let top_n = ir::rel::TopN::from_proto(*top_n)?;
-                // Self::TopN(top_n)
+                let top_n = ir::rel::TopN::from_proto(*top_n)?;
+                Self::TopN(top_n)
+            }
+            proto::RelVariant::Shard(shard) => {
+                let shard = ir::rel::Shard::from_proto(*shard)?;
+                Self::Shard(shard)
+            }
+            proto::RelVariant::RecursiveCte(recursive_cte) => {
+                let recursive_cte = ir::rel::RecursiveCte::from_proto(*recursive_cte)?;
+                Self::RecursiveCte(recursive_cte)
             }
         };
         Ok(plan)
@@ -150,8 +150,9 @@ impl ToFromProto for ir::rel::LogicalPlan {
                 let source = source.to_proto()?.into();
                 proto::RelVariant::Source(source)
             }
-            Self::Shard(_) => {
-                not_implemented_err!("shard");
+            Self::Shard(shard) => {
+                let shard = shard.to_proto()?.into();
+                proto::RelVariant::Shard(shard)
             }
             Self::Project(project) => {
                 let project = project.to_proto()?.into();
@@ -170,24 +171,20 @@ impl ToFromProto for ir::rel::LogicalPlan {
                 proto::RelVariant::Limit(limit)
             }
             Self::Explode(explode) => {
-                not_implemented_err!("explode");
-                // let explode = explode.to_proto()?.into();
-                // proto::RelVariant::Explode(explode)
+                let explode = explode.to_proto()?.into();
+                proto::RelVariant::Explode(explode)
             }
             Self::Unpivot(unpivot) => {
-                not_implemented_err!("unpivot");
-                // let unpivot = unpivot.to_proto()?.into();
-                // proto::RelVariant::Unpivot(unpivot)
+                let unpivot = unpivot.to_proto()?.into();
+                proto::RelVariant::Unpivot(unpivot)
             }
             Self::Sort(sort) => {
-                not_implemented_err!("sort");
-                // let sort = sort.to_proto()?.into();
-                // proto::RelVariant::Sort(sort)
+                let sort = sort.to_proto()?.into();
+                proto::RelVariant::Sort(sort)
             }
             Self::Repartition(repartition) => {
-                not_implemented_err!("repartition");
-                // let repartition = repartition.to_proto()?.into();
-                // proto::RelVariant::Repartition(repartition)
+                let repartition = repartition.to_proto()?.into();
+                proto::RelVariant::Repartition(repartition)
             }
             Self::Distinct(distinct) => {
                 let distinct = distinct.to_proto()?.into();
@@ -198,9 +195,8 @@ impl ToFromProto for ir::rel::LogicalPlan {
                 proto::RelVariant::Aggregate(aggregate)
             }
             Self::Pivot(pivot) => {
-                not_implemented_err!("pivot");
-                // let pivot = pivot.to_proto()?.into();
-                // proto::RelVariant::Pivot(pivot)
+                let pivot = pivot.to_proto()?.into();
+                proto::RelVariant::Pivot(pivot)
             }
             Self::Concat(concat) => {
                 let concat = concat.to_proto()?.into();
@@ -215,39 +211,36 @@ impl ToFromProto for ir::rel::LogicalPlan {
                 proto::RelVariant::Union(union_)
             }
             Self::Join(join) => {
-                not_implemented_err!("join");
-                // let join = join.to_proto()?.into();
-                // proto::RelVariant::Join(join)
+                let join = join.to_proto()?.into();
+                proto::RelVariant::Join(join)
             }
             Self::Sink(sink) => {
-                not_implemented_err!("sink");
-                // let sink = sink.to_proto()?.into();
-                // proto::RelVariant::Sink(sink)
+                let sink = sink.to_proto()?.into();
+                proto::RelVariant::Sink(sink)
             }
             Self::Sample(sample) => {
-                not_implemented_err!("sample");
-                // let sample = sample.to_proto()?.into();
-                // proto::RelVariant::Sample(sample)
+                let sample = sample.to_proto()?.into();
+                proto::RelVariant::Sample(sample)
             }
             Self::MonotonicallyIncreasingId(monotonically_increasing_id) => {
-                not_implemented_err!("monotonically_increasing_id");
-                // let monotonically_increasing_id = monotonically_increasing_id.to_proto()?.into();
-                // proto::RelVariant::MonotonicallyIncreasingId(monotonically_increasing_id)
+                let monotonically_increasing_id = monotonically_increasing_id.to_proto()?.into();
+                proto::RelVariant::MonotonicallyIncreasingId(monotonically_increasing_id)
             }
             Self::SubqueryAlias(subquery_alias) => {
-                not_implemented_err!("subquery_alias");
-                // let subquery_alias = subquery_alias.to_proto()?.into();
-                // proto::RelVariant::SubqueryAlias(subquery_alias)
+                let subquery_alias = subquery_alias.to_proto()?.into();
+                proto::RelVariant::SubqueryAlias(subquery_alias)
             }
             Self::Window(window) => {
-                not_implemented_err!("window");
-                // let window = window.to_proto()?.into();
-                // proto::RelVariant::Window(window)
+                let window = window.to_proto()?.into();
+                proto::RelVariant::Window(window)
             }
             Self::TopN(top_n) => {
-                not_implemented_err!("top_n");
-                // let top_n = top_n.to_proto()?.into();
-                // proto::RelVariant::TopN(top_n)
+                let top_n = top_n.to_proto()?.into();
+                proto::RelVariant::TopN(top_n)
+            }
+            Self::RecursiveCte(recursive_cte) => {
+                let recursive_cte = recursive_cte.to_proto()?.into();
+                proto::RelVariant::RecursiveCte(recursive_cte)
             }
         };
         Ok(Self::Message {
@@ -534,6 +527,424 @@ impl ToFromProto for ir::rel::Aggregate {
     }
 }
 
+impl ToFromProto for ir::rel::Explode {
+    type Message = proto::RelExplode;
+
+    fn from_proto(message: Self::Message) -> ProtoResult<Self>
+    where
+        Self: Sized,
+    {
+        let input = from_proto_arc(message.input)?;
+        let to_explode = from_protos(message.projections)?;
+        Ok(ir::rel::new_explode(input, to_explode)?)
+    }
+
+    fn to_proto(&self) -> ProtoResult<Self::Message> {
+        let input = self.input.to_proto()?.into();
+        let projections = to_protos(&self.to_explode)?;
+        Ok(Self::Message {
+            input: Some(input),
+            projections,
+        })
+    }
+}
+
+impl ToFromProto for ir::rel::Unpivot {
+    type Message = proto::RelUnpivot;
+
+    fn from_proto(message: Self::Message) -> ProtoResult<Self>
+    where
+        Self: Sized,
+    {
+        let input = from_proto_arc(message.input)?;
+        let ids = from_protos(message.ids)?;
+        let values = from_protos(message.values)?;
+        Ok(ir::rel::new_unpivot(
+            input,
+            ids,
+            values,
+            message.variable_name,
+            message.value_name,
+        )?)
+    }
+
+    fn to_proto(&self) -> ProtoResult<Self::Message> {
+        let input = self.input.to_proto()?.into();
+        let ids = to_protos(&self.ids)?;
+        let values = to_protos(&self.values)?;
+        Ok(Self::Message {
+            input: Some(input),
+            ids,
+            values,
+            variable_name: self.variable_name.clone(),
+            value_name: self.value_name.clone(),
+        })
+    }
+}
+
+impl ToFromProto for ir::rel::Sort {
+    type Message = proto::RelSort;
+
+    fn from_proto(message: Self::Message) -> ProtoResult<Self>
+    where
+        Self: Sized,
+    {
+        let input = from_proto_arc(message.input)?;
+        let (sort_by, descending, nulls_first) = from_sort_orders(message.sort_by)?;
+        Ok(ir::rel::new_sort(input, sort_by, descending, nulls_first)?)
+    }
+
+    fn to_proto(&self) -> ProtoResult<Self::Message> {
+        let input = self.input.to_proto()?.into();
+        let sort_by = to_sort_orders(&self.sort_by, &self.descending, &self.nulls_first)?;
+        Ok(Self::Message {
+            input: Some(input),
+            sort_by,
+        })
+    }
+}
+
+impl ToFromProto for ir::rel::Repartition {
+    type Message = proto::RelRepartition;
+
+    fn from_proto(message: Self::Message) -> ProtoResult<Self>
+    where
+        Self: Sized,
+    {
+        let input = from_proto_arc(message.input)?;
+        let spec = match non_null!(message.spec) {
+            proto::RepartitionSpec::Hash(hash) => {
+                let num_partitions = hash.num_partitions.map(|n| n as usize);
+                let by = from_protos(hash.by)?.into_iter().map(Arc::new).collect();
+                ir::rel::RepartitionSpec::Hash(ir::rel::HashRepartitionConfig::new(
+                    num_partitions,
+                    by,
+                ))
+            }
+            proto::RepartitionSpec::Random(random) => {
+                let num_partitions = random.num_partitions.map(|n| n as usize);
+                ir::rel::RepartitionSpec::Random(ir::rel::RandomShuffleConfig::new(num_partitions))
+            }
+            proto::RepartitionSpec::IntoPartitions(into_partitions) => {
+                let num_partitions = into_partitions.num_partitions as usize;
+                ir::rel::RepartitionSpec::IntoPartitions(ir::rel::IntoPartitionsConfig::new(
+                    num_partitions,
+                ))
+            }
+        };
+        Ok(ir::rel::new_repartition(input, spec)?)
+    }
+
+    fn to_proto(&self) -> ProtoResult<Self::Message> {
+        let input = self.input.to_proto()?.into();
+        let spec = match &self.repartition_spec {
+            ir::rel::RepartitionSpec::Hash(config) => {
+                proto::RepartitionSpec::Hash(proto::rel_repartition::Hash {
+                    num_partitions: config.num_partitions.map(|n| n as u64),
+                    by: to_protos(&config.by)?,
+                })
+            }
+            ir::rel::RepartitionSpec::Random(config) => {
+                proto::RepartitionSpec::Random(proto::rel_repartition::Random {
+                    num_partitions: config.num_partitions.map(|n| n as u64),
+                })
+            }
+            ir::rel::RepartitionSpec::IntoPartitions(config) => {
+                proto::RepartitionSpec::IntoPartitions(proto::rel_repartition::IntoPartitions {
+                    num_partitions: config.num_partitions as u64,
+                })
+            }
+        };
+        Ok(Self::Message {
+            input: Some(input),
+            spec: Some(spec),
+        })
+    }
+}
+
+impl ToFromProto for ir::rel::Pivot {
+    type Message = proto::RelPivot;
+
+    fn from_proto(message: Self::Message) -> ProtoResult<Self>
+    where
+        Self: Sized,
+    {
+        let input = from_proto_arc(message.input)?;
+        let groups = from_protos(message.groups)?;
+        let pivot_column = ir::Expr::from_proto(*non_null!(message.pivot_column))?;
+        let value_column = ir::Expr::from_proto(*non_null!(message.value_column))?;
+        let agg = ir::rex::AggExpr::from_proto(*non_null!(message.agg))?;
+        Ok(ir::rel::new_pivot(
+            input,
+            groups,
+            pivot_column,
+            value_column,
+            ir::Expr::Agg(agg),
+            message.names,
+        )?)
+    }
+
+    fn to_proto(&self) -> ProtoResult<Self::Message> {
+        let input = self.input.to_proto()?.into();
+        let groups = to_protos(&self.group_by)?;
+        let pivot_column = self.pivot_column.to_proto()?.into();
+        let value_column = self.value_column.to_proto()?.into();
+        let agg = self.aggregation.to_proto()?.into();
+        Ok(Self::Message {
+            input: Some(input),
+            groups,
+            pivot_column: Some(pivot_column),
+            value_column: Some(value_column),
+            agg: Some(agg),
+            names: self.names.clone(),
+        })
+    }
+}
+
+impl ToFromProto for ir::rel::Join {
+    type Message = proto::RelJoin;
+
+    fn from_proto(message: Self::Message) -> ProtoResult<Self>
+    where
+        Self: Sized,
+    {
+        let lhs = from_proto_arc(message.lhs)?;
+        let rhs = from_proto_arc(message.rhs)?;
+        let on = message.on.map(|on| ir::Expr::from_proto(*on)).transpose()?;
+        let join_type = ir::rel::JoinType::from_proto(message.join_type)?;
+        let join_strategy = message
+            .join_strategy
+            .map(ir::rel::JoinStrategy::from_proto)
+            .transpose()?;
+        Ok(ir::rel::new_join(lhs, rhs, on, join_type, join_strategy)?)
+    }
+
+    fn to_proto(&self) -> ProtoResult<Self::Message> {
+        let lhs = self.left.to_proto()?.into();
+        let rhs = self.right.to_proto()?.into();
+        let on = self.on.inner().map(|on| on.to_proto()).transpose()?;
+        let join_type = self.join_type.to_proto()?;
+        let join_strategy = self.join_strategy.map(|s| s.to_proto()).transpose()?;
+        Ok(Self::Message {
+            lhs: Some(lhs),
+            rhs: Some(rhs),
+            on: on.map(Box::new),
+            join_type,
+            join_strategy,
+        })
+    }
+}
+
+impl ToFromProto for ir::rel::Sink {
+    type Message = proto::RelSink;
+
+    fn from_proto(message: Self::Message) -> ProtoResult<Self>
+    where
+        Self: Sized,
+    {
+        let input = from_proto_arc(message.input)?;
+        let schema = ir::Schema::from_proto(non_null!(message.schema))?;
+        let sink_info = ir::rel::SinkInfo::from_proto(non_null!(message.info))?;
+        Ok(ir::rel::new_sink(input, schema, sink_info.into())?)
+    }
+
+    fn to_proto(&self) -> ProtoResult<Self::Message> {
+        let input = self.input.to_proto()?.into();
+        let schema = self.schema.to_proto()?;
+        let info = self.sink_info.to_proto()?;
+        Ok(Self::Message {
+            input: Some(input),
+            schema: Some(schema),
+            info: Some(info),
+        })
+    }
+}
+
+impl ToFromProto for ir::rel::Sample {
+    type Message = proto::RelSample;
+
+    fn from_proto(message: Self::Message) -> ProtoResult<Self>
+    where
+        Self: Sized,
+    {
+        let input = from_proto_arc(message.input)?;
+        Ok(ir::rel::new_sample(
+            input,
+            message.fraction,
+            message.with_replacement,
+            message.seed,
+        )?)
+    }
+
+    fn to_proto(&self) -> ProtoResult<Self::Message> {
+        let input = self.input.to_proto()?.into();
+        Ok(Self::Message {
+            input: Some(input),
+            fraction: self.fraction,
+            with_replacement: self.with_replacement,
+            seed: self.seed,
+        })
+    }
+}
+
+impl ToFromProto for ir::rel::MonotonicallyIncreasingId {
+    type Message = proto::RelMonotonicallyIncreasingId;
+
+    fn from_proto(message: Self::Message) -> ProtoResult<Self>
+    where
+        Self: Sized,
+    {
+        let input = from_proto_arc(message.input)?;
+        Ok(ir::rel::new_monotonically_increasing_id(
+            input,
+            &message.column_name,
+            message.starting_offset,
+        )?)
+    }
+
+    fn to_proto(&self) -> ProtoResult<Self::Message> {
+        let input = self.input.to_proto()?.into();
+        Ok(Self::Message {
+            input: Some(input),
+            column_name: self.column_name.clone(),
+            starting_offset: self.starting_offset,
+        })
+    }
+}
+
+impl ToFromProto for ir::rel::SubqueryAlias {
+    type Message = proto::RelSubqueryAlias;
+
+    fn from_proto(message: Self::Message) -> ProtoResult<Self>
+    where
+        Self: Sized,
+    {
+        let input = from_proto_arc(message.input)?;
+        Ok(ir::rel::new_subquery_alias(input, &message.name)?)
+    }
+
+    fn to_proto(&self) -> ProtoResult<Self::Message> {
+        let input = self.input.to_proto()?.into();
+        Ok(Self::Message {
+            input: Some(input),
+            name: self.name.to_string(),
+        })
+    }
+}
+
+impl ToFromProto for ir::rel::Window {
+    type Message = proto::RelWindow;
+
+    fn from_proto(message: Self::Message) -> ProtoResult<Self>
+    where
+        Self: Sized,
+    {
+        let input = from_proto_arc(message.input)?;
+        let window_functions = from_proto_vec(message.functions)?;
+        let window_spec = ir::rex::WindowSpec::from_proto(*non_null!(message.spec))?;
+        Ok(ir::rel::new_window(
+            input,
+            window_functions,
+            message.aliases,
+            window_spec,
+        )?)
+    }
+
+    fn to_proto(&self) -> ProtoResult<Self::Message> {
+        let input = self.input.to_proto()?.into();
+        let functions = to_proto_vec(&self.window_functions)?;
+        let spec = self.window_spec.to_proto()?.into();
+        Ok(Self::Message {
+            input: Some(input),
+            functions,
+            aliases: self.aliases.clone(),
+            spec: Some(spec),
+        })
+    }
+}
+
+impl ToFromProto for ir::rel::TopN {
+    type Message = proto::RelTopN;
+
+    fn from_proto(message: Self::Message) -> ProtoResult<Self>
+    where
+        Self: Sized,
+    {
+        let input = from_proto_arc(message.input)?;
+        let (sort_by, descending, nulls_first) = from_sort_orders(message.sort_by)?;
+        Ok(ir::rel::new_top_n(
+            input,
+            sort_by,
+            descending,
+            nulls_first,
+            message.limit,
+        )?)
+    }
+
+    fn to_proto(&self) -> ProtoResult<Self::Message> {
+        let input = self.input.to_proto()?.into();
+        let sort_by = to_sort_orders(&self.sort_by, &self.descending, &self.nulls_first)?;
+        Ok(Self::Message {
+            input: Some(input),
+            sort_by,
+            limit: self.limit,
+        })
+    }
+}
+
+impl ToFromProto for ir::rel::Shard {
+    type Message = proto::RelShard;
+
+    fn from_proto(message: Self::Message) -> ProtoResult<Self>
+    where
+        Self: Sized,
+    {
+        let input = from_proto_arc(message.input)?;
+        let sharder = from_proto(message.sharder)?;
+        Ok(ir::rel::new_shard(input, sharder)?)
+    }
+
+    fn to_proto(&self) -> ProtoResult<Self::Message> {
+        let input = self.input.to_proto()?.into();
+        let sharder = self.sharder.to_proto()?;
+        Ok(Self::Message {
+            input: Some(input),
+            sharder: Some(sharder),
+        })
+    }
+}
+
+impl ToFromProto for ir::rel::RecursiveCte {
+    type Message = proto::RelRecursiveCte;
+
+    fn from_proto(message: Self::Message) -> ProtoResult<Self>
+    where
+        Self: Sized,
+    {
+        let anchor = from_proto_arc(message.anchor)?;
+        let recursive = from_proto_arc(message.recursive)?;
+        Ok(ir::rel::new_recursive_cte(
+            &message.name,
+            message.working_table_key,
+            anchor,
+            recursive,
+            message.is_all,
+        )?)
+    }
+
+    fn to_proto(&self) -> ProtoResult<Self::Message> {
+        let anchor = self.anchor.to_proto()?.into();
+        let recursive = self.recursive.to_proto()?.into();
+        Ok(Self::Message {
+            name: self.name.to_string(),
+            working_table_key: self.working_table_key.clone(),
+            anchor: Some(anchor),
+            recursive: Some(recursive),
+            is_all: self.is_all,
+        })
+    }
+}
+
 impl ToFromProto for ActorPoolProject {
     type Message = proto::RelActorPoolProject;
 
@@ -654,7 +1065,11 @@ impl ToFromProto for ir::rel::PhysicalScanInfo {
             })
             .transpose()?
             .unwrap_or_default();
-        let pushdowns = ir::Pushdowns::default(); // pushdowns are no serialized yet
+        let pushdowns = message
+            .pushdowns
+            .map(|pushdowns| ir::Pushdowns::from_proto(*pushdowns))
+            .transpose()?
+            .unwrap_or_default();
 
         //
         let scan_state = if let Some(tasks) = message.tasks {
@@ -694,12 +1109,11 @@ impl ToFromProto for ir::rel::PhysicalScanInfo {
         let partitions = to_proto_vec(&self.partitioning_keys)?;
         let partitions = proto::PartitionFields { partitions };
         // convert pushdowns
-        // todo(conner)
-        //
+        let pushdowns = self.pushdowns.to_proto()?.into();
         Ok(proto::source_info::ScanInfo {
             schema: Some(schema),
             partitions: Some(partitions),
-            pushdowns: None,
+            pushdowns: Some(pushdowns),
             tasks: Some(tasks),
         })
     }
@@ -785,6 +1199,182 @@ impl ToFromProto for ir::PartitionTransform {
     }
 }
 
+impl ToFromProto for ir::Pushdowns {
+    type Message = proto::Pushdowns;
+
+    fn from_proto(message: Self::Message) -> ProtoResult<Self>
+    where
+        Self: Sized,
+    {
+        let filters = message
+            .filter
+            .map(|filter| ir::Expr::from_proto(*filter).map(Arc::new))
+            .transpose()?;
+        let partition_filters = message
+            .partition_filter
+            .map(|filter| ir::Expr::from_proto(*filter).map(Arc::new))
+            .transpose()?;
+        let columns = message.columns.map(|columns| Arc::new(columns.columns));
+        let limit = message.limit.map(|limit| limit as usize);
+        let sharder = message
+            .sharder
+            .map(ir::rel::Sharder::from_proto)
+            .transpose()?;
+        Ok(Self::new(
+            filters,
+            partition_filters,
+            columns,
+            limit,
+            sharder,
+        ))
+    }
+
+    fn to_proto(&self) -> ProtoResult<Self::Message> {
+        let filter = self.filters.as_ref().map(|f| f.to_proto()).transpose()?;
+        let partition_filter = self
+            .partition_filters
+            .as_ref()
+            .map(|f| f.to_proto())
+            .transpose()?;
+        let columns = self
+            .columns
+            .as_ref()
+            .map(|columns| proto::pushdowns::Columns {
+                columns: columns.to_vec(),
+            });
+        let sharder = self.sharder.as_ref().map(|s| s.to_proto()).transpose()?;
+        Ok(Self::Message {
+            filter: filter.map(Box::new),
+            partition_filter: partition_filter.map(Box::new),
+            columns,
+            limit: self.limit.map(|limit| limit as u64),
+            sharder,
+        })
+    }
+}
+
+impl ToFromProto for ir::rel::Sharder {
+    type Message = proto::Sharder;
+
+    fn from_proto(message: Self::Message) -> ProtoResult<Self>
+    where
+        Self: Sized,
+    {
+        let strategy = proto::ShardingStrategy::try_from(message.strategy)
+            .unwrap_or(proto::ShardingStrategy::Unspecified);
+        let strategy = match strategy {
+            proto::ShardingStrategy::Unspecified => {
+                from_proto_err!("Unspecified sharding strategy.")
+            }
+            proto::ShardingStrategy::File => ir::rel::ShardingStrategy::File,
+        };
+        if message.world_size == 0 || message.rank >= message.world_size {
+            from_proto_err!(
+                "invalid sharder with world size {} and rank {}",
+                message.world_size,
+                message.rank
+            )
+        }
+        Ok(Self::new(
+            strategy,
+            message.world_size as usize,
+            message.rank as usize,
+        ))
+    }
+
+    fn to_proto(&self) -> ProtoResult<Self::Message> {
+        let strategy = match self.strategy() {
+            ir::rel::ShardingStrategy::File => proto::ShardingStrategy::File,
+        };
+        Ok(Self::Message {
+            strategy: strategy as i32,
+            world_size: self.world_size() as u64,
+            rank: self.rank() as u64,
+        })
+    }
+}
+
+impl ToFromProto for ir::rel::JoinType {
+    type Message = i32;
+
+    fn from_proto(message: Self::Message) -> ProtoResult<Self>
+    where
+        Self: Sized,
+    {
+        let join_type = proto::JoinType::try_from(message).unwrap_or(proto::JoinType::Unspecified);
+        let join_type = match join_type {
+            proto::JoinType::Unspecified => from_proto_err!("Unspecified join type."),
+            proto::JoinType::Inner => Self::Inner,
+            proto::JoinType::Left => Self::Left,
+            proto::JoinType::Right => Self::Right,
+            proto::JoinType::Outer => Self::Outer,
+            proto::JoinType::Anti => Self::Anti,
+            proto::JoinType::Semi => Self::Semi,
+        };
+        Ok(join_type)
+    }
+
+    fn to_proto(&self) -> ProtoResult<Self::Message> {
+        let join_type = match self {
+            Self::Inner => proto::JoinType::Inner,
+            Self::Left => proto::JoinType::Left,
+            Self::Right => proto::JoinType::Right,
+            Self::Outer => proto::JoinType::Outer,
+            Self::Anti => proto::JoinType::Anti,
+            Self::Semi => proto::JoinType::Semi,
+        };
+        Ok(join_type as i32)
+    }
+}
+
+impl ToFromProto for ir::rel::JoinStrategy {
+    type Message = i32;
+
+    fn from_proto(message: Self::Message) -> ProtoResult<Self>
+    where
+        Self: Sized,
+    {
+        let join_strategy =
+            proto::JoinStrategy::try_from(message).unwrap_or(proto::JoinStrategy::Unspecified);
+        let join_strategy = match join_strategy {
+            proto::JoinStrategy::Unspecified => from_proto_err!("Unspecified join strategy."),
+            proto::JoinStrategy::Hash => Self::Hash,
+            proto::JoinStrategy::SortMerge => Self::SortMerge,
+            proto::JoinStrategy::Broadcast => Self::Broadcast,
+            proto::JoinStrategy::Cross => Self::Cross,
+        };
+        Ok(join_strategy)
+    }
+
+    fn to_proto(&self) -> ProtoResult<Self::Message> {
+        let join_strategy = match self {
+            Self::Hash => proto::JoinStrategy::Hash,
+            Self::SortMerge => proto::JoinStrategy::SortMerge,
+            Self::Broadcast => proto::JoinStrategy::Broadcast,
+            Self::Cross => proto::JoinStrategy::Cross,
+        };
+        Ok(join_strategy as i32)
+    }
+}
+
+// Sinks hold python objects (catalog tables, data sinks) so the info is opaque like scan tasks.
+impl ToFromProto for ir::rel::SinkInfo {
+    type Message = proto::SinkInfo;
+
+    fn from_proto(message: Self::Message) -> ProtoResult<Self>
+    where
+        Self: Sized,
+    {
+        Ok(bincode::deserialize(&message.info)?)
+    }
+
+    fn to_proto(&self) -> ProtoResult<Self::Message> {
+        Ok(Self::Message {
+            info: bincode::serialize(self)?,
+        })
+    }
+}
+
 // This was too intimidating, but we can shove the bytes into a wrapper atm.
 impl ToFromProto for ir::ScanTaskLikeRef {
     type Message = proto::ScanTask;
@@ -1,20 +1,27 @@
 use std::sync::Arc;
 
+use common_hashable_float_wrapper::FloatWrapper;
+
 use super::{from_proto, from_proto_arc, ProtoResult, ToFromProto};
 use crate::{
     from_proto_err, non_null, not_implemented_err, not_optimized_err,
     proto::{
-        from_proto_vec,
+        from_proto_vec, from_protos, from_sort_orders,
         functions::{from_proto_function, function_expr_to_proto},
-        to_proto_vec, UNIT,
+        to_proto_vec, to_protos, to_sort_orders, UNIT,
     },
+    to_proto_err,
 };
 
 /// Export daft_ir types under an `ir` namespace to concisely disambiguate domains.
 #[rustfmt::skip]
 mod ir {
     pub use crate::rex::*;
+    pub use crate::rel::LogicalPlan;
+    pub use crate::schema::{Field, TimeUnit};
     pub use crate::CountMode;
+    pub use daft_core::datatypes::IntervalValue;
+    pub use daft_dsl::expr::BoundColumn;
 }
 
 /// Export daft_proto types under a `proto` namespace because prost is heinous.
@@ -22,8 +29,11 @@ mod ir {
 mod proto {
     pub use daft_proto::protos::daft::v1::*;
     pub use daft_proto::protos::daft::v1::agg::Variant as AggVariant;
+    pub use daft_proto::protos::daft::v1::column::Scope as ColumnScope;
     pub use daft_proto::protos::daft::v1::expr::Variant as ExprVariant;
     pub use daft_proto::protos::daft::v1::literal::Variant as LiteralVariant;
+    pub use daft_proto::protos::daft::v1::plan_ref::Variant as PlanRefVariant;
+    pub use daft_proto::protos::daft::v1::window_expr::Variant as WindowExprVariant;
 }
 
 impl ToFromProto for ir::Expr {
@@ -63,16 +73,14 @@ impl ToFromProto for ir::Expr {
                 // there are various function expression types hidden within this method.
                 from_proto_function(function)?
             }
-            proto::ExprVariant::Over(_) => {
-                not_implemented_err!("over")
-                // let window_expr = from_proto_arc(over.window_expr)?;
-                // let window_spec = ir::WindowSpec::from_proto(over.window_spec)?;
-                // Self::Over(window_expr, window_spec)
+            proto::ExprVariant::Over(over) => {
+                let window_expr = ir::WindowExpr::from_proto(*non_null!(over.expr))?;
+                let window_spec = ir::WindowSpec::from_proto(*non_null!(over.spec))?;
+                Self::Over(window_expr, window_spec)
             }
-            proto::ExprVariant::WindowFunction(_) => {
-                not_implemented_err!("window")
-                // let window_expr = from_proto_arc(window_function.window_expr)?;
-                // Self::WindowFunction(window_expr)
+            proto::ExprVariant::WindowFunction(window_function) => {
+                let window_expr = ir::WindowExpr::from_proto(*non_null!(window_function.expr))?;
+                Self::WindowFunction(window_expr)
             }
             proto::ExprVariant::Not(not) => {
                 let expr = from_proto_arc(not.expr)?;
@@ -126,23 +134,39 @@ impl ToFromProto for ir::Expr {
                     predicate,
                 }
             }
-            proto::ExprVariant::Subquery(_) => {
-                not_implemented_err!("subquery")
-                // Self::Subquery(ir::Subquery::from_proto(subquery)?)
+            proto::ExprVariant::Subquery(subquery) => {
+                let subquery = ir::Subquery::from_proto(*subquery)?;
+                Self::Subquery(subquery)
             }
-            proto::ExprVariant::SubqueryIn(_) => {
-                not_implemented_err!("subquery_in")
-                // let expr = from_proto_arc(in_subquery.expr)?;
-                // let subquery = ir::Subquery::from_proto(in_subquery.subquery)?;
-                // Self::InSubquery(expr, subquery)
+            proto::ExprVariant::SubqueryIn(subquery_in) => {
+                // daft only has the single expression form `<expr> IN <subquery>`.
+                let mut args = from_protos(subquery_in.args)?;
+                if args.len() != 1 {
+                    not_implemented_err!("subquery_in with {} arguments", args.len())
+                }
+                let expr = args.remove(0).into();
+                let subquery = ir::Subquery::from_proto(proto::Subquery {
+                    input: subquery_in.input,
+                })?;
+                Self::InSubquery(expr, subquery)
             }
             proto::ExprVariant::SubqueryComp(_) => {
+                // daft does not have quantified comparison predicates.
                 not_implemented_err!("subquery_comp")
-                // Self::Exists(ir::Subquery::from_proto(exists.subquery)?)
             }
-            proto::ExprVariant::SubqueryTest(_) => {
-                not_implemented_err!("subquery_test")
-                // Self::Exists(ir::Subquery::from_proto(exists.subquery)?)
+            proto::ExprVariant::SubqueryTest(subquery_test) => {
+                let test = proto::subquery_test::Test::try_from(subquery_test.test)
+                    .unwrap_or(proto::subquery_test::Test::Unspecified);
+                let subquery = ir::Subquery::from_proto(proto::Subquery {
+                    input: subquery_test.input,
+                })?;
+                match test {
+                    proto::subquery_test::Test::Exists => Self::Exists(subquery),
+                    proto::subquery_test::Test::Unique => not_implemented_err!("subquery_unique"),
+                    proto::subquery_test::Test::Unspecified => {
+                        from_proto_err!("Unspecified subquery test.")
+                    }
+                }
             }
         };
         Ok(expr)
@@ -194,11 +218,22 @@ impl ToFromProto for ir::Expr {
                 let function = function_expr_to_proto(func, inputs)?;
                 proto::ExprVariant::Function(function)
             }
-            Self::Over(..) => {
-                not_implemented_err!("over")
+            Self::Over(window_expr, window_spec) => {
+                let expr = window_expr.to_proto()?.into();
+                let spec = window_spec.to_proto()?.into();
+                proto::ExprVariant::Over(
+                    proto::Over {
+                        expr: Some(expr),
+                        spec: Some(spec),
+                    }
+                    .into(),
+                )
             }
-            Self::WindowFunction(_) => {
-                not_implemented_err!("window")
+            Self::WindowFunction(window_expr) => {
+                let expr = window_expr.to_proto()?.into();
+                proto::ExprVariant::WindowFunction(
+                    proto::WindowFunction { expr: Some(expr) }.into(),
+                )
             }
             Self::Not(expr) => {
                 let expr = expr.to_proto()?.into();
@@ -276,17 +311,19 @@ impl ToFromProto for ir::Expr {
                 let function = scalar_function.to_proto()?;
                 proto::ExprVariant::Function(function)
             }
-            Self::Subquery(_) => {
-                // todo(conner)
-                not_implemented_err!("subquery")
+            Self::Subquery(subquery) => {
+                let subquery = subquery.to_proto()?.into();
+                proto::ExprVariant::Subquery(subquery)
             }
-            Self::InSubquery(..) => {
-                // todo(conner)
-                not_implemented_err!("in_subquery")
+            Self::InSubquery(expr, subquery) => {
+                let input = subquery.to_proto()?.input;
+                let args = vec![expr.to_proto()?];
+                proto::ExprVariant::SubqueryIn(proto::SubqueryIn { input, args }.into())
             }
-            Self::Exists(_) => {
-                // todo(conner)
-                not_implemented_err!("exists")
+            Self::Exists(subquery) => {
+                let input = subquery.to_proto()?.input;
+                let test = proto::subquery_test::Test::Exists as i32;
+                proto::ExprVariant::SubqueryTest(proto::SubqueryTest { input, test }.into())
             }
         };
         Ok(proto::Expr {
@@ -299,32 +336,114 @@ impl ToFromProto for ir::Expr {
 impl ToFromProto for ir::Column {
     type Message = proto::Column;
 
+    #[allow(deprecated)]
     fn from_proto(message: Self::Message) -> ProtoResult<Self> {
-        // we only ever produce resolved columns
-        let column = ir::ResolvedColumn::Basic(message.name.into());
-        Ok(Self::Resolved(column))
+        // we only ever produce resolved or bound columns
+        let scope = match message.scope {
+            Some(scope) => scope,
+            None => {
+                let column = ir::ResolvedColumn::Basic(message.name.into());
+                return Ok(Self::Resolved(column));
+            }
+        };
+        let field = ir::Field::from_proto(non_null!(message.field))?;
+        let column = match scope {
+            proto::ColumnScope::JoinSide(side) => {
+                let side = ir::JoinSide::from_proto(side)?;
+                Self::Resolved(ir::ResolvedColumn::JoinSide(field, side))
+            }
+            proto::ColumnScope::OuterRef(plan_ref) => {
+                let plan_ref = ir::PlanRef::from_proto(plan_ref)?;
+                Self::Resolved(ir::ResolvedColumn::OuterRef(field, plan_ref))
+            }
+            proto::ColumnScope::Index(index) => Self::Bound(ir::BoundColumn {
+                index: index as usize,
+                field,
+            }),
+        };
+        Ok(column)
     }
 
+    #[allow(deprecated)]
     fn to_proto(&self) -> ProtoResult<Self::Message> {
-        // validate the column is resolved
+        // validate the column is resolved or bound
         let column = match self {
-            Self::Bound(_) => not_implemented_err!("column::bound"),
+            Self::Bound(column) => proto::Column {
+                name: column.field.name.to_string(),
+                qualifier: None,
+                field: Some(column.field.to_proto()?),
+                scope: Some(proto::ColumnScope::Index(column.index as u64)),
+            },
             Self::Unresolved(_) => not_optimized_err!("unresolved column in optimized plan"),
-            Self::Resolved(column) => column,
-        };
-        // convert the resolved column to proto
-        let column = match column {
-            ir::ResolvedColumn::Basic(name) => proto::Column {
+            Self::Resolved(ir::ResolvedColumn::Basic(name)) => proto::Column {
                 name: name.to_string(),
                 qualifier: None,
+                field: None,
+                scope: None,
+            },
+            Self::Resolved(ir::ResolvedColumn::JoinSide(field, side)) => proto::Column {
+                name: field.name.to_string(),
+                qualifier: None,
+                field: Some(field.to_proto()?),
+                scope: Some(proto::ColumnScope::JoinSide(side.to_proto()?)),
+            },
+            Self::Resolved(ir::ResolvedColumn::OuterRef(field, plan_ref)) => proto::Column {
+                name: field.name.to_string(),
+                qualifier: None,
+                field: Some(field.to_proto()?),
+                scope: Some(proto::ColumnScope::OuterRef(plan_ref.to_proto()?)),
             },
-            ir::ResolvedColumn::JoinSide(_, _) => not_implemented_err!("column::join_side"),
-            ir::ResolvedColumn::OuterRef(_, _) => not_implemented_err!("column::outer_ref"),
         };
         Ok(column)
     }
 }
 
+impl ToFromProto for ir::JoinSide {
+    type Message = i32;
+
+    fn from_proto(message: Self::Message) -> ProtoResult<Self> {
+        let join_side = proto::JoinSide::try_from(message).unwrap_or(proto::JoinSide::Unspecified);
+        let join_side = match join_side {
+            proto::JoinSide::Unspecified => from_proto_err!("Unspecified join side."),
+            proto::JoinSide::Left => Self::Left,
+            proto::JoinSide::Right => Self::Right,
+        };
+        Ok(join_side)
+    }
+
+    fn to_proto(&self) -> ProtoResult<Self::Message> {
+        let join_side = match self {
+            Self::Left => proto::JoinSide::Left,
+            Self::Right => proto::JoinSide::Right,
+        };
+        Ok(join_side as i32)
+    }
+}
+
+impl ToFromProto for ir::PlanRef {
+    type Message = proto::PlanRef;
+
+    fn from_proto(message: Self::Message) -> ProtoResult<Self> {
+        let plan_ref = match non_null!(message.variant) {
+            proto::PlanRefVariant::Alias(alias) => Self::Alias(alias.into()),
+            proto::PlanRefVariant::Unqualified(_) => Self::Unqualified,
+            proto::PlanRefVariant::Id(id) => Self::Id(id as usize),
+        };
+        Ok(plan_ref)
+    }
+
+    fn to_proto(&self) -> ProtoResult<Self::Message> {
+        let variant = match self {
+            Self::Alias(alias) => proto::PlanRefVariant::Alias(alias.to_string()),
+            Self::Unqualified => proto::PlanRefVariant::Unqualified(UNIT),
+            Self::Id(id) => proto::PlanRefVariant::Id(*id as u64),
+        };
+        Ok(Self::Message {
+            variant: Some(variant),
+        })
+    }
+}
+
 impl ToFromProto for ir::AggExpr {
     type Message = proto::Agg;
 
@@ -356,51 +475,61 @@ impl ToFromProto for ir::AggExpr {
                     "max" => Self::Max(arg),
                     "bool_and" => Self::BoolAnd(arg),
                     "bool_or" => Self::BoolOr(arg),
-                    "any_value" => Self::AnyValue(arg, true), // TODO: handle ignore_nulls
+                    "any_value" => Self::AnyValue(arg, set_function.ignore_nulls),
+                    "approx_count_distinct" => Self::ApproxCountDistinct(arg),
                     "agg_list" => Self::List(arg),
                     "agg_set" => Self::Set(arg),
                     "agg_concat" => Self::Concat(arg),
                     "skew" => Self::Skew(arg),
+                    "min_by" | "max_by" => {
+                        let Some(by) = set_function.args.get(1) else {
+                            return Err(super::ProtoError::FromProto(format!(
+                                "{name} requires a second argument"
+                            )));
+                        };
+                        let by = ir::Expr::from_proto(by.clone())?.into();
+                        match name {
+                            "min_by" => Self::MinBy(arg, by),
+                            _ => Self::MaxBy(arg, by),
+                        }
+                    }
                     _ => not_implemented_err!("unrecognized aggregation function: {}", name),
                 }
             }
-            proto::AggVariant::ApproxPercentile(_) => {
-                not_implemented_err!("approx_percentile");
-                // let expr = from_proto_arc(non_null!(approx_percentile.expr))?;
-                // let percentiles = approx_percentile.percentiles.iter().map(|p| FloatWrapper(*p)).collect();
-                // Self::ApproxPercentile(ApproxPercentileParams {
-                //     child: expr,
-                //     percentiles,
-                //     force_list_output: approx_percentile.force_list_output,
-                // })
-            }
-            proto::AggVariant::ApproxSketch(_) => {
-                not_implemented_err!("approx_sketch");
-                // let expr = from_proto_arc(non_null!(approx_sketch.expr))?;
-                // let sketch_type = match approx_sketch.sketch_type {
-                //     1 => SketchType::DDSketch,
-                //     2 => SketchType::HyperLogLog,
-                //     _ => return Err(ProtoError::FromProto("Invalid sketch type".to_string())),
-                // };
-                // Self::ApproxSketch(expr, sketch_type)
-            }
-            proto::AggVariant::MergeSketch(_) => {
-                not_implemented_err!("merge_sketch");
-                // let expr = from_proto_arc(non_null!(merge_sketch.expr))?;
-                // let sketch_type = match merge_sketch.sketch_type {
-                //     1 => SketchType::DDSketch,
-                //     2 => SketchType::HyperLogLog,
-                //     _ => return Err(ProtoError::FromProto("Invalid sketch type".to_string())),
-                // };
-                // Self::MergeSketch(expr, sketch_type)
-            }
-            proto::AggVariant::MapGroups(_) => {
-                not_implemented_err!("map_groups");
-                // let func = from_proto_arc(non_null!(map_groups.func))?;
-                // let inputs = map_groups.inputs.into_iter()
-                //     .map(|input| ir::Expr::from_proto(input).map(|e| e.into()))
-                //     .collect::<ProtoResult<Vec<_>>>()?;
-                // Self::MapGroups { func, inputs }
+            proto::AggVariant::ApproxPercentile(approx_percentile) => {
+                let child = from_proto_arc(approx_percentile.expr)?;
+                let percentiles = approx_percentile
+                    .percentiles
+                    .into_iter()
+                    .map(FloatWrapper)
+                    .collect();
+                Self::ApproxPercentile(ir::ApproxPercentileParams {
+                    child,
+                    percentiles,
+                    force_list_output: approx_percentile.force_list_output,
+                })
+            }
+            proto::AggVariant::ApproxSketch(approx_sketch) => {
+                let expr = from_proto_arc(approx_sketch.expr)?;
+                let sketch_type = ir::SketchType::from_proto(approx_sketch.sketch_type)?;
+                Self::ApproxSketch(expr, sketch_type)
+            }
+            proto::AggVariant::MergeSketch(merge_sketch) => {
+                let expr = from_proto_arc(merge_sketch.expr)?;
+                let sketch_type = ir::SketchType::from_proto(merge_sketch.sketch_type)?;
+                Self::MergeSketch(expr, sketch_type)
+            }
+            proto::AggVariant::MapGroups(map_groups) => {
+                // the function is encoded as a function expression without its inputs.
+                let func = match ir::Expr::from_proto(*non_null!(map_groups.func))? {
+                    ir::Expr::Function { func, .. } => func,
+                    expr => from_proto_err!("expected a function for map_groups, found: {}", expr),
+                };
+                let inputs = from_protos(map_groups.inputs)?
+                    .into_iter()
+                    .map(Arc::new)
+                    .collect();
+                Self::MapGroups { func, inputs }
             }
         };
         Ok(agg)
@@ -418,6 +547,7 @@ impl ToFromProto for ir::AggExpr {
                     name: name.to_string(),
                     args: vec![expr.to_proto()?],
                     is_all: true,
+                    ignore_nulls: false,
                 })
             }
             Self::CountDistinct(expr) => {
@@ -426,6 +556,7 @@ impl ToFromProto for ir::AggExpr {
                     name: "count".to_string(),
                     args: vec![expr.to_proto()?],
                     is_all: false,
+                    ignore_nulls: false,
                 })
             }
             Self::Sum(expr) => {
@@ -434,6 +565,7 @@ impl ToFromProto for ir::AggExpr {
                     name: "sum".to_string(),
                     args: vec![expr.to_proto()?],
                     is_all: true,
+                    ignore_nulls: false,
                 })
             }
             Self::Mean(expr) => {
@@ -442,6 +574,7 @@ impl ToFromProto for ir::AggExpr {
                     name: "mean".to_string(),
                     args: vec![expr.to_proto()?],
                     is_all: true,
+                    ignore_nulls: false,
                 })
             }
             Self::Stddev(expr) => {
@@ -450,6 +583,7 @@ impl ToFromProto for ir::AggExpr {
                     name: "stddev".to_string(),
                     args: vec![expr.to_proto()?],
                     is_all: true,
+                    ignore_nulls: false,
                 })
             }
             Self::Min(expr) => {
@@ -458,6 +592,7 @@ impl ToFromProto for ir::AggExpr {
                     name: "min".to_string(),
                     args: vec![expr.to_proto()?],
                     is_all: true,
+                    ignore_nulls: false,
                 })
             }
             Self::Max(expr) => {
@@ -466,6 +601,7 @@ impl ToFromProto for ir::AggExpr {
                     name: "max".to_string(),
                     args: vec![expr.to_proto()?],
                     is_all: true,
+                    ignore_nulls: false,
                 })
             }
             Self::BoolAnd(expr) => {
@@ -474,6 +610,7 @@ impl ToFromProto for ir::AggExpr {
                     name: "bool_and".to_string(),
                     args: vec![expr.to_proto()?],
                     is_all: true,
+                    ignore_nulls: false,
                 })
             }
             Self::BoolOr(expr) => {
@@ -482,14 +619,16 @@ impl ToFromProto for ir::AggExpr {
                     name: "bool_or".to_string(),
                     args: vec![expr.to_proto()?],
                     is_all: true,
+                    ignore_nulls: false,
                 })
             }
-            Self::AnyValue(expr, _) => {
-                // ANY_VALUE([ALL] <expr>)
+            Self::AnyValue(expr, ignore_nulls) => {
+                // ANY_VALUE([ALL] <expr>) [IGNORE NULLS]
                 proto::AggVariant::SetFunction(proto::agg::SetFunction {
                     name: "any_value".to_string(),
                     args: vec![expr.to_proto()?],
                     is_all: true,
+                    ignore_nulls: *ignore_nulls,
                 })
             }
             Self::List(expr) => {
@@ -498,6 +637,7 @@ impl ToFromProto for ir::AggExpr {
                     name: "agg_list".to_string(),
                     args: vec![expr.to_proto()?],
                     is_all: true,
+                    ignore_nulls: false,
                 })
             }
             Self::Set(expr) => {
@@ -506,6 +646,7 @@ impl ToFromProto for ir::AggExpr {
                     name: "agg_set".to_string(),
                     args: vec![expr.to_proto()?],
                     is_all: true,
+                    ignore_nulls: false,
                 })
             }
             Self::Concat(expr) => {
@@ -514,6 +655,7 @@ impl ToFromProto for ir::AggExpr {
                     name: "agg_concat".to_string(),
                     args: vec![expr.to_proto()?],
                     is_all: true,
+                    ignore_nulls: false,
                 })
             }
             Self::Skew(expr) => {
@@ -522,13 +664,72 @@ impl ToFromProto for ir::AggExpr {
                     name: "skew".to_string(),
                     args: vec![expr.to_proto()?],
                     is_all: true,
+                    ignore_nulls: false,
                 })
             }
-            Self::MapGroups { .. } => not_implemented_err!("map_groups"),
-            Self::ApproxPercentile(_) => not_implemented_err!("approx_percentile"),
-            Self::ApproxCountDistinct(_) => not_implemented_err!("approx_count_distinct"),
-            Self::ApproxSketch(..) => not_implemented_err!("approx_sketch"),
-            Self::MergeSketch(..) => not_implemented_err!("merge_sketch"),
+            Self::MinBy(expr, by) => {
+                // MIN_BY([ALL] <expr>, <by>)
+                proto::AggVariant::SetFunction(proto::agg::SetFunction {
+                    name: "min_by".to_string(),
+                    args: vec![expr.to_proto()?, by.to_proto()?],
+                    is_all: true,
+                    ignore_nulls: false,
+                })
+            }
+            Self::MaxBy(expr, by) => {
+                // MAX_BY([ALL] <expr>, <by>)
+                proto::AggVariant::SetFunction(proto::agg::SetFunction {
+                    name: "max_by".to_string(),
+                    args: vec![expr.to_proto()?, by.to_proto()?],
+                    is_all: true,
+                    ignore_nulls: false,
+                })
+            }
+            Self::ApproxCountDistinct(expr) => {
+                // APPROX_COUNT_DISTINCT([ALL] <expr>)
+                proto::AggVariant::SetFunction(proto::agg::SetFunction {
+                    name: "approx_count_distinct".to_string(),
+                    args: vec![expr.to_proto()?],
+                    is_all: true,
+                    ignore_nulls: false,
+                })
+            }
+            Self::ApproxPercentile(params) => proto::AggVariant::ApproxPercentile(
+                proto::agg::ApproxPercentile {
+                    expr: Some(params.child.to_proto()?.into()),
+                    percentiles: params.percentiles.iter().map(|p| p.0).collect(),
+                    force_list_output: params.force_list_output,
+                }
+                .into(),
+            ),
+            Self::ApproxSketch(expr, sketch_type) => proto::AggVariant::ApproxSketch(
+                proto::agg::ApproxSketch {
+                    expr: Some(expr.to_proto()?.into()),
+                    sketch_type: sketch_type.to_proto()?,
+                }
+                .into(),
+            ),
+            Self::MergeSketch(expr, sketch_type) => proto::AggVariant::MergeSketch(
+                proto::agg::MergeSketch {
+                    expr: Some(expr.to_proto()?.into()),
+                    sketch_type: sketch_type.to_proto()?,
+                }
+                .into(),
+            ),
+            Self::MapGroups { func, inputs } => {
+                // the inputs are held by the aggregation, so the function is encoded without them.
+                let func = ir::Expr::Function {
+                    func: func.clone(),
+                    inputs: vec![],
+                };
+                proto::AggVariant::MapGroups(
+                    proto::agg::MapGroups {
+                        func: Some(func.to_proto()?.into()),
+                        inputs: to_protos(inputs)?,
+                    }
+                    .into(),
+                )
+            }
         };
         Ok(Self::Message {
             variant: Some(variant),
@@ -536,27 +737,175 @@ impl ToFromProto for ir::AggExpr {
     }
 }
 
-impl ToFromProto for ir::expr::WindowExpr {
-    type Message = proto::WindowFunction;
+impl ToFromProto for ir::SketchType {
+    type Message = i32;
 
-    fn from_proto(_message: Self::Message) -> ProtoResult<Self> {
-        not_implemented_err!("expr_window_function")
+    fn from_proto(message: Self::Message) -> ProtoResult<Self> {
+        let sketch_type = proto::agg::SketchType::try_from(message)
+            .unwrap_or(proto::agg::SketchType::Unspecified);
+        let sketch_type = match sketch_type {
+            proto::agg::SketchType::Unspecified => from_proto_err!("Unspecified sketch type."),
+            proto::agg::SketchType::Dd => Self::DDSketch,
+            proto::agg::SketchType::Hll => Self::HyperLogLog,
+        };
+        Ok(sketch_type)
     }
 
     fn to_proto(&self) -> ProtoResult<Self::Message> {
-        not_implemented_err!("expr_window_function")
+        let sketch_type = match self {
+            Self::DDSketch => proto::agg::SketchType::Dd,
+            Self::HyperLogLog => proto::agg::SketchType::Hll,
+        };
+        Ok(sketch_type as i32)
     }
 }
 
-impl ToFromProto for ir::expr::Subquery {
+impl ToFromProto for ir::WindowExpr {
+    type Message = proto::WindowExpr;
+
+    fn from_proto(message: Self::Message) -> ProtoResult<Self> {
+        let window_expr = match non_null!(message.variant) {
+            proto::WindowExprVariant::Agg(agg) => Self::Agg(ir::AggExpr::from_proto(*agg)?),
+            proto::WindowExprVariant::RowNumber(_) => Self::RowNumber,
+            proto::WindowExprVariant::Rank(_) => Self::Rank,
+            proto::WindowExprVariant::DenseRank(_) => Self::DenseRank,
+            proto::WindowExprVariant::Offset(offset) => Self::Offset {
+                input: from_proto_arc(offset.input)?,
+                offset: offset.offset as isize,
+                default: offset
+                    .default
+                    .map(|default| ir::Expr::from_proto(*default).map(Arc::new))
+                    .transpose()?,
+            },
+        };
+        Ok(window_expr)
+    }
+
+    fn to_proto(&self) -> ProtoResult<Self::Message> {
+        let variant = match self {
+            Self::Agg(agg) => proto::WindowExprVariant::Agg(agg.to_proto()?.into()),
+            Self::RowNumber => proto::WindowExprVariant::RowNumber(true),
+            Self::Rank => proto::WindowExprVariant::Rank(true),
+            Self::DenseRank => proto::WindowExprVariant::DenseRank(true),
+            Self::Offset {
+                input,
+                offset,
+                default,
+            } => {
+                let Ok(offset) = i32::try_from(*offset) else {
+                    to_proto_err!("window offset {} does not fit in an int32", offset)
+                };
+                proto::WindowExprVariant::Offset(
+                    proto::Offset {
+                        input: Some(input.to_proto()?.into()),
+                        offset,
+                        default: default
+                            .as_ref()
+                            .map(|default| default.to_proto().map(Box::new))
+                            .transpose()?,
+                    }
+                    .into(),
+                )
+            }
+        };
+        Ok(Self::Message {
+            variant: Some(variant),
+        })
+    }
+}
+
+impl ToFromProto for ir::WindowSpec {
+    type Message = proto::WindowSpec;
+
+    fn from_proto(message: Self::Message) -> ProtoResult<Self> {
+        let partition_by = from_protos(message.partition_by)?
+            .into_iter()
+            .map(Arc::new)
+            .collect();
+        let (order_by, descending, nulls_first) = from_sort_orders(message.order_by)?;
+        let frame = message
+            .frame
+            .map(|frame| ir::WindowFrame::from_proto(*frame))
+            .transpose()?;
+        Ok(Self {
+            partition_by,
+            order_by,
+            descending,
+            nulls_first,
+            frame,
+            min_periods: message.min_periods as usize,
+        })
+    }
+
+    fn to_proto(&self) -> ProtoResult<Self::Message> {
+        let partition_by = to_protos(&self.partition_by)?;
+        let order_by = to_sort_orders(&self.order_by, &self.descending, &self.nulls_first)?;
+        let frame = self
+            .frame
+            .as_ref()
+            .map(|frame| frame.to_proto().map(Box::new))
+            .transpose()?;
+        Ok(Self::Message {
+            partition_by,
+            order_by,
+            frame,
+            min_periods: self.min_periods as u64,
+        })
+    }
+}
+
+/// ROWS frames have integer offsets whereas RANGE frames offset by a literal of the order by type.
+impl ToFromProto for ir::WindowFrame {
+    type Message = proto::WindowFrame;
+
+    fn from_proto(message: Self::Message) -> ProtoResult<Self> {
+        let frame_type =
+            proto::FrameType::try_from(message.r#type).unwrap_or(proto::FrameType::Unspecified);
+        let start = window_boundary_from_proto(frame_type, message.start)?
+            .unwrap_or(ir::WindowBoundary::UnboundedPreceding);
+        let end = window_boundary_from_proto(frame_type, message.end)?
+            .unwrap_or(ir::WindowBoundary::UnboundedFollowing);
+        Ok(Self { start, end })
+    }
+
+    fn to_proto(&self) -> ProtoResult<Self::Message> {
+        if matches!(self.start, ir::WindowBoundary::UnboundedFollowing)
+            || matches!(self.end, ir::WindowBoundary::UnboundedPreceding)
+        {
+            to_proto_err!("window frame boundaries are out of order: {:?}", self)
+        }
+        let (start_type, start) = window_boundary_to_proto(&self.start)?;
+        let (end_type, end) = window_boundary_to_proto(&self.end)?;
+        let frame_type = match (start_type, end_type) {
+            (Some(start_type), Some(end_type)) if start_type != end_type => {
+                to_proto_err!("window frame mixes rows and range boundaries: {:?}", self)
+            }
+            (Some(frame_type), _) | (None, Some(frame_type)) => frame_type,
+            (None, None) => proto::FrameType::Rows,
+        };
+        Ok(Self::Message {
+            r#type: frame_type as i32,
+            start,
+            end,
+        })
+    }
+}
+
+impl ToFromProto for ir::Subquery {
     type Message = proto::Subquery;
 
-    fn from_proto(_message: Self::Message) -> ProtoResult<Self> {
-        not_implemented_err!("expr_subquery")
+    fn from_proto(message: Self::Message) -> ProtoResult<Self> {
+        let plan = ir::LogicalPlan::from_proto(*non_null!(message.input))?;
+        Ok(Self::new(plan))
     }
 
     fn to_proto(&self) -> ProtoResult<Self::Message> {
-        not_implemented_err!("expr_subquery")
+        let Some(plan) = self.plan.as_any().downcast_ref::<ir::LogicalPlan>() else {
+            to_proto_err!("expected the subquery plan to be a logical plan")
+        };
+        Ok(Self::Message {
+            input: Some(plan.to_proto()?.into()),
+        })
     }
 }
 
@@ -640,34 +989,39 @@ impl ToFromProto for ir::LiteralValue {
             proto::LiteralVariant::Uint32(i) => Self::UInt32(i),
             proto::LiteralVariant::Int64(i) => Self::Int64(i),
             proto::LiteralVariant::Uint64(i) => Self::UInt64(i),
-            proto::LiteralVariant::Timestamp(_) => {
-                // let unit = daft_ir::schema::TimeUnit::from_proto(timestamp.unit)?;
-                // LiteralValue::Timestamp(timestamp.value, unit, timestamp.timezone)
-                not_implemented_err!("literal_timestamp")
+            proto::LiteralVariant::Timestamp(timestamp) => {
+                let unit = ir::TimeUnit::from_proto(timestamp.unit)?;
+                Self::Timestamp(timestamp.value, unit, timestamp.timezone)
             }
             proto::LiteralVariant::Date(days) => Self::Date(days),
-            proto::LiteralVariant::Time(_) => {
-                // let unit = daft_ir::schema::TimeUnit::from_proto(time.unit)?;
-                // LiteralValue::Time(time.value, unit)
-                not_implemented_err!("literal_time")
-            }
-            proto::LiteralVariant::Duration(_duration) => {
-                // let unit = daft_ir::schema::TimeUnit::from_proto(duration.unit)?;
-                // LiteralValue::Duration(duration.value, unit)
-                not_implemented_err!("literal_duration")
-            }
-            proto::LiteralVariant::Interval(_interval) => {
-                // LiteralValue::Interval(daft_ir::IntervalValue {
-                //     months: interval.months,
-                //     days: interval.days,
-                //     nanoseconds: interval.nanoseconds,
-                // })
-                not_implemented_err!("literal_interval")
-            }
+            proto::LiteralVariant::Time(time) => {
+                let unit = ir::TimeUnit::from_proto(time.unit)?;
+                Self::Time(time.value, unit)
+            }
+            proto::LiteralVariant::Duration(duration) => {
+                let unit = ir::TimeUnit::from_proto(duration.unit)?;
+                Self::Duration(duration.value, unit)
+            }
+            proto::LiteralVariant::Interval(interval) => Self::Interval(ir::IntervalValue::new(
+                interval.months,
+                interval.days,
+                interval.nanoseconds,
+            )),
             proto::LiteralVariant::Float64(f) => Self::Float64(f),
-            proto::LiteralVariant::Decimal(_decimal) => {
-                // TODO: Implement decimal parsing from string
-                not_implemented_err!("literal_decimal")
+            proto::LiteralVariant::Decimal(decimal) => {
+                let precision = decimal.precision as u8;
+                let scale = decimal.scale as i8;
+                let value = parse_decimal128(&decimal.value, scale)?;
+                Self::Decimal(value, precision, scale)
+            }
+            proto::LiteralVariant::Series(series) => Self::Series(bincode::deserialize(&series)?),
+            #[cfg(feature = "python")]
+            proto::LiteralVariant::Python(object) => {
+                Self::Python(bincode::deserialize(&object.object)?)
+            }
+            #[cfg(not(feature = "python"))]
+            proto::LiteralVariant::Python(_) => {
+                not_implemented_err!("python literals require the python feature")
             }
             proto::LiteralVariant::Struct(struct_) => {
                 let mut fields = vec![];
@@ -729,18 +1083,25 @@ impl ToFromProto for ir::LiteralValue {
             }
             Self::Float64(f) => proto::LiteralVariant::Float64(*f),
             Self::Decimal(value, precision, scale) => {
+                if *scale < 0 {
+                    not_implemented_err!("decimal literal with negative scale")
+                }
                 proto::LiteralVariant::Decimal(proto::literal::Decimal {
                     value: display_decimal128(*value, *precision, *scale),
+                    precision: *precision as u32,
+                    scale: *scale as i32,
                 })
             }
-            Self::Series(_) => not_implemented_err!("series literal"),
+            Self::Series(series) => proto::LiteralVariant::Series(bincode::serialize(series)?),
             #[cfg(feature = "python")]
-            Self::Python(_) => todo!("python literal"),
+            Self::Python(object) => proto::LiteralVariant::Python(proto::PyObject {
+                object: bincode::serialize(object)?,
+            }),
             Self::Struct(struct_) => {
                 let mut fields = vec![];
                 for field in struct_ {
                     fields.push(proto::literal::r#struct::Field {
-                        name: field.0.to_string(),
+                        name: field.0.name.to_string(),
                         value: Some(field.1.to_proto()?),
                     });
                 }
@@ -777,3 +1138,58 @@ fn display_decimal128(val: i128, _precision: u8, scale: i8) -> String {
         }
     }
 }
+
+/// Parses the string produced by `display_decimal128` back into the unscaled value.
+fn parse_decimal128(value: &str, scale: i8) -> ProtoResult<i128> {
+    let (integral, decimals) = value.split_once('.').unwrap_or((value, ""));
+    if decimals.len() != scale.max(0) as usize {
+        from_proto_err!("decimal {} does not have scale {}", value, scale)
+    }
+    match format!("{integral}{decimals}").parse() {
+        Ok(value) => Ok(value),
+        Err(_) => from_proto_err!("invalid decimal literal: {}", value),
+    }
+}
+
+/// Returns the frame type which the boundary requires (if any) and its offset expression.
+fn window_boundary_to_proto(
+    boundary: &ir::WindowBoundary,
+) -> ProtoResult<(Option<proto::FrameType>, Option<Box<proto::Expr>>)> {
+    let (frame_type, offset) = match boundary {
+        ir::WindowBoundary::UnboundedPreceding | ir::WindowBoundary::UnboundedFollowing => {
+            return Ok((None, None))
+        }
+        ir::WindowBoundary::Offset(offset) => {
+            (proto::FrameType::Rows, ir::LiteralValue::Int64(*offset))
+        }
+        ir::WindowBoundary::RangeOffset(offset) => (proto::FrameType::Range, offset.clone()),
+    };
+    let offset = ir::Expr::Literal(offset).to_proto()?;
+    Ok((Some(frame_type), Some(offset.into())))
+}
+
+/// Returns the boundary for an offset expression, or None if the boundary is unbounded.
+fn window_boundary_from_proto(
+    frame_type: proto::FrameType,
+    offset: Option<Box<proto::Expr>>,
+) -> ProtoResult<Option<ir::WindowBoundary>> {
+    let Some(offset) = offset else {
+        return Ok(None);
+    };
+    let offset = match ir::Expr::from_proto(*offset)? {
+        ir::Expr::Literal(offset) => offset,
+        expr => from_proto_err!("expected a literal window frame offset, found: {}", expr),
+    };
+    let boundary = match (frame_type, offset) {
+        (proto::FrameType::Rows, ir::LiteralValue::Int64(offset)) => {
+            ir::WindowBoundary::Offset(offset)
+        }
+        (proto::FrameType::Rows, offset) => {
+            from_proto_err!("expected an int64 rows offset, found: {}", offset)
+        }
+        (proto::FrameType::Range, offset) => ir::WindowBoundary::RangeOffset(offset),
+        (proto::FrameType::Groups, _) => not_implemented_err!("groups window frame"),
+        (proto::FrameType::Unspecified, _) => from_proto_err!("Unspecified window frame type."),
+    };
+    Ok(Some(boundary))
+}
@@ -147,6 +147,7 @@ impl ToFromProto for ir::DataType {
                     fixed_shape_sparse_tensor.indices_offset,
                 )
             }
+            proto::DataTypeVariant::Geometry(_) => Self::Geometry,
             proto::DataTypeVariant::Unknown(_) => Self::Unknown,
             #[cfg(feature = "python")]
             proto::DataTypeVariant::Python(_) => Self::Python,
@@ -276,6 +277,7 @@ impl ToFromProto for ir::DataType {
                     .into(),
                 )
             }
+            Self::Geometry => proto::DataTypeVariant::Geometry(UNIT),
             Self::Unknown => proto::DataTypeVariant::Unknown(UNIT),
             #[cfg(feature = "python")]
             Self::Python => proto::DataTypeVariant::Python(UNIT),
@@ -315,6 +317,7 @@ impl ToFromProto for ir::TimeUnit {
             proto::TimeUnit::Unspecified => Self::Nanoseconds, // default fallback
             proto::TimeUnit::Nanoseconds => Self::Nanoseconds,
             proto::TimeUnit::Microseconds => Self::Microseconds,
+            proto::TimeUnit::Milliseconds => Self::Milliseconds,
             proto::TimeUnit::Seconds => Self::Seconds,
         })
     }
@@ -323,7 +326,7 @@ impl ToFromProto for ir::TimeUnit {
         let time_unit = match self {
             Self::Nanoseconds => proto::TimeUnit::Nanoseconds,
             Self::Microseconds => proto::TimeUnit::Microseconds,
-            Self::Milliseconds => to_proto_err!("No milliseconds!?"),
+            Self::Milliseconds => proto::TimeUnit::Milliseconds,
             Self::Seconds => proto::TimeUnit::Seconds,
         };
         Ok(time_unit as i32)
@@ -367,3 +370,15 @@ impl ToFromProto for ir::ImageMode {
         Ok(image_mode as i32)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_geometry_round_trip() {
+        let dtype = ir::DataType::Geometry;
+        let message = dtype.to_proto().unwrap();
+        assert_eq!(ir::DataType::from_proto(message).unwrap(), dtype);
+    }
+}
@@ -0,0 +1,615 @@
+use std::sync::Arc;
+
+use common_file_formats::{FileFormat, WriteMode};
+use common_hashable_float_wrapper::FloatWrapper;
+use daft_core::{
+    datatypes::{Int64Array, IntervalValue},
+    series::IntoSeries,
+};
+use proptest::{collection::vec, option, prelude::*};
+
+use super::ToFromProto;
+use crate::{
+    functions::{
+        map::MapExpr,
+        partitioning::PartitioningExpr,
+        sketch::{HashableVecPercentiles, SketchExpr},
+        struct_::StructExpr,
+        FunctionExpr,
+    },
+    rel::{self, LogicalPlan},
+    rex::{
+        expr::bound_col, left_col, resolved_col, right_col, AggExpr, ApproxPercentileParams,
+        Column, Expr, ExprRef, LiteralValue, Operator, PlanRef, ResolvedColumn, SketchType,
+        Subquery, WindowBoundary, WindowExpr, WindowFrame, WindowSpec,
+    },
+    schema::{DataType, Field, Schema, TimeUnit},
+    CountMode,
+};
+
+/// Asserts that converting to protobuf and back produces an equal value.
+fn assert_round_trip<T>(value: &T)
+where
+    T: ToFromProto + PartialEq + std::fmt::Debug,
+{
+    let message = value.to_proto().unwrap();
+    assert_eq!(&T::from_proto(message).unwrap(), value);
+}
+
+fn schema() -> Schema {
+    Schema::new(vec![
+        Field::new("a", DataType::Int64),
+        Field::new("b", DataType::Utf8),
+        Field::new("c", DataType::Float64),
+        Field::new("d", DataType::List(Box::new(DataType::Int64))),
+        Field::new(
+            "s",
+            DataType::Struct(vec![Field::new("x", DataType::Int64)]),
+        ),
+    ])
+}
+
+fn source(cache_key: &str, schema: Schema) -> LogicalPlan {
+    let schema = Arc::new(schema);
+    let info = rel::InMemoryInfo::new(
+        schema.clone(),
+        cache_key.to_string(),
+        None,
+        1,
+        0,
+        0,
+        None,
+        None,
+    );
+    let source = rel::new_source(schema, rel::SourceInfo::InMemory(info)).unwrap();
+    LogicalPlan::Source(source)
+}
+
+fn lit(value: LiteralValue) -> ExprRef {
+    Expr::Literal(value).into()
+}
+
+fn time_unit() -> impl Strategy<Value = TimeUnit> {
+    prop_oneof![
+        Just(TimeUnit::Nanoseconds),
+        Just(TimeUnit::Microseconds),
+        Just(TimeUnit::Milliseconds),
+        Just(TimeUnit::Seconds),
+    ]
+}
+
+fn float() -> impl Strategy<Value = f64> {
+    prop::num::f64::NORMAL | prop::num::f64::ZERO | prop::num::f64::SUBNORMAL
+}
+
+fn scalar_literal() -> impl Strategy<Value = LiteralValue> {
+    prop_oneof![
+        Just(LiteralValue::Null),
+        any::<bool>().prop_map(LiteralValue::Boolean),
+        "\\PC{0,8}".prop_map(LiteralValue::Utf8),
+        vec(any::<u8>(), 0..8).prop_map(LiteralValue::Binary),
+        vec(any::<u8>(), 1..8).prop_map(|v| {
+            let size = v.len();
+            LiteralValue::FixedSizeBinary(v, size)
+        }),
+        any::<i8>().prop_map(LiteralValue::Int8),
+        any::<u8>().prop_map(LiteralValue::UInt8),
+        any::<i16>().prop_map(LiteralValue::Int16),
+        any::<u16>().prop_map(LiteralValue::UInt16),
+        any::<i32>().prop_map(LiteralValue::Int32),
+        any::<u32>().prop_map(LiteralValue::UInt32),
+        any::<i64>().prop_map(LiteralValue::Int64),
+        any::<u64>().prop_map(LiteralValue::UInt64),
+        (
+            any::<i64>(),
+            time_unit(),
+            option::of("[A-Za-z/_+:0-9]{1,16}")
+        )
+            .prop_map(|(v, u, tz)| LiteralValue::Timestamp(v, u, tz)),
+        any::<i32>().prop_map(LiteralValue::Date),
+        (any::<i64>(), time_unit()).prop_map(|(v, u)| LiteralValue::Time(v, u)),
+        (any::<i64>(), time_unit()).prop_map(|(v, u)| LiteralValue::Duration(v, u)),
+        (any::<i32>(), any::<i32>(), any::<i64>())
+            .prop_map(|(m, d, n)| LiteralValue::Interval(IntervalValue::new(m, d, n))),
+        float().prop_map(LiteralValue::Float64),
+        (1u8..=38)
+            .prop_flat_map(|p| (any::<i128>(), Just(p), 0..=p as i8))
+            .prop_map(|(v, p, s)| LiteralValue::Decimal(v, p, s)),
+    ]
+}
+
+fn literal() -> impl Strategy<Value = LiteralValue> {
+    prop_oneof![
+        4 => scalar_literal(),
+        1 => vec(scalar_literal(), 1..4).prop_map(|values| {
+            let fields = values
+                .into_iter()
+                .enumerate()
+                .map(|(i, value)| (format!("f{i}"), value));
+            LiteralValue::new_struct(fields)
+        }),
+    ]
+}
+
+fn dtype() -> impl Strategy<Value = DataType> {
+    prop_oneof![
+        Just(DataType::Boolean),
+        Just(DataType::Int64),
+        Just(DataType::Float32),
+        Just(DataType::Utf8),
+        Just(DataType::Date),
+        (time_unit(), option::of("UTC|America/New_York"))
+            .prop_map(|(u, tz)| DataType::Timestamp(u, tz)),
+        (1usize..=38, 0usize..=10).prop_map(|(p, s)| DataType::Decimal128(p, s)),
+        Just(DataType::List(Box::new(DataType::Int64))),
+    ]
+}
+
+fn operator() -> impl Strategy<Value = Operator> {
+    prop_oneof![
+        Just(Operator::Eq),
+        Just(Operator::EqNullSafe),
+        Just(Operator::NotEq),
+        Just(Operator::Lt),
+        Just(Operator::LtEq),
+        Just(Operator::Gt),
+        Just(Operator::GtEq),
+        Just(Operator::Plus),
+        Just(Operator::Minus),
+        Just(Operator::Multiply),
+        Just(Operator::TrueDivide),
+        Just(Operator::FloorDivide),
+        Just(Operator::Modulus),
+        Just(Operator::And),
+        Just(Operator::Or),
+        Just(Operator::Xor),
+        Just(Operator::ShiftLeft),
+        Just(Operator::ShiftRight),
+    ]
+}
+
+fn field() -> impl Strategy<Value = Field> {
+    ("[a-z]{1,4}", dtype()).prop_map(|(name, dtype)| Field::new(name, dtype))
+}
+
+fn column() -> impl Strategy<Value = ExprRef> {
+    prop_oneof![
+        4 => "[a-d]".prop_map(resolved_col),
+        1 => field().prop_map(left_col),
+        1 => field().prop_map(right_col),
+        1 => (0usize..8, field()).prop_map(|(index, field)| bound_col(index, field)),
+        1 => (field(), option::of("[a-z]{1,4}"), any::<Option<usize>>()).prop_map(
+            |(field, alias, id)| {
+                let plan_ref = match (alias, id) {
+                    (Some(alias), _) => PlanRef::Alias(alias.into()),
+                    (None, Some(id)) => PlanRef::Id(id),
+                    (None, None) => PlanRef::Unqualified,
+                };
+                let column = ResolvedColumn::OuterRef(field, plan_ref);
+                Expr::Column(Column::Resolved(column)).into()
+            }
+        ),
+    ]
+}
+
+fn agg(input: BoxedStrategy<ExprRef>) -> impl Strategy<Value = AggExpr> {
+    let count_mode = prop_oneof![
+        Just(CountMode::All),
+        Just(CountMode::Valid),
+        Just(CountMode::Null),
+    ];
+    let sketch_type = prop_oneof![Just(SketchType::DDSketch), Just(SketchType::HyperLogLog)];
+    prop_oneof![
+        (input.clone(), count_mode).prop_map(|(e, mode)| AggExpr::Count(e, mode)),
+        input.clone().prop_map(AggExpr::CountDistinct),
+        input.clone().prop_map(AggExpr::Sum),
+        (input.clone(), vec(0.0..=1.0f64, 1..4), any::<bool>()).prop_map(
+            |(child, percentiles, force_list_output)| {
+                AggExpr::ApproxPercentile(ApproxPercentileParams {
+                    child,
+                    percentiles: percentiles.into_iter().map(FloatWrapper).collect(),
+                    force_list_output,
+                })
+            }
+        ),
+        input.clone().prop_map(AggExpr::ApproxCountDistinct),
+        (input.clone(), sketch_type.clone()).prop_map(|(e, t)| AggExpr::ApproxSketch(e, t)),
+        (input.clone(), sketch_type).prop_map(|(e, t)| AggExpr::MergeSketch(e, t)),
+        input.clone().prop_map(AggExpr::Mean),
+        input.clone().prop_map(AggExpr::Stddev),
+        input.clone().prop_map(AggExpr::Min),
+        input.clone().prop_map(AggExpr::Max),
+        input.clone().prop_map(AggExpr::BoolAnd),
+        input.clone().prop_map(AggExpr::BoolOr),
+        (input.clone(), any::<bool>()).prop_map(|(e, b)| AggExpr::AnyValue(e, b)),
+        input.clone().prop_map(AggExpr::List),
+        input.clone().prop_map(AggExpr::Set),
+        input.clone().prop_map(AggExpr::Concat),
+        input.clone().prop_map(AggExpr::Skew),
+        (input.clone(), input.clone()).prop_map(|(e, by)| AggExpr::MinBy(e, by)),
+        (input.clone(), input).prop_map(|(e, by)| AggExpr::MaxBy(e, by)),
+    ]
+}
+
+fn window_frame() -> impl Strategy<Value = WindowFrame> {
+    let rows = (option::of(-8i64..8), option::of(-8i64..8)).prop_map(|(start, end)| WindowFrame {
+        start: start.map_or(WindowBoundary::UnboundedPreceding, WindowBoundary::Offset),
+        end: end.map_or(WindowBoundary::UnboundedFollowing, WindowBoundary::Offset),
+    });
+    let range =
+        (option::of(scalar_literal()), option::of(scalar_literal())).prop_map(|(start, end)| {
+            WindowFrame {
+                start: start.map_or(
+                    WindowBoundary::UnboundedPreceding,
+                    WindowBoundary::RangeOffset,
+                ),
+                end: end.map_or(
+                    WindowBoundary::UnboundedFollowing,
+                    WindowBoundary::RangeOffset,
+                ),
+            }
+        });
+    prop_oneof![rows, range]
+}
+
+fn window_spec(input: BoxedStrategy<ExprRef>) -> impl Strategy<Value = WindowSpec> {
+    let order_by = vec((input.clone(), any::<bool>(), any::<bool>()), 0..3);
+    (
+        vec(input, 0..3),
+        order_by,
+        option::of(window_frame()),
+        0usize..4,
+    )
+        .prop_map(|(partition_by, order_by, frame, min_periods)| WindowSpec {
+            partition_by,
+            order_by: order_by.iter().map(|(e, _, _)| e.clone()).collect(),
+            descending: order_by.iter().map(|(_, d, _)| *d).collect(),
+            nulls_first: order_by.iter().map(|(_, _, n)| *n).collect(),
+            frame,
+            min_periods,
+        })
+}
+
+fn window_expr(input: BoxedStrategy<ExprRef>) -> impl Strategy<Value = WindowExpr> {
+    prop_oneof![
+        agg(input.clone()).prop_map(WindowExpr::Agg),
+        Just(WindowExpr::RowNumber),
+        Just(WindowExpr::Rank),
+        Just(WindowExpr::DenseRank),
+        (input.clone(), -4isize..4, option::of(input)).prop_map(|(input, offset, default)| {
+            WindowExpr::Offset {
+                input,
+                offset,
+                default,
+            }
+        }),
+    ]
+}
+
+/// Special forms carry their parameters as named arguments.
+fn special_form(input: BoxedStrategy<ExprRef>) -> impl Strategy<Value = ExprRef> {
+    let func = prop_oneof![
+        (vec(0.0..=1.0f64, 1..4), any::<bool>()).prop_map(|(percentiles, force_list_output)| {
+            FunctionExpr::Sketch(SketchExpr::Percentile {
+                percentiles: HashableVecPercentiles(percentiles),
+                force_list_output,
+            })
+        }),
+        "\\PC{0,8}".prop_map(|name| FunctionExpr::Struct(StructExpr::Get(name))),
+        Just(FunctionExpr::Partitioning(PartitioningExpr::Years)),
+        Just(FunctionExpr::Partitioning(PartitioningExpr::Months)),
+        Just(FunctionExpr::Partitioning(PartitioningExpr::Days)),
+        Just(FunctionExpr::Partitioning(PartitioningExpr::Hours)),
+        any::<i32>().prop_map(|n| FunctionExpr::Partitioning(PartitioningExpr::IcebergBucket(n))),
+        any::<i64>().prop_map(|w| FunctionExpr::Partitioning(PartitioningExpr::IcebergTruncate(w))),
+    ];
+    let map_get = (input.clone(), input.clone()).prop_map(|(map, key)| {
+        let func = FunctionExpr::Map(MapExpr::Get);
+        Expr::Function {
+            func,
+            inputs: vec![map, key],
+        }
+        .into()
+    });
+    let unary = (func, input).prop_map(|(func, input)| {
+        Expr::Function {
+            func,
+            inputs: vec![input],
+        }
+        .into()
+    });
+    prop_oneof![map_get, unary]
+}
+
+fn expr() -> impl Strategy<Value = ExprRef> {
+    let leaf = prop_oneof![column(), literal().prop_map(lit)];
+    leaf.prop_recursive(3, 24, 3, |inner| {
+        prop_oneof![
+            (operator(), inner.clone(), inner.clone())
+                .prop_map(|(op, left, right)| { Expr::BinaryOp { op, left, right }.into() }),
+            (inner.clone(), "[a-z]{1,8}").prop_map(|(e, name)| Expr::Alias(e, name.into()).into()),
+            (inner.clone(), dtype()).prop_map(|(e, dtype)| Expr::Cast(e, dtype).into()),
+            inner.clone().prop_map(|e| Expr::Not(e).into()),
+            inner.clone().prop_map(|e| Expr::IsNull(e).into()),
+            inner.clone().prop_map(|e| Expr::NotNull(e).into()),
+            (inner.clone(), inner.clone()).prop_map(|(e, fill)| Expr::FillNull(e, fill).into()),
+            (inner.clone(), vec(inner.clone(), 0..3))
+                .prop_map(|(e, items)| Expr::IsIn(e, items).into()),
+            (inner.clone(), inner.clone(), inner.clone())
+                .prop_map(|(e, lower, upper)| Expr::Between(e, lower, upper).into()),
+            vec(inner.clone(), 0..3).prop_map(|items| Expr::List(items).into()),
+            (inner.clone(), inner.clone(), inner.clone()).prop_map(
+                |(if_true, if_false, predicate)| {
+                    Expr::IfElse {
+                        if_true,
+                        if_false,
+                        predicate,
+                    }
+                    .into()
+                }
+            ),
+            agg(inner.clone()).prop_map(|agg| Expr::Agg(agg).into()),
+            (window_expr(inner.clone()), window_spec(inner.clone()))
+                .prop_map(|(window_expr, spec)| Expr::Over(window_expr, spec).into()),
+            window_expr(inner.clone())
+                .prop_map(|window_expr| Expr::WindowFunction(window_expr).into()),
+            special_form(inner),
+        ]
+    })
+}
+
+/// Plans whose output schema is the source schema, so any of the columns can be referenced.
+fn plan() -> impl Strategy<Value = LogicalPlan> {
+    let leaf = Just(source("cache_key", schema()));
+    let sort_by = vec(
+        ("[a-c]".prop_map(resolved_col), any::<bool>(), any::<bool>()),
+        1..3,
+    );
+    leaf.prop_recursive(4, 8, 1, move |inner| {
+        let input = inner.prop_map(Arc::new);
+        prop_oneof![
+            (input.clone(), expr()).prop_map(|(input, predicate)| {
+                LogicalPlan::Filter(rel::new_filter(input, predicate).unwrap())
+            }),
+            (input.clone(), any::<u64>()).prop_map(|(input, limit)| {
+                LogicalPlan::Limit(rel::new_limit(input, limit).unwrap())
+            }),
+            input
+                .clone()
+                .prop_map(|input| { LogicalPlan::Distinct(rel::new_distinct(input).unwrap()) }),
+            (input.clone(), sort_by.clone()).prop_map(|(input, sort_by)| {
+                let (sort_by, descending, nulls_first) = unzip_sort_by(sort_by);
+                let sort = rel::new_sort(input, sort_by, descending, nulls_first).unwrap();
+                LogicalPlan::Sort(sort)
+            }),
+            (input.clone(), sort_by.clone(), any::<u64>()).prop_map(|(input, sort_by, limit)| {
+                let (sort_by, descending, nulls_first) = unzip_sort_by(sort_by);
+                let top_n = rel::new_top_n(input, sort_by, descending, nulls_first, limit).unwrap();
+                LogicalPlan::TopN(top_n)
+            }),
+            (
+                input.clone(),
+                0.0..=1.0f64,
+                any::<bool>(),
+                any::<Option<u64>>()
+            )
+                .prop_map(|(input, fraction, with_replacement, seed)| {
+                    let sample = rel::new_sample(input, fraction, with_replacement, seed).unwrap();
+                    LogicalPlan::Sample(sample)
+                }),
+            (input.clone(), repartition_spec()).prop_map(|(input, spec)| {
+                LogicalPlan::Repartition(rel::new_repartition(input, spec).unwrap())
+            }),
+            (input.clone(), 1usize..8, any::<usize>()).prop_map(|(input, world_size, rank)| {
+                let sharder =
+                    rel::Sharder::new(rel::ShardingStrategy::File, world_size, rank % world_size);
+                LogicalPlan::Shard(rel::new_shard(input, sharder).unwrap())
+            }),
+            (input.clone(), "[a-z]{1,8}").prop_map(|(input, name)| {
+                LogicalPlan::SubqueryAlias(rel::new_subquery_alias(input, &name).unwrap())
+            }),
+            (input, source_plan()).prop_map(|(lhs, rhs)| {
+                LogicalPlan::Concat(rel::new_concat(lhs, Arc::new(rhs)).unwrap())
+            }),
+        ]
+    })
+}
+
+fn source_plan() -> impl Strategy<Value = LogicalPlan> {
+    "[a-z]{1,8}".prop_map(|cache_key| source(&cache_key, schema()))
+}
+
+fn repartition_spec() -> impl Strategy<Value = rel::RepartitionSpec> {
+    let num_partitions = option::of(1usize..16);
+    prop_oneof![
+        (
+            num_partitions.clone(),
+            vec("[a-c]".prop_map(resolved_col), 1..3)
+        )
+            .prop_map(|(num_partitions, by)| {
+                rel::RepartitionSpec::Hash(rel::HashRepartitionConfig::new(num_partitions, by))
+            }),
+        num_partitions.prop_map(|num_partitions| {
+            rel::RepartitionSpec::Random(rel::RandomShuffleConfig::new(num_partitions))
+        }),
+        (1usize..16).prop_map(|num_partitions| {
+            rel::RepartitionSpec::IntoPartitions(rel::IntoPartitionsConfig::new(num_partitions))
+        }),
+    ]
+}
+
+fn unzip_sort_by(sort_by: Vec<(ExprRef, bool, bool)>) -> (Vec<ExprRef>, Vec<bool>, Vec<bool>) {
+    let descending = sort_by.iter().map(|(_, d, _)| *d).collect();
+    let nulls_first = sort_by.iter().map(|(_, _, n)| *n).collect();
+    let sort_by = sort_by.into_iter().map(|(e, _, _)| e).collect();
+    (sort_by, descending, nulls_first)
+}
+
+fn join_type() -> impl Strategy<Value = rel::JoinType> {
+    prop_oneof![
+        Just(rel::JoinType::Inner),
+        Just(rel::JoinType::Left),
+        Just(rel::JoinType::Right),
+        Just(rel::JoinType::Outer),
+        Just(rel::JoinType::Anti),
+        Just(rel::JoinType::Semi),
+    ]
+}
+
+fn join_strategy() -> impl Strategy<Value = rel::JoinStrategy> {
+    prop_oneof![
+        Just(rel::JoinStrategy::Hash),
+        Just(rel::JoinStrategy::SortMerge),
+        Just(rel::JoinStrategy::Broadcast),
+        Just(rel::JoinStrategy::Cross),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn test_literal_round_trip(value in literal()) {
+        assert_round_trip(&value);
+    }
+
+    #[test]
+    fn test_expr_round_trip(expr in expr()) {
+        assert_round_trip(&expr);
+    }
+
+    #[test]
+    fn test_plan_round_trip(plan in plan()) {
+        assert_round_trip(&plan);
+    }
+
+    #[test]
+    fn test_subquery_round_trip(plan in plan(), expr in expr()) {
+        let subquery = Subquery::new(plan);
+        assert_round_trip(&Expr::Subquery(subquery.clone()));
+        assert_round_trip(&Expr::InSubquery(expr, subquery.clone()));
+        assert_round_trip(&Expr::Exists(subquery));
+    }
+
+    #[test]
+    fn test_join_round_trip(
+        lhs in plan(),
+        join_type in join_type(),
+        join_strategy in option::of(join_strategy()),
+        with_predicate in any::<bool>(),
+    ) {
+        let rhs = source("rhs", Schema::new(vec![
+            Field::new("x", DataType::Int64),
+            Field::new("y", DataType::Utf8),
+        ]));
+        let on = with_predicate.then(|| {
+            let left = left_col(Field::new("a", DataType::Int64));
+            let right = right_col(Field::new("x", DataType::Int64));
+            Arc::new(Expr::BinaryOp { op: Operator::Eq, left, right })
+        });
+        let join = rel::new_join(lhs, rhs, on, join_type, join_strategy).unwrap();
+        assert_round_trip(&LogicalPlan::Join(join));
+    }
+
+    #[test]
+    fn test_window_round_trip(
+        input in plan(),
+        spec in window_spec("[a-c]".prop_map(resolved_col).boxed()),
+        offset in -4isize..4,
+    ) {
+        let window_functions = vec![
+            WindowExpr::Agg(AggExpr::Sum(resolved_col("a"))),
+            WindowExpr::RowNumber,
+            WindowExpr::Offset { input: resolved_col("b"), offset, default: None },
+        ];
+        let aliases = vec!["sum".to_string(), "row_number".to_string(), "lag".to_string()];
+        let window = rel::new_window(input, window_functions, aliases, spec).unwrap();
+        assert_round_trip(&LogicalPlan::Window(window));
+    }
+
+    #[test]
+    fn test_schema_changing_plan_round_trip(input in plan(), starting_offset in any::<Option<u64>>()) {
+        let input = Arc::new(input);
+        let explode = rel::new_explode(input.clone(), vec![resolved_col("d")]).unwrap();
+        assert_round_trip(&LogicalPlan::Explode(explode));
+        let unpivot = rel::new_unpivot(
+            input.clone(),
+            vec![resolved_col("b")],
+            vec![resolved_col("a"), resolved_col("c")],
+            "variable".to_string(),
+            "value".to_string(),
+        )
+        .unwrap();
+        assert_round_trip(&LogicalPlan::Unpivot(unpivot));
+        let pivot = rel::new_pivot(
+            input.clone(),
+            vec![resolved_col("a")],
+            resolved_col("b"),
+            resolved_col("c"),
+            Expr::Agg(AggExpr::Sum(resolved_col("c"))),
+            vec!["x".to_string(), "y".to_string()],
+        )
+        .unwrap();
+        assert_round_trip(&LogicalPlan::Pivot(pivot));
+        let monotonically_increasing_id =
+            rel::new_monotonically_increasing_id(input.clone(), "id", starting_offset).unwrap();
+        assert_round_trip(&LogicalPlan::MonotonicallyIncreasingId(monotonically_increasing_id));
+        let aggregate = rel::new_aggregate(
+            input,
+            vec![Expr::Agg(AggExpr::AnyValue(resolved_col("c"), true))],
+            vec![Expr::Column(Column::Resolved(ResolvedColumn::Basic("a".into())))],
+        )
+        .unwrap();
+        assert_round_trip(&LogicalPlan::Aggregate(aggregate));
+    }
+}
+
+#[test]
+fn test_series_literal_round_trip() {
+    let series = Int64Array::from(("s", vec![1, 2, 3])).into_series();
+    assert_round_trip(&LiteralValue::Series(series));
+}
+
+#[test]
+fn test_sink_round_trip() {
+    let input = source("cache_key", schema());
+    let sink_info = rel::SinkInfo::OutputFileInfo(rel::OutputFileInfo {
+        root_dir: "s3://bucket/path".to_string(),
+        write_mode: WriteMode::Overwrite,
+        file_format: FileFormat::Parquet,
+        partition_cols: Some(vec![resolved_col("a")]),
+        compression: Some("zstd".to_string()),
+        io_config: None,
+    });
+    let schema = Schema::new(vec![Field::new("path", DataType::Utf8)]);
+    let sink = rel::new_sink(input, schema, Arc::new(sink_info)).unwrap();
+    assert_round_trip(&LogicalPlan::Sink(sink));
+}
+
+#[test]
+fn test_recursive_cte_round_trip() {
+    let anchor = source("anchor", schema());
+    let working_table = Arc::new(source("working_table", schema()));
+    let predicate = Expr::BinaryOp {
+        op: Operator::Lt,
+        left: resolved_col("a"),
+        right: lit(LiteralValue::Int64(10)),
+    };
+    let recursive = rel::new_filter(working_table, predicate).unwrap();
+    let recursive_cte = rel::new_recursive_cte(
+        "t",
+        "working_table".to_string(),
+        anchor,
+        LogicalPlan::Filter(recursive),
+        true,
+    )
+    .unwrap();
+    assert_round_trip(&LogicalPlan::RecursiveCte(recursive_cte));
+}
+
+#[test]
+fn test_mixed_window_frame_is_rejected() {
+    let frame = WindowFrame {
+        start: WindowBoundary::Offset(-1),
+        end: WindowBoundary::RangeOffset(LiteralValue::Int64(1)),
+    };
+    assert!(frame.to_proto().is_err());
+}
@@ -6,6 +6,7 @@ mod dispatcher;
 mod intermediate_ops;
 mod pipeline;
 mod progress_bar;
+mod recursive_cte;
 mod resource_manager;
 mod run;
 mod runtime_stats;
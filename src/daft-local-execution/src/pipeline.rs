@@ -14,7 +14,7 @@ use daft_dsl::join::get_common_join_cols;
 use daft_local_plan::{
     ActorPoolProject, CommitWrite, Concat, CrossJoin, Dedup, EmptyScan, Explode, Filter,
     HashAggregate, HashJoin, InMemoryScan, Limit, LocalPhysicalPlan, MonotonicallyIncreasingId,
-    PhysicalWrite, Pivot, Project, Sample, Sort, TopN, UnGroupedAggregate, Unpivot,
+    PhysicalWrite, Pivot, Project, RecursiveCte, Sample, Sort, TopN, UnGroupedAggregate, Unpivot,
     WindowOrderByOnly, WindowPartitionAndDynamicFrame, WindowPartitionAndOrderBy,
     WindowPartitionOnly,
 };
@@ -55,6 +55,7 @@ use crate::{
         window_partition_only::WindowPartitionOnlySink,
         write::{WriteFormat, WriteSink},
     },
+    recursive_cte::RecursiveCteNode,
     sources::{empty_scan::EmptyScanSource, in_memory::InMemorySource, source::SourceNode},
     state_bridge::BroadcastStateBridge,
     streaming_sink::{
@@ -374,6 +375,25 @@ pub fn physical_plan_to_pipeline(
             .arced();
             SourceNode::new(in_memory_source, stats_state.clone(), ctx).boxed()
         }
+        LocalPhysicalPlan::RecursiveCte(RecursiveCte {
+            anchor,
+            recursive,
+            working_table_key,
+            is_all,
+            schema,
+            stats_state,
+        }) => RecursiveCteNode::new(
+            anchor,
+            recursive,
+            working_table_key.clone(),
+            *is_all,
+            schema.clone(),
+            stats_state.clone(),
+            psets,
+            cfg,
+            ctx,
+        )?
+        .boxed(),
         LocalPhysicalPlan::Project(Project {
             input,
             projection,
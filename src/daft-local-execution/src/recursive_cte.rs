@@ -0,0 +1,368 @@
+use std::{collections::HashMap, sync::Arc};
+
+use capitalize::Capitalize;
+use common_daft_config::DaftExecutionConfig;
+use common_display::tree::TreeDisplay;
+use common_error::{DaftError, DaftResult};
+use common_treenode::{TreeNode, TreeNodeRecursion};
+use daft_core::prelude::SchemaRef;
+use daft_dsl::{expr::bound_expr::BoundExpr, lit, resolved_col};
+use daft_local_plan::{LocalPhysicalPlan, LocalPhysicalPlanRef};
+use daft_logical_plan::stats::StatsState;
+use daft_micropartition::{
+    partitioning::{MicroPartitionSet, PartitionSetCache, PartitionSetRef},
+    MicroPartition, MicroPartitionRef,
+};
+
+use crate::{
+    channel::{create_channel, Receiver},
+    pipeline::{physical_plan_to_pipeline, NodeInfo, PipelineNode, RuntimeContext},
+    progress_bar::ProgressBarColor,
+    resource_manager::MemoryManager,
+    runtime_stats::{CountingSender, RuntimeStatsContext, RuntimeStatsEventHandler},
+    ExecutionRuntimeContext,
+};
+
+const ROW_IDX_COLUMN: &str = "__recursive_cte_row_idx__";
+
+/// The partition sets that the anchor and recursive term of a recursive CTE scan, including the
+/// working table of the current iteration.
+///
+/// Unlike the partition set cache that the pipeline is built from, this holds on to the partition
+/// sets, since the recursive term is planned again after the pipeline has been built.
+#[derive(Debug, Default, Clone)]
+struct RecursiveCtePartitionSets {
+    psets: HashMap<String, PartitionSetRef<MicroPartitionRef>>,
+}
+
+impl RecursiveCtePartitionSets {
+    fn new(
+        plans: &[&LocalPhysicalPlanRef],
+        psets: &(impl PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>> + ?Sized),
+    ) -> Self {
+        let mut in_memory_psets = HashMap::new();
+        for plan in plans {
+            let _ = plan.apply(|node| {
+                if let LocalPhysicalPlan::InMemoryScan(scan) = node.as_ref()
+                    && let Some(pset) = psets.get_partition_set(&scan.info.cache_key)
+                {
+                    in_memory_psets.insert(scan.info.cache_key.clone(), pset);
+                }
+                Ok(TreeNodeRecursion::Continue)
+            });
+        }
+        Self {
+            psets: in_memory_psets,
+        }
+    }
+
+    fn with_working_table(&self, key: &str, working_table: Vec<MicroPartitionRef>) -> Self {
+        let mut psets = self.psets.clone();
+        let working_table = MicroPartitionSet::new(working_table.into_iter().enumerate());
+        psets.insert(key.to_string(), Arc::new(working_table));
+        Self { psets }
+    }
+}
+
+impl PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>> for RecursiveCtePartitionSets {
+    fn get_partition_set(&self, key: &str) -> Option<PartitionSetRef<MicroPartitionRef>> {
+        self.psets.get(key).cloned()
+    }
+
+    fn get_all_partition_sets(&self) -> Vec<PartitionSetRef<MicroPartitionRef>> {
+        self.psets.values().cloned().collect()
+    }
+
+    fn put_partition_set(&self, _key: &str, _partition_set: &Arc<MicroPartitionSet>) {
+        unreachable!("Partition sets of a recursive CTE are only bound by the RecursiveCteNode")
+    }
+
+    fn rm_partition_set(&self, _key: &str) {
+        unreachable!("Partition sets of a recursive CTE are only bound by the RecursiveCteNode")
+    }
+
+    fn clear(&self) {
+        unreachable!("Partition sets of a recursive CTE are only bound by the RecursiveCteNode")
+    }
+}
+
+/// The rows produced so far by a recursive CTE with `UNION` semantics.
+struct SeenRows {
+    rows: MicroPartition,
+}
+
+impl SeenRows {
+    fn new(schema: SchemaRef) -> Self {
+        Self {
+            rows: MicroPartition::empty(Some(schema)),
+        }
+    }
+
+    /// Deduplicates `produced`, discards the rows that were already seen, and returns the remaining
+    /// rows after adding them to the seen rows.
+    fn insert_new_rows(&mut self, produced: &[MicroPartitionRef]) -> DaftResult<MicroPartitionRef> {
+        let schema = self.rows.schema();
+        let num_seen = self.rows.len();
+
+        // Seen rows are unique and come first, so a produced row survives the dedup only if it
+        // is the first occurrence of a row that was not seen before.
+        let combined = MicroPartition::concat(
+            std::iter::once(&self.rows).chain(produced.iter().map(|mp| mp.as_ref())),
+        )?
+        .add_monotonically_increasing_id(0, ROW_IDX_COLUMN)?;
+        let combined_schema = combined.schema();
+        let columns = schema
+            .names()
+            .into_iter()
+            .map(|name| BoundExpr::try_new(resolved_col(name), &combined_schema))
+            .collect::<DaftResult<Vec<_>>>()?;
+        let is_new = BoundExpr::try_new(
+            resolved_col(ROW_IDX_COLUMN).gt_eq(lit(num_seen as u64)),
+            &combined_schema,
+        )?;
+        let new_rows = combined
+            .dedup(&columns)?
+            .filter(&[is_new])?
+            .eval_expression_list(&columns)?;
+
+        self.rows = MicroPartition::concat([&self.rows, &new_rows])?;
+        Ok(Arc::new(new_rows))
+    }
+}
+
+/// Executes a recursive CTE by running the anchor, and then repeatedly planning and running the
+/// recursive term against the rows of the previous iteration until no new rows are produced.
+pub(crate) struct RecursiveCteNode {
+    anchor: Box<dyn PipelineNode>,
+    recursive: LocalPhysicalPlanRef,
+    /// Pipeline of the recursive term without a working table, only used for display.
+    recursive_display: Box<dyn PipelineNode>,
+    working_table_key: String,
+    is_all: bool,
+    schema: SchemaRef,
+    psets: RecursiveCtePartitionSets,
+    cfg: Arc<DaftExecutionConfig>,
+    runtime_stats: Arc<RuntimeStatsContext>,
+    plan_stats: StatsState,
+    node_info: NodeInfo,
+}
+
+impl RecursiveCteNode {
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        anchor: &LocalPhysicalPlanRef,
+        recursive: &LocalPhysicalPlanRef,
+        working_table_key: String,
+        is_all: bool,
+        schema: SchemaRef,
+        plan_stats: StatsState,
+        psets: &(impl PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>> + ?Sized),
+        cfg: &Arc<DaftExecutionConfig>,
+        ctx: &RuntimeContext,
+    ) -> crate::Result<Self> {
+        let node_info = ctx.next_node_info("RecursiveCte");
+        let runtime_stats = RuntimeStatsContext::new(node_info.clone());
+        let anchor_node = physical_plan_to_pipeline(anchor, psets, cfg, ctx)?;
+        let recursive_display = physical_plan_to_pipeline(recursive, psets, cfg, ctx)?;
+        Ok(Self {
+            anchor: anchor_node,
+            recursive: recursive.clone(),
+            recursive_display,
+            working_table_key,
+            is_all,
+            schema,
+            psets: RecursiveCtePartitionSets::new(&[anchor, recursive], psets),
+            cfg: cfg.clone(),
+            runtime_stats,
+            plan_stats,
+            node_info,
+        })
+    }
+
+    pub(crate) fn boxed(self) -> Box<dyn PipelineNode> {
+        Box::new(self)
+    }
+
+    fn multiline_display(&self) -> Vec<String> {
+        vec![
+            "RecursiveCte".to_string(),
+            format!("Union all = {}", self.is_all),
+            format!("Schema = {}", self.schema.short_string()),
+        ]
+    }
+}
+
+/// Runs the recursive term against `working_table` in a pipeline of its own.
+async fn run_recursive_term(
+    recursive: &LocalPhysicalPlanRef,
+    psets: &RecursiveCtePartitionSets,
+    cfg: &Arc<DaftExecutionConfig>,
+    maintain_order: bool,
+    memory_manager: &Arc<MemoryManager>,
+    rt_stats_handler: &Arc<RuntimeStatsEventHandler>,
+) -> DaftResult<Vec<MicroPartitionRef>> {
+    let pipeline = physical_plan_to_pipeline(recursive, psets, cfg, &RuntimeContext::new())?;
+    let mut iteration_handle = ExecutionRuntimeContext::new(
+        cfg.default_morsel_size,
+        memory_manager.clone(),
+        None,
+        rt_stats_handler.clone(),
+    );
+    let receiver = pipeline.start(maintain_order, &mut iteration_handle)?;
+
+    let mut produced = vec![];
+    while let Some(part) = receiver.recv().await {
+        if !part.is_empty() {
+            produced.push(part);
+        }
+    }
+    while let Some(result) = iteration_handle.join_next().await {
+        match result {
+            Ok(Err(e)) | Err(e) => {
+                iteration_handle.shutdown().await;
+                return Err(e.into());
+            }
+            _ => {}
+        }
+    }
+    Ok(produced)
+}
+
+impl TreeDisplay for RecursiveCteNode {
+    fn display_as(&self, level: common_display::DisplayLevel) -> String {
+        use std::fmt::Write;
+        let mut display = String::new();
+
+        use common_display::DisplayLevel;
+        match level {
+            DisplayLevel::Compact => {
+                writeln!(display, "{}", self.name()).unwrap();
+            }
+            level => {
+                let multiline_display = self.multiline_display().join("\n");
+                writeln!(display, "{}", multiline_display).unwrap();
+                if let StatsState::Materialized(stats) = &self.plan_stats {
+                    writeln!(display, "Stats = {}", stats).unwrap();
+                }
+                if matches!(level, DisplayLevel::Verbose) {
+                    let rt_result = self.runtime_stats.render();
+                    for (name, value) in rt_result {
+                        writeln!(display, "{} = {}", name.capitalize(), value).unwrap();
+                    }
+                }
+            }
+        }
+        display
+    }
+
+    fn get_children(&self) -> Vec<&dyn TreeDisplay> {
+        vec![
+            self.anchor.as_tree_display(),
+            self.recursive_display.as_tree_display(),
+        ]
+    }
+}
+
+impl PipelineNode for RecursiveCteNode {
+    fn children(&self) -> Vec<&dyn PipelineNode> {
+        vec![self.anchor.as_ref(), self.recursive_display.as_ref()]
+    }
+
+    fn name(&self) -> &'static str {
+        "RecursiveCte"
+    }
+
+    fn start(
+        &self,
+        maintain_order: bool,
+        runtime_handle: &mut ExecutionRuntimeContext,
+    ) -> crate::Result<Receiver<Arc<MicroPartition>>> {
+        let progress_bar = runtime_handle.make_progress_bar(
+            self.name(),
+            ProgressBarColor::Magenta,
+            self.node_id(),
+            self.runtime_stats.clone(),
+        );
+        let anchor_receiver = self.anchor.start(maintain_order, runtime_handle)?;
+
+        let (destination_sender, destination_receiver) = create_channel(0);
+        let counting_sender = CountingSender::new(
+            destination_sender,
+            self.runtime_stats.clone(),
+            progress_bar,
+            runtime_handle.runtime_stats_handler(),
+        );
+
+        let recursive = self.recursive.clone();
+        let working_table_key = self.working_table_key.clone();
+        let is_all = self.is_all;
+        let schema = self.schema.clone();
+        let psets = self.psets.clone();
+        let cfg = self.cfg.clone();
+        let memory_manager = runtime_handle.memory_manager();
+        let rt_stats_handler = runtime_handle.runtime_stats_handler();
+        runtime_handle.spawn_local(
+            async move {
+                let mut seen = (!is_all).then(|| SeenRows::new(schema.clone()));
+
+                let mut anchor = vec![];
+                while let Some(part) = anchor_receiver.recv().await {
+                    if !part.is_empty() {
+                        anchor.push(part);
+                    }
+                }
+                let mut working_table = match &mut seen {
+                    Some(seen) => vec![seen.insert_new_rows(&anchor)?],
+                    None => anchor,
+                };
+
+                let mut iterations = 0;
+                while working_table.iter().any(|part| !part.is_empty()) {
+                    for part in &working_table {
+                        if counting_sender.send(part.clone()).await.is_err() {
+                            return Ok(());
+                        }
+                    }
+
+                    if iterations == cfg.recursive_cte_max_iterations {
+                        return Err(DaftError::ComputeError(format!(
+                            "Recursive CTE did not reach a fixpoint after {iterations} iterations. The limit can be raised with `recursive_cte_max_iterations` in the execution config."
+                        )));
+                    }
+                    iterations += 1;
+
+                    let iteration_psets =
+                        psets.with_working_table(&working_table_key, working_table);
+                    let produced = run_recursive_term(
+                        &recursive,
+                        &iteration_psets,
+                        &cfg,
+                        maintain_order,
+                        &memory_manager,
+                        &rt_stats_handler,
+                    )
+                    .await?;
+                    working_table = match &mut seen {
+                        Some(seen) => vec![seen.insert_new_rows(&produced)?],
+                        None => produced,
+                    };
+                }
+                Ok(())
+            },
+            self.name(),
+        );
+        Ok(destination_receiver)
+    }
+
+    fn as_tree_display(&self) -> &dyn TreeDisplay {
+        self
+    }
+
+    fn node_id(&self) -> usize {
+        self.node_info.id
+    }
+
+    fn plan_id(&self) -> Arc<str> {
+        Arc::from(self.node_info.context.get("plan_id").unwrap().clone())
+    }
+}
@@ -14,8 +14,8 @@ pub use plan::LanceWrite;
 pub use plan::{
     ActorPoolProject, CommitWrite, Concat, CrossJoin, Dedup, EmptyScan, Explode, Filter,
     HashAggregate, HashJoin, InMemoryScan, Limit, LocalPhysicalPlan, LocalPhysicalPlanRef,
-    MonotonicallyIncreasingId, PhysicalScan, PhysicalWrite, Pivot, Project, Repartition, Sample,
-    Sort, TopN, UnGroupedAggregate, Unpivot, WindowOrderByOnly, WindowPartitionAndDynamicFrame,
+    MonotonicallyIncreasingId, PhysicalScan, PhysicalWrite, Pivot, Project, RecursiveCte,
+    Repartition, Sample, Sort, TopN, UnGroupedAggregate, Unpivot, WindowOrderByOnly, WindowPartitionAndDynamicFrame,
     WindowPartitionAndOrderBy, WindowPartitionOnly,
 };
 #[cfg(feature = "python")]
@@ -50,6 +50,7 @@ pub enum LocalPhysicalPlan {
     Dedup(Dedup),
     Pivot(Pivot),
     Concat(Concat),
+    RecursiveCte(RecursiveCte),
     HashJoin(HashJoin),
     CrossJoin(CrossJoin),
     // SortMergeJoin(SortMergeJoin),
@@ -108,6 +109,7 @@ impl LocalPhysicalPlan {
             | Self::Dedup(Dedup { stats_state, .. })
             | Self::Pivot(Pivot { stats_state, .. })
             | Self::Concat(Concat { stats_state, .. })
+            | Self::RecursiveCte(RecursiveCte { stats_state, .. })
             | Self::HashJoin(HashJoin { stats_state, .. })
             | Self::CrossJoin(CrossJoin { stats_state, .. })
             | Self::PhysicalWrite(PhysicalWrite { stats_state, .. })
@@ -587,6 +589,25 @@ impl LocalPhysicalPlan {
         .arced()
     }
 
+    pub(crate) fn recursive_cte(
+        anchor: LocalPhysicalPlanRef,
+        recursive: LocalPhysicalPlanRef,
+        working_table_key: String,
+        is_all: bool,
+        stats_state: StatsState,
+    ) -> LocalPhysicalPlanRef {
+        let schema = anchor.schema().clone();
+        Self::RecursiveCte(RecursiveCte {
+            anchor,
+            recursive,
+            working_table_key,
+            is_all,
+            schema,
+            stats_state,
+        })
+        .arced()
+    }
+
     pub fn physical_write(
         input: LocalPhysicalPlanRef,
         data_schema: SchemaRef,
@@ -709,6 +730,7 @@ impl LocalPhysicalPlan {
             | Self::Explode(Explode { schema, .. })
             | Self::Unpivot(Unpivot { schema, .. })
             | Self::Concat(Concat { schema, .. })
+            | Self::RecursiveCte(RecursiveCte { schema, .. })
             | Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { schema, .. })
             | Self::WindowPartitionOnly(WindowPartitionOnly { schema, .. })
             | Self::WindowPartitionAndOrderBy(WindowPartitionAndOrderBy { schema, .. })
@@ -794,6 +816,9 @@ impl LocalPhysicalPlan {
 
             Self::HashJoin(HashJoin { left, right, .. }) => vec![left.clone(), right.clone()],
             Self::CrossJoin(CrossJoin { left, right, .. }) => vec![left.clone(), right.clone()],
+            Self::RecursiveCte(RecursiveCte {
+                anchor, recursive, ..
+            }) => vec![anchor.clone(), recursive.clone()],
             #[cfg(feature = "python")]
             Self::CatalogWrite(CatalogWrite { input, .. }) => vec![input.clone()],
             #[cfg(feature = "python")]
@@ -848,6 +873,7 @@ impl LocalPhysicalPlan {
                 Self::HashJoin(_) => panic!("LocalPhysicalPlan::with_new_children: HashJoin should have 2 children"),
                 Self::CrossJoin(_) => panic!("LocalPhysicalPlan::with_new_children: CrossJoin should have 2 children"),
                 Self::Concat(_) => panic!("LocalPhysicalPlan::with_new_children: Concat should have 2 children"),
+                Self::RecursiveCte(_) => panic!("LocalPhysicalPlan::with_new_children: RecursiveCte should have 2 children"),
             },
             [new_left, new_right] => match self {
                 Self::HashJoin(HashJoin {  left_on, right_on, null_equals_null, join_type, schema, stats_state, .. }) => {
@@ -859,6 +885,9 @@ impl LocalPhysicalPlan {
                 Self::Concat(Concat {  ..}) => {
                     Self::concat(new_left.clone(), new_right.clone(), StatsState::NotMaterialized)
                 }
+                Self::RecursiveCte(RecursiveCte { working_table_key, is_all, stats_state, .. }) => {
+                    Self::recursive_cte(new_left.clone(), new_right.clone(), working_table_key.clone(), *is_all, stats_state.clone())
+                }
                 _ => panic!("LocalPhysicalPlan::with_new_children: Wrong number of children"),
             },
             _ => panic!("LocalPhysicalPlan::with_new_children: Wrong number of children"),
@@ -1096,6 +1125,18 @@ pub struct Concat {
     pub stats_state: StatsState,
 }
 
+/// Iterates `recursive` until it produces no new rows, binding the rows of the previous iteration
+/// to the in-memory scan of `working_table_key`. See [`daft_logical_plan::ops::RecursiveCte`].
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RecursiveCte {
+    pub anchor: LocalPhysicalPlanRef,
+    pub recursive: LocalPhysicalPlanRef,
+    pub working_table_key: String,
+    pub is_all: bool,
+    pub schema: SchemaRef,
+    pub stats_state: StatsState,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PhysicalWrite {
     pub input: LocalPhysicalPlanRef,
@@ -359,6 +359,17 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                 concat.stats_state.clone(),
             ))
         }
+        LogicalPlan::RecursiveCte(recursive_cte) => {
+            let anchor = translate(&recursive_cte.anchor)?;
+            let recursive = translate(&recursive_cte.recursive)?;
+            Ok(LocalPhysicalPlan::recursive_cte(
+                anchor,
+                recursive,
+                recursive_cte.working_table_key.clone(),
+                recursive_cte.is_all,
+                recursive_cte.stats_state.clone(),
+            ))
+        }
         LogicalPlan::Repartition(repartition) => {
             log::warn!("Repartition not supported on the NativeRunner. This will be a no-op. Please use the RayRunner instead if you need to repartition");
             translate(&repartition.input)
@@ -16,11 +16,13 @@ use common_treenode::TreeNode;
 use daft_algebra::boolean::combine_conjunction;
 use daft_core::join::{JoinStrategy, JoinType};
 use daft_dsl::{
-    left_col, resolved_col, right_col, unresolved_col, Column, Expr, ExprRef, UnresolvedColumn,
-    WindowSpec,
+    left_col, lit, null_lit, resolved_col, right_col, unresolved_col, Column, Expr, ExprRef,
+    UnresolvedColumn, WindowSpec,
 };
+use daft_functions::grouping::{contains_grouping, Grouping, GroupingId};
 use daft_schema::schema::{Schema, SchemaRef};
 use indexmap::IndexSet;
+use itertools::Itertools;
 use resolve_expr::ExprResolver;
 #[cfg(feature = "python")]
 use {
@@ -41,7 +43,7 @@ use crate::{
         join::{JoinOptions, JoinPredicate},
         SetQuantifier, UnionStrategy,
     },
-    optimization::OptimizerBuilder,
+    optimization::{is_count_column, pull_up_correlated_cols, OptimizerBuilder},
     partitioning::{
         HashRepartitionConfig, IntoPartitionsConfig, RandomShuffleConfig, RepartitionSpec,
     },
@@ -494,6 +496,12 @@ impl LogicalPlanBuilder {
         agg_exprs: Vec<ExprRef>,
         groupby_exprs: Vec<ExprRef>,
     ) -> DaftResult<Self> {
+        if agg_exprs.iter().any(contains_grouping) {
+            return Err(DaftError::ValueError(
+                "grouping() and grouping_id() can only be used in an aggregation with grouping sets, ROLLUP or CUBE".to_string(),
+            ));
+        }
+
         let groupby_resolver = ExprResolver::default();
         let groupby_exprs = groupby_resolver.resolve(groupby_exprs, self.plan.clone())?;
 
@@ -505,6 +513,127 @@ impl LogicalPlanBuilder {
         Ok(self.with_new_plan(logical_plan))
     }
 
+    /// Aggregates over several grouping sets in a single pass, as in `GROUP BY GROUPING SETS`.
+    ///
+    /// Each grouping set holds indices into `groupby_exprs`, and the group keys that are not part of
+    /// a grouping set are null in its rows. The input is expanded with a copy of each row per
+    /// grouping set, which is aggregated by the group keys and the index of the grouping set.
+    pub fn aggregate_grouping_sets(
+        &self,
+        agg_exprs: Vec<ExprRef>,
+        groupby_exprs: Vec<ExprRef>,
+        grouping_sets: Vec<Vec<usize>>,
+    ) -> DaftResult<Self> {
+        let groupby_resolver = ExprResolver::default();
+        let groupby_exprs = groupby_resolver.resolve(groupby_exprs, self.plan.clone())?;
+
+        if grouping_sets.is_empty() {
+            return Err(DaftError::ValueError(
+                "Expected at least one grouping set".to_string(),
+            ));
+        }
+        if let Some(i) = grouping_sets
+            .iter()
+            .flatten()
+            .find(|i| **i >= groupby_exprs.len())
+        {
+            return Err(DaftError::ValueError(format!(
+                "Grouping set index {i} is out of bounds for {} grouping expressions",
+                groupby_exprs.len()
+            )));
+        }
+
+        let agg_exprs = agg_exprs
+            .into_iter()
+            .map(|e| self.rewrite_grouping_functions(e, &groupby_exprs, &grouping_sets))
+            .collect::<DaftResult<Vec<_>>>()?;
+
+        let schema = self.schema();
+        let grouping_set = resolved_col(ops::GROUPING_SET_COLUMN);
+        let mut keys = groupby_exprs
+            .iter()
+            .enumerate()
+            .map(|(i, e)| {
+                let containing = grouping_sets
+                    .iter()
+                    .positions(|set| set.contains(&i))
+                    .map(|j| lit(j as u64))
+                    .collect::<Vec<_>>();
+                if containing.len() == grouping_sets.len() {
+                    return Ok(e.clone());
+                }
+                let null = null_lit().cast(&e.get_type(&schema)?);
+                let key = if containing.is_empty() {
+                    null
+                } else {
+                    grouping_set
+                        .clone()
+                        .is_in(containing)
+                        .if_else(e.clone(), null)
+                };
+                Ok(key.alias(e.name()))
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        keys.push(grouping_set.clone());
+
+        let set_indices = Expr::List((0..grouping_sets.len()).map(|j| lit(j as u64)).collect())
+            .arced()
+            .alias(ops::GROUPING_SET_COLUMN);
+        self.with_columns(vec![set_indices])?
+            .explode(vec![grouping_set])?
+            .aggregate(agg_exprs, keys)?
+            .exclude(vec![ops::GROUPING_SET_COLUMN.to_string()])
+    }
+
+    /// Replaces `grouping()` and `grouping_id()` calls with their values for each grouping set.
+    fn rewrite_grouping_functions(
+        &self,
+        expr: ExprRef,
+        groupby_exprs: &[ExprRef],
+        grouping_sets: &[Vec<usize>],
+    ) -> DaftResult<ExprRef> {
+        use common_treenode::Transformed;
+
+        if !contains_grouping(&expr) {
+            return Ok(expr);
+        }
+        let name = expr.name().to_string();
+        let rewritten = expr
+            .transform(|e| match e.as_ref() {
+                Expr::ScalarFunction(sf)
+                    if sf.is_function_type::<Grouping>() || sf.is_function_type::<GroupingId>() =>
+                {
+                    let args = ExprResolver::default()
+                        .resolve(sf.inputs.clone().into_inner(), self.plan.clone())?;
+                    let positions = if args.is_empty() {
+                        (0..groupby_exprs.len()).collect()
+                    } else {
+                        args.iter()
+                            .map(|arg| {
+                                groupby_exprs.iter().position(|g| g == arg).ok_or_else(|| {
+                                    DaftError::ValueError(format!(
+                                        "Argument of {}() must be a grouping expression, got {arg}",
+                                        sf.name()
+                                    ))
+                                })
+                            })
+                            .collect::<DaftResult<Vec<_>>>()?
+                    };
+                    Ok(Transformed::yes(ops::grouping_id_expr(
+                        &positions,
+                        grouping_sets,
+                    )))
+                }
+                _ => Ok(Transformed::no(e)),
+            })?
+            .data;
+        Ok(if rewritten.name() == name {
+            rewritten
+        } else {
+            rewritten.alias(name)
+        })
+    }
+
     pub fn pivot(
         &self,
         group_by: Vec<ExprRef>,
@@ -608,6 +737,156 @@ impl LogicalPlanBuilder {
         self.join(right, None, vec![], JoinType::Inner, None, options)
     }
 
+    /// Joins this plan with a lateral subquery, i.e. a subquery that may reference columns of this plan.
+    ///
+    /// Equality predicates between the subquery and this plan are pulled up out of the subquery
+    /// and evaluated by the join, so that the subquery is only computed once.
+    pub fn lateral_join<Right: Into<LogicalPlanRef>>(
+        &self,
+        right: Right,
+        on: Option<ExprRef>,
+        join_type: JoinType,
+        options: JoinOptions,
+    ) -> DaftResult<Self> {
+        if !matches!(join_type, JoinType::Inner | JoinType::Left) {
+            return Err(DaftError::ValueError(format!(
+                "Lateral joins must be inner or left joins, found: {join_type}"
+            )));
+        }
+
+        let left_plan = self.plan.clone();
+        let correlated_plan: LogicalPlanRef = right.into();
+        let right_schema = correlated_plan.schema();
+
+        let (right_plan, subquery_on, outer_on) = pull_up_correlated_cols(correlated_plan.clone())?;
+
+        let expr_resolver = ExprResolver::default();
+        let on = on
+            .map(|expr| expr_resolver.resolve_join_on(expr, left_plan.clone(), right_plan.clone()))
+            .transpose()?;
+
+        let correlated_on = combine_conjunction(
+            outer_on
+                .into_iter()
+                .zip(subquery_on)
+                .map(|(o, s)| {
+                    Ok(o.to_left_cols(left_plan.schema())?
+                        .eq(s.to_right_cols(right_plan.schema())?))
+                })
+                .collect::<DaftResult<Vec<_>>>()?,
+        );
+
+        let on = match (correlated_on, on) {
+            (Some(c), Some(o)) => Some(c.and(o)),
+            (correlated_on, on) => correlated_on.or(on),
+        };
+
+        // the subquery may have gained columns needed by the correlated predicates
+        let pulled_up = right_plan
+            .schema()
+            .field_names()
+            .map(|name| !right_schema.has_field(name))
+            .collect::<Vec<_>>();
+        // counts of unmatched rows are 0 rather than null
+        let fill_count = right_plan
+            .schema()
+            .field_names()
+            .map(|name| join_type == JoinType::Left && is_count_column(&correlated_plan, name))
+            .collect::<Vec<_>>();
+
+        let (left_plan, right_plan, on) = ops::join::Join::deduplicate_join_columns(
+            left_plan,
+            right_plan,
+            on,
+            &[],
+            join_type,
+            options,
+        )?;
+
+        let pulled_up_names = right_plan
+            .schema()
+            .field_names()
+            .zip(pulled_up)
+            .filter(|(_, pulled_up)| *pulled_up)
+            .map(|(name, _)| name.to_string())
+            .collect::<HashSet<_>>();
+        let count_names = right_plan
+            .schema()
+            .field_names()
+            .zip(fill_count)
+            .filter(|(_, fill_count)| *fill_count)
+            .map(|(name, _)| name.to_string())
+            .collect::<HashSet<_>>();
+
+        let join: LogicalPlanRef = Arc::new(
+            ops::Join::try_new(
+                left_plan,
+                right_plan,
+                JoinPredicate::try_new(on)?,
+                join_type,
+                None,
+            )?
+            .into(),
+        );
+
+        if pulled_up_names.is_empty() && count_names.is_empty() {
+            return Ok(self.with_new_plan(join));
+        }
+
+        let projection = join
+            .schema()
+            .field_names()
+            .filter(|name| !pulled_up_names.contains(*name))
+            .map(|name| {
+                if count_names.contains(name) {
+                    resolved_col(name).fill_null(lit(0u64))
+                } else {
+                    resolved_col(name)
+                }
+            })
+            .collect();
+
+        let logical_plan: LogicalPlan = ops::Project::try_new(join, projection)?.into();
+        Ok(self.with_new_plan(logical_plan))
+    }
+
+    /// Joins on pairs of left and right key expressions, like the DataFrame API, where keys that are
+    /// the same column name on both sides are merged into a single output column.
+    pub fn join_on_keys(
+        &self,
+        right: &Self,
+        left_on: Vec<ExprRef>,
+        right_on: Vec<ExprRef>,
+        join_type: JoinType,
+        join_strategy: Option<JoinStrategy>,
+        options: JoinOptions,
+    ) -> DaftResult<Self> {
+        let mut on_exprs = Vec::new();
+        let mut using = Vec::new();
+
+        // special logic to maintain DataFrame join behavior
+        // TODO: remove this once we add plan IDs to DataFrame
+        for (l, r) in left_on.into_iter().zip(right_on) {
+            if let (
+                Expr::Column(Column::Unresolved(UnresolvedColumn { name: l_name, .. })),
+                Expr::Column(Column::Unresolved(UnresolvedColumn { name: r_name, .. })),
+            ) = (l.as_ref(), r.as_ref())
+                && l_name == r_name
+            {
+                using.push(l_name.to_string());
+            } else {
+                let l = l.to_left_cols(self.schema())?;
+                let r = r.to_right_cols(right.schema())?;
+
+                on_exprs.push(l.eq(r));
+            }
+        }
+
+        let on = combine_conjunction(on_exprs);
+
+        self.join(right, on, using, join_type, join_strategy, options)
+    }
+
     pub fn concat(&self, other: &Self) -> DaftResult<Self> {
         let logical_plan: LogicalPlan =
             ops::Concat::try_new(self.plan.clone(), other.plan.clone())?.into();
@@ -644,6 +923,114 @@ impl LogicalPlanBuilder {
         Ok(self.with_new_plan(logical_plan))
     }
 
+    /// Creates the source that the recursive term of a recursive CTE reads its working table from.
+    ///
+    /// The rows of the working table are only bound while executing the
+    /// [`recursive_cte`](Self::recursive_cte) built from it.
+    pub fn recursive_cte_working_table(name: &str, schema: SchemaRef) -> Self {
+        let working_table_key = format!("recursive-cte-{name}-{}", uuid::Uuid::new_v4());
+        let source_info = SourceInfo::InMemory(InMemoryInfo::new(
+            schema.clone(),
+            working_table_key,
+            None,
+            1,
+            0,
+            0,
+            None,
+            None,
+        ));
+        let logical_plan: LogicalPlan = ops::Source::new(schema, source_info.into()).into();
+        Self::from(Arc::new(logical_plan))
+    }
+
+    /// Computes the fixpoint of a recursive CTE with this plan as its anchor.
+    ///
+    /// `recursive` reads the rows produced by the previous iteration from `working_table`, which
+    /// must have been created with [`recursive_cte_working_table`](Self::recursive_cte_working_table).
+    /// Its columns are matched to the anchor by position and cast to the anchor's types where they
+    /// differ. If `recursive` never reads the working table, this is a plain union.
+    pub fn recursive_cte(
+        &self,
+        name: impl Into<Arc<str>>,
+        working_table: &Self,
+        recursive: &Self,
+        is_all: bool,
+    ) -> DaftResult<Self> {
+        let LogicalPlan::Source(ops::Source { source_info, .. }) = working_table.plan.as_ref()
+        else {
+            return Err(DaftError::ValueError(
+                "Working table of a recursive CTE must be created with recursive_cte_working_table"
+                    .to_string(),
+            ));
+        };
+        let SourceInfo::InMemory(InMemoryInfo {
+            cache_key: working_table_key,
+            ..
+        }) = source_info.as_ref()
+        else {
+            return Err(DaftError::ValueError(
+                "Working table of a recursive CTE must be created with recursive_cte_working_table"
+                    .to_string(),
+            ));
+        };
+
+        let is_recursive = recursive.plan.exists(|plan| {
+            matches!(
+                plan.as_ref(),
+                LogicalPlan::Source(ops::Source { source_info, .. })
+                    if matches!(source_info.as_ref(), SourceInfo::InMemory(info) if info.cache_key == *working_table_key)
+            )
+        });
+        if !is_recursive {
+            let set_quantifier = if is_all {
+                SetQuantifier::All
+            } else {
+                SetQuantifier::Distinct
+            };
+            return self.union(recursive, set_quantifier, UnionStrategy::Positional);
+        }
+
+        let anchor_schema = self.schema();
+        let recursive_schema = recursive.schema();
+        if anchor_schema.len() != recursive_schema.len() {
+            return Err(DaftError::SchemaMismatch(format!(
+                "Recursive term must have the same number of columns as its anchor, but got {} and {}",
+                recursive_schema.len(),
+                anchor_schema.len()
+            )));
+        }
+
+        let recursive = if anchor_schema == recursive_schema {
+            recursive.plan.clone()
+        } else {
+            let projection = anchor_schema
+                .fields()
+                .iter()
+                .zip(recursive_schema.fields())
+                .map(|(l, r)| {
+                    let expr = resolved_col(r.name.clone());
+                    let expr = if l.dtype == r.dtype {
+                        expr
+                    } else {
+                        expr.cast(&l.dtype)
+                    };
+                    expr.alias(l.name.clone())
+                })
+                .collect();
+            LogicalPlan::from(ops::Project::try_new(recursive.plan.clone(), projection)?).arced()
+        };
+
+        let logical_plan: LogicalPlan = ops::RecursiveCte::try_new(
+            name.into(),
+            working_table_key.clone(),
+            self.plan.clone(),
+            recursive,
+            is_all,
+        )?
+        .into();
+        Ok(self.with_new_plan(logical_plan))
+    }
+
     pub fn add_monotonically_increasing_id(
         &self,
         column_name: Option<&str>,
@@ -1148,6 +1535,22 @@ impl PyLogicalPlanBuilder {
             .into())
     }
 
+    pub fn aggregate_grouping_sets(
+        &self,
+        agg_exprs: Vec<PyExpr>,
+        groupby_exprs: Vec<PyExpr>,
+        grouping_sets: Vec<Vec<usize>>,
+    ) -> PyResult<Self> {
+        Ok(self
+            .builder
+            .aggregate_grouping_sets(
+                pyexprs_to_exprs(agg_exprs),
+                pyexprs_to_exprs(groupby_exprs),
+                grouping_sets,
+            )?
+            .into())
+    }
+
     pub fn pivot(
         &self,
         group_by: Vec<PyExpr>,
@@ -1187,38 +1590,15 @@ impl PyLogicalPlanBuilder {
         prefix: Option<String>,
         suffix: Option<String>,
     ) -> PyResult<Self> {
-        let left_on = left_on.into_iter().map(|expr| expr.expr);
-        let right_on = right_on.into_iter().map(|expr| expr.expr);
-
-        let mut on_exprs = Vec::new();
-        let mut using = Vec::new();
-
-        // special logic to maintain DataFrame join behavior
-        // TODO: remove this once we add plan IDs to DataFrame
-        for (l, r) in left_on.zip(right_on) {
-            if let (
-                Expr::Column(Column::Unresolved(UnresolvedColumn { name: l_name, .. })),
-                Expr::Column(Column::Unresolved(UnresolvedColumn { name: r_name, .. })),
-            ) = (l.as_ref(), r.as_ref())
-                && l_name == r_name
-            {
-                using.push(l_name.to_string());
-            } else {
-                let l = l.to_left_cols(self.builder.schema())?;
-                let r = r.to_right_cols(right.builder.schema())?;
-
-                on_exprs.push(l.eq(r));
-            }
-        }
-
-        let on = combine_conjunction(on_exprs);
+        let left_on = left_on.into_iter().map(|expr| expr.expr).collect();
+        let right_on = right_on.into_iter().map(|expr| expr.expr).collect();
 
         Ok(self
             .builder
-            .join(
+            .join_on_keys(
                 &right.builder,
-                on,
-                using,
+                left_on,
+                right_on,
                 join_type,
                 join_strategy,
                 JoinOptions { prefix, suffix },
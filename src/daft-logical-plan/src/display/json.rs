@@ -92,6 +92,10 @@ where
                 "quantifier": union_.quantifier,
                 "strategy": union_.strategy,
             }),
+            LogicalPlan::RecursiveCte(recursive_cte) => json!({
+                "name": recursive_cte.name,
+                "is_all": recursive_cte.is_all,
+            }),
             LogicalPlan::Join(join) => json!({
                 "on": vec![&join.on.inner().map(|e| e.to_string())],
                 "type": join.join_type,
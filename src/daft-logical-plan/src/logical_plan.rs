@@ -46,6 +46,7 @@ pub enum LogicalPlan {
     SubqueryAlias(SubqueryAlias),
     Window(Window),
     TopN(TopN),
+    RecursiveCte(RecursiveCte),
 }
 
 pub type LogicalPlanRef = Arc<LogicalPlan>;
@@ -121,6 +122,7 @@ impl LogicalPlan {
             Self::SubqueryAlias(SubqueryAlias { input, .. }) => input.schema(),
             Self::Window(Window { schema, .. }) => schema.clone(),
             Self::TopN(TopN { input, .. }) => input.schema(),
+            Self::RecursiveCte(recursive_cte) => recursive_cte.schema(),
         }
     }
 
@@ -244,6 +246,7 @@ impl LogicalPlan {
             }
             Self::Intersect(_) => vec![IndexSet::new(), IndexSet::new()],
             Self::Union(_) => vec![IndexSet::new(), IndexSet::new()],
+            Self::RecursiveCte(_) => vec![IndexSet::new(), IndexSet::new()],
             Self::Source(_) => todo!(),
             Self::Sink(_) => todo!(),
             Self::SubqueryAlias(SubqueryAlias { input, .. }) => input.required_columns(),
@@ -293,6 +296,7 @@ impl LogicalPlan {
             Self::SubqueryAlias(..) => "Alias",
             Self::Window(..) => "Window",
             Self::TopN(..) => "TopN",
+            Self::RecursiveCte(..) => "RecursiveCte",
         }
     }
 
@@ -317,7 +321,8 @@ impl LogicalPlan {
             | Self::Sample(Sample { stats_state, .. })
             | Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { stats_state, .. })
             | Self::Window(Window { stats_state, .. })
-            | Self::TopN(TopN { stats_state, .. }) => stats_state,
+            | Self::TopN(TopN { stats_state, .. })
+            | Self::RecursiveCte(RecursiveCte { stats_state, .. }) => stats_state,
             Self::Intersect(_) => {
                 panic!("Intersect nodes should be optimized away before stats are materialized")
             }
@@ -369,6 +374,7 @@ impl LogicalPlan {
             }
             Self::Window(plan) => Self::Window(plan.with_materialized_stats()),
             Self::TopN(plan) => Self::TopN(plan.with_materialized_stats()),
+            Self::RecursiveCte(plan) => Self::RecursiveCte(plan.with_materialized_stats()),
         }
     }
 
@@ -399,6 +405,7 @@ impl LogicalPlan {
             Self::SubqueryAlias(alias) => alias.multiline_display(),
             Self::Window(window) => window.multiline_display(),
             Self::TopN(top_n) => top_n.multiline_display(),
+            Self::RecursiveCte(recursive_cte) => recursive_cte.multiline_display(),
         }
     }
 
@@ -429,6 +436,9 @@ impl LogicalPlan {
             Self::SubqueryAlias(SubqueryAlias { input, .. }) => vec![input],
             Self::Window(Window { input, .. }) => vec![input],
             Self::TopN(TopN { input, .. }) => vec![input],
+            Self::RecursiveCte(RecursiveCte {
+                anchor, recursive, ..
+            }) => vec![anchor, recursive],
         }
     }
 
@@ -468,6 +478,7 @@ impl LogicalPlan {
                 Self::Intersect(_) => panic!("Intersect ops should never have only one input, but got one"),
                 Self::Union(_) => panic!("Union ops should never have only one input, but got one"),
                 Self::Join(_) => panic!("Join ops should never have only one input, but got one"),
+                Self::RecursiveCte(_) => panic!("RecursiveCte ops should never have only one input, but got one"),
             },
             [input1, input2] => match self {
                 Self::Source(_) => panic!("Source nodes don't have children, with_new_children() should never be called for Source ops"),
@@ -481,6 +492,13 @@ impl LogicalPlan {
                     *join_type,
                     *join_strategy,
                 ).unwrap()),
+                Self::RecursiveCte(RecursiveCte { name, working_table_key, is_all, .. }) => Self::RecursiveCte(RecursiveCte::try_new(
+                    name.clone(),
+                    working_table_key.clone(),
+                    input1.clone(),
+                    input2.clone(),
+                    *is_all,
+                ).unwrap()),
                 _ => panic!("Logical op {} has one input, but got two", self),
             },
             _ => panic!("Logical ops should never have more than 2 inputs, but got: {}", children.len())
@@ -611,7 +629,8 @@ impl LogicalPlan {
             | Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { plan_id, .. })
             | Self::SubqueryAlias(SubqueryAlias { plan_id, .. })
             | Self::Window(Window { plan_id, .. })
-            | Self::TopN(TopN { plan_id, .. }) => plan_id,
+            | Self::TopN(TopN { plan_id, .. })
+            | Self::RecursiveCte(RecursiveCte { plan_id, .. }) => plan_id,
         }
     }
 
@@ -639,7 +658,8 @@ impl LogicalPlan {
             | Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { node_id, .. })
             | Self::SubqueryAlias(SubqueryAlias { node_id, .. })
             | Self::Window(Window { node_id, .. })
-            | Self::TopN(TopN { node_id, .. }) => node_id,
+            | Self::TopN(TopN { node_id, .. })
+            | Self::RecursiveCte(RecursiveCte { node_id, .. }) => node_id,
         }
     }
 
@@ -673,6 +693,9 @@ impl LogicalPlan {
             Self::SubqueryAlias(alias) => Self::SubqueryAlias(alias.with_plan_id(plan_id)),
             Self::Window(window) => Self::Window(window.with_plan_id(plan_id)),
             Self::TopN(top_n) => Self::TopN(top_n.with_plan_id(plan_id)),
+            Self::RecursiveCte(recursive_cte) => {
+                Self::RecursiveCte(recursive_cte.with_plan_id(plan_id))
+            }
         }
     }
 
@@ -706,6 +729,9 @@ impl LogicalPlan {
             Self::SubqueryAlias(alias) => Self::SubqueryAlias(alias.with_node_id(node_id)),
             Self::Window(window) => Self::Window(window.with_node_id(node_id)),
             Self::TopN(top_n) => Self::TopN(top_n.with_node_id(node_id)),
+            Self::RecursiveCte(recursive_cte) => {
+                Self::RecursiveCte(recursive_cte.with_node_id(node_id))
+            }
         }
     }
 }
@@ -812,3 +838,4 @@ impl_from_data_struct_for_logical_plan!(Sample);
 impl_from_data_struct_for_logical_plan!(MonotonicallyIncreasingId);
 impl_from_data_struct_for_logical_plan!(Window);
 impl_from_data_struct_for_logical_plan!(TopN);
+impl_from_data_struct_for_logical_plan!(RecursiveCte);
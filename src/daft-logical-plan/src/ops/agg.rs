@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use daft_dsl::{exprs_to_schema, ExprRef};
+use daft_dsl::{exprs_to_schema, lit, resolved_col, ExprRef};
 use daft_schema::schema::SchemaRef;
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
@@ -116,3 +116,64 @@ impl Aggregate {
         res
     }
 }
+
+/// Name of the column holding the index of the grouping set each row is aggregated in, when
+/// aggregating over several grouping sets.
+pub(crate) const GROUPING_SET_COLUMN: &str = "__grouping_set__";
+
+/// The grouping sets of `ROLLUP` over `n` grouping expressions, e.g. `(a, b), (a), ()`.
+#[must_use]
+pub fn rollup_grouping_sets(n: usize) -> Vec<Vec<usize>> {
+    (0..=n).rev().map(|i| (0..i).collect()).collect()
+}
+
+/// The grouping sets of `CUBE` over `n` grouping expressions, e.g. `(a, b), (a), (b), ()`.
+#[must_use]
+pub fn cube_grouping_sets(n: usize) -> Vec<Vec<usize>> {
+    (0..1usize << n)
+        .rev()
+        .map(|mask| (0..n).filter(|i| mask & (1 << (n - 1 - i)) != 0).collect())
+        .collect()
+}
+
+/// Builds the value of `grouping_id()` over the grouping expressions at `positions` from the
+/// grouping set index column.
+///
+/// A bit is set when its expression is not part of the row's grouping set, with the first
+/// expression as the most significant bit.
+pub(crate) fn grouping_id_expr(positions: &[usize], grouping_sets: &[Vec<usize>]) -> ExprRef {
+    let ids = grouping_sets
+        .iter()
+        .map(|set| {
+            positions
+                .iter()
+                .fold(0i64, |acc, p| (acc << 1) | i64::from(!set.contains(p)))
+        })
+        .collect::<Vec<_>>();
+    let (last, rest) = ids
+        .split_last()
+        .expect("There should be at least one grouping set");
+    rest.iter()
+        .enumerate()
+        .rev()
+        .fold(lit(*last), |acc, (i, id)| {
+            resolved_col(GROUPING_SET_COLUMN)
+                .eq(lit(i as u64))
+                .if_else(lit(*id), acc)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rollup_and_cube_grouping_sets() {
+        assert_eq!(rollup_grouping_sets(2), vec![vec![0, 1], vec![0], vec![]]);
+        assert_eq!(
+            cube_grouping_sets(2),
+            vec![vec![0, 1], vec![0], vec![1], vec![]]
+        );
+        assert_eq!(cube_grouping_sets(0), vec![Vec::<usize>::new()]);
+    }
+}
@@ -9,6 +9,7 @@ mod limit;
 mod monotonically_increasing_id;
 mod pivot;
 mod project;
+mod recursive_cte;
 mod repartition;
 mod sample;
 mod set_operations;
@@ -22,7 +23,8 @@ mod unpivot;
 mod window;
 
 pub use actor_pool_project::ActorPoolProject;
-pub use agg::Aggregate;
+pub use agg::{cube_grouping_sets, rollup_grouping_sets, Aggregate};
+pub(crate) use agg::{grouping_id_expr, GROUPING_SET_COLUMN};
 pub use concat::Concat;
 pub use distinct::Distinct;
 pub use explode::Explode;
@@ -32,6 +34,7 @@ pub use limit::Limit;
 pub use monotonically_increasing_id::MonotonicallyIncreasingId;
 pub use pivot::Pivot;
 pub use project::Project;
+pub use recursive_cte::RecursiveCte;
 pub use repartition::Repartition;
 pub use sample::Sample;
 pub use set_operations::{Except, Intersect, SetQuantifier, Union, UnionStrategy};
@@ -553,6 +553,17 @@ fn replace_column_with_semantic_id_aggexpr(
             replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema)
                 .map_yes_no(AggExpr::Max, |_| e)
         }
+        AggExpr::MinBy(ref child, ref by) | AggExpr::MaxBy(ref child, ref by) => {
+            let child = replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema);
+            let by = replace_column_with_semantic_id(by.clone(), subexprs_to_replace, schema);
+            if !child.transformed && !by.transformed {
+                Transformed::no(e)
+            } else if let AggExpr::MinBy(..) = e {
+                Transformed::yes(AggExpr::MinBy(child.data, by.data))
+            } else {
+                Transformed::yes(AggExpr::MaxBy(child.data, by.data))
+            }
+        }
         AggExpr::BoolAnd(ref child) => {
             replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema)
                 .map_yes_no(AggExpr::BoolAnd, |_| e)
@@ -0,0 +1,93 @@
+use std::sync::Arc;
+
+use common_error::DaftError;
+use daft_schema::schema::SchemaRef;
+use serde::{Deserialize, Serialize};
+use snafu::ResultExt;
+
+use crate::{
+    logical_plan::{self, CreationSnafu},
+    stats::{PlanStats, StatsState},
+    LogicalPlan,
+};
+
+/// Fixpoint of a recursive common table expression.
+///
+/// The anchor is evaluated once and seeds the working table. The recursive term reads the working
+/// table through an in-memory source keyed by `working_table_key`, and is re-evaluated against the
+/// rows produced by the previous iteration until it produces no new rows. With `is_all = false`
+/// (`UNION`), rows that were already produced are discarded before the next iteration.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct RecursiveCte {
+    pub plan_id: Option<usize>,
+    pub node_id: Option<usize>,
+    pub name: Arc<str>,
+    pub working_table_key: String,
+    // Upstream nodes.
+    pub anchor: Arc<LogicalPlan>,
+    pub recursive: Arc<LogicalPlan>,
+    pub is_all: bool,
+    pub stats_state: StatsState,
+}
+
+impl RecursiveCte {
+    pub(crate) fn try_new(
+        name: Arc<str>,
+        working_table_key: String,
+        anchor: Arc<LogicalPlan>,
+        recursive: Arc<LogicalPlan>,
+        is_all: bool,
+    ) -> logical_plan::Result<Self> {
+        let anchor_schema = anchor.schema();
+        let recursive_schema = recursive.schema();
+        if anchor_schema != recursive_schema {
+            return Err(DaftError::SchemaMismatch(format!(
+                "Recursive term of CTE `{name}` must have the same schema as its anchor, but got: {recursive_schema} and {anchor_schema}"
+            )))
+            .context(CreationSnafu);
+        }
+
+        Ok(Self {
+            plan_id: None,
+            node_id: None,
+            name,
+            working_table_key,
+            anchor,
+            recursive,
+            is_all,
+            stats_state: StatsState::NotMaterialized,
+        })
+    }
+
+    pub fn schema(&self) -> SchemaRef {
+        self.anchor.schema()
+    }
+
+    pub fn with_plan_id(mut self, plan_id: usize) -> Self {
+        self.plan_id = Some(plan_id);
+        self
+    }
+
+    pub fn with_node_id(mut self, node_id: usize) -> Self {
+        self.node_id = Some(node_id);
+        self
+    }
+
+    pub(crate) fn with_materialized_stats(mut self) -> Self {
+        // The number of iterations is unknown until execution, so the anchor is a lower bound.
+        let approx_stats = self.anchor.materialized_stats().approx_stats.clone();
+        self.stats_state = StatsState::Materialized(PlanStats::new(approx_stats).into());
+        self
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![
+            format!("RecursiveCte: {}", self.name),
+            format!("Union all = {}", self.is_all),
+        ];
+        if let StatsState::Materialized(stats) = &self.stats_state {
+            res.push(format!("Stats = {}", stats));
+        }
+        res
+    }
+}
@@ -339,12 +339,20 @@ impl Union {
                             Ok::<_, logical_plan::Error>(Field::new(l.name.clone(), new_dtype))
                         })
                         .collect::<Result<Vec<_>, _>>()?;
-                    let projection_fields = coerced_fields
-                        .into_iter()
+                    let lhs_projection = coerced_fields
+                        .iter()
                         .map(|f| resolved_col(f.name.clone()).cast(&f.dtype))
                         .collect::<Vec<_>>();
-                    let lhs = Project::try_new(self.lhs.clone(), projection_fields.clone())?.into();
-                    let rhs = Project::try_new(self.rhs.clone(), projection_fields)?.into();
+                    // the right side columns are matched by position and take the left side names
+                    let rhs_projection = coerced_fields
+                        .iter()
+                        .zip(rhs_schema.field_names())
+                        .map(|(f, rhs_name)| {
+                            resolved_col(rhs_name).cast(&f.dtype).alias(f.name.clone())
+                        })
+                        .collect::<Vec<_>>();
+                    let lhs = Project::try_new(self.lhs.clone(), lhs_projection)?.into();
+                    let rhs = Project::try_new(self.rhs.clone(), rhs_projection)?.into();
                     (lhs, rhs)
                 } else {
                     (self.lhs.clone(), self.rhs.clone())
@@ -6,3 +6,4 @@ mod rules;
 mod test;
 
 pub use optimizer::{Optimizer, OptimizerBuilder, OptimizerConfig};
+pub(crate) use rules::{is_count_column, pull_up_correlated_cols};
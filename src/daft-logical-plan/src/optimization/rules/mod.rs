@@ -48,4 +48,5 @@ pub use simplify_expressions::SimplifyExpressionsRule;
 pub use simplify_null_filtered_join::SimplifyNullFilteredJoin;
 pub use split_actor_pool_projects::SplitActorPoolProjects;
 pub use split_explode_from_project::SplitExplodeFromProject;
+pub(crate) use unnest_subquery::{is_count_column, pull_up_correlated_cols};
 pub use unnest_subquery::{UnnestPredicateSubquery, UnnestScalarSubquery};
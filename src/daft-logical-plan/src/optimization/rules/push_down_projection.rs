@@ -558,6 +558,11 @@ impl PushDownProjection {
                 let new_plan = plan.with_new_children(&[new_distinct]).arced();
                 Ok(Transformed::yes(new_plan.into()))
             }
+            LogicalPlan::RecursiveCte(_) => {
+                // Cannot push down past a RecursiveCte,
+                // since the recursive term reads back every column of the working table.
+                Ok(Transformed::no(plan))
+            }
             LogicalPlan::Intersect(_) => {
                 // Cannot push down past an Intersect,
                 // since Intersect implicitly requires all parent columns.
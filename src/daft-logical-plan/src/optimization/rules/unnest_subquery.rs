@@ -4,14 +4,16 @@ use common_error::{DaftError, DaftResult};
 use common_treenode::{DynTreeNode, Transformed, TreeNode};
 use daft_algebra::boolean::{combine_conjunction, split_conjunction};
 use daft_core::{join::JoinType, prelude::SchemaRef};
-use daft_dsl::{resolved_col, Column, Expr, ExprRef, Operator, ResolvedColumn, Subquery};
+use daft_dsl::{
+    lit, resolved_col, AggExpr, Column, Expr, ExprRef, Operator, ResolvedColumn, Subquery,
+};
 use itertools::multiunzip;
 use uuid::Uuid;
 
 use super::OptimizerRule;
 use crate::{
-    logical_plan::downcast_subquery,
-    ops::{join::JoinPredicate, Aggregate, Filter, Join, Project},
+    logical_plan::{downcast_subquery, SubqueryAlias},
+    ops::{join::JoinPredicate, Aggregate, Filter, Join, Project, Sort},
     LogicalPlan, LogicalPlanRef,
 };
 
@@ -58,6 +60,9 @@ use crate::{
 /// ON inner_key
 /// WHERE outer_key = subquery.outer_key
 /// ```
+///
+/// When a correlated subquery computes a `count`, the groups missing from the left join
+/// are filled with 0, since counting an empty set yields 0 rather than null.
 #[derive(Debug)]
 pub struct UnnestScalarSubquery {}
 
@@ -110,10 +115,12 @@ impl UnnestScalarSubquery {
                     )));
                 };
 
+                let is_count = is_count_column(&subquery_plan, output_col);
+
                 // alias output column
                 let subquery_plan = Arc::new(LogicalPlan::Project(Project::try_new(
                     subquery_plan,
-                    vec![resolved_col(output_col.as_str()).alias(subquery_alias)],
+                    vec![resolved_col(output_col.as_str()).alias(subquery_alias.clone())],
                 )?));
 
                 let (decorrelated_subquery, subquery_on, input_on) =
@@ -151,13 +158,34 @@ impl UnnestScalarSubquery {
 
                 let on = JoinPredicate::try_new(on_expr)?;
 
-                Ok(Arc::new(LogicalPlan::Join(Join::try_new(
+                let join = Arc::new(LogicalPlan::Join(Join::try_new(
                     curr_input,
                     decorrelated_subquery,
                     on,
                     join_type,
                     None,
-                )?)))
+                )?));
+
+                if join_type == JoinType::Left && is_count {
+                    let projection = join
+                        .schema()
+                        .names()
+                        .into_iter()
+                        .map(|name| {
+                            if *name == *subquery_alias {
+                                resolved_col(name).fill_null(lit(0u64))
+                            } else {
+                                resolved_col(name)
+                            }
+                        })
+                        .collect();
+
+                    Ok(Arc::new(LogicalPlan::Project(Project::try_new(
+                        join, projection,
+                    )?)))
+                } else {
+                    Ok(join)
+                }
             })?;
 
         Ok(Transformed::yes((new_input, new_exprs)))
@@ -378,7 +406,48 @@ impl OptimizerRule for UnnestPredicateSubquery {
     }
 }
 
-fn pull_up_correlated_cols(
+/// Returns true if the column `name` of `plan` is the result of a `count` aggregation.
+pub(crate) fn is_count_column(plan: &LogicalPlanRef, name: &str) -> bool {
+    fn unalias(expr: &ExprRef) -> &ExprRef {
+        match expr.as_ref() {
+            Expr::Alias(inner, _) => unalias(inner),
+            _ => expr,
+        }
+    }
+
+    match plan.as_ref() {
+        LogicalPlan::Project(Project {
+            input, projection, ..
+        }) => projection
+            .iter()
+            .find(|e| e.name() == name)
+            .is_some_and(|e| match unalias(e).as_ref() {
+                Expr::Column(Column::Resolved(ResolvedColumn::Basic(col_name))) => {
+                    is_count_column(input, col_name)
+                }
+                Expr::Agg(AggExpr::Count(..) | AggExpr::CountDistinct(..)) => true,
+                _ => false,
+            }),
+        LogicalPlan::Aggregate(Aggregate { aggregations, .. }) => aggregations
+            .iter()
+            .find(|e| e.name() == name)
+            .is_some_and(|e| {
+                matches!(
+                    unalias(e).as_ref(),
+                    Expr::Agg(AggExpr::Count(..) | AggExpr::CountDistinct(..))
+                )
+            }),
+        LogicalPlan::Filter(Filter { input, .. })
+        | LogicalPlan::Sort(Sort { input, .. })
+        | LogicalPlan::SubqueryAlias(SubqueryAlias { input, .. }) => is_count_column(input, name),
+        _ => false,
+    }
+}
+
+/// Pulls equality predicates on outer columns out of a correlated subquery plan.
+///
+/// Returns the decorrelated plan along with the subquery and outer columns that must be joined on.
+pub(crate) fn pull_up_correlated_cols(
     plan: LogicalPlanRef,
 ) -> DaftResult<(LogicalPlanRef, Vec<ExprRef>, Vec<ExprRef>)> {
     let (new_inputs, subquery_on, outer_on): (Vec<_>, Vec<_>, Vec<_>) = multiunzip(
@@ -538,7 +607,8 @@ fn pull_up_correlated_cols(
         | LogicalPlan::Concat(..)
         | LogicalPlan::Join(..)
         | LogicalPlan::Sink(..)
-        | LogicalPlan::Window(..) => {
+        | LogicalPlan::Window(..)
+        | LogicalPlan::RecursiveCte(..) => {
             if subquery_on.is_empty() {
                 Ok((plan.clone(), vec![], vec![]))
             } else {
@@ -585,8 +655,8 @@ mod tests {
     use std::sync::Arc;
 
     use common_error::DaftResult;
-    use daft_core::join::JoinType;
-    use daft_dsl::{unresolved_col, Column, Expr, PlanRef, ResolvedColumn, Subquery};
+    use daft_core::{count_mode::CountMode, join::JoinType};
+    use daft_dsl::{lit, unresolved_col, Column, Expr, PlanRef, ResolvedColumn, Subquery};
     use daft_schema::{dtype::DataType, field::Field};
 
     use super::{UnnestPredicateSubquery, UnnestScalarSubquery};
@@ -730,6 +800,67 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn correlated_count_scalar_subquery() -> DaftResult<()> {
+        let tbl1 = dummy_scan_node(dummy_scan_operator(vec![
+            Field::new("key", DataType::Int64),
+            Field::new("val", DataType::Int64),
+        ]));
+
+        let tbl2 = dummy_scan_node(dummy_scan_operator(vec![
+            Field::new("key2", DataType::Int64),
+            Field::new("val2", DataType::Int64),
+        ]));
+
+        let subquery = tbl2
+            .filter(
+                unresolved_col("key2").eq(Arc::new(Expr::Column(Column::Resolved(
+                    ResolvedColumn::OuterRef(
+                        Field::new("key", DataType::Int64),
+                        PlanRef::Unqualified,
+                    ),
+                )))),
+            )?
+            .aggregate(vec![unresolved_col("val2").count(CountMode::All)], vec![])?;
+        let subquery_expr = Arc::new(Expr::Subquery(Subquery {
+            plan: subquery.build(),
+        }));
+        let subquery_alias = subquery_expr.semantic_id(&subquery.schema()).id;
+
+        let plan = tbl1
+            .select(vec![unresolved_col("val"), subquery_expr])?
+            .build();
+
+        // keys without matching rows have a count of 0 rather than null
+        let expected = tbl1
+            .join(
+                tbl2.aggregate(
+                    vec![unresolved_col("val2").count(CountMode::All)],
+                    vec![unresolved_col("key2")],
+                )?
+                .select(vec![
+                    unresolved_col("val2").alias(subquery_alias.clone()),
+                    unresolved_col("key2"),
+                ])?,
+                unresolved_col("key").eq(unresolved_col("key2")).into(),
+                vec![],
+                JoinType::Left,
+                None,
+                Default::default(),
+            )?
+            .select(vec![
+                unresolved_col("key"),
+                unresolved_col("val"),
+                unresolved_col(subquery_alias.clone()).fill_null(lit(0u64)),
+                unresolved_col("key2"),
+            ])?
+            .select(vec![unresolved_col("val"), unresolved_col(subquery_alias)])?
+            .build();
+
+        assert_scalar_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
+
     #[test]
     fn uncorrelated_predicate_subquery() -> DaftResult<()> {
         let tbl1 = dummy_scan_node(dummy_scan_operator(vec![
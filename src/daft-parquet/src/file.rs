@@ -4,7 +4,7 @@ use std::{
     sync::Arc,
 };
 
-use arrow2::io::parquet::read::{column_iter_to_arrays, schema::infer_schema_with_options};
+use arrow2::io::parquet::read::column_iter_to_arrays;
 use common_error::DaftResult;
 use common_runtime::{combine_stream, get_io_runtime};
 use daft_core::{prelude::*, utils::arrow::cast_array_for_daft_if_needed};
@@ -23,6 +23,7 @@ use tokio_stream::wrappers::ReceiverStream;
 
 use crate::{
     determine_parquet_parallelism,
+    geoparquet::{infer_arrow_schema, GeoParquetMetadata},
     metadata::read_parquet_metadata,
     read::ParquetSchemaInferenceOptions,
     read_planner::{CoalescePass, RangesContainer, ReadPlanner, SplitLargeRequestPass},
@@ -116,6 +117,7 @@ pub fn build_row_ranges(
     uri: &str,
 ) -> super::Result<Vec<RowGroupRange>> {
     let limit = limit.map(|v| v as i64);
+    let geo = GeoParquetMetadata::from_file_metadata(metadata);
     let mut row_ranges = vec![];
     let mut curr_row_index = 0;
 
@@ -142,7 +144,7 @@ pub fn build_row_ranges(
             }
             let rg = metadata.row_groups.get(&i).unwrap();
             if let Some(ref pred) = predicate {
-                let stats = statistics::row_group_metadata_to_table_stats(rg, schema)
+                let stats = statistics::row_group_metadata_to_table_stats(rg, schema, geo.as_ref())
                     .with_context(|_| UnableToConvertRowGroupMetadataToStatsSnafu {
                         path: uri.to_string(),
                     })?;
@@ -174,10 +176,11 @@ pub fn build_row_ranges(
                 continue;
             } else if rows_to_add > 0 {
                 if let Some(ref pred) = predicate {
-                    let stats = statistics::row_group_metadata_to_table_stats(rg, schema)
-                        .with_context(|_| UnableToConvertRowGroupMetadataToStatsSnafu {
-                            path: uri.to_string(),
-                        })?;
+                    let stats =
+                        statistics::row_group_metadata_to_table_stats(rg, schema, geo.as_ref())
+                            .with_context(|_| UnableToConvertRowGroupMetadataToStatsSnafu {
+                                path: uri.to_string(),
+                            })?;
                     let evaled = stats.eval_expression(pred).with_context(|_| {
                         UnableToRunExpressionOnStatsSnafu {
                             path: uri.to_string(),
@@ -273,7 +276,7 @@ impl ParquetReaderBuilder {
 
     pub fn build(self) -> super::Result<ParquetFileReader> {
         let options = self.schema_inference_options.into();
-        let mut arrow_schema = infer_schema_with_options(&self.metadata, Some(options)).context(
+        let mut arrow_schema = infer_arrow_schema(&self.metadata, Some(options)).context(
             UnableToParseSchemaFromMetadataSnafu {
                 path: self.uri.clone(),
             },
@@ -0,0 +1,331 @@
+//! Support for the [GeoParquet](https://geoparquet.org) `geo` file metadata.
+//!
+//! GeoParquet files store geometries as WKB binary columns and describe them in a JSON blob under the
+//! `geo` key-value metadata key. We use it to surface those columns as Daft `Geometry` columns and,
+//! when the file declares a bounding-box covering column (GeoParquet 1.1), to derive per-row-group
+//! bounding-box statistics for pruning.
+use std::collections::HashMap;
+
+use arrow2::io::parquet::read::schema::{infer_schema_with_options, SchemaInferenceOptions};
+use daft_core::prelude::DataType;
+use daft_stats::ColumnRangeStatistics;
+use parquet2::{
+    metadata::{FileMetaData, RowGroupMetaData},
+    statistics::PrimitiveStatistics,
+};
+use serde::Deserialize;
+
+const GEO_METADATA_KEY: &str = "geo";
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct GeoParquetMetadata {
+    pub version: Option<String>,
+    pub primary_column: Option<String>,
+    pub columns: HashMap<String, GeoParquetColumn>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct GeoParquetColumn {
+    pub encoding: String,
+    #[serde(default)]
+    pub geometry_types: Vec<String>,
+    pub bbox: Option<Vec<f64>>,
+    pub covering: Option<GeoParquetCovering>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct GeoParquetCovering {
+    pub bbox: BboxCovering,
+}
+
+/// Paths to the leaf columns holding each geometry's bounding box.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct BboxCovering {
+    pub xmin: Vec<String>,
+    pub ymin: Vec<String>,
+    pub xmax: Vec<String>,
+    pub ymax: Vec<String>,
+}
+
+impl GeoParquetMetadata {
+    /// Parses the `geo` metadata of a Parquet file, if present and well-formed.
+    pub fn from_file_metadata(metadata: &FileMetaData) -> Option<Self> {
+        let value = metadata
+            .key_value_metadata
+            .as_ref()?
+            .iter()
+            .find(|kv| kv.key == GEO_METADATA_KEY)?
+            .value
+            .as_ref()?;
+        match serde_json::from_str(value) {
+            Ok(geo) => Some(geo),
+            Err(e) => {
+                log::warn!("Ignoring malformed GeoParquet metadata: {e}");
+                None
+            }
+        }
+    }
+
+    fn wkb_column(&self, name: &str) -> Option<&GeoParquetColumn> {
+        self.columns
+            .get(name)
+            .filter(|column| column.encoding.eq_ignore_ascii_case("WKB"))
+    }
+
+    /// Retypes WKB-encoded geometry columns in an inferred Arrow schema as Daft `Geometry`.
+    pub fn apply_to_arrow_schema(&self, schema: &mut arrow2::datatypes::Schema) {
+        let arrow2::datatypes::DataType::Extension(name, _, metadata) = DataType::Geometry
+            .to_arrow()
+            .expect("Geometry should be convertible to Arrow")
+        else {
+            unreachable!("Geometry should be an Arrow extension type")
+        };
+        for field in &mut schema.fields {
+            if self.wkb_column(&field.name).is_some()
+                && matches!(
+                    field.data_type,
+                    arrow2::datatypes::DataType::Binary | arrow2::datatypes::DataType::LargeBinary
+                )
+            {
+                field.data_type = arrow2::datatypes::DataType::Extension(
+                    name.clone(),
+                    Box::new(field.data_type.clone()),
+                    metadata.clone(),
+                );
+            }
+        }
+    }
+
+    /// Builds bounding-box statistics for a geometry column from its covering column's statistics.
+    pub fn bounding_box_statistics(
+        &self,
+        column: &str,
+        row_group: &RowGroupMetaData,
+    ) -> ColumnRangeStatistics {
+        let Some(covering) = self
+            .wkb_column(column)
+            .and_then(|column| column.covering.as_ref())
+        else {
+            return ColumnRangeStatistics::Missing;
+        };
+        let bbox = &covering.bbox;
+        let bounds = (|| {
+            Some([
+                leaf_bound(row_group, &bbox.xmin, false)?,
+                leaf_bound(row_group, &bbox.ymin, false)?,
+                leaf_bound(row_group, &bbox.xmax, true)?,
+                leaf_bound(row_group, &bbox.ymax, true)?,
+            ])
+        })();
+        bounds
+            .and_then(|bbox| ColumnRangeStatistics::from_bounding_box(bbox).ok())
+            .unwrap_or(ColumnRangeStatistics::Missing)
+    }
+}
+
+/// Infers the Arrow schema of a Parquet file, typing GeoParquet geometry columns as Daft `Geometry`.
+pub fn infer_arrow_schema(
+    metadata: &FileMetaData,
+    options: Option<SchemaInferenceOptions>,
+) -> arrow2::error::Result<arrow2::datatypes::Schema> {
+    let mut schema = infer_schema_with_options(metadata, options)?;
+    if let Some(geo) = GeoParquetMetadata::from_file_metadata(metadata) {
+        geo.apply_to_arrow_schema(&mut schema);
+    }
+    Ok(schema)
+}
+
+/// Reads the min (or max) statistic of the floating point leaf column at `path`.
+fn leaf_bound(row_group: &RowGroupMetaData, path: &[String], max: bool) -> Option<f64> {
+    let column = row_group
+        .columns()
+        .iter()
+        .find(|c| c.descriptor().path_in_schema == path)?;
+    let stats = column.statistics()?.ok()?;
+    let stats = stats.as_any();
+    if let Some(stats) = stats.downcast_ref::<PrimitiveStatistics<f64>>() {
+        if max {
+            stats.max_value
+        } else {
+            stats.min_value
+        }
+    } else if let Some(stats) = stats.downcast_ref::<PrimitiveStatistics<f32>>() {
+        if max {
+            stats.max_value
+        } else {
+            stats.min_value
+        }
+        .map(f64::from)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs::File, sync::Arc};
+
+    use arrow2::{
+        array::{Array, BinaryArray, Float64Array, StructArray},
+        chunk::Chunk,
+        datatypes::{DataType as ArrowDataType, Field as ArrowField, Schema as ArrowSchema},
+        io::parquet::write::{
+            CompressionOptions, Encoding, FileWriter, KeyValue, RowGroupIterator, Version,
+            WriteOptions,
+        },
+    };
+    use common_error::DaftResult;
+    use daft_core::{prelude::Schema, utils::wkb::point_to_wkb};
+    use daft_dsl::{lit, resolved_col};
+    use daft_functions_geo::{st_geomfromtext, st_intersects};
+    use daft_io::{IOClient, IOConfig};
+
+    use super::*;
+    use crate::{read::read_parquet, row_group_metadata_to_table_stats};
+
+    const GEO_METADATA: &str = r#"{
+        "version": "1.1.0",
+        "primary_column": "geometry",
+        "columns": {
+            "geometry": {
+                "encoding": "WKB",
+                "geometry_types": ["Point"],
+                "covering": {"bbox": {
+                    "xmin": ["bbox", "xmin"], "ymin": ["bbox", "ymin"],
+                    "xmax": ["bbox", "xmax"], "ymax": ["bbox", "ymax"]
+                }}
+            },
+            "other": {"encoding": "point"}
+        }
+    }"#;
+
+    fn bbox_field() -> ArrowField {
+        let coord = |name| ArrowField::new(name, ArrowDataType::Float64, false);
+        ArrowField::new(
+            "bbox",
+            ArrowDataType::Struct(vec![
+                coord("xmin"),
+                coord("ymin"),
+                coord("xmax"),
+                coord("ymax"),
+            ]),
+            false,
+        )
+    }
+
+    fn points_chunk(points: &[(f64, f64)]) -> Chunk<Box<dyn Array>> {
+        let geometry =
+            BinaryArray::<i32>::from_iter(points.iter().map(|(x, y)| Some(point_to_wkb(*x, *y))));
+        let xs = Float64Array::from_vec(points.iter().map(|(x, _)| *x).collect()).boxed();
+        let ys = Float64Array::from_vec(points.iter().map(|(_, y)| *y).collect()).boxed();
+        let bbox = StructArray::new(
+            bbox_field().data_type,
+            vec![xs.clone(), ys.clone(), xs, ys],
+            None,
+        );
+        Chunk::new(vec![geometry.boxed(), bbox.boxed()])
+    }
+
+    /// Writes a GeoParquet file with one row group per entry of `row_groups`.
+    fn write_geoparquet(path: &std::path::Path, row_groups: &[&[(f64, f64)]]) {
+        let schema = ArrowSchema::from(vec![
+            ArrowField::new("geometry", ArrowDataType::Binary, true),
+            bbox_field(),
+        ]);
+        let options = WriteOptions {
+            write_statistics: true,
+            version: Version::V2,
+            compression: CompressionOptions::Uncompressed,
+            data_pagesize_limit: None,
+        };
+        let chunks = row_groups.iter().map(|points| Ok(points_chunk(points)));
+        let encodings = vec![vec![Encoding::Plain], vec![Encoding::Plain; 4]];
+        let row_groups = RowGroupIterator::try_new(chunks, &schema, options, encodings).unwrap();
+
+        let mut writer = FileWriter::try_new(File::create(path).unwrap(), schema, options).unwrap();
+        for group in row_groups {
+            writer.write(group.unwrap()).unwrap();
+        }
+        writer
+            .end(Some(vec![KeyValue {
+                key: GEO_METADATA_KEY.to_string(),
+                value: Some(GEO_METADATA.to_string()),
+            }]))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_parse_geo_metadata() {
+        let geo: GeoParquetMetadata = serde_json::from_str(GEO_METADATA).unwrap();
+        assert_eq!(geo.primary_column.as_deref(), Some("geometry"));
+        assert!(geo.wkb_column("geometry").unwrap().covering.is_some());
+        assert!(geo.wkb_column("other").is_none());
+
+        let mut schema = ArrowSchema::from(vec![
+            ArrowField::new("geometry", ArrowDataType::Binary, true),
+            ArrowField::new("other", ArrowDataType::Binary, true),
+        ]);
+        geo.apply_to_arrow_schema(&mut schema);
+        let daft_schema = Schema::from(&schema);
+        assert_eq!(
+            daft_schema.get_field("geometry").unwrap().dtype,
+            DataType::Geometry
+        );
+        assert_eq!(
+            daft_schema.get_field("other").unwrap().dtype,
+            DataType::Binary
+        );
+    }
+
+    #[test]
+    fn test_geoparquet_row_group_pruning() -> DaftResult<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("points.parquet");
+        write_geoparquet(
+            &path,
+            &[&[(0.0, 0.0), (1.0, 2.0)], &[(10.0, 10.0), (11.0, 12.0)]],
+        );
+        let uri = path.to_str().unwrap();
+
+        let metadata = parquet2::read::read_metadata(&mut File::open(&path).unwrap()).unwrap();
+        let geo = GeoParquetMetadata::from_file_metadata(&metadata).unwrap();
+        let schema = Schema::from(infer_arrow_schema(&metadata, None).unwrap());
+        assert_eq!(schema.get_field("geometry")?.dtype, DataType::Geometry);
+
+        let bboxes = metadata
+            .row_groups
+            .values()
+            .map(|rg| {
+                let stats = row_group_metadata_to_table_stats(rg, &schema, Some(&geo))?;
+                Ok(stats[0].bounding_box())
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        assert_eq!(
+            bboxes,
+            vec![Some([0.0, 0.0, 1.0, 2.0]), Some([10.0, 10.0, 11.0, 12.0])]
+        );
+
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        let predicate = st_intersects(
+            resolved_col("geometry"),
+            st_geomfromtext(lit("POLYGON((9 9,12 9,12 12,9 12,9 9))")),
+        );
+        let table = read_parquet(
+            uri,
+            None,
+            None,
+            None,
+            None,
+            Some(predicate),
+            io_client,
+            None,
+            true,
+            Default::default(),
+            None,
+        )?;
+        assert_eq!(table.len(), 2);
+        assert_eq!(table.get_column(0).data_type(), &DataType::Geometry);
+
+        Ok(())
+    }
+}
@@ -8,6 +8,7 @@ use daft_core::prelude::SchemaRef;
 use snafu::Snafu;
 
 mod file;
+pub mod geoparquet;
 pub mod metadata;
 #[cfg(feature = "python")]
 pub mod python;
@@ -7,10 +7,12 @@ use indexmap::IndexMap;
 use snafu::ResultExt;
 
 use super::column_range::parquet_statistics_to_column_range_statistics;
+use crate::geoparquet::GeoParquetMetadata;
 
 pub fn row_group_metadata_to_table_stats(
     metadata: &crate::metadata::RowGroupMetaData,
     schema: &Schema,
+    geo: Option<&GeoParquetMetadata>,
 ) -> DaftResult<TableStatistics> {
     // Create a map from {field_name: statistics} from the RowGroupMetaData for easy access
     let mut parquet_column_metadata: IndexMap<_, _> = metadata
@@ -30,7 +32,12 @@ pub fn row_group_metadata_to_table_stats(
     let columns = schema
         .into_iter()
         .map(|field| {
-            Ok(if ColumnRangeStatistics::supports_dtype(&field.dtype) {
+            Ok(if field.dtype.is_geometry() {
+                // Geometry columns have no usable min/max, only a bounding box from a covering column.
+                geo.map_or(ColumnRangeStatistics::Missing, |geo| {
+                    geo.bounding_box_statistics(&field.name, metadata)
+                })
+            } else if ColumnRangeStatistics::supports_dtype(&field.dtype) {
                 parquet_column_metadata
                     .swap_remove(&field.name)
                     .expect("Cannot find parsed Daft field in Parquet rowgroup metadata")
@@ -19,8 +19,8 @@ use tokio_stream::wrappers::ReceiverStream;
 use crate::{
     determine_parquet_parallelism,
     file::{build_row_ranges, RowGroupRange},
+    geoparquet::infer_arrow_schema,
     read::{ArrowChunk, ArrowChunkIters, ParquetSchemaInferenceOptions},
-    stream_reader::read::schema::infer_schema_with_options,
     PARQUET_MORSEL_SIZE,
 };
 
@@ -274,9 +274,11 @@ pub fn local_parquet_read_into_column_iters(
             })?,
     };
 
-    let schema = infer_schema_with_options(&metadata, Some(schema_infer_options.into()))
-        .with_context(|_| super::UnableToParseSchemaFromMetadataSnafu {
-            path: uri.to_string(),
+    let schema =
+        infer_arrow_schema(&metadata, Some(schema_infer_options.into())).with_context(|_| {
+            super::UnableToParseSchemaFromMetadataSnafu {
+                path: uri.to_string(),
+            }
         })?;
     let schema = prune_fields_from_schema(schema, columns)?;
     let daft_schema = Schema::from(&schema);
@@ -367,9 +369,11 @@ pub fn local_parquet_read_into_arrow(
     };
 
     // and infer a [`Schema`] from the `metadata`.
-    let schema = infer_schema_with_options(&metadata, Some(schema_infer_options.into()))
-        .with_context(|_| super::UnableToParseSchemaFromMetadataSnafu {
-            path: uri.to_string(),
+    let schema =
+        infer_arrow_schema(&metadata, Some(schema_infer_options.into())).with_context(|_| {
+            super::UnableToParseSchemaFromMetadataSnafu {
+                path: uri.to_string(),
+            }
         })?;
     let schema = prune_fields_from_schema(schema, columns)?;
     let daft_schema = Schema::from(&schema);
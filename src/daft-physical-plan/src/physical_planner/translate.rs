@@ -553,6 +553,9 @@ pub(super) fn translate_single_logical_node(
         LogicalPlan::Window(_window) => Err(DaftError::NotImplemented(
             "Window functions are currently only supported on the native runner.".to_string(),
         )),
+        LogicalPlan::RecursiveCte(_) => Err(DaftError::NotImplemented(
+            "Recursive CTEs are currently only supported on the native runner.".to_string(),
+        )),
     }?;
     // TODO(desmond): We can't perform this check for now because ScanTasks currently provide
     // different size estimations depending on when the approximation is computed. Once we fix
@@ -612,6 +615,8 @@ pub fn extract_agg_expr(expr: &ExprRef) -> DaftResult<AggExpr> {
                 AggExpr::Stddev(e) => AggExpr::Stddev(Expr::Alias(e, name.clone()).into()),
                 AggExpr::Min(e) => AggExpr::Min(Expr::Alias(e, name.clone()).into()),
                 AggExpr::Max(e) => AggExpr::Max(Expr::Alias(e, name.clone()).into()),
+                AggExpr::MinBy(e, by) => AggExpr::MinBy(Expr::Alias(e, name.clone()).into(), by),
+                AggExpr::MaxBy(e, by) => AggExpr::MaxBy(Expr::Alias(e, name.clone()).into(), by),
                 AggExpr::BoolAnd(e) => AggExpr::BoolAnd(Expr::Alias(e, name.clone()).into()),
                 AggExpr::BoolOr(e) => AggExpr::BoolOr(Expr::Alias(e, name.clone()).into()),
                 AggExpr::AnyValue(e, ignore_nulls) => {
@@ -824,6 +829,20 @@ pub fn populate_aggregation_stages_bound_with_schema(
                 let global_max_col = second_stage!(AggExpr::Max(max_col));
                 final_stage(global_max_col);
             }
+            // The first stage keeps the `by` value of each partial result, which is taken with
+            // `min_by(by, by)` rather than `min(by)` so that both pick the same row.
+            AggExpr::MinBy(expr, by) => {
+                let min_by_col = first_stage!(AggExpr::MinBy(expr.clone(), by.clone()));
+                let by_col = first_stage!(AggExpr::MinBy(by.clone(), by.clone()));
+                let global_min_by_col = second_stage!(AggExpr::MinBy(min_by_col, by_col));
+                final_stage(global_min_by_col);
+            }
+            AggExpr::MaxBy(expr, by) => {
+                let max_by_col = first_stage!(AggExpr::MaxBy(expr.clone(), by.clone()));
+                let by_col = first_stage!(AggExpr::MaxBy(by.clone(), by.clone()));
+                let global_max_by_col = second_stage!(AggExpr::MaxBy(max_by_col, by_col));
+                final_stage(global_max_by_col);
+            }
             AggExpr::BoolAnd(expr) => {
                 let bool_and_col = first_stage!(AggExpr::BoolAnd(expr.clone()));
                 let global_bool_and_col = second_stage!(AggExpr::BoolAnd(bool_and_col.clone()));
@@ -1160,6 +1179,34 @@ pub fn populate_aggregation_stages(
                     ));
                 final_exprs.push(resolved_col(max_of_max_id.clone()).alias(output_name));
             }
+            AggExpr::MinBy(e, by) | AggExpr::MaxBy(e, by) => {
+                let with_by = |e, by| match agg_expr {
+                    AggExpr::MinBy(..) => AggExpr::MinBy(e, by),
+                    _ => AggExpr::MaxBy(e, by),
+                };
+                // The first stage also keeps the `by` value of each partial result.
+                let value_id = agg_expr.semantic_id(schema).id;
+                let by_id = with_by(by.clone(), by.clone()).semantic_id(schema).id;
+                let global_id =
+                    with_by(resolved_col(value_id.clone()), resolved_col(by_id.clone()))
+                        .semantic_id(schema)
+                        .id;
+                first_stage_aggs
+                    .entry(value_id.clone())
+                    .or_insert_with(|| with_by(e.alias(value_id.clone()), by.clone()));
+                first_stage_aggs
+                    .entry(by_id.clone())
+                    .or_insert_with(|| with_by(by.alias(by_id.clone()), by.clone()));
+                second_stage_aggs
+                    .entry(global_id.clone())
+                    .or_insert_with(|| {
+                        with_by(
+                            resolved_col(value_id.clone()).alias(global_id.clone()),
+                            resolved_col(by_id.clone()),
+                        )
+                    });
+                final_exprs.push(resolved_col(global_id.clone()).alias(output_name));
+            }
             AggExpr::BoolAnd(e) => {
                 // First stage
                 let bool_and_id =
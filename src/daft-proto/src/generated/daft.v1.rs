@@ -11,7 +11,7 @@ pub struct Schema {
 pub struct DataType {
     #[prost(
         oneof = "data_type::Variant",
-        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35"
+        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36"
     )]
     pub variant: ::core::option::Option<data_type::Variant>,
 }
@@ -200,6 +200,8 @@ pub mod data_type {
         Python(super::Unit),
         #[prost(message, tag = "35")]
         Unknown(super::Unit),
+        #[prost(message, tag = "36")]
+        Geometry(super::Unit),
     }
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -269,7 +271,7 @@ pub mod expr {
 pub struct Literal {
     #[prost(
         oneof = "literal::Variant",
-        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21"
+        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23"
     )]
     pub variant: ::core::option::Option<literal::Variant>,
 }
@@ -318,6 +320,10 @@ pub mod literal {
     pub struct Decimal {
         #[prost(string, tag = "1")]
         pub value: ::prost::alloc::string::String,
+        #[prost(uint32, tag = "2")]
+        pub precision: u32,
+        #[prost(int32, tag = "3")]
+        pub scale: i32,
     }
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Struct {
@@ -378,6 +384,11 @@ pub mod literal {
         Decimal(Decimal),
         #[prost(message, tag = "21")]
         Struct(Struct),
+        /// bincode
+        #[prost(bytes, tag = "22")]
+        Series(::prost::alloc::vec::Vec<u8>),
+        #[prost(message, tag = "23")]
+        Python(super::PyObject),
     }
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -386,6 +397,41 @@ pub struct Column {
     pub name: ::prost::alloc::string::String,
     #[prost(uint64, optional, tag = "2")]
     pub qualifier: ::core::option::Option<u64>,
+    /// Join-side, outer and bound columns also carry their field.
+    #[prost(message, optional, tag = "3")]
+    pub field: ::core::option::Option<Field>,
+    #[prost(oneof = "column::Scope", tags = "4, 5, 6")]
+    pub scope: ::core::option::Option<column::Scope>,
+}
+/// Nested message and enum types in `Column`.
+pub mod column {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Scope {
+        #[prost(enumeration = "super::JoinSide", tag = "4")]
+        JoinSide(i32),
+        #[prost(message, tag = "5")]
+        OuterRef(super::PlanRef),
+        #[prost(uint64, tag = "6")]
+        Index(u64),
+    }
+}
+/// The plan which an outer column of a subquery refers to.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PlanRef {
+    #[prost(oneof = "plan_ref::Variant", tags = "1, 2, 3")]
+    pub variant: ::core::option::Option<plan_ref::Variant>,
+}
+/// Nested message and enum types in `PlanRef`.
+pub mod plan_ref {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Variant {
+        #[prost(string, tag = "1")]
+        Alias(::prost::alloc::string::String),
+        #[prost(message, tag = "2")]
+        Unqualified(super::Unit),
+        #[prost(uint64, tag = "3")]
+        Id(u64),
+    }
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Alias {
@@ -579,6 +625,8 @@ pub struct WindowSpec {
     pub order_by: ::prost::alloc::vec::Vec<SortOrder>,
     #[prost(message, optional, boxed, tag = "3")]
     pub frame: ::core::option::Option<::prost::alloc::boxed::Box<WindowFrame>>,
+    #[prost(uint64, tag = "4")]
+    pub min_periods: u64,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SortOrder {
@@ -589,6 +637,7 @@ pub struct SortOrder {
     #[prost(bool, tag = "3")]
     pub nulls_first: bool,
 }
+/// Unbounded frame boundaries are omitted.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct WindowFrame {
     #[prost(enumeration = "FrameType", tag = "1")]
@@ -669,7 +718,7 @@ pub mod subquery_test {
 pub struct Rel {
     #[prost(
         oneof = "rel::Variant",
-        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23"
+        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25"
     )]
     pub variant: ::core::option::Option<rel::Variant>,
 }
@@ -724,6 +773,10 @@ pub mod rel {
         TopN(::prost::alloc::boxed::Box<super::RelTopN>),
         #[prost(message, tag = "23")]
         Unpivot(::prost::alloc::boxed::Box<super::RelUnpivot>),
+        #[prost(message, tag = "24")]
+        Shard(::prost::alloc::boxed::Box<super::RelShard>),
+        #[prost(message, tag = "25")]
+        RecursiveCte(::prost::alloc::boxed::Box<super::RelRecursiveCte>),
     }
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -773,16 +826,57 @@ pub struct RelExplode {
 pub struct RelUnpivot {
     #[prost(message, optional, boxed, tag = "1")]
     pub input: ::core::option::Option<::prost::alloc::boxed::Box<Rel>>,
+    #[prost(message, repeated, tag = "2")]
+    pub ids: ::prost::alloc::vec::Vec<Expr>,
+    #[prost(message, repeated, tag = "3")]
+    pub values: ::prost::alloc::vec::Vec<Expr>,
+    #[prost(string, tag = "4")]
+    pub variable_name: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub value_name: ::prost::alloc::string::String,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RelSort {
     #[prost(message, optional, boxed, tag = "1")]
     pub input: ::core::option::Option<::prost::alloc::boxed::Box<Rel>>,
+    #[prost(message, repeated, tag = "2")]
+    pub sort_by: ::prost::alloc::vec::Vec<SortOrder>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RelRepartition {
     #[prost(message, optional, boxed, tag = "1")]
     pub input: ::core::option::Option<::prost::alloc::boxed::Box<Rel>>,
+    #[prost(oneof = "rel_repartition::Spec", tags = "2, 3, 4")]
+    pub spec: ::core::option::Option<rel_repartition::Spec>,
+}
+/// Nested message and enum types in `RelRepartition`.
+pub mod rel_repartition {
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Hash {
+        #[prost(uint64, optional, tag = "1")]
+        pub num_partitions: ::core::option::Option<u64>,
+        #[prost(message, repeated, tag = "2")]
+        pub by: ::prost::alloc::vec::Vec<super::Expr>,
+    }
+    #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct Random {
+        #[prost(uint64, optional, tag = "1")]
+        pub num_partitions: ::core::option::Option<u64>,
+    }
+    #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct IntoPartitions {
+        #[prost(uint64, tag = "1")]
+        pub num_partitions: u64,
+    }
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Spec {
+        #[prost(message, tag = "2")]
+        Hash(Hash),
+        #[prost(message, tag = "3")]
+        Random(Random),
+        #[prost(message, tag = "4")]
+        IntoPartitions(IntoPartitions),
+    }
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RelDistinct {
@@ -802,6 +896,16 @@ pub struct RelAggregate {
 pub struct RelPivot {
     #[prost(message, optional, boxed, tag = "1")]
     pub input: ::core::option::Option<::prost::alloc::boxed::Box<Rel>>,
+    #[prost(message, repeated, tag = "2")]
+    pub groups: ::prost::alloc::vec::Vec<Expr>,
+    #[prost(message, optional, boxed, tag = "3")]
+    pub pivot_column: ::core::option::Option<::prost::alloc::boxed::Box<Expr>>,
+    #[prost(message, optional, boxed, tag = "4")]
+    pub value_column: ::core::option::Option<::prost::alloc::boxed::Box<Expr>>,
+    #[prost(message, optional, boxed, tag = "5")]
+    pub agg: ::core::option::Option<::prost::alloc::boxed::Box<Agg>>,
+    #[prost(string, repeated, tag = "6")]
+    pub names: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RelConcat {
@@ -845,36 +949,98 @@ pub struct RelJoin {
     pub lhs: ::core::option::Option<::prost::alloc::boxed::Box<Rel>>,
     #[prost(message, optional, boxed, tag = "2")]
     pub rhs: ::core::option::Option<::prost::alloc::boxed::Box<Rel>>,
+    /// The join predicate references columns by their JoinSide.
+    #[prost(message, optional, boxed, tag = "3")]
+    pub on: ::core::option::Option<::prost::alloc::boxed::Box<Expr>>,
+    #[prost(enumeration = "JoinType", tag = "4")]
+    pub join_type: i32,
+    #[prost(enumeration = "JoinStrategy", optional, tag = "5")]
+    pub join_strategy: ::core::option::Option<i32>,
 }
+/// The output schema depends on the sink (catalog writes produce python objects) so it is carried.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RelSink {
     #[prost(message, optional, boxed, tag = "1")]
     pub input: ::core::option::Option<::prost::alloc::boxed::Box<Rel>>,
+    #[prost(message, optional, tag = "2")]
+    pub schema: ::core::option::Option<Schema>,
+    #[prost(message, optional, tag = "3")]
+    pub info: ::core::option::Option<SinkInfo>,
+}
+/// SinkInfo is opaque like ScanTask since catalog and data sinks hold python objects.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SinkInfo {
+    /// bincode
+    #[prost(bytes = "vec", tag = "1")]
+    pub info: ::prost::alloc::vec::Vec<u8>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RelSample {
     #[prost(message, optional, boxed, tag = "1")]
     pub input: ::core::option::Option<::prost::alloc::boxed::Box<Rel>>,
+    #[prost(double, tag = "2")]
+    pub fraction: f64,
+    #[prost(bool, tag = "3")]
+    pub with_replacement: bool,
+    #[prost(uint64, optional, tag = "4")]
+    pub seed: ::core::option::Option<u64>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RelMonotonicallyIncreasingId {
     #[prost(message, optional, boxed, tag = "1")]
     pub input: ::core::option::Option<::prost::alloc::boxed::Box<Rel>>,
+    #[prost(string, tag = "2")]
+    pub column_name: ::prost::alloc::string::String,
+    #[prost(uint64, optional, tag = "3")]
+    pub starting_offset: ::core::option::Option<u64>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RelSubqueryAlias {
     #[prost(message, optional, boxed, tag = "1")]
     pub input: ::core::option::Option<::prost::alloc::boxed::Box<Rel>>,
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RelWindow {
     #[prost(message, optional, boxed, tag = "1")]
     pub input: ::core::option::Option<::prost::alloc::boxed::Box<Rel>>,
+    #[prost(message, repeated, tag = "2")]
+    pub functions: ::prost::alloc::vec::Vec<WindowExpr>,
+    #[prost(string, repeated, tag = "3")]
+    pub aliases: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(message, optional, boxed, tag = "4")]
+    pub spec: ::core::option::Option<::prost::alloc::boxed::Box<WindowSpec>>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RelTopN {
     #[prost(message, optional, boxed, tag = "1")]
     pub input: ::core::option::Option<::prost::alloc::boxed::Box<Rel>>,
+    #[prost(message, repeated, tag = "2")]
+    pub sort_by: ::prost::alloc::vec::Vec<SortOrder>,
+    #[prost(uint64, tag = "3")]
+    pub limit: u64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RelShard {
+    #[prost(message, optional, boxed, tag = "1")]
+    pub input: ::core::option::Option<::prost::alloc::boxed::Box<Rel>>,
+    #[prost(message, optional, tag = "2")]
+    pub sharder: ::core::option::Option<Sharder>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RelRecursiveCte {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// The cache key of the in-memory source which the recursive term reads from.
+    #[prost(string, tag = "2")]
+    pub working_table_key: ::prost::alloc::string::String,
+    #[prost(message, optional, boxed, tag = "3")]
+    pub anchor: ::core::option::Option<::prost::alloc::boxed::Box<Rel>>,
+    #[prost(message, optional, boxed, tag = "4")]
+    pub recursive: ::core::option::Option<::prost::alloc::boxed::Box<Rel>>,
+    #[prost(bool, tag = "5")]
+    pub is_all: bool,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SourceInfo {
@@ -982,6 +1148,8 @@ pub struct Pushdowns {
     pub columns: ::core::option::Option<pushdowns::Columns>,
     #[prost(uint64, optional, tag = "4")]
     pub limit: ::core::option::Option<u64>,
+    #[prost(message, optional, tag = "5")]
+    pub sharder: ::core::option::Option<Sharder>,
 }
 /// Nested message and enum types in `Pushdowns`.
 pub mod pushdowns {
@@ -992,6 +1160,15 @@ pub mod pushdowns {
         pub columns: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
     }
 }
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct Sharder {
+    #[prost(enumeration = "ShardingStrategy", tag = "1")]
+    pub strategy: i32,
+    #[prost(uint64, tag = "2")]
+    pub world_size: u64,
+    #[prost(uint64, tag = "3")]
+    pub rank: u64,
+}
 /// ScanTasks is just typed list.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ScanTasks {
@@ -1028,9 +1205,10 @@ pub struct Agg {
 pub mod agg {
     /// avg, max, min, sum, stddev, skew
     /// any, every, some, bool_and, bool_or
-    /// count, count_all, count_nulls
+    /// count, count_all, count_nulls, approx_count_distinct
     /// any_value
     /// agg_list, agg_set, agg_concat
+    /// min_by, max_by
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct SetFunction {
         #[prost(string, tag = "1")]
@@ -1039,6 +1217,9 @@ pub mod agg {
         pub args: ::prost::alloc::vec::Vec<super::Expr>,
         #[prost(bool, tag = "3")]
         pub is_all: bool,
+        /// See SQL <null treatment>, only any_value accepts IGNORE NULLS.
+        #[prost(bool, tag = "4")]
+        pub ignore_nulls: bool,
     }
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct ApproxPercentile {
@@ -1128,6 +1309,7 @@ pub enum TimeUnit {
     Nanoseconds = 1,
     Microseconds = 2,
     Seconds = 3,
+    Milliseconds = 4,
 }
 impl TimeUnit {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -1140,6 +1322,7 @@ impl TimeUnit {
             Self::Nanoseconds => "TIME_UNIT_NANOSECONDS",
             Self::Microseconds => "TIME_UNIT_MICROSECONDS",
             Self::Seconds => "TIME_UNIT_SECONDS",
+            Self::Milliseconds => "TIME_UNIT_MILLISECONDS",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -1149,6 +1332,7 @@ impl TimeUnit {
             "TIME_UNIT_NANOSECONDS" => Some(Self::Nanoseconds),
             "TIME_UNIT_MICROSECONDS" => Some(Self::Microseconds),
             "TIME_UNIT_SECONDS" => Some(Self::Seconds),
+            "TIME_UNIT_MILLISECONDS" => Some(Self::Milliseconds),
             _ => None,
         }
     }
@@ -1420,3 +1604,105 @@ impl JoinSide {
         }
     }
 }
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum JoinType {
+    Unspecified = 0,
+    Inner = 1,
+    Left = 2,
+    Right = 3,
+    Outer = 4,
+    Anti = 5,
+    Semi = 6,
+}
+impl JoinType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "JOIN_TYPE_UNSPECIFIED",
+            Self::Inner => "JOIN_TYPE_INNER",
+            Self::Left => "JOIN_TYPE_LEFT",
+            Self::Right => "JOIN_TYPE_RIGHT",
+            Self::Outer => "JOIN_TYPE_OUTER",
+            Self::Anti => "JOIN_TYPE_ANTI",
+            Self::Semi => "JOIN_TYPE_SEMI",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "JOIN_TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+            "JOIN_TYPE_INNER" => Some(Self::Inner),
+            "JOIN_TYPE_LEFT" => Some(Self::Left),
+            "JOIN_TYPE_RIGHT" => Some(Self::Right),
+            "JOIN_TYPE_OUTER" => Some(Self::Outer),
+            "JOIN_TYPE_ANTI" => Some(Self::Anti),
+            "JOIN_TYPE_SEMI" => Some(Self::Semi),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum JoinStrategy {
+    Unspecified = 0,
+    Hash = 1,
+    SortMerge = 2,
+    Broadcast = 3,
+    Cross = 4,
+}
+impl JoinStrategy {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "JOIN_STRATEGY_UNSPECIFIED",
+            Self::Hash => "JOIN_STRATEGY_HASH",
+            Self::SortMerge => "JOIN_STRATEGY_SORT_MERGE",
+            Self::Broadcast => "JOIN_STRATEGY_BROADCAST",
+            Self::Cross => "JOIN_STRATEGY_CROSS",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "JOIN_STRATEGY_UNSPECIFIED" => Some(Self::Unspecified),
+            "JOIN_STRATEGY_HASH" => Some(Self::Hash),
+            "JOIN_STRATEGY_SORT_MERGE" => Some(Self::SortMerge),
+            "JOIN_STRATEGY_BROADCAST" => Some(Self::Broadcast),
+            "JOIN_STRATEGY_CROSS" => Some(Self::Cross),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ShardingStrategy {
+    Unspecified = 0,
+    File = 1,
+}
+impl ShardingStrategy {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "SHARDING_STRATEGY_UNSPECIFIED",
+            Self::File => "SHARDING_STRATEGY_FILE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SHARDING_STRATEGY_UNSPECIFIED" => Some(Self::Unspecified),
+            "SHARDING_STRATEGY_FILE" => Some(Self::File),
+            _ => None,
+        }
+    }
+}
@@ -592,6 +592,16 @@ impl RecordBatch {
             AggExpr::Max(expr) => self
                 .eval_expression(&BoundExpr::new_unchecked(expr.clone()))?
                 .max(groups),
+            AggExpr::MinBy(expr, by) => {
+                let evaled = self.eval_expression(&BoundExpr::new_unchecked(expr.clone()))?;
+                let by = self.eval_expression(&BoundExpr::new_unchecked(by.clone()))?;
+                evaled.min_by(&by, groups)
+            }
+            AggExpr::MaxBy(expr, by) => {
+                let evaled = self.eval_expression(&BoundExpr::new_unchecked(expr.clone()))?;
+                let by = self.eval_expression(&BoundExpr::new_unchecked(by.clone()))?;
+                evaled.max_by(&by, groups)
+            }
             AggExpr::BoolAnd(expr) => self
                 .eval_expression(&BoundExpr::new_unchecked(expr.clone()))?
                 .bool_and(groups),
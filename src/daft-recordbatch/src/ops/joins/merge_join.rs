@@ -4,7 +4,10 @@ use common_error::{DaftError, DaftResult};
 use daft_core::{
     array::ops::full::FullNull,
     datatypes::{DataType, UInt64Array},
-    kernels::search_sorted::build_partial_compare_with_nulls,
+    kernels::{
+        row_encoding::{build_partial_row_compare, requires_row_encoding},
+        search_sorted::build_partial_compare_with_nulls,
+    },
     series::{IntoSeries, Series},
 };
 
@@ -81,11 +84,15 @@ pub fn merge_inner_join(left: &RecordBatch, right: &RecordBatch) -> DaftResult<(
     // Construct comparator over all join keys.
     let mut cmp_list = Vec::with_capacity(left.num_columns());
     for (left_series, right_series) in left.columns.iter().zip(right.columns.iter()) {
-        cmp_list.push(build_partial_compare_with_nulls(
-            left_series.to_arrow().as_ref(),
-            right_series.to_arrow().as_ref(),
-            false,
-        )?);
+        if requires_row_encoding(left_series.data_type()) {
+            cmp_list.push(build_partial_row_compare(left_series, right_series)?);
+        } else {
+            cmp_list.push(build_partial_compare_with_nulls(
+                left_series.to_arrow().as_ref(),
+                right_series.to_arrow().as_ref(),
+                false,
+            )?);
+        }
     }
     let combined_comparator = |a_idx: usize, b_idx: usize| -> Option<Ordering> {
         for comparator in &cmp_list {
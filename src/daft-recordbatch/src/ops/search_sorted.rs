@@ -1,6 +1,11 @@
 use common_error::{DaftError, DaftResult};
 use daft_core::{
-    array::DataArray, datatypes::UInt64Array, kernels::search_sorted::search_sorted_multi_array,
+    array::DataArray,
+    datatypes::UInt64Array,
+    kernels::{
+        row_encoding::{encode_rows, requires_row_encoding},
+        search_sorted::search_sorted_multi_array,
+    },
     series::Series,
 };
 
@@ -18,6 +23,18 @@ impl RecordBatch {
             return Err(DaftError::ValueError(format!("Mismatch in number of arguments for `descending` in search sorted: num_columns: {} vs : descending.len() {}", self.num_columns(), descending.len())));
         }
 
+        // Nulls are placed last when ascending and first when descending, like the comparators of
+        // `search_sorted_multi_array`.
+        if self
+            .columns
+            .iter()
+            .any(|s| requires_row_encoding(s.data_type()))
+        {
+            let data = encode_rows(&self.columns, descending, descending)?;
+            let keys = encode_rows(&keys.columns, descending, descending)?;
+            return data.search_sorted(&keys, false);
+        }
+
         if self.num_columns() == 1 {
             return self
                 .get_column(0)
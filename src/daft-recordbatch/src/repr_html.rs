@@ -135,6 +135,10 @@ pub fn html_value(s: &Series, idx: usize) -> String {
             let arr = s.fixed_shape_sparse_tensor().unwrap();
             arr.html_value(idx)
         }
+        DataType::Geometry => {
+            let arr = s.geometry().unwrap();
+            arr.html_value(idx)
+        }
         #[cfg(feature = "python")]
         DataType::Python => {
             let arr = s.python().unwrap();
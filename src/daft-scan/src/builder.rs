@@ -434,11 +434,26 @@ pub fn iceberg_scan<T: AsRef<str>>(
     LogicalPlanBuilder::table_scan(scan_operator.into(), None)
 }
 
+/// Creates a logical scan operator which plans the Iceberg table at `metadata_location` natively.
 #[cfg(not(feature = "python"))]
 pub fn iceberg_scan<T: AsRef<str>>(
-    uri: T,
+    metadata_location: T,
     snapshot_id: Option<usize>,
     io_config: Option<IOConfig>,
 ) -> DaftResult<LogicalPlanBuilder> {
-    panic!("Iceberg scan requires the 'python' feature to be enabled.")
+    use crate::iceberg::{read_table_metadata, IcebergScanOperator, SnapshotSelection};
+
+    let metadata_location = metadata_location.as_ref();
+    let storage_config = Arc::new(StorageConfig::new_internal(true, io_config));
+    let metadata = read_table_metadata(metadata_location, &storage_config)?;
+    let selection = snapshot_id.map_or(SnapshotSelection::Current, |id| {
+        SnapshotSelection::Id(id as i64)
+    });
+    let operator = IcebergScanOperator::try_new(
+        metadata_location.to_string(),
+        Arc::new(metadata),
+        &selection,
+        storage_config,
+    )?;
+    LogicalPlanBuilder::table_scan(ScanOperatorRef(Arc::new(operator)), None)
 }
@@ -0,0 +1,1100 @@
+//! Readers and writers for Iceberg manifest lists and manifests, see
+//! <https://iceberg.apache.org/spec/#manifests>.
+use std::{cmp::Ordering, collections::HashMap, fmt::Display};
+
+use avro_rs::{types::Value, Schema as AvroSchema};
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::LiteralValue;
+use serde_json::json;
+
+use super::spec::{PartitionSpec, PrimitiveType, Schema};
+
+/// Whether a manifest tracks data files or delete files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum ManifestContent {
+    #[default]
+    Data,
+    Deletes,
+}
+
+/// Bounds of a single partition field across all files in a manifest.
+#[derive(Debug, Clone)]
+pub(crate) struct FieldSummary {
+    pub contains_null: bool,
+    pub contains_nan: Option<bool>,
+    pub lower_bound: Option<Vec<u8>>,
+    pub upper_bound: Option<Vec<u8>>,
+}
+
+/// An entry of a snapshot's manifest list.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ManifestFile {
+    pub path: String,
+    pub partition_spec_id: i32,
+    pub content: ManifestContent,
+    pub sequence_number: i64,
+    pub partitions: Option<Vec<FieldSummary>>,
+    pub length: i64,
+    pub min_sequence_number: i64,
+    pub added_snapshot_id: i64,
+    pub added_files_count: i32,
+    pub existing_files_count: i32,
+    pub deleted_files_count: i32,
+    pub added_rows_count: i64,
+    pub existing_rows_count: i64,
+    pub deleted_rows_count: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DataContent {
+    Data,
+    PositionDeletes,
+    EqualityDeletes,
+}
+
+/// A data or delete file tracked by a manifest entry that has not been deleted.
+#[derive(Debug, Clone)]
+pub(crate) struct DataFile {
+    pub content: DataContent,
+    pub file_path: String,
+    pub file_format: String,
+    /// The file's partition tuple, in the order of its partition spec's fields.
+    pub partition: Vec<Value>,
+    pub record_count: i64,
+    pub file_size_in_bytes: i64,
+    pub value_counts: HashMap<i32, i64>,
+    pub null_value_counts: HashMap<i32, i64>,
+    pub nan_value_counts: HashMap<i32, i64>,
+    pub lower_bounds: HashMap<i32, Vec<u8>>,
+    pub upper_bounds: HashMap<i32, Vec<u8>>,
+    /// The id of the snapshot that added the file, inherited from the manifest when the entry
+    /// doesn't set one.
+    pub snapshot_id: i64,
+    /// The data sequence number, inherited from the manifest when the entry doesn't set one.
+    pub sequence_number: i64,
+    /// The sequence number of the snapshot that wrote the file, inherited like `sequence_number`.
+    pub file_sequence_number: i64,
+}
+
+/// The status of a manifest entry, relative to the snapshot that wrote the manifest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum EntryStatus {
+    Existing = 0,
+    Added = 1,
+    Deleted = 2,
+}
+
+/// A file to write to a manifest.
+#[derive(Debug, Clone)]
+pub(crate) struct ManifestEntry {
+    pub status: EntryStatus,
+    pub file: DataFile,
+}
+
+fn invalid_manifest(path: &str, message: impl Display) -> DaftError {
+    DaftError::ValueError(format!("Invalid Iceberg manifest {path}: {message}"))
+}
+
+fn read_avro(path: &str, bytes: &[u8]) -> DaftResult<Vec<Vec<(String, Value)>>> {
+    let reader = avro_rs::Reader::new(bytes).map_err(|e| invalid_manifest(path, e))?;
+    reader
+        .map(
+            |value| match value.map_err(|e| invalid_manifest(path, e))? {
+                Value::Record(fields) => Ok(fields),
+                other => Err(invalid_manifest(
+                    path,
+                    format!("expected a record, found {other:?}"),
+                )),
+            },
+        )
+        .collect()
+}
+
+/// Looks up a record field, treating nulls the same as missing fields.
+fn get<'a>(record: &'a [(String, Value)], name: &str) -> Option<&'a Value> {
+    let value = record.iter().find(|(key, _)| key == name).map(|(_, v)| v)?;
+    match unwrap_union(value) {
+        Value::Null => None,
+        value => Some(value),
+    }
+}
+
+fn unwrap_union(value: &Value) -> &Value {
+    match value {
+        Value::Union(inner) => unwrap_union(inner),
+        value => value,
+    }
+}
+
+fn as_long(value: &Value) -> Option<i64> {
+    match value {
+        Value::Int(v) => Some(*v as i64),
+        Value::Long(v) => Some(*v),
+        _ => None,
+    }
+}
+
+fn as_bytes(value: &Value) -> Option<Vec<u8>> {
+    match value {
+        Value::Bytes(bytes) | Value::Fixed(_, bytes) => Some(bytes.clone()),
+        _ => None,
+    }
+}
+
+fn get_long(path: &str, record: &[(String, Value)], name: &str) -> DaftResult<i64> {
+    get(record, name)
+        .and_then(as_long)
+        .ok_or_else(|| invalid_manifest(path, format!("missing `{name}`")))
+}
+
+fn get_string(path: &str, record: &[(String, Value)], name: &str) -> DaftResult<String> {
+    match get(record, name) {
+        Some(Value::String(s)) => Ok(s.clone()),
+        _ => Err(invalid_manifest(path, format!("missing `{name}`"))),
+    }
+}
+
+/// Reads an Iceberg `map<int, T>` field, which Avro encodes as an array of key-value records.
+fn get_int_map<T>(
+    record: &[(String, Value)],
+    name: &str,
+    convert: impl Fn(&Value) -> Option<T>,
+) -> HashMap<i32, T> {
+    let Some(Value::Array(entries)) = get(record, name) else {
+        return HashMap::new();
+    };
+    entries
+        .iter()
+        .filter_map(|entry| match unwrap_union(entry) {
+            Value::Record(kv) => Some((
+                get(kv, "key").and_then(as_long)? as i32,
+                convert(get(kv, "value")?)?,
+            )),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Reads the manifest list of a snapshot.
+pub(crate) fn read_manifest_list(path: &str, bytes: &[u8]) -> DaftResult<Vec<ManifestFile>> {
+    read_avro(path, bytes)?
+        .into_iter()
+        .map(|record| {
+            let partitions = match get(&record, "partitions") {
+                Some(Value::Array(summaries)) => Some(
+                    summaries
+                        .iter()
+                        .map(|summary| match unwrap_union(summary) {
+                            Value::Record(summary) => Ok(FieldSummary {
+                                contains_null: matches!(
+                                    get(summary, "contains_null"),
+                                    Some(Value::Boolean(true))
+                                ),
+                                contains_nan: match get(summary, "contains_nan") {
+                                    Some(Value::Boolean(b)) => Some(*b),
+                                    _ => None,
+                                },
+                                lower_bound: get(summary, "lower_bound").and_then(as_bytes),
+                                upper_bound: get(summary, "upper_bound").and_then(as_bytes),
+                            }),
+                            _ => Err(invalid_manifest(path, "invalid partition field summary")),
+                        })
+                        .collect::<DaftResult<_>>()?,
+                ),
+                _ => None,
+            };
+            let sequence_number = get(&record, "sequence_number")
+                .and_then(as_long)
+                .unwrap_or(0);
+            let count = |names: &[&str]| {
+                names
+                    .iter()
+                    .find_map(|name| get(&record, name).and_then(as_long))
+                    .unwrap_or(0)
+            };
+            Ok(ManifestFile {
+                path: get_string(path, &record, "manifest_path")?,
+                partition_spec_id: get_long(path, &record, "partition_spec_id")? as i32,
+                content: match get(&record, "content").and_then(as_long) {
+                    None | Some(0) => ManifestContent::Data,
+                    Some(1) => ManifestContent::Deletes,
+                    Some(other) => {
+                        return Err(invalid_manifest(
+                            path,
+                            format!("unknown manifest content {other}"),
+                        ))
+                    }
+                },
+                sequence_number,
+                partitions,
+                length: get(&record, "manifest_length")
+                    .and_then(as_long)
+                    .unwrap_or(0),
+                min_sequence_number: get(&record, "min_sequence_number")
+                    .and_then(as_long)
+                    .unwrap_or(sequence_number),
+                added_snapshot_id: get(&record, "added_snapshot_id")
+                    .and_then(as_long)
+                    .unwrap_or(0),
+                // v1 manifest lists named the file counts `*_data_files_count`.
+                added_files_count: count(&["added_files_count", "added_data_files_count"]) as i32,
+                existing_files_count: count(&["existing_files_count", "existing_data_files_count"])
+                    as i32,
+                deleted_files_count: count(&["deleted_files_count", "deleted_data_files_count"])
+                    as i32,
+                added_rows_count: count(&["added_rows_count"]),
+                existing_rows_count: count(&["existing_rows_count"]),
+                deleted_rows_count: count(&["deleted_rows_count"]),
+            })
+        })
+        .collect()
+}
+
+/// Reads the live (not deleted) files of a manifest.
+///
+/// `partition_names` are the names of the manifest's partition spec fields, used to order the
+/// values of each file's partition tuple.
+pub(crate) fn read_manifest(
+    manifest: &ManifestFile,
+    bytes: &[u8],
+    partition_names: &[String],
+) -> DaftResult<Vec<DataFile>> {
+    let path = manifest.path.as_str();
+    let mut files = vec![];
+    for entry in read_avro(path, bytes)? {
+        if get(&entry, "status").and_then(as_long) == Some(EntryStatus::Deleted as i64) {
+            continue;
+        }
+        let Some(Value::Record(data_file)) = get(&entry, "data_file") else {
+            return Err(invalid_manifest(path, "missing `data_file`"));
+        };
+        let partition = match get(data_file, "partition") {
+            Some(Value::Record(values)) => partition_names
+                .iter()
+                .map(|name| get(values, name).cloned().unwrap_or(Value::Null))
+                .collect(),
+            _ => vec![Value::Null; partition_names.len()],
+        };
+        let content = match get(data_file, "content").and_then(as_long) {
+            None | Some(0) => DataContent::Data,
+            Some(1) => DataContent::PositionDeletes,
+            Some(2) => DataContent::EqualityDeletes,
+            Some(other) => {
+                return Err(invalid_manifest(
+                    path,
+                    format!("unknown data file content {other}"),
+                ))
+            }
+        };
+        files.push(DataFile {
+            content,
+            file_path: get_string(path, data_file, "file_path")?,
+            file_format: get_string(path, data_file, "file_format")?,
+            partition,
+            record_count: get_long(path, data_file, "record_count")?,
+            file_size_in_bytes: get_long(path, data_file, "file_size_in_bytes")?,
+            value_counts: get_int_map(data_file, "value_counts", as_long),
+            null_value_counts: get_int_map(data_file, "null_value_counts", as_long),
+            nan_value_counts: get_int_map(data_file, "nan_value_counts", as_long),
+            lower_bounds: get_int_map(data_file, "lower_bounds", as_bytes),
+            upper_bounds: get_int_map(data_file, "upper_bounds", as_bytes),
+            snapshot_id: get(&entry, "snapshot_id")
+                .and_then(as_long)
+                .unwrap_or(manifest.added_snapshot_id),
+            sequence_number: get(&entry, "sequence_number")
+                .and_then(as_long)
+                .unwrap_or(manifest.sequence_number),
+            file_sequence_number: get(&entry, "file_sequence_number")
+                .and_then(as_long)
+                .unwrap_or(manifest.sequence_number),
+        });
+    }
+    Ok(files)
+}
+
+/// Converts a partition value read from a manifest into a single-element Series of `dtype`.
+pub(crate) fn partition_value_to_series(
+    name: &str,
+    value: &Value,
+    dtype: &DataType,
+) -> DaftResult<Series> {
+    let literal = match unwrap_union(value) {
+        Value::Null => return Ok(Series::full_null(name, dtype, 1)),
+        Value::Boolean(v) => LiteralValue::Boolean(*v),
+        Value::Int(v) | Value::Date(v) | Value::TimeMillis(v) => LiteralValue::Int32(*v),
+        Value::Long(v) | Value::TimeMicros(v) | Value::TimestampMillis(v) => {
+            LiteralValue::Int64(*v)
+        }
+        Value::TimestampMicros(v) => LiteralValue::Int64(*v),
+        Value::Float(v) => LiteralValue::Float64(*v as f64),
+        Value::Double(v) => LiteralValue::Float64(*v),
+        Value::String(v) => LiteralValue::Utf8(v.clone()),
+        Value::Bytes(v) => LiteralValue::Binary(v.clone()),
+        Value::Fixed(size, v) => LiteralValue::FixedSizeBinary(v.clone(), *size),
+        Value::Uuid(v) => LiteralValue::FixedSizeBinary(v.as_bytes().to_vec(), 16),
+        Value::Decimal(v) => {
+            let bytes: Vec<u8> = v.try_into().map_err(|e| DaftError::External(Box::new(e)))?;
+            let DataType::Decimal128(precision, scale) = dtype else {
+                return Err(DaftError::TypeError(format!(
+                    "Iceberg decimal partition value can not be read as {dtype}"
+                )));
+            };
+            if bytes.len() > 16 {
+                return Err(DaftError::ValueError(
+                    "Iceberg decimal partition value does not fit in 128 bits".to_string(),
+                ));
+            }
+            LiteralValue::Decimal(decode_big_endian(&bytes), *precision as u8, *scale as i8)
+        }
+        other => {
+            return Err(DaftError::TypeError(format!(
+                "Unsupported Iceberg partition value {other:?}"
+            )))
+        }
+    };
+    cast_literal(name, literal, dtype)
+}
+
+/// Decodes a bound from Iceberg's single-value binary serialization into a single-element Series.
+///
+/// Returns `None` for bounds that can't be decoded, since they are only used for pruning.
+pub(crate) fn bound_to_series(
+    name: &str,
+    bytes: &[u8],
+    primitive: &PrimitiveType,
+) -> Option<Series> {
+    let int = || Some(i32::from_le_bytes(bytes.try_into().ok()?));
+    // Columns promoted from int to long (or float to double) keep the bounds of older files.
+    let long = || match bytes.len() {
+        4 => int().map(i64::from),
+        _ => Some(i64::from_le_bytes(bytes.try_into().ok()?)),
+    };
+    let literal = match primitive {
+        PrimitiveType::Boolean => LiteralValue::Boolean(*bytes.first()? != 0),
+        PrimitiveType::Int | PrimitiveType::Date => LiteralValue::Int32(int()?),
+        PrimitiveType::Long
+        | PrimitiveType::Time
+        | PrimitiveType::Timestamp
+        | PrimitiveType::Timestamptz
+        | PrimitiveType::TimestampNs
+        | PrimitiveType::TimestamptzNs => LiteralValue::Int64(long()?),
+        PrimitiveType::Float => {
+            LiteralValue::Float64(f32::from_le_bytes(bytes.try_into().ok()?) as f64)
+        }
+        PrimitiveType::Double => LiteralValue::Float64(match bytes.len() {
+            4 => f32::from_le_bytes(bytes.try_into().ok()?) as f64,
+            _ => f64::from_le_bytes(bytes.try_into().ok()?),
+        }),
+        PrimitiveType::Decimal { precision, scale } => LiteralValue::Decimal(
+            (bytes.len() <= 16).then(|| decode_big_endian(bytes))?,
+            *precision,
+            *scale as i8,
+        ),
+        PrimitiveType::String => LiteralValue::Utf8(String::from_utf8(bytes.to_vec()).ok()?),
+        PrimitiveType::Binary => LiteralValue::Binary(bytes.to_vec()),
+        PrimitiveType::Uuid | PrimitiveType::Fixed(_) => {
+            LiteralValue::FixedSizeBinary(bytes.to_vec(), bytes.len())
+        }
+    };
+    cast_literal(name, literal, &primitive.to_daft()).ok()
+}
+
+/// Writes a v2 manifest of `entries`, which must all have `content` and belong to `spec`.
+///
+/// Returns the manifest's entry in a manifest list along with the manifest's bytes. Added entries
+/// inherit their sequence numbers from the snapshot that commits the manifest, which has id
+/// `snapshot_id` and sequence number `sequence_number`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn write_manifest(
+    path: &str,
+    schema: &Schema,
+    spec: &PartitionSpec,
+    partition_types: &[Option<PrimitiveType>],
+    content: ManifestContent,
+    snapshot_id: i64,
+    sequence_number: i64,
+    entries: &[ManifestEntry],
+) -> DaftResult<(ManifestFile, Vec<u8>)> {
+    let partition_types = spec
+        .fields
+        .iter()
+        .zip(partition_types)
+        .map(|(field, value_type)| {
+            value_type.ok_or_else(|| {
+                DaftError::ValueError(format!(
+                    "Iceberg partition field {} has no primitive source column",
+                    field.name
+                ))
+            })
+        })
+        .collect::<DaftResult<Vec<_>>>()?;
+    let partition_fields = spec
+        .fields
+        .iter()
+        .zip(&partition_types)
+        .enumerate()
+        .map(|(i, (field, value_type))| {
+            let field_id = field.field_id.unwrap_or(1000 + i as i32);
+            json!({
+                "name": field.name,
+                "type": ["null", avro_type(value_type, field_id)],
+                "default": null,
+                "field-id": field_id,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut records = Vec::with_capacity(entries.len());
+    for entry in entries {
+        let file = &entry.file;
+        let partition = spec
+            .fields
+            .iter()
+            .zip(&partition_types)
+            .zip(&file.partition)
+            .map(|((field, value_type), value)| {
+                (
+                    field.name.clone(),
+                    Value::Union(Box::new(normalize_value(unwrap_union(value), value_type))),
+                )
+            })
+            .collect();
+        // Added entries leave their sequence numbers unset, to inherit them when committed.
+        let inherited = |value: i64| match entry.status {
+            EntryStatus::Added => Value::Union(Box::new(Value::Null)),
+            _ => Value::Union(Box::new(Value::Long(value))),
+        };
+        records.push(Value::Record(vec![
+            ("status".to_string(), Value::Int(entry.status as i32)),
+            (
+                "snapshot_id".to_string(),
+                Value::Union(Box::new(Value::Long(match entry.status {
+                    EntryStatus::Added => snapshot_id,
+                    _ => file.snapshot_id,
+                }))),
+            ),
+            (
+                "sequence_number".to_string(),
+                inherited(file.sequence_number),
+            ),
+            (
+                "file_sequence_number".to_string(),
+                inherited(file.file_sequence_number),
+            ),
+            (
+                "data_file".to_string(),
+                Value::Record(vec![
+                    (
+                        "content".to_string(),
+                        Value::Int(match file.content {
+                            DataContent::Data => 0,
+                            DataContent::PositionDeletes => 1,
+                            DataContent::EqualityDeletes => 2,
+                        }),
+                    ),
+                    (
+                        "file_path".to_string(),
+                        Value::String(file.file_path.clone()),
+                    ),
+                    (
+                        "file_format".to_string(),
+                        Value::String(file.file_format.clone()),
+                    ),
+                    ("partition".to_string(), Value::Record(partition)),
+                    ("record_count".to_string(), Value::Long(file.record_count)),
+                    (
+                        "file_size_in_bytes".to_string(),
+                        Value::Long(file.file_size_in_bytes),
+                    ),
+                    (
+                        "value_counts".to_string(),
+                        int_map_value(&file.value_counts, |v| Value::Long(*v)),
+                    ),
+                    (
+                        "null_value_counts".to_string(),
+                        int_map_value(&file.null_value_counts, |v| Value::Long(*v)),
+                    ),
+                    (
+                        "nan_value_counts".to_string(),
+                        int_map_value(&file.nan_value_counts, |v| Value::Long(*v)),
+                    ),
+                    (
+                        "lower_bounds".to_string(),
+                        int_map_value(&file.lower_bounds, |v| Value::Bytes(v.clone())),
+                    ),
+                    (
+                        "upper_bounds".to_string(),
+                        int_map_value(&file.upper_bounds, |v| Value::Bytes(v.clone())),
+                    ),
+                ]),
+            ),
+        ]));
+    }
+
+    let entry_schema = json!({
+        "type": "record",
+        "name": "manifest_entry",
+        "fields": [
+            {"name": "status", "type": "int", "field-id": 0},
+            {"name": "snapshot_id", "type": ["null", "long"], "default": null, "field-id": 1},
+            {"name": "sequence_number", "type": ["null", "long"], "default": null, "field-id": 3},
+            {"name": "file_sequence_number", "type": ["null", "long"], "default": null, "field-id": 4},
+            {"name": "data_file", "field-id": 2, "type": {
+                "type": "record",
+                "name": "r2",
+                "fields": [
+                    {"name": "content", "type": "int", "field-id": 134},
+                    {"name": "file_path", "type": "string", "field-id": 100},
+                    {"name": "file_format", "type": "string", "field-id": 101},
+                    {"name": "partition", "field-id": 102, "type": {
+                        "type": "record",
+                        "name": "r102",
+                        "fields": partition_fields,
+                    }},
+                    {"name": "record_count", "type": "long", "field-id": 103},
+                    {"name": "file_size_in_bytes", "type": "long", "field-id": 104},
+                    int_map_schema("value_counts", 109, 119, "long"),
+                    int_map_schema("null_value_counts", 110, 121, "long"),
+                    int_map_schema("nan_value_counts", 137, 138, "long"),
+                    int_map_schema("lower_bounds", 125, 126, "bytes"),
+                    int_map_schema("upper_bounds", 128, 129, "bytes"),
+                ],
+            }},
+        ],
+    });
+    let metadata = [
+        ("schema", serde_json::to_string(schema)?),
+        ("schema-id", schema.schema_id.to_string()),
+        ("partition-spec", serde_json::to_string(&spec.fields)?),
+        ("partition-spec-id", spec.spec_id.to_string()),
+        ("format-version", "2".to_string()),
+        (
+            "content",
+            match content {
+                ManifestContent::Data => "data",
+                ManifestContent::Deletes => "deletes",
+            }
+            .to_string(),
+        ),
+    ];
+    let bytes = write_avro(entry_schema, &metadata, records)?;
+
+    let mut manifest = ManifestFile {
+        path: path.to_string(),
+        partition_spec_id: spec.spec_id,
+        content,
+        sequence_number,
+        partitions: Some(summarize_partitions(&partition_types, entries)),
+        length: bytes.len() as i64,
+        min_sequence_number: entries
+            .iter()
+            .map(|entry| match entry.status {
+                EntryStatus::Added => sequence_number,
+                _ => entry.file.sequence_number,
+            })
+            .min()
+            .unwrap_or(sequence_number),
+        added_snapshot_id: snapshot_id,
+        ..Default::default()
+    };
+    for entry in entries {
+        let rows = entry.file.record_count;
+        let (files_count, rows_count) = match entry.status {
+            EntryStatus::Existing => (
+                &mut manifest.existing_files_count,
+                &mut manifest.existing_rows_count,
+            ),
+            EntryStatus::Added => (
+                &mut manifest.added_files_count,
+                &mut manifest.added_rows_count,
+            ),
+            EntryStatus::Deleted => (
+                &mut manifest.deleted_files_count,
+                &mut manifest.deleted_rows_count,
+            ),
+        };
+        *files_count += 1;
+        *rows_count += rows;
+    }
+    Ok((manifest, bytes))
+}
+
+/// Writes the v2 manifest list of a snapshot.
+pub(crate) fn write_manifest_list(
+    snapshot_id: i64,
+    parent_snapshot_id: Option<i64>,
+    sequence_number: i64,
+    manifests: &[ManifestFile],
+) -> DaftResult<Vec<u8>> {
+    let optional = |value: Option<Value>| Value::Union(Box::new(value.unwrap_or(Value::Null)));
+    let records = manifests
+        .iter()
+        .map(|manifest| {
+            let partitions = manifest.partitions.as_ref().map(|summaries| {
+                Value::Array(
+                    summaries
+                        .iter()
+                        .map(|summary| {
+                            Value::Record(vec![
+                                (
+                                    "contains_null".to_string(),
+                                    Value::Boolean(summary.contains_null),
+                                ),
+                                (
+                                    "contains_nan".to_string(),
+                                    optional(summary.contains_nan.map(Value::Boolean)),
+                                ),
+                                (
+                                    "lower_bound".to_string(),
+                                    optional(summary.lower_bound.clone().map(Value::Bytes)),
+                                ),
+                                (
+                                    "upper_bound".to_string(),
+                                    optional(summary.upper_bound.clone().map(Value::Bytes)),
+                                ),
+                            ])
+                        })
+                        .collect(),
+                )
+            });
+            Value::Record(vec![
+                (
+                    "manifest_path".to_string(),
+                    Value::String(manifest.path.clone()),
+                ),
+                ("manifest_length".to_string(), Value::Long(manifest.length)),
+                (
+                    "partition_spec_id".to_string(),
+                    Value::Int(manifest.partition_spec_id),
+                ),
+                (
+                    "content".to_string(),
+                    Value::Int(match manifest.content {
+                        ManifestContent::Data => 0,
+                        ManifestContent::Deletes => 1,
+                    }),
+                ),
+                (
+                    "sequence_number".to_string(),
+                    Value::Long(manifest.sequence_number),
+                ),
+                (
+                    "min_sequence_number".to_string(),
+                    Value::Long(manifest.min_sequence_number),
+                ),
+                (
+                    "added_snapshot_id".to_string(),
+                    Value::Long(manifest.added_snapshot_id),
+                ),
+                (
+                    "added_files_count".to_string(),
+                    Value::Int(manifest.added_files_count),
+                ),
+                (
+                    "existing_files_count".to_string(),
+                    Value::Int(manifest.existing_files_count),
+                ),
+                (
+                    "deleted_files_count".to_string(),
+                    Value::Int(manifest.deleted_files_count),
+                ),
+                (
+                    "added_rows_count".to_string(),
+                    Value::Long(manifest.added_rows_count),
+                ),
+                (
+                    "existing_rows_count".to_string(),
+                    Value::Long(manifest.existing_rows_count),
+                ),
+                (
+                    "deleted_rows_count".to_string(),
+                    Value::Long(manifest.deleted_rows_count),
+                ),
+                ("partitions".to_string(), optional(partitions)),
+            ])
+        })
+        .collect();
+
+    let list_schema = json!({
+        "type": "record",
+        "name": "manifest_file",
+        "fields": [
+            {"name": "manifest_path", "type": "string", "field-id": 500},
+            {"name": "manifest_length", "type": "long", "field-id": 501},
+            {"name": "partition_spec_id", "type": "int", "field-id": 502},
+            {"name": "content", "type": "int", "field-id": 517},
+            {"name": "sequence_number", "type": "long", "field-id": 515},
+            {"name": "min_sequence_number", "type": "long", "field-id": 516},
+            {"name": "added_snapshot_id", "type": "long", "field-id": 503},
+            {"name": "added_files_count", "type": "int", "field-id": 504},
+            {"name": "existing_files_count", "type": "int", "field-id": 505},
+            {"name": "deleted_files_count", "type": "int", "field-id": 506},
+            {"name": "added_rows_count", "type": "long", "field-id": 512},
+            {"name": "existing_rows_count", "type": "long", "field-id": 513},
+            {"name": "deleted_rows_count", "type": "long", "field-id": 514},
+            {"name": "partitions", "default": null, "field-id": 507, "type": ["null", {
+                "type": "array",
+                "element-id": 508,
+                "items": {
+                    "type": "record",
+                    "name": "r508",
+                    "fields": [
+                        {"name": "contains_null", "type": "boolean", "field-id": 509},
+                        {"name": "contains_nan", "type": ["null", "boolean"], "default": null, "field-id": 518},
+                        {"name": "lower_bound", "type": ["null", "bytes"], "default": null, "field-id": 510},
+                        {"name": "upper_bound", "type": ["null", "bytes"], "default": null, "field-id": 511},
+                    ],
+                },
+            }]},
+        ],
+    });
+    let metadata = [
+        ("snapshot-id", snapshot_id.to_string()),
+        (
+            "parent-snapshot-id",
+            parent_snapshot_id.map_or_else(|| "null".to_string(), |id| id.to_string()),
+        ),
+        ("sequence-number", sequence_number.to_string()),
+        ("format-version", "2".to_string()),
+    ];
+    write_avro(list_schema, &metadata, records)
+}
+
+/// Writes an Avro object container file with a single uncompressed block.
+///
+/// avro-rs re-serializes the schema when it writes the header, dropping the `field-id` attributes
+/// that Iceberg resolves fields with, so the container is assembled here with `schema` as given.
+fn write_avro(
+    schema: serde_json::Value,
+    metadata: &[(&str, String)],
+    records: Vec<Value>,
+) -> DaftResult<Vec<u8>> {
+    let to_daft_error = |e: avro_rs::Error| DaftError::External(e.into());
+    // Logical types only matter to readers, and avro-rs rejects some that Iceberg uses (such as
+    // uuid on fixed), so values are encoded against the underlying types.
+    let encoding_schema =
+        AvroSchema::parse(&strip_logical_types(schema.clone())).map_err(to_daft_error)?;
+
+    let mut header = HashMap::from([
+        (
+            "avro.schema".to_string(),
+            Value::Bytes(schema.to_string().into_bytes()),
+        ),
+        ("avro.codec".to_string(), Value::Bytes(b"null".to_vec())),
+    ]);
+    for (key, value) in metadata {
+        header.insert((*key).to_string(), Value::Bytes(value.clone().into_bytes()));
+    }
+    let sync_marker = *uuid::Uuid::new_v4().as_bytes();
+
+    let mut bytes = b"Obj\x01".to_vec();
+    bytes.extend(
+        avro_rs::to_avro_datum(
+            &AvroSchema::Map(Box::new(AvroSchema::Bytes)),
+            Value::Map(header),
+        )
+        .map_err(to_daft_error)?,
+    );
+    bytes.extend(sync_marker);
+    if !records.is_empty() {
+        let count = records.len();
+        let mut block = vec![];
+        for record in records {
+            block.extend(avro_rs::to_avro_datum(&encoding_schema, record).map_err(to_daft_error)?);
+        }
+        for long in [count, block.len()] {
+            bytes.extend(
+                avro_rs::to_avro_datum(&AvroSchema::Long, Value::Long(long as i64))
+                    .map_err(to_daft_error)?,
+            );
+        }
+        bytes.extend(block);
+        bytes.extend(sync_marker);
+    }
+    Ok(bytes)
+}
+
+fn strip_logical_types(mut schema: serde_json::Value) -> serde_json::Value {
+    match &mut schema {
+        serde_json::Value::Object(object) => {
+            object.remove("logicalType");
+            for value in object.values_mut() {
+                *value = strip_logical_types(value.take());
+            }
+        }
+        serde_json::Value::Array(values) => {
+            for value in values {
+                *value = strip_logical_types(value.take());
+            }
+        }
+        _ => {}
+    }
+    schema
+}
+
+/// The schema of an Iceberg `map<int, T>` field, which Avro encodes as an array of key-value records.
+fn int_map_schema(name: &str, field_id: i32, key_id: i32, value_type: &str) -> serde_json::Value {
+    let value_id = key_id + 1;
+    json!({
+        "name": name,
+        "type": ["null", {
+            "type": "array",
+            "logicalType": "map",
+            "items": {
+                "type": "record",
+                "name": format!("k{key_id}_v{value_id}"),
+                "fields": [
+                    {"name": "key", "type": "int", "field-id": key_id},
+                    {"name": "value", "type": value_type, "field-id": value_id},
+                ],
+            },
+        }],
+        "default": null,
+        "field-id": field_id,
+    })
+}
+
+fn int_map_value<T>(map: &HashMap<i32, T>, convert: impl Fn(&T) -> Value) -> Value {
+    let mut entries = map.iter().collect::<Vec<_>>();
+    entries.sort_by_key(|(key, _)| **key);
+    Value::Union(Box::new(Value::Array(
+        entries
+            .into_iter()
+            .map(|(key, value)| {
+                Value::Record(vec![
+                    ("key".to_string(), Value::Int(*key)),
+                    ("value".to_string(), convert(value)),
+                ])
+            })
+            .collect(),
+    )))
+}
+
+/// Returns the Avro type that values of `primitive` are written with.
+fn avro_type(primitive: &PrimitiveType, field_id: i32) -> serde_json::Value {
+    match primitive {
+        PrimitiveType::Boolean => json!("boolean"),
+        PrimitiveType::Int => json!("int"),
+        PrimitiveType::Long => json!("long"),
+        PrimitiveType::Float => json!("float"),
+        PrimitiveType::Double => json!("double"),
+        PrimitiveType::Decimal { precision, scale } => json!({
+            "type": "fixed",
+            "name": format!("decimal_{field_id}"),
+            "size": decimal_size(*precision),
+            "logicalType": "decimal",
+            "precision": precision,
+            "scale": scale,
+        }),
+        PrimitiveType::Date => json!({"type": "int", "logicalType": "date"}),
+        PrimitiveType::Time => json!({"type": "long", "logicalType": "time-micros"}),
+        PrimitiveType::Timestamp | PrimitiveType::Timestamptz => json!({
+            "type": "long",
+            "logicalType": "timestamp-micros",
+            "adjust-to-utc": *primitive == PrimitiveType::Timestamptz,
+        }),
+        PrimitiveType::TimestampNs | PrimitiveType::TimestamptzNs => json!({
+            "type": "long",
+            "logicalType": "timestamp-nanos",
+            "adjust-to-utc": *primitive == PrimitiveType::TimestamptzNs,
+        }),
+        PrimitiveType::String => json!("string"),
+        PrimitiveType::Uuid => json!({
+            "type": "fixed",
+            "name": format!("uuid_{field_id}"),
+            "size": 16,
+            "logicalType": "uuid",
+        }),
+        PrimitiveType::Fixed(len) => json!({
+            "type": "fixed",
+            "name": format!("fixed_{field_id}"),
+            "size": len,
+        }),
+        PrimitiveType::Binary => json!("bytes"),
+    }
+}
+
+/// The number of bytes that Iceberg stores decimals of `precision` digits in.
+fn decimal_size(precision: u8) -> usize {
+    let max_unscaled = 10u128.saturating_pow(precision as u32) - 1;
+    (1..=16)
+        .find(|size| max_unscaled < 1u128 << (8 * size - 1))
+        .unwrap_or(16)
+}
+
+/// Converts a value read from a manifest to the underlying Avro type of `primitive`, which is
+/// what values are written as.
+pub(crate) fn normalize_value(value: &Value, primitive: &PrimitiveType) -> Value {
+    match (value, primitive) {
+        (Value::Date(v) | Value::TimeMillis(v), _) => Value::Int(*v),
+        (Value::TimeMicros(v) | Value::TimestampMillis(v) | Value::TimestampMicros(v), _) => {
+            Value::Long(*v)
+        }
+        (Value::Uuid(v), _) => Value::Fixed(16, v.as_bytes().to_vec()),
+        (Value::Decimal(v), PrimitiveType::Decimal { precision, .. }) => {
+            let bytes: Vec<u8> = v.try_into().unwrap_or_default();
+            let size = decimal_size(*precision);
+            let fill = if bytes.first().is_some_and(|b| b & 0x80 != 0) {
+                0xff
+            } else {
+                0
+            };
+            let mut fixed = vec![fill; size.saturating_sub(bytes.len())];
+            fixed.extend(&bytes[bytes.len().saturating_sub(size)..]);
+            Value::Fixed(size, fixed)
+        }
+        (Value::Bytes(v), PrimitiveType::Uuid | PrimitiveType::Fixed(_)) => {
+            Value::Fixed(v.len(), v.clone())
+        }
+        (value, _) => value.clone(),
+    }
+}
+
+/// Converts the value of a single-element Series to the Avro value that Iceberg stores values of
+/// `primitive` as, such as in partition tuples.
+pub(crate) fn series_to_value(series: &Series, primitive: &PrimitiveType) -> DaftResult<Value> {
+    if !series.is_valid(0) {
+        return Ok(Value::Null);
+    }
+    let storage_type = match primitive {
+        PrimitiveType::Int | PrimitiveType::Date => DataType::Int32,
+        PrimitiveType::Long
+        | PrimitiveType::Time
+        | PrimitiveType::Timestamp
+        | PrimitiveType::Timestamptz
+        | PrimitiveType::TimestampNs
+        | PrimitiveType::TimestamptzNs => DataType::Int64,
+        PrimitiveType::Float => DataType::Float64,
+        _ => primitive.to_daft(),
+    };
+    let series = if series.data_type() == &storage_type {
+        series.clone()
+    } else if series.data_type().is_logical() {
+        // Logical types are cast through their physical representation, e.g. days for dates.
+        series
+            .cast(&series.data_type().to_physical())?
+            .cast(&storage_type)?
+    } else {
+        series.cast(&storage_type)?
+    };
+    Ok(match LiteralValue::get_from_series(&series, 0)? {
+        LiteralValue::Null => Value::Null,
+        LiteralValue::Boolean(v) => Value::Boolean(v),
+        LiteralValue::Int32(v) => Value::Int(v),
+        LiteralValue::Int64(v) => Value::Long(v),
+        LiteralValue::Float64(v) if *primitive == PrimitiveType::Float => Value::Float(v as f32),
+        LiteralValue::Float64(v) => Value::Double(v),
+        LiteralValue::Utf8(v) => Value::String(v),
+        LiteralValue::Binary(v) => Value::Bytes(v),
+        LiteralValue::FixedSizeBinary(v, size) => Value::Fixed(size, v),
+        LiteralValue::Decimal(v, precision, _) => {
+            let size = decimal_size(precision);
+            Value::Fixed(size, v.to_be_bytes()[16 - size..].to_vec())
+        }
+        other => {
+            return Err(DaftError::TypeError(format!(
+                "Can not write {other} as an Iceberg {primitive} value"
+            )))
+        }
+    })
+}
+
+/// Encodes a value with Iceberg's single-value binary serialization, as used for bounds.
+///
+/// Returns `None` for values that have no such encoding, which are then written without bounds.
+pub(crate) fn value_to_bound(value: &Value) -> Option<Vec<u8>> {
+    Some(match unwrap_union(value) {
+        Value::Boolean(v) => vec![u8::from(*v)],
+        Value::Int(v) | Value::Date(v) => v.to_le_bytes().to_vec(),
+        Value::Long(v) | Value::TimeMicros(v) | Value::TimestampMicros(v) => {
+            v.to_le_bytes().to_vec()
+        }
+        Value::Float(v) => v.to_le_bytes().to_vec(),
+        Value::Double(v) => v.to_le_bytes().to_vec(),
+        Value::String(v) => v.as_bytes().to_vec(),
+        // Decimals are sign-extended big-endian integers, which also decode as bounds.
+        Value::Bytes(v) | Value::Fixed(_, v) => v.clone(),
+        _ => return None,
+    })
+}
+
+/// Compares two values of the same Iceberg type, for the types whose bounds are tracked.
+pub(crate) fn compare_values(a: &Value, b: &Value) -> Option<Ordering> {
+    match (unwrap_union(a), unwrap_union(b)) {
+        (Value::Boolean(a), Value::Boolean(b)) => Some(a.cmp(b)),
+        (Value::Int(a) | Value::Date(a), Value::Int(b) | Value::Date(b)) => Some(a.cmp(b)),
+        (
+            Value::Long(a) | Value::TimeMicros(a) | Value::TimestampMicros(a),
+            Value::Long(b) | Value::TimeMicros(b) | Value::TimestampMicros(b),
+        ) => Some(a.cmp(b)),
+        (Value::Float(a), Value::Float(b)) => a.partial_cmp(b),
+        (Value::Double(a), Value::Double(b)) => a.partial_cmp(b),
+        (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+        (Value::Bytes(a), Value::Bytes(b)) => Some(a.cmp(b)),
+        _ => None,
+    }
+}
+
+fn is_nan(value: &Value) -> bool {
+    match value {
+        Value::Float(v) => v.is_nan(),
+        Value::Double(v) => v.is_nan(),
+        _ => false,
+    }
+}
+
+/// Summarizes the partition values of `entries` for a manifest list, one summary per field.
+fn summarize_partitions(
+    partition_types: &[PrimitiveType],
+    entries: &[ManifestEntry],
+) -> Vec<FieldSummary> {
+    partition_types
+        .iter()
+        .enumerate()
+        .map(|(i, value_type)| {
+            let values = entries
+                .iter()
+                .filter_map(|entry| entry.file.partition.get(i))
+                .map(unwrap_union)
+                .collect::<Vec<_>>();
+            let non_null = values
+                .iter()
+                .filter(|value| !matches!(value, Value::Null) && !is_nan(value))
+                .copied()
+                .collect::<Vec<_>>();
+            let bound = |ordering: Ordering| {
+                let mut bound = *non_null.first()?;
+                for value in &non_null[1..] {
+                    if compare_values(value, bound)? == ordering {
+                        bound = value;
+                    }
+                }
+                value_to_bound(bound)
+            };
+            FieldSummary {
+                contains_null: values.iter().any(|value| matches!(value, Value::Null)),
+                contains_nan: matches!(value_type, PrimitiveType::Float | PrimitiveType::Double)
+                    .then(|| values.iter().any(|value| is_nan(value))),
+                lower_bound: bound(Ordering::Less),
+                upper_bound: bound(Ordering::Greater),
+            }
+        })
+        .collect()
+}
+
+fn cast_literal(name: &str, literal: LiteralValue, dtype: &DataType) -> DaftResult<Series> {
+    Ok(literal.to_series().cast(dtype)?.rename(name))
+}
+
+/// Decodes a big-endian two's complement integer, as used for decimals.
+fn decode_big_endian(bytes: &[u8]) -> i128 {
+    let fill = if bytes.first().is_some_and(|b| b & 0x80 != 0) {
+        0xff
+    } else {
+        0
+    };
+    let mut buf = [fill; 16];
+    buf[16 - bytes.len()..].copy_from_slice(bytes);
+    i128::from_be_bytes(buf)
+}
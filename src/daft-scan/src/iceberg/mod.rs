@@ -0,0 +1,1004 @@
+//! Native scan planning for Iceberg tables, see <https://iceberg.apache.org/spec/#scan-planning>.
+//!
+//! The [`IcebergScanOperator`] reads a snapshot's manifest list and manifests directly and turns
+//! the live data files into [`ScanTask`]s, pruning manifests and files with partition and column
+//! statistics along the way. Writes go the other way, see [`write::produce_snapshot`].
+mod manifest;
+pub mod spec;
+pub mod write;
+
+use std::{collections::HashMap, sync::Arc};
+
+use avro_rs::types::Value;
+use bytes::Bytes;
+use common_error::{DaftError, DaftResult};
+use common_file_formats::{FileFormatConfig, ParquetSourceConfig};
+use common_runtime::RuntimeRef;
+use common_scan_info::{PartitionField, Pushdowns, ScanOperator, ScanTaskLike, ScanTaskLikeRef};
+use daft_core::prelude::*;
+use daft_dsl::{expr::bound_expr::BoundExpr, ExprRef};
+use daft_io::{IOClient, IOStatsContext, IOStatsRef};
+use daft_recordbatch::RecordBatch;
+use daft_stats::{
+    ColumnRangeStatistics, PartitionSpec, TableMetadata as DaftTableMetadata, TableStatistics,
+    TruthValue,
+};
+use futures::future::try_join_all;
+use manifest::{DataContent, DataFile, ManifestContent, ManifestFile};
+use spec::{PrimitiveType, Type};
+pub use spec::{SnapshotSelection, TableMetadata};
+
+use crate::{storage_config::StorageConfig, DataSource, ScanTask};
+
+/// Field id of the `file_path` column of position delete files.
+const DELETE_FILE_PATH_FIELD_ID: i32 = 2147483546;
+
+#[derive(Debug)]
+pub struct IcebergScanOperator {
+    /// Name of the table, only used for display.
+    table_name: String,
+    metadata: Arc<TableMetadata>,
+    snapshot_id: Option<i64>,
+    iceberg_schema: spec::Schema,
+    schema: SchemaRef,
+    partitioning_keys: Vec<PartitionField>,
+    file_format_config: Arc<FileFormatConfig>,
+    storage_config: Arc<StorageConfig>,
+}
+
+/// A partition spec resolved against the table schema.
+struct ResolvedPartitionSpec {
+    spec: spec::PartitionSpec,
+    fields: Vec<PartitionField>,
+    /// The type that partition values and bounds are serialized with, for each field.
+    value_types: Vec<Option<PrimitiveType>>,
+}
+
+impl ResolvedPartitionSpec {
+    fn try_new(spec: spec::PartitionSpec, schema: &spec::Schema) -> DaftResult<Self> {
+        let fields = spec.to_daft(schema)?;
+        let value_types = spec
+            .fields
+            .iter()
+            .map(
+                |field| match schema.find_field(field.source_id)?.field_type {
+                    Type::Primitive(source) => Some(match field.transform {
+                        spec::Transform::Identity
+                        | spec::Transform::Truncate(_)
+                        | spec::Transform::Void => source,
+                        spec::Transform::Day => PrimitiveType::Date,
+                        _ => PrimitiveType::Int,
+                    }),
+                    _ => None,
+                },
+            )
+            .collect();
+        Ok(Self {
+            spec,
+            fields,
+            value_types,
+        })
+    }
+
+    fn names(&self) -> Vec<String> {
+        self.spec.fields.iter().map(|f| f.name.clone()).collect()
+    }
+
+    fn schema(&self) -> Schema {
+        Schema::new(self.fields.iter().map(PartitionField::clone_field))
+    }
+
+    /// Returns whether any file in `manifest` may match `partition_filter`, using the bounds of
+    /// each partition field across the manifest.
+    fn manifest_may_match(&self, manifest: &ManifestFile, partition_filter: &ExprRef) -> bool {
+        let Some(summaries) = &manifest.partitions else {
+            return true;
+        };
+        let schema = Arc::new(self.schema());
+        let columns = self
+            .fields
+            .iter()
+            .zip(&self.value_types)
+            .zip(summaries)
+            .map(|((field, value_type), summary)| {
+                let name = &field.field.name;
+                let bounds = match (value_type, &summary.lower_bound, &summary.upper_bound) {
+                    (Some(value_type), Some(lower), Some(upper))
+                        if summary.contains_nan != Some(true) =>
+                    {
+                        manifest::bound_to_series(name, lower, value_type)
+                            .zip(manifest::bound_to_series(name, upper, value_type))
+                    }
+                    _ => None,
+                };
+                match bounds {
+                    Some((lower, upper)) if lower.data_type() == &field.field.dtype => {
+                        ColumnRangeStatistics::new(Some(lower), Some(upper))
+                            .unwrap_or(ColumnRangeStatistics::Missing)
+                    }
+                    _ => ColumnRangeStatistics::Missing,
+                }
+            })
+            .collect::<Vec<_>>();
+        if columns.len() != self.fields.len() {
+            return true;
+        }
+        // Filters that can't be evaluated against this spec (e.g. after partition evolution) are
+        // left to the per-file check.
+        let stats = TableStatistics::new(columns, schema.clone());
+        BoundExpr::try_new(partition_filter.clone(), &schema)
+            .ok()
+            .and_then(|filter| stats.eval_expression(&filter).ok())
+            .is_none_or(|result| result.to_truth_value() != TruthValue::False)
+    }
+
+    fn partition_values(&self, file: &DataFile) -> DaftResult<RecordBatch> {
+        let columns = self
+            .fields
+            .iter()
+            .zip(&file.partition)
+            .map(|(field, value)| {
+                manifest::partition_value_to_series(&field.field.name, value, &field.field.dtype)
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        if columns.is_empty() {
+            RecordBatch::empty(None)
+        } else {
+            RecordBatch::new_with_size(self.schema(), columns, 1)
+        }
+    }
+}
+
+impl IcebergScanOperator {
+    pub fn try_new(
+        table_name: String,
+        metadata: Arc<TableMetadata>,
+        selection: &SnapshotSelection,
+        storage_config: Arc<StorageConfig>,
+    ) -> DaftResult<Self> {
+        let snapshot_id = metadata
+            .select_snapshot(selection)?
+            .map(|snapshot| snapshot.snapshot_id);
+        let iceberg_schema = metadata.schema_for(selection)?.clone();
+        let schema = Arc::new(iceberg_schema.to_daft());
+        let partitioning_keys = metadata
+            .default_partition_spec()?
+            .to_daft(&iceberg_schema)?;
+        let file_format_config = Arc::new(FileFormatConfig::Parquet(ParquetSourceConfig {
+            field_id_mapping: Some(Arc::new(iceberg_schema.field_id_mapping())),
+            ..Default::default()
+        }));
+        Ok(Self {
+            table_name,
+            metadata,
+            snapshot_id,
+            iceberg_schema,
+            schema,
+            partitioning_keys,
+            file_format_config,
+            storage_config,
+        })
+    }
+
+    /// Returns the id of the snapshot being scanned, or `None` if the table has no snapshots.
+    pub fn snapshot_id(&self) -> Option<i64> {
+        self.snapshot_id
+    }
+
+    /// Builds column statistics for a data file from its lower and upper bounds.
+    fn file_statistics(&self, file: &DataFile) -> DaftResult<Option<TableStatistics>> {
+        let mut any_loaded = false;
+        let columns = self
+            .iceberg_schema
+            .fields
+            .iter()
+            .map(|field| {
+                let Type::Primitive(primitive) = &field.field_type else {
+                    return Ok(ColumnRangeStatistics::Missing);
+                };
+                // Bounds exclude NaNs, so they can't be used for columns containing any.
+                let has_nans = matches!(primitive, PrimitiveType::Float | PrimitiveType::Double)
+                    && file
+                        .nan_value_counts
+                        .get(&field.id)
+                        .is_none_or(|count| *count > 0);
+                let bounds = (!has_nans)
+                    .then(|| {
+                        file.lower_bounds
+                            .get(&field.id)
+                            .zip(file.upper_bounds.get(&field.id))
+                    })
+                    .flatten()
+                    .and_then(|(lower, upper)| {
+                        manifest::bound_to_series(&field.name, lower, primitive)
+                            .zip(manifest::bound_to_series(&field.name, upper, primitive))
+                    });
+                let Some((lower, upper)) = bounds else {
+                    return Ok(ColumnRangeStatistics::Missing);
+                };
+                any_loaded = true;
+                Ok(ColumnRangeStatistics::new(Some(lower), Some(upper))?)
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        Ok(any_loaded.then(|| TableStatistics::new(columns, self.schema.clone())))
+    }
+
+    /// Reads the manifests of the scanned snapshot, skipping those that `partition_filter` rules out.
+    fn read_manifests(
+        &self,
+        specs: &HashMap<i32, ResolvedPartitionSpec>,
+        partition_filter: Option<&ExprRef>,
+    ) -> DaftResult<Vec<(ManifestFile, Vec<DataFile>)>> {
+        let Some(snapshot_id) = self.snapshot_id else {
+            return Ok(vec![]);
+        };
+        let snapshot = self.metadata.snapshot_by_id(snapshot_id)?;
+        let (io_runtime, io_client) = self.storage_config.get_io_client_and_runtime()?;
+        let io_stats = IOStatsContext::new(format!(
+            "IcebergScanOperator::to_scan_tasks for {}",
+            self.table_name
+        ));
+
+        let manifests = if let Some(manifest_list) = &snapshot.manifest_list {
+            let bytes = read_file(
+                &io_runtime,
+                io_client.clone(),
+                manifest_list,
+                Some(io_stats.clone()),
+            )?;
+            manifest::read_manifest_list(manifest_list, &bytes)?
+        } else {
+            // v1 snapshots may list their manifests inline, which then all use the only partition spec.
+            let spec_id = self.metadata.default_spec_id.unwrap_or(0);
+            snapshot
+                .manifests
+                .iter()
+                .flatten()
+                .map(|path| ManifestFile {
+                    path: path.clone(),
+                    partition_spec_id: spec_id,
+                    added_snapshot_id: snapshot.snapshot_id,
+                    ..Default::default()
+                })
+                .collect()
+        };
+
+        let mut to_read = vec![];
+        for manifest in manifests {
+            let spec = specs.get(&manifest.partition_spec_id).ok_or_else(|| {
+                DaftError::ValueError(format!(
+                    "Iceberg manifest {} references unknown partition spec {}",
+                    manifest.path, manifest.partition_spec_id
+                ))
+            })?;
+            // Delete manifests are always read, their files are matched to data files later.
+            if manifest.content == ManifestContent::Data
+                && let Some(filter) = partition_filter
+                && !spec.manifest_may_match(&manifest, filter)
+            {
+                continue;
+            }
+            to_read.push((manifest, spec.names()));
+        }
+
+        io_runtime.block_within_async_context(async move {
+            try_join_all(to_read.into_iter().map(|(manifest, partition_names)| {
+                let io_client = io_client.clone();
+                let io_stats = io_stats.clone();
+                async move {
+                    let bytes = io_client
+                        .single_url_get(manifest.path.clone(), None, Some(io_stats))
+                        .await?
+                        .bytes()
+                        .await?;
+                    let files = manifest::read_manifest(&manifest, &bytes, &partition_names)?;
+                    DaftResult::Ok((manifest, files))
+                }
+            }))
+            .await
+        })?
+    }
+}
+
+/// Reads the table metadata file at `metadata_location`.
+pub fn read_table_metadata(
+    metadata_location: &str,
+    storage_config: &StorageConfig,
+) -> DaftResult<TableMetadata> {
+    let (io_runtime, io_client) = storage_config.get_io_client_and_runtime()?;
+    let bytes = read_file(&io_runtime, io_client, metadata_location, None)?;
+    serde_json::from_slice(&bytes).map_err(|e| {
+        DaftError::ValueError(format!(
+            "Invalid Iceberg table metadata {metadata_location}: {e}"
+        ))
+    })
+}
+
+fn read_file(
+    io_runtime: &RuntimeRef,
+    io_client: Arc<IOClient>,
+    path: &str,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<Bytes> {
+    let path = path.to_string();
+    Ok(io_runtime.block_within_async_context(async move {
+        io_client
+            .single_url_get(path, None, io_stats)
+            .await?
+            .bytes()
+            .await
+    })??)
+}
+
+/// A position delete file, along with what is needed to match it to the data files it applies to.
+struct PositionDeletes {
+    path: String,
+    spec_id: i32,
+    partition: Vec<Value>,
+    sequence_number: i64,
+    /// Set when the delete file only references a single data file.
+    referenced_file: Option<String>,
+}
+
+impl PositionDeletes {
+    fn applies_to(&self, spec_id: i32, file: &DataFile) -> bool {
+        self.sequence_number >= file.sequence_number
+            && self.spec_id == spec_id
+            && self.partition == file.partition
+            && self
+                .referenced_file
+                .as_ref()
+                .is_none_or(|path| path == &file.file_path)
+    }
+}
+
+impl ScanOperator for IcebergScanOperator {
+    fn name(&self) -> &'static str {
+        "IcebergScanOperator"
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    fn partitioning_keys(&self) -> &[PartitionField] {
+        &self.partitioning_keys
+    }
+
+    fn file_path_column(&self) -> Option<&str> {
+        None
+    }
+
+    fn generated_fields(&self) -> Option<SchemaRef> {
+        None
+    }
+
+    fn can_absorb_filter(&self) -> bool {
+        false
+    }
+
+    fn can_absorb_select(&self) -> bool {
+        true
+    }
+
+    fn can_absorb_limit(&self) -> bool {
+        false
+    }
+
+    fn can_absorb_shard(&self) -> bool {
+        false
+    }
+
+    fn multiline_display(&self) -> Vec<String> {
+        let mut lines = vec![
+            format!("IcebergScanOperator({})", self.table_name),
+            format!(
+                "Snapshot = {}",
+                self.snapshot_id
+                    .map_or_else(|| "None".to_string(), |id| id.to_string())
+            ),
+            format!("Schema = {}", self.schema.short_string()),
+            format!(
+                "Partitioning keys = [{}]",
+                self.partitioning_keys
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        ];
+        lines.extend(self.storage_config.multiline_display());
+
+        lines
+    }
+
+    fn to_scan_tasks(&self, pushdowns: Pushdowns) -> DaftResult<Vec<ScanTaskLikeRef>> {
+        let current_schema = self.metadata.current_schema()?;
+        let specs = self
+            .metadata
+            .partition_specs()
+            .into_iter()
+            .map(|spec| {
+                Ok((
+                    spec.spec_id,
+                    ResolvedPartitionSpec::try_new(spec, current_schema)?,
+                ))
+            })
+            .collect::<DaftResult<HashMap<_, _>>>()?;
+        let manifests = self.read_manifests(&specs, pushdowns.partition_filters.as_ref())?;
+
+        let mut position_deletes = vec![];
+        let mut data_files = vec![];
+        for (manifest, files) in manifests {
+            for file in files {
+                match file.content {
+                    DataContent::Data => data_files.push((manifest.partition_spec_id, file)),
+                    DataContent::PositionDeletes => {
+                        let referenced_file = file
+                            .lower_bounds
+                            .get(&DELETE_FILE_PATH_FIELD_ID)
+                            .filter(|lower| {
+                                file.upper_bounds.get(&DELETE_FILE_PATH_FIELD_ID) == Some(*lower)
+                            })
+                            .and_then(|path| String::from_utf8(path.clone()).ok());
+                        position_deletes.push(PositionDeletes {
+                            path: file.file_path,
+                            spec_id: manifest.partition_spec_id,
+                            partition: file.partition,
+                            sequence_number: file.sequence_number,
+                            referenced_file,
+                        });
+                    }
+                    DataContent::EqualityDeletes => {
+                        return Err(DaftError::NotImplemented(format!(
+                            "Iceberg equality deletes are not supported, found {}",
+                            file.file_path
+                        )));
+                    }
+                }
+            }
+        }
+
+        let data_filter = pushdowns
+            .filters
+            .as_ref()
+            .map(|filter| BoundExpr::try_new(filter.clone(), &self.schema))
+            .transpose()?;
+        let limit_files = pushdowns.limit.is_some()
+            && pushdowns.filters.is_none()
+            && pushdowns.partition_filters.is_none();
+        let mut rows_left = pushdowns.limit.unwrap_or(0) as i64;
+
+        let mut scan_tasks = vec![];
+        for (spec_id, file) in data_files {
+            if limit_files && rows_left <= 0 {
+                break;
+            }
+            if !file.file_format.eq_ignore_ascii_case("parquet") {
+                return Err(DaftError::NotImplemented(format!(
+                    "{} for iceberg not implemented!",
+                    file.file_format
+                )));
+            }
+
+            let spec = &specs[&spec_id];
+            let partition_values = spec.partition_values(&file)?;
+            if let Some(partition_filter) = &pushdowns.partition_filters
+                && !spec.fields.is_empty()
+            {
+                let partition_filter =
+                    BoundExpr::try_new(partition_filter.clone(), &partition_values.schema)?;
+                let evaluated = partition_values.eval_expression_list(&[partition_filter])?;
+                if evaluated.get_column(0).bool()?.get(0) != Some(true) {
+                    continue;
+                }
+            }
+
+            let statistics = self.file_statistics(&file)?;
+            if let (Some(stats), Some(filter)) = (&statistics, &data_filter)
+                && stats.eval_expression(filter)?.to_truth_value() == TruthValue::False
+            {
+                continue;
+            }
+
+            let iceberg_delete_files = position_deletes
+                .iter()
+                .filter(|deletes| deletes.applies_to(spec_id, &file))
+                .map(|deletes| deletes.path.clone())
+                .collect();
+            rows_left -= file.record_count;
+            scan_tasks.push(Arc::new(ScanTask::new(
+                vec![DataSource::File {
+                    path: file.file_path,
+                    chunk_spec: None,
+                    size_bytes: Some(file.file_size_in_bytes as u64),
+                    iceberg_delete_files: Some(iceberg_delete_files),
+                    metadata: Some(DaftTableMetadata {
+                        length: file.record_count as usize,
+                    }),
+                    partition_spec: Some(PartitionSpec {
+                        keys: partition_values,
+                    }),
+                    statistics,
+                    parquet_metadata: None,
+                }],
+                self.file_format_config.clone(),
+                self.schema.clone(),
+                self.storage_config.clone(),
+                pushdowns.clone(),
+                None,
+            )) as Arc<dyn ScanTaskLike>);
+        }
+        Ok(scan_tasks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{path::Path, sync::Arc};
+
+    use avro_rs::{types::Value, Schema as AvroSchema, Writer};
+    use common_error::DaftResult;
+    use common_scan_info::{Pushdowns, ScanOperator};
+    use daft_core::prelude::{Int32Array, IntoSeries};
+    use daft_dsl::{lit, resolved_col};
+    use daft_recordbatch::RecordBatch;
+    use serde_json::json;
+
+    use super::{
+        write::{produce_snapshot, NewDataFile, WriteMode},
+        IcebergScanOperator, SnapshotSelection, TableMetadata,
+    };
+    use crate::{storage_config::StorageConfig, DataSource, ScanTask};
+
+    const MANIFEST_LIST_SCHEMA: &str = r#"{
+        "type": "record", "name": "manifest_file", "fields": [
+            {"name": "manifest_path", "type": "string"},
+            {"name": "manifest_length", "type": "long"},
+            {"name": "partition_spec_id", "type": "int"},
+            {"name": "content", "type": "int"},
+            {"name": "sequence_number", "type": "long"},
+            {"name": "partitions", "type": ["null", {"type": "array", "items": {
+                "type": "record", "name": "r508", "fields": [
+                    {"name": "contains_null", "type": "boolean"},
+                    {"name": "contains_nan", "type": ["null", "boolean"]},
+                    {"name": "lower_bound", "type": ["null", "bytes"]},
+                    {"name": "upper_bound", "type": ["null", "bytes"]}
+                ]
+            }}]}
+        ]
+    }"#;
+
+    const MANIFEST_SCHEMA: &str = r#"{
+        "type": "record", "name": "manifest_entry", "fields": [
+            {"name": "status", "type": "int"},
+            {"name": "sequence_number", "type": ["null", "long"]},
+            {"name": "data_file", "type": {
+                "type": "record", "name": "r2", "fields": [
+                    {"name": "content", "type": "int"},
+                    {"name": "file_path", "type": "string"},
+                    {"name": "file_format", "type": "string"},
+                    {"name": "partition", "type": {
+                        "type": "record", "name": "r102", "fields": [
+                            {"name": "p", "type": ["null", "int"]}
+                        ]
+                    }},
+                    {"name": "record_count", "type": "long"},
+                    {"name": "file_size_in_bytes", "type": "long"},
+                    {"name": "lower_bounds", "type": ["null", {"type": "array", "items": {
+                        "type": "record", "name": "k126_v127", "fields": [
+                            {"name": "key", "type": "int"},
+                            {"name": "value", "type": "bytes"}
+                        ]
+                    }}]},
+                    {"name": "upper_bounds", "type": ["null", {"type": "array", "items": {
+                        "type": "record", "name": "k129_v130", "fields": [
+                            {"name": "key", "type": "int"},
+                            {"name": "value", "type": "bytes"}
+                        ]
+                    }}]}
+                ]
+            }}
+        ]
+    }"#;
+
+    struct TestFile {
+        path: &'static str,
+        content: i32,
+        p: i32,
+        bounds: Vec<(i32, Vec<u8>, Vec<u8>)>,
+    }
+
+    fn data_file(path: &'static str, p: i32, id_bounds: (i64, i64)) -> TestFile {
+        TestFile {
+            path,
+            content: 0,
+            p,
+            bounds: vec![(
+                1,
+                id_bounds.0.to_le_bytes().to_vec(),
+                id_bounds.1.to_le_bytes().to_vec(),
+            )],
+        }
+    }
+
+    fn write_avro(path: &Path, schema: &str, records: Vec<Value>) {
+        let schema = AvroSchema::parse_str(schema).unwrap();
+        let mut writer = Writer::new(&schema, vec![]);
+        for record in records {
+            writer.append(record).unwrap();
+        }
+        std::fs::write(path, writer.into_inner().unwrap()).unwrap();
+    }
+
+    fn bounds_map(bounds: &[(i32, Vec<u8>)]) -> Value {
+        Value::Union(Box::new(Value::Array(
+            bounds
+                .iter()
+                .map(|(key, value)| {
+                    Value::Record(vec![
+                        ("key".to_string(), Value::Int(*key)),
+                        ("value".to_string(), Value::Bytes(value.clone())),
+                    ])
+                })
+                .collect(),
+        )))
+    }
+
+    fn write_manifest(path: &Path, files: &[TestFile]) {
+        let records = files
+            .iter()
+            .map(|file| {
+                let lower = file
+                    .bounds
+                    .iter()
+                    .map(|(id, lower, _)| (*id, lower.clone()))
+                    .collect::<Vec<_>>();
+                let upper = file
+                    .bounds
+                    .iter()
+                    .map(|(id, _, upper)| (*id, upper.clone()))
+                    .collect::<Vec<_>>();
+                Value::Record(vec![
+                    ("status".to_string(), Value::Int(1)),
+                    (
+                        "sequence_number".to_string(),
+                        Value::Union(Box::new(Value::Null)),
+                    ),
+                    (
+                        "data_file".to_string(),
+                        Value::Record(vec![
+                            ("content".to_string(), Value::Int(file.content)),
+                            (
+                                "file_path".to_string(),
+                                Value::String(file.path.to_string()),
+                            ),
+                            (
+                                "file_format".to_string(),
+                                Value::String("PARQUET".to_string()),
+                            ),
+                            (
+                                "partition".to_string(),
+                                Value::Record(vec![(
+                                    "p".to_string(),
+                                    Value::Union(Box::new(Value::Int(file.p))),
+                                )]),
+                            ),
+                            ("record_count".to_string(), Value::Long(10)),
+                            ("file_size_in_bytes".to_string(), Value::Long(1024)),
+                            ("lower_bounds".to_string(), bounds_map(&lower)),
+                            ("upper_bounds".to_string(), bounds_map(&upper)),
+                        ]),
+                    ),
+                ])
+            })
+            .collect();
+        write_avro(path, MANIFEST_SCHEMA, records);
+    }
+
+    /// Writes a manifest list, where each manifest is given as `(path, content, sequence number,
+    /// bounds of the partition column)`.
+    fn write_manifest_list(path: &Path, manifests: &[(&Path, i32, i64, (i32, i32))]) {
+        let records = manifests
+            .iter()
+            .map(|(manifest, content, sequence_number, (lower, upper))| {
+                let summary = Value::Record(vec![
+                    ("contains_null".to_string(), Value::Boolean(false)),
+                    (
+                        "contains_nan".to_string(),
+                        Value::Union(Box::new(Value::Null)),
+                    ),
+                    (
+                        "lower_bound".to_string(),
+                        Value::Union(Box::new(Value::Bytes(lower.to_le_bytes().to_vec()))),
+                    ),
+                    (
+                        "upper_bound".to_string(),
+                        Value::Union(Box::new(Value::Bytes(upper.to_le_bytes().to_vec()))),
+                    ),
+                ]);
+                Value::Record(vec![
+                    (
+                        "manifest_path".to_string(),
+                        Value::String(manifest.to_str().unwrap().to_string()),
+                    ),
+                    ("manifest_length".to_string(), Value::Long(0)),
+                    ("partition_spec_id".to_string(), Value::Int(0)),
+                    ("content".to_string(), Value::Int(*content)),
+                    ("sequence_number".to_string(), Value::Long(*sequence_number)),
+                    (
+                        "partitions".to_string(),
+                        Value::Union(Box::new(Value::Array(vec![summary]))),
+                    ),
+                ])
+            })
+            .collect();
+        write_avro(path, MANIFEST_LIST_SCHEMA, records);
+    }
+
+    /// Writes a table partitioned by identity on `p` with two snapshots:
+    /// - snapshot 1 with files `a.parquet` and `b.parquet` in partition 0 and `c.parquet` in
+    ///   partition 1,
+    /// - snapshot 2 which adds position deletes for `a.parquet`, and a manifest for partition 5
+    ///   that doesn't exist and so fails the scan unless it is pruned.
+    fn write_table(dir: &Path) -> TableMetadata {
+        let m0 = dir.join("m0.avro");
+        let m1 = dir.join("m1.avro");
+        let m2 = dir.join("m2.avro");
+        let missing = dir.join("missing.avro");
+        write_manifest(
+            &m0,
+            &[
+                data_file("a.parquet", 0, (0, 10)),
+                data_file("b.parquet", 0, (50, 200)),
+            ],
+        );
+        write_manifest(&m1, &[data_file("c.parquet", 1, (0, 10))]);
+        write_manifest(
+            &m2,
+            &[TestFile {
+                path: "a-deletes.parquet",
+                content: 1,
+                p: 0,
+                bounds: vec![(
+                    super::DELETE_FILE_PATH_FIELD_ID,
+                    b"a.parquet".to_vec(),
+                    b"a.parquet".to_vec(),
+                )],
+            }],
+        );
+
+        let list1 = dir.join("snap-1.avro");
+        let list2 = dir.join("snap-2.avro");
+        write_manifest_list(&list1, &[(&m0, 0, 1, (0, 0)), (&m1, 0, 1, (1, 1))]);
+        write_manifest_list(
+            &list2,
+            &[
+                (&m0, 0, 1, (0, 0)),
+                (&m1, 0, 1, (1, 1)),
+                (&m2, 1, 2, (0, 0)),
+                (&missing, 0, 2, (5, 5)),
+            ],
+        );
+
+        serde_json::from_value(json!({
+            "format-version": 2,
+            "table-uuid": "9c12d441-03fe-4693-9a96-a0705ddf69c1",
+            "location": dir.to_str().unwrap(),
+            "last-sequence-number": 2,
+            "last-updated-ms": 2000,
+            "last-column-id": 2,
+            "current-schema-id": 0,
+            "schemas": [{
+                "type": "struct",
+                "schema-id": 0,
+                "fields": [
+                    {"id": 1, "name": "id", "required": true, "type": "long"},
+                    {"id": 2, "name": "p", "required": false, "type": "int"}
+                ]
+            }],
+            "default-spec-id": 0,
+            "partition-specs": [{
+                "spec-id": 0,
+                "fields": [{"source-id": 2, "field-id": 1000, "name": "p", "transform": "identity"}]
+            }],
+            "last-partition-id": 1000,
+            "current-snapshot-id": 2,
+            "snapshots": [
+                {
+                    "snapshot-id": 1,
+                    "sequence-number": 1,
+                    "timestamp-ms": 1000,
+                    "manifest-list": list1.to_str().unwrap(),
+                    "summary": {"operation": "append"},
+                    "schema-id": 0
+                },
+                {
+                    "snapshot-id": 2,
+                    "parent-snapshot-id": 1,
+                    "sequence-number": 2,
+                    "timestamp-ms": 2000,
+                    "manifest-list": list2.to_str().unwrap(),
+                    "summary": {"operation": "delete"},
+                    "schema-id": 0
+                }
+            ],
+            "snapshot-log": [
+                {"snapshot-id": 1, "timestamp-ms": 1000},
+                {"snapshot-id": 2, "timestamp-ms": 2000}
+            ],
+            "refs": {
+                "main": {"snapshot-id": 2, "type": "branch"},
+                "v1": {"snapshot-id": 1, "type": "tag"}
+            }
+        }))
+        .unwrap()
+    }
+
+    fn scan(
+        metadata: &TableMetadata,
+        selection: SnapshotSelection,
+        pushdowns: Pushdowns,
+    ) -> DaftResult<Vec<(String, Vec<String>)>> {
+        let operator = IcebergScanOperator::try_new(
+            "test".to_string(),
+            Arc::new(metadata.clone()),
+            &selection,
+            Arc::new(StorageConfig::new_internal(false, None)),
+        )?;
+        Ok(operator
+            .to_scan_tasks(pushdowns)?
+            .into_iter()
+            .map(|task| {
+                let task = task.as_any().downcast_ref::<ScanTask>().unwrap();
+                let DataSource::File {
+                    path,
+                    iceberg_delete_files,
+                    ..
+                } = &task.sources[0]
+                else {
+                    unreachable!()
+                };
+                (path.clone(), iceberg_delete_files.clone().unwrap())
+            })
+            .collect())
+    }
+
+    fn paths(tasks: &[(String, Vec<String>)]) -> Vec<&str> {
+        tasks.iter().map(|(path, _)| path.as_str()).collect()
+    }
+
+    #[test]
+    fn test_scan_snapshot_by_id() -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let metadata = write_table(dir.path());
+
+        let tasks = scan(&metadata, SnapshotSelection::Id(1), Pushdowns::default())?;
+        assert_eq!(paths(&tasks), ["a.parquet", "b.parquet", "c.parquet"]);
+        assert!(tasks.iter().all(|(_, deletes)| deletes.is_empty()));
+
+        let tasks = scan(
+            &metadata,
+            SnapshotSelection::Ref("v1".to_string()),
+            Pushdowns::default().with_limit(Some(5)),
+        )?;
+        assert_eq!(paths(&tasks), ["a.parquet"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_scan_prunes_manifests_by_partition() -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let metadata = write_table(dir.path());
+
+        // The current snapshot can't be scanned without pruning the missing manifest.
+        assert!(scan(&metadata, SnapshotSelection::Current, Pushdowns::default()).is_err());
+
+        let pushdowns =
+            Pushdowns::default().with_partition_filters(Some(resolved_col("p").eq(lit(0))));
+        let tasks = scan(&metadata, SnapshotSelection::Current, pushdowns)?;
+        assert_eq!(
+            tasks,
+            [
+                (
+                    "a.parquet".to_string(),
+                    vec!["a-deletes.parquet".to_string()]
+                ),
+                ("b.parquet".to_string(), vec![]),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_scan_prunes_files_by_stats() -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let metadata = write_table(dir.path());
+
+        let pushdowns = Pushdowns::default()
+            .with_filters(Some(resolved_col("id").gt(lit(100i64))))
+            .with_partition_filters(Some(resolved_col("p").lt(lit(3))));
+        let tasks = scan(&metadata, SnapshotSelection::Current, pushdowns)?;
+        assert_eq!(paths(&tasks), ["b.parquet"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_snapshot_selection() -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let metadata = write_table(dir.path());
+
+        let selected = |selection| {
+            metadata
+                .select_snapshot(&selection)
+                .map(|snapshot| snapshot.map(|snapshot| snapshot.snapshot_id))
+        };
+        assert_eq!(selected(SnapshotSelection::Current)?, Some(2));
+        assert_eq!(selected(SnapshotSelection::Id(1))?, Some(1));
+        assert_eq!(
+            selected(SnapshotSelection::Ref("main".to_string()))?,
+            Some(2)
+        );
+        assert_eq!(selected(SnapshotSelection::AsOf(1500))?, Some(1));
+        assert_eq!(selected(SnapshotSelection::AsOf(2000))?, Some(2));
+        assert!(selected(SnapshotSelection::AsOf(999)).is_err());
+        assert!(selected(SnapshotSelection::Id(3)).is_err());
+        assert!(selected(SnapshotSelection::Ref("missing".to_string())).is_err());
+
+        let roundtripped: TableMetadata =
+            serde_json::from_str(&serde_json::to_string(&metadata).unwrap()).unwrap();
+        assert_eq!(roundtripped, metadata);
+        Ok(())
+    }
+
+    fn new_data_file(path: &str, p: i32, id_bounds: (i64, i64)) -> NewDataFile {
+        let partition = Int32Array::from(("p", vec![p])).into_series();
+        NewDataFile {
+            file_path: path.to_string(),
+            partition_values: Some(RecordBatch::from_nonempty_columns(vec![partition]).unwrap()),
+            record_count: 10,
+            file_size_in_bytes: 1024,
+            value_counts: [(1, 10)].into(),
+            null_value_counts: [(1, 0)].into(),
+            lower_bounds: [(1, id_bounds.0.to_le_bytes().to_vec())].into(),
+            upper_bounds: [(1, id_bounds.1.to_le_bytes().to_vec())].into(),
+        }
+    }
+
+    #[test]
+    fn test_produce_snapshot() -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let mut metadata = write_table(dir.path());
+        let storage_config = StorageConfig::new_internal(false, None);
+
+        // Appends keep the existing manifests, and the new ones are pruned like any other.
+        let files = [new_data_file("d.parquet", 7, (300, 400))];
+        let snapshot = produce_snapshot(&metadata, &files, WriteMode::Append, &storage_config)?;
+        assert_eq!(snapshot.parent_snapshot_id, Some(2));
+        assert_eq!(snapshot.sequence_number, 3);
+        assert_eq!(snapshot.summary["added-data-files"], "1");
+        metadata.add_snapshot(snapshot);
+        let pushdowns = Pushdowns::default()
+            .with_filters(Some(resolved_col("id").gt(lit(100i64))))
+            .with_partition_filters(Some(resolved_col("p").eq(lit(7))));
+        let tasks = scan(&metadata, SnapshotSelection::Current, pushdowns)?;
+        assert_eq!(paths(&tasks), ["d.parquet"]);
+
+        // Dynamic overwrites only replace the files in the partitions that are written to.
+        let mut metadata = write_table(dir.path());
+        metadata.current_snapshot_id = Some(1);
+        metadata.refs.remove("main");
+        let files = [new_data_file("e.parquet", 0, (0, 5))];
+        let snapshot = produce_snapshot(
+            &metadata,
+            &files,
+            WriteMode::DynamicPartitionOverwrite,
+            &storage_config,
+        )?;
+        assert_eq!(snapshot.summary["deleted-data-files"], "2");
+        metadata.add_snapshot(snapshot);
+        let tasks = scan(&metadata, SnapshotSelection::Current, Pushdowns::default())?;
+        assert_eq!(paths(&tasks), ["e.parquet", "c.parquet"]);
+        Ok(())
+    }
+}
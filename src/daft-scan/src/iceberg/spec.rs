@@ -0,0 +1,743 @@
+//! Serde models of Iceberg table metadata, see <https://iceberg.apache.org/spec/#table-metadata>.
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Display,
+    str::FromStr,
+};
+
+use common_error::{DaftError, DaftResult};
+use common_scan_info::PartitionTransform;
+use daft_schema::{dtype::DataType, field::Field, time_unit::TimeUnit};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct TableMetadata {
+    pub format_version: u8,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub table_uuid: Option<String>,
+    pub location: String,
+    #[serde(default)]
+    pub last_sequence_number: i64,
+    pub last_updated_ms: i64,
+    pub last_column_id: i32,
+    #[serde(default)]
+    pub schemas: Vec<Schema>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_schema_id: Option<i32>,
+    /// The table schema, only written by v1 tables which predate `schemas`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<Schema>,
+    #[serde(default)]
+    pub partition_specs: Vec<PartitionSpec>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_spec_id: Option<i32>,
+    /// The fields of the partition spec, only written by v1 tables which predate `partition-specs`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub partition_spec: Option<Vec<PartitionField>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_partition_id: Option<i32>,
+    #[serde(default)]
+    pub properties: HashMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_snapshot_id: Option<i64>,
+    #[serde(default)]
+    pub snapshots: Vec<Snapshot>,
+    #[serde(default)]
+    pub snapshot_log: Vec<SnapshotLogEntry>,
+    #[serde(default)]
+    pub refs: HashMap<String, SnapshotReference>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Snapshot {
+    pub snapshot_id: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_snapshot_id: Option<i64>,
+    #[serde(default)]
+    pub sequence_number: i64,
+    pub timestamp_ms: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub manifest_list: Option<String>,
+    /// Manifest paths, only written by v1 tables which don't use a manifest list.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub manifests: Option<Vec<String>>,
+    #[serde(default)]
+    pub summary: HashMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema_id: Option<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct SnapshotLogEntry {
+    pub snapshot_id: i64,
+    pub timestamp_ms: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SnapshotReferenceType {
+    Branch,
+    Tag,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct SnapshotReference {
+    pub snapshot_id: i64,
+    #[serde(rename = "type")]
+    pub type_: SnapshotReferenceType,
+}
+
+/// Which snapshot of a table to read.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum SnapshotSelection {
+    /// The table's current snapshot.
+    #[default]
+    Current,
+    /// The snapshot with the given id.
+    Id(i64),
+    /// The snapshot that the named branch or tag points to.
+    Ref(String),
+    /// The snapshot that was current at the given time, in milliseconds since the epoch.
+    AsOf(i64),
+}
+
+impl Display for SnapshotSelection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Current => write!(f, "current"),
+            Self::Id(id) => write!(f, "snapshot {id}"),
+            Self::Ref(name) => write!(f, "ref {name}"),
+            Self::AsOf(timestamp_ms) => write!(f, "as of {timestamp_ms}ms"),
+        }
+    }
+}
+
+impl TableMetadata {
+    pub fn current_schema(&self) -> DaftResult<&Schema> {
+        match (self.current_schema_id, &self.schema) {
+            (Some(id), _) => self.schema_by_id(id),
+            (None, Some(schema)) => Ok(schema),
+            (None, None) => self.schemas.first().ok_or_else(|| {
+                DaftError::ValueError("Iceberg table metadata has no schema".to_string())
+            }),
+        }
+    }
+
+    pub fn schema_by_id(&self, id: i32) -> DaftResult<&Schema> {
+        self.schemas
+            .iter()
+            .chain(self.schema.as_ref())
+            .find(|schema| schema.schema_id == id)
+            .ok_or_else(|| {
+                DaftError::ValueError(format!("Iceberg table metadata has no schema with id {id}"))
+            })
+    }
+
+    /// Returns all partition specs, upgrading a v1 `partition-spec` to a spec with id 0.
+    pub fn partition_specs(&self) -> Vec<PartitionSpec> {
+        if self.partition_specs.is_empty() {
+            vec![PartitionSpec {
+                spec_id: 0,
+                fields: self.partition_spec.clone().unwrap_or_default(),
+            }]
+        } else {
+            self.partition_specs.clone()
+        }
+    }
+
+    pub fn default_partition_spec(&self) -> DaftResult<PartitionSpec> {
+        let spec_id = self.default_spec_id.unwrap_or(0);
+        self.partition_specs()
+            .into_iter()
+            .find(|spec| spec.spec_id == spec_id)
+            .ok_or_else(|| {
+                DaftError::ValueError(format!(
+                    "Iceberg table metadata has no partition spec with id {spec_id}"
+                ))
+            })
+    }
+
+    pub fn snapshot_by_id(&self, id: i64) -> DaftResult<&Snapshot> {
+        self.snapshots
+            .iter()
+            .find(|snapshot| snapshot.snapshot_id == id)
+            .ok_or_else(|| {
+                DaftError::ValueError(format!("Iceberg table has no snapshot with id {id}"))
+            })
+    }
+
+    /// Returns the current snapshot, or `None` if the table has no data yet.
+    pub fn current_snapshot(&self) -> DaftResult<Option<&Snapshot>> {
+        // v1 writers use -1 to mean that there is no current snapshot.
+        match self.current_snapshot_id {
+            Some(id) if id >= 0 => self.snapshot_by_id(id).map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    /// Resolves `selection` to a snapshot, returning `None` for the current snapshot of an empty table.
+    pub fn select_snapshot(&self, selection: &SnapshotSelection) -> DaftResult<Option<&Snapshot>> {
+        match selection {
+            SnapshotSelection::Current => self.current_snapshot(),
+            SnapshotSelection::Id(id) => self.snapshot_by_id(*id).map(Some),
+            SnapshotSelection::Ref(name) => {
+                let reference = self.refs.get(name).ok_or_else(|| {
+                    DaftError::ValueError(format!(
+                        "Iceberg table has no branch or tag named {name}"
+                    ))
+                })?;
+                self.snapshot_by_id(reference.snapshot_id).map(Some)
+            }
+            SnapshotSelection::AsOf(timestamp_ms) => {
+                let snapshot_id = if self.snapshot_log.is_empty() {
+                    self.snapshots
+                        .iter()
+                        .filter(|snapshot| snapshot.timestamp_ms <= *timestamp_ms)
+                        .max_by_key(|snapshot| snapshot.timestamp_ms)
+                        .map(|snapshot| snapshot.snapshot_id)
+                } else {
+                    self.snapshot_log
+                        .iter()
+                        .take_while(|entry| entry.timestamp_ms <= *timestamp_ms)
+                        .last()
+                        .map(|entry| entry.snapshot_id)
+                };
+                let snapshot_id = snapshot_id.ok_or_else(|| {
+                    DaftError::ValueError(format!(
+                        "Iceberg table has no snapshot as of {timestamp_ms}ms"
+                    ))
+                })?;
+                self.snapshot_by_id(snapshot_id).map(Some)
+            }
+        }
+    }
+
+    /// Adds `snapshot` to the table and makes it the head of the `main` branch, as catalogs do
+    /// when committing it.
+    pub fn add_snapshot(&mut self, snapshot: Snapshot) {
+        self.last_sequence_number = snapshot.sequence_number;
+        self.last_updated_ms = snapshot.timestamp_ms;
+        self.current_snapshot_id = Some(snapshot.snapshot_id);
+        self.refs.insert(
+            "main".to_string(),
+            SnapshotReference {
+                snapshot_id: snapshot.snapshot_id,
+                type_: SnapshotReferenceType::Branch,
+            },
+        );
+        self.snapshot_log.push(SnapshotLogEntry {
+            snapshot_id: snapshot.snapshot_id,
+            timestamp_ms: snapshot.timestamp_ms,
+        });
+        self.snapshots.push(snapshot);
+    }
+
+    /// Returns the schema to read a selected snapshot with.
+    ///
+    /// Time travel to a snapshot or tag reads the schema the snapshot was written with, while the
+    /// current snapshot and branches are read with the table's current schema.
+    pub fn schema_for(&self, selection: &SnapshotSelection) -> DaftResult<&Schema> {
+        let time_travel = match selection {
+            SnapshotSelection::Current => false,
+            SnapshotSelection::Ref(name) => self
+                .refs
+                .get(name)
+                .is_some_and(|reference| reference.type_ == SnapshotReferenceType::Tag),
+            SnapshotSelection::Id(_) | SnapshotSelection::AsOf(_) => true,
+        };
+        if time_travel
+            && let Some(schema_id) = self
+                .select_snapshot(selection)?
+                .and_then(|snapshot| snapshot.schema_id)
+        {
+            return self.schema_by_id(schema_id);
+        }
+        self.current_schema()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename = "struct", rename_all = "kebab-case")]
+pub struct Schema {
+    #[serde(default)]
+    pub schema_id: i32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub identifier_field_ids: Option<Vec<i32>>,
+    pub fields: Vec<NestedField>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NestedField {
+    pub id: i32,
+    pub name: String,
+    pub required: bool,
+    #[serde(rename = "type")]
+    pub field_type: Type,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Type {
+    Primitive(PrimitiveType),
+    Struct(StructType),
+    List(ListType),
+    Map(MapType),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename = "struct")]
+pub struct StructType {
+    pub fields: Vec<NestedField>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename = "list", rename_all = "kebab-case")]
+pub struct ListType {
+    pub element_id: i32,
+    pub element: Box<Type>,
+    pub element_required: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename = "map", rename_all = "kebab-case")]
+pub struct MapType {
+    pub key_id: i32,
+    pub key: Box<Type>,
+    pub value_id: i32,
+    pub value: Box<Type>,
+    pub value_required: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum PrimitiveType {
+    Boolean,
+    Int,
+    Long,
+    Float,
+    Double,
+    Decimal { precision: u8, scale: u8 },
+    Date,
+    Time,
+    Timestamp,
+    Timestamptz,
+    TimestampNs,
+    TimestamptzNs,
+    String,
+    Uuid,
+    Fixed(u64),
+    Binary,
+}
+
+impl Display for PrimitiveType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Boolean => write!(f, "boolean"),
+            Self::Int => write!(f, "int"),
+            Self::Long => write!(f, "long"),
+            Self::Float => write!(f, "float"),
+            Self::Double => write!(f, "double"),
+            Self::Decimal { precision, scale } => write!(f, "decimal({precision}, {scale})"),
+            Self::Date => write!(f, "date"),
+            Self::Time => write!(f, "time"),
+            Self::Timestamp => write!(f, "timestamp"),
+            Self::Timestamptz => write!(f, "timestamptz"),
+            Self::TimestampNs => write!(f, "timestamp_ns"),
+            Self::TimestamptzNs => write!(f, "timestamptz_ns"),
+            Self::String => write!(f, "string"),
+            Self::Uuid => write!(f, "uuid"),
+            Self::Fixed(len) => write!(f, "fixed[{len}]"),
+            Self::Binary => write!(f, "binary"),
+        }
+    }
+}
+
+impl FromStr for PrimitiveType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid Iceberg primitive type: {s}");
+        Ok(match s {
+            "boolean" => Self::Boolean,
+            "int" => Self::Int,
+            "long" => Self::Long,
+            "float" => Self::Float,
+            "double" => Self::Double,
+            "date" => Self::Date,
+            "time" => Self::Time,
+            "timestamp" => Self::Timestamp,
+            "timestamptz" => Self::Timestamptz,
+            "timestamp_ns" => Self::TimestampNs,
+            "timestamptz_ns" => Self::TimestamptzNs,
+            "string" => Self::String,
+            "uuid" => Self::Uuid,
+            "binary" => Self::Binary,
+            _ if let Some(len) = s.strip_prefix("fixed[").and_then(|s| s.strip_suffix(']')) => {
+                Self::Fixed(len.trim().parse().map_err(|_| invalid())?)
+            }
+            _ if let Some(args) = s.strip_prefix("decimal(").and_then(|s| s.strip_suffix(')')) => {
+                let (precision, scale) = args.split_once(',').ok_or_else(invalid)?;
+                Self::Decimal {
+                    precision: precision.trim().parse().map_err(|_| invalid())?,
+                    scale: scale.trim().parse().map_err(|_| invalid())?,
+                }
+            }
+            _ => return Err(invalid()),
+        })
+    }
+}
+
+impl TryFrom<String> for PrimitiveType {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<PrimitiveType> for String {
+    fn from(value: PrimitiveType) -> Self {
+        value.to_string()
+    }
+}
+
+impl PrimitiveType {
+    pub fn to_daft(&self) -> DataType {
+        match self {
+            Self::Boolean => DataType::Boolean,
+            Self::Int => DataType::Int32,
+            Self::Long => DataType::Int64,
+            Self::Float => DataType::Float32,
+            Self::Double => DataType::Float64,
+            Self::Decimal { precision, scale } => {
+                DataType::Decimal128(*precision as usize, *scale as usize)
+            }
+            Self::Date => DataType::Date,
+            Self::Time => DataType::Time(TimeUnit::Microseconds),
+            Self::Timestamp => DataType::Timestamp(TimeUnit::Microseconds, None),
+            Self::Timestamptz => {
+                DataType::Timestamp(TimeUnit::Microseconds, Some("UTC".to_string()))
+            }
+            Self::TimestampNs => DataType::Timestamp(TimeUnit::Nanoseconds, None),
+            Self::TimestamptzNs => {
+                DataType::Timestamp(TimeUnit::Nanoseconds, Some("UTC".to_string()))
+            }
+            Self::String => DataType::Utf8,
+            Self::Uuid => DataType::FixedSizeBinary(16),
+            Self::Fixed(len) => DataType::FixedSizeBinary(*len as usize),
+            Self::Binary => DataType::Binary,
+        }
+    }
+}
+
+impl Type {
+    pub fn to_daft(&self) -> DataType {
+        match self {
+            Self::Primitive(primitive) => primitive.to_daft(),
+            Self::Struct(StructType { fields }) => {
+                DataType::Struct(fields.iter().map(NestedField::to_daft).collect())
+            }
+            Self::List(list) => DataType::List(Box::new(list.element.to_daft())),
+            Self::Map(map) => DataType::Map {
+                key: Box::new(map.key.to_daft()),
+                value: Box::new(map.value.to_daft()),
+            },
+        }
+    }
+
+    /// Converts a Daft type to an Iceberg type, taking fresh field ids from `next_id`.
+    fn from_daft(dtype: &DataType, next_id: &mut i32) -> DaftResult<Self> {
+        let mut take_id = || {
+            *next_id += 1;
+            *next_id
+        };
+        let primitive = match dtype {
+            DataType::Boolean => PrimitiveType::Boolean,
+            DataType::Int8
+            | DataType::Int16
+            | DataType::Int32
+            | DataType::UInt8
+            | DataType::UInt16 => PrimitiveType::Int,
+            DataType::Int64 | DataType::UInt32 => PrimitiveType::Long,
+            DataType::Float32 => PrimitiveType::Float,
+            DataType::Float64 => PrimitiveType::Double,
+            DataType::Decimal128(precision, scale) => PrimitiveType::Decimal {
+                precision: *precision as u8,
+                scale: *scale as u8,
+            },
+            DataType::Date => PrimitiveType::Date,
+            DataType::Time(_) => PrimitiveType::Time,
+            DataType::Timestamp(TimeUnit::Nanoseconds, None) => PrimitiveType::TimestampNs,
+            DataType::Timestamp(TimeUnit::Nanoseconds, Some(_)) => PrimitiveType::TimestamptzNs,
+            DataType::Timestamp(_, None) => PrimitiveType::Timestamp,
+            DataType::Timestamp(_, Some(_)) => PrimitiveType::Timestamptz,
+            DataType::Utf8 => PrimitiveType::String,
+            DataType::Binary => PrimitiveType::Binary,
+            DataType::FixedSizeBinary(len) => PrimitiveType::Fixed(*len as u64),
+            DataType::Struct(fields) => {
+                let fields = fields
+                    .iter()
+                    .map(|field| NestedField::from_daft(field, next_id))
+                    .collect::<DaftResult<_>>()?;
+                return Ok(Self::Struct(StructType { fields }));
+            }
+            DataType::List(element) | DataType::FixedSizeList(element, _) => {
+                let element_id = take_id();
+                return Ok(Self::List(ListType {
+                    element_id,
+                    element: Box::new(Self::from_daft(element, next_id)?),
+                    element_required: false,
+                }));
+            }
+            DataType::Map { key, value } => {
+                let key_id = take_id();
+                let value_id = take_id();
+                return Ok(Self::Map(MapType {
+                    key_id,
+                    key: Box::new(Self::from_daft(key, next_id)?),
+                    value_id,
+                    value: Box::new(Self::from_daft(value, next_id)?),
+                    value_required: false,
+                }));
+            }
+            _ => {
+                return Err(DaftError::TypeError(format!(
+                    "Daft type {dtype} has no Iceberg equivalent"
+                )))
+            }
+        };
+        Ok(Self::Primitive(primitive))
+    }
+}
+
+impl NestedField {
+    pub fn to_daft(&self) -> Field {
+        Field::new(self.name.clone(), self.field_type.to_daft())
+    }
+
+    fn from_daft(field: &Field, next_id: &mut i32) -> DaftResult<Self> {
+        *next_id += 1;
+        let id = *next_id;
+        Ok(Self {
+            id,
+            name: field.name.to_string(),
+            required: false,
+            field_type: Type::from_daft(&field.dtype, next_id)?,
+            doc: None,
+        })
+    }
+}
+
+impl Schema {
+    /// Converts a Daft schema to an Iceberg schema, assigning field ids in depth-first order.
+    pub fn from_daft(schema: &daft_schema::schema::Schema) -> DaftResult<Self> {
+        let mut next_id = 0;
+        let fields = schema
+            .into_iter()
+            .map(|field| NestedField::from_daft(field, &mut next_id))
+            .collect::<DaftResult<_>>()?;
+        Ok(Self {
+            schema_id: 0,
+            identifier_field_ids: None,
+            fields,
+        })
+    }
+
+    pub fn to_daft(&self) -> daft_schema::schema::Schema {
+        daft_schema::schema::Schema::new(self.fields.iter().map(NestedField::to_daft))
+    }
+
+    /// Finds a field by id, searching nested types as well as the top-level fields.
+    pub fn find_field(&self, id: i32) -> Option<&NestedField> {
+        fn find_in(fields: &[NestedField], id: i32) -> Option<&NestedField> {
+            fields.iter().find_map(|field| {
+                (field.id == id)
+                    .then_some(field)
+                    .or_else(|| find_in_type(&field.field_type, id))
+            })
+        }
+        fn find_in_type(field_type: &Type, id: i32) -> Option<&NestedField> {
+            match field_type {
+                Type::Primitive(_) => None,
+                Type::Struct(StructType { fields }) => find_in(fields, id),
+                Type::List(list) => find_in_type(&list.element, id),
+                Type::Map(map) => {
+                    find_in_type(&map.key, id).or_else(|| find_in_type(&map.value, id))
+                }
+            }
+        }
+        find_in(&self.fields, id)
+    }
+
+    /// Returns the Daft field for every field id in the schema, which lets the Parquet reader
+    /// resolve columns by id rather than by their (possibly stale) names.
+    pub fn field_id_mapping(&self) -> BTreeMap<i32, Field> {
+        fn visit_type(field_type: &Type, name: &str, mapping: &mut BTreeMap<i32, Field>) {
+            match field_type {
+                Type::Primitive(_) => {}
+                Type::Struct(StructType { fields }) => visit_fields(fields, mapping),
+                Type::List(list) => {
+                    mapping.insert(list.element_id, Field::new(name, list.element.to_daft()));
+                    visit_type(&list.element, name, mapping);
+                }
+                Type::Map(map) => {
+                    mapping.insert(map.key_id, Field::new("key", map.key.to_daft()));
+                    mapping.insert(map.value_id, Field::new("value", map.value.to_daft()));
+                    visit_type(&map.key, "key", mapping);
+                    visit_type(&map.value, "value", mapping);
+                }
+            }
+        }
+        fn visit_fields(fields: &[NestedField], mapping: &mut BTreeMap<i32, Field>) {
+            for field in fields {
+                mapping.insert(field.id, field.to_daft());
+                visit_type(&field.field_type, &field.name, mapping);
+            }
+        }
+        let mut mapping = BTreeMap::new();
+        visit_fields(&self.fields, &mut mapping);
+        mapping
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct PartitionSpec {
+    #[serde(default)]
+    pub spec_id: i32,
+    pub fields: Vec<PartitionField>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct PartitionField {
+    pub source_id: i32,
+    /// Assigned by the table, only absent in v1 metadata where ids start from 1000.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field_id: Option<i32>,
+    pub name: String,
+    pub transform: Transform,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum Transform {
+    Identity,
+    Bucket(u64),
+    Truncate(u64),
+    Year,
+    Month,
+    Day,
+    Hour,
+    Void,
+}
+
+impl Display for Transform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Identity => write!(f, "identity"),
+            Self::Bucket(n) => write!(f, "bucket[{n}]"),
+            Self::Truncate(w) => write!(f, "truncate[{w}]"),
+            Self::Year => write!(f, "year"),
+            Self::Month => write!(f, "month"),
+            Self::Day => write!(f, "day"),
+            Self::Hour => write!(f, "hour"),
+            Self::Void => write!(f, "void"),
+        }
+    }
+}
+
+impl FromStr for Transform {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid Iceberg partition transform: {s}");
+        let parse_arg = |prefix: &str| {
+            s.strip_prefix(prefix)
+                .and_then(|s| s.strip_suffix(']'))
+                .map(|arg| arg.trim().parse::<u64>().map_err(|_| invalid()))
+        };
+        Ok(match s {
+            "identity" => Self::Identity,
+            "year" => Self::Year,
+            "month" => Self::Month,
+            "day" => Self::Day,
+            "hour" => Self::Hour,
+            "void" => Self::Void,
+            _ if let Some(n) = parse_arg("bucket[") => Self::Bucket(n?),
+            _ if let Some(w) = parse_arg("truncate[") => Self::Truncate(w?),
+            _ => return Err(invalid()),
+        })
+    }
+}
+
+impl TryFrom<String> for Transform {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<Transform> for String {
+    fn from(value: Transform) -> Self {
+        value.to_string()
+    }
+}
+
+impl Transform {
+    pub fn to_daft(&self) -> PartitionTransform {
+        match self {
+            Self::Identity => PartitionTransform::Identity,
+            Self::Bucket(n) => PartitionTransform::IcebergBucket(*n),
+            Self::Truncate(w) => PartitionTransform::IcebergTruncate(*w),
+            Self::Year => PartitionTransform::Year,
+            Self::Month => PartitionTransform::Month,
+            Self::Day => PartitionTransform::Day,
+            Self::Hour => PartitionTransform::Hour,
+            Self::Void => PartitionTransform::Void,
+        }
+    }
+
+    /// Returns the type of the partition values this transform produces from `source_type`.
+    pub fn result_type(&self, source_type: &DataType) -> DataType {
+        match self {
+            Self::Identity | Self::Truncate(_) | Self::Void => source_type.clone(),
+            Self::Bucket(_) | Self::Year | Self::Month | Self::Hour => DataType::Int32,
+            Self::Day => DataType::Date,
+        }
+    }
+}
+
+impl PartitionSpec {
+    /// Returns the Daft partition fields of this spec, resolving source columns in `schema`.
+    pub fn to_daft(&self, schema: &Schema) -> DaftResult<Vec<common_scan_info::PartitionField>> {
+        self.fields
+            .iter()
+            .map(|field| {
+                let source = schema.find_field(field.source_id).ok_or_else(|| {
+                    DaftError::ValueError(format!(
+                        "Iceberg partition field {} references unknown source field id {}",
+                        field.name, field.source_id
+                    ))
+                })?;
+                let source_field = source.to_daft();
+                let result_field = Field::new(
+                    field.name.clone(),
+                    field.transform.result_type(&source_field.dtype),
+                );
+                common_scan_info::PartitionField::new(
+                    result_field,
+                    Some(source_field),
+                    Some(field.transform.to_daft()),
+                )
+            })
+            .collect()
+    }
+}
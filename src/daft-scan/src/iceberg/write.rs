@@ -0,0 +1,383 @@
+//! Snapshot production for Iceberg writes, see <https://iceberg.apache.org/spec/#snapshots>.
+//!
+//! Data files are written elsewhere (see `daft-writers`); [`produce_snapshot`] writes the
+//! manifests and manifest list that add them to a table, and returns the snapshot that a catalog
+//! then commits.
+use std::{
+    collections::HashMap,
+    path::Path,
+    sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use avro_rs::types::Value;
+use common_error::{DaftError, DaftResult};
+use common_runtime::RuntimeRef;
+use daft_core::prelude::*;
+use daft_io::{IOClient, SourceType};
+use daft_recordbatch::RecordBatch;
+use uuid::Uuid;
+
+use super::{
+    manifest::{self, DataContent, DataFile, EntryStatus, ManifestContent, ManifestEntry},
+    read_file,
+    spec::{PrimitiveType, Snapshot, TableMetadata},
+    ResolvedPartitionSpec,
+};
+use crate::storage_config::StorageConfig;
+
+/// How a write changes the existing data of a table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WriteMode {
+    /// Adds the new files, keeping all existing data.
+    Append,
+    /// Replaces all existing data with the new files.
+    Overwrite,
+    /// Replaces the data of only the partitions that new files are written to.
+    DynamicPartitionOverwrite,
+}
+
+impl WriteMode {
+    fn operation(self) -> &'static str {
+        match self {
+            Self::Append => "append",
+            Self::Overwrite | Self::DynamicPartitionOverwrite => "overwrite",
+        }
+    }
+}
+
+/// A data file that has been written for a table, but is not yet part of any snapshot.
+#[derive(Debug, Clone)]
+pub struct NewDataFile {
+    pub file_path: String,
+    /// A single row with a column for each field of the table's default partition spec, or
+    /// `None` for unpartitioned tables.
+    pub partition_values: Option<RecordBatch>,
+    pub record_count: i64,
+    pub file_size_in_bytes: i64,
+    pub value_counts: HashMap<i32, i64>,
+    pub null_value_counts: HashMap<i32, i64>,
+    pub lower_bounds: HashMap<i32, Vec<u8>>,
+    pub upper_bounds: HashMap<i32, Vec<u8>>,
+}
+
+/// Encodes the value of a single-element Series as an Iceberg bound of `primitive`, returning
+/// `None` for nulls and types without bounds.
+pub fn encode_bound(value: &Series, primitive: &PrimitiveType) -> DaftResult<Option<Vec<u8>>> {
+    Ok(manifest::value_to_bound(&manifest::series_to_value(
+        value, primitive,
+    )?))
+}
+
+impl NewDataFile {
+    fn to_data_file(
+        &self,
+        spec: &ResolvedPartitionSpec,
+        snapshot_id: i64,
+        sequence_number: i64,
+    ) -> DaftResult<DataFile> {
+        let partition = spec
+            .spec
+            .fields
+            .iter()
+            .zip(&spec.value_types)
+            .map(|(field, value_type)| {
+                let column = self
+                    .partition_values
+                    .as_ref()
+                    .and_then(|values| {
+                        let idx = values.schema.get_index(&field.name).ok()?;
+                        Some(values.get_column(idx))
+                    })
+                    .ok_or_else(|| {
+                        DaftError::ValueError(format!(
+                            "Iceberg data file {} has no value for partition field {}",
+                            self.file_path, field.name
+                        ))
+                    })?;
+                let value_type = value_type.ok_or_else(|| {
+                    DaftError::ValueError(format!(
+                        "Iceberg partition field {} has no primitive source column",
+                        field.name
+                    ))
+                })?;
+                manifest::series_to_value(column, &value_type)
+            })
+            .collect::<DaftResult<_>>()?;
+        Ok(DataFile {
+            content: DataContent::Data,
+            file_path: self.file_path.clone(),
+            file_format: "PARQUET".to_string(),
+            partition,
+            record_count: self.record_count,
+            file_size_in_bytes: self.file_size_in_bytes,
+            value_counts: self.value_counts.clone(),
+            null_value_counts: self.null_value_counts.clone(),
+            nan_value_counts: HashMap::new(),
+            lower_bounds: self.lower_bounds.clone(),
+            upper_bounds: self.upper_bounds.clone(),
+            snapshot_id,
+            sequence_number,
+            file_sequence_number: sequence_number,
+        })
+    }
+}
+
+/// Counts of the files and rows that a snapshot adds or removes, for its summary.
+#[derive(Default)]
+struct Changes {
+    added_files: i64,
+    added_records: i64,
+    added_size: i64,
+    deleted_files: i64,
+    deleted_records: i64,
+    deleted_size: i64,
+}
+
+impl Changes {
+    fn add(&mut self, file: &DataFile) {
+        self.added_files += 1;
+        self.added_records += file.record_count;
+        self.added_size += file.file_size_in_bytes;
+    }
+
+    fn delete(&mut self, file: &DataFile) {
+        self.deleted_files += 1;
+        self.deleted_records += file.record_count;
+        self.deleted_size += file.file_size_in_bytes;
+    }
+
+    fn summary(&self, mode: WriteMode, parent: Option<&Snapshot>) -> HashMap<String, String> {
+        let mut summary = HashMap::from([
+            ("operation".to_string(), mode.operation().to_string()),
+            ("added-data-files".to_string(), self.added_files.to_string()),
+            ("added-records".to_string(), self.added_records.to_string()),
+            ("added-files-size".to_string(), self.added_size.to_string()),
+        ]);
+        if self.deleted_files > 0 {
+            summary.insert(
+                "deleted-data-files".to_string(),
+                self.deleted_files.to_string(),
+            );
+            summary.insert(
+                "deleted-records".to_string(),
+                self.deleted_records.to_string(),
+            );
+            summary.insert(
+                "removed-files-size".to_string(),
+                self.deleted_size.to_string(),
+            );
+        }
+        // Totals are carried forward from the parent, and left out if it doesn't track them.
+        for (total, added, deleted) in [
+            ("total-data-files", self.added_files, self.deleted_files),
+            ("total-records", self.added_records, self.deleted_records),
+            ("total-files-size", self.added_size, self.deleted_size),
+        ] {
+            let previous = match parent {
+                None => Some(0),
+                Some(parent) => parent
+                    .summary
+                    .get(total)
+                    .and_then(|value| value.parse::<i64>().ok()),
+            };
+            if let Some(previous) = previous {
+                summary.insert(total.to_string(), (previous + added - deleted).to_string());
+            }
+        }
+        summary
+    }
+}
+
+/// Writes the manifests and manifest list of a snapshot that adds `files` to the `main` branch of
+/// the table described by `base`, and returns the snapshot.
+///
+/// The snapshot is not committed: catalogs commit it on the condition that `main` still points to
+/// the snapshot `base` is at, and call this again with the refreshed metadata if it has moved on.
+pub fn produce_snapshot(
+    base: &TableMetadata,
+    files: &[NewDataFile],
+    mode: WriteMode,
+    storage_config: &StorageConfig,
+) -> DaftResult<Snapshot> {
+    if base.format_version != 2 {
+        return Err(DaftError::NotImplemented(format!(
+            "Writing to Iceberg tables of format version {} is not supported",
+            base.format_version
+        )));
+    }
+    let schema = base.current_schema()?;
+    let specs = base
+        .partition_specs()
+        .into_iter()
+        .map(|spec| Ok((spec.spec_id, ResolvedPartitionSpec::try_new(spec, schema)?)))
+        .collect::<DaftResult<HashMap<_, _>>>()?;
+    let default_spec_id = base.default_partition_spec()?.spec_id;
+    let default_spec = &specs[&default_spec_id];
+
+    let snapshot_id = new_snapshot_id(base);
+    let sequence_number = base.last_sequence_number + 1;
+    let parent = base.current_snapshot()?;
+    let (io_runtime, io_client) = storage_config.get_io_client_and_runtime()?;
+    let metadata_dir = format!("{}/metadata", base.location.trim_end_matches('/'));
+    let commit_id = Uuid::new_v4();
+    let mut manifest_paths = (0..).map(|i| format!("{metadata_dir}/{commit_id}-m{i}.avro"));
+    let mut write_manifest =
+        |spec: &ResolvedPartitionSpec, entries: &[ManifestEntry]| -> DaftResult<_> {
+            let path = manifest_paths.next().unwrap();
+            let (manifest, bytes) = manifest::write_manifest(
+                &path,
+                schema,
+                &spec.spec,
+                &spec.value_types,
+                ManifestContent::Data,
+                snapshot_id,
+                sequence_number,
+                entries,
+            )?;
+            write_file(&io_runtime, io_client.clone(), &path, bytes)?;
+            Ok(manifest)
+        };
+
+    let mut changes = Changes::default();
+    let mut manifests = vec![];
+    let added = files
+        .iter()
+        .map(|file| file.to_data_file(default_spec, snapshot_id, sequence_number))
+        .collect::<DaftResult<Vec<_>>>()?;
+    if !added.is_empty() {
+        added.iter().for_each(|file| changes.add(file));
+        let entries = added
+            .iter()
+            .map(|file| ManifestEntry {
+                status: EntryStatus::Added,
+                file: file.clone(),
+            })
+            .collect::<Vec<_>>();
+        manifests.push(write_manifest(default_spec, &entries)?);
+    }
+
+    // Partitions are compared by their written form, since values read back from manifests may
+    // use Avro logical types.
+    let normalized = |partition: &[Value], spec: &ResolvedPartitionSpec| -> Vec<Value> {
+        partition
+            .iter()
+            .zip(&spec.value_types)
+            .map(|(value, value_type)| match value_type {
+                Some(value_type) => manifest::normalize_value(value, value_type),
+                None => value.clone(),
+            })
+            .collect()
+    };
+    let written_partitions = added
+        .iter()
+        .map(|file| normalized(&file.partition, default_spec))
+        .collect::<Vec<_>>();
+
+    if let Some(parent) = parent
+        && let Some(manifest_list) = &parent.manifest_list
+    {
+        let bytes = read_file(&io_runtime, io_client.clone(), manifest_list, None)?;
+        for existing in manifest::read_manifest_list(manifest_list, &bytes)? {
+            let replaced = match (mode, existing.content) {
+                (WriteMode::Append, _) => false,
+                // Deletes only apply to the data files they were written for, which are all
+                // replaced here.
+                (WriteMode::Overwrite, ManifestContent::Deletes) => continue,
+                (WriteMode::Overwrite, ManifestContent::Data) => true,
+                (WriteMode::DynamicPartitionOverwrite, ManifestContent::Deletes) => false,
+                // Partitions of other specs can't be matched to the written ones.
+                (WriteMode::DynamicPartitionOverwrite, ManifestContent::Data) => {
+                    existing.partition_spec_id == default_spec_id
+                }
+            };
+            if !replaced {
+                manifests.push(existing);
+                continue;
+            }
+            let spec = specs.get(&existing.partition_spec_id).ok_or_else(|| {
+                DaftError::ValueError(format!(
+                    "Iceberg manifest {} references unknown partition spec {}",
+                    existing.path, existing.partition_spec_id
+                ))
+            })?;
+            let bytes = read_file(&io_runtime, io_client.clone(), &existing.path, None)?;
+            let entries = manifest::read_manifest(&existing, &bytes, &spec.names())?
+                .into_iter()
+                .map(|file| {
+                    let deleted = mode == WriteMode::Overwrite
+                        || written_partitions.contains(&normalized(&file.partition, spec));
+                    let status = if deleted {
+                        changes.delete(&file);
+                        EntryStatus::Deleted
+                    } else {
+                        EntryStatus::Existing
+                    };
+                    ManifestEntry { status, file }
+                })
+                .collect::<Vec<_>>();
+            if entries
+                .iter()
+                .all(|entry| entry.status == EntryStatus::Existing)
+            {
+                manifests.push(existing);
+            } else {
+                manifests.push(write_manifest(spec, &entries)?);
+            }
+        }
+    }
+
+    let parent_snapshot_id = parent.map(|parent| parent.snapshot_id);
+    let manifest_list = format!("{metadata_dir}/snap-{snapshot_id}-1-{commit_id}.avro");
+    let bytes = manifest::write_manifest_list(
+        snapshot_id,
+        parent_snapshot_id,
+        sequence_number,
+        &manifests,
+    )?;
+    write_file(&io_runtime, io_client, &manifest_list, bytes)?;
+
+    let now_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_millis() as i64);
+    Ok(Snapshot {
+        snapshot_id,
+        parent_snapshot_id,
+        sequence_number,
+        timestamp_ms: now_ms.max(base.last_updated_ms),
+        manifest_list: Some(manifest_list),
+        manifests: None,
+        summary: changes.summary(mode, parent),
+        schema_id: Some(schema.schema_id),
+    })
+}
+
+/// Returns a random positive snapshot id that isn't used by the table yet.
+fn new_snapshot_id(base: &TableMetadata) -> i64 {
+    loop {
+        let (high, low) = Uuid::new_v4().as_u64_pair();
+        let id = ((high ^ low) & i64::MAX as u64) as i64;
+        if id != 0 && base.snapshot_by_id(id).is_err() {
+            return id;
+        }
+    }
+}
+
+fn write_file(
+    io_runtime: &RuntimeRef,
+    io_client: Arc<IOClient>,
+    path: &str,
+    bytes: Vec<u8>,
+) -> DaftResult<()> {
+    let (source_type, url) = daft_io::parse_url(path)?;
+    // Object stores have no directories, but local paths need them to exist.
+    if source_type == SourceType::File
+        && let Some(parent) = Path::new(url.trim_start_matches("file://")).parent()
+    {
+        std::fs::create_dir_all(parent)?;
+    }
+    let url = url.to_string();
+    Ok(io_runtime.block_within_async_context(async move {
+        io_client.single_url_put(&url, bytes.into(), None).await
+    })??)
+}
@@ -23,6 +23,7 @@ mod anonymous;
 pub use anonymous::AnonymousScanOperator;
 pub mod glob;
 mod hive;
+pub mod iceberg;
 use common_daft_config::DaftExecutionConfig;
 pub mod builder;
 pub mod scan_task_iters;
@@ -151,6 +151,9 @@ pub enum DataType {
     #[display("FixedShapeSparseTensor[{_0}; {_1:?}; indices_offset: {_2}]")]
     FixedShapeSparseTensor(Box<DataType>, Vec<u64>, bool),
 
+    /// A logical type for geometries, stored as Well-Known Binary (WKB).
+    Geometry,
+
     #[cfg(feature = "python")]
     Python,
 
@@ -270,7 +273,8 @@ impl DataType {
             | Self::Tensor(..)
             | Self::FixedShapeTensor(..)
             | Self::SparseTensor(..)
-            | Self::FixedShapeSparseTensor(..) => {
+            | Self::FixedShapeSparseTensor(..)
+            | Self::Geometry => {
                 let physical = Box::new(self.to_physical());
                 let logical_extension = Self::Extension(
                     DAFT_SUPER_EXTENSION_NAME.into(),
@@ -350,6 +354,7 @@ impl DataType {
                     Field::new("indices", List(Box::new(minimal_indices_dtype)))
                 },
             ]),
+            Geometry => Binary,
             _ => {
                 assert!(self.is_physical());
                 self.clone()
@@ -492,6 +497,11 @@ impl DataType {
         matches!(self, Self::FixedShapeImage(..))
     }
 
+    #[inline]
+    pub fn is_geometry(&self) -> bool {
+        matches!(self, Self::Geometry)
+    }
+
     #[inline]
     pub fn is_map(&self) -> bool {
         matches!(self, Self::Map { .. })
@@ -724,6 +734,7 @@ impl DataType {
                 | Self::FixedShapeTensor(..)
                 | Self::SparseTensor(..)
                 | Self::FixedShapeSparseTensor(..)
+                | Self::Geometry
                 | Self::Map { .. }
         )
     }
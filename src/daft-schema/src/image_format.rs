@@ -17,6 +17,8 @@ pub enum ImageFormat {
     TIFF,
     GIF,
     BMP,
+    WEBP,
+    AVIF,
 }
 
 #[cfg(feature = "python")]
@@ -40,18 +42,35 @@ impl ImageFormat {
 
 impl ImageFormat {
     pub fn iterator() -> std::slice::Iter<'static, Self> {
-        use ImageFormat::{BMP, GIF, JPEG, PNG, TIFF};
+        use ImageFormat::{AVIF, BMP, GIF, JPEG, PNG, TIFF, WEBP};
 
-        static FORMATS: [ImageFormat; 5] = [PNG, JPEG, TIFF, GIF, BMP];
+        static FORMATS: [ImageFormat; 7] = [PNG, JPEG, TIFF, GIF, BMP, WEBP, AVIF];
         FORMATS.iter()
     }
+
+    /// Detects the format of an encoded image from the magic bytes at the start of its data.
+    pub fn from_magic_bytes(bytes: &[u8]) -> Option<Self> {
+        use ImageFormat::{AVIF, BMP, GIF, JPEG, PNG, TIFF, WEBP};
+
+        match bytes {
+            [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n', ..] => Some(PNG),
+            [0xff, 0xd8, 0xff, ..] => Some(JPEG),
+            [b'I', b'I', 0x2a, 0x00, ..] | [b'M', b'M', 0x00, 0x2a, ..] => Some(TIFF),
+            [b'G', b'I', b'F', b'8', b'7' | b'9', b'a', ..] => Some(GIF),
+            [b'B', b'M', ..] => Some(BMP),
+            [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'E', b'B', b'P', ..] => Some(WEBP),
+            // ISO base media file with an `ftyp` box whose major brand is an AVIF brand.
+            [_, _, _, _, b'f', b't', b'y', b'p', b'a', b'v', b'i', b'f' | b's', ..] => Some(AVIF),
+            _ => None,
+        }
+    }
 }
 
 impl FromStr for ImageFormat {
     type Err = DaftError;
 
     fn from_str(format: &str) -> DaftResult<Self> {
-        use ImageFormat::{BMP, GIF, JPEG, PNG, TIFF};
+        use ImageFormat::{AVIF, BMP, GIF, JPEG, PNG, TIFF, WEBP};
 
         match format {
             "PNG" => Ok(PNG),
@@ -59,6 +78,8 @@ impl FromStr for ImageFormat {
             "TIFF" => Ok(TIFF),
             "GIF" => Ok(GIF),
             "BMP" => Ok(BMP),
+            "WEBP" => Ok(WEBP),
+            "AVIF" => Ok(AVIF),
             _ => Err(DaftError::TypeError(format!(
                 "Image format {} is not supported; only the following formats are supported: {:?}",
                 format,
@@ -69,3 +90,36 @@ impl FromStr for ImageFormat {
 }
 
 impl_bincode_py_state_serialization!(ImageFormat);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_magic_bytes() {
+        let test_cases: Vec<(&[u8], Option<ImageFormat>)> = vec![
+            (b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR", Some(ImageFormat::PNG)),
+            (b"\xff\xd8\xff\xe0\0\x10JFIF", Some(ImageFormat::JPEG)),
+            (b"II*\0\x08\0\0\0", Some(ImageFormat::TIFF)),
+            (b"MM\0*\0\0\0\x08", Some(ImageFormat::TIFF)),
+            (b"GIF89a\x01\0", Some(ImageFormat::GIF)),
+            (b"GIF87a\x01\0", Some(ImageFormat::GIF)),
+            (b"BM\x36\0\0\0", Some(ImageFormat::BMP)),
+            (b"RIFF\x24\0\0\0WEBPVP8L", Some(ImageFormat::WEBP)),
+            (b"RIFF\x24\0\0\0WAVEfmt ", None),
+            (b"\0\0\0\x20ftypavif\0\0\0\0", Some(ImageFormat::AVIF)),
+            (b"\0\0\0\x20ftypavis\0\0\0\0", Some(ImageFormat::AVIF)),
+            (b"\0\0\0\x20ftypisom\0\0\0\0", None),
+            (b"not an image", None),
+            (b"", None),
+        ];
+
+        for (bytes, expected) in test_cases {
+            assert_eq!(
+                ImageFormat::from_magic_bytes(bytes),
+                expected,
+                "Failed for bytes: {bytes:?}"
+            );
+        }
+    }
+}
@@ -336,6 +336,11 @@ impl PyDataType {
         }
     }
 
+    #[staticmethod]
+    pub fn geometry() -> PyResult<Self> {
+        Ok(DataType::Geometry.into())
+    }
+
     #[staticmethod]
     pub fn python() -> PyResult<Self> {
         Ok(DataType::Python.into())
@@ -501,6 +506,10 @@ impl PyDataType {
         self.dtype.is_fixed_shape_sparse_tensor()
     }
 
+    pub fn is_geometry(&self) -> bool {
+        self.dtype.is_geometry()
+    }
+
     pub fn is_python(&self) -> bool {
         self.dtype.is_python()
     }
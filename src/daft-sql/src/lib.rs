@@ -33,10 +33,13 @@ mod tests {
 
     use common_error::DaftError;
     use daft_core::prelude::*;
-    use daft_dsl::{lit, unresolved_col, Expr, ExprRef, PlanRef, Subquery, UnresolvedColumn};
+    use daft_dsl::{
+        common_treenode::TreeNode, lit, unresolved_col, Expr, ExprRef, PlanRef, Subquery,
+        UnresolvedColumn,
+    };
     use daft_logical_plan::{
-        logical_plan::Source, source_info::PlaceHolderInfo, ClusteringSpec, JoinOptions,
-        LogicalPlan, LogicalPlanBuilder, LogicalPlanRef, SourceInfo,
+        logical_plan::Source, ops::RecursiveCte, source_info::PlaceHolderInfo, ClusteringSpec,
+        JoinOptions, LogicalPlan, LogicalPlanBuilder, LogicalPlanRef, SourceInfo,
     };
     use daft_session::Session;
     use error::SQLPlannerResult;
@@ -366,6 +369,56 @@ mod tests {
         Ok(())
     }
 
+    #[rstest]
+    fn test_grouping_sets(mut planner: SQLPlanner, tbl_1: LogicalPlanRef) -> SQLPlannerResult<()> {
+        let sql =
+            "select utf8, i32, max(i64) from tbl1 group by grouping sets ((utf8, i32), (utf8), ())";
+        let plan = planner.plan_sql(sql)?;
+
+        let expected = LogicalPlanBuilder::from(tbl_1)
+            .alias("tbl1")
+            .aggregate_grouping_sets(
+                vec![unresolved_col("i64").max()],
+                vec![unresolved_col("utf8"), unresolved_col("i32")],
+                vec![vec![0, 1], vec![0], vec![]],
+            )?
+            .select(vec![
+                unresolved_col("utf8"),
+                unresolved_col("i32"),
+                unresolved_col("i64"),
+            ])?
+            .build();
+
+        assert_eq!(plan, expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::rollup(
+        "select utf8, i32, count(*) from tbl1 group by rollup(utf8, i32)",
+        "select utf8, i32, count(*) from tbl1 group by grouping sets ((utf8, i32), (utf8), ())"
+    )]
+    #[case::composite_rollup(
+        "select utf8, i32, i64, count(*) from tbl1 group by rollup((utf8, i32), i64)",
+        "select utf8, i32, i64, count(*) from tbl1 group by grouping sets ((utf8, i32, i64), (utf8, i32), ())"
+    )]
+    #[case::cube(
+        "select utf8, i32, count(*) from tbl1 group by cube(utf8, i32)",
+        "select utf8, i32, count(*) from tbl1 group by grouping sets ((utf8, i32), (utf8), (i32), ())"
+    )]
+    #[case::mixed(
+        "select utf8, i32, i64, count(*) from tbl1 group by utf8, rollup(i32, i64)",
+        "select utf8, i32, i64, count(*) from tbl1 group by grouping sets ((utf8, i32, i64), (utf8, i32), (utf8))"
+    )]
+    fn test_grouping_set_shorthands(
+        mut planner: SQLPlanner,
+        #[case] query: &str,
+        #[case] equivalent: &str,
+    ) -> SQLPlannerResult<()> {
+        assert_eq!(planner.plan_sql(query)?, planner.plan_sql(equivalent)?);
+        Ok(())
+    }
+
     #[rstest]
     #[case::basic("select utf8 from tbl1 order by utf8")]
     #[case::asc("select utf8 from tbl1 order by utf8 asc")]
@@ -459,6 +512,150 @@ mod tests {
         Ok(())
     }
 
+    #[rstest]
+    #[case::eq_any(
+        "select utf8 from tbl1 where i32 = any (select id from tbl2)",
+        "select utf8 from tbl1 where i32 in (select id from tbl2)"
+    )]
+    #[case::eq_some(
+        "select utf8 from tbl1 where i32 = some (select id from tbl2)",
+        "select utf8 from tbl1 where i32 in (select id from tbl2)"
+    )]
+    #[case::ne_all(
+        "select utf8 from tbl1 where i32 <> all (select id from tbl2)",
+        "select utf8 from tbl1 where i32 not in (select id from tbl2)"
+    )]
+    #[case::in_parenthesized(
+        "select utf8 from tbl1 where i32 in ((select id from tbl2))",
+        "select utf8 from tbl1 where i32 in (select id from tbl2)"
+    )]
+    #[case::parenthesized_query(
+        "select * from ((select i32 from tbl1))",
+        "select * from (select i32 from tbl1)"
+    )]
+    #[case::comma_lateral(
+        "select utf8, val from tbl1, lateral (select val from tbl2 where id = tbl1.i32) t",
+        "select utf8, val from tbl1 cross join lateral (select val from tbl2 where id = tbl1.i32) t"
+    )]
+    fn test_equivalent_subqueries(
+        mut planner: SQLPlanner,
+        #[case] query: &str,
+        #[case] equivalent: &str,
+    ) -> SQLPlannerResult<()> {
+        assert_eq!(planner.plan_sql(query)?, planner.plan_sql(equivalent)?);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::gt_any("select utf8 from tbl1 where i32 > any (select id from tbl2)")]
+    #[case::lt_all("select utf8 from tbl1 where i32 < all (select id from tbl2)")]
+    #[case::eq_all("select utf8 from tbl1 where i32 = all (select id from tbl2)")]
+    #[case::ne_any("select utf8 from tbl1 where i32 <> any (select id from tbl2)")]
+    #[case::correlated_any(
+        "select utf8 from tbl1 where i32 >= any (select id from tbl2 where text = tbl1.utf8)"
+    )]
+    #[case::scalar_in_select(
+        "select utf8, (select count(*) from tbl2 where id = tbl1.i32) from tbl1"
+    )]
+    #[case::scalar_in_having(
+        "select utf8, sum(i32) from tbl1 group by utf8 having sum(i32) > (select avg(val) from tbl2)"
+    )]
+    #[case::union_order_by(
+        "(select i32 from tbl1) union all (select id from tbl2) order by i32 limit 3"
+    )]
+    #[case::left_lateral(
+        "select utf8, c from tbl1 left join lateral (select count(*) as c from tbl2 where id = tbl1.i32) t on true"
+    )]
+    fn test_compiles_subqueries(
+        mut planner: SQLPlanner,
+        #[case] query: &str,
+    ) -> SQLPlannerResult<()> {
+        let plan = planner.plan_sql(query);
+        assert!(plan.is_ok(), "query: {query}\nerror: {plan:?}");
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::union_all(
+        "with recursive t(n) as (select id from tbl2 union all select n + 1 from t where n < 5) select n from t",
+        true
+    )]
+    #[case::union(
+        "with recursive t as (select id from tbl2 union select id + 1 from t where id < 5) select id from t",
+        false
+    )]
+    fn test_recursive_cte(
+        mut planner: SQLPlanner,
+        #[case] query: &str,
+        #[case] expected_is_all: bool,
+    ) -> SQLPlannerResult<()> {
+        let plan = planner.plan_sql(query)?;
+
+        fn find_recursive_cte(plan: &LogicalPlan) -> Option<&RecursiveCte> {
+            match plan {
+                LogicalPlan::RecursiveCte(cte) => Some(cte),
+                _ => plan.children().into_iter().find_map(find_recursive_cte),
+            }
+        }
+        let cte = find_recursive_cte(&plan).expect("expected a RecursiveCte node in the plan");
+        assert_eq!(cte.name.as_ref(), "t");
+        assert_eq!(cte.is_all, expected_is_all);
+        assert_eq!(cte.anchor.schema(), cte.recursive.schema());
+
+        Ok(())
+    }
+
+    #[rstest]
+    fn test_recursive_cte_without_self_reference(mut planner: SQLPlanner) -> SQLPlannerResult<()> {
+        // A `WITH RECURSIVE` whose body never references itself is a plain union.
+        let plan = planner.plan_sql(
+            "with recursive t as (select i32 from tbl1 union all select id from tbl2) select * from t",
+        )?;
+        let has_recursive_cte =
+            plan.exists(|node| matches!(node.as_ref(), LogicalPlan::RecursiveCte(_)));
+        assert!(!has_recursive_cte);
+
+        Ok(())
+    }
+
+    #[rstest]
+    fn test_lateral_join(
+        mut planner: SQLPlanner,
+        tbl_1: LogicalPlanRef,
+        tbl_2: LogicalPlanRef,
+    ) -> SQLPlannerResult<()> {
+        use daft_dsl::{Column, ResolvedColumn};
+
+        let sql = "select utf8, val from tbl1 cross join lateral (select val from tbl2 where id = tbl1.i32) t";
+        let plan = planner.plan_sql(sql)?;
+
+        let outer_col = Arc::new(Expr::Column(Column::Resolved(ResolvedColumn::OuterRef(
+            Field::new("i32", DataType::Int32),
+            PlanRef::Alias("tbl1".into()),
+        ))));
+        let subquery = LogicalPlanBuilder::from(tbl_2)
+            .alias("tbl2")
+            .filter(unresolved_col("id").eq(outer_col))?
+            .select(vec![unresolved_col("val")])?
+            .alias("t");
+
+        let expected = LogicalPlanBuilder::from(tbl_1)
+            .alias("tbl1")
+            .lateral_join(
+                subquery,
+                None,
+                JoinType::Inner,
+                JoinOptions::default().prefix("t."),
+            )?
+            .select(vec![unresolved_col("utf8"), unresolved_col("val")])?
+            .build();
+
+        assert_eq!(plan, expected);
+
+        Ok(())
+    }
+
     #[rstest]
     fn test_multiple_from_with_join(
         mut planner: SQLPlanner,
@@ -27,6 +27,8 @@ impl SQLModule for SQLModuleAggs {
         parent.add_fn("mean", AggExpr::Mean(nil.clone()));
         parent.add_fn("min", AggExpr::Min(nil.clone()));
         parent.add_fn("max", AggExpr::Max(nil.clone()));
+        parent.add_fn("min_by", AggExpr::MinBy(nil.clone(), nil.clone()));
+        parent.add_fn("max_by", AggExpr::MaxBy(nil.clone(), nil.clone()));
         parent.add_fn("bool_and", AggExpr::BoolAnd(nil.clone()));
         parent.add_fn("bool_or", AggExpr::BoolOr(nil.clone()));
         parent.add_fn("stddev", AggExpr::Stddev(nil.clone()));
@@ -53,6 +55,8 @@ impl SQLFunction for AggExpr {
             Self::Mean(_) => static_docs::AVG_DOCSTRING.replace("{}", alias),
             Self::Min(_) => static_docs::MIN_DOCSTRING.to_string(),
             Self::Max(_) => static_docs::MAX_DOCSTRING.to_string(),
+            Self::MinBy(..) => static_docs::MIN_BY_DOCSTRING.to_string(),
+            Self::MaxBy(..) => static_docs::MAX_BY_DOCSTRING.to_string(),
             Self::Stddev(_) => static_docs::STDDEV_DOCSTRING.to_string(),
             Self::BoolAnd(_) => static_docs::BOOL_AND_DOCSTRING.to_string(),
             Self::BoolOr(_) => static_docs::BOOL_OR_DOCSTRING.to_string(),
@@ -71,6 +75,7 @@ impl SQLFunction for AggExpr {
             | Self::Stddev(_)
             | Self::BoolAnd(_)
             | Self::BoolOr(_) => &["input"],
+            Self::MinBy(..) | Self::MaxBy(..) => &["input", "by"],
             e => unimplemented!("Need to implement arg names for {e}"),
         }
     }
@@ -162,6 +167,14 @@ fn to_expr(expr: &AggExpr, args: &[ExprRef]) -> SQLPlannerResult<ExprRef> {
             ensure!(args.len() == 1, "max takes exactly one argument");
             Ok(args[0].clone().max())
         }
+        AggExpr::MinBy(..) => {
+            ensure!(args.len() == 2, "min_by takes exactly two arguments");
+            Ok(args[0].clone().min_by(args[1].clone()))
+        }
+        AggExpr::MaxBy(..) => {
+            ensure!(args.len() == 2, "max_by takes exactly two arguments");
+            Ok(args[0].clone().max_by(args[1].clone()))
+        }
         AggExpr::BoolAnd(_) => {
             ensure!(args.len() == 1, "bool_and takes exactly one argument");
             Ok(args[0].clone().bool_and())
@@ -416,6 +429,82 @@ Example:
     ╰───────╯
     (Showing first 1 of 1 rows)";
 
+    pub(crate) const MIN_BY_DOCSTRING: &str =
+        "Finds the value of the input expression at the minimum non-null value of the `by` expression.
+
+Example:
+
+.. code-block:: sql
+    :caption: SQL
+
+    SELECT min_by(x, y) FROM tbl
+
+.. code-block:: text
+    :caption: Input
+
+    ╭───────┬───────╮
+    │ x     ┆ y     │
+    │ ---   ┆ ---   │
+    │ Utf8  ┆ Int64 │
+    ╞═══════╪═══════╡
+    │ a     ┆ 100   │
+    ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
+    │ b     ┆ 200   │
+    ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
+    │ c     ┆ null  │
+    ╰───────┴───────╯
+    (Showing first 3 of 3 rows)
+
+.. code-block:: text
+    :caption: Output
+
+    ╭───────╮
+    │ x     │
+    │ ---   │
+    │ Utf8  │
+    ╞═══════╡
+    │ a     │
+    ╰───────╯
+    (Showing first 1 of 1 rows)";
+
+    pub(crate) const MAX_BY_DOCSTRING: &str =
+        "Finds the value of the input expression at the maximum non-null value of the `by` expression.
+
+Example:
+
+.. code-block:: sql
+    :caption: SQL
+
+    SELECT max_by(x, y) FROM tbl
+
+.. code-block:: text
+    :caption: Input
+
+    ╭───────┬───────╮
+    │ x     ┆ y     │
+    │ ---   ┆ ---   │
+    │ Utf8  ┆ Int64 │
+    ╞═══════╪═══════╡
+    │ a     ┆ 100   │
+    ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
+    │ b     ┆ 200   │
+    ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
+    │ c     ┆ null  │
+    ╰───────┴───────╯
+    (Showing first 3 of 3 rows)
+
+.. code-block:: text
+    :caption: Output
+
+    ╭───────╮
+    │ x     │
+    │ ---   │
+    │ Utf8  │
+    ╞═══════╡
+    │ b     │
+    ╰───────╯
+    (Showing first 1 of 1 rows)";
+
     pub(crate) const STDDEV_DOCSTRING: &str =
         "Calculates the standard deviation of non-null elements in the input expression.
 
@@ -10,14 +10,18 @@ use common_error::{DaftError, DaftResult};
 use daft_catalog::Identifier;
 use daft_core::prelude::*;
 use daft_dsl::{
+    binary_op,
     functions::{ScalarFunction, ScalarUDF},
     has_agg, lit, literals_to_series, null_lit, resolved_col, unresolved_col, Column, Expr,
     ExprRef, LiteralValue, Operator, PlanRef, Subquery, UnresolvedColumn,
 };
-use daft_functions::numeric::{ceil::ceil, floor::floor};
+use daft_functions::{
+    grouping::contains_grouping,
+    numeric::{ceil::ceil, floor::floor},
+};
 use daft_functions_utf8::{ilike, like, to_date, to_datetime};
 use daft_logical_plan::{
-    ops::{SetQuantifier, UnionStrategy},
+    ops::{cube_grouping_sets, rollup_grouping_sets, SetQuantifier, UnionStrategy},
     JoinOptions, LogicalPlanBuilder, LogicalPlanRef,
 };
 use daft_session::Session;
@@ -30,8 +34,9 @@ use sqlparser::{
         Value, WildcardAdditionalOptions, With,
     },
     dialect::GenericDialect,
+    keywords::Keyword,
     parser::{Parser, ParserOptions},
-    tokenizer::Tokenizer,
+    tokenizer::{Token, Tokenizer, Whitespace},
 };
 
 use crate::{
@@ -233,22 +238,21 @@ impl SQLPlanner<'_> {
     }
 
     fn plan_ctes(&self, with: &With) -> SQLPlannerResult<()> {
-        if with.recursive {
-            unsupported_sql_err!("Recursive CTEs are not supported");
-        }
-
         for cte in &with.cte_tables {
             if cte.materialized.is_some() {
                 unsupported_sql_err!("MATERIALIZED is not supported");
             }
 
             if cte.from.is_some() {
-                invalid_operation_err!("FROM should only exist in recursive CTEs");
+                unsupported_sql_err!("FROM in CTEs is not supported");
             }
 
-            let plan = self.new_with_context().plan_query(&cte.query)?;
-
-            let plan = apply_table_alias(plan, &cte.alias)?;
+            let plan = if with.recursive {
+                self.plan_recursive_cte(cte)?
+            } else {
+                let plan = self.new_with_context().plan_query(&cte.query)?;
+                apply_table_alias(plan, &cte.alias)?
+            };
             self.context_mut()
                 .bound_ctes
                 .insert(cte.alias.name.value.clone(), plan);
@@ -256,8 +260,62 @@ impl SQLPlanner<'_> {
         Ok(())
     }
 
+    /// Plans a CTE of a `WITH RECURSIVE` clause.
+    ///
+    /// A recursive CTE is of the form `<anchor> UNION [ALL] <recursive term>`, where only the
+    /// recursive term may reference the CTE itself. While planning the recursive term, the CTE name
+    /// is bound to the working table, i.e. the rows produced by the previous iteration.
+    fn plan_recursive_cte(&self, cte: &ast::Cte) -> SQLPlannerResult<LogicalPlanBuilder> {
+        use sqlparser::ast::{SetOperator, SetQuantifier as SQLSetQuantifier};
+
+        let query = &cte.query;
+        let (
+            SetExpr::SetOperation {
+                op: SetOperator::Union,
+                set_quantifier,
+                left,
+                right,
+            },
+            None,
+            None,
+        ) = (query.body.as_ref(), &query.order_by, &query.limit)
+        else {
+            // not of the recursive form, so it cannot reference itself
+            let plan = self.new_with_context().plan_query(query)?;
+            return apply_table_alias(plan, &cte.alias);
+        };
+        check_query_features(query)?;
+
+        let is_all = match set_quantifier {
+            SQLSetQuantifier::All => true,
+            SQLSetQuantifier::None | SQLSetQuantifier::Distinct => false,
+            set_quantifier => {
+                unsupported_sql_err!("UNION {set_quantifier} in recursive CTEs is not supported")
+            }
+        };
+
+        let name = cte.alias.name.value.clone();
+        let mut left = set_expr_to_query(left);
+        left.with.clone_from(&query.with);
+        let anchor = self.new_with_context().plan_query(&left)?;
+        let anchor = apply_table_alias(anchor, &cte.alias)?;
+
+        let working_table = LogicalPlanBuilder::recursive_cte_working_table(&name, anchor.schema());
+        self.context_mut()
+            .bound_ctes
+            .insert(name.clone(), working_table.clone());
+        let recursive = self
+            .new_with_context()
+            .plan_query(&set_expr_to_query(right))?;
+
+        Ok(anchor
+            .recursive_cte(name.clone(), &working_table, &recursive, is_all)?
+            .alias(name))
+    }
+
     pub fn plan(&mut self, input: &str) -> SQLPlannerResult<Statement> {
         let tokens = Tokenizer::new(&GenericDialect {}, input).tokenize()?;
+        let tokens = rewrite_subquery_tokens(tokens);
 
         let mut parser = Parser::new(&GenericDialect {})
             .with_options(ParserOptions {
@@ -293,9 +351,18 @@ impl SQLPlanner<'_> {
     pub(crate) fn plan_query(&mut self, query: &Query) -> SQLPlannerResult<LogicalPlanBuilder> {
         check_query_features(query)?;
 
+        if let Some(with) = &query.with {
+            self.plan_ctes(with)?;
+        }
+
         let selection = match query.body.as_ref() {
             SetExpr::Select(selection) => selection,
-            SetExpr::Query(_) => unsupported_sql_err!("Subqueries are not supported"),
+            SetExpr::Query(subquery) => {
+                // a parenthesized query keeps the current scope so it can still be correlated
+                let plan = self.plan_query(subquery)?;
+                self.current_plan = Some(plan);
+                return self.plan_set_expr_modifiers(query);
+            }
             SetExpr::SetOperation {
                 op,
                 set_quantifier,
@@ -306,26 +373,14 @@ impl SQLPlanner<'_> {
                     SetOperator::{Intersect, Union},
                     SetQuantifier as SQLSetQuantifier,
                 };
-                fn make_query(expr: &SetExpr) -> Query {
-                    Query {
-                        with: None,
-                        body: Box::new(expr.clone()),
-                        order_by: None,
-                        limit: None,
-                        limit_by: vec![],
-                        offset: None,
-                        fetch: None,
-                        locks: vec![],
-                        for_clause: None,
-                        settings: None,
-                        format_clause: None,
-                    }
-                }
-
-                let left = self.new_with_context().plan_query(&make_query(left))?;
-                let right = self.new_with_context().plan_query(&make_query(right))?;
-
-                return match (op, set_quantifier) {
+                let left = self
+                    .new_with_context()
+                    .plan_query(&set_expr_to_query(left))?;
+                let right = self
+                    .new_with_context()
+                    .plan_query(&set_expr_to_query(right))?;
+
+                let plan: SQLPlannerResult<_> = match (op, set_quantifier) {
                     (Union, set_quantifier) => {
                         let (set_quantifier, strategy) = match set_quantifier {
                             SQLSetQuantifier::All => {
@@ -355,6 +410,8 @@ impl SQLPlanner<'_> {
                         unsupported_sql_err!("{op} {set_quantifier} is not supported.")
                     }
                 };
+                self.current_plan = Some(plan?);
+                return self.plan_set_expr_modifiers(query);
             }
             SetExpr::Values(..) => unsupported_sql_err!("VALUES are not supported"),
             SetExpr::Insert(..) => unsupported_sql_err!("INSERT is not supported"),
@@ -363,10 +420,6 @@ impl SQLPlanner<'_> {
         };
         check_select_features(selection)?;
 
-        if let Some(with) = &query.with {
-            self.plan_ctes(with)?;
-        }
-
         // FROM/JOIN
         let from = selection.clone().from;
         self.plan_from(&from)?;
@@ -387,7 +440,7 @@ impl SQLPlanner<'_> {
 
         // GROUP BY
         let mut groupby_exprs = Vec::new();
-        let mut rollup = false;
+        let mut grouping_sets = None;
 
         match &selection.group_by {
             GroupByExpr::All(s) => {
@@ -396,23 +449,7 @@ impl SQLPlanner<'_> {
                 }
             }
             GroupByExpr::Expressions(expressions, _) => {
-                groupby_exprs = match expressions.as_slice() {
-                    [sqlparser::ast::Expr::Rollup(exprs)] => {
-                        rollup = true;
-                        let mut out = Vec::new();
-                        for expr_list in exprs {
-                            if expr_list.len() > 1 {
-                                unsupported_sql_err!("nested ROLLUP's are not supported");
-                            }
-                            out.push(self.plan_expr(&expr_list[0])?);
-                        }
-                        out
-                    }
-                    exprs => exprs
-                        .iter()
-                        .map(|expr| self.plan_expr(expr))
-                        .collect::<SQLPlannerResult<Vec<_>>>()?,
-                };
+                (groupby_exprs, grouping_sets) = self.plan_group_by(expressions)?;
             }
         }
 
@@ -429,7 +466,7 @@ impl SQLPlanner<'_> {
             })
             .transpose()?;
 
-        let has_aggs = projections.iter().any(has_agg) || !groupby_exprs.is_empty();
+        let has_aggs = projections.iter().any(needs_agg) || !groupby_exprs.is_empty();
 
         if has_aggs {
             let having = selection
@@ -438,7 +475,7 @@ impl SQLPlanner<'_> {
                 .map(|h| self.plan_expr(h))
                 .transpose()?;
 
-            self.plan_aggregate_query(projections, order_by, groupby_exprs, having, rollup)?;
+            self.plan_aggregate_query(projections, order_by, groupby_exprs, grouping_sets, having)?;
         } else {
             self.plan_non_agg_query(projections, order_by)?;
         }
@@ -457,6 +494,32 @@ impl SQLPlanner<'_> {
             None => {}
         }
 
+        self.plan_limit(query)?;
+
+        Ok(self.current_plan.clone().unwrap())
+    }
+
+    /// Applies the ORDER BY and LIMIT of a query whose body is a set operation or a parenthesized query.
+    fn plan_set_expr_modifiers(&mut self, query: &Query) -> SQLPlannerResult<LogicalPlanBuilder> {
+        if let Some(order_by) = &query.order_by {
+            if order_by.interpolate.is_some() {
+                unsupported_sql_err!("ORDER BY [query] [INTERPOLATE]");
+            }
+
+            let OrderByExprs {
+                exprs,
+                descending,
+                nulls_first,
+            } = self.plan_order_by_exprs(&order_by.exprs)?;
+            self.update_plan(|plan| plan.sort(exprs, descending, nulls_first))?;
+        }
+
+        self.plan_limit(query)?;
+
+        Ok(self.current_plan.clone().unwrap())
+    }
+
+    fn plan_limit(&mut self, query: &Query) -> SQLPlannerResult<()> {
         if let Some(limit) = &query.limit {
             let limit_expr = self.plan_expr(limit)?;
             match limit_expr.as_ref() {
@@ -473,7 +536,7 @@ impl SQLPlanner<'_> {
             }
         }
 
-        Ok(self.current_plan.clone().unwrap())
+        Ok(())
     }
 
     fn plan_non_agg_query(
@@ -501,8 +564,8 @@ impl SQLPlanner<'_> {
         projections: Vec<Arc<Expr>>,
         order_by: Option<OrderByExprs>,
         groupby_exprs: Vec<Arc<Expr>>,
+        grouping_sets: Option<Vec<Vec<usize>>>,
         having: Option<Arc<Expr>>,
-        rollup: bool,
     ) -> Result<(), PlannerError> {
         let mut aggs = HashSet::new();
 
@@ -511,7 +574,7 @@ impl SQLPlanner<'_> {
         let projections = projections
             .into_iter()
             .map(|expr| {
-                if has_agg(&expr) {
+                if needs_agg(&expr) {
                     aggs.insert(expr.clone());
                     resolved_col(expr.name())
                 // if the projection is the same as one in a groupby, we don't need to reevaluate it again
@@ -533,7 +596,7 @@ impl SQLPlanner<'_> {
 
         let having = having.map(|expr| {
             // ensure that all aggs required for having filter are present
-            if has_agg(&expr) {
+            if needs_agg(&expr) {
                 let id = expr.semantic_id(&schema).id;
 
                 aggs.insert(expr.alias(id.clone()));
@@ -555,7 +618,7 @@ impl SQLPlanner<'_> {
                 let updated_exprs = exprs
                     .into_iter()
                     .map(|e| {
-                        if has_agg(&e) {
+                        if needs_agg(&e) {
                             let id = e.semantic_id(&schema).id;
 
                             aggs.insert(e.alias(id.clone()));
@@ -575,43 +638,12 @@ impl SQLPlanner<'_> {
             },
         );
 
-        if rollup {
-            let mut plans = Vec::new();
-            for i in (0..=groupby_exprs.len()).rev() {
-                let current_groups = groupby_exprs[0..i].to_vec();
-
-                let next_agg = self
-                    .current_plan_ref()
-                    .clone()
-                    .aggregate(aggs.clone().into_iter().collect(), current_groups.clone())?;
-                plans.push(next_agg);
-            }
-            let mut plans = plans.into_iter();
-            let first = plans.next().unwrap();
-            let first_schema = first.schema();
-            self.update_plan(|_| SQLPlannerResult::Ok(first))?;
-
-            for next in plans {
-                let next_schema = next.schema();
-                let nulled_cols = first_schema
-                    .into_iter()
-                    .map(|f| {
-                        if next_schema.has_field(&f.name) {
-                            unresolved_col(f.name.clone())
-                        } else {
-                            null_lit().alias(f.name.clone()).cast(&f.dtype)
-                        }
-                    })
-                    .collect::<Vec<_>>();
-
-                let next = next.select(nulled_cols)?;
-
-                self.update_plan(|plan| {
-                    plan.union(&next, SetQuantifier::All, UnionStrategy::Positional)
-                })?;
-            }
-        } else {
-            self.update_plan(|plan| plan.aggregate(aggs.into_iter().collect(), groupby_exprs))?;
+        let aggs = aggs.into_iter().collect();
+        match grouping_sets {
+            Some(grouping_sets) => self.update_plan(|plan| {
+                plan.aggregate_grouping_sets(aggs, groupby_exprs, grouping_sets)
+            })?,
+            None => self.update_plan(|plan| plan.aggregate(aggs, groupby_exprs))?,
         }
 
         if let Some(having) = having {
@@ -631,6 +663,90 @@ impl SQLPlanner<'_> {
         Ok(())
     }
 
+    /// Plans the GROUP BY expressions, along with the grouping sets over them (as indices into the
+    /// expressions) when the clause contains GROUPING SETS, ROLLUP or CUBE.
+    ///
+    /// Several items are combined by taking the cross product of their grouping sets, so that
+    /// `GROUP BY a, ROLLUP(b, c)` is `GROUPING SETS ((a, b, c), (a, b), (a))`.
+    #[allow(clippy::type_complexity)]
+    fn plan_group_by(
+        &self,
+        exprs: &[sqlparser::ast::Expr],
+    ) -> SQLPlannerResult<(Vec<ExprRef>, Option<Vec<Vec<usize>>>)> {
+        use sqlparser::ast::Expr as SQLExpr;
+
+        let mut groupby_exprs = Vec::new();
+        let mut has_grouping_sets = false;
+        let mut grouping_sets = vec![vec![]];
+
+        for expr in exprs {
+            let item_sets = match expr {
+                SQLExpr::GroupingSets(sets) => {
+                    has_grouping_sets = true;
+                    sets.iter()
+                        .map(|set| self.plan_grouping_set(set, &mut groupby_exprs))
+                        .collect::<SQLPlannerResult<Vec<_>>>()?
+                }
+                SQLExpr::Rollup(elements) | SQLExpr::Cube(elements) => {
+                    has_grouping_sets = true;
+                    let elements = elements
+                        .iter()
+                        .map(|element| self.plan_grouping_set(element, &mut groupby_exprs))
+                        .collect::<SQLPlannerResult<Vec<_>>>()?;
+                    let sets = if matches!(expr, SQLExpr::Rollup(_)) {
+                        rollup_grouping_sets(elements.len())
+                    } else {
+                        cube_grouping_sets(elements.len())
+                    };
+                    sets.into_iter()
+                        .map(|set| set.iter().flat_map(|i| elements[*i].clone()).collect())
+                        .collect()
+                }
+                expr => {
+                    vec![self.plan_grouping_set(std::slice::from_ref(expr), &mut groupby_exprs)?]
+                }
+            };
+            grouping_sets = grouping_sets
+                .iter()
+                .cartesian_product(&item_sets)
+                .map(|(set, item_set): (&Vec<usize>, &Vec<usize>)| {
+                    set.iter().chain(item_set).copied().unique().collect()
+                })
+                .collect();
+        }
+
+        Ok((groupby_exprs, has_grouping_sets.then_some(grouping_sets)))
+    }
+
+    /// Plans the expressions of a grouping set, returning their indices in `groupby_exprs`.
+    fn plan_grouping_set(
+        &self,
+        exprs: &[sqlparser::ast::Expr],
+        groupby_exprs: &mut Vec<ExprRef>,
+    ) -> SQLPlannerResult<Vec<usize>> {
+        use sqlparser::ast::Expr as SQLExpr;
+
+        exprs
+            .iter()
+            .map(|expr| {
+                if matches!(
+                    expr,
+                    SQLExpr::GroupingSets(_) | SQLExpr::Rollup(_) | SQLExpr::Cube(_)
+                ) {
+                    unsupported_sql_err!("nested GROUPING SETS, ROLLUP or CUBE");
+                }
+                let expr = self.plan_expr(expr)?;
+                Ok(match groupby_exprs.iter().position(|e| *e == expr) {
+                    Some(i) => i,
+                    None => {
+                        groupby_exprs.push(expr);
+                        groupby_exprs.len() - 1
+                    }
+                })
+            })
+            .collect()
+    }
+
     fn plan_order_by_exprs(
         &self,
         expr: &[sqlparser::ast::OrderByExpr],
@@ -709,16 +825,13 @@ impl SQLPlanner<'_> {
         for join in &from.joins {
             use sqlparser::ast::{
                 JoinConstraint,
-                JoinOperator::{FullOuter, Inner, LeftAnti, LeftOuter, LeftSemi, RightOuter},
+                JoinOperator::{
+                    CrossApply, CrossJoin, FullOuter, Inner, LeftAnti, LeftOuter, LeftSemi,
+                    OuterApply, RightOuter,
+                },
+                TableFactor,
             };
 
-            let right_plan = self.plan_relation(&join.relation)?;
-
-            let mut join_options = JoinOptions::default();
-            if let [id] = right_plan.plan.clone().get_aliases().as_slice() {
-                join_options = join_options.prefix(format!("{id}."));
-            }
-
             let (join_type, constraint) = match &join.join_operator {
                 Inner(constraint) => (JoinType::Inner, constraint),
                 LeftOuter(constraint) => (JoinType::Left, constraint),
@@ -726,10 +839,27 @@ impl SQLPlanner<'_> {
                 FullOuter(constraint) => (JoinType::Outer, constraint),
                 LeftSemi(constraint) => (JoinType::Semi, constraint),
                 LeftAnti(constraint) => (JoinType::Anti, constraint),
+                CrossJoin | CrossApply => (JoinType::Inner, &JoinConstraint::None),
+                OuterApply => (JoinType::Left, &JoinConstraint::None),
 
                 _ => unsupported_sql_err!("Unsupported join type: {:?}", join.join_operator),
             };
 
+            let is_cross = matches!(join.join_operator, CrossJoin | CrossApply | OuterApply);
+            let is_lateral = matches!(join.join_operator, CrossApply | OuterApply)
+                || matches!(join.relation, TableFactor::Derived { lateral: true, .. });
+
+            let right_plan = if is_lateral {
+                left_planner.plan_lateral_relation(&join.relation)?
+            } else {
+                self.plan_relation(&join.relation)?
+            };
+
+            let mut join_options = JoinOptions::default();
+            if let [id] = right_plan.plan.clone().get_aliases().as_slice() {
+                join_options = join_options.prefix(format!("{id}."));
+            }
+
             let (on, using) = match &constraint {
                 JoinConstraint::On(expr) => {
                     left_planner.right_side_plan = Some(right_plan.clone());
@@ -744,9 +874,21 @@ impl SQLPlanner<'_> {
                     (None, using)
                 }
                 JoinConstraint::Natural => unsupported_sql_err!("NATURAL JOIN not supported"),
+                JoinConstraint::None if is_cross => (None, vec![]),
                 JoinConstraint::None => unsupported_sql_err!("JOIN without ON/USING not supported"),
             };
 
+            if is_lateral {
+                if !using.is_empty() {
+                    unsupported_sql_err!("LATERAL JOIN with USING");
+                }
+
+                left_planner.update_plan(|plan| {
+                    plan.lateral_join(right_plan, on, join_type, join_options)
+                })?;
+                continue;
+            }
+
             let left_schema = left_planner.current_plan_ref().schema();
 
             left_planner.update_plan(|plan| {
@@ -771,6 +913,27 @@ impl SQLPlanner<'_> {
         Ok(left_planner.current_plan.unwrap())
     }
 
+    /// Plans the subquery of a lateral join, which may reference the columns of the current plan.
+    fn plan_lateral_relation(
+        &self,
+        rel: &sqlparser::ast::TableFactor,
+    ) -> SQLPlannerResult<LogicalPlanBuilder> {
+        let sqlparser::ast::TableFactor::Derived {
+            subquery, alias, ..
+        } = rel
+        else {
+            return self.plan_relation(rel);
+        };
+
+        let mut child_planner = self.new_child();
+        let plan = child_planner.plan_query(subquery)?;
+        if let Some(alias) = alias {
+            apply_table_alias(plan, alias)
+        } else {
+            Ok(plan)
+        }
+    }
+
     /// Plans the FROM clause of a query and populates `self.current_relation`.
     /// Should only be called once per query.
     fn plan_from(&mut self, from: &[TableWithJoins]) -> SQLPlannerResult<()> {
@@ -835,7 +998,7 @@ impl SQLPlanner<'_> {
                 alias,
             } => {
                 if *lateral {
-                    unsupported_sql_err!("LATERAL");
+                    unsupported_sql_err!("LATERAL must be the right side of a join");
                 }
                 let subquery = self.new_with_context().plan_query(subquery)?;
                 (subquery, alias)
@@ -1222,6 +1385,15 @@ impl SQLPlanner<'_> {
                 list,
                 negated,
             } => {
+                // `x IN ((SELECT ...))` is parsed as a list containing a parenthesized subquery
+                if let [SQLExpr::Subquery(subquery)] = list.as_slice() {
+                    return self.plan_expr(&SQLExpr::InSubquery {
+                        expr: expr.clone(),
+                        subquery: subquery.clone(),
+                        negated: *negated,
+                    });
+                }
+
                 let expr = self.plan_expr(expr)?;
                 let list = list
                     .iter()
@@ -1306,8 +1478,16 @@ impl SQLPlanner<'_> {
             }
             SQLExpr::SimilarTo { .. } => unsupported_sql_err!("SIMILAR TO"),
             SQLExpr::RLike { .. } => unsupported_sql_err!("RLIKE"),
-            SQLExpr::AnyOp { .. } => unsupported_sql_err!("ANY"),
-            SQLExpr::AllOp { .. } => unsupported_sql_err!("ALL"),
+            SQLExpr::AnyOp {
+                left,
+                compare_op,
+                right,
+            } => self.plan_quantified_comparison(left, compare_op, right, false),
+            SQLExpr::AllOp {
+                left,
+                compare_op,
+                right,
+            } => self.plan_quantified_comparison(left, compare_op, right, true),
             SQLExpr::Convert { .. } => unsupported_sql_err!("CONVERT"),
             SQLExpr::Cast { .. } => unsupported_sql_err!("CAST"),
             SQLExpr::AtTimeZone { .. } => unsupported_sql_err!("AT TIME ZONE"),
@@ -1695,6 +1875,79 @@ impl SQLPlanner<'_> {
         }
     }
 
+    /// Plans `<expr> <op> ANY (<subquery>)` and `<expr> <op> ALL (<subquery>)`.
+    ///
+    /// `= ANY` and `<> ALL` are planned as `IN` and `NOT IN`, the other comparisons compare
+    /// against the minimum and maximum of the subquery, which are then decorrelated like any
+    /// other scalar subquery.
+    fn plan_quantified_comparison(
+        &self,
+        left: &sqlparser::ast::Expr,
+        op: &BinaryOperator,
+        right: &sqlparser::ast::Expr,
+        all: bool,
+    ) -> SQLPlannerResult<ExprRef> {
+        let quantifier = if all { "ALL" } else { "ANY" };
+        let sqlparser::ast::Expr::Subquery(subquery) = right else {
+            unsupported_sql_err!("{quantifier} is only supported with a subquery");
+        };
+
+        let expr = self.plan_expr(left)?;
+        let mut child_planner = self.new_child();
+        let subquery = child_planner.plan_query(subquery)?;
+
+        let names = subquery.schema().names();
+        let [name] = names.as_slice() else {
+            invalid_operation_err!(
+                "{quantifier} subquery must return exactly one column, found {}",
+                names.len()
+            );
+        };
+        let column = unresolved_col(name.as_str());
+
+        let aggregate = |agg: ExprRef| -> SQLPlannerResult<ExprRef> {
+            let plan = subquery.aggregate(vec![agg], vec![])?.build();
+            Ok(Expr::Subquery(Subquery { plan }).arced())
+        };
+        let is_empty = || -> SQLPlannerResult<ExprRef> {
+            Ok(aggregate(column.clone().count(CountMode::All))?.eq(lit(0u64)))
+        };
+        let min = || aggregate(column.clone().min());
+        let max = || aggregate(column.clone().max());
+        let compare = |bound: ExprRef| -> SQLPlannerResult<ExprRef> {
+            Ok(binary_op(
+                self.sql_operator_to_operator(op)?,
+                expr.clone(),
+                bound,
+            ))
+        };
+
+        match (op, all) {
+            (BinaryOperator::Eq, false) => Ok(expr.in_subquery(Subquery {
+                plan: subquery.build(),
+            })),
+            (BinaryOperator::NotEq, true) => Ok(expr
+                .in_subquery(Subquery {
+                    plan: subquery.build(),
+                })
+                .not()),
+            (BinaryOperator::NotEq, false) => Ok(compare(min()?)?.or(compare(max()?)?)),
+            (BinaryOperator::Lt | BinaryOperator::LtEq, false) => compare(max()?),
+            (BinaryOperator::Gt | BinaryOperator::GtEq, false) => compare(min()?),
+            // ALL is vacuously true for an empty subquery
+            (BinaryOperator::Eq, true) => {
+                Ok(is_empty()?.or(compare(min()?)?.and(compare(max()?)?)))
+            }
+            (BinaryOperator::Lt | BinaryOperator::LtEq, true) => {
+                Ok(is_empty()?.or(compare(min()?)?))
+            }
+            (BinaryOperator::Gt | BinaryOperator::GtEq, true) => {
+                Ok(is_empty()?.or(compare(max()?)?))
+            }
+            _ => unsupported_sql_err!("{op} {quantifier}"),
+        }
+    }
+
     fn sql_operator_to_operator(&self, op: &BinaryOperator) -> SQLPlannerResult<Operator> {
         match op {
             BinaryOperator::Plus => Ok(Operator::Plus),
@@ -1799,6 +2052,110 @@ impl SQLPlanner<'_> {
 /// Checks if the SQL query is valid syntax and doesn't use unsupported features.
 /// /// This function examines various clauses and options in the provided [sqlparser::ast::Query]
 /// and returns an error if any unsupported features are encountered.
+/// Returns true if the expression has to be computed by the aggregation, which is the case for
+/// aggregations and `grouping()` calls.
+fn needs_agg(expr: &ExprRef) -> bool {
+    has_agg(expr) || contains_grouping(expr)
+}
+
+/// Rewrites token sequences that the parser does not accept into equivalent ones that it does.
+///
+/// - `<op> ANY (SELECT ...)`, `<op> SOME (SELECT ...)` and `<op> ALL (SELECT ...)` only parse with
+///   an expression operand, so the subquery is wrapped in another set of parentheses.
+/// - `FROM a, LATERAL (...)` is mistaken for a trailing comma, so it is rewritten to
+///   `FROM a CROSS JOIN LATERAL (...)`.
+fn rewrite_subquery_tokens(tokens: Vec<Token>) -> Vec<Token> {
+    fn is_keyword(token: &Token, keyword: Keyword) -> bool {
+        matches!(token, Token::Word(w) if w.keyword == keyword)
+    }
+
+    fn next_non_whitespace(tokens: &[Token], from: usize) -> Option<usize> {
+        (from..tokens.len()).find(|&i| !matches!(tokens[i], Token::Whitespace(_)))
+    }
+
+    let mut rewritten = Vec::with_capacity(tokens.len());
+    // positions in `tokens` of the closing parentheses of wrapped subqueries
+    let mut closing_parens = Vec::new();
+
+    for (i, token) in tokens.iter().enumerate() {
+        if closing_parens.last() == Some(&i) {
+            closing_parens.pop();
+            rewritten.push(Token::RParen);
+        }
+
+        match token {
+            Token::Word(w)
+                if matches!(w.keyword, Keyword::ANY | Keyword::SOME | Keyword::ALL)
+                    && matches!(
+                        rewritten
+                            .iter()
+                            .rev()
+                            .find(|t| !matches!(t, Token::Whitespace(_))),
+                        Some(
+                            Token::Eq
+                                | Token::DoubleEq
+                                | Token::Neq
+                                | Token::Lt
+                                | Token::Gt
+                                | Token::LtEq
+                                | Token::GtEq
+                        )
+                    ) =>
+            {
+                let lparen = next_non_whitespace(&tokens, i + 1);
+                let query = lparen.and_then(|l| next_non_whitespace(&tokens, l + 1));
+
+                if let (Some(lparen), Some(query)) = (lparen, query)
+                    && tokens[lparen] == Token::LParen
+                    && (is_keyword(&tokens[query], Keyword::SELECT)
+                        || is_keyword(&tokens[query], Keyword::WITH))
+                {
+                    let mut depth = 0;
+                    let rparen = (lparen..tokens.len()).find(|&j| {
+                        match &tokens[j] {
+                            Token::LParen => depth += 1,
+                            Token::RParen => depth -= 1,
+                            _ => {}
+                        }
+                        depth == 0
+                    });
+
+                    if let Some(rparen) = rparen {
+                        closing_parens.push(rparen);
+                        rewritten.push(if w.keyword == Keyword::ALL {
+                            token.clone()
+                        } else {
+                            Token::make_keyword("ANY")
+                        });
+                        // the opening parenthesis is pushed when the loop reaches `lparen`
+                        rewritten.push(Token::Whitespace(Whitespace::Space));
+                        rewritten.push(Token::LParen);
+                        continue;
+                    }
+                }
+                rewritten.push(token.clone());
+            }
+            Token::Comma => {
+                let lateral = next_non_whitespace(&tokens, i + 1)
+                    .filter(|&l| is_keyword(&tokens[l], Keyword::LATERAL));
+                let lparen = lateral.and_then(|l| next_non_whitespace(&tokens, l + 1));
+
+                if lparen.is_some_and(|l| tokens[l] == Token::LParen) {
+                    rewritten.push(Token::Whitespace(Whitespace::Space));
+                    rewritten.push(Token::make_keyword("CROSS"));
+                    rewritten.push(Token::Whitespace(Whitespace::Space));
+                    rewritten.push(Token::make_keyword("JOIN"));
+                } else {
+                    rewritten.push(token.clone());
+                }
+            }
+            _ => rewritten.push(token.clone()),
+        }
+    }
+
+    rewritten
+}
+
 fn check_query_features(query: &sqlparser::ast::Query) -> SQLPlannerResult<()> {
     if !query.limit_by.is_empty() {
         unsupported_sql_err!("LIMIT BY");
@@ -1984,6 +2341,23 @@ fn is_table_path(name: &ObjectName) -> bool {
 }
 
 /// Add the relevant projection and alias plan nodes to reflect the TableAlias
+/// Wraps a set expression, e.g. one side of a set operation, into a query of its own.
+fn set_expr_to_query(expr: &SetExpr) -> Query {
+    Query {
+        with: None,
+        body: Box::new(expr.clone()),
+        order_by: None,
+        limit: None,
+        limit_by: vec![],
+        offset: None,
+        fetch: None,
+        locks: vec![],
+        for_clause: None,
+        settings: None,
+        format_clause: None,
+    }
+}
+
 fn apply_table_alias(
     mut plan: LogicalPlanBuilder,
     alias: &TableAlias,
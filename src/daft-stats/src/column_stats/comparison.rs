@@ -127,6 +127,8 @@ impl ColumnRangeStatistics {
     pub fn union(&self, rhs: &Self) -> crate::Result<Self> {
         match (self, rhs) {
             (Self::Missing, _) | (_, Self::Missing) => Ok(Self::Missing),
+            // Geometries are unordered, so their bounding boxes are merged instead.
+            (Self::Loaded(..), Self::Loaded(..)) if self.is_geometry() => self.geometry_union(rhs),
             (Self::Loaded(s_lower, s_upper), Self::Loaded(r_lower, r_upper)) => {
                 let new_min = s_lower.if_else(
                     r_lower,
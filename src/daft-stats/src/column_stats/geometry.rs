@@ -0,0 +1,89 @@
+//! Bounding-box statistics for Geometry columns.
+//!
+//! Geometries have no total order, so instead of a min/max value a Geometry column's statistics hold
+//! the lower-left and upper-right corners of its bounding box as WKB points.
+use daft_core::{
+    prelude::*,
+    utils::wkb::{
+        bounding_boxes_intersect, merge_bounding_boxes, point_to_wkb, read_bounding_box,
+        BoundingBox,
+    },
+};
+use snafu::ResultExt;
+
+use super::{ColumnRangeStatistics, TruthValue};
+use crate::DaftCoreComputeSnafu;
+
+fn point_series(name: &str, x: f64, y: f64) -> crate::Result<Series> {
+    BinaryArray::from_iter(name, std::iter::once(Some(point_to_wkb(x, y))))
+        .into_series()
+        .cast(&DataType::Geometry)
+        .context(DaftCoreComputeSnafu)
+}
+
+fn series_bounding_box(series: &Series) -> Option<BoundingBox> {
+    let geometry = series.geometry().ok()?;
+    geometry
+        .physical
+        .as_arrow()
+        .iter()
+        .flatten()
+        .filter_map(|wkb| read_bounding_box(wkb).ok().and_then(|(_, bbox)| bbox))
+        .reduce(merge_bounding_boxes)
+}
+
+impl ColumnRangeStatistics {
+    /// Creates statistics for a Geometry column from its bounding box.
+    pub fn from_bounding_box(bbox: BoundingBox) -> crate::Result<Self> {
+        let [xmin, ymin, xmax, ymax] = bbox;
+        Ok(Self::Loaded(
+            point_series("lower", xmin, ymin)?,
+            point_series("upper", xmax, ymax)?,
+        ))
+    }
+
+    pub(crate) fn is_geometry(&self) -> bool {
+        matches!(self, Self::Loaded(l, _) if l.data_type().is_geometry())
+    }
+
+    /// The bounding box covering both the lower and upper geometries, if these are Geometry statistics.
+    ///
+    /// This works both for column statistics (two corner points) and for literals, where the lower
+    /// and upper values are the same geometry.
+    pub fn bounding_box(&self) -> Option<BoundingBox> {
+        match self {
+            Self::Loaded(l, u) if l.data_type().is_geometry() => {
+                match (series_bounding_box(l), series_bounding_box(u)) {
+                    (Some(l), Some(u)) => Some(merge_bounding_boxes(l, u)),
+                    (bbox, None) | (None, bbox) => bbox,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    pub(crate) fn geometry_from_series(series: &Series) -> crate::Result<Self> {
+        match series_bounding_box(series) {
+            Some(bbox) => Self::from_bounding_box(bbox),
+            None => Ok(Self::Missing),
+        }
+    }
+
+    pub(crate) fn geometry_union(&self, rhs: &Self) -> crate::Result<Self> {
+        match (self.bounding_box(), rhs.bounding_box()) {
+            (Some(l), Some(r)) => Self::from_bounding_box(merge_bounding_boxes(l, r)),
+            _ => Ok(Self::Missing),
+        }
+    }
+
+    /// Evaluates a spatial predicate that can only be true when both geometries' bounding boxes
+    /// overlap, such as `st_intersects` or `st_contains`.
+    pub(crate) fn bounding_boxes_may_intersect(&self, rhs: &Self) -> Self {
+        match (self.bounding_box(), rhs.bounding_box()) {
+            (Some(l), Some(r)) if !bounding_boxes_intersect(l, r) => {
+                Self::from_truth_value(TruthValue::False)
+            }
+            _ => Self::Missing,
+        }
+    }
+}
@@ -1,5 +1,6 @@
 mod arithmetic;
 mod comparison;
+mod geometry;
 mod logical;
 
 use std::{
@@ -89,7 +90,10 @@ impl ColumnRangeStatistics {
             DataType::Utf8 | DataType::Binary | DataType::FixedSizeBinary(..) |
 
             // Temporal types
-            DataType::Date | DataType::Time(..) | DataType::Timestamp(..) | DataType::Duration(..) | DataType::Interval => true,
+            DataType::Date | DataType::Time(..) | DataType::Timestamp(..) | DataType::Duration(..) | DataType::Interval |
+
+            // Geometry types, whose statistics are bounding boxes rather than min/max values
+            DataType::Geometry => true,
 
             // UNSUPPORTED TYPES:
             // Types that don't support comparisons and can't be used as ColumnRangeStatistics
@@ -149,6 +153,9 @@ impl ColumnRangeStatistics {
 
     #[must_use]
     pub fn from_series(series: &Series) -> Self {
+        if series.data_type().is_geometry() {
+            return Self::geometry_from_series(series).unwrap_or(Self::Missing);
+        }
         let lower = series.min(None).unwrap();
         let upper = series.max(None).unwrap();
         let _count = series
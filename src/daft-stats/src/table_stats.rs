@@ -129,6 +129,10 @@ impl TableStatistics {
             Expr::BinaryOp { op, left, right } => {
                 let lhs = self.eval_expression(&BoundExpr::new_unchecked(left.clone()))?;
                 let rhs = self.eval_expression(&BoundExpr::new_unchecked(right.clone()))?;
+                // Geometry statistics are bounding boxes, which can't be compared or combined arithmetically.
+                if lhs.is_geometry() || rhs.is_geometry() {
+                    return Ok(ColumnRangeStatistics::Missing);
+                }
                 use daft_dsl::Operator::{And, Eq, Gt, GtEq, Lt, LtEq, Minus, NotEq, Or, Plus};
                 match op {
                     Lt => lhs.lt(&rhs),
@@ -147,10 +151,39 @@ impl TableStatistics {
             Expr::Cast(col, dtype) => self
                 .eval_expression(&BoundExpr::new_unchecked(col.clone()))?
                 .cast(dtype),
+            // Spatial predicates can only hold when the bounding boxes of both sides overlap.
+            Expr::ScalarFunction(func)
+                if matches!(func.name(), "st_intersects" | "st_contains")
+                    && func.inputs.len() == 2 =>
+            {
+                let lhs = self.eval_geometry_argument(func.inputs.required(0).unwrap())?;
+                let rhs = self.eval_geometry_argument(func.inputs.required(1).unwrap())?;
+                Ok(lhs.bounding_boxes_may_intersect(&rhs))
+            }
             _ => Ok(ColumnRangeStatistics::Missing),
         }
     }
 
+    /// Evaluates an argument of a spatial predicate.
+    ///
+    /// Arguments that don't reference any columns (e.g. `st_geomfromtext(lit(...))`) are computed
+    /// directly so that their exact bounding box can be used for pruning.
+    fn eval_geometry_argument(&self, expr: &ExprRef) -> crate::Result<ColumnRangeStatistics> {
+        fn references_columns(expr: &ExprRef) -> bool {
+            matches!(expr.as_ref(), Expr::Column(_)) || expr.children().iter().any(references_columns)
+        }
+
+        if references_columns(expr) {
+            return self.eval_expression(&BoundExpr::new_unchecked(expr.clone()));
+        }
+        let table = RecordBatch::new_with_size(Schema::empty(), vec![], 1)
+            .context(DaftCoreComputeSnafu)?;
+        let evaluated = table
+            .eval_expression_list(&[BoundExpr::new_unchecked(expr.clone())])
+            .context(DaftCoreComputeSnafu)?;
+        Ok(ColumnRangeStatistics::from_series(evaluated.get_column(0)))
+    }
+
     #[deprecated(note = "name-referenced columns")]
     /// Casts a `TableStatistics` to a schema.
     ///
@@ -275,4 +308,41 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_geometry_bounding_box_pruning() -> crate::Result<()> {
+        use daft_core::utils::wkb::point_to_wkb;
+        use daft_functions_geo::{st_contains, st_geomfromtext, st_intersects};
+
+        let points = [point_to_wkb(0.0, 0.0), point_to_wkb(2.0, 3.0)];
+        let geometry = BinaryArray::from_iter("g", points.iter().map(Some))
+            .into_series()
+            .cast(&DataType::Geometry)
+            .unwrap();
+        let table = RecordBatch::from_nonempty_columns(vec![geometry]).unwrap();
+        let table_stats = TableStatistics::from_table(&table);
+        assert_eq!(table_stats[0].bounding_box(), Some([0.0, 0.0, 2.0, 3.0]));
+
+        let far_away = st_geomfromtext(lit("POLYGON((10 10,11 10,11 11,10 11,10 10))"));
+        let expr = BoundExpr::try_new(st_intersects(resolved_col("g"), far_away.clone()), &table.schema)
+            .context(DaftCoreComputeSnafu)?;
+        let result = table_stats.eval_expression(&expr)?;
+        assert_eq!(result.to_truth_value(), TruthValue::False);
+
+        let expr = BoundExpr::try_new(st_contains(far_away, resolved_col("g")), &table.schema)
+            .context(DaftCoreComputeSnafu)?;
+        let result = table_stats.eval_expression(&expr)?;
+        assert_eq!(result.to_truth_value(), TruthValue::False);
+
+        let nearby = st_geomfromtext(lit("POINT(1 1)"));
+        let expr = BoundExpr::try_new(st_intersects(resolved_col("g"), nearby), &table.schema)
+            .context(DaftCoreComputeSnafu)?;
+        let result = table_stats.eval_expression(&expr)?;
+        assert_eq!(result.to_truth_value(), TruthValue::Maybe);
+
+        let unioned = table_stats.union(&table_stats)?;
+        assert_eq!(unioned[0].bounding_box(), Some([0.0, 0.0, 2.0, 3.0]));
+
+        Ok(())
+    }
 }
@@ -0,0 +1,273 @@
+use std::{io::Write, path::PathBuf, sync::Arc};
+
+use arrow2::{
+    datatypes::{DataType as ArrowDataType, TimeUnit as ArrowTimeUnit},
+    io::csv::write::{write_chunk, write_header, SerializeOptions},
+};
+use async_trait::async_trait;
+use common_error::{DaftError, DaftResult};
+use common_runtime::get_io_runtime;
+use daft_compression::{CompressedWriter, CompressionCodec};
+use daft_core::prelude::*;
+use daft_io::{parse_url, IOConfig, SourceType};
+use daft_micropartition::MicroPartition;
+use daft_recordbatch::RecordBatch;
+
+use crate::{
+    storage_backend::{FileStorageBackend, S3StorageBackend, StorageBackend},
+    utils::{build_filename, file_suffix},
+    AsyncFileWriter,
+};
+
+/// Helper function that checks if we support native writes given the file schema.
+pub(crate) fn native_csv_writer_supported(file_schema: &SchemaRef) -> DaftResult<bool> {
+    // The arrow2 CSV serializer panics on types it does not know how to format, e.g. nested types.
+    let datatypes_convertable = file_schema.to_arrow()?.fields.iter().all(|field| {
+        matches!(
+            field.data_type(),
+            ArrowDataType::Boolean
+                | ArrowDataType::Int8
+                | ArrowDataType::Int16
+                | ArrowDataType::Int32
+                | ArrowDataType::Int64
+                | ArrowDataType::UInt8
+                | ArrowDataType::UInt16
+                | ArrowDataType::UInt32
+                | ArrowDataType::UInt64
+                | ArrowDataType::Float32
+                | ArrowDataType::Float64
+                | ArrowDataType::Date32
+                | ArrowDataType::Date64
+                | ArrowDataType::Time32(ArrowTimeUnit::Second | ArrowTimeUnit::Millisecond)
+                | ArrowDataType::Time64(ArrowTimeUnit::Microsecond | ArrowTimeUnit::Nanosecond)
+                | ArrowDataType::Timestamp(_, _)
+                | ArrowDataType::Utf8
+                | ArrowDataType::LargeUtf8
+                | ArrowDataType::Binary
+                | ArrowDataType::LargeBinary
+        )
+    });
+    Ok(datatypes_convertable)
+}
+
+pub(crate) fn create_native_csv_writer(
+    root_dir: &str,
+    file_idx: usize,
+    partition_values: Option<&RecordBatch>,
+    io_config: Option<IOConfig>,
+    compression: Option<CompressionCodec>,
+) -> DaftResult<Box<dyn AsyncFileWriter<Input = Arc<MicroPartition>, Result = Option<RecordBatch>>>>
+{
+    // Parse the root directory and add partition values if present.
+    let (source_type, root_dir) = parse_url(root_dir)?;
+    let filename = build_filename(
+        source_type,
+        root_dir.as_ref(),
+        partition_values,
+        file_idx,
+        &file_suffix("csv", compression),
+    )?;
+    match source_type {
+        SourceType::File => {
+            let storage_backend = FileStorageBackend {};
+            Ok(Box::new(CsvWriter::new(
+                filename,
+                partition_values.cloned(),
+                storage_backend,
+                compression,
+            )))
+        }
+        SourceType::S3 => {
+            let (scheme, _, _) = daft_io::s3_like::parse_s3_url(root_dir.as_ref())?;
+            let io_config = io_config.ok_or_else(|| {
+                DaftError::InternalError("IO config is required for S3 writes".to_string())
+            })?;
+            let storage_backend = S3StorageBackend::new(scheme, io_config);
+            Ok(Box::new(CsvWriter::new(
+                filename,
+                partition_values.cloned(),
+                storage_backend,
+                compression,
+            )))
+        }
+        _ => Err(DaftError::ValueError(format!(
+            "Unsupported source type: {:?}",
+            source_type
+        ))),
+    }
+}
+
+struct CsvWriter<B: StorageBackend> {
+    filename: PathBuf,
+    partition_values: Option<RecordBatch>,
+    storage_backend: B,
+    compression: Option<CompressionCodec>,
+    file_writer: Option<CompressedWriter<B::Writer>>,
+    bytes_written: usize,
+}
+
+impl<B: StorageBackend> CsvWriter<B> {
+    const PATH_FIELD_NAME: &str = "path";
+    const INFLATION_FACTOR: f64 = 0.5;
+
+    fn new(
+        filename: PathBuf,
+        partition_values: Option<RecordBatch>,
+        storage_backend: B,
+        compression: Option<CompressionCodec>,
+    ) -> Self {
+        Self {
+            filename,
+            partition_values,
+            storage_backend,
+            compression,
+            file_writer: None,
+            bytes_written: 0,
+        }
+    }
+
+    /// Estimates the number of bytes that will be written for the given data.
+    fn estimate_bytes_to_write(&self, data: &MicroPartition) -> DaftResult<usize> {
+        let base_size = data.size_bytes()?.unwrap_or(0);
+        let estimated_size = (base_size as f64 * Self::INFLATION_FACTOR) as usize;
+        Ok(estimated_size)
+    }
+
+    async fn create_writer(&mut self, schema: &Schema) -> DaftResult<()> {
+        let backend_writer = self.storage_backend.create_writer(&self.filename).await?;
+        let mut file_writer = CompressedWriter::try_new(backend_writer, self.compression)?;
+        write_header(
+            &mut file_writer,
+            &schema.field_names().collect::<Vec<_>>(),
+            &SerializeOptions::default(),
+        )?;
+        self.file_writer = Some(file_writer);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<B: StorageBackend> AsyncFileWriter for CsvWriter<B> {
+    type Input = Arc<MicroPartition>;
+    type Result = Option<RecordBatch>;
+
+    async fn write(&mut self, data: Self::Input) -> DaftResult<usize> {
+        if self.file_writer.is_none() {
+            self.create_writer(&data.schema()).await?;
+        }
+        let est_bytes_to_write = self.estimate_bytes_to_write(&data)?;
+        self.bytes_written += est_bytes_to_write;
+        let record_batches = data.get_tables()?;
+
+        let mut file_writer = self
+            .file_writer
+            .take()
+            .expect("File writer should be created by now");
+        let io_runtime = get_io_runtime(true);
+        let file_writer = io_runtime
+            .spawn_blocking(move || -> DaftResult<CompressedWriter<_>> {
+                let options = SerializeOptions::default();
+                for record_batch in record_batches.iter() {
+                    write_chunk(&mut file_writer, &record_batch.to_chunk(), &options)?;
+                }
+                Ok(file_writer)
+            })
+            .await
+            .map_err(|e| DaftError::External(e.into()))??;
+        self.file_writer.replace(file_writer);
+
+        Ok(est_bytes_to_write)
+    }
+
+    async fn close(&mut self) -> DaftResult<Self::Result> {
+        if let Some(file_writer) = self.file_writer.take() {
+            let io_runtime = get_io_runtime(true);
+            io_runtime
+                .spawn_blocking(move || -> DaftResult<()> {
+                    file_writer.finish()?.flush()?;
+                    Ok(())
+                })
+                .await
+                .map_err(|e| DaftError::External(e.into()))??;
+        }
+        self.storage_backend.finalize().await?;
+        // Return a recordbatch containing the filename that we wrote to.
+        let field = Field::new(Self::PATH_FIELD_NAME, DataType::Utf8);
+        let filename_series = Series::from_arrow(
+            Arc::new(field.clone()),
+            Box::new(arrow2::array::Utf8Array::<i64>::from_slice([&self
+                .filename
+                .to_string_lossy()])),
+        )?;
+        let record_batch =
+            RecordBatch::new_with_size(Schema::new(vec![field]), vec![filename_series], 1)?;
+        let record_batch_with_partition_values =
+            if let Some(partition_values) = self.partition_values.take() {
+                record_batch.union(&partition_values)?
+            } else {
+                record_batch
+            };
+        Ok(Some(record_batch_with_partition_values))
+    }
+
+    fn bytes_written(&self) -> usize {
+        self.bytes_written
+    }
+
+    fn bytes_per_file(&self) -> Vec<usize> {
+        vec![self.bytes_written()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Read, sync::Arc};
+
+    use daft_core::{
+        prelude::{Int64Array, Schema, Utf8Array},
+        series::IntoSeries,
+    };
+    use daft_micropartition::MicroPartition;
+    use daft_recordbatch::RecordBatch;
+
+    use super::CsvWriter;
+    use crate::{storage_backend::FileStorageBackend, AsyncFileWriter};
+
+    fn make_micropartition() -> Arc<MicroPartition> {
+        let a = Int64Array::from(("a", vec![1, 2, 3])).into_series();
+        let b = Utf8Array::from_values("b", ["x", "y, z", "w"].iter()).into_series();
+        let schema = Arc::new(Schema::new(vec![a.field().clone(), b.field().clone()]));
+        let batch = RecordBatch::new_with_size(schema.clone(), vec![a, b], 3).unwrap();
+        Arc::new(MicroPartition::new_loaded(
+            schema,
+            Arc::new(vec![batch]),
+            None,
+        ))
+    }
+
+    #[tokio::test]
+    async fn test_compressed_csv_write() {
+        let dir = std::env::temp_dir().join(format!("daft-csv-writer-{}", uuid::Uuid::new_v4()));
+        let filename = dir.join("part-0.csv.gz");
+        let mut writer = CsvWriter::new(
+            filename.clone(),
+            None,
+            FileStorageBackend {},
+            Some(daft_compression::CompressionCodec::Gzip),
+        );
+        writer.write(make_micropartition()).await.unwrap();
+        writer.write(make_micropartition()).await.unwrap();
+        writer.close().await.unwrap();
+
+        let file = std::fs::File::open(&filename).unwrap();
+        let mut contents = String::new();
+        flate2::read::GzDecoder::new(file)
+            .read_to_string(&mut contents)
+            .unwrap();
+        std::fs::remove_dir_all(dir).unwrap();
+        assert_eq!(
+            contents,
+            "a,b\n1,x\n2,\"y, z\"\n3,w\n1,x\n2,\"y, z\"\n3,w\n"
+        );
+    }
+}
@@ -0,0 +1,381 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use async_trait::async_trait;
+use common_daft_config::DaftExecutionConfig;
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::{
+    expr::bound_expr::BoundExpr,
+    functions::partitioning::{days, hours, iceberg_bucket, iceberg_truncate, months, years},
+    null_lit, resolved_col, ExprRef,
+};
+use daft_io::IOConfig;
+use daft_micropartition::MicroPartition;
+use daft_recordbatch::RecordBatch;
+use daft_scan::iceberg::{
+    spec::{self as iceberg, PrimitiveType, Transform, Type},
+    write::{encode_bound, NewDataFile},
+    TableMetadata,
+};
+
+use crate::{
+    batch::TargetBatchWriterFactory, file::TargetFileSizeWriterFactory,
+    parquet_writer::create_native_parquet_writer_with_arrow_schema,
+    partition::PartitionedWriterFactory, AsyncFileWriter, TargetInMemorySizeBytesCalculator,
+    WriterFactory, RETURN_PATHS_COLUMN_NAME,
+};
+
+const PARQUET_FIELD_ID_KEY: &str = "PARQUET:field_id";
+
+/// The data files written by an Iceberg writer, which are committed to the table once all of
+/// them have been written.
+#[derive(Debug, Clone, Default)]
+pub struct IcebergDataFiles(Arc<Mutex<Vec<NewDataFile>>>);
+
+impl IcebergDataFiles {
+    /// Takes the data files written so far.
+    pub fn take(&self) -> Vec<NewDataFile> {
+        std::mem::take(&mut self.0.lock().unwrap())
+    }
+}
+
+/// IcebergWriterFactory creates native Parquet writers for the data files of an Iceberg table,
+/// which tag columns with their Iceberg field ids and collect the file statistics that go into
+/// manifests.
+struct IcebergWriterFactory {
+    data_dir: String,
+    schema: iceberg::Schema,
+    arrow_schema: Arc<arrow_schema::Schema>,
+    io_config: Option<IOConfig>,
+    data_files: IcebergDataFiles,
+}
+
+impl WriterFactory for IcebergWriterFactory {
+    type Input = Arc<MicroPartition>;
+    type Result = Option<RecordBatch>;
+
+    fn create_writer(
+        &self,
+        file_idx: usize,
+        partition_values: Option<&RecordBatch>,
+    ) -> DaftResult<Box<dyn AsyncFileWriter<Input = Self::Input, Result = Self::Result>>> {
+        let writer = create_native_parquet_writer_with_arrow_schema(
+            &self.data_dir,
+            self.arrow_schema.clone(),
+            file_idx,
+            partition_values,
+            self.io_config.clone(),
+        )?;
+        Ok(Box::new(IcebergFileWriter {
+            writer,
+            schema: self.schema.clone(),
+            partition_values: partition_values.cloned(),
+            stats: vec![ColumnStats::default(); self.schema.fields.len()],
+            record_count: 0,
+            data_files: self.data_files.clone(),
+        }))
+    }
+}
+
+/// Statistics of a top-level column across the batches written to a file.
+#[derive(Clone, Default)]
+struct ColumnStats {
+    value_count: i64,
+    null_count: i64,
+    lower: Option<Series>,
+    upper: Option<Series>,
+}
+
+impl ColumnStats {
+    fn update(&mut self, series: &Series, primitive: Option<&PrimitiveType>) -> DaftResult<()> {
+        self.value_count += series.len() as i64;
+        self.null_count += series.validity().map_or(0, |v| v.unset_bits()) as i64;
+        // Bounds are only tracked for types whose order matches Iceberg's, which leaves out e.g.
+        // floating point columns, whose bounds would have to account for NaNs.
+        if primitive.is_some_and(|primitive| {
+            matches!(
+                primitive,
+                PrimitiveType::Int
+                    | PrimitiveType::Long
+                    | PrimitiveType::Date
+                    | PrimitiveType::Time
+                    | PrimitiveType::Timestamp
+                    | PrimitiveType::Timestamptz
+                    | PrimitiveType::TimestampNs
+                    | PrimitiveType::TimestamptzNs
+                    | PrimitiveType::String
+            )
+        }) {
+            let series = series.cast(&series.data_type().to_physical())?;
+            self.lower = Some(Self::merge(
+                self.lower.take(),
+                series.min(None)?,
+                Series::min,
+            )?);
+            self.upper = Some(Self::merge(
+                self.upper.take(),
+                series.max(None)?,
+                Series::max,
+            )?);
+        }
+        Ok(())
+    }
+
+    fn merge(
+        current: Option<Series>,
+        new: Series,
+        agg: impl Fn(&Series, Option<&daft_core::array::ops::GroupIndices>) -> DaftResult<Series>,
+    ) -> DaftResult<Series> {
+        match current {
+            Some(current) => agg(&Series::concat(&[&current, &new])?, None),
+            None => Ok(new),
+        }
+    }
+}
+
+struct IcebergFileWriter {
+    writer: Box<dyn AsyncFileWriter<Input = Arc<MicroPartition>, Result = Option<RecordBatch>>>,
+    schema: iceberg::Schema,
+    partition_values: Option<RecordBatch>,
+    stats: Vec<ColumnStats>,
+    record_count: i64,
+    data_files: IcebergDataFiles,
+}
+
+#[async_trait]
+impl AsyncFileWriter for IcebergFileWriter {
+    type Input = Arc<MicroPartition>;
+    type Result = Option<RecordBatch>;
+
+    async fn write(&mut self, data: Self::Input) -> DaftResult<usize> {
+        for batch in data.get_tables()?.iter() {
+            self.record_count += batch.len() as i64;
+            for (idx, (field, stats)) in self.schema.fields.iter().zip(&mut self.stats).enumerate()
+            {
+                let primitive = match &field.field_type {
+                    Type::Primitive(primitive) => Some(primitive),
+                    _ => None,
+                };
+                stats.update(batch.get_column(idx), primitive)?;
+            }
+        }
+        self.writer.write(data).await
+    }
+
+    async fn close(&mut self) -> DaftResult<Self::Result> {
+        let result = self.writer.close().await?;
+        let Some(result) = result else {
+            return Ok(None);
+        };
+        let path_idx = result.schema.get_index(RETURN_PATHS_COLUMN_NAME)?;
+        let file_path = result
+            .get_column(path_idx)
+            .utf8()?
+            .get(0)
+            .ok_or_else(|| {
+                DaftError::InternalError("Parquet writer returned no file path".to_string())
+            })?
+            .to_string();
+
+        let mut data_file = NewDataFile {
+            file_path,
+            partition_values: self.partition_values.take(),
+            record_count: self.record_count,
+            file_size_in_bytes: self.writer.bytes_written() as i64,
+            value_counts: HashMap::new(),
+            null_value_counts: HashMap::new(),
+            lower_bounds: HashMap::new(),
+            upper_bounds: HashMap::new(),
+        };
+        for (field, stats) in self.schema.fields.iter().zip(&self.stats) {
+            data_file.value_counts.insert(field.id, stats.value_count);
+            data_file
+                .null_value_counts
+                .insert(field.id, stats.null_count);
+            let Type::Primitive(primitive) = &field.field_type else {
+                continue;
+            };
+            for (bound, bounds) in [
+                (&stats.lower, &mut data_file.lower_bounds),
+                (&stats.upper, &mut data_file.upper_bounds),
+            ] {
+                if let Some(bound) = bound
+                    && let Some(encoded) = encode_bound(bound, primitive)?
+                {
+                    bounds.insert(field.id, encoded);
+                }
+            }
+        }
+        self.data_files.0.lock().unwrap().push(data_file);
+        Ok(Some(result))
+    }
+
+    fn bytes_written(&self) -> usize {
+        self.writer.bytes_written()
+    }
+
+    fn bytes_per_file(&self) -> Vec<usize> {
+        self.writer.bytes_per_file()
+    }
+}
+
+/// Tags `field`, and the fields nested in it, with the Iceberg field ids of `field_type`.
+fn with_field_ids(field: &arrow_schema::Field, id: i32, field_type: &Type) -> arrow_schema::Field {
+    use arrow_schema::DataType as ArrowType;
+
+    let data_type = match (field.data_type(), field_type) {
+        (ArrowType::Struct(children), Type::Struct(struct_type)) => ArrowType::Struct(
+            children
+                .iter()
+                .zip(&struct_type.fields)
+                .map(|(child, nested)| with_field_ids(child, nested.id, &nested.field_type))
+                .collect(),
+        ),
+        (ArrowType::List(item), Type::List(list)) => ArrowType::List(Arc::new(with_field_ids(
+            item,
+            list.element_id,
+            &list.element,
+        ))),
+        (ArrowType::LargeList(item), Type::List(list)) => ArrowType::LargeList(Arc::new(
+            with_field_ids(item, list.element_id, &list.element),
+        )),
+        (ArrowType::Map(entries, sorted), Type::Map(map)) => match entries.data_type() {
+            ArrowType::Struct(key_value) if key_value.len() == 2 => {
+                let key_value = vec![
+                    with_field_ids(&key_value[0], map.key_id, &map.key),
+                    with_field_ids(&key_value[1], map.value_id, &map.value),
+                ];
+                ArrowType::Map(
+                    Arc::new(
+                        entries
+                            .as_ref()
+                            .clone()
+                            .with_data_type(ArrowType::Struct(key_value.into())),
+                    ),
+                    *sorted,
+                )
+            }
+            data_type => data_type.clone(),
+        },
+        (data_type, _) => data_type.clone(),
+    };
+    let mut metadata = field.metadata().clone();
+    metadata.insert(PARQUET_FIELD_ID_KEY.to_string(), id.to_string());
+    field
+        .clone()
+        .with_data_type(data_type)
+        .with_metadata(metadata)
+}
+
+/// Returns the expression that computes the values of a partition field from the table's columns.
+fn partition_expr(
+    field: &iceberg::PartitionField,
+    schema: &iceberg::Schema,
+) -> DaftResult<ExprRef> {
+    let source = schema.find_field(field.source_id).ok_or_else(|| {
+        DaftError::ValueError(format!(
+            "Iceberg partition field {} references unknown source field id {}",
+            field.name, field.source_id
+        ))
+    })?;
+    let input = resolved_col(source.name.as_str());
+    let expr = match field.transform {
+        Transform::Identity => input,
+        Transform::Year => years(input),
+        Transform::Month => months(input),
+        Transform::Day => days(input),
+        Transform::Hour => hours(input),
+        Transform::Bucket(n) => iceberg_bucket(input, n as i32),
+        Transform::Truncate(w) => iceberg_truncate(input, w as i64),
+        Transform::Void => {
+            null_lit().cast(&field.transform.result_type(&source.field_type.to_daft()))
+        }
+    };
+    Ok(expr.alias(field.name.as_str()))
+}
+
+type MicroPartitionWriterFactory =
+    Arc<dyn WriterFactory<Input = Arc<MicroPartition>, Result = Vec<RecordBatch>>>;
+
+/// Creates a writer factory for native writes of data files to the Iceberg table described by
+/// `metadata`, which splits data by the table's default partition spec.
+///
+/// Input must already have the schema of the table. The written files are collected in the
+/// returned [`IcebergDataFiles`] as their writers are closed.
+pub fn make_iceberg_writer_factory(
+    metadata: &TableMetadata,
+    io_config: Option<IOConfig>,
+    cfg: &DaftExecutionConfig,
+) -> DaftResult<(MicroPartitionWriterFactory, IcebergDataFiles)> {
+    let schema = metadata.current_schema()?.clone();
+    let daft_schema = Arc::new(schema.to_daft());
+    let arrow2_schema = daft_schema.to_arrow()?;
+    if let Some(field) = arrow2_schema
+        .fields
+        .iter()
+        .find(|field| !field.data_type().can_convert_to_arrow_rs())
+    {
+        return Err(DaftError::NotImplemented(format!(
+            "Native Iceberg writes do not support column {} of type {:?}",
+            field.name,
+            field.data_type()
+        )));
+    }
+    let arrow_schema = arrow_schema::Schema::from(arrow2_schema);
+    let arrow_schema = Arc::new(arrow_schema::Schema::new(
+        arrow_schema
+            .fields()
+            .iter()
+            .zip(&schema.fields)
+            .map(|(field, nested)| with_field_ids(field, nested.id, &nested.field_type))
+            .collect::<Vec<_>>(),
+    ));
+
+    let spec = metadata.default_partition_spec()?;
+    let partition_by = spec
+        .fields
+        .iter()
+        .map(|field| BoundExpr::try_new(partition_expr(field, &schema)?, &daft_schema))
+        .collect::<DaftResult<Vec<_>>>()?;
+
+    let data_files = IcebergDataFiles::default();
+    let base_writer_factory = IcebergWriterFactory {
+        data_dir: format!("{}/data", metadata.location.trim_end_matches('/')),
+        schema,
+        arrow_schema,
+        io_config,
+        data_files: data_files.clone(),
+    };
+    let file_size_calculator = TargetInMemorySizeBytesCalculator::new(
+        cfg.parquet_target_filesize,
+        cfg.parquet_inflation_factor,
+    );
+    let row_group_size_calculator = TargetInMemorySizeBytesCalculator::new(
+        std::cmp::min(
+            cfg.parquet_target_row_group_size,
+            cfg.parquet_target_filesize,
+        ),
+        cfg.parquet_inflation_factor,
+    );
+    let row_group_writer_factory = TargetBatchWriterFactory::new(
+        Arc::new(base_writer_factory),
+        Arc::new(row_group_size_calculator),
+    );
+    let file_writer_factory = TargetFileSizeWriterFactory::new(
+        Arc::new(row_group_writer_factory),
+        Arc::new(file_size_calculator),
+    );
+
+    let writer_factory: Arc<dyn WriterFactory<Input = _, Result = _>> = if partition_by.is_empty() {
+        Arc::new(file_writer_factory)
+    } else {
+        Arc::new(PartitionedWriterFactory::new(
+            Arc::new(file_writer_factory),
+            partition_by,
+        ))
+    };
+    Ok((writer_factory, data_files))
+}
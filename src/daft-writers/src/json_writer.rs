@@ -1,10 +1,11 @@
-use std::{path::PathBuf, sync::Arc};
+use std::{io::Write, path::PathBuf, sync::Arc};
 
 use arrow_array::RecordBatch as ArrowRecordBatch;
 use arrow_json::{writer::LineDelimited, LineDelimitedWriter, WriterBuilder};
 use async_trait::async_trait;
 use common_error::{DaftError, DaftResult};
 use common_runtime::get_io_runtime;
+use daft_compression::{CompressedWriter, CompressionCodec};
 use daft_core::prelude::*;
 use daft_io::{parse_url, IOConfig, SourceType};
 use daft_micropartition::MicroPartition;
@@ -12,7 +13,7 @@ use daft_recordbatch::RecordBatch;
 
 use crate::{
     storage_backend::{FileStorageBackend, S3StorageBackend, StorageBackend},
-    utils::build_filename,
+    utils::{build_filename, file_suffix},
     AsyncFileWriter,
 };
 
@@ -30,6 +31,7 @@ pub(crate) fn create_native_json_writer(
     file_idx: usize,
     partition_values: Option<&RecordBatch>,
     io_config: Option<IOConfig>,
+    compression: Option<CompressionCodec>,
 ) -> DaftResult<Box<dyn AsyncFileWriter<Input = Arc<MicroPartition>, Result = Option<RecordBatch>>>>
 {
     // Parse the root directory and add partition values if present.
@@ -39,7 +41,7 @@ pub(crate) fn create_native_json_writer(
         root_dir.as_ref(),
         partition_values,
         file_idx,
-        "json",
+        &file_suffix("json", compression),
     )?;
     match source_type {
         SourceType::File => {
@@ -48,6 +50,7 @@ pub(crate) fn create_native_json_writer(
                 filename,
                 partition_values.cloned(),
                 storage_backend,
+                compression,
             )))
         }
         SourceType::S3 => {
@@ -60,6 +63,7 @@ pub(crate) fn create_native_json_writer(
                 filename,
                 partition_values.cloned(),
                 storage_backend,
+                compression,
             )))
         }
         _ => Err(DaftError::ValueError(format!(
@@ -73,7 +77,8 @@ struct JsonWriter<B: StorageBackend> {
     filename: PathBuf,
     partition_values: Option<RecordBatch>,
     storage_backend: B,
-    file_writer: Option<LineDelimitedWriter<B::Writer>>,
+    compression: Option<CompressionCodec>,
+    file_writer: Option<LineDelimitedWriter<CompressedWriter<B::Writer>>>,
     bytes_written: usize,
 }
 
@@ -81,11 +86,17 @@ impl<B: StorageBackend> JsonWriter<B> {
     const PATH_FIELD_NAME: &str = "path";
     const INFLATION_FACTOR: f64 = 0.5;
 
-    fn new(filename: PathBuf, partition_values: Option<RecordBatch>, storage_backend: B) -> Self {
+    fn new(
+        filename: PathBuf,
+        partition_values: Option<RecordBatch>,
+        storage_backend: B,
+        compression: Option<CompressionCodec>,
+    ) -> Self {
         Self {
             filename,
             partition_values,
             storage_backend,
+            compression,
             file_writer: None,
             bytes_written: 0,
         }
@@ -101,6 +112,7 @@ impl<B: StorageBackend> JsonWriter<B> {
 
     async fn create_writer(&mut self) -> DaftResult<()> {
         let backend_writer = self.storage_backend.create_writer(&self.filename).await?;
+        let backend_writer = CompressedWriter::try_new(backend_writer, self.compression)?;
         let builder = WriterBuilder::new().with_explicit_nulls(true);
         let file_writer = builder.build::<_, LineDelimited>(backend_writer);
         self.file_writer = Some(file_writer);
@@ -150,16 +162,15 @@ impl<B: StorageBackend> AsyncFileWriter for JsonWriter<B> {
     async fn close(&mut self) -> DaftResult<Self::Result> {
         let io_runtime = get_io_runtime(true);
         let mut file_writer = self.file_writer.take().unwrap();
-        self.file_writer = Some(
-            io_runtime
-                .spawn_blocking(move || -> DaftResult<LineDelimitedWriter<_>> {
-                    file_writer.finish()?;
-
-                    Ok(file_writer)
-                })
-                .await
-                .map_err(|e| DaftError::ParquetError(e.to_string()))??,
-        );
+        io_runtime
+            .spawn_blocking(move || -> DaftResult<()> {
+                file_writer.finish()?;
+                // Write the compression codec's trailer, if any.
+                file_writer.into_inner().finish()?.flush()?;
+                Ok(())
+            })
+            .await
+            .map_err(|e| DaftError::ParquetError(e.to_string()))??;
         self.storage_backend.finalize().await?;
         // Return a recordbatch containing the filename that we wrote to.
         let field = Field::new(Self::PATH_FIELD_NAME, DataType::Utf8);
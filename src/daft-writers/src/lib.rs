@@ -1,7 +1,9 @@
 #![feature(hash_raw_entry)]
 #![feature(let_chains)]
 mod batch;
+mod csv_writer;
 mod file;
+mod iceberg;
 mod ipc;
 mod json_writer;
 mod parquet_writer;
@@ -41,6 +43,7 @@ use daft_logical_plan::OutputFileInfo;
 use daft_micropartition::MicroPartition;
 use daft_recordbatch::RecordBatch;
 use file::TargetFileSizeWriterFactory;
+pub use iceberg::{make_iceberg_writer_factory, IcebergDataFiles};
 use ipc::IPCWriterFactory;
 #[cfg(feature = "python")]
 pub use lance::make_lance_writer_factory;
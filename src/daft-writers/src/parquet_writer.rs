@@ -70,6 +70,25 @@ pub(crate) fn create_native_parquet_writer(
     partition_values: Option<&RecordBatch>,
     io_config: Option<IOConfig>,
 ) -> DaftResult<Box<dyn AsyncFileWriter<Input = Arc<MicroPartition>, Result = Option<RecordBatch>>>>
+{
+    create_native_parquet_writer_with_arrow_schema(
+        root_dir,
+        Arc::new(schema.to_arrow()?.into()),
+        file_idx,
+        partition_values,
+        io_config,
+    )
+}
+
+/// Like [`create_native_parquet_writer`], but with the Arrow schema given directly, so that
+/// callers can attach field metadata such as Parquet field ids.
+pub(crate) fn create_native_parquet_writer_with_arrow_schema(
+    root_dir: &str,
+    arrow_schema: Arc<arrow_schema::Schema>,
+    file_idx: usize,
+    partition_values: Option<&RecordBatch>,
+    io_config: Option<IOConfig>,
+) -> DaftResult<Box<dyn AsyncFileWriter<Input = Arc<MicroPartition>, Result = Option<RecordBatch>>>>
 {
     // Parse the root directory and add partition values if present.
     let (source_type, root_dir) = parse_url(root_dir)?;
@@ -90,8 +109,6 @@ pub(crate) fn create_native_parquet_writer(
             .build(),
     );
 
-    let arrow_schema = Arc::new(schema.to_arrow()?.into());
-
     let parquet_schema = ArrowSchemaConverter::new()
         .with_coerce_types(writer_properties.coerce_types())
         .convert(&arrow_schema)
@@ -9,8 +9,10 @@ use daft_micropartition::MicroPartition;
 use daft_recordbatch::RecordBatch;
 
 use crate::{
+    csv_writer::{create_native_csv_writer, native_csv_writer_supported},
     json_writer::{create_native_json_writer, native_json_writer_supported},
     parquet_writer::{create_native_parquet_writer, native_parquet_writer_supported},
+    utils::parse_compression,
     AsyncFileWriter, WriterFactory,
 };
 
@@ -52,6 +54,7 @@ impl PhysicalWriterFactory {
             FileFormat::Parquet => {
                 Self::select_parquet_writer_type(output_file_info, file_schema, native_enabled)
             }
+            FileFormat::Csv => Self::select_csv_writer_type(output_file_info, file_schema),
             FileFormat::Json => Self::select_json_writer_type(file_schema),
             _ => Ok(WriterType::Pyarrow), // Default to PyArrow for unsupported formats.
         }
@@ -77,6 +80,22 @@ impl PhysicalWriterFactory {
         }
     }
 
+    /// Selects writer type for CSV format.
+    fn select_csv_writer_type(
+        output_file_info: &OutputFileInfo<BoundExpr>,
+        file_schema: &SchemaRef,
+    ) -> DaftResult<WriterType> {
+        // PyArrow's CSV writer does not support compression, so compressed writes must be native.
+        if output_file_info.compression.is_none() && cfg!(feature = "python") {
+            return Ok(WriterType::Pyarrow);
+        }
+        let native_supported = native_csv_writer_supported(file_schema)?;
+        if !native_supported {
+            return Err(DaftError::NotImplemented("Compressed CSV writes are not supported with nested, extension, decimal, duration or null data types".to_string()));
+        }
+        Ok(WriterType::Native)
+    }
+
     fn select_json_writer_type(file_schema: &SchemaRef) -> DaftResult<WriterType> {
         let native_supported = native_json_writer_supported(file_schema)?;
         if !native_supported {
@@ -104,6 +123,7 @@ impl WriterFactory for PhysicalWriterFactory {
                 self.output_file_info.file_format,
                 partition_values,
                 self.output_file_info.io_config.clone(),
+                self.output_file_info.compression.as_ref(),
             ),
             WriterType::Pyarrow => create_pyarrow_file_writer(
                 &self.output_file_info.root_dir,
@@ -152,15 +172,27 @@ fn create_native_writer(
     file_format: FileFormat,
     partition_values: Option<&RecordBatch>,
     io_config: Option<daft_io::IOConfig>,
+    compression: Option<&String>,
 ) -> DaftResult<Box<dyn AsyncFileWriter<Input = Arc<MicroPartition>, Result = Option<RecordBatch>>>>
 {
     match file_format {
         FileFormat::Parquet => {
             create_native_parquet_writer(root_dir, schema, file_idx, partition_values, io_config)
         }
-        FileFormat::Json => {
-            create_native_json_writer(root_dir, file_idx, partition_values, io_config)
-        }
+        FileFormat::Csv => create_native_csv_writer(
+            root_dir,
+            file_idx,
+            partition_values,
+            io_config,
+            parse_compression(compression)?,
+        ),
+        FileFormat::Json => create_native_json_writer(
+            root_dir,
+            file_idx,
+            partition_values,
+            io_config,
+            parse_compression(compression)?,
+        ),
         _ => Err(DaftError::ComputeError(
             "Unsupported file format for native write".to_string(),
         )),
@@ -1,6 +1,7 @@
 use std::path::{Path, PathBuf};
 
 use common_error::{DaftError, DaftResult};
+use daft_compression::CompressionCodec;
 use daft_io::SourceType;
 use daft_recordbatch::RecordBatch;
 
@@ -28,6 +29,27 @@ pub(crate) fn build_filename(
     }
 }
 
+/// Parses the compression option of a CSV or JSON write.
+pub(crate) fn parse_compression(
+    compression: Option<&String>,
+) -> DaftResult<Option<CompressionCodec>> {
+    compression
+        .map(|name| {
+            CompressionCodec::from_name(name).ok_or_else(|| {
+                DaftError::ValueError(format!("Unsupported compression for writes: {name}"))
+            })
+        })
+        .transpose()
+}
+
+/// The file suffix for `format`, with the codec's extension appended so that reads infer the compression.
+pub(crate) fn file_suffix(format: &str, compression: Option<CompressionCodec>) -> String {
+    match compression {
+        Some(codec) => format!("{format}.{}", codec.extension()),
+        None => format.to_string(),
+    }
+}
+
 /// Helper function to get the partition path from the record batch.
 fn get_partition_path(partition_values: Option<&RecordBatch>) -> DaftResult<PathBuf> {
     match partition_values {
@@ -109,6 +131,7 @@ mod tests {
     use std::sync::Arc;
 
     use common_error::{DaftError, DaftResult};
+    use daft_compression::CompressionCodec;
     use daft_core::{
         prelude::{DataType, Field},
         series::Series,
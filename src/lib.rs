@@ -120,6 +120,7 @@ pub mod pylib {
         daft_distributed::register_modules(m)?;
         daft_dsl::register_modules(m)?;
         daft_functions::register_modules(m)?;
+        daft_functions_json::register_modules(m)?;
         daft_io::register_modules(m)?;
         daft_ir::register_modules(m)?;
         daft_json::register_modules(m)?;
@@ -157,6 +158,7 @@ pub mod pylib {
         functions_registry.register::<daft_functions_uri::UriFunctions>();
         functions_registry.register::<daft_image::functions::ImageFunctions>();
         functions_registry.register::<daft_functions_binary::BinaryFunctions>();
+        functions_registry.register::<daft_functions_geo::GeoFunctions>();
         functions_registry.register::<daft_functions_json::JsonFunctions>();
         functions_registry.register::<daft_functions_list::ListFunctions>();
         functions_registry.register::<daft_functions_utf8::Utf8Functions>();
@@ -171,6 +173,8 @@ pub mod pylib {
         functions_registry.add_fn(daft_functions::coalesce::Coalesce);
         functions_registry
             .add_fn(daft_functions::monotonically_increasing_id::MonotonicallyIncreasingId);
+        functions_registry.add_fn(daft_functions::grouping::Grouping);
+        functions_registry.add_fn(daft_functions::grouping::GroupingId);
         functions_registry.register::<daft_functions::distance::DistanceFunctions>();
 
         Ok(())
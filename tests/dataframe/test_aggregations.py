@@ -9,6 +9,7 @@ import pytest
 
 import daft
 from daft import col
+from daft.functions import grouping, grouping_id
 from daft.context import get_context
 from daft.datatype import DataType
 from daft.errors import ExpressionTypeError
@@ -888,3 +889,86 @@ def test_bool_agg_type_error(make_df):
     with pytest.raises(Exception) as exc_info:
         df.agg(col("int_col").bool_or()).collect()
     assert "bool_or is not implemented for type Int64" in str(exc_info.value)
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_rollup(make_df, repartition_nparts, with_morsel_size):
+    df = make_df(
+        {"pet": ["cat", "dog", "dog", "cat", None], "sex": ["F", "M", "F", "F", "M"], "age": [1, 2, 3, 4, 5]},
+        repartition=repartition_nparts,
+    )
+    res = df.rollup("pet", "sex").agg(col("age").sum(), grouping("pet").alias("g"), grouping_id().alias("gid"))
+    assert res.sort(["gid", "pet", "sex"]).to_pydict() == {
+        "pet": ["cat", "dog", "dog", None, "cat", "dog", None, None],
+        "sex": ["F", "F", "M", "M", None, None, None, None],
+        "age": [5, 3, 2, 5, 5, 5, 5, 15],
+        "g": [0, 0, 0, 0, 0, 0, 0, 1],
+        "gid": [0, 0, 0, 0, 1, 1, 1, 3],
+    }
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_cube(make_df, repartition_nparts, with_morsel_size):
+    df = make_df(
+        {"pet": ["cat", "dog", "dog", "cat"], "sex": ["F", "M", "F", "F"], "age": [1, 2, 3, 4]},
+        repartition=repartition_nparts,
+    )
+    res = df.cube("pet", "sex").agg(col("age").sum(), col("age").count().alias("n"), grouping_id("sex").alias("gs"))
+    assert res.sort(["gs", "pet", "sex"]).to_pydict() == {
+        "pet": ["cat", "dog", "dog", None, None, "cat", "dog", None],
+        "sex": ["F", "F", "M", "F", "M", None, None, None],
+        "age": [5, 3, 2, 8, 2, 5, 5, 10],
+        "n": [2, 1, 1, 3, 1, 2, 2, 4],
+        "gs": [0, 0, 0, 0, 0, 1, 1, 1],
+    }
+
+
+def test_grouping_requires_grouping_sets(make_df):
+    df = make_df({"pet": ["cat", "dog"], "age": [1, 2]})
+    with pytest.raises(Exception, match="grouping sets, ROLLUP or CUBE"):
+        df.groupby("pet").agg(grouping("pet"))
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 7])
+def test_agg_groupby_min_by_max_by(make_df, repartition_nparts, with_morsel_size):
+    daft_df = make_df(
+        {
+            "group": [1, 1, 1, 2, 2, 2, 3],
+            "name": ["a", "b", "c", "d", "e", "f", "g"],
+            "score": [2, None, 1, 5, 7, 6, None],
+        },
+        repartition=repartition_nparts,
+    )
+    daft_df = daft_df.groupby("group").agg(
+        col("name").min_by(col("score")).alias("min_by"),
+        col("name").max_by(col("score")).alias("max_by"),
+    )
+    result = daft_df.sort("group").to_pydict()
+    assert result == {"group": [1, 2, 3], "min_by": ["c", "d", None], "max_by": ["a", "e", None]}
+
+
+def test_agg_min_max_nested(make_df):
+    daft_df = make_df(
+        {
+            "group": [1, 1, 1, 2, 2],
+            "l": [[1, 2], [1], None, [0, 5], [0, 5, 1]],
+            "s": [{"a": 2, "b": "x"}, {"a": 2, "b": "w"}, None, None, {"a": 1, "b": "y"}],
+        }
+    )
+    result = (
+        daft_df.groupby("group")
+        .agg(
+            col("l").min().alias("l_min"),
+            col("l").max().alias("l_max"),
+            col("s").min().alias("s_min"),
+            col("s").max().alias("s_max"),
+            col("group").min_by(col("s")).alias("group_min_by_s"),
+        )
+        .sort("group")
+        .to_pydict()
+    )
+    assert result["l_min"] == [[1], [0, 5]]
+    assert result["l_max"] == [[1, 2], [0, 5, 1]]
+    assert result["s_min"] == [{"a": 2, "b": "w"}, {"a": 1, "b": "y"}]
+    assert result["s_max"] == [{"a": 2, "b": "x"}, {"a": 1, "b": "y"}]
+    assert result["group_min_by_s"] == [1, 2]
@@ -126,3 +126,85 @@ def test_decimal_grouped_stddev(prec, partitions) -> None:
     assert res.to_pydict() == {"group": [0, 1], "decimal128": [pytest.approx(5.51), pytest.approx(0)]}
     schema = res.schema()
     assert schema["decimal128"].dtype == daft.DataType.float64()
+
+
+def test_decimal_arithmetic_is_exact() -> None:
+    df = daft.from_pydict(
+        {
+            "price": [decimal.Decimal("0.10"), decimal.Decimal("1.05"), decimal.Decimal("10.00")],
+            "qty": [decimal.Decimal("0.20"), decimal.Decimal("0.50"), decimal.Decimal("3.00")],
+        }
+    ).with_columns(
+        {
+            "price": daft.col("price").cast(daft.DataType.decimal128(10, 2)),
+            "qty": daft.col("qty").cast(daft.DataType.decimal128(10, 2)),
+        }
+    )
+    res = df.select(
+        (df["price"] + df["qty"]).alias("add"),
+        (df["price"] * df["qty"]).alias("mul"),
+        (df["price"] / df["qty"]).alias("div"),
+    ).to_pydict()
+    assert res["add"] == [decimal.Decimal("0.30"), decimal.Decimal("1.55"), decimal.Decimal("13.00")]
+    assert res["mul"] == [decimal.Decimal("0.0200"), decimal.Decimal("0.5250"), decimal.Decimal("30.0000")]
+    assert res["div"] == [decimal.Decimal("0.5"), decimal.Decimal("2.1"), decimal.Decimal("3.3333333333333")]
+
+
+def test_decimal_narrowing_cast_overflow() -> None:
+    df = daft.from_pydict({"a": [decimal.Decimal("123.45")]})
+    assert df.select(df["a"].cast(daft.DataType.decimal128(4, 1))).to_pydict()["a"] == [decimal.Decimal("123.5")]
+    with pytest.raises(Exception, match="Decimal overflow"):
+        df.select(df["a"].cast(daft.DataType.decimal128(3, 1))).collect()
+
+
+def test_decimal_division_by_zero() -> None:
+    df = daft.from_pydict({"a": [decimal.Decimal("1.00")], "b": [decimal.Decimal("0.00")]})
+    with pytest.raises(Exception, match="division by zero"):
+        df.select(df["a"] / df["b"]).collect()
+
+
+def test_decimal_sum_overflow() -> None:
+    df = daft.from_pydict({"a": [decimal.Decimal("9" * 36)] * 200}).with_column(
+        "a", daft.col("a").cast(daft.DataType.decimal128(38, 0))
+    )
+    with pytest.raises(Exception, match="Decimal overflow"):
+        df.sum("a").collect()
+
+
+def test_decimal_mean_rounds_half_up() -> None:
+    df = daft.from_pydict({"a": [decimal.Decimal("0.00"), decimal.Decimal("0.00"), decimal.Decimal("0.02")]})
+    df = df.with_column("a", df["a"].cast(daft.DataType.decimal128(5, 2)))
+    # 0.02 / 3 = 0.006666..., which must round up at scale 6 rather than truncate.
+    assert df.mean("a").to_pydict()["a"] == [decimal.Decimal("0.006667")]
+
+
+def test_decimal_string_casts_are_exact() -> None:
+    df = daft.from_pydict({"s": ["12.345", "-0.005", "1e2", "not a number", None]})
+    res = df.select(df["s"].cast(daft.DataType.decimal128(10, 2)).alias("d")).to_pydict()
+    assert res["d"] == [decimal.Decimal("12.35"), decimal.Decimal("-0.01"), decimal.Decimal("100.00"), None, None]
+
+    df = daft.from_pydict({"d": [decimal.Decimal("-1.05"), decimal.Decimal("123456789.99")]})
+    df = df.with_column("d", df["d"].cast(daft.DataType.decimal128(12, 2)))
+    assert df.select(df["d"].cast(daft.DataType.string())).to_pydict()["d"] == ["-1.05", "123456789.99"]
+
+
+def test_decimal_integer_casts_are_exact() -> None:
+    df = daft.from_pydict({"i": [12, -3, None]})
+    res = df.select(df["i"].cast(daft.DataType.decimal128(5, 2)).alias("d")).to_pydict()
+    assert res["d"] == [decimal.Decimal("12.00"), decimal.Decimal("-3.00"), None]
+
+    with pytest.raises(Exception, match="Decimal overflow"):
+        df.select(df["i"].cast(daft.DataType.decimal128(2, 1))).collect()
+
+    df = daft.from_pydict({"d": [decimal.Decimal("1.99"), decimal.Decimal("-1.99")]})
+    assert df.select(df["d"].cast(daft.DataType.int64())).to_pydict()["d"] == [1, -1]
+    with pytest.raises(Exception, match="out of range"):
+        df.select((df["d"] * 1000).cast(daft.DataType.int8())).collect()
+
+
+def test_decimal_round() -> None:
+    df = daft.from_pydict({"d": [decimal.Decimal("9.995"), decimal.Decimal("-1.125"), decimal.Decimal("1.124")]})
+    df = df.with_column("d", df["d"].cast(daft.DataType.decimal128(4, 3)))
+    res = df.select(df["d"].round(2))
+    assert res.schema()["d"].dtype == daft.DataType.decimal128(4, 2)
+    assert res.to_pydict()["d"] == [decimal.Decimal("10.00"), decimal.Decimal("-1.13"), decimal.Decimal("1.12")]
@@ -481,3 +481,36 @@ def test_top_k_second_level_sorting(sort_keys, desc, nulls_first, expected):
     )
     result = df.sort(by=sort_keys, desc=desc, nulls_first=nulls_first).limit(6)
     assert result.to_pydict() == expected
+
+
+@pytest.mark.parametrize("n_partitions", [1, 3])
+def test_sort_by_struct(make_df, n_partitions: int):
+    df = make_df(
+        {
+            "s": [{"a": 2, "b": "x"}, {"a": 1, "b": "y"}, None, {"a": 2, "b": "w"}, {"a": 1, "b": None}],
+            "id": [0, 1, 2, 3, 4],
+        },
+        repartition=n_partitions,
+    )
+    assert df.sort("s").to_pydict()["id"] == [1, 4, 3, 0, 2]
+    assert df.sort("s", desc=True, nulls_first=False).to_pydict()["id"] == [0, 3, 4, 1, 2]
+
+
+@pytest.mark.parametrize("n_partitions", [1, 3])
+def test_sort_by_list(make_df, n_partitions: int):
+    df = make_df({"l": [[1, 2], [1], [], None, [0, 5], [1, 2, 0]], "id": [0, 1, 2, 3, 4, 5]}, repartition=n_partitions)
+    assert df.sort("l").to_pydict()["id"] == [2, 4, 1, 0, 5, 3]
+    assert df.sort(["l", "id"], desc=[True, False]).to_pydict()["id"] == [3, 5, 0, 1, 4, 2]
+
+
+def test_top_k_by_list():
+    df = daft.from_pydict({"l": [[3], [1, 1], [2], [1]], "id": [0, 1, 2, 3]})
+    assert df.sort("l", desc=True).limit(2).to_pydict()["id"] == [0, 2]
+
+
+def test_sort_merge_join_on_struct():
+    left = daft.from_pydict({"k": [{"a": 1, "b": "x"}, {"a": 2, "b": "y"}, None], "l": [0, 1, 2]})
+    right = daft.from_pydict({"k": [{"a": 2, "b": "y"}, {"a": 1, "b": "z"}, None], "r": [3, 4, 5]})
+    result = left.join(right, on="k", strategy="sort_merge").to_pydict()
+    assert result["l"] == [1]
+    assert result["r"] == [3]
@@ -0,0 +1,59 @@
+from __future__ import annotations
+
+import pytest
+
+import daft
+from daft.datatype import DataType
+from daft.expressions import col, lit
+from daft.functions import hamming_distance
+
+
+def test_repr_hamming():
+    assert repr(hamming_distance(col("a"), col("b"))) == "hamming_distance(col(a), col(b))"
+
+
+@pytest.mark.parametrize(
+    "dtype",
+    [DataType.uint8(), DataType.uint16(), DataType.uint32(), DataType.uint64(), DataType.int32(), DataType.int64()],
+)
+def test_hamming_distance_integers(dtype):
+    df = daft.from_pydict({"a": [0b1011, 7, 0, None], "b": [0b0001, 0, 0, 1]})
+    df = df.select(col("a").cast(dtype), col("b").cast(dtype))
+    res = df.select(hamming_distance("a", "b").alias("dist")).to_pydict()
+    assert res == {"dist": [2, 3, 0, None]}
+
+
+def test_hamming_distance_uint64_hashes():
+    df = daft.from_pydict({"a": [2**64 - 1, 2**63]}).select(col("a").cast(DataType.uint64()))
+    res = df.select(hamming_distance(col("a"), lit(0).cast(DataType.uint64())).alias("dist")).to_pydict()
+    assert res == {"dist": [64, 1]}
+
+
+def test_hamming_distance_negative_integers():
+    df = daft.from_pydict({"a": [-1, -2], "b": [0, -1]})
+    res = df.select(hamming_distance("a", "b").alias("dist")).to_pydict()
+    assert res == {"dist": [64, 1]}
+
+
+def test_hamming_distance_binary():
+    df = daft.from_pydict({"a": [b"\x00\xff", b"abc", None], "b": [b"\x01\x0f", b"abc", b"x"]})
+    res = df.select(hamming_distance("a", "b").alias("dist")).to_pydict()
+    assert res == {"dist": [5, 0, None]}
+
+
+def test_hamming_distance_binary_length_mismatch():
+    df = daft.from_pydict({"a": [b"ab"], "b": [b"abc"]})
+    with pytest.raises(Exception, match="same number of bytes"):
+        df.select(hamming_distance("a", "b")).collect()
+
+
+def test_hamming_distance_mixed_kinds():
+    df = daft.from_pydict({"a": [1], "b": [b"a"]})
+    with pytest.raises(Exception, match="both be integers or both be binary"):
+        df.select(hamming_distance("a", "b")).collect()
+
+
+def test_hamming_distance_sql():
+    df = daft.from_pydict({"a": [0b1011, 7], "b": [0b0001, 0]})
+    res = daft.sql("select hamming_distance(a, b) as dist from df").to_pydict()
+    assert res == {"dist": [2, 3]}
@@ -0,0 +1,107 @@
+from __future__ import annotations
+
+import random
+
+import pytest
+
+import daft
+from daft import Series
+from daft.datatype import DataType
+from daft.expressions import col
+from daft.functions import build_ivf_index, ivf_search, knn, semantic_join
+
+DTYPE = DataType.embedding(DataType.float32(), 4)
+
+
+def _random_embeddings(n, seed=0):
+    rng = random.Random(seed)
+    return [[rng.uniform(-1, 1) for _ in range(4)] for _ in range(n)]
+
+
+@pytest.fixture
+def index_path(tmp_path):
+    return str(tmp_path / "index.ivf")
+
+
+@pytest.mark.parametrize("metric", ["cosine", "dot", "euclidean", "manhattan"])
+def test_ivf_search_probing_all_lists_matches_knn(index_path, metric):
+    reference = Series.from_pylist(_random_embeddings(200)).cast(DTYPE)
+    build_ivf_index(reference, index_path, nlist=10, metric=metric)
+
+    df = daft.from_pydict({"q": _random_embeddings(20, seed=1)}).select(col("q").cast(DTYPE))
+    res = df.select(
+        ivf_search(col("q"), index_path, k=5, nprobe=10).alias("ann"),
+        knn(col("q"), reference, k=5, metric=metric).alias("exact"),
+    ).to_pydict()
+
+    for ann, exact in zip(res["ann"], res["exact"]):
+        assert [n["id"] for n in ann] == [n["id"] for n in exact]
+        assert [n["score"] for n in ann] == [pytest.approx(n["score"], rel=1e-4, abs=1e-5) for n in exact]
+
+
+def test_ivf_search_recall_increases_with_nprobe(index_path):
+    reference = Series.from_pylist(_random_embeddings(500)).cast(DTYPE)
+    build_ivf_index(reference, index_path, nlist=20, metric="euclidean")
+
+    df = daft.from_pydict({"q": _random_embeddings(50, seed=1)}).select(col("q").cast(DTYPE))
+    exact = df.select(knn(col("q"), reference, k=10, metric="euclidean")).to_pydict()["q"]
+
+    def recall(nprobe):
+        ann = df.select(ivf_search(col("q"), index_path, k=10, nprobe=nprobe)).to_pydict()["q"]
+        hits = sum(len({n["id"] for n in a} & {n["id"] for n in e}) for a, e in zip(ann, exact))
+        return hits / sum(len(e) for e in exact)
+
+    assert recall(1) <= recall(5) <= recall(20) == 1.0
+
+
+def test_ivf_search_with_ids_and_nulls(index_path):
+    reference = Series.from_pylist([[1, 0, 0, 0], None, [0, 1, 0, 0]]).cast(DTYPE)
+    build_ivf_index(reference, index_path, ids=Series.from_pylist([100, 101, 102]), metric="euclidean")
+
+    df = daft.from_pydict({"q": [[0, 0.9, 0, 0], None]}).select(col("q").cast(DTYPE))
+    res = df.select(ivf_search(col("q"), index_path, k=5)).to_pydict()["q"]
+    assert [n["id"] for n in res[0]] == [102, 100]
+    assert res[1] is None
+
+
+def test_ivf_search_size_mismatch(index_path):
+    build_ivf_index(Series.from_pylist([[1, 0, 0, 0]]).cast(DTYPE), index_path)
+
+    df = daft.from_pydict({"q": [[1, 0]]}).select(col("q").cast(DataType.embedding(DataType.float32(), 2)))
+    with pytest.raises(Exception, match="same size as the index"):
+        df.select(ivf_search(col("q"), index_path, k=1)).collect()
+
+
+def test_build_ivf_index_rejects_hamming(index_path):
+    with pytest.raises(Exception, match="hamming metric is not supported"):
+        build_ivf_index(Series.from_pylist([[1, 0, 0, 0]]).cast(DTYPE), index_path, metric="hamming")
+
+
+def test_semantic_join(index_path):
+    docs = daft.from_pydict(
+        {
+            "doc": ["north", "east", "south"],
+            "e": [[0, 1, 0, 0], [1, 0, 0, 0], [0, -1, 0, 0]],
+        }
+    ).with_column("e", col("e").cast(DTYPE))
+    queries = daft.from_pydict(
+        {
+            "q": ["up", "right", "none"],
+            "qe": [[0, 2, 0.1, 0], [3, 0.2, 0, 0], None],
+        }
+    ).with_column("qe", col("qe").cast(DTYPE))
+
+    res = semantic_join(queries, docs, "qe", "e", index_path, k=1).select("q", "doc", "score").sort("q").to_pydict()
+    assert res["q"] == ["right", "up"]
+    assert res["doc"] == ["east", "north"]
+    assert all(score < 0.01 for score in res["score"])
+
+
+def test_semantic_join_k(index_path):
+    docs = daft.from_pydict({"doc": ["a", "b", "c"], "e": [[1, 0, 0, 0], [1, 1, 0, 0], [-1, 0, 0, 0]]})
+    docs = docs.with_column("e", col("e").cast(DTYPE))
+    queries = daft.from_pydict({"q": ["x"], "qe": [[1, 0, 0, 0]]}).with_column("qe", col("qe").cast(DTYPE))
+
+    res = semantic_join(queries, docs, "qe", "e", index_path, k=2, metric="euclidean").sort("score").to_pydict()
+    assert res["doc"] == ["a", "b"]
+    assert res["score"] == [pytest.approx(0.0), pytest.approx(1.0)]
@@ -0,0 +1,162 @@
+from __future__ import annotations
+
+import math
+
+import pytest
+
+import daft
+from daft import Series
+from daft.datatype import DataType
+from daft.expressions import col, lit
+from daft.functions import knn
+
+SOURCE = [[1.0, 2.0, 3.0], [0.0, 0.0, 0.0], [-1.5, 4.0, 2.0], None]
+QUERY = [0.5, -1.0, 2.0]
+
+
+def _dot(x, y):
+    return sum(xi * yi for xi, yi in zip(x, y))
+
+
+def _euclidean(x, y):
+    return math.sqrt(sum((xi - yi) ** 2 for xi, yi in zip(x, y)))
+
+
+def _manhattan(x, y):
+    return sum(abs(xi - yi) for xi, yi in zip(x, y))
+
+
+@pytest.mark.parametrize(
+    ["method", "brute_force"],
+    [
+        ("dot_product", _dot),
+        ("euclidean_distance", _euclidean),
+        ("manhattan_distance", _manhattan),
+    ],
+)
+@pytest.mark.parametrize("inner_dtype", [DataType.float32(), DataType.float64()])
+@pytest.mark.parametrize("outer", [DataType.fixed_size_list, DataType.embedding])
+def test_distance_with_literal_query(method, brute_force, inner_dtype, outer):
+    dtype = outer(inner_dtype, 3)
+    df = daft.from_pydict({"source": SOURCE})
+    df = df.select(col("source").cast(dtype))
+    res = df.select(getattr(col("source").embedding, method)(lit(QUERY).cast(dtype))).to_pydict()
+
+    expected = [brute_force(x, QUERY) if x is not None else None for x in SOURCE]
+    assert res["source"] == [pytest.approx(e) if e is not None else None for e in expected]
+
+
+@pytest.mark.parametrize("method", ["dot_product", "euclidean_distance", "manhattan_distance"])
+def test_distance_pairwise_int8(method):
+    dtype = DataType.embedding(DataType.int8(), 2)
+    df = daft.from_pydict({"a": [[100, 100], [-128, 127]], "b": [[100, 100], [127, -128]]})
+    res = df.select(getattr(col("a").cast(dtype).embedding, method)(col("b").cast(dtype))).to_pydict()["a"]
+
+    brute_force = {"dot_product": _dot, "euclidean_distance": _euclidean, "manhattan_distance": _manhattan}[method]
+    assert res == [
+        pytest.approx(brute_force([100, 100], [100, 100])),
+        pytest.approx(brute_force([-128, 127], [127, -128])),
+    ]
+
+
+def test_distance_lengths_unrolled():
+    # longer than a single SIMD lane to exercise both the lanes and the tail.
+    size = 21
+    a = [float(i) for i in range(size)]
+    b = [float(size - i) for i in range(size)]
+    dtype = DataType.fixed_size_list(DataType.float64(), size)
+    df = daft.from_pydict({"a": [a], "b": [b]}).select(col("a").cast(dtype), col("b").cast(dtype))
+    res = df.select(
+        col("a").embedding.dot_product(col("b")).alias("dot"),
+        col("a").embedding.euclidean_distance(col("b")).alias("l2"),
+        col("a").embedding.manhattan_distance(col("b")).alias("l1"),
+    ).to_pydict()
+    assert res["dot"] == [pytest.approx(_dot(a, b))]
+    assert res["l2"] == [pytest.approx(_euclidean(a, b))]
+    assert res["l1"] == [_manhattan(a, b)]
+
+
+def test_hamming_distance_embeddings():
+    df = daft.from_pydict(
+        {
+            "bits": [[0b1011, 255], [1, 2], None],
+            "floats": [[1.0, 2.0, 3.0], [1.0, 2.0, 3.0], [0.0, 0.0, 0.0]],
+        }
+    )
+    bits = col("bits").cast(DataType.fixed_size_list(DataType.uint8(), 2))
+    floats = col("floats").cast(DataType.embedding(DataType.float32(), 3))
+    res = df.select(
+        bits.embedding.hamming_distance(lit([0b0001, 255]).cast(DataType.fixed_size_list(DataType.uint8(), 2))),
+        floats.embedding.hamming_distance(lit([1.0, 2.5, 3.5]).cast(DataType.embedding(DataType.float32(), 3))),
+    ).to_pydict()
+    assert res == {"bits": [2, 7, None], "floats": [2, 2, 3]}
+
+
+@pytest.mark.parametrize("method", ["dot_product", "euclidean_distance", "manhattan_distance", "hamming_distance"])
+def test_distance_size_mismatch(method):
+    df = daft.from_pydict({"a": [[1, 2, 3]], "b": [[1, 2, 3, 4]]})
+    df = df.select(
+        col("a").cast(DataType.embedding(DataType.float32(), 3)),
+        col("b").cast(DataType.embedding(DataType.float32(), 4)),
+    )
+    with pytest.raises(ValueError, match=f"Expected inputs to '{method}' to have the same size"):
+        df.select(getattr(col("a").embedding, method)(col("b")))
+
+
+@pytest.mark.parametrize("method", ["dot_product", "euclidean_distance", "manhattan_distance"])
+def test_distance_not_fixed_size(method):
+    df = daft.from_pydict({"a": [[1, 2, 3]]})
+    with pytest.raises(ValueError, match=f"Expected inputs to '{method}' to be fixed size list or embedding"):
+        df.select(getattr(col("a").embedding, method)(col("a")))
+
+
+REFERENCE = [[0.0, 0.0], [1.0, 1.0], None, [5.0, 5.0], [1.0, 0.0]]
+
+
+@pytest.mark.parametrize(
+    ["metric", "expected"],
+    [
+        ("euclidean", [[(0, 0.0), (4, 1.0)], [(3, math.sqrt(2)), (1, math.sqrt(18))]]),
+        ("manhattan", [[(0, 0.0), (4, 1.0)], [(3, 2.0), (1, 6.0)]]),
+        ("dot", [[(0, 0.0), (1, 0.0)], [(3, 40.0), (1, 8.0)]]),
+    ],
+)
+def test_knn(metric, expected):
+    dtype = DataType.fixed_size_list(DataType.float32(), 2)
+    reference = Series.from_pylist(REFERENCE).cast(dtype)
+    df = daft.from_pydict({"q": [[0.0, 0.0], [4.0, 4.0], None]})
+    res = df.select(knn(col("q").cast(dtype), reference, k=2, metric=metric)).to_pydict()["q"]
+
+    assert res[2] is None
+    for neighbours, expected_neighbours in zip(res[:2], expected):
+        assert [n["id"] for n in neighbours] == [id for id, _ in expected_neighbours]
+        assert [n["score"] for n in neighbours] == [pytest.approx(score) for _, score in expected_neighbours]
+
+
+def test_knn_cosine_skips_zero_vectors():
+    dtype = DataType.embedding(DataType.float64(), 2)
+    reference = Series.from_pylist(REFERENCE).cast(dtype)
+    df = daft.from_pydict({"q": [[2.0, 2.0]]})
+    res = df.select(knn(col("q").cast(dtype), reference, k=10)).to_pydict()["q"]
+
+    # the zero vector has no cosine distance, and the null reference is skipped.
+    assert [n["id"] for n in res[0]] == [1, 3, 4]
+    assert res[0][0]["score"] == pytest.approx(0.0)
+    assert res[0][2]["score"] == pytest.approx(1 - 1 / math.sqrt(2))
+
+
+def test_knn_schema():
+    dtype = DataType.fixed_size_list(DataType.float32(), 2)
+    reference = Series.from_pylist(REFERENCE).cast(dtype)
+    df = daft.from_pydict({"q": [[0.0, 0.0]]})
+    df = df.select(knn(col("q").cast(dtype), reference, k=1))
+    neighbour = DataType.struct({"id": DataType.uint64(), "score": DataType.float64()})
+    assert df.schema()["q"].dtype == DataType.list(neighbour)
+
+
+def test_knn_invalid_metric():
+    dtype = DataType.fixed_size_list(DataType.float32(), 2)
+    reference = Series.from_pylist(REFERENCE).cast(dtype)
+    df = daft.from_pydict({"q": [[0.0, 0.0]]})
+    with pytest.raises(ValueError, match="Invalid metric"):
+        df.select(knn(col("q").cast(dtype), reference, k=1, metric="chebyshev"))
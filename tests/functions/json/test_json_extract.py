@@ -0,0 +1,106 @@
+from __future__ import annotations
+
+import pytest
+
+import daft
+from daft import Series, col
+from daft.datatype import DataType
+from daft.functions import infer_json_dtype
+
+DOCUMENTS = [
+    '{"id": 1, "user": {"name": "a", "tags": ["x", "y"]}, "items": [{"sku": "p", "qty": 2}, {"sku": "q"}]}',
+    '{"id": 2.5, "user": {"name": "b", "age": 30}, "items": []}',
+    None,
+    '{"id": "3", "user": "anonymous", "items": [{"sku": "r", "qty": 1}]}',
+]
+
+
+def _extract(path, dtype=None):
+    df = daft.from_pydict({"json": DOCUMENTS})
+    return df.select(col("json").json_extract(path, dtype)).to_pydict()["json"]
+
+
+def test_json_extract_as_json_strings():
+    assert _extract("$.id") == ["1", "2.5", None, '"3"']
+    assert _extract("$.user.tags") == ['["x","y"]', None, None, None]
+    assert _extract("$.missing") == [None, None, None, None]
+
+
+@pytest.mark.parametrize(
+    ["path", "dtype", "expected"],
+    [
+        ("$.id", DataType.int64(), [1, 2, None, None]),
+        ("$.id", DataType.float64(), [1.0, 2.5, None, None]),
+        ("$.id", DataType.string(), ["1", "2.5", None, "3"]),
+        ("user.name", DataType.string(), ["a", "b", None, None]),
+        ("$['user']['tags'][-1]", DataType.string(), ["y", None, None, None]),
+        ("$.user.age", DataType.int32(), [None, 30, None, None]),
+    ],
+)
+def test_json_extract_scalars(path, dtype, expected):
+    assert _extract(path, dtype) == expected
+
+
+def test_json_extract_struct_with_different_shapes():
+    dtype = DataType.struct(
+        {"name": DataType.string(), "age": DataType.int64(), "tags": DataType.list(DataType.string())}
+    )
+    assert _extract("$.user", dtype) == [
+        {"name": "a", "age": None, "tags": ["x", "y"]},
+        {"name": "b", "age": 30, "tags": None},
+        None,
+        None,
+    ]
+
+
+def test_json_extract_objects_as_strings():
+    # objects are kept as JSON text when extracted as strings, which is what conflicting shapes infer to.
+    assert _extract("$.user", DataType.string()) == [
+        '{"name":"a","tags":["x","y"]}',
+        '{"name":"b","age":30}',
+        None,
+        "anonymous",
+    ]
+
+
+@pytest.mark.parametrize(
+    ["path", "expected"],
+    [
+        ("$.items[*].sku", [["p", "q"], [], None, ["r"]]),
+        ("$..qty", [["2"], [], None, ["1"]]),
+        ("$.items[0:1].sku", [["p"], [], None, ["r"]]),
+        ("$.items[0]['sku', 'qty']", [["p", "2"], [], None, ["r", "1"]]),
+    ],
+)
+def test_json_extract_indefinite_paths(path, expected):
+    assert _extract(path, DataType.list(DataType.string())) == expected
+
+
+def test_json_extract_with_inferred_dtype():
+    values = Series.from_pylist(DOCUMENTS)
+    dtype = infer_json_dtype(values, "$.items")
+    assert dtype == DataType.list(DataType.struct({"sku": DataType.string(), "qty": DataType.int64()}))
+    assert _extract("$.items", dtype) == [
+        [{"sku": "p", "qty": 2}, {"sku": "q", "qty": None}],
+        [],
+        None,
+        [{"sku": "r", "qty": 1}],
+    ]
+
+
+def test_infer_json_dtype():
+    values = Series.from_pylist(DOCUMENTS)
+    assert infer_json_dtype(values, "$.id", sample_size=2) == DataType.float64()
+    assert infer_json_dtype(values, "$.id") == DataType.string()
+    assert infer_json_dtype(values, "$.user", sample_size=2) == DataType.struct(
+        {"name": DataType.string(), "tags": DataType.list(DataType.string()), "age": DataType.int64()}
+    )
+    assert infer_json_dtype(values, "$.missing") == DataType.string()
+
+
+def test_json_extract_invalid():
+    df = daft.from_pydict({"json": DOCUMENTS})
+    with pytest.raises(ValueError, match="Invalid JSONPath"):
+        df.select(col("json").json_extract("$.items[0"))
+    with pytest.raises(Exception, match="Failed to parse JSON"):
+        daft.from_pydict({"json": ["{"]}).select(col("json").json_extract("$.a")).collect()
@@ -0,0 +1,227 @@
+from __future__ import annotations
+
+import struct
+
+import pytest
+
+import daft
+from daft import col
+from daft.datatype import DataType as dt
+
+PEOPLE_DTYPE = dt.struct(
+    {
+        "name": dt.string(),
+        "age": dt.int64(),
+        "scores": dt.list(dt.float64()),
+        "address": dt.struct({"city": dt.string(), "zip": dt.int32()}),
+    }
+)
+
+PEOPLE = [
+    {"name": "Alice", "age": 30, "scores": [1.5, 2.5], "address": {"city": "Paris", "zip": 75001}},
+    None,
+    {"name": "Bob", "age": None, "scores": [], "address": None},
+]
+
+
+def roundtrip(items, dtype, format, **options):
+    df = daft.from_pydict({"value": daft.Series.from_pylist(items).cast(dtype)})
+    df = df.select(col("value").serialize(format, **options))
+    df = df.select(col("value").deserialize(format, dtype, **options))
+    return df.to_pydict()["value"]
+
+
+@pytest.mark.parametrize("format", ["msgpack", "cbor", "bson", "avro"])
+def test_roundtrip_struct(format):
+    assert roundtrip(PEOPLE, PEOPLE_DTYPE, format) == PEOPLE
+
+
+@pytest.mark.parametrize("format", ["msgpack", "cbor", "avro"])
+def test_roundtrip_list(format):
+    items = [[1, 2, 3], [], None, [None, -4]]
+    assert roundtrip(items, dt.list(dt.int64()), format) == items
+
+
+@pytest.mark.parametrize("format", ["msgpack", "cbor", "bson"])
+def test_roundtrip_map(format):
+    dtype = dt.struct({"tags": dt.map(dt.string(), dt.int64())})
+    items = [{"tags": [("a", 1), ("b", 2)]}, {"tags": []}]
+    assert roundtrip(items, dtype, format) == items
+
+
+def test_serialize_returns_binary():
+    df = daft.from_pydict({"value": [{"a": 1}]})
+    df = df.select(col("value").serialize("msgpack"))
+    assert df.schema()["value"].dtype == dt.binary()
+    assert df.to_pydict()["value"] == [b"\x81\xa1a\x01"]
+
+
+def test_deserialize_msgpack():
+    # {"a": 1, "b": "hi"}
+    df = daft.from_pydict({"value": [b"\x82\xa1a\x01\xa1b\xa2hi", None]})
+    df = df.select(col("value").deserialize("msgpack", dt.struct({"a": dt.int64(), "b": dt.string()})))
+    assert df.to_pydict()["value"] == [{"a": 1, "b": "hi"}, None]
+
+
+def test_deserialize_bson():
+    # {"a": 1}
+    document = b"\x0c\x00\x00\x00\x10a\x00\x01\x00\x00\x00\x00"
+    df = daft.from_pydict({"value": [document]})
+    df = df.select(col("value").deserialize("bson", dt.struct({"a": dt.int32()})))
+    assert df.to_pydict()["value"] == [{"a": 1}]
+
+
+def test_serialize_avro_single_object():
+    df = daft.from_pydict({"value": [1]})
+    df = df.select(col("value").serialize("avro", schema='"long"'))
+    # marker, CRC-64-AVRO fingerprint of "long" and the zigzag encoded value.
+    assert df.to_pydict()["value"] == [b"\xc3\x01" + struct.pack("<Q", 0xD054E14493F41DB7) + b"\x02"]
+
+
+def test_deserialize_avro_with_schema():
+    schema = """
+    {
+        "type": "record",
+        "name": "User",
+        "namespace": "com.example",
+        "fields": [
+            {"name": "name", "type": "string"},
+            {"name": "favorite_number", "type": ["null", "int"]}
+        ]
+    }
+    """
+    items = [{"name": "Alice", "favorite_number": 7}, {"name": "Bob", "favorite_number": None}]
+    dtype = dt.struct({"name": dt.string(), "favorite_number": dt.int32()})
+    assert roundtrip(items, dtype, "avro", schema=schema) == items
+
+
+def test_deserialize_avro_with_mismatched_schema():
+    df = daft.from_pydict({"value": [1]})
+    df = df.select(col("value").serialize("avro", schema='"long"'))
+    with pytest.raises(Exception, match="fingerprint"):
+        df.select(col("value").deserialize("avro", dt.int32())).collect()
+
+
+def _varint(value: int) -> bytes:
+    out = bytearray()
+    while value >= 0x80:
+        out.append((value & 0x7F) | 0x80)
+        value >>= 7
+    out.append(value)
+    return bytes(out)
+
+
+def _tag(number: int, wire_type: int) -> bytes:
+    return _varint(number << 3 | wire_type)
+
+
+def _len_field(number: int, payload: bytes | str) -> bytes:
+    if isinstance(payload, str):
+        payload = payload.encode()
+    return _tag(number, 2) + _varint(len(payload)) + payload
+
+
+def _int_field(number: int, value: int) -> bytes:
+    return _tag(number, 0) + _varint(value)
+
+
+def _field_descriptor(name: str, number: int, type: int, label: int = 1, type_name: str | None = None) -> bytes:
+    # FieldDescriptorProto: name = 1, number = 3, label = 4, type = 5, type_name = 6
+    field = _len_field(1, name) + _int_field(3, number) + _int_field(4, label) + _int_field(5, type)
+    if type_name is not None:
+        field += _len_field(6, type_name)
+    return field
+
+
+def _descriptor_set() -> bytes:
+    """Describes `message Person { string name = 1; int64 id = 2; repeated string emails = 3; Inner inner = 4; }`."""
+    inner = _len_field(1, "Inner") + _len_field(2, _field_descriptor("x", 1, type=13))
+    person = (
+        _len_field(1, "Person")
+        + _len_field(2, _field_descriptor("name", 1, type=9))
+        + _len_field(2, _field_descriptor("id", 2, type=3))
+        + _len_field(2, _field_descriptor("emails", 3, type=9, label=3))
+        + _len_field(2, _field_descriptor("inner", 4, type=11, type_name=".test.Inner"))
+    )
+    # FileDescriptorProto: name = 1, package = 2, message_type = 4, syntax = 12
+    file = _len_field(1, "person.proto") + _len_field(2, "test") + _len_field(4, person) + _len_field(4, inner)
+    file += _len_field(12, "proto3")
+    # FileDescriptorSet: file = 1
+    return _len_field(1, file)
+
+
+PERSON_DTYPE = dt.struct(
+    {
+        "name": dt.string(),
+        "id": dt.int64(),
+        "emails": dt.list(dt.string()),
+        "inner": dt.struct({"x": dt.uint32()}),
+    }
+)
+
+
+def test_serialize_protobuf():
+    df = daft.from_pydict({"value": [{"name": "Al", "id": 150}]})
+    df = df.select(col("value").serialize("protobuf", descriptor_set=_descriptor_set(), message="test.Person"))
+    assert df.to_pydict()["value"] == [b"\x0a\x02Al\x10\x96\x01"]
+
+
+def test_deserialize_protobuf_defaults():
+    df = daft.from_pydict({"value": [b"\x0a\x02Al\x10\x96\x01", b""]})
+    df = df.select(
+        col("value").deserialize("protobuf", PERSON_DTYPE, descriptor_set=_descriptor_set(), message="test.Person")
+    )
+    assert df.to_pydict()["value"] == [
+        {"name": "Al", "id": 150, "emails": [], "inner": None},
+        {"name": "", "id": 0, "emails": [], "inner": None},
+    ]
+
+
+def test_roundtrip_protobuf():
+    items = [
+        {"name": "Alice", "id": 1, "emails": ["a@x.com", "alice@y.org"], "inner": {"x": 7}},
+        {"name": "Bob", "id": -2, "emails": [], "inner": None},
+    ]
+    options = {"descriptor_set": _descriptor_set(), "message": "test.Person"}
+    assert roundtrip(items, PERSON_DTYPE, "protobuf", **options) == items
+
+
+def test_protobuf_requires_descriptor_set():
+    df = daft.from_pydict({"value": [{"name": "Al"}]})
+    with pytest.raises(Exception, match="descriptor_set"):
+        df.select(col("value").serialize("protobuf")).collect()
+
+
+def test_options_for_other_formats():
+    df = daft.from_pydict({"value": [{"name": "Al"}]})
+    with pytest.raises(Exception, match="only supported by the avro format"):
+        df.select(col("value").serialize("msgpack", schema='"long"')).collect()
+
+
+def test_deserialize_requires_binary():
+    df = daft.from_pydict({"value": ["abc"]})
+    with pytest.raises(Exception, match="must be of Binary type"):
+        df.select(col("value").deserialize("msgpack", dt.int64())).collect()
+
+
+@pytest.mark.parametrize("format", ["msgpack", "cbor", "bson"])
+def test_deserialize_invalid(format):
+    df = daft.from_pydict({"value": [b"\xc1\xff", None]})
+    with pytest.raises(Exception, match="Failed to parse"):
+        df.select(col("value").deserialize(format, dt.int64())).collect()
+
+
+@pytest.mark.parametrize("format", ["msgpack", "cbor", "bson"])
+def test_try_deserialize_invalid(format):
+    df = daft.from_pydict({"value": [b"\xc1\xff", None]})
+    df = df.select(col("value").try_deserialize(format, dt.int64()))
+    assert df.to_pydict()["value"] == [None, None]
+
+
+def test_try_deserialize_type_mismatch():
+    # 1, "a" and [1]
+    df = daft.from_pydict({"value": [b"\x01", b"\xa1a", b"\x91\x01"]})
+    with pytest.raises(Exception, match="Cannot deserialize"):
+        df.select(col("value").deserialize("msgpack", dt.int64())).collect()
+    df = df.select(col("value").try_deserialize("msgpack", dt.int64()))
+    assert df.to_pydict()["value"] == [1, None, None]
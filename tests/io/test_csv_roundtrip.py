@@ -8,6 +8,7 @@ import pytest
 
 import daft
 from daft import DataType, TimeUnit
+from tests.conftest import get_tests_daft_runner_name
 
 PYARROW_GE_11_0_0 = tuple(int(s) for s in pa.__version__.split(".") if s.isnumeric()) >= (11, 0, 0)
 
@@ -77,3 +78,15 @@ def test_roundtrip_simple_arrow_types(tmp_path, data, pa_type, expected_dtype, e
     assert before.schema()["foo"].dtype == expected_dtype
     assert after.schema()["foo"].dtype == expected_inferred_dtype
     assert before.to_arrow() == after.with_column("foo", after["foo"].cast(expected_dtype)).to_arrow()
+
+
+@pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="Compressed CSV writes are only implemented in the native runner"
+)
+@pytest.mark.parametrize("compression", ["gzip", "zstd", "snappy", "lz4", "bzip2", "xz"])
+def test_roundtrip_compressed(tmp_path, compression):
+    before = daft.from_pydict({"id": [1, 2, 3], "name": ["a", "b, c", None]})
+    paths = before.write_csv(str(tmp_path), compression=compression).to_pydict()["path"]
+    assert all(".csv." in path for path in paths)
+    after = daft.read_csv(str(tmp_path))
+    assert before.to_arrow() == after.sort("id").to_arrow()
@@ -202,3 +202,15 @@ def test_throws_error_on_duration_and_binary_types(tmp_path):
         match="Not Yet Implemented: JSON writes are not supported with extension, timezone with timestamp, binary, or duration data types",
     ):
         before_binary.write_json(str(tmp_path))
+
+
+@pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="JSON writes are only implemented in the native runner"
+)
+@pytest.mark.parametrize("compression", ["gzip", "zstd", "snappy", "lz4", "bzip2", "xz"])
+def test_roundtrip_compressed(tmp_path, compression):
+    before = daft.from_pydict({"id": [1, 2, 3], "name": ["a", "b", None]})
+    paths = before.write_json(str(tmp_path), compression=compression).to_pydict()["path"]
+    assert all(".json." in path for path in paths)
+    after = daft.read_json(str(tmp_path))
+    assert before.to_arrow() == after.sort("id").to_arrow()
@@ -0,0 +1,117 @@
+from __future__ import annotations
+
+import threading
+import time
+from http.server import BaseHTTPRequestHandler, ThreadingHTTPServer
+
+import pytest
+
+import daft
+from daft import col
+
+
+class _Handler(BaseHTTPRequestHandler):
+    def log_message(self, format, *args):
+        pass
+
+    def do_GET(self):
+        server = self.server
+        with server.lock:
+            server.requests.append((self.path, time.monotonic()))
+            server.active += 1
+            server.max_active = max(server.max_active, server.active)
+        try:
+            self._respond()
+        finally:
+            with server.lock:
+                server.active -= 1
+
+    def _respond(self):
+        if self.path.startswith("/data/"):
+            body = b"x" * int(self.path.removeprefix("/data/"))
+            time.sleep(0.1)
+            self.send_response(200)
+            self.send_header("Content-Length", str(len(body)))
+            self.send_header("X-Test", "daft")
+            self.end_headers()
+            self.wfile.write(body)
+        elif self.path == "/redirect":
+            self.send_response(302)
+            self.send_header("Location", "/data/3")
+            self.send_header("Content-Length", "0")
+            self.end_headers()
+        elif self.path == "/throttled" and sum(path == "/throttled" for path, _ in self.server.requests) == 1:
+            self.send_response(429)
+            self.send_header("Retry-After", "1")
+            self.send_header("Content-Length", "0")
+            self.end_headers()
+        elif self.path == "/throttled":
+            self.send_response(200)
+            self.send_header("Content-Length", "2")
+            self.end_headers()
+            self.wfile.write(b"ok")
+        else:
+            self.send_response(404)
+            self.send_header("Content-Length", "0")
+            self.end_headers()
+
+
+@pytest.fixture
+def server():
+    server = ThreadingHTTPServer(("127.0.0.1", 0), _Handler)
+    server.lock = threading.Lock()
+    server.requests = []
+    server.active = 0
+    server.max_active = 0
+    thread = threading.Thread(target=server.serve_forever, daemon=True)
+    thread.start()
+    yield server
+    server.shutdown()
+    thread.join()
+
+
+def _url(server, path):
+    return f"http://127.0.0.1:{server.server_address[1]}{path}"
+
+
+def _download(urls, **kwargs):
+    df = daft.from_pydict({"urls": urls})
+    return df.select(col("urls").url.download(**kwargs)).to_pydict()["urls"]
+
+
+def test_url_download_http_metadata(server):
+    results = _download([_url(server, "/redirect"), _url(server, "/missing"), None], return_metadata=True)
+    assert results[0]["content"] == b"xxx"
+    assert results[0]["status"] == 200
+    assert results[0]["url"] == _url(server, "/data/3")
+    assert ("x-test", "daft") in [(key.lower(), value) for key, value in results[0]["headers"]]
+    assert results[1]["content"] is None
+    assert results[1]["status"] == 404
+    assert results[2] is None
+
+
+def test_url_download_http_error_status(server):
+    with pytest.raises(FileNotFoundError):
+        _download([_url(server, "/missing")], max_bytes=10)
+    assert _download([_url(server, "/missing")], max_bytes=10, on_error="null") == [None]
+
+
+def test_url_download_http_max_bytes(server):
+    urls = [_url(server, "/data/5"), _url(server, "/data/20")]
+    with pytest.raises(Exception, match="larger than the limit"):
+        _download(urls, max_bytes=10)
+    assert _download(urls, max_bytes=10, on_error="null") == [b"x" * 5, None]
+
+
+def test_url_download_http_retry_after(server):
+    assert _download([_url(server, "/throttled")], max_connections_per_host=1) == [b"ok"]
+    (_, first), (_, second) = server.requests
+    assert second - first >= 1.0
+
+
+def test_url_download_http_per_host_limits(server):
+    urls = [_url(server, f"/data/{i}") for i in range(6)]
+    assert _download(urls, max_connections_per_host=1, host_delay=0.2) == [b"x" * i for i in range(6)]
+    assert server.max_active == 1
+    starts = sorted(start for _, start in server.requests)
+    assert all(b - a >= 0.2 for a, b in zip(starts, starts[1:]))
@@ -0,0 +1,70 @@
+from __future__ import annotations
+
+import numpy as np
+import pytest
+
+import daft
+from daft import DataType
+from daft.functions import hamming_distance
+from daft.recordbatch import MicroPartition
+
+HASHES = ["ahash", "dhash", "phash"]
+
+
+def _pattern(height, width, transpose=False):
+    v, u = np.meshgrid(np.arange(height) / height, np.arange(width) / width, indexing="ij")
+    if transpose:
+        u, v = v, u
+    values = 127.5 + 60.0 * np.sin(7.0 * u) * np.cos(3.0 * v + 1.0) + 60.0 * np.sin(5.0 * u * v)
+    return values.astype(np.uint8)[..., np.newaxis]
+
+
+def _hash(arrs, method):
+    s = daft.Series.from_pylist(arrs, dtype=DataType.python()).cast(DataType.image("L"))
+    table = MicroPartition.from_pydict({"images": s})
+    result = table.eval_expression_list([getattr(daft.col("images").image, method)()])
+    assert result.schema()["images"].dtype == DataType.uint64()
+    return result.to_pydict()["images"]
+
+
+@pytest.mark.parametrize("method", HASHES)
+def test_perceptual_hash_dtype_and_nulls(mixed_shape_data_fixture, method):
+    table = MicroPartition.from_pydict({"images": mixed_shape_data_fixture})
+    result = table.eval_expression_list([getattr(daft.col("images").image, method)()])
+    assert result.schema()["images"].dtype == DataType.uint64()
+    hashes = result.to_pydict()["images"]
+    assert hashes[0] is not None and hashes[1] is not None and hashes[2] is None
+
+
+def test_dhash_gradients():
+    horizontal = np.tile(np.linspace(0, 255, 64).astype(np.uint8), (64, 1))[..., np.newaxis]
+    vertical = np.ascontiguousarray(horizontal.transpose(1, 0, 2))
+    assert _hash([horizontal, vertical], "dhash") == [2**64 - 1, 0]
+
+
+def test_ahash_constant_image():
+    assert _hash([np.full((16, 16, 1), 7, dtype=np.uint8)], "ahash") == [0]
+
+
+@pytest.mark.parametrize("method", HASHES)
+def test_perceptual_hash_near_duplicates(method):
+    original, rescaled, different = _hash(
+        [_pattern(64, 64), _pattern(96, 128), _pattern(64, 64, transpose=True)],
+        method,
+    )
+    df = daft.from_pydict({"a": [original, original], "b": [rescaled, different]})
+    df = df.select(daft.col("a").cast(DataType.uint64()), daft.col("b").cast(DataType.uint64()))
+    near, far = df.select(hamming_distance("a", "b").alias("dist")).to_pydict()["dist"]
+    assert near < far
+
+
+def test_perceptual_hash_sql(fixed_shape_data_fixture):
+    df = daft.from_pydict({"images": fixed_shape_data_fixture})
+    actual = daft.sql(
+        """
+        select image_phash(images) as h, hamming_distance(image_phash(images), image_phash(images)) as d
+        from df
+        """
+    ).to_pydict()
+    assert actual["d"] == [0, 0, None]
+    assert actual["h"][0] == actual["h"][1]
@@ -0,0 +1,202 @@
+from __future__ import annotations
+
+import numpy as np
+import pytest
+
+import daft
+from daft import DataType
+from daft.recordbatch import MicroPartition
+from daft.sql import SQLCatalog
+
+
+def _eval(data, expr):
+    table = MicroPartition.from_pydict({"images": data})
+    return table.eval_expression_list([expr])
+
+
+def _apply(data, fn):
+    return [fn(arr) if arr is not None else None for arr in data.to_pylist()]
+
+
+def _has_alpha(arr):
+    return arr.shape[2] in (2, 4)
+
+
+@pytest.mark.parametrize(
+    "degrees,k",
+    [(90, -1), (180, 2), (270, 1), (-90, 1), (360, 0)],
+)
+def test_image_rotate_mixed_shape(mixed_shape_data_fixture, degrees, k):
+    result = _eval(mixed_shape_data_fixture, daft.col("images").image.rotate(degrees))
+    assert result.schema()["images"].dtype == mixed_shape_data_fixture.datatype()
+    expected = _apply(mixed_shape_data_fixture, lambda arr: np.rot90(arr, k=k))
+    np.testing.assert_equal(result.to_pydict()["images"], expected)
+
+
+def test_image_rotate_fixed_shape_swaps_dimensions(fixed_shape_data_fixture):
+    result = _eval(fixed_shape_data_fixture, daft.col("images").image.rotate(90))
+    mode = fixed_shape_data_fixture.datatype().image_mode
+    assert result.schema()["images"].dtype == DataType.image(mode, 4, 3)
+    expected = _apply(fixed_shape_data_fixture, lambda arr: np.rot90(arr, k=-1))
+    np.testing.assert_equal(result.to_pydict()["images"], expected)
+
+
+def test_image_rotate_rejects_non_right_angles(fixed_shape_data_fixture):
+    with pytest.raises(Exception, match="multiple of 90 degrees"):
+        _eval(fixed_shape_data_fixture, daft.col("images").image.rotate(45))
+
+
+def test_image_flip(mixed_shape_data_fixture):
+    result = _eval(mixed_shape_data_fixture, daft.col("images").image.flip_horizontal())
+    np.testing.assert_equal(result.to_pydict()["images"], _apply(mixed_shape_data_fixture, lambda arr: arr[:, ::-1]))
+
+    result = _eval(mixed_shape_data_fixture, daft.col("images").image.flip_vertical())
+    np.testing.assert_equal(result.to_pydict()["images"], _apply(mixed_shape_data_fixture, lambda arr: arr[::-1]))
+
+
+def test_image_center_crop_fixed_shape(fixed_shape_data_fixture):
+    result = _eval(fixed_shape_data_fixture, daft.col("images").image.center_crop(2, 2))
+    mode = fixed_shape_data_fixture.datatype().image_mode
+    assert result.schema()["images"].dtype == DataType.image(mode, 2, 2)
+    # 3x4 images: the 2x2 crop starts at x = 1, y = 0.
+    expected = _apply(fixed_shape_data_fixture, lambda arr: arr[0:2, 1:3])
+    np.testing.assert_equal(result.to_pydict()["images"], expected)
+
+
+def test_image_center_crop_larger_than_image(mixed_shape_data_fixture):
+    result = _eval(mixed_shape_data_fixture, daft.col("images").image.center_crop(100, 100))
+    np.testing.assert_equal(result.to_pydict()["images"], mixed_shape_data_fixture.to_pylist())
+
+
+@pytest.mark.parametrize("fill", [0, 255])
+def test_image_pad_to_aspect_fixed_shape(fixed_shape_data_fixture, fill):
+    result = _eval(fixed_shape_data_fixture, daft.col("images").image.pad_to_aspect(1.0, fill=fill))
+    mode = fixed_shape_data_fixture.datatype().image_mode
+    assert result.schema()["images"].dtype == DataType.image(mode, 4, 4)
+
+    def pad(arr):
+        padded = np.full((4, 4, arr.shape[2]), fill, dtype=arr.dtype)
+        padded[0:3] = arr
+        return padded
+
+    np.testing.assert_equal(result.to_pydict()["images"], _apply(fixed_shape_data_fixture, pad))
+
+
+def test_image_pad_to_aspect_wide(mixed_shape_data_fixture):
+    result = _eval(mixed_shape_data_fixture, daft.col("images").image.pad_to_aspect(2.0))
+    shapes = [arr.shape[:2] if arr is not None else None for arr in result.to_pydict()["images"]]
+    # 2x3 pads to 2x4 and 3x4 pads to 3x6.
+    assert shapes == [(2, 4), (3, 6), None]
+
+
+def test_image_gaussian_blur_preserves_shape(fixed_shape_data_fixture):
+    result = _eval(fixed_shape_data_fixture, daft.col("images").image.gaussian_blur(1.0))
+    assert result.schema()["images"].dtype == fixed_shape_data_fixture.datatype()
+    for actual, original in zip(result.to_pydict()["images"], fixed_shape_data_fixture.to_pylist()):
+        if original is None:
+            assert actual is None
+        else:
+            assert actual.shape == original.shape
+
+
+def test_image_adjust_brightness(mixed_shape_data_fixture):
+    result = _eval(mixed_shape_data_fixture, daft.col("images").image.adjust_brightness(1.0))
+    np.testing.assert_equal(result.to_pydict()["images"], mixed_shape_data_fixture.to_pylist())
+
+    result = _eval(mixed_shape_data_fixture, daft.col("images").image.adjust_brightness(0.0))
+
+    def darken(arr):
+        expected = np.zeros_like(arr)
+        if _has_alpha(arr):
+            expected[..., -1] = arr[..., -1]
+        return expected
+
+    np.testing.assert_equal(result.to_pydict()["images"], _apply(mixed_shape_data_fixture, darken))
+
+
+def test_image_adjust_brightness_saturates(mixed_shape_data_fixture):
+    result = _eval(mixed_shape_data_fixture, daft.col("images").image.adjust_brightness(100.0))
+
+    def brighten(arr):
+        expected = np.clip(arr.astype(np.float32) * 100.0, 0, 255).astype(arr.dtype)
+        if _has_alpha(arr):
+            expected[..., -1] = arr[..., -1]
+        return expected
+
+    np.testing.assert_equal(result.to_pydict()["images"], _apply(mixed_shape_data_fixture, brighten))
+
+
+def test_image_adjust_contrast(mixed_shape_data_fixture):
+    result = _eval(mixed_shape_data_fixture, daft.col("images").image.adjust_contrast(1.0))
+    np.testing.assert_equal(result.to_pydict()["images"], mixed_shape_data_fixture.to_pylist())
+
+    result = _eval(mixed_shape_data_fixture, daft.col("images").image.adjust_contrast(0.0))
+    for actual in result.to_pydict()["images"]:
+        if actual is not None:
+            # Every color channel collapses to the mean gray level.
+            num_color_channels = actual.shape[2] - 1 if _has_alpha(actual) else actual.shape[2]
+            assert len(np.unique(actual[..., :num_color_channels])) == 1
+
+
+def test_image_thumbnail(mixed_shape_data_fixture):
+    result = _eval(mixed_shape_data_fixture, daft.col("images").image.thumbnail(2, 2))
+    shapes = [arr.shape[:2] if arr is not None else None for arr in result.to_pydict()["images"]]
+    # 2x3 scales by 2/3 and 3x4 scales by 1/2, preserving the aspect ratio.
+    assert shapes == [(1, 2), (1, 2), None]
+
+    result = _eval(mixed_shape_data_fixture, daft.col("images").image.thumbnail(10, 10))
+    np.testing.assert_equal(result.to_pydict()["images"], mixed_shape_data_fixture.to_pylist())
+
+
+def test_image_normalize_fixed_shape(fixed_shape_data_fixture):
+    num_channels = fixed_shape_data_fixture.to_pylist()[0].shape[2]
+    mean = [0.1 * (i + 1) for i in range(num_channels)]
+    std = [0.5] * num_channels
+
+    result = _eval(fixed_shape_data_fixture, daft.col("images").image.normalize(mean, std))
+    assert result.schema()["images"].dtype == DataType.tensor(DataType.float32(), (3, 4, num_channels))
+
+    expected = _apply(
+        fixed_shape_data_fixture,
+        lambda arr: ((arr.astype(np.float32) / 255.0 - np.array(mean, dtype=np.float32)) / np.float32(0.5)),
+    )
+    for actual, exp in zip(result.to_pydict()["images"], expected):
+        if exp is None:
+            assert actual is None
+        else:
+            np.testing.assert_allclose(actual, exp, rtol=1e-5, atol=1e-6)
+
+
+def test_image_normalize_mixed_shape_scalar_params(mixed_shape_data_fixture):
+    result = _eval(mixed_shape_data_fixture, daft.col("images").image.normalize(0.5, 0.25))
+    assert result.schema()["images"].dtype == DataType.tensor(DataType.float32())
+
+    expected = _apply(mixed_shape_data_fixture, lambda arr: (arr.astype(np.float32) / 255.0 - 0.5) / 0.25)
+    for actual, exp in zip(result.to_pydict()["images"], expected):
+        if exp is None:
+            assert actual is None
+        else:
+            np.testing.assert_allclose(actual, exp, rtol=1e-5, atol=1e-6)
+
+
+def test_image_normalize_wrong_number_of_channels(fixed_shape_data_fixture):
+    with pytest.raises(Exception, match="values to normalize an image"):
+        _eval(fixed_shape_data_fixture, daft.col("images").image.normalize([0.1] * 5, [0.5] * 5))
+
+
+def test_image_transforms_sql(fixed_shape_data_fixture):
+    catalog = SQLCatalog({"df": daft.from_pydict({"images": fixed_shape_data_fixture})})
+    actual = daft.sql(
+        """
+        select
+            image_rotate(images, 180) as rotated,
+            image_flip_horizontal(image_flip_vertical(images)) as flipped,
+            image_center_crop(images, 2, 2) as cropped,
+            image_thumbnail(images, 2, 2) as thumbnail
+        from df
+        """,
+        catalog,
+    ).to_pydict()
+    np.testing.assert_equal(actual["rotated"], actual["flipped"])
+    assert [arr.shape[:2] if arr is not None else None for arr in actual["cropped"]] == [(2, 2), (2, 2), None]
+    assert [arr.shape[:2] if arr is not None else None for arr in actual["thumbnail"]] == [(1, 2), (1, 2), None]
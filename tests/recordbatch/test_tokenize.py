@@ -216,3 +216,103 @@ def test_tokenize_builtin_tokens_disabled(encoding, end_token):
     s = daft.from_pydict({"a": test_data})
     a = s.select(col("a").str.tokenize_encode(encoding, use_special_tokens=False)).to_pydict()["a"]
     assert len(a[0]) >= 1 and a[0][0] != end_token
+
+
+WORDPIECE_TOKENIZER = "tests/assets/tokens/wordpiece_tokenizer.json"
+BPE_TOKENIZER = "tests/assets/tokens/bpe_tokenizer.json"
+UNIGRAM_TOKENIZER = "tests/assets/tokens/unigram_tokenizer.json"
+
+
+@pytest.mark.parametrize(
+    ["tokens_path", "test_data", "expected"],
+    [
+        (
+            WORDPIECE_TOKENIZER,
+            ["Hello, World!", "unaffable", "héllo xyz", None],
+            [[2, 4, 10, 5, 9, 3], [2, 6, 7, 8, 3], [2, 4, 1, 3], None],
+        ),
+        (BPE_TOKENIZER, ["hello world", "hello<|endoftext|>", "held"], [[11, 16], [11, 17], [8, 15]]),
+        # the tokenizer file truncates to 3 tokens
+        (UNIGRAM_TOKENIZER, ["hello world", "hex", "hello world hello world"], [[2, 3], [8, 0], [2, 3, 2]]),
+    ],
+)
+def test_tokenize_huggingface_encode(tokens_path, test_data, expected):
+    s = daft.from_pydict({"a": test_data})
+    a = s.select(col("a").str.tokenize_encode(tokens_path)).to_pydict()["a"]
+    assert a == expected
+
+
+def test_tokenize_huggingface_encode_without_special_tokens():
+    s = daft.from_pydict({"a": ["hello [SEP] world"]})
+    a = s.select(col("a").str.tokenize_encode(WORDPIECE_TOKENIZER, use_special_tokens=False)).to_pydict()["a"]
+    assert a == [[4, 3, 5]]
+
+
+def test_tokenize_huggingface_truncation():
+    s = daft.from_pydict({"a": ["hello world the"]})
+    a = s.select(col("a").str.tokenize_encode(WORDPIECE_TOKENIZER, max_length=4)).to_pydict()["a"]
+    assert a == [[2, 4, 5, 3]]
+
+
+def test_tokenize_huggingface_padding():
+    s = daft.from_pydict({"a": ["hello", None, "hello world"]})
+    a = s.select(
+        col("a").str.tokenize_encode(WORDPIECE_TOKENIZER, padding="longest", return_attention_mask=True)
+    ).to_pydict()["a"]
+    assert a == [
+        {"input_ids": [2, 4, 3, 0], "attention_mask": [1, 1, 1, 0]},
+        None,
+        {"input_ids": [2, 4, 5, 3], "attention_mask": [1, 1, 1, 1]},
+    ]
+
+    a = s.select(
+        col("a").str.tokenize_encode(WORDPIECE_TOKENIZER, padding="max_length", max_length=6, pad_token_id=1)
+    ).to_pydict()["a"]
+    assert a == [[2, 4, 3, 1, 1, 1], None, [2, 4, 5, 3, 1, 1]]
+
+
+def test_tokenize_huggingface_padding_from_file():
+    # the tokenizer file pads on the left with <|endoftext|>
+    s = daft.from_pydict({"a": ["hello", "hello world"]})
+    a = s.select(col("a").str.tokenize_encode(BPE_TOKENIZER, return_attention_mask=True)).to_pydict()["a"]
+    assert a == [
+        {"input_ids": [17, 11], "attention_mask": [0, 1]},
+        {"input_ids": [11, 16], "attention_mask": [1, 1]},
+    ]
+
+
+def test_tokenize_tiktoken_padding():
+    s = daft.from_pydict({"a": ["hello", "hello world"]})
+    a = s.select(col("a").str.tokenize_encode("cl100k_base", padding="longest", pad_token_id=5)).to_pydict()["a"]
+    assert a == [[15339, 5], [15339, 1917]]
+
+    with pytest.raises(DaftCoreException, match="pad_token_id must be provided"):
+        s.select(col("a").str.tokenize_encode("cl100k_base", padding="longest")).collect()
+
+
+@pytest.mark.parametrize(
+    ["tokens_path", "tokens", "skip_special_tokens", "expected"],
+    [
+        (WORDPIECE_TOKENIZER, [2, 4, 10, 5, 9, 3], True, "hello, world!"),
+        (WORDPIECE_TOKENIZER, [2, 6, 7, 8, 3], False, "[CLS] unaffable [SEP]"),
+        (BPE_TOKENIZER, [11, 16, 17], False, "hello world<|endoftext|>"),
+        (BPE_TOKENIZER, [11, 16, 17], True, "hello world"),
+        (UNIGRAM_TOKENIZER, [2, 3], False, "hello world"),
+    ],
+)
+def test_tokenize_huggingface_decode(tokens_path, tokens, skip_special_tokens, expected):
+    s = daft.from_pydict({"a": [tokens]})
+    a = s.select(col("a").str.tokenize_decode(tokens_path, skip_special_tokens=skip_special_tokens)).to_pydict()["a"]
+    assert a == [expected]
+
+
+def test_tokenize_huggingface_decode_bad_token():
+    s = daft.from_pydict({"a": [[2, 100]]})
+    with pytest.raises(DaftCoreException, match="Input has bad token 100"):
+        s.select(col("a").str.tokenize_decode(WORDPIECE_TOKENIZER)).collect()
+
+
+def test_tokenize_huggingface_rejects_pattern():
+    s = daft.from_pydict({"a": ["hello"]})
+    with pytest.raises(DaftCoreException, match="pattern and special_tokens are not supported"):
+        s.select(col("a").str.tokenize_encode(WORDPIECE_TOKENIZER, pattern=P50K_REGEX)).collect()
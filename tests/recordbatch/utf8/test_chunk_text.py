@@ -0,0 +1,83 @@
+from __future__ import annotations
+
+import pytest
+
+from daft.exceptions import DaftCoreException
+from daft.expressions import col
+from daft.recordbatch import MicroPartition
+
+
+def chunks(*triples):
+    return [{"text": text, "start": start, "end": end} for text, start, end in triples]
+
+
+def test_chunk_text_characters():
+    table = MicroPartition.from_pydict({"col": ["abcdefghij", None, "", "héllo wörld"]})
+    result = table.eval_expression_list([col("col").str.chunk_text(4, overlap=1)])
+    assert result.to_pydict() == {
+        "col": [
+            chunks(("abcd", 0, 4), ("defg", 3, 7), ("ghij", 6, 10)),
+            None,
+            [],
+            chunks(("héll", 0, 4), ("lo w", 3, 7), ("wörl", 6, 10), ("ld", 9, 11)),
+        ]
+    }
+
+
+def test_chunk_text_tokens():
+    table = MicroPartition.from_pydict({"col": ["hello world, this is a test of token chunking"]})
+    result = table.eval_expression_list([col("col").str.chunk_text(4, overlap=1, strategy="tokens")])
+    assert result.to_pydict() == {
+        "col": [
+            chunks(
+                ("hello world, this", 0, 17),
+                (" this is a test", 12, 27),
+                (" test of token chunk", 22, 42),
+                (" chunking", 36, 45),
+            )
+        ]
+    }
+
+
+def test_chunk_text_sentences():
+    table = MicroPartition.from_pydict({"col": ["One. Two two. Three three three. Four."]})
+    result = table.eval_expression_list([col("col").str.chunk_text(20, overlap=10, strategy="sentences")])
+    assert result.to_pydict() == {
+        "col": [chunks(("One. Two two.", 0, 13), ("Three three three.", 14, 32), ("Four.", 33, 38))]
+    }
+
+
+def test_chunk_text_recursive():
+    text = "para one is here.\n\npara two is longer than that one\nwith a second line."
+    table = MicroPartition.from_pydict({"col": [text]})
+    result = table.eval_expression_list([col("col").str.chunk_text(20, overlap=5, strategy="recursive")])
+    assert result.to_pydict() == {
+        "col": [
+            chunks(
+                ("para one is here.", 0, 17),
+                ("para two is longer", 19, 37),
+                ("than that one", 38, 51),
+                ("with a second line.", 52, 71),
+            )
+        ]
+    }
+
+
+def test_chunk_text_explode():
+    table = MicroPartition.from_pydict({"id": [1, 2], "col": ["abcdef", "gh"]})
+    result = table.eval_expression_list([col("id"), col("col").str.chunk_text(3)]).explode([col("col")._explode()])
+    assert result.to_pydict() == {"id": [1, 1, 2], "col": chunks(("abc", 0, 3), ("def", 3, 6), ("gh", 0, 2))}
+
+
+@pytest.mark.parametrize(
+    ["kwargs", "match"],
+    [
+        ({"chunk_size": 0}, "chunk_size must be positive"),
+        ({"chunk_size": 4, "overlap": 4}, "overlap must be smaller than chunk_size"),
+        ({"chunk_size": 4, "strategy": "paragraphs"}, "Unsupported chunking strategy"),
+    ],
+)
+def test_chunk_text_invalid_args(kwargs, match):
+    table = MicroPartition.from_pydict({"col": ["abc"]})
+    with pytest.raises(DaftCoreException, match=match):
+        table.eval_expression_list([col("col").str.chunk_text(**kwargs)])
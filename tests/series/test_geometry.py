@@ -0,0 +1,78 @@
+from __future__ import annotations
+
+import copy
+import struct
+
+import pyarrow as pa
+import pytest
+
+from daft.datatype import DataType, get_super_ext_type
+from daft.series import Series
+
+DaftExtension = get_super_ext_type()
+
+
+def wkb_point(x: float, y: float) -> bytes:
+    return struct.pack("<BIdd", 1, 1, x, y)
+
+
+def make_geometry_series(points):
+    data = [wkb_point(*p) if p is not None else None for p in points]
+    return Series.from_arrow(pa.array(data, type=pa.binary())).cast(DataType.geometry())
+
+
+def test_geometry_arrow_round_trip():
+    s = make_geometry_series([(1.0, 2.0), None, (3.0, 4.0)])
+    assert s.datatype() == DataType.geometry()
+
+    arrow_arr = s.to_arrow()
+    assert isinstance(arrow_arr.type, DaftExtension)
+
+    from_arrow = Series.from_arrow(arrow_arr)
+    assert from_arrow.datatype() == DataType.geometry()
+    assert from_arrow.to_pylist() == s.to_pylist()
+
+    s_copy = copy.deepcopy(s)
+    assert s_copy.datatype() == DataType.geometry()
+    assert s_copy.to_pylist() == s.to_pylist()
+
+
+def test_geometry_binary_cast_round_trip():
+    data = [wkb_point(1.0, 2.0), None]
+    binary = Series.from_arrow(pa.array(data, type=pa.binary()))
+    geometry = binary.cast(DataType.geometry())
+    assert geometry.datatype() == DataType.geometry()
+
+    back = geometry.cast(DataType.binary())
+    assert back.datatype() == DataType.binary()
+    assert back.to_pylist() == data
+
+
+@pytest.mark.parametrize(
+    "source",
+    [
+        Series.from_pylist(["POINT (1 2)"]),
+        Series.from_pylist([1, 2]),
+        Series.from_pylist([b"not wkb"]),
+    ],
+)
+def test_invalid_cast_to_geometry(source):
+    with pytest.raises(Exception):
+        source.cast(DataType.geometry())
+
+
+def test_geometry_take_concat_sort():
+    a = make_geometry_series([(1.0, 2.0), None])
+    b = make_geometry_series([(0.0, 0.0)])
+
+    concatenated = Series.concat([a, b])
+    assert concatenated.datatype() == DataType.geometry()
+    assert len(concatenated) == 3
+
+    taken = concatenated.take(Series.from_pylist([2, 0]))
+    assert taken.datatype() == DataType.geometry()
+    assert taken.to_pylist() == [wkb_point(0.0, 0.0), wkb_point(1.0, 2.0)]
+
+    sorted_series = concatenated.sort()
+    assert sorted_series.datatype() == DataType.geometry()
+    assert sorted_series.to_pylist()[-1] is None
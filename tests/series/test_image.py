@@ -442,17 +442,14 @@ def test_image_encode_decode_pil_roundtrip(fixed_shape, mode, file_format):
         ("RGB", "bmp"),
         ("RGBA", "png"),
         ("RGBA", "tiff"),
-        # Rust image crate doesn't support WebP encoding.
-        # ("RGBA", "webp"),
-        # TODO(Clark): Support uint16 images.
-        # ("L16", "png"),
+        ("RGBA", "webp"),
+        ("L16", "png"),
         # OpenCV doesn't support 2-channel images.
         # ("LA16", "png"),
-        # TODO(Clark): Support uint16 images.
-        # ("RGB16", "png"),
-        # ("RGB16", "tiff"),
-        # ("RGBA16", "png"),
-        # ("RGBA16", "tiff"),
+        ("RGB16", "png"),
+        ("RGB16", "tiff"),
+        ("RGBA16", "png"),
+        ("RGBA16", "tiff"),
         # Image crate doesn't support LogLuv HDR encoding.
         # ("RGB32F", "tiff"),
         # ("RGBA32F", "tiff"),
@@ -594,13 +591,10 @@ def test_image_decode_opencv_multi_modes(output_mode):
         ("RGB", "bmp"),
         ("RGBA", "png"),
         ("RGBA", "tiff"),
-        # Rust image crate doesn't support WebP encoding.
-        # ("RGBA", "webp"),
-        # TODO(Clark): Support uint16 images.
+        ("RGBA", "webp"),
+        # 16-bit images are decoded into 8-bit images without an explicit mode, see
+        # test_image_encode_decode_opencv_roundtrip_16bit.
         # ("L16", "png"),
-        # OpenCV doesn't support 2-channel images.
-        # ("LA16", "png"),
-        # TODO(Clark): Support uint16 images.
         # ("RGB16", "png"),
         # ("RGB16", "tiff"),
         # ("RGBA16", "png"),
@@ -642,6 +636,102 @@ def test_image_encode_decode_opencv_roundtrip(mode, file_format):
     np.testing.assert_equal(opencv_decoded_imgs, [arr, arr, arr])
 
 
+@pytest.mark.parametrize(
+    ["mode", "file_format"],
+    [
+        ("L16", "png"),
+        ("RGB16", "png"),
+        ("RGB16", "tiff"),
+        ("RGBA16", "png"),
+        ("RGBA16", "tiff"),
+    ],
+)
+def test_image_encode_decode_opencv_roundtrip_16bit(mode, file_format):
+    num_channels = MODE_TO_NUM_CHANNELS[mode]
+    shape = (4, 4, num_channels)
+    # Use the full 16-bit range so that truncating to 8 bits would be caught.
+    arr = (np.arange(np.prod(shape)) * 1021).reshape(shape).astype(np.uint16)
+    cv2_arr = arr
+    color_conv = MODE_TO_OPENCV_COLOR_CONVERSION.get(mode)
+    if color_conv is not None:
+        cv2_arr = cv2.cvtColor(arr, color_conv)
+    img_bytes = cv2.imencode(f".{file_format}", cv2_arr)[1].tobytes()
+    s = Series.from_arrow(pa.array([img_bytes, None], type=pa.binary()))
+
+    t = s.image.decode(mode=mode)
+    assert t.datatype() == DataType.image(mode)
+    np.testing.assert_equal(t.to_pylist(), [arr, None])
+
+    u = t.image.encode(file_format.upper())
+    opencv_decoded_img = cv2.imdecode(np.frombuffer(u.to_pylist()[0], dtype=np.uint8), cv2.IMREAD_UNCHANGED)
+    if num_channels == 1:
+        opencv_decoded_img = np.expand_dims(opencv_decoded_img, -1)
+    color_conv = MODE_TO_OPENCV_COLOR_CONVERSION_DECODE.get(mode)
+    if color_conv is not None:
+        opencv_decoded_img = cv2.cvtColor(opencv_decoded_img, color_conv)
+    assert opencv_decoded_img.dtype == np.uint16
+    np.testing.assert_equal(opencv_decoded_img, arr)
+
+
+def test_image_decode_16bit_without_mode():
+    arr = (np.arange(16) * 4369).reshape((4, 4)).astype(np.uint16)
+    img_bytes = cv2.imencode(".png", arr)[1].tobytes()
+    s = Series.from_arrow(pa.array([img_bytes], type=pa.binary()))
+
+    t = s.image.decode()
+    assert t.datatype() == DataType.image()
+    # 16-bit values are rescaled into the 8-bit range.
+    expected = np.expand_dims((arr // 257).astype(np.uint8), -1)
+    np.testing.assert_equal(t.to_pylist(), [expected])
+
+
+@pytest.mark.parametrize("mode", ["L", "LA", "RGB", "RGBA"])
+def test_image_encode_decode_webp_roundtrip(mode):
+    num_channels = MODE_TO_NUM_CHANNELS[mode]
+    arrs = [
+        np.arange(2 * 3 * num_channels).reshape((2, 3, num_channels)).astype(np.uint8),
+        np.arange(4 * 4 * num_channels).reshape((4, 4, num_channels)).astype(np.uint8),
+        None,
+    ]
+    t = Series.from_pylist(arrs, dtype=DataType.python()).cast(DataType.image(mode))
+
+    u = t.image.encode("WEBP")
+    assert all(bytes_[8:12] == b"WEBP" for bytes_ in u.to_pylist()[:2])
+    # WebP images are encoded losslessly.
+    np.testing.assert_equal(u.image.decode(mode=mode).to_pylist(), arrs)
+
+
+@pytest.mark.parametrize(
+    ["file_format", "magic_bytes"],
+    [
+        ("png", b"\x89PNG"),
+        ("jpeg", b"\xff\xd8\xff"),
+        ("tiff", b"II*\x00"),
+        ("bmp", b"BM"),
+        ("webp", b"RIFF"),
+    ],
+)
+def test_image_decode_sniffs_format(file_format, magic_bytes):
+    arr = np.full((4, 4, 3), 128, dtype=np.uint8)
+    img_bytes = io.BytesIO()
+    Image.fromarray(arr, mode="RGB").save(img_bytes, file_format)
+    img_bytes = img_bytes.getvalue()
+    assert img_bytes.startswith(magic_bytes)
+
+    t = Series.from_pylist([img_bytes]).image.decode()
+    assert t.to_pylist()[0].shape == (4, 4, 3)
+
+
+def test_image_decode_avif_unsupported():
+    # The header of an AVIF file: an ISO base media `ftyp` box with the `avif` brand.
+    s = Series.from_pylist([b"\x00\x00\x00\x20ftypavif\x00\x00\x00\x00mif1miaf"])
+
+    with pytest.raises(ValueError, match="decoding AVIF images is not supported"):
+        s.image.decode()
+
+    assert s.image.decode(on_error="null").to_pylist() == [None]
+
+
 @pytest.mark.parametrize("mode", ["L", "LA", "RGB", "RGBA"])
 @pytest.mark.parametrize("fixed_shape", [True, False])
 def test_image_resize_same_mode(fixed_shape, mode):
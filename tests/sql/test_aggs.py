@@ -249,3 +249,34 @@ def test_group_by_with_lit_selection():
     res = daft.sql("select 0 from df group by ids", df=df)
     expected = {"literal": [0, 0, 0, 0]}
     assert res.to_pydict() == expected
+
+
+def test_grouping_sets_with_grouping():
+    df = daft.from_pydict({"dept": ["IT", "IT", "HR", "HR"], "year": [2022, 2023, 2022, 2023], "cost": [1, 2, 3, 4]})
+    sql = """
+    SELECT dept, year, SUM(cost) AS total, GROUPING(dept) AS gd, GROUPING_ID(dept, year) AS gid
+    FROM df
+    GROUP BY GROUPING SETS ((dept), (year), ())
+    ORDER BY GROUPING_ID(dept, year), dept, year
+    """
+    assert daft.sql(sql, df=df).to_pydict() == {
+        "dept": ["HR", "IT", None, None, None],
+        "year": [None, None, 2022, 2023, None],
+        "total": [7, 3, 4, 6, 10],
+        "gd": [0, 0, 1, 1, 1],
+        "gid": [1, 1, 2, 2, 3],
+    }
+
+
+def test_cube_and_composite_rollup():
+    df = daft.from_pydict({"a": [1, 1, 2], "b": ["x", "y", "x"], "c": [10, 20, 30]})
+
+    cube = daft.sql("SELECT a, b, SUM(c) AS s FROM df GROUP BY CUBE(a, b) ORDER BY a, b", df=df).to_pydict()
+    assert cube == {
+        "a": [1, 1, 1, 2, 2, None, None, None],
+        "b": ["x", "y", None, "x", None, "x", "y", None],
+        "s": [10, 20, 30, 30, 30, 40, 20, 60],
+    }
+
+    rollup = daft.sql("SELECT a, b, SUM(c) AS s FROM df GROUP BY ROLLUP((a, b)) ORDER BY a, b", df=df).to_pydict()
+    assert rollup == {"a": [1, 1, 2, None], "b": ["x", "y", "x", None], "s": [10, 20, 30, 60]}
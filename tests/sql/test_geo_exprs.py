@@ -0,0 +1,45 @@
+from __future__ import annotations
+
+import daft
+from daft import col
+from daft.sql.sql import SQLCatalog
+
+
+def test_geo_functions():
+    df = daft.from_pydict(
+        {
+            "shape": ["POLYGON((0 0,4 0,4 4,0 4,0 0))", "POLYGON((10 10,11 10,11 11,10 11,10 10))", None],
+            "point": ["POINT(1 1)", "POINT(3 4)", "POINT(0 0)"],
+        }
+    )
+    catalog = SQLCatalog({"test": df})
+
+    sql = """
+    SELECT
+        st_contains(st_geomfromtext(shape), st_geomfromtext(point)) as contains,
+        st_intersects(st_geomfromtext(point), st_geomfromtext(shape)) as intersects,
+        st_distance(st_geomfromtext(shape), st_geomfromtext(point)) as distance,
+        st_astext(st_geomfromtext(point)) as wkt
+    FROM test
+    """
+    actual = daft.sql(sql, catalog=catalog).collect().to_pydict()
+    expected = {
+        "contains": [True, False, None],
+        "intersects": [True, False, None],
+        "distance": [0.0, 9.219544457292887, None],
+        "wkt": ["POINT(1 1)", "POINT(3 4)", "POINT(0 0)"],
+    }
+    assert actual == expected
+
+    df = df.select(
+        col("shape").geo.from_wkt().geo.contains(col("point").geo.from_wkt()).alias("contains"),
+        col("point").geo.from_wkt().geo.to_geojson().alias("geojson"),
+    )
+    assert df.to_pydict() == {
+        "contains": [True, False, None],
+        "geojson": [
+            '{"type":"Point","coordinates":[1.0,1.0]}',
+            '{"type":"Point","coordinates":[3.0,4.0]}',
+            '{"type":"Point","coordinates":[0.0,0.0]}',
+        ],
+    }
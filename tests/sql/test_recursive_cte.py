@@ -0,0 +1,92 @@
+from __future__ import annotations
+
+import pytest
+
+import daft
+from daft.sql import SQLCatalog
+from tests.conftest import get_tests_daft_runner_name
+
+pytestmark = pytest.mark.skipif(
+    get_tests_daft_runner_name() == "ray",
+    reason="recursive CTEs are only supported on the native runner",
+)
+
+
+@pytest.fixture
+def catalog():
+    # 1 -> 2 -> 3 -> 1 forms a cycle, 4 -> 5 is reachable only from 4.
+    edges = daft.from_pydict({"src": [1, 2, 3, 4], "dst": [2, 3, 1, 5]})
+    return SQLCatalog({"edges": edges})
+
+
+def test_recursive_cte_counting():
+    df = daft.sql(
+        """
+        with recursive t(n) as (
+            select 1 as n
+            union all
+            select n + 1 from t where n < 10
+        )
+        select n from t
+        """
+    )
+    assert sorted(df.to_pydict()["n"]) == list(range(1, 11))
+
+
+def test_recursive_cte_graph_reachability(catalog):
+    df = daft.sql(
+        """
+        with recursive reachable as (
+            select dst as node from edges where src = 1
+            union
+            select edges.dst from reachable join edges on reachable.node = edges.src
+        )
+        select node from reachable
+        """,
+        catalog,
+    )
+    # UNION discards rows that were already produced, so the cycle terminates.
+    assert sorted(df.to_pydict()["node"]) == [1, 2, 3]
+
+
+def test_recursive_cte_union_all_cycle_hits_max_iterations(catalog):
+    query = """
+        with recursive reachable as (
+            select dst as node from edges where src = 1
+            union all
+            select edges.dst from reachable join edges on reachable.node = edges.src
+        )
+        select node from reachable
+    """
+    with daft.execution_config_ctx(recursive_cte_max_iterations=5):
+        with pytest.raises(Exception, match="did not reach a fixpoint after 5 iterations"):
+            daft.sql(query, catalog).collect()
+
+
+def test_recursive_cte_depth(catalog):
+    df = daft.sql(
+        """
+        with recursive paths(node, depth) as (
+            select 4 as node, 0 as depth
+            union all
+            select edges.dst, paths.depth + 1 from paths join edges on paths.node = edges.src
+        )
+        select node, depth from paths
+        """,
+        catalog,
+    )
+    assert df.sort("depth").to_pydict() == {"node": [4, 5], "depth": [0, 1]}
+
+
+def test_recursive_cte_without_self_reference():
+    df = daft.sql(
+        """
+        with recursive t as (
+            select 1 as n
+            union all
+            select 2 as n
+        )
+        select n from t
+        """
+    )
+    assert sorted(df.to_pydict()["n"]) == [1, 2]
@@ -0,0 +1,77 @@
+from __future__ import annotations
+
+import pytest
+
+import daft
+from daft.sql import SQLCatalog
+from tests.utils import sort_pydict
+
+
+@pytest.fixture
+def catalog():
+    t1 = daft.from_pydict({"k": [1, 2, 3, 4], "v": [10, 20, 30, 40]})
+    t2 = daft.from_pydict({"k": [1, 1, 2, 5], "x": [5, 15, 25, 35]})
+    return SQLCatalog({"t1": t1, "t2": t2})
+
+
+@pytest.mark.parametrize(
+    "query,expected",
+    [
+        ("select k from t1 where k = any (select k from t2)", [1, 2]),
+        ("select k from t1 where k = some (select k from t2)", [1, 2]),
+        ("select k from t1 where k <> all (select k from t2)", [3, 4]),
+        ("select k from t1 where k < any (select k from t2)", [1, 2, 3, 4]),
+        ("select k from t1 where k > all (select k from t2 where k < 3)", [3, 4]),
+        ("select k from t1 where k > all (select k from t2 where k > 100)", [1, 2, 3, 4]),
+        ("select k from t1 where v > any (select x from t2 where t2.k = t1.k)", [1]),
+    ],
+)
+def test_any_all(catalog, query, expected):
+    actual = daft.sql(query, catalog).collect().to_pydict()
+    assert sorted(actual["k"]) == expected
+
+
+def test_correlated_scalar_subquery_in_select(catalog):
+    df = daft.sql(
+        """
+        select
+            k,
+            (select count(*) from t2 where t2.k = t1.k) as c,
+            (select sum(x) from t2 where t2.k = t1.k) as s
+        from t1
+        """,
+        catalog,
+    )
+
+    actual = df.collect().to_pydict()
+
+    expected = {"k": [1, 2, 3, 4], "c": [2, 1, 0, 0], "s": [20, 25, None, None]}
+
+    assert sort_pydict(actual, "k", ascending=True) == expected
+
+
+def test_lateral_join(catalog):
+    df = daft.sql("select t1.k, s.x from t1, lateral (select x from t2 where t2.k = t1.k) s", catalog)
+
+    actual = df.collect().to_pydict()
+
+    assert sort_pydict(actual, "x", ascending=True) == {"k": [1, 1, 2], "x": [5, 15, 25]}
+
+
+def test_left_lateral_join(catalog):
+    df = daft.sql(
+        "select t1.k, s.c from t1 left join lateral (select count(*) as c from t2 where t2.k = t1.k) s on true",
+        catalog,
+    )
+
+    actual = df.collect().to_pydict()
+
+    assert sort_pydict(actual, "k", ascending=True) == {"k": [1, 2, 3, 4], "c": [2, 1, 0, 0]}
+
+
+def test_parenthesized_set_operations(catalog):
+    df = daft.sql("(select k from t1) union all (select k from t2) order by k limit 3", catalog)
+    assert df.collect().to_pydict() == {"k": [1, 1, 1]}
+
+    df = daft.sql("select v from t1 union all select x from t2 order by v", catalog)
+    assert df.collect().to_pydict() == {"v": [5, 10, 15, 20, 25, 30, 35, 40]}
@@ -46,6 +46,7 @@ all_daft_types = (
     + daft_sparse_tensor_types
     + daft_fixed_shape_tensor_types
     + daft_fixed_shape_sparse_tensor_types
+    + [DataType.geometry()]
 )
 
 
@@ -248,6 +249,11 @@ def test_is_fixed_shape_sparse_tensor(test_type: DataType):
         test_type == DataType.sparse_tensor(test_type.dtype, shape=test_type.shape)
 
 
+@pytest.mark.parametrize("test_type", all_daft_types)
+def test_is_geometry(test_type):
+    assert test_type.is_geometry() == (test_type == DataType.geometry())
+
+
 @pytest.mark.parametrize("test_type", all_daft_types)
 def test_is_python(test_type):
     assert test_type.is_python() == (test_type == DataType.python())